@@ -42,6 +42,8 @@ where
                     "api_flow",
                     &[
                         Flow::PaymentsCancel,
+                        Flow::PaymentsReverseAuthorization,
+                        Flow::PaymentsCancelPostCapture,
                         Flow::PaymentsCapture,
                         Flow::PaymentsConfirm,
                         Flow::PaymentsCreate,
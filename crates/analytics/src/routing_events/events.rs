@@ -71,4 +71,5 @@ pub struct RoutingEventsResult {
     pub method: String,
     pub routing_engine: String,
     pub routing_approach: Option<String>,
+    pub routing_rule: Option<String>,
 }
@@ -703,6 +703,11 @@ pub struct WebhookDetails {
     /// If this property is true, a webhook message is posted whenever a payment fails
     #[schema(example = true)]
     pub payment_failed_enabled: Option<bool>,
+
+    /// If this property is true, a webhook message is posted a day before the authorization of a
+    /// manually captured payment expires
+    #[schema(example = true)]
+    pub payment_authorization_expiring_enabled: Option<bool>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -800,6 +805,10 @@ pub struct MerchantConnectorCreate {
     #[schema(value_type = Option<ConnectorWalletDetails>)]
     pub connector_wallets_details: Option<ConnectorWalletDetails>,
 
+    /// Fees charged by the connector per currency, used to estimate the processing cost of payments in least cost routing
+    #[schema(value_type = Option<ConnectorFeeSchedule>)]
+    pub fee_schedule: Option<common_types::connector_fees::ConnectorFeeSchedule>,
+
     /// Additional data that might be required by hyperswitch, to enable some specific features.
     #[schema(value_type = Option<MerchantConnectorAccountFeatureMetadata>)]
     pub feature_metadata: Option<MerchantConnectorAccountFeatureMetadata>,
@@ -944,6 +953,10 @@ pub struct MerchantConnectorCreate {
     /// The connector_wallets_details is used to store wallet details such as certificates and wallet credentials
     #[schema(value_type = Option<ConnectorWalletDetails>)]
     pub connector_wallets_details: Option<ConnectorWalletDetails>,
+
+    /// Fees charged by the connector per currency, used to estimate the processing cost of payments in least cost routing
+    #[schema(value_type = Option<ConnectorFeeSchedule>)]
+    pub fee_schedule: Option<common_types::connector_fees::ConnectorFeeSchedule>,
 }
 
 #[cfg(feature = "v1")]
@@ -1265,6 +1278,10 @@ pub struct MerchantConnectorResponse {
     #[schema(value_type = Option<ConnectorWalletDetails>)]
     pub connector_wallets_details: Option<ConnectorWalletDetails>,
 
+    /// Fees charged by the connector per currency, used to estimate the processing cost of payments in least cost routing
+    #[schema(value_type = Option<ConnectorFeeSchedule>)]
+    pub fee_schedule: Option<common_types::connector_fees::ConnectorFeeSchedule>,
+
     /// Additional data that might be required by hyperswitch, to enable some specific features.
     #[schema(value_type = Option<MerchantConnectorAccountFeatureMetadata>)]
     pub feature_metadata: Option<MerchantConnectorAccountFeatureMetadata>,
@@ -1391,6 +1408,10 @@ pub struct MerchantConnectorResponse {
     /// The connector_wallets_details is used to store wallet details such as certificates and wallet credentials
     #[schema(value_type = Option<ConnectorWalletDetails>)]
     pub connector_wallets_details: Option<ConnectorWalletDetails>,
+
+    /// Fees charged by the connector per currency, used to estimate the processing cost of payments in least cost routing
+    #[schema(value_type = Option<ConnectorFeeSchedule>)]
+    pub fee_schedule: Option<common_types::connector_fees::ConnectorFeeSchedule>,
 }
 
 #[cfg(feature = "v1")]
@@ -1651,6 +1672,10 @@ pub struct MerchantConnectorUpdate {
     /// The connector_wallets_details is used to store wallet details such as certificates and wallet credentials
     #[schema(value_type = Option<ConnectorWalletDetails>)]
     pub connector_wallets_details: Option<ConnectorWalletDetails>,
+
+    /// Fees charged by the connector per currency, used to estimate the processing cost of payments in least cost routing. An empty list removes the configured fees.
+    #[schema(value_type = Option<ConnectorFeeSchedule>)]
+    pub fee_schedule: Option<common_types::connector_fees::ConnectorFeeSchedule>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -1737,6 +1762,10 @@ pub struct MerchantConnectorUpdate {
     /// The connector_wallets_details is used to store wallet details such as certificates and wallet credentials
     pub connector_wallets_details: Option<ConnectorWalletDetails>,
 
+    /// Fees charged by the connector per currency, used to estimate the processing cost of payments in least cost routing. An empty list removes the configured fees.
+    #[schema(value_type = Option<ConnectorFeeSchedule>)]
+    pub fee_schedule: Option<common_types::connector_fees::ConnectorFeeSchedule>,
+
     /// Additional data that might be required by hyperswitch, to enable some specific features.
     #[schema(value_type = Option<MerchantConnectorAccountFeatureMetadata>)]
     pub feature_metadata: Option<MerchantConnectorAccountFeatureMetadata>,
@@ -2126,6 +2155,29 @@ pub struct ProfileCreate {
     /// Four-digit code assigned based on business type to determine processing fees and risk level
     #[schema(value_type = Option<MerchantCategoryCode>, example = "5411")]
     pub merchant_category_code: Option<api_enums::MerchantCategoryCode>,
+
+    /// Time in seconds after its creation, after which a payment that has not been authorized or completed is
+    /// marked as expired. Payments do not expire if this is not set
+    #[schema(example = 86400)]
+    pub payment_intent_expiry: Option<u32>,
+
+    /// Indicates if a payment whose authorization is about to lapse before it is captured is authorized again
+    /// as a new merchant initiated payment, using the payment method saved for off session use. The merchant is
+    /// notified through a `payment_authorization_expiring` webhook if the payment is not authorized again, or
+    /// when `payment_authorization_expiring_enabled` is set in the webhook details
+    #[schema(default = false, example = false)]
+    pub is_auto_reauthorization_enabled: Option<bool>,
+
+    /// Policy for retrying failed payments of the profile with other connectors, based on the error category
+    /// of the failure configured in the gateway status map
+    #[schema(value_type = Option<RetryPolicy>)]
+    pub retry_policy: Option<common_types::payments::RetryPolicy>,
+
+    /// Configuration for offering customers to pay in the currency of their card, with the markup applied
+    /// over the exchange rate and the validity of the quotes
+    #[schema(value_type = Option<DynamicCurrencyConversionConfig>)]
+    pub dynamic_currency_conversion:
+        Option<common_types::payments::DynamicCurrencyConversionConfig>,
 }
 
 #[nutype::nutype(
@@ -2272,6 +2324,34 @@ pub struct ProfileCreate {
     /// Four-digit code assigned based on business type to determine processing fees and risk level
     #[schema(value_type = Option<MerchantCategoryCode>, example = "5411")]
     pub merchant_category_code: Option<api_enums::MerchantCategoryCode>,
+
+    /// Time in seconds after its creation, after which a payment that has not been authorized or completed is
+    /// marked as expired. Payments do not expire if this is not set
+    #[schema(example = 86400)]
+    pub payment_intent_expiry: Option<u32>,
+
+    /// Indicates if a payment whose authorization is about to lapse before it is captured is authorized again
+    /// as a new merchant initiated payment, using the payment method saved for off session use. The merchant is
+    /// notified through a `payment_authorization_expiring` webhook if the payment is not authorized again, or
+    /// when `payment_authorization_expiring_enabled` is set in the webhook details
+    #[schema(default = false, example = false)]
+    pub is_auto_reauthorization_enabled: Option<bool>,
+
+    /// Policy for retrying failed payments of the profile with other connectors, based on the error category
+    /// of the failure configured in the gateway status map
+    #[schema(value_type = Option<RetryPolicy>)]
+    pub retry_policy: Option<common_types::payments::RetryPolicy>,
+
+    /// Configuration for offering customers to pay in the currency of their card, with the markup applied
+    /// over the exchange rate and the validity of the quotes
+    #[schema(value_type = Option<DynamicCurrencyConversionConfig>)]
+    pub dynamic_currency_conversion:
+        Option<common_types::payments::DynamicCurrencyConversionConfig>,
+
+    /// Action taken when a payment method saved for a customer has the same fingerprint as a payment method
+    /// already saved for the customer. Defaults to rejecting the duplicate payment method
+    #[schema(value_type = Option<PaymentMethodDuplicatePolicy>, example = "reuse_existing")]
+    pub payment_method_duplicate_policy: Option<common_enums::PaymentMethodDuplicatePolicy>,
 }
 
 #[cfg(feature = "v1")]
@@ -2447,6 +2527,29 @@ pub struct ProfileResponse {
     /// Four-digit code assigned based on business type to determine processing fees and risk level
     #[schema(value_type = Option<MerchantCategoryCode>, example = "5411")]
     pub merchant_category_code: Option<api_enums::MerchantCategoryCode>,
+
+    /// Time in seconds after its creation, after which a payment that has not been authorized or completed is
+    /// marked as expired. Payments do not expire if this is not set
+    #[schema(example = 86400)]
+    pub payment_intent_expiry: Option<i64>,
+
+    /// Indicates if a payment whose authorization is about to lapse before it is captured is authorized again
+    /// as a new merchant initiated payment, using the payment method saved for off session use. The merchant is
+    /// notified through a `payment_authorization_expiring` webhook if the payment is not authorized again, or
+    /// when `payment_authorization_expiring_enabled` is set in the webhook details
+    #[schema(default = false, example = false)]
+    pub is_auto_reauthorization_enabled: Option<bool>,
+
+    /// Policy for retrying failed payments of the profile with other connectors, based on the error category
+    /// of the failure configured in the gateway status map
+    #[schema(value_type = Option<RetryPolicy>)]
+    pub retry_policy: Option<common_types::payments::RetryPolicy>,
+
+    /// Configuration for offering customers to pay in the currency of their card, with the markup applied
+    /// over the exchange rate and the validity of the quotes
+    #[schema(value_type = Option<DynamicCurrencyConversionConfig>)]
+    pub dynamic_currency_conversion:
+        Option<common_types::payments::DynamicCurrencyConversionConfig>,
 }
 
 #[cfg(feature = "v2")]
@@ -2601,6 +2704,34 @@ pub struct ProfileResponse {
     /// Four-digit code assigned based on business type to determine processing fees and risk level
     #[schema(value_type = Option<MerchantCategoryCode>, example = "5411")]
     pub merchant_category_code: Option<api_enums::MerchantCategoryCode>,
+
+    /// Time in seconds after its creation, after which a payment that has not been authorized or completed is
+    /// marked as expired. Payments do not expire if this is not set
+    #[schema(example = 86400)]
+    pub payment_intent_expiry: Option<i64>,
+
+    /// Indicates if a payment whose authorization is about to lapse before it is captured is authorized again
+    /// as a new merchant initiated payment, using the payment method saved for off session use. The merchant is
+    /// notified through a `payment_authorization_expiring` webhook if the payment is not authorized again, or
+    /// when `payment_authorization_expiring_enabled` is set in the webhook details
+    #[schema(default = false, example = false)]
+    pub is_auto_reauthorization_enabled: Option<bool>,
+
+    /// Policy for retrying failed payments of the profile with other connectors, based on the error category
+    /// of the failure configured in the gateway status map
+    #[schema(value_type = Option<RetryPolicy>)]
+    pub retry_policy: Option<common_types::payments::RetryPolicy>,
+
+    /// Configuration for offering customers to pay in the currency of their card, with the markup applied
+    /// over the exchange rate and the validity of the quotes
+    #[schema(value_type = Option<DynamicCurrencyConversionConfig>)]
+    pub dynamic_currency_conversion:
+        Option<common_types::payments::DynamicCurrencyConversionConfig>,
+
+    /// Action taken when a payment method saved for a customer has the same fingerprint as a payment method
+    /// already saved for the customer. Defaults to rejecting the duplicate payment method
+    #[schema(value_type = Option<PaymentMethodDuplicatePolicy>, example = "reuse_existing")]
+    pub payment_method_duplicate_policy: Option<common_enums::PaymentMethodDuplicatePolicy>,
 }
 
 #[cfg(feature = "v1")]
@@ -2761,6 +2892,29 @@ pub struct ProfileUpdate {
     /// Four-digit code assigned based on business type to determine processing fees and risk level
     #[schema(value_type = Option<MerchantCategoryCode>, example = "5411")]
     pub merchant_category_code: Option<api_enums::MerchantCategoryCode>,
+
+    /// Time in seconds after its creation, after which a payment that has not been authorized or completed is
+    /// marked as expired. Payments do not expire if this is not set
+    #[schema(example = 86400)]
+    pub payment_intent_expiry: Option<u32>,
+
+    /// Indicates if a payment whose authorization is about to lapse before it is captured is authorized again
+    /// as a new merchant initiated payment, using the payment method saved for off session use. The merchant is
+    /// notified through a `payment_authorization_expiring` webhook if the payment is not authorized again, or
+    /// when `payment_authorization_expiring_enabled` is set in the webhook details
+    #[schema(default = false, example = false)]
+    pub is_auto_reauthorization_enabled: Option<bool>,
+
+    /// Policy for retrying failed payments of the profile with other connectors, based on the error category
+    /// of the failure configured in the gateway status map. An empty policy removes the configured policy
+    #[schema(value_type = Option<RetryPolicy>)]
+    pub retry_policy: Option<common_types::payments::RetryPolicy>,
+
+    /// Configuration for offering customers to pay in the currency of their card, with the markup applied
+    /// over the exchange rate and the validity of the quotes
+    #[schema(value_type = Option<DynamicCurrencyConversionConfig>)]
+    pub dynamic_currency_conversion:
+        Option<common_types::payments::DynamicCurrencyConversionConfig>,
 }
 
 #[cfg(feature = "v2")]
@@ -2897,6 +3051,34 @@ pub struct ProfileUpdate {
     /// Four-digit code assigned based on business type to determine processing fees and risk level
     #[schema(value_type = Option<MerchantCategoryCode>, example = "5411")]
     pub merchant_category_code: Option<api_enums::MerchantCategoryCode>,
+
+    /// Time in seconds after its creation, after which a payment that has not been authorized or completed is
+    /// marked as expired. Payments do not expire if this is not set
+    #[schema(example = 86400)]
+    pub payment_intent_expiry: Option<u32>,
+
+    /// Indicates if a payment whose authorization is about to lapse before it is captured is authorized again
+    /// as a new merchant initiated payment, using the payment method saved for off session use. The merchant is
+    /// notified through a `payment_authorization_expiring` webhook if the payment is not authorized again, or
+    /// when `payment_authorization_expiring_enabled` is set in the webhook details
+    #[schema(default = false, example = false)]
+    pub is_auto_reauthorization_enabled: Option<bool>,
+
+    /// Policy for retrying failed payments of the profile with other connectors, based on the error category
+    /// of the failure configured in the gateway status map. An empty policy removes the configured policy
+    #[schema(value_type = Option<RetryPolicy>)]
+    pub retry_policy: Option<common_types::payments::RetryPolicy>,
+
+    /// Configuration for offering customers to pay in the currency of their card, with the markup applied
+    /// over the exchange rate and the validity of the quotes
+    #[schema(value_type = Option<DynamicCurrencyConversionConfig>)]
+    pub dynamic_currency_conversion:
+        Option<common_types::payments::DynamicCurrencyConversionConfig>,
+
+    /// Action taken when a payment method saved for a customer has the same fingerprint as a payment method
+    /// already saved for the customer. Defaults to rejecting the duplicate payment method
+    #[schema(value_type = Option<PaymentMethodDuplicatePolicy>, example = "reuse_existing")]
+    pub payment_method_duplicate_policy: Option<common_enums::PaymentMethodDuplicatePolicy>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
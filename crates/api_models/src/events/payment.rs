@@ -11,9 +11,10 @@ use crate::payment_methods::PaymentMethodListResponseForSession;
 use crate::{
     payment_methods::PaymentMethodListResponse,
     payments::{
-        ExtendedCardInfoResponse, PaymentIdType, PaymentListFilterConstraints,
-        PaymentListResponseV2, PaymentsApproveRequest, PaymentsCancelRequest,
-        PaymentsCaptureRequest, PaymentsCompleteAuthorizeRequest,
+        CurrencyConversionQuoteRequest, CurrencyConversionQuoteResponse, ExtendedCardInfoResponse,
+        InstallmentPlansRequest, InstallmentPlansResponse, PaymentIdType, PaymentLedgerResponse,
+        PaymentListFilterConstraints, PaymentListResponseV2, PaymentsApproveRequest,
+        PaymentsCancelRequest, PaymentsCaptureRequest, PaymentsCompleteAuthorizeRequest,
         PaymentsDynamicTaxCalculationRequest, PaymentsDynamicTaxCalculationResponse,
         PaymentsExternalAuthenticationRequest, PaymentsExternalAuthenticationResponse,
         PaymentsIncrementalAuthorizationRequest, PaymentsManualUpdateRequest,
@@ -254,6 +255,30 @@ impl ApiEventMetric for PaymentMethodMigrateResponse {
 
 impl ApiEventMetric for PaymentMethodUpdate {}
 
+#[cfg(feature = "v1")]
+impl ApiEventMetric for payment_methods::PaymentMethodExportRequest {}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for payment_methods::PaymentMethodExportResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PaymentMethodExport {
+            export_id: self.export_id.clone(),
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for payment_methods::PaymentMethodExportRecipientKeyCreateRequest {}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for payment_methods::PaymentMethodExportRecipientKeyResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PaymentMethodExportRecipientKey {
+            key_id: self.key_id.clone(),
+        })
+    }
+}
+
 #[cfg(feature = "v1")]
 impl ApiEventMetric for payment_methods::DefaultPaymentMethod {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
@@ -276,6 +301,27 @@ impl ApiEventMetric for payment_methods::PaymentMethodDeleteResponse {
     }
 }
 
+#[cfg(feature = "v2")]
+impl ApiEventMetric for payment_methods::PaymentMethodDuplicatesResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PaymentMethod {
+            payment_method_id: self.id.clone(),
+            payment_method_type: None,
+            payment_method_subtype: None,
+        })
+    }
+}
+
+#[cfg(feature = "v2")]
+impl ApiEventMetric for payment_methods::PaymentMethodMergeRequest {}
+
+#[cfg(feature = "v2")]
+impl ApiEventMetric for payment_methods::PaymentMethodMergeResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        self.payment_method.get_api_event_type()
+    }
+}
+
 #[cfg(feature = "v1")]
 impl ApiEventMetric for payment_methods::PaymentMethodDeleteResponse {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
@@ -413,6 +459,39 @@ impl ApiEventMetric for PaymentsExternalAuthenticationRequest {
 #[cfg(feature = "v1")]
 impl ApiEventMetric for ExtendedCardInfoResponse {}
 
+#[cfg(feature = "v1")]
+impl ApiEventMetric for PaymentLedgerResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for InstallmentPlansRequest {}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for InstallmentPlansResponse {}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for CurrencyConversionQuoteRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for CurrencyConversionQuoteResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
 #[cfg(feature = "v1")]
 impl ApiEventMetric for PaymentsManualUpdateRequest {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
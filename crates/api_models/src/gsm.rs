@@ -27,7 +27,7 @@ pub struct GsmCreateRequest {
     pub unified_code: Option<String>,
     /// error message unified across the connectors
     pub unified_message: Option<String>,
-    /// category in which error belongs to
+    /// category in which error belongs to, used to select the cascade of connectors in the retry policy of the profile
     pub error_category: Option<ErrorCategory>,
     /// indicates if retry with pan is possible
     pub clear_pan_possible: bool,
@@ -93,7 +93,7 @@ pub struct GsmUpdateRequest {
     pub unified_code: Option<String>,
     /// error message unified across the connectors
     pub unified_message: Option<String>,
-    /// category in which error belongs to
+    /// category in which error belongs to, used to select the cascade of connectors in the retry policy of the profile
     pub error_category: Option<ErrorCategory>,
     /// indicates if retry with pan is possible
     pub clear_pan_possible: Option<bool>,
@@ -150,7 +150,7 @@ pub struct GsmResponse {
     pub unified_code: Option<String>,
     /// error message unified across the connectors
     pub unified_message: Option<String>,
-    /// category in which error belongs to
+    /// category in which error belongs to, used to select the cascade of connectors in the retry policy of the profile
     pub error_category: Option<ErrorCategory>,
     /// indicates if retry with pan is possible
     pub clear_pan_possible: bool,
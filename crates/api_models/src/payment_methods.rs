@@ -798,7 +798,7 @@ pub enum PaymentMethodResponseData {
 }
 
 #[cfg(feature = "v1")]
-#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema, Clone)]
 pub struct PaymentMethodResponse {
     /// Unique identifier for a merchant
     #[schema(example = "merchant_1671528864", value_type = String)]
@@ -2005,6 +2005,43 @@ pub struct PaymentMethodDeleteResponse {
     pub id: id_type::GlobalPaymentMethodId,
 }
 
+#[cfg(feature = "v2")]
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct PaymentMethodDuplicatesResponse {
+    /// The unique identifier of the Payment method whose duplicates are listed
+    #[schema(value_type = String, example = "12345_pm_01926c58bc6e77c09e809964e72af8c8")]
+    pub id: id_type::GlobalPaymentMethodId,
+
+    /// The active payment methods of the merchant with the same fingerprint as the payment method,
+    /// saved for the same customer or for other customers
+    pub duplicates: Vec<PaymentMethodResponse>,
+}
+
+#[cfg(feature = "v2")]
+#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentMethodMergeRequest {
+    /// The duplicates to be merged into the payment method. The mandates and the connector tokens of the
+    /// duplicates are moved to the payment method, and the duplicates are deactivated
+    #[schema(value_type = Vec<String>, example = json!(["12345_pm_01926c58bc6e77c09e809964e72af8c9"]))]
+    pub duplicate_payment_method_ids: Vec<id_type::GlobalPaymentMethodId>,
+}
+
+#[cfg(feature = "v2")]
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct PaymentMethodMergeResponse {
+    /// The payment method into which the duplicates were merged
+    pub payment_method: PaymentMethodResponse,
+
+    /// The unique identifiers of the duplicates which were merged and deactivated
+    #[schema(value_type = Vec<String>)]
+    pub merged_payment_method_ids: Vec<id_type::GlobalPaymentMethodId>,
+
+    /// The number of mandates of the duplicates which now refer to the payment method
+    #[schema(example = 1)]
+    pub updated_mandates_count: usize,
+}
+
 #[cfg(feature = "v1")]
 #[derive(Debug, serde::Serialize, ToSchema)]
 pub struct CustomerDefaultPaymentMethodResponse {
@@ -2573,6 +2610,116 @@ impl From<PaymentMethodMigrationResponseType> for PaymentMethodMigrationResponse
     }
 }
 
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentMethodExportRequest {
+    /// The identifier of the approved public key of the recipient of the export. The export file
+    /// is encrypted with this key as a JWE, so that it can only be decrypted by the recipient
+    #[schema(example = "pm_export_key_q7uyXmGDbVNw1KotpSoj")]
+    pub recipient_key_id: String,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentMethodExportResponse {
+    /// The unique identifier of the payment method export
+    #[schema(example = "pm_export_q7uyXmGDbVNw1KotpSoj")]
+    pub export_id: String,
+
+    /// The identifier of the merchant whose payment methods are exported
+    #[schema(value_type = String, example = "merchant_1671528864")]
+    pub merchant_id: id_type::MerchantId,
+
+    /// The status of the payment method export
+    pub status: PaymentMethodExportStatus,
+
+    /// The number of payment methods exported so far, which is the number of payment methods in
+    /// the export once it is completed
+    #[schema(example = 120)]
+    pub payment_methods_count: Option<usize>,
+
+    /// The number of payment methods which were not exported as their details could not be
+    /// retrieved from the locker
+    #[schema(example = 0)]
+    pub skipped_payment_methods_count: Option<usize>,
+
+    /// The number of encrypted parts generated so far, which are numbered from 1. Each part
+    /// contains at most 100 payment methods and is downloaded separately.
+    #[schema(example = 2)]
+    pub parts_count: Option<usize>,
+
+    /// The time at which the payment method export was requested
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentMethodExportStatus {
+    /// The export file is being generated
+    Pending,
+    /// The export file is generated and can be downloaded
+    Completed,
+    /// The export file could not be generated
+    Failed,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentMethodExportRecipientKeyCreateRequest {
+    /// The name of the recipient of the exports, such as the processor the payment methods are
+    /// moved to
+    #[schema(example = "Acme Payments")]
+    pub recipient_name: String,
+
+    /// The PEM encoded RSA public key of the recipient of the exports
+    #[schema(example = "-----BEGIN PUBLIC KEY-----\n...\n-----END PUBLIC KEY-----")]
+    pub public_key: String,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentMethodExportRecipientKeyResponse {
+    /// The unique identifier of the recipient key, which is set as the `kid` header of the JWE
+    #[schema(example = "pm_export_key_q7uyXmGDbVNw1KotpSoj")]
+    pub key_id: String,
+
+    /// The identifier of the merchant the recipient key is registered for
+    #[schema(value_type = String, example = "merchant_1671528864")]
+    pub merchant_id: id_type::MerchantId,
+
+    /// The name of the recipient of the exports
+    #[schema(example = "Acme Payments")]
+    pub recipient_name: String,
+
+    /// The status of the recipient key. Exports can only be encrypted with approved keys
+    pub status: PaymentMethodExportRecipientKeyStatus,
+
+    /// The time at which the recipient key was registered
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+
+    /// The time at which the recipient key was approved
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub approved_at: Option<time::PrimitiveDateTime>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentMethodExportRecipientKeyStatus {
+    /// The recipient key is registered and waiting to be approved
+    PendingApproval,
+    /// The recipient key is approved and can be used for exports
+    Approved,
+}
+
 impl
     TryFrom<(
         PaymentMethodRecord,
@@ -911,13 +911,17 @@ pub struct PaymentsRequest {
     /// The billing details of the payment. This address will be used for invoicing.
     pub billing: Option<Address>,
 
-    /// A timestamp (ISO 8601 code) that determines when the payment should be captured.
-    /// Providing this field will automatically set `capture` to true
+    /// A timestamp (ISO 8601 code) at which the payment should be captured. Can only be provided along with the `scheduled` capture method, when the payment is being confirmed. The payment is authorized on confirmation and captured at this time. Cannot be combined with `capture_delay_in_seconds`.
     #[schema(example = "2022-09-10T10:11:12Z")]
     #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
-    #[remove_in(PaymentsUpdateRequest, PaymentsCreateRequest, PaymentsConfirmRequest)]
+    #[remove_in(PaymentsUpdateRequest)]
     pub capture_on: Option<PrimitiveDateTime>,
 
+    /// The delay in seconds after the confirmation of the payment at which the payment should be captured. Can only be provided along with the `scheduled` capture method, when the payment is being confirmed. Cannot be combined with `capture_on`.
+    #[schema(example = 86400)]
+    #[remove_in(PaymentsUpdateRequest)]
+    pub capture_delay_in_seconds: Option<u32>,
+
     /// If set to `true`, Hyperswitch attempts to confirm and authorize the payment immediately after creation, provided sufficient payment method details are included. If `false` or omitted (default is `false`), the payment is created with a status such as `requires_payment_method` or `requires_confirmation`, and a separate `POST /payments/{payment_id}/confirm` call is necessary to proceed with authorization.
     #[schema(default = false, example = true)]
     pub confirm: Option<bool>,
@@ -1043,6 +1047,16 @@ pub struct PaymentsRequest {
     #[schema(value_type = Option<PaymentMethodType>, example = "google_pay")]
     pub payment_method_type: Option<api_enums::PaymentMethodType>,
 
+    /// The installment plan in which the customer wants to pay for the payment. Can only be provided for card payments. The available plans for a card can be retrieved from `POST /payments/installment_plans`.
+    #[schema(value_type = Option<InstallmentPlan>)]
+    #[remove_in(PaymentsUpdateRequest)]
+    pub installment_plan: Option<common_types::payments::InstallmentPlan>,
+
+    /// The identifier of the currency conversion quote accepted by the customer, to pay in the currency of their card. Quotes can be requested from `POST /payments/{payment_id}/currency_conversion_quote` and can only be accepted with the card for which they were requested.
+    #[schema(value_type = Option<String>, example = "dcc_HJHb3vS3nAbW0c0v2Nku")]
+    #[remove_in(PaymentsUpdateRequest, PaymentsCreateRequest)]
+    pub currency_conversion_quote_id: Option<String>,
+
     /// Business country of the merchant for this payment.
     /// To be deprecated soon. Pass the profile_id instead
     #[schema(value_type = Option<CountryAlpha2>, example = "US")]
@@ -4429,7 +4443,7 @@ pub struct PaymentsCaptureRequest {
     /// The amount to capture, in the lowest denomination of the currency. If omitted, the entire `amount_capturable` of the payment will be captured. Must be less than or equal to the current `amount_capturable`.
     #[schema(value_type = Option<i64>, example = 6540)]
     pub amount_to_capture: Option<MinorUnit>,
-    /// Decider to refund the uncaptured amount. (Currently not fully supported or behavior may vary by connector).
+    /// Decider to release the uncaptured amount of the authorization after this capture. This is applicable only for `manual_multiple` captures, and for connectors which support releasing the uncaptured remainder of a partially captured authorization.
     pub refund_uncaptured_amount: Option<bool>,
     /// A dynamic suffix that appears on your customer's credit card statement. This is concatenated with the (shortened) descriptor prefix set on your account to form the complete statement descriptor. The combined length should not exceed connector-specific limits (typically 22 characters).
     pub statement_descriptor_suffix: Option<String>,
@@ -4905,8 +4919,7 @@ pub struct PaymentsResponse {
     #[schema(example = true)]
     pub off_session: Option<bool>,
 
-    /// A timestamp (ISO 8601 code) that determines when the payment should be captured.
-    /// Providing this field will automatically set `capture` to true
+    /// A timestamp (ISO 8601 code) at which the payment is scheduled to be captured, for payments with the `scheduled` capture method
     #[schema(example = "2022-09-10T10:11:12Z")]
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
     #[remove_in(PaymentsCreateResponseOpenApi)]
@@ -5162,6 +5175,26 @@ pub struct PaymentsResponse {
 
     /// Contains whole connector response
     pub whole_connector_response: Option<String>,
+
+    /// Status of the release of the uncaptured remainder of a partially captured authorization
+    #[schema(value_type = Option<AuthorizationReversalStatus>, example = "success")]
+    pub authorization_reversal_status: Option<common_enums::AuthorizationReversalStatus>,
+
+    /// The uncaptured amount (in minor units) of the authorization that was released by the connector
+    #[schema(value_type = Option<i64>, example = 1000)]
+    pub amount_reversed: Option<MinorUnit>,
+
+    /// The installment plan in which the customer pays for the payment
+    #[schema(value_type = Option<InstallmentPlan>)]
+    pub installment_plan: Option<common_types::payments::InstallmentPlan>,
+
+    /// The currency conversion accepted by the customer to pay in the currency of their card
+    #[schema(value_type = Option<CurrencyConversionDetails>)]
+    pub currency_conversion: Option<common_types::payments::CurrencyConversionDetails>,
+
+    /// The reason for which the payment was cancelled, from a fixed set of values
+    #[schema(value_type = Option<PaymentCancellationReason>, example = "requested_by_customer")]
+    pub cancellation_reason_code: Option<common_enums::PaymentCancellationReason>,
 }
 
 #[cfg(feature = "v2")]
@@ -7490,6 +7523,9 @@ pub struct PaymentsCancelRequest {
     pub payment_id: id_type::PaymentId,
     /// The reason for the payment cancel
     pub cancellation_reason: Option<String>,
+    /// The reason for the payment cancel, from a fixed set of values
+    #[schema(value_type = Option<PaymentCancellationReason>, example = "requested_by_customer")]
+    pub cancellation_reason_code: Option<common_enums::PaymentCancellationReason>,
     /// Merchant connector details used to make payments.
     #[schema(value_type = Option<MerchantConnectorDetailsWrap>, deprecated)]
     pub merchant_connector_details: Option<admin::MerchantConnectorDetailsWrap>,
@@ -8319,6 +8355,181 @@ pub struct ExtendedCardInfoResponse {
     pub payload: String,
 }
 
+/// Ledger of the amount movements of a payment
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentLedgerResponse {
+    /// The identifier for the payment
+    #[schema(value_type = String, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: id_type::PaymentId,
+    /// Entries recorded for the amount movements of the payment, in the order in which they were recorded
+    pub entries: Vec<PaymentLedgerEntryResponse>,
+    /// Balances of the payment, computed from its ledger entries
+    pub balances: PaymentLedgerBalances,
+    /// Balances of the ledger which do not match the amounts recorded on the payment, its refunds and its disputes
+    pub mismatches: Vec<PaymentLedgerMismatch>,
+}
+
+/// An immutable entry of the payment ledger, moving an amount from the credit account to the debit account
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentLedgerEntryResponse {
+    /// The identifier for the ledger entry
+    pub entry_id: String,
+    /// The identifier for the payment attempt under which the amount was moved
+    pub attempt_id: String,
+    /// The identifier for the attempt, capture, refund or dispute which moved the amount
+    pub reference_id: String,
+    /// The kind of amount movement
+    #[schema(value_type = LedgerEntryType, example = "capture")]
+    pub entry_type: api_enums::LedgerEntryType,
+    /// The account to which the amount was moved
+    #[schema(value_type = LedgerAccount, example = "merchant_balance")]
+    pub debit_account: api_enums::LedgerAccount,
+    /// The account from which the amount was moved
+    #[schema(value_type = LedgerAccount, example = "authorization_hold")]
+    pub credit_account: api_enums::LedgerAccount,
+    /// The amount moved, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+    /// The currency of the amount
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+    /// Time at which the entry was recorded
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+/// Balances of a payment computed from its ledger entries
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Default, serde::Serialize, ToSchema)]
+pub struct PaymentLedgerBalances {
+    /// Amount currently held on the payment method of the customer
+    #[schema(value_type = i64, example = 0)]
+    pub authorized_amount: MinorUnit,
+    /// Total amount captured for the merchant
+    #[schema(value_type = i64, example = 6540)]
+    pub captured_amount: MinorUnit,
+    /// Total amount refunded to the customer
+    #[schema(value_type = i64, example = 1000)]
+    pub refunded_amount: MinorUnit,
+    /// Amount currently withdrawn from the merchant through disputes
+    #[schema(value_type = i64, example = 0)]
+    pub charged_back_amount: MinorUnit,
+    /// Captured amount retained by the merchant after refunds and chargebacks
+    #[schema(value_type = i64, example = 5540)]
+    pub net_settled_amount: MinorUnit,
+}
+
+/// A balance of the payment ledger which does not match the amount recorded on the payment, its refunds or its disputes
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentLedgerMismatch {
+    /// The name of the mismatched balance
+    #[schema(example = "captured_amount")]
+    pub balance: String,
+    /// The balance computed from the ledger entries
+    #[schema(value_type = i64, example = 6540)]
+    pub ledger_amount: MinorUnit,
+    /// The amount recorded on the payment, its refunds or its disputes
+    #[schema(value_type = i64, example = 5000)]
+    pub expected_amount: MinorUnit,
+}
+
+/// Request to list the installment plans which can be offered for a card
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct InstallmentPlansRequest {
+    /// The first six to eight digits of the card number
+    #[schema(value_type = String, min_length = 6, max_length = 8, example = "424242")]
+    pub card_bin: Secret<String>,
+    /// The amount of the payment, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 120000)]
+    pub amount: MinorUnit,
+    /// The currency of the payment
+    #[schema(value_type = Currency, example = "BRL")]
+    pub currency: api_enums::Currency,
+    /// The identifier for the profile whose connectors are looked up. If not provided, the connectors of all the profiles of the merchant are looked up
+    #[schema(value_type = Option<String>, example = "pro_abcdefghijklmnop")]
+    pub profile_id: Option<id_type::ProfileId>,
+}
+
+/// Installment plans which can be offered for a card, per connector
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct InstallmentPlansResponse {
+    /// The issuing country of the card, if it could be determined from the card BIN
+    #[schema(value_type = Option<CountryAlpha2>, example = "BR")]
+    pub card_issuing_country: Option<api_enums::CountryAlpha2>,
+    /// The installment plans offered by each connector which has installments enabled for cards
+    pub connectors: Vec<ConnectorInstallmentPlans>,
+}
+
+/// Installment plans which can be offered for a card through a connector
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct ConnectorInstallmentPlans {
+    /// The name of the connector
+    #[schema(example = "adyen")]
+    pub connector: String,
+    /// The identifier for the merchant connector account
+    #[schema(value_type = String, example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub merchant_connector_id: id_type::MerchantConnectorAccountId,
+    /// The installment plans which can be requested for the payment
+    pub plans: Vec<InstallmentPlanOption>,
+}
+
+/// An installment plan which can be requested for a payment
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct InstallmentPlanOption {
+    /// Number of installments in which the payment amount is charged
+    #[schema(example = 6)]
+    pub count: u8,
+    /// Interval at which the installments are charged
+    #[schema(value_type = InstallmentInterval, example = "monthly")]
+    pub interval: api_enums::InstallmentInterval,
+    /// Whether the installments bear interest for the customer
+    pub is_interest_bearing: bool,
+    /// The amount of each installment, in the lowest denomination of the currency. Not provided for plans bearing interest, as the interest is applied by the issuer
+    #[schema(value_type = Option<i64>, example = 20000)]
+    pub installment_amount: Option<MinorUnit>,
+}
+
+/// Request to quote the conversion of the payment amount to the currency of a card
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CurrencyConversionQuoteRequest {
+    /// The unique identifier for the payment
+    #[serde(skip_deserializing)]
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    /// The first six to eight digits of the card number
+    #[schema(value_type = String, min_length = 6, max_length = 8, example = "424242")]
+    pub card_bin: Secret<String>,
+    /// It's a token used for client side verification.
+    #[schema(example = "pay_U42c409qyHwOkWo3vK60_secret_el9ksDkiB8hi6j9N78yo")]
+    pub client_secret: Option<String>,
+}
+
+/// A quote for paying the payment amount in the currency of the card
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct CurrencyConversionQuoteResponse {
+    /// The identifier for the payment
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    /// The amounts, currencies and exchange rate of the conversion offered to the customer
+    #[schema(value_type = CurrencyConversionDetails)]
+    pub quote: common_types::payments::CurrencyConversionDetails,
+    /// Time after which the quote can no longer be accepted
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:21:44Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: PrimitiveDateTime,
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, ToSchema)]
 pub struct ClickToPaySessionResponse {
     pub dpa_id: String,
@@ -1,6 +1,9 @@
 use std::fmt::Debug;
 
-use common_types::three_ds_decision_rule_engine::{ThreeDSDecision, ThreeDSDecisionRule};
+use common_types::{
+    connector_fees::{ConnectorFeeSchedule, FeeCalculationInput},
+    three_ds_decision_rule_engine::{ThreeDSDecision, ThreeDSDecisionRule},
+};
 use common_utils::{
     errors::{ParsingError, ValidationError},
     ext_traits::ValueExt,
@@ -29,7 +32,7 @@ const DEFAULT_HEDGING_PERCENT: f64 = 5.0;
 const DEFAULT_ELIMINATION_THRESHOLD: f64 = 0.35;
 const DEFAULT_PAYMENT_METHOD: &str = "CARD";
 
-#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq)]
 #[serde(tag = "type", content = "data", rename_all = "snake_case")]
 pub enum ConnectorSelection {
     Priority(Vec<RoutableConnectorChoice>),
@@ -65,6 +68,9 @@ pub struct RoutingConfigRequest {
     #[schema(value_type = Option<String>)]
     pub profile_id: Option<common_utils::id_type::ProfileId>,
     pub transaction_type: Option<TransactionType>,
+    /// The algorithm this algorithm is a new version of
+    #[schema(value_type = Option<String>)]
+    pub parent_algorithm_id: Option<common_utils::id_type::RoutingId>,
 }
 
 #[derive(Debug, serde::Serialize, ToSchema)]
@@ -146,6 +152,7 @@ impl EuclidDirFilter for ConnectorSelection {
         DirKeyKind::PaymentType,
         DirKeyKind::SetupFutureUsage,
         DirKeyKind::CaptureMethod,
+        DirKeyKind::InstallmentInterval,
         DirKeyKind::BillingCountry,
         DirKeyKind::BusinessCountry,
         DirKeyKind::BusinessLabel,
@@ -155,6 +162,10 @@ impl EuclidDirFilter for ConnectorSelection {
         DirKeyKind::CardRedirectType,
         DirKeyKind::BankTransferType,
         DirKeyKind::RealTimePaymentType,
+        DirKeyKind::PaymentHourOfDay,
+        DirKeyKind::PaymentDayOfWeek,
+        DirKeyKind::PaymentDate,
+        DirKeyKind::PaymentHolidayCalendar,
     ];
 }
 
@@ -183,6 +194,15 @@ impl EuclidAnalysable for ConnectorSelection {
             })
             .collect()
     }
+
+    fn get_volume_split_total(&self) -> Option<u64> {
+        match self {
+            Self::Priority(_) => None,
+            Self::VolumeSplit(splits) => {
+                Some(splits.iter().map(|split| u64::from(split.split)).sum())
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema, PartialEq)]
@@ -278,6 +298,82 @@ impl From<RoutableConnectorChoice> for RoutableChoiceSerde {
     }
 }
 
+impl From<ast::RoutableChoiceKind> for RoutableChoiceKind {
+    fn from(value: ast::RoutableChoiceKind) -> Self {
+        match value {
+            ast::RoutableChoiceKind::OnlyConnector => Self::OnlyConnector,
+            ast::RoutableChoiceKind::FullStruct => Self::FullStruct,
+        }
+    }
+}
+
+impl From<RoutableChoiceKind> for ast::RoutableChoiceKind {
+    fn from(value: RoutableChoiceKind) -> Self {
+        match value {
+            RoutableChoiceKind::OnlyConnector => Self::OnlyConnector,
+            RoutableChoiceKind::FullStruct => Self::FullStruct,
+        }
+    }
+}
+
+impl From<ast::RoutableConnectorChoice> for RoutableConnectorChoice {
+    fn from(value: ast::RoutableConnectorChoice) -> Self {
+        Self {
+            choice_kind: value.choice_kind.into(),
+            connector: value.connector,
+            merchant_connector_id: value.merchant_connector_id,
+        }
+    }
+}
+
+impl From<RoutableConnectorChoice> for ast::RoutableConnectorChoice {
+    fn from(value: RoutableConnectorChoice) -> Self {
+        Self {
+            choice_kind: value.choice_kind.into(),
+            connector: value.connector,
+            merchant_connector_id: value.merchant_connector_id,
+        }
+    }
+}
+
+impl From<ast::ConnectorSelection> for ConnectorSelection {
+    fn from(value: ast::ConnectorSelection) -> Self {
+        match value {
+            ast::ConnectorSelection::Priority(choices) => {
+                Self::Priority(choices.into_iter().map(Into::into).collect())
+            }
+            ast::ConnectorSelection::VolumeSplit(splits) => Self::VolumeSplit(
+                splits
+                    .into_iter()
+                    .map(|split| ConnectorVolumeSplit {
+                        connector: split.connector.into(),
+                        split: split.split,
+                    })
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl From<ConnectorSelection> for ast::ConnectorSelection {
+    fn from(value: ConnectorSelection) -> Self {
+        match value {
+            ConnectorSelection::Priority(choices) => {
+                Self::Priority(choices.into_iter().map(Into::into).collect())
+            }
+            ConnectorSelection::VolumeSplit(splits) => Self::VolumeSplit(
+                splits
+                    .into_iter()
+                    .map(|split| ast::ConnectorVolumeSplit {
+                        connector: split.connector.into(),
+                        split: split.split,
+                    })
+                    .collect(),
+            ),
+        }
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
 pub struct RoutableConnectorChoiceWithStatus {
     pub routable_connector_choice: RoutableConnectorChoice,
@@ -305,6 +401,7 @@ pub enum RoutingAlgorithmKind {
     Advanced,
     Dynamic,
     ThreeDsDecisionRule,
+    LeastCost,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -342,6 +439,9 @@ pub enum StaticRoutingAlgorithm {
     Advanced(Program<ConnectorSelection>),
     #[schema(value_type=ProgramThreeDsDecisionRule)]
     ThreeDsDecisionRule(Program<ThreeDSDecisionRule>),
+    /// Connectors ranked by the processing fee expected for the payment, computed from the fee
+    /// schedules of their merchant connector accounts
+    LeastCost(Vec<RoutableConnectorChoice>),
 }
 
 #[derive(Serialize, Deserialize, ToSchema)]
@@ -366,7 +466,11 @@ pub struct RuleThreeDsDecisionRule {
 impl StaticRoutingAlgorithm {
     pub fn should_validate_connectors_in_routing_config(&self) -> bool {
         match self {
-            Self::Single(_) | Self::Priority(_) | Self::VolumeSplit(_) | Self::Advanced(_) => true,
+            Self::Single(_)
+            | Self::Priority(_)
+            | Self::VolumeSplit(_)
+            | Self::Advanced(_)
+            | Self::LeastCost(_) => true,
             Self::ThreeDsDecisionRule(_) => false,
         }
     }
@@ -380,6 +484,7 @@ pub enum RoutingAlgorithmSerde {
     VolumeSplit(Vec<ConnectorVolumeSplit>),
     Advanced(Program<ConnectorSelection>),
     ThreeDsDecisionRule(Program<ThreeDSDecisionRule>),
+    LeastCost(Vec<RoutableConnectorChoice>),
 }
 
 impl TryFrom<RoutingAlgorithmSerde> for StaticRoutingAlgorithm {
@@ -397,6 +502,19 @@ impl TryFrom<RoutingAlgorithmSerde> for StaticRoutingAlgorithm {
                     "Connectors list can't be empty for Volume split Algorithm",
                 ))?
             }
+            RoutingAlgorithmSerde::LeastCost(i) if i.is_empty() => {
+                Err(ParsingError::StructParseFailure(
+                    "Connectors list can't be empty for Least cost Algorithm",
+                ))?
+            }
+            RoutingAlgorithmSerde::LeastCost(i)
+                if i.iter()
+                    .any(|choice| choice.merchant_connector_id.is_none()) =>
+            {
+                Err(ParsingError::StructParseFailure(
+                    "Merchant connector id is required for every connector of Least cost Algorithm",
+                ))?
+            }
             _ => {}
         };
         Ok(match value {
@@ -405,6 +523,7 @@ impl TryFrom<RoutingAlgorithmSerde> for StaticRoutingAlgorithm {
             RoutingAlgorithmSerde::VolumeSplit(i) => Self::VolumeSplit(i),
             RoutingAlgorithmSerde::Advanced(i) => Self::Advanced(i),
             RoutingAlgorithmSerde::ThreeDsDecisionRule(i) => Self::ThreeDsDecisionRule(i),
+            RoutingAlgorithmSerde::LeastCost(i) => Self::LeastCost(i),
         })
     }
 }
@@ -508,21 +627,65 @@ impl StaticRoutingAlgorithm {
             Self::VolumeSplit(_) => RoutingAlgorithmKind::VolumeSplit,
             Self::Advanced(_) => RoutingAlgorithmKind::Advanced,
             Self::ThreeDsDecisionRule(_) => RoutingAlgorithmKind::ThreeDsDecisionRule,
+            Self::LeastCost(_) => RoutingAlgorithmKind::LeastCost,
         }
     }
 }
 
+/// Orders the connectors of a least cost algorithm by the fee expected for the payment, cheapest
+/// first. Connectors whose fee cannot be estimated, as their merchant connector account has no
+/// fee schedule for the currency of the payment, follow in their configured order.
+pub fn sort_connectors_by_expected_fee(
+    connectors: &[RoutableConnectorChoice],
+    fee_schedules: &std::collections::HashMap<
+        common_utils::id_type::MerchantConnectorAccountId,
+        ConnectorFeeSchedule,
+    >,
+    fee_input: &FeeCalculationInput,
+) -> Vec<RoutableConnectorChoice> {
+    let mut connectors_with_fee = connectors
+        .iter()
+        .map(|choice| {
+            let expected_fee = choice
+                .merchant_connector_id
+                .as_ref()
+                .and_then(|mca_id| fee_schedules.get(mca_id))
+                .and_then(|fee_schedule| fee_schedule.get_expected_fee(fee_input))
+                .map(common_utils::types::MinorUnit::get_amount_as_i64);
+            (choice, expected_fee)
+        })
+        .collect::<Vec<_>>();
+
+    connectors_with_fee.sort_by_key(|(_, expected_fee)| (expected_fee.is_none(), *expected_fee));
+
+    connectors_with_fee
+        .into_iter()
+        .map(|(choice, _)| choice.clone())
+        .collect()
+}
+
 #[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
 pub struct RoutingAlgorithmRef {
     pub algorithm_id: Option<common_utils::id_type::RoutingId>,
     pub timestamp: i64,
     pub config_algo_id: Option<String>,
     pub surcharge_config_algo_id: Option<String>,
+    /// Algorithm evaluated alongside the active one for comparison, without affecting the routing decision
+    pub shadow_algorithm_id: Option<common_utils::id_type::RoutingId>,
+    /// Algorithm that was active before the current one, used to roll back an activation
+    pub previous_algorithm_id: Option<common_utils::id_type::RoutingId>,
 }
 
 impl RoutingAlgorithmRef {
     pub fn update_algorithm_id(&mut self, new_id: common_utils::id_type::RoutingId) {
-        self.algorithm_id = Some(new_id);
+        if let Some(current_id) = self.algorithm_id.replace(new_id) {
+            self.previous_algorithm_id = Some(current_id);
+        }
+        self.timestamp = common_utils::date_time::now_unix_timestamp();
+    }
+
+    pub fn update_shadow_algorithm_id(&mut self, new_id: Option<common_utils::id_type::RoutingId>) {
+        self.shadow_algorithm_id = new_id;
         self.timestamp = common_utils::date_time::now_unix_timestamp();
     }
 
@@ -558,6 +721,58 @@ pub struct RoutingDictionaryRecord {
     pub modified_at: i64,
     pub algorithm_for: Option<TransactionType>,
     pub decision_engine_routing_id: Option<String>,
+    /// The algorithm this algorithm was derived from
+    #[schema(value_type = Option<String>)]
+    pub parent_algorithm_id: Option<common_utils::id_type::RoutingId>,
+    /// Likely misconfigurations found by analysing the algorithm, returned on creation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warnings: Option<Vec<RoutingAnalysisWarning>>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RoutingAnalysisWarningType {
+    /// The rule is never selected as earlier rules match all of its payments
+    UnreachableRule,
+    /// A statement of the rule is never matched as earlier rules match all of its payments
+    ShadowedStatement,
+    /// The connector is not enabled for the profile
+    UnavailableConnector,
+    /// The volume split percentages do not add up to 100
+    InvalidVolumeSplit,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingAnalysisWarning {
+    pub warning_type: RoutingAnalysisWarningType,
+    /// Name of the rule the warning was raised for, absent for the default selection
+    pub rule_name: Option<String>,
+    pub message: String,
+}
+
+impl From<euclid::dssa::types::AnalysisWarning> for RoutingAnalysisWarning {
+    fn from(warning: euclid::dssa::types::AnalysisWarning) -> Self {
+        use euclid::dssa::types::AnalysisWarning;
+
+        let warning_type = match &warning {
+            AnalysisWarning::UnreachableRule { .. } => RoutingAnalysisWarningType::UnreachableRule,
+            AnalysisWarning::ShadowedStatement { .. } => {
+                RoutingAnalysisWarningType::ShadowedStatement
+            }
+            AnalysisWarning::UnavailableConnector { .. } => {
+                RoutingAnalysisWarningType::UnavailableConnector
+            }
+            AnalysisWarning::InvalidVolumeSplit { .. } => {
+                RoutingAnalysisWarningType::InvalidVolumeSplit
+            }
+        };
+
+        Self {
+            warning_type,
+            rule_name: warning.get_rule_name().map(ToString::to_string),
+            message: warning.to_string(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -1533,3 +1748,806 @@ pub enum ContractUpdationStatusEventResponse {
     ContractUpdationSucceeded,
     ContractUpdationFailed,
 }
+
+/// Request to replay recent payments of a profile through a draft routing algorithm
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingSimulationRequest {
+    /// The profile whose recent payments are replayed
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    /// The draft algorithm to evaluate the payments against
+    pub algorithm: StaticRoutingAlgorithm,
+    /// Number of most recent payments to replay, capped at the maximum allowed limit
+    #[schema(example = 100)]
+    pub limit: Option<u32>,
+}
+
+/// Report of how payments would have been distributed by a draft routing algorithm
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingSimulationResponse {
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    /// Number of payments that were evaluated by the draft algorithm
+    pub evaluated_payments: u32,
+    /// Number of payments that could not be evaluated, for example when the currency is missing
+    pub skipped_payments: u32,
+    /// Number of evaluated payments for which the draft algorithm chose a different connector
+    pub rerouted_payments: u32,
+    /// Distribution of the evaluated payments across connectors as they were actually routed
+    pub current_distribution: Vec<ConnectorSimulationStats>,
+    /// Distribution of the evaluated payments across connectors as chosen by the draft algorithm
+    pub simulated_distribution: Vec<ConnectorSimulationStats>,
+    /// Observed success rate of the evaluated payments
+    pub current_success_rate: Option<f64>,
+    /// Success rate expected with the draft algorithm, based on the observed success rate of each connector
+    pub expected_success_rate: Option<f64>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ConnectorSimulationStats {
+    /// The connector the payments are routed to
+    pub connector: String,
+    /// Number of payments routed to the connector
+    pub payment_count: u32,
+    /// Share of the evaluated payments routed to the connector, in percent
+    pub payment_share: f64,
+    /// Total amount routed to the connector, per currency
+    #[schema(value_type = HashMap<Currency, i64>)]
+    pub volume: std::collections::HashMap<common_enums::Currency, common_utils::types::MinorUnit>,
+    /// Observed success rate of the connector among the evaluated payments
+    pub success_rate: Option<f64>,
+}
+
+/// Smallest UTC offset of a merchant's timezone, in minutes
+pub const MIN_ROUTING_UTC_OFFSET_MINUTES: i16 = -12 * 60;
+/// Largest UTC offset of a merchant's timezone, in minutes
+pub const MAX_ROUTING_UTC_OFFSET_MINUTES: i16 = 14 * 60;
+
+/// Configuration used to resolve the payment time keys of the routing DSL for a profile.
+/// Profiles without this configuration have their payment time keys resolved in UTC.
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(default)]
+pub struct RoutingTemporalConfig {
+    /// Offset of the merchant's timezone from UTC, in minutes
+    #[schema(example = 330, minimum = -720, maximum = 840)]
+    pub utc_offset_minutes: i16,
+    /// Holiday calendars keyed by their name, each holding dates in the YYYYMMDD format
+    #[schema(value_type = HashMap<String, Vec<u32>>, example = json!({"india": [20241225, 20250126]}))]
+    pub holiday_calendars: std::collections::HashMap<String, Vec<u32>>,
+}
+
+impl RoutingTemporalConfig {
+    /// Returns the names of the holiday calendars which contain the given YYYYMMDD date, sorted
+    /// so that the order does not depend on the order of the configuration
+    pub fn get_holiday_calendars_for_date(&self, date: u32) -> Vec<String> {
+        let mut holiday_calendars = self
+            .holiday_calendars
+            .iter()
+            .filter(|(_, dates)| dates.contains(&date))
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<_>>();
+        holiday_calendars.sort();
+        holiday_calendars
+    }
+
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if !(MIN_ROUTING_UTC_OFFSET_MINUTES..=MAX_ROUTING_UTC_OFFSET_MINUTES)
+            .contains(&self.utc_offset_minutes)
+        {
+            return Err(ValidationError::InvalidValue {
+                message: format!(
+                    "utc_offset_minutes must be between {MIN_ROUTING_UTC_OFFSET_MINUTES} and {MAX_ROUTING_UTC_OFFSET_MINUTES}"
+                ),
+            });
+        }
+
+        for (name, dates) in &self.holiday_calendars {
+            if name.trim().is_empty() {
+                return Err(ValidationError::InvalidValue {
+                    message: "Holiday calendar names must not be empty".to_string(),
+                });
+            }
+
+            if let Some(date) = dates.iter().find(|date| !is_valid_calendar_date(**date)) {
+                return Err(ValidationError::InvalidValue {
+                    message: format!(
+                        "Holiday calendar '{name}' has the invalid date {date}, dates must be in the YYYYMMDD format"
+                    ),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks whether a YYYYMMDD date is a date of the calendar
+fn is_valid_calendar_date(date: u32) -> bool {
+    if !(10_000_000..=99_999_999).contains(&date) {
+        return false;
+    }
+
+    let year = i32::try_from(date / 10000).ok();
+    let month = u8::try_from(date / 100 % 100)
+        .ok()
+        .and_then(|month| time::Month::try_from(month).ok());
+    let day = u8::try_from(date % 100).ok();
+
+    match (year, month, day) {
+        (Some(year), Some(month), Some(day)) => {
+            time::Date::from_calendar_date(year, month, day).is_ok()
+        }
+        _ => false,
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RoutingTemporalConfigPath {
+    pub profile_id: common_utils::id_type::ProfileId,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RoutingTemporalConfigWrapper {
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub config: RoutingTemporalConfig,
+}
+
+/// Payment time configuration of a profile
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingTemporalConfigResponse {
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub config: RoutingTemporalConfig,
+}
+
+/// Request to parse a routing program written in the textual routing DSL
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingDslParseRequest {
+    /// The routing program in its textual representation
+    #[schema(example = "default: [stripe]\n\nrule_1: [adyen]\n{\n    amount >= 1000\n}\n")]
+    pub dsl: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingDslParseResponse {
+    /// The parsed routing program, as accepted by the advanced routing algorithm
+    #[schema(value_type = ProgramConnectorSelection)]
+    pub program: Program<ConnectorSelection>,
+}
+
+/// Request to print a routing program in the textual routing DSL
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingDslPrintRequest {
+    #[schema(value_type = ProgramConnectorSelection)]
+    pub program: Program<ConnectorSelection>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingDslPrintResponse {
+    /// The routing program in its textual representation
+    pub dsl: String,
+}
+
+/// Query to compare a routing algorithm with another version of it
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RoutingAlgorithmDiffQuery {
+    /// The algorithm to compare with, defaults to the parent of the routing algorithm
+    pub base_algorithm_id: Option<common_utils::id_type::RoutingId>,
+}
+
+/// Structural difference between two versions of a routing algorithm
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingAlgorithmDiffResponse {
+    #[schema(value_type = String)]
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    /// The algorithm the changes are relative to
+    #[schema(value_type = String)]
+    pub base_algorithm_id: common_utils::id_type::RoutingId,
+    pub kind: RoutingAlgorithmKind,
+    pub base_kind: RoutingAlgorithmKind,
+    pub changes: RoutingAlgorithmChanges,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingAlgorithmChanges {
+    /// Whether the connectors chosen when no rule matches have changed
+    pub default_selection_changed: bool,
+    /// Names of the rules present only in the newer version
+    pub rules_added: Vec<String>,
+    /// Names of the rules present only in the older version
+    pub rules_removed: Vec<String>,
+    /// Rules present in both versions which have changed
+    pub rules_changed: Vec<RoutingRuleChange>,
+    /// Volume split weights which have changed, for the default selection and for the rules
+    /// present in both versions
+    pub connector_weight_changes: Vec<ConnectorWeightChange>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingRuleChange {
+    pub name: String,
+    pub conditions_changed: bool,
+    pub connector_selection_changed: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ConnectorWeightChange {
+    /// The rule whose volume split has changed, absent for the default selection
+    pub rule_name: Option<String>,
+    /// The connector, along with its merchant connector id if present
+    pub connector: String,
+    /// Percentage of the traffic routed to the connector in the older version
+    pub base_split: Option<u8>,
+    /// Percentage of the traffic routed to the connector in the newer version
+    pub split: Option<u8>,
+}
+
+impl StaticRoutingAlgorithm {
+    /// Returns the changes made in this algorithm relative to `base`
+    pub fn get_changes_from(&self, base: &Self) -> RoutingAlgorithmChanges {
+        match (base, self) {
+            (Self::ThreeDsDecisionRule(base), Self::ThreeDsDecisionRule(program)) => {
+                get_program_changes(base, program, |_, _, _| Vec::new())
+            }
+            (Self::ThreeDsDecisionRule(_), _) | (_, Self::ThreeDsDecisionRule(_)) => {
+                RoutingAlgorithmChanges {
+                    default_selection_changed: true,
+                    ..Default::default()
+                }
+            }
+            _ => get_program_changes(
+                &base.to_connector_selection_program(),
+                &self.to_connector_selection_program(),
+                get_connector_weight_changes,
+            ),
+        }
+    }
+
+    /// Represents the connector selection algorithms as a program without rules, so that
+    /// algorithms of different kinds can be compared and analysed alike
+    pub fn to_connector_selection_program(&self) -> Program<ConnectorSelection> {
+        let default_selection = match self {
+            Self::Single(connector) => ConnectorSelection::Priority(vec![*connector.clone()]),
+            Self::Priority(connectors) | Self::LeastCost(connectors) => {
+                ConnectorSelection::Priority(connectors.clone())
+            }
+            Self::VolumeSplit(splits) => ConnectorSelection::VolumeSplit(splits.clone()),
+            Self::Advanced(program) => return program.clone(),
+            Self::ThreeDsDecisionRule(_) => ConnectorSelection::Priority(Vec::new()),
+        };
+
+        Program {
+            default_selection,
+            rules: Vec::new(),
+            metadata: std::collections::HashMap::new(),
+        }
+    }
+}
+
+fn get_program_changes<O: PartialEq>(
+    base: &Program<O>,
+    program: &Program<O>,
+    get_weight_changes: impl Fn(Option<&str>, &O, &O) -> Vec<ConnectorWeightChange>,
+) -> RoutingAlgorithmChanges {
+    let mut changes = RoutingAlgorithmChanges {
+        default_selection_changed: base.default_selection != program.default_selection,
+        connector_weight_changes: get_weight_changes(
+            None,
+            &base.default_selection,
+            &program.default_selection,
+        ),
+        ..Default::default()
+    };
+
+    for rule in &program.rules {
+        match base
+            .rules
+            .iter()
+            .find(|base_rule| base_rule.name == rule.name)
+        {
+            Some(base_rule) => {
+                let conditions_changed = serde_json::to_value(&base_rule.statements).ok()
+                    != serde_json::to_value(&rule.statements).ok();
+                let connector_selection_changed =
+                    base_rule.connector_selection != rule.connector_selection;
+
+                if conditions_changed || connector_selection_changed {
+                    changes.rules_changed.push(RoutingRuleChange {
+                        name: rule.name.clone(),
+                        conditions_changed,
+                        connector_selection_changed,
+                    });
+                }
+                changes.connector_weight_changes.extend(get_weight_changes(
+                    Some(&rule.name),
+                    &base_rule.connector_selection,
+                    &rule.connector_selection,
+                ));
+            }
+            None => changes.rules_added.push(rule.name.clone()),
+        }
+    }
+
+    changes.rules_removed = base
+        .rules
+        .iter()
+        .filter(|base_rule| !program.rules.iter().any(|rule| rule.name == base_rule.name))
+        .map(|base_rule| base_rule.name.clone())
+        .collect();
+
+    changes
+}
+
+fn get_connector_weight_changes(
+    rule_name: Option<&str>,
+    base: &ConnectorSelection,
+    selection: &ConnectorSelection,
+) -> Vec<ConnectorWeightChange> {
+    let get_splits = |selection: &ConnectorSelection| match selection {
+        ConnectorSelection::VolumeSplit(splits) => splits
+            .iter()
+            .map(|split| (split.connector.to_string(), split.split))
+            .collect::<Vec<_>>(),
+        ConnectorSelection::Priority(_) => Vec::new(),
+    };
+    let base_splits = get_splits(base);
+    let splits = get_splits(selection);
+
+    let find_split = |splits: &[(String, u8)], connector: &str| {
+        splits
+            .iter()
+            .find(|(split_connector, _)| split_connector == connector)
+            .map(|(_, split)| *split)
+    };
+
+    let updated = splits.iter().filter_map(|(connector, split)| {
+        let base_split = find_split(&base_splits, connector);
+        (base_split != Some(*split)).then(|| ConnectorWeightChange {
+            rule_name: rule_name.map(ToString::to_string),
+            connector: connector.clone(),
+            base_split,
+            split: Some(*split),
+        })
+    });
+    let removed = base_splits.iter().filter_map(|(connector, base_split)| {
+        find_split(&splits, connector)
+            .is_none()
+            .then(|| ConnectorWeightChange {
+                rule_name: rule_name.map(ToString::to_string),
+                connector: connector.clone(),
+                base_split: Some(*base_split),
+                split: None,
+            })
+    });
+
+    updated.chain(removed).collect()
+}
+
+/// Request to activate the routing algorithm that was active before the current one
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingRollbackRequest {
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub transaction_type: Option<TransactionType>,
+}
+
+/// Request to activate a routing algorithm at a future time
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingActivationScheduleRequest {
+    /// Time at which the routing algorithm is activated
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub activate_at: time::PrimitiveDateTime,
+    pub transaction_type: Option<TransactionType>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingActivationScheduleResponse {
+    /// Identifier of the scheduled activation, used to cancel it
+    pub activation_id: String,
+    #[schema(value_type = String)]
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub transaction_type: TransactionType,
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub activate_at: time::PrimitiveDateTime,
+}
+
+/// Query to list the pending scheduled activations of the routing algorithms of a profile
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RoutingActivationScheduleListQuery {
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub transaction_type: Option<TransactionType>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingEligibilityExplanationRequest {
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    /// Payment whose details are used as the context, takes precedence over `context`
+    #[schema(value_type = Option<String>)]
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+    /// Synthetic routing input used as the context when no payment is given
+    #[schema(value_type = Option<Object>)]
+    pub context: Option<euclid::backend::inputs::BackendInput>,
+    pub transaction_type: Option<TransactionType>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ConstraintGraphExplanation {
+    pub message: String,
+    pub causes: Vec<ConstraintGraphExplanation>,
+}
+
+impl From<euclid::dssa::graph::TraceExplanation> for ConstraintGraphExplanation {
+    fn from(explanation: euclid::dssa::graph::TraceExplanation) -> Self {
+        Self {
+            message: explanation.message,
+            causes: explanation.causes.into_iter().map(Self::from).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ConnectorEligibilityExplanation {
+    pub connector: RoutableConnectorChoice,
+    pub eligible: bool,
+    /// Reason the connector is ineligible for the given context
+    pub explanation: Option<ConstraintGraphExplanation>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct PaymentMethodEligibilityExplanation {
+    pub connector: String,
+    #[schema(value_type = String)]
+    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    #[schema(value_type = PaymentMethod)]
+    pub payment_method: common_enums::PaymentMethod,
+    #[schema(value_type = PaymentMethodType)]
+    pub payment_method_type: common_enums::PaymentMethodType,
+    pub eligible: bool,
+    /// Reason the payment method type is filtered out of the payment methods list
+    pub explanation: Option<ConstraintGraphExplanation>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingEligibilityExplanationResponse {
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub connectors: Vec<ConnectorEligibilityExplanation>,
+    pub payment_methods: Vec<PaymentMethodEligibilityExplanation>,
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use common_types::connector_fees::CurrencyFeeSchedule;
+
+    use super::*;
+
+    fn volume_split(splits: &[(RoutableConnectors, u8)]) -> ConnectorSelection {
+        ConnectorSelection::VolumeSplit(
+            splits
+                .iter()
+                .map(|(connector, split)| ConnectorVolumeSplit {
+                    connector: RoutableConnectorChoice {
+                        choice_kind: RoutableChoiceKind::FullStruct,
+                        connector: *connector,
+                        merchant_connector_id: None,
+                    },
+                    split: *split,
+                })
+                .collect(),
+        )
+    }
+
+    fn rule(
+        name: &str,
+        selection: ConnectorSelection,
+        amount: i64,
+    ) -> ast::Rule<ConnectorSelection> {
+        ast::Rule {
+            name: name.to_string(),
+            connector_selection: selection,
+            statements: vec![ast::IfStatement {
+                condition: vec![ast::Comparison {
+                    lhs: "amount".to_string(),
+                    comparison: ast::ComparisonType::GreaterThan,
+                    value: ast::ValueType::Number(common_utils::types::MinorUnit::new(amount)),
+                    metadata: std::collections::HashMap::new(),
+                }],
+                nested: None,
+            }],
+        }
+    }
+
+    fn advanced(rules: Vec<ast::Rule<ConnectorSelection>>) -> StaticRoutingAlgorithm {
+        StaticRoutingAlgorithm::Advanced(Program {
+            default_selection: volume_split(&[(RoutableConnectors::Stripe, 100)]),
+            rules,
+            metadata: std::collections::HashMap::new(),
+        })
+    }
+
+    #[test]
+    fn test_routing_temporal_config_validation() {
+        let config = RoutingTemporalConfig {
+            utc_offset_minutes: 330,
+            holiday_calendars: std::collections::HashMap::from([
+                ("india".to_string(), vec![20241225, 20240229]),
+                ("us".to_string(), vec![20241225]),
+            ]),
+        };
+        assert!(config.validate().is_ok());
+        assert_eq!(
+            config.get_holiday_calendars_for_date(20241225),
+            vec!["india".to_string(), "us".to_string()]
+        );
+
+        let out_of_range_offset = RoutingTemporalConfig {
+            utc_offset_minutes: MAX_ROUTING_UTC_OFFSET_MINUTES + 1,
+            ..Default::default()
+        };
+        assert!(out_of_range_offset.validate().is_err());
+
+        for invalid_date in [20230229, 20241301, 20241200, 241225] {
+            let config = RoutingTemporalConfig {
+                utc_offset_minutes: 0,
+                holiday_calendars: std::collections::HashMap::from([(
+                    "india".to_string(),
+                    vec![invalid_date],
+                )]),
+            };
+            assert!(
+                config.validate().is_err(),
+                "{invalid_date} should be invalid"
+            );
+        }
+    }
+
+    #[test]
+    fn test_advanced_algorithm_changes() {
+        let base = advanced(vec![
+            rule(
+                "high_value",
+                volume_split(&[
+                    (RoutableConnectors::Stripe, 50),
+                    (RoutableConnectors::Adyen, 50),
+                ]),
+                1000,
+            ),
+            rule(
+                "low_value",
+                volume_split(&[(RoutableConnectors::Stripe, 100)]),
+                10,
+            ),
+            rule(
+                "legacy",
+                volume_split(&[(RoutableConnectors::Adyen, 100)]),
+                5,
+            ),
+        ]);
+        let algorithm = advanced(vec![
+            rule(
+                "high_value",
+                volume_split(&[
+                    (RoutableConnectors::Stripe, 70),
+                    (RoutableConnectors::Checkout, 30),
+                ]),
+                1000,
+            ),
+            rule(
+                "low_value",
+                volume_split(&[(RoutableConnectors::Stripe, 100)]),
+                20,
+            ),
+            rule(
+                "new_rule",
+                volume_split(&[(RoutableConnectors::Adyen, 100)]),
+                5,
+            ),
+        ]);
+
+        let changes = algorithm.get_changes_from(&base);
+
+        assert!(!changes.default_selection_changed);
+        assert_eq!(changes.rules_added, vec!["new_rule".to_string()]);
+        assert_eq!(changes.rules_removed, vec!["legacy".to_string()]);
+        assert_eq!(
+            changes.rules_changed,
+            vec![
+                RoutingRuleChange {
+                    name: "high_value".to_string(),
+                    conditions_changed: false,
+                    connector_selection_changed: true,
+                },
+                RoutingRuleChange {
+                    name: "low_value".to_string(),
+                    conditions_changed: true,
+                    connector_selection_changed: false,
+                },
+            ]
+        );
+        assert_eq!(
+            changes.connector_weight_changes,
+            vec![
+                ConnectorWeightChange {
+                    rule_name: Some("high_value".to_string()),
+                    connector: "stripe".to_string(),
+                    base_split: Some(50),
+                    split: Some(70),
+                },
+                ConnectorWeightChange {
+                    rule_name: Some("high_value".to_string()),
+                    connector: "checkout".to_string(),
+                    base_split: None,
+                    split: Some(30),
+                },
+                ConnectorWeightChange {
+                    rule_name: Some("high_value".to_string()),
+                    connector: "adyen".to_string(),
+                    base_split: Some(50),
+                    split: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_changes_across_algorithm_kinds() {
+        let base = StaticRoutingAlgorithm::Priority(vec![RoutableConnectorChoice {
+            choice_kind: RoutableChoiceKind::FullStruct,
+            connector: RoutableConnectors::Stripe,
+            merchant_connector_id: None,
+        }]);
+        let algorithm = advanced(vec![rule(
+            "high_value",
+            volume_split(&[(RoutableConnectors::Adyen, 100)]),
+            1000,
+        )]);
+
+        let changes = algorithm.get_changes_from(&base);
+
+        assert!(changes.default_selection_changed);
+        assert_eq!(changes.rules_added, vec!["high_value".to_string()]);
+        assert!(changes.rules_removed.is_empty());
+        assert_eq!(
+            changes.connector_weight_changes,
+            vec![ConnectorWeightChange {
+                rule_name: None,
+                connector: "stripe".to_string(),
+                base_split: None,
+                split: Some(100),
+            }]
+        );
+    }
+
+    fn least_cost_choice(connector: RoutableConnectors, mca_id: &str) -> RoutableConnectorChoice {
+        RoutableConnectorChoice {
+            choice_kind: RoutableChoiceKind::FullStruct,
+            connector,
+            merchant_connector_id: Some(
+                common_utils::id_type::MerchantConnectorAccountId::wrap(mca_id.to_string())
+                    .unwrap(),
+            ),
+        }
+    }
+
+    fn fee_schedule(fixed_fee: i64, percentage_fee_bps: u16) -> CurrencyFeeSchedule {
+        CurrencyFeeSchedule {
+            currency: common_enums::Currency::USD,
+            fixed_fee: common_utils::types::MinorUnit::new(fixed_fee),
+            percentage_fee_bps,
+            card_network_fees: Vec::new(),
+            card_type_fees: Vec::new(),
+            cross_border_fee: None,
+        }
+    }
+
+    #[test]
+    fn test_sort_connectors_by_expected_fee() {
+        let stripe = least_cost_choice(RoutableConnectors::Stripe, "mca_stripe");
+        let adyen = least_cost_choice(RoutableConnectors::Adyen, "mca_adyen");
+        let checkout = least_cost_choice(RoutableConnectors::Checkout, "mca_checkout");
+        let cybersource = least_cost_choice(RoutableConnectors::Cybersource, "mca_cybersource");
+
+        let mut adyen_fee_schedule = fee_schedule(10, 250);
+        adyen_fee_schedule.card_network_fees = vec![common_types::connector_fees::CardNetworkFee {
+            card_network: common_enums::CardNetwork::Visa,
+            fixed_fee: common_utils::types::MinorUnit::new(0),
+            percentage_fee_bps: 100,
+        }];
+        adyen_fee_schedule.cross_border_fee = Some(common_types::connector_fees::CrossBorderFee {
+            acquirer_country: Some(common_enums::CountryAlpha2::NL),
+            fixed_fee: common_utils::types::MinorUnit::new(0),
+            percentage_fee_bps: 50,
+        });
+        let mut checkout_fee_schedule = fee_schedule(0, 0);
+        checkout_fee_schedule.currency = common_enums::Currency::EUR;
+        let mut stripe_eur_fee_schedule = fee_schedule(25, 150);
+        stripe_eur_fee_schedule.currency = common_enums::Currency::EUR;
+
+        let fee_schedules = std::collections::HashMap::from([
+            (
+                stripe.merchant_connector_id.clone().unwrap(),
+                ConnectorFeeSchedule::try_from(vec![
+                    fee_schedule(30, 290),
+                    stripe_eur_fee_schedule,
+                ])
+                .unwrap(),
+            ),
+            (
+                adyen.merchant_connector_id.clone().unwrap(),
+                ConnectorFeeSchedule::try_from(vec![adyen_fee_schedule]).unwrap(),
+            ),
+            (
+                checkout.merchant_connector_id.clone().unwrap(),
+                ConnectorFeeSchedule::try_from(vec![checkout_fee_schedule]).unwrap(),
+            ),
+        ]);
+
+        let mut fee_input = FeeCalculationInput {
+            amount: common_utils::types::MinorUnit::new(10000),
+            currency: common_enums::Currency::USD,
+            card_network: None,
+            card_type: None,
+            issuer_country: Some(common_enums::CountryAlpha2::US),
+            business_country: None,
+        };
+        let connectors = vec![
+            cybersource.clone(),
+            checkout.clone(),
+            stripe.clone(),
+            adyen.clone(),
+        ];
+
+        // adyen: 10 + 250 bps + 50 bps cross border = 310, stripe: 30 + 290 bps = 320
+        assert_eq!(
+            sort_connectors_by_expected_fee(&connectors, &fee_schedules, &fee_input),
+            vec![
+                adyen.clone(),
+                stripe.clone(),
+                cybersource.clone(),
+                checkout.clone()
+            ]
+        );
+
+        // adyen: 10 + 250 bps + 100 bps visa + 50 bps cross border = 410
+        fee_input.card_network = Some(common_enums::CardNetwork::Visa);
+        assert_eq!(
+            sort_connectors_by_expected_fee(&connectors, &fee_schedules, &fee_input),
+            vec![
+                stripe.clone(),
+                adyen.clone(),
+                cybersource.clone(),
+                checkout.clone()
+            ]
+        );
+
+        // checkout: 0, stripe: 25 + 150 bps = 175, adyen has no schedule for EUR
+        fee_input.currency = common_enums::Currency::EUR;
+        assert_eq!(
+            sort_connectors_by_expected_fee(&connectors, &fee_schedules, &fee_input),
+            vec![checkout, stripe, cybersource, adyen]
+        );
+    }
+
+    #[test]
+    fn test_connector_fee_schedule_rejects_duplicate_currencies() {
+        let fee_schedule = serde_json::json!([
+            { "currency": "USD", "fixed_fee": 30, "percentage_fee_bps": 290 },
+            { "currency": "EUR", "fixed_fee": 25 },
+        ]);
+        let fee_schedule = serde_json::from_value::<ConnectorFeeSchedule>(fee_schedule).unwrap();
+        assert_eq!(fee_schedule.get_currency_fee_schedules().len(), 2);
+
+        let duplicate_fee_schedule = serde_json::json!([
+            { "currency": "USD", "fixed_fee": 30 },
+            { "currency": "USD", "percentage_fee_bps": 290 },
+        ]);
+        assert!(serde_json::from_value::<ConnectorFeeSchedule>(duplicate_fee_schedule).is_err());
+    }
+}
@@ -327,6 +327,68 @@ pub enum CaptureStatus {
     Failed,
 }
 
+/// The kind of amount movement recorded by an entry of the payment ledger
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum LedgerEntryType {
+    /// Amount authorized on the payment method of the customer
+    Authorize,
+    /// Authorized amount captured for the merchant
+    Capture,
+    /// Authorized amount released by voiding the authorization
+    Void,
+    /// Uncaptured amount of a partially captured authorization released back to the customer
+    AuthorizationReversal,
+    /// Captured amount returned to the customer through a refund
+    Refund,
+    /// Captured amount withdrawn from the merchant through a dispute
+    Chargeback,
+    /// Charged back amount returned to the merchant after the dispute was resolved in their favour
+    ChargebackReversal,
+}
+
+/// The account of the payment ledger whose balance is affected by an entry
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum LedgerAccount {
+    /// Funds of the customer, outside the payment
+    Customer,
+    /// Funds held on the payment method of the customer through an authorization
+    AuthorizationHold,
+    /// Funds captured for the merchant
+    MerchantBalance,
+    /// Funds withdrawn from the merchant through disputes
+    Chargeback,
+}
+
 #[derive(
     Default,
     Clone,
@@ -379,6 +441,90 @@ impl PaymentResourceUpdateStatus {
     }
 }
 
+/// Interval at which the installments of an installment payment are charged to the customer
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::VariantNames,
+    strum::EnumIter,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum InstallmentInterval {
+    Weekly,
+    Biweekly,
+    #[default]
+    Monthly,
+}
+
+/// Status of the release of the uncaptured remainder of a partially captured authorization
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuthorizationReversalStatus {
+    /// The reversal was requested and is awaiting the response of the connector
+    Pending,
+    /// The uncaptured remainder was released by the connector
+    Success,
+    /// The connector failed to release the uncaptured remainder
+    Failure,
+}
+
+/// Reason for which the merchant cancelled the payment
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentCancellationReason {
+    /// The payment was created more than once for the same order
+    Duplicate,
+    /// The payment is suspected to be fraudulent
+    Fraudulent,
+    /// The customer asked for the payment to be cancelled
+    RequestedByCustomer,
+    /// The customer abandoned the checkout
+    Abandoned,
+    /// The order could not be fulfilled
+    OrderNotFulfilled,
+    /// The payment was cancelled for a reason not covered by the other values
+    Other,
+}
+
 #[derive(
     Clone,
     Debug,
@@ -1422,6 +1568,7 @@ pub enum EventClass {
     Refunds,
     Disputes,
     Mandates,
+    PaymentMethods,
     #[cfg(feature = "payouts")]
     Payouts,
 }
@@ -1435,6 +1582,9 @@ impl EventClass {
                 EventType::PaymentFailed,
                 EventType::PaymentProcessing,
                 EventType::PaymentCancelled,
+                EventType::PaymentExpired,
+                EventType::PaymentAuthorizationExpiring,
+                EventType::PaymentReauthorized,
                 EventType::PaymentAuthorized,
                 EventType::PaymentCaptured,
                 EventType::ActionRequired,
@@ -1450,6 +1600,7 @@ impl EventClass {
                 EventType::DisputeLost,
             ]),
             Self::Mandates => HashSet::from([EventType::MandateActive, EventType::MandateRevoked]),
+            Self::PaymentMethods => HashSet::from([EventType::PaymentMethodUpdated]),
             #[cfg(feature = "payouts")]
             Self::Payouts => HashSet::from([
                 EventType::PayoutSuccess,
@@ -1491,6 +1642,12 @@ pub enum EventType {
     PaymentAuthorized,
     PaymentCaptured,
     ActionRequired,
+    /// Payment intent expired before it was completed
+    PaymentExpired,
+    /// Authorization of the payment is about to lapse before the payment was captured
+    PaymentAuthorizationExpiring,
+    /// Payment was authorized again as a new payment, since its authorization was about to lapse
+    PaymentReauthorized,
     RefundSucceeded,
     RefundFailed,
     DisputeOpened,
@@ -1502,6 +1659,8 @@ pub enum EventType {
     DisputeLost,
     MandateActive,
     MandateRevoked,
+    /// Card details of a stored payment method were updated after the card was reissued
+    PaymentMethodUpdated,
     #[cfg(feature = "payouts")]
     PayoutSuccess,
     #[cfg(feature = "payouts")]
@@ -1611,13 +1770,19 @@ pub enum IntentStatus {
     PartiallyCapturedAndCapturable,
     /// There has been a discrepancy between the amount/currency sent in the request and the amount/currency received by the processor
     Conflicted,
+    /// The payment was not completed within the expiry configured on the business profile, and has been cancelled.
+    Expired,
 }
 
 impl IntentStatus {
     /// Indicates whether the payment intent is in terminal state or not
     pub fn is_in_terminal_state(self) -> bool {
         match self {
-            Self::Succeeded | Self::Failed | Self::Cancelled | Self::PartiallyCaptured => true,
+            Self::Succeeded
+            | Self::Failed
+            | Self::Cancelled
+            | Self::PartiallyCaptured
+            | Self::Expired => true,
             Self::Processing
             | Self::RequiresCustomerAction
             | Self::RequiresMerchantAction
@@ -1639,6 +1804,7 @@ impl IntentStatus {
             | Self::Succeeded
             | Self::Failed
             | Self::Cancelled
+            | Self::Expired
             |  Self::PartiallyCaptured
             |  Self::RequiresCapture | Self::Conflicted => false,
             Self::Processing
@@ -1737,6 +1903,64 @@ pub enum PaymentMethodStatus {
     AwaitingData,
 }
 
+/// Action taken when a payment method being saved for a customer is a duplicate of a payment
+/// method already saved for the customer
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentMethodDuplicatePolicy {
+    /// Saving the payment method fails with a duplicate payment method error.
+    #[default]
+    Reject,
+    /// The payment method already saved for the customer is returned, and no new payment method
+    /// is created.
+    ReuseExisting,
+    /// A new payment method is created alongside the payment method already saved for the
+    /// customer.
+    Allow,
+}
+
+/// Status of the network token of a payment method, as notified by the token service
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum NetworkTokenStatus {
+    /// Indicates that the network token can be used for payments.
+    Active,
+    /// Indicates that the network token was suspended by the token service, and the card number
+    /// has to be used for payments until the token is resumed.
+    Suspended,
+    /// Indicates that the network token was deleted by the token service, and can no longer be
+    /// used for payments.
+    Deleted,
+}
+
 impl From<AttemptStatus> for PaymentMethodStatus {
     fn from(attempt_status: AttemptStatus) -> Self {
         match attempt_status {
@@ -2418,6 +2642,39 @@ pub enum DecisionEngineMerchantCategoryCode {
 }
 
 impl CardNetwork {
+    /// Number of days for which an authorization made on the network is held by the issuer, after
+    /// which the authorization lapses and can no longer be captured
+    pub fn get_authorization_validity_in_days(
+        &self,
+        merchant_category_code: Option<MerchantCategoryCode>,
+        is_extended_authorization_applied: bool,
+    ) -> u16 {
+        match self {
+            // Lodging merchants can hold authorizations for the duration of the stay
+            Self::Visa
+                if is_extended_authorization_applied
+                    || merchant_category_code == Some(MerchantCategoryCode::Mcc7011) =>
+            {
+                30
+            }
+            Self::Visa => 5,
+            Self::Mastercard | Self::Maestro if is_extended_authorization_applied => 30,
+            Self::Discover | Self::DinersClub => 10,
+            Self::Mastercard
+            | Self::Maestro
+            | Self::AmericanExpress
+            | Self::JCB
+            | Self::CartesBancaires
+            | Self::UnionPay
+            | Self::Interac
+            | Self::RuPay
+            | Self::Star
+            | Self::Pulse
+            | Self::Accel
+            | Self::Nyce => 7,
+        }
+    }
+
     pub fn is_global_network(&self) -> bool {
         match self {
             Self::Interac
@@ -7482,6 +7739,8 @@ pub enum PermissionGroup {
     ReconOpsView,
     ReconOpsManage,
     InternalManage,
+    PaymentMethodExportView,
+    PaymentMethodExportManage,
 }
 
 #[derive(Clone, Debug, serde::Serialize, PartialEq, Eq, Hash, strum::EnumIter)]
@@ -7495,6 +7754,7 @@ pub enum ParentGroup {
     ReconReports,
     Account,
     Internal,
+    PaymentMethodExport,
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, serde::Serialize)]
@@ -7525,6 +7785,7 @@ pub enum Resource {
     ReconConfig,
     RevenueRecovery,
     InternalConnector,
+    PaymentMethodExport,
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, serde::Serialize, Hash)]
@@ -8160,6 +8421,17 @@ impl ErrorCategory {
             | Self::FrmDecline => false,
         }
     }
+
+    /// Soft declines are failures which may succeed when the payment is retried, while hard
+    /// declines are failures of the payment method which are not expected to succeed on a retry
+    pub fn is_soft_decline(self) -> bool {
+        match self {
+            Self::ProcessorDowntime
+            | Self::ProcessorDeclineUnauthorized
+            | Self::ProcessorDeclineIncorrectData => true,
+            Self::IssueWithPaymentMethod | Self::FrmDecline => false,
+        }
+    }
 }
 
 #[derive(
@@ -8430,6 +8702,17 @@ pub enum ProcessTrackerRunner {
     AttachPayoutAccountWorkflow,
     PaymentMethodStatusUpdateWorkflow,
     PassiveRecoveryWorkflow,
+    RoutingActivationWorkflow,
+    PaymentIntentExpiryWorkflow,
+    ScheduledCaptureWorkflow,
+    AuthorizationExpiryWorkflow,
+    CardAccountUpdaterWorkflow,
+    CardExpiryScanWorkflow,
+    NetworkTokenStatusCheckWorkflow,
+    PaymentMethodExportWorkflow,
+    PaymentLedgerWorkflow,
+    PaymentMethodFingerprintBackfillWorkflow,
+    PaymentRetryWorkflow,
 }
 
 #[derive(Debug)]
@@ -8472,3 +8755,77 @@ pub enum TokenDataType {
     /// Fetch network token for the given payment method
     NetworkToken,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_authorization_validity_in_days() {
+        assert_eq!(
+            CardNetwork::Visa.get_authorization_validity_in_days(None, false),
+            5
+        );
+        assert_eq!(
+            CardNetwork::Visa.get_authorization_validity_in_days(None, true),
+            30
+        );
+        assert_eq!(
+            CardNetwork::Visa
+                .get_authorization_validity_in_days(Some(MerchantCategoryCode::Mcc7011), false),
+            30
+        );
+        assert_eq!(
+            CardNetwork::Visa
+                .get_authorization_validity_in_days(Some(MerchantCategoryCode::Mcc5411), false),
+            5
+        );
+
+        for card_network in [CardNetwork::Mastercard, CardNetwork::Maestro] {
+            assert_eq!(
+                card_network.get_authorization_validity_in_days(None, false),
+                7
+            );
+            assert_eq!(
+                card_network.get_authorization_validity_in_days(None, true),
+                30
+            );
+            // Lodging merchants only hold authorizations for longer on Visa
+            assert_eq!(
+                card_network
+                    .get_authorization_validity_in_days(Some(MerchantCategoryCode::Mcc7011), false),
+                7
+            );
+        }
+
+        for card_network in [CardNetwork::Discover, CardNetwork::DinersClub] {
+            assert_eq!(
+                card_network.get_authorization_validity_in_days(None, false),
+                10
+            );
+            assert_eq!(
+                card_network.get_authorization_validity_in_days(None, true),
+                10
+            );
+        }
+
+        for card_network in [
+            CardNetwork::AmericanExpress,
+            CardNetwork::JCB,
+            CardNetwork::CartesBancaires,
+            CardNetwork::UnionPay,
+            CardNetwork::Interac,
+            CardNetwork::RuPay,
+            CardNetwork::Star,
+            CardNetwork::Pulse,
+            CardNetwork::Accel,
+            CardNetwork::Nyce,
+        ] {
+            assert_eq!(
+                card_network
+                    .get_authorization_validity_in_days(Some(MerchantCategoryCode::Mcc7011), true),
+                7
+            );
+        }
+    }
+}
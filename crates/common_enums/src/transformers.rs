@@ -3,7 +3,7 @@ use std::fmt::{Display, Formatter};
 use serde::{Deserialize, Serialize};
 
 use crate::enums::{
-    AttemptStatus, Country, CountryAlpha2, CountryAlpha3, IntentStatus, PaymentMethod,
+    AttemptStatus, Country, CountryAlpha2, CountryAlpha3, Currency, IntentStatus, PaymentMethod,
     PaymentMethodType,
 };
 
@@ -267,6 +267,262 @@ impl CountryAlpha2 {
             Self::ZW => CountryAlpha3::ZWE,
         }
     }
+
+    /// Returns the currency which is legal tender in the country, if any. Countries which use the
+    /// currency of another country map to that currency
+    pub const fn from_alpha2_to_currency(code: Self) -> Option<Currency> {
+        match code {
+            Self::AF => Some(Currency::AFN),
+            Self::AX => Some(Currency::EUR),
+            Self::AL => Some(Currency::ALL),
+            Self::DZ => Some(Currency::DZD),
+            Self::AS => Some(Currency::USD),
+            Self::AD => Some(Currency::EUR),
+            Self::AO => Some(Currency::AOA),
+            Self::AI => Some(Currency::XCD),
+            Self::AQ => None,
+            Self::AG => Some(Currency::XCD),
+            Self::AR => Some(Currency::ARS),
+            Self::AM => Some(Currency::AMD),
+            Self::AW => Some(Currency::AWG),
+            Self::AU => Some(Currency::AUD),
+            Self::AT => Some(Currency::EUR),
+            Self::AZ => Some(Currency::AZN),
+            Self::BS => Some(Currency::BSD),
+            Self::BH => Some(Currency::BHD),
+            Self::BD => Some(Currency::BDT),
+            Self::BB => Some(Currency::BBD),
+            Self::BY => Some(Currency::BYN),
+            Self::BE => Some(Currency::EUR),
+            Self::BZ => Some(Currency::BZD),
+            Self::BJ => Some(Currency::XOF),
+            Self::BM => Some(Currency::BMD),
+            Self::BT => Some(Currency::BTN),
+            Self::BO => Some(Currency::BOB),
+            Self::BQ => Some(Currency::USD),
+            Self::BA => Some(Currency::BAM),
+            Self::BW => Some(Currency::BWP),
+            Self::BV => Some(Currency::NOK),
+            Self::BR => Some(Currency::BRL),
+            Self::IO => Some(Currency::USD),
+            Self::BN => Some(Currency::BND),
+            Self::BG => Some(Currency::BGN),
+            Self::BF => Some(Currency::XOF),
+            Self::BI => Some(Currency::BIF),
+            Self::CV => Some(Currency::CVE),
+            Self::KH => Some(Currency::KHR),
+            Self::CM => Some(Currency::XAF),
+            Self::CA => Some(Currency::CAD),
+            Self::KY => Some(Currency::KYD),
+            Self::CF => Some(Currency::XAF),
+            Self::TD => Some(Currency::XAF),
+            Self::CL => Some(Currency::CLP),
+            Self::CN => Some(Currency::CNY),
+            Self::CX => Some(Currency::AUD),
+            Self::CC => Some(Currency::AUD),
+            Self::CO => Some(Currency::COP),
+            Self::KM => Some(Currency::KMF),
+            Self::CG => Some(Currency::XAF),
+            Self::CD => Some(Currency::CDF),
+            Self::CK => Some(Currency::NZD),
+            Self::CR => Some(Currency::CRC),
+            Self::CI => Some(Currency::XOF),
+            Self::HR => Some(Currency::EUR),
+            Self::CU => Some(Currency::CUP),
+            Self::CW => Some(Currency::ANG),
+            Self::CY => Some(Currency::EUR),
+            Self::CZ => Some(Currency::CZK),
+            Self::DK => Some(Currency::DKK),
+            Self::DJ => Some(Currency::DJF),
+            Self::DM => Some(Currency::XCD),
+            Self::DO => Some(Currency::DOP),
+            Self::EC => Some(Currency::USD),
+            Self::EG => Some(Currency::EGP),
+            Self::SV => Some(Currency::USD),
+            Self::GQ => Some(Currency::XAF),
+            Self::ER => Some(Currency::ERN),
+            Self::EE => Some(Currency::EUR),
+            Self::ET => Some(Currency::ETB),
+            Self::FK => Some(Currency::FKP),
+            Self::FO => Some(Currency::DKK),
+            Self::FJ => Some(Currency::FJD),
+            Self::FI => Some(Currency::EUR),
+            Self::FR => Some(Currency::EUR),
+            Self::GF => Some(Currency::EUR),
+            Self::PF => Some(Currency::XPF),
+            Self::TF => Some(Currency::EUR),
+            Self::GA => Some(Currency::XAF),
+            Self::GM => Some(Currency::GMD),
+            Self::GE => Some(Currency::GEL),
+            Self::DE => Some(Currency::EUR),
+            Self::GH => Some(Currency::GHS),
+            Self::GI => Some(Currency::GIP),
+            Self::GR => Some(Currency::EUR),
+            Self::GL => Some(Currency::DKK),
+            Self::GD => Some(Currency::XCD),
+            Self::GP => Some(Currency::EUR),
+            Self::GU => Some(Currency::USD),
+            Self::GT => Some(Currency::GTQ),
+            Self::GG => Some(Currency::GBP),
+            Self::GN => Some(Currency::GNF),
+            Self::GW => Some(Currency::XOF),
+            Self::GY => Some(Currency::GYD),
+            Self::HT => Some(Currency::HTG),
+            Self::HM => Some(Currency::AUD),
+            Self::VA => Some(Currency::EUR),
+            Self::HN => Some(Currency::HNL),
+            Self::HK => Some(Currency::HKD),
+            Self::HU => Some(Currency::HUF),
+            Self::IS => Some(Currency::ISK),
+            Self::IN => Some(Currency::INR),
+            Self::ID => Some(Currency::IDR),
+            Self::IR => Some(Currency::IRR),
+            Self::IQ => Some(Currency::IQD),
+            Self::IE => Some(Currency::EUR),
+            Self::IM => Some(Currency::GBP),
+            Self::IL => Some(Currency::ILS),
+            Self::IT => Some(Currency::EUR),
+            Self::JM => Some(Currency::JMD),
+            Self::JP => Some(Currency::JPY),
+            Self::JE => Some(Currency::GBP),
+            Self::JO => Some(Currency::JOD),
+            Self::KZ => Some(Currency::KZT),
+            Self::KE => Some(Currency::KES),
+            Self::KI => Some(Currency::AUD),
+            Self::KP => Some(Currency::KPW),
+            Self::KR => Some(Currency::KRW),
+            Self::KW => Some(Currency::KWD),
+            Self::KG => Some(Currency::KGS),
+            Self::LA => Some(Currency::LAK),
+            Self::LV => Some(Currency::EUR),
+            Self::LB => Some(Currency::LBP),
+            Self::LS => Some(Currency::LSL),
+            Self::LR => Some(Currency::LRD),
+            Self::LY => Some(Currency::LYD),
+            Self::LI => Some(Currency::CHF),
+            Self::LT => Some(Currency::EUR),
+            Self::LU => Some(Currency::EUR),
+            Self::MO => Some(Currency::MOP),
+            Self::MK => Some(Currency::MKD),
+            Self::MG => Some(Currency::MGA),
+            Self::MW => Some(Currency::MWK),
+            Self::MY => Some(Currency::MYR),
+            Self::MV => Some(Currency::MVR),
+            Self::ML => Some(Currency::XOF),
+            Self::MT => Some(Currency::EUR),
+            Self::MH => Some(Currency::USD),
+            Self::MQ => Some(Currency::EUR),
+            Self::MR => Some(Currency::MRU),
+            Self::MU => Some(Currency::MUR),
+            Self::YT => Some(Currency::EUR),
+            Self::MX => Some(Currency::MXN),
+            Self::FM => Some(Currency::USD),
+            Self::MD => Some(Currency::MDL),
+            Self::MC => Some(Currency::EUR),
+            Self::MN => Some(Currency::MNT),
+            Self::ME => Some(Currency::EUR),
+            Self::MS => Some(Currency::XCD),
+            Self::MA => Some(Currency::MAD),
+            Self::MZ => Some(Currency::MZN),
+            Self::MM => Some(Currency::MMK),
+            Self::NA => Some(Currency::NAD),
+            Self::NR => Some(Currency::AUD),
+            Self::NP => Some(Currency::NPR),
+            Self::NL => Some(Currency::EUR),
+            Self::NC => Some(Currency::XPF),
+            Self::NZ => Some(Currency::NZD),
+            Self::NI => Some(Currency::NIO),
+            Self::NE => Some(Currency::XOF),
+            Self::NG => Some(Currency::NGN),
+            Self::NU => Some(Currency::NZD),
+            Self::NF => Some(Currency::AUD),
+            Self::MP => Some(Currency::USD),
+            Self::NO => Some(Currency::NOK),
+            Self::OM => Some(Currency::OMR),
+            Self::PK => Some(Currency::PKR),
+            Self::PW => Some(Currency::USD),
+            Self::PS => Some(Currency::ILS),
+            Self::PA => Some(Currency::PAB),
+            Self::PG => Some(Currency::PGK),
+            Self::PY => Some(Currency::PYG),
+            Self::PE => Some(Currency::PEN),
+            Self::PH => Some(Currency::PHP),
+            Self::PN => Some(Currency::NZD),
+            Self::PL => Some(Currency::PLN),
+            Self::PT => Some(Currency::EUR),
+            Self::PR => Some(Currency::USD),
+            Self::QA => Some(Currency::QAR),
+            Self::RE => Some(Currency::EUR),
+            Self::RO => Some(Currency::RON),
+            Self::RU => Some(Currency::RUB),
+            Self::RW => Some(Currency::RWF),
+            Self::BL => Some(Currency::EUR),
+            Self::SH => Some(Currency::SHP),
+            Self::KN => Some(Currency::XCD),
+            Self::LC => Some(Currency::XCD),
+            Self::MF => Some(Currency::EUR),
+            Self::PM => Some(Currency::EUR),
+            Self::VC => Some(Currency::XCD),
+            Self::WS => Some(Currency::WST),
+            Self::SM => Some(Currency::EUR),
+            Self::ST => Some(Currency::STN),
+            Self::SA => Some(Currency::SAR),
+            Self::SN => Some(Currency::XOF),
+            Self::RS => Some(Currency::RSD),
+            Self::SC => Some(Currency::SCR),
+            Self::SL => Some(Currency::SLE),
+            Self::SG => Some(Currency::SGD),
+            Self::SX => Some(Currency::ANG),
+            Self::SK => Some(Currency::EUR),
+            Self::SI => Some(Currency::EUR),
+            Self::SB => Some(Currency::SBD),
+            Self::SO => Some(Currency::SOS),
+            Self::ZA => Some(Currency::ZAR),
+            Self::GS => Some(Currency::GBP),
+            Self::SS => Some(Currency::SSP),
+            Self::ES => Some(Currency::EUR),
+            Self::LK => Some(Currency::LKR),
+            Self::SD => Some(Currency::SDG),
+            Self::SR => Some(Currency::SRD),
+            Self::SJ => Some(Currency::NOK),
+            Self::SZ => Some(Currency::SZL),
+            Self::SE => Some(Currency::SEK),
+            Self::CH => Some(Currency::CHF),
+            Self::SY => Some(Currency::SYP),
+            Self::TW => Some(Currency::TWD),
+            Self::TJ => Some(Currency::TJS),
+            Self::TZ => Some(Currency::TZS),
+            Self::TH => Some(Currency::THB),
+            Self::TL => Some(Currency::USD),
+            Self::TG => Some(Currency::XOF),
+            Self::TK => Some(Currency::NZD),
+            Self::TO => Some(Currency::TOP),
+            Self::TT => Some(Currency::TTD),
+            Self::TN => Some(Currency::TND),
+            Self::TR => Some(Currency::TRY),
+            Self::TM => Some(Currency::TMT),
+            Self::TC => Some(Currency::USD),
+            Self::TV => Some(Currency::AUD),
+            Self::UG => Some(Currency::UGX),
+            Self::UA => Some(Currency::UAH),
+            Self::AE => Some(Currency::AED),
+            Self::GB => Some(Currency::GBP),
+            Self::US => Some(Currency::USD),
+            Self::UM => Some(Currency::USD),
+            Self::UY => Some(Currency::UYU),
+            Self::UZ => Some(Currency::UZS),
+            Self::VU => Some(Currency::VUV),
+            Self::VE => Some(Currency::VES),
+            Self::VN => Some(Currency::VND),
+            Self::VG => Some(Currency::USD),
+            Self::VI => Some(Currency::USD),
+            Self::WF => Some(Currency::XPF),
+            Self::EH => Some(Currency::MAD),
+            Self::YE => Some(Currency::YER),
+            Self::ZM => Some(Currency::ZMW),
+            Self::ZW => Some(Currency::ZWL),
+        }
+    }
 }
 impl Country {
     pub const fn from_alpha2(code: CountryAlpha2) -> Self {
@@ -2307,4 +2563,20 @@ mod tests {
             serde_json::from_str::<HyperswitchRequestNumeric>(request_str);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_alpha2_to_currency() {
+        assert_eq!(
+            CountryAlpha2::from_alpha2_to_currency(CountryAlpha2::IN),
+            Some(Currency::INR)
+        );
+        assert_eq!(
+            CountryAlpha2::from_alpha2_to_currency(CountryAlpha2::AX),
+            Some(Currency::EUR)
+        );
+        assert_eq!(
+            CountryAlpha2::from_alpha2_to_currency(CountryAlpha2::AQ),
+            None
+        );
+    }
 }
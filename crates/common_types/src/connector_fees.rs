@@ -0,0 +1,223 @@
+//! Types for the processing fees charged by connectors
+
+use common_enums::enums;
+use common_utils::{impl_to_sql_from_sql_json, types::MinorUnit};
+use diesel::{sql_types::Jsonb, AsExpression, FromSqlRow};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Number of basis points that make up the whole amount
+const BASIS_POINTS_DENOMINATOR: i128 = 10_000;
+
+#[derive(
+    Serialize, Deserialize, Debug, Clone, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
+)]
+#[diesel(sql_type = Jsonb)]
+#[serde(try_from = "Vec<CurrencyFeeSchedule>")]
+/// Fees charged by the connector for processing a payment, used to estimate the processing cost
+/// of a payment in least cost routing. Contains at most one schedule per currency.
+#[schema(value_type = Vec<CurrencyFeeSchedule>)]
+pub struct ConnectorFeeSchedule(Vec<CurrencyFeeSchedule>);
+impl_to_sql_from_sql_json!(ConnectorFeeSchedule);
+
+impl TryFrom<Vec<CurrencyFeeSchedule>> for ConnectorFeeSchedule {
+    type Error = String;
+
+    fn try_from(schedules: Vec<CurrencyFeeSchedule>) -> Result<Self, Self::Error> {
+        let mut currencies = std::collections::HashSet::new();
+
+        if let Some(schedule) = schedules
+            .iter()
+            .find(|schedule| !currencies.insert(schedule.currency))
+        {
+            return Err(format!(
+                "fee schedule is configured more than once for the currency {}",
+                schedule.currency
+            ));
+        }
+
+        Ok(Self(schedules))
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(deny_unknown_fields)]
+/// Fees charged by the connector for processing a payment in a currency
+pub struct CurrencyFeeSchedule {
+    /// The currency the fixed fees are expressed in. The schedule is only applied to payments in
+    /// this currency.
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: enums::Currency,
+
+    /// Fee charged for every payment, in the lowest denomination of the currency
+    #[serde(default)]
+    #[schema(value_type = i64, example = 30)]
+    pub fixed_fee: MinorUnit,
+
+    /// Fee charged on the payment amount, in basis points
+    #[serde(default)]
+    #[schema(example = 290)]
+    pub percentage_fee_bps: u16,
+
+    /// Additional fees charged for cards of specific networks
+    #[serde(default)]
+    pub card_network_fees: Vec<CardNetworkFee>,
+
+    /// Additional fees charged for cards of specific types
+    #[serde(default)]
+    pub card_type_fees: Vec<CardTypeFee>,
+
+    /// Additional fee charged when the card is issued in a country other than the acquirer's
+    pub cross_border_fee: Option<CrossBorderFee>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(deny_unknown_fields)]
+/// Additional fee charged for cards of a network
+pub struct CardNetworkFee {
+    /// The card network the fee applies to
+    #[schema(value_type = CardNetwork, example = "Visa")]
+    pub card_network: enums::CardNetwork,
+
+    /// Fee charged for every payment, in the lowest denomination of the currency
+    #[serde(default)]
+    #[schema(value_type = i64, example = 5)]
+    pub fixed_fee: MinorUnit,
+
+    /// Fee charged on the payment amount, in basis points
+    #[serde(default)]
+    #[schema(example = 10)]
+    pub percentage_fee_bps: u16,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(deny_unknown_fields)]
+/// Additional fee charged for cards of a type
+pub struct CardTypeFee {
+    /// The card type the fee applies to
+    #[schema(value_type = CardType, example = "credit")]
+    pub card_type: enums::CardType,
+
+    /// Fee charged for every payment, in the lowest denomination of the currency
+    #[serde(default)]
+    #[schema(value_type = i64, example = 0)]
+    pub fixed_fee: MinorUnit,
+
+    /// Fee charged on the payment amount, in basis points
+    #[serde(default)]
+    #[schema(example = 50)]
+    pub percentage_fee_bps: u16,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(deny_unknown_fields)]
+/// Additional fee charged for cards issued outside of the acquirer's country
+pub struct CrossBorderFee {
+    /// Country of the acquirer, defaults to the business country of the payment
+    #[schema(value_type = Option<CountryAlpha2>, example = "US")]
+    pub acquirer_country: Option<enums::CountryAlpha2>,
+
+    /// Fee charged for every payment, in the lowest denomination of the currency
+    #[serde(default)]
+    #[schema(value_type = i64, example = 0)]
+    pub fixed_fee: MinorUnit,
+
+    /// Fee charged on the payment amount, in basis points
+    #[serde(default)]
+    #[schema(example = 100)]
+    pub percentage_fee_bps: u16,
+}
+
+/// Attributes of a payment which determine the fees charged for it
+#[derive(Debug, Clone)]
+pub struct FeeCalculationInput {
+    /// Amount of the payment
+    pub amount: MinorUnit,
+    /// Currency of the payment
+    pub currency: enums::Currency,
+    /// Network of the card used for the payment
+    pub card_network: Option<enums::CardNetwork>,
+    /// Type of the card used for the payment
+    pub card_type: Option<enums::CardType>,
+    /// Country the card used for the payment was issued in
+    pub issuer_country: Option<enums::CountryAlpha2>,
+    /// Business country of the payment
+    pub business_country: Option<enums::CountryAlpha2>,
+}
+
+impl ConnectorFeeSchedule {
+    /// Schedules of the currencies the connector fees are configured for
+    pub fn get_currency_fee_schedules(&self) -> &[CurrencyFeeSchedule] {
+        &self.0
+    }
+
+    /// Whether no currency has a schedule configured
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Expected fee for processing the payment, `None` if no schedule is configured for the
+    /// currency of the payment
+    pub fn get_expected_fee(&self, input: &FeeCalculationInput) -> Option<MinorUnit> {
+        self.0
+            .iter()
+            .find(|schedule| schedule.currency == input.currency)
+            .map(|schedule| schedule.get_expected_fee(input))
+    }
+}
+
+impl CurrencyFeeSchedule {
+    /// Expected fee for processing the payment, irrespective of its currency. Fees are rounded up
+    /// to the lowest denomination of the currency.
+    fn get_expected_fee(&self, input: &FeeCalculationInput) -> MinorUnit {
+        let mut fees = vec![(self.fixed_fee, self.percentage_fee_bps)];
+
+        if let Some(card_network) = &input.card_network {
+            fees.extend(
+                self.card_network_fees
+                    .iter()
+                    .filter(|fee| fee.card_network == *card_network)
+                    .map(|fee| (fee.fixed_fee, fee.percentage_fee_bps)),
+            );
+        }
+
+        if let Some(card_type) = input.card_type {
+            fees.extend(
+                self.card_type_fees
+                    .iter()
+                    .filter(|fee| fee.card_type == card_type)
+                    .map(|fee| (fee.fixed_fee, fee.percentage_fee_bps)),
+            );
+        }
+
+        if let Some(cross_border_fee) = &self.cross_border_fee {
+            let acquirer_country = cross_border_fee.acquirer_country.or(input.business_country);
+
+            if let (Some(issuer_country), Some(acquirer_country)) =
+                (input.issuer_country, acquirer_country)
+            {
+                if issuer_country != acquirer_country {
+                    fees.push((
+                        cross_border_fee.fixed_fee,
+                        cross_border_fee.percentage_fee_bps,
+                    ));
+                }
+            }
+        }
+
+        let (fixed_fee, percentage_fee_bps) = fees.into_iter().fold(
+            (0_i128, 0_i128),
+            |(fixed_fee, percentage_fee_bps), (fee, fee_bps)| {
+                (
+                    fixed_fee + i128::from(fee.get_amount_as_i64()),
+                    percentage_fee_bps + i128::from(fee_bps),
+                )
+            },
+        );
+        let amount = i128::from(input.amount.get_amount_as_i64());
+        let percentage_fee = (amount * percentage_fee_bps + BASIS_POINTS_DENOMINATOR - 1)
+            .div_euclid(BASIS_POINTS_DENOMINATOR);
+
+        MinorUnit::new(i64::try_from(fixed_fee + percentage_fee).unwrap_or(i64::MAX))
+    }
+}
@@ -254,3 +254,390 @@ pub struct XenditMultipleSplitResponse {
     pub routes: Vec<XenditSplitRoute>,
 }
 impl_to_sql_from_sql_json!(XenditMultipleSplitResponse);
+
+/// Max duration in seconds for which a failed connector can be excluded from retries
+const MAX_CONNECTOR_EXCLUSION_WINDOW_IN_SECONDS: u32 = 24 * 60 * 60;
+
+/// Max delay in seconds after which a failed payment is retried. The payment method data of the
+/// payment is held in the temporary locker until then
+const MAX_RETRY_DELAY_IN_SECONDS: u32 = 10 * 60;
+
+/// Policy for retrying failed payments of a profile, based on the error category of the failure
+/// configured in the gateway status map (GSM)
+#[derive(
+    Serialize, Deserialize, Debug, Clone, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
+)]
+#[diesel(sql_type = Jsonb)]
+#[serde(deny_unknown_fields)]
+pub struct RetryPolicy {
+    /// Ordered lists of connectors to which a failed payment is cascaded, per error category of
+    /// the failure. Failures of other categories are retried with the connectors chosen by routing
+    #[serde(default)]
+    pub cascades: Vec<RetryCascade>,
+    /// Duration in seconds for which a connector which failed a payment is not used for retries
+    #[schema(example = 300)]
+    pub connector_exclusion_window_in_seconds: Option<u32>,
+    /// Card networks for which payments are retried only when the failure is a soft decline
+    #[serde(default)]
+    #[schema(value_type = Vec<CardNetwork>)]
+    pub soft_decline_only_networks: Vec<enums::CardNetwork>,
+    /// Delay in seconds after which each retry attempt of a failed payment is made. When
+    /// configured, the payment fails with the attempt and is retried in the background after the
+    /// delay, instead of being retried while the payment is being processed. Only payments which
+    /// do not require the customer to authenticate are retried with a delay
+    #[schema(example = 30)]
+    pub retry_delay_in_seconds: Option<u32>,
+}
+impl_to_sql_from_sql_json!(RetryPolicy);
+
+/// Ordered list of connectors to which payments failing with the error category are cascaded
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RetryCascade {
+    /// The error category of the failure, as configured in the gateway status map
+    #[schema(value_type = ErrorCategory)]
+    pub error_category: enums::ErrorCategory,
+    /// Connectors to retry the payment with, in order of preference
+    #[schema(value_type = Vec<RoutableConnectors>)]
+    pub connectors: Vec<enums::RoutableConnectors>,
+}
+
+impl RetryPolicy {
+    /// Validates the retry policy provided by the merchant
+    pub fn validate(&self) -> Result<(), errors::ValidationError> {
+        let mut error_categories = std::collections::BTreeSet::new();
+        for cascade in &self.cascades {
+            if !error_categories.insert(cascade.error_category) {
+                return Err(errors::ValidationError::InvalidValue {
+                    message: format!(
+                        "Duplicate cascade found in retry_policy for error category {}",
+                        cascade.error_category
+                    ),
+                });
+            }
+
+            let connectors = cascade
+                .connectors
+                .iter()
+                .collect::<std::collections::HashSet<_>>();
+            if cascade.connectors.is_empty() || connectors.len() != cascade.connectors.len() {
+                return Err(errors::ValidationError::InvalidValue {
+                    message: format!(
+                        "Cascade of error category {} in retry_policy should contain unique connectors",
+                        cascade.error_category
+                    ),
+                });
+            }
+        }
+
+        if self
+            .connector_exclusion_window_in_seconds
+            .is_some_and(|window| window > MAX_CONNECTOR_EXCLUSION_WINDOW_IN_SECONDS)
+        {
+            return Err(errors::ValidationError::InvalidValue {
+                message: format!(
+                    "connector_exclusion_window_in_seconds in retry_policy should not exceed {MAX_CONNECTOR_EXCLUSION_WINDOW_IN_SECONDS}"
+                ),
+            });
+        }
+
+        if self
+            .retry_delay_in_seconds
+            .is_some_and(|retry_delay| retry_delay > MAX_RETRY_DELAY_IN_SECONDS)
+        {
+            return Err(errors::ValidationError::InvalidValue {
+                message: format!(
+                    "retry_delay_in_seconds in retry_policy should not exceed {MAX_RETRY_DELAY_IN_SECONDS}"
+                ),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Checks whether no setting is configured in the retry policy, in which case the policy of
+    /// the profile is removed
+    pub fn is_empty(&self) -> bool {
+        self.cascades.is_empty()
+            && self.connector_exclusion_window_in_seconds.is_none()
+            && self.soft_decline_only_networks.is_empty()
+            && self.retry_delay_in_seconds.is_none()
+    }
+
+    /// Checks whether the connector which failed the payment with the error category has to be
+    /// excluded from retries. Only failures on the side of the connector exclude it, failures of
+    /// the payment method or fraud checks would fail the payment with any other connector too
+    pub fn should_exclude_failed_connector(
+        &self,
+        error_category: Option<enums::ErrorCategory>,
+    ) -> bool {
+        self.connector_exclusion_window_in_seconds.is_some()
+            && error_category.is_some_and(enums::ErrorCategory::should_perform_elimination_routing)
+    }
+
+    /// Returns the cascade of connectors configured for the error category, if any
+    pub fn get_cascade(&self, error_category: enums::ErrorCategory) -> Option<&RetryCascade> {
+        self.cascades
+            .iter()
+            .find(|cascade| cascade.error_category == error_category)
+    }
+
+    /// Checks whether a payment made with the card network can be retried after a failure of the
+    /// error category
+    pub fn is_retry_allowed(
+        &self,
+        card_network: Option<&enums::CardNetwork>,
+        error_category: Option<enums::ErrorCategory>,
+    ) -> bool {
+        match card_network {
+            Some(card_network) if self.soft_decline_only_networks.contains(card_network) => {
+                error_category.is_some_and(enums::ErrorCategory::is_soft_decline)
+            }
+            _ => true,
+        }
+    }
+}
+
+/// Minimum number of installments of an installment plan
+pub const MIN_INSTALLMENT_COUNT: u8 = 2;
+
+/// Maximum number of installments of an installment plan
+pub const MAX_INSTALLMENT_COUNT: u8 = 48;
+
+/// Installment plan in which the customer pays for a card payment
+#[derive(
+    Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
+)]
+#[diesel(sql_type = Jsonb)]
+#[serde(deny_unknown_fields)]
+pub struct InstallmentPlan {
+    /// Number of installments in which the payment amount is charged
+    #[schema(example = 6, minimum = 2, maximum = 48)]
+    pub count: u8,
+    /// Interval at which the installments are charged
+    #[serde(default)]
+    #[schema(value_type = InstallmentInterval, example = "monthly")]
+    pub interval: enums::InstallmentInterval,
+    /// Whether the installments bear interest for the customer. Interest free installments are
+    /// usually funded by the merchant
+    #[serde(default)]
+    pub is_interest_bearing: bool,
+}
+impl_to_sql_from_sql_json!(InstallmentPlan);
+
+impl InstallmentPlan {
+    /// Validates the installment plan provided in the payment request
+    pub fn validate(&self) -> Result<(), errors::ValidationError> {
+        if !(MIN_INSTALLMENT_COUNT..=MAX_INSTALLMENT_COUNT).contains(&self.count) {
+            return Err(errors::ValidationError::InvalidValue {
+                message: format!(
+                    "count in installment_plan should be between {MIN_INSTALLMENT_COUNT} and {MAX_INSTALLMENT_COUNT}"
+                ),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Maximum markup in basis points which can be applied over the exchange rate of a currency
+/// conversion
+pub const MAX_CURRENCY_CONVERSION_MARKUP_IN_BASIS_POINTS: u16 = 1000;
+
+/// Minimum duration in seconds for which a currency conversion quote can be accepted
+pub const MIN_CURRENCY_CONVERSION_QUOTE_VALIDITY_IN_SECONDS: u32 = 60;
+
+/// Maximum duration in seconds for which a currency conversion quote can be accepted
+pub const MAX_CURRENCY_CONVERSION_QUOTE_VALIDITY_IN_SECONDS: u32 = 3600;
+
+/// Default duration in seconds for which a currency conversion quote can be accepted
+pub const DEFAULT_CURRENCY_CONVERSION_QUOTE_VALIDITY_IN_SECONDS: u32 = 600;
+
+/// Configuration for offering customers to pay in the currency of their card
+#[derive(
+    Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
+)]
+#[diesel(sql_type = Jsonb)]
+#[serde(deny_unknown_fields)]
+pub struct DynamicCurrencyConversionConfig {
+    /// Markup in basis points applied over the exchange rate offered to the customer
+    #[schema(example = 300, maximum = 1000)]
+    pub markup_in_basis_points: u16,
+    /// Duration in seconds for which a quote can be accepted by the customer
+    #[schema(example = 600, minimum = 60, maximum = 3600)]
+    pub quote_validity_in_seconds: Option<u32>,
+}
+impl_to_sql_from_sql_json!(DynamicCurrencyConversionConfig);
+
+impl DynamicCurrencyConversionConfig {
+    /// Validates the dynamic currency conversion configuration provided by the merchant
+    pub fn validate(&self) -> Result<(), errors::ValidationError> {
+        if self.markup_in_basis_points > MAX_CURRENCY_CONVERSION_MARKUP_IN_BASIS_POINTS {
+            return Err(errors::ValidationError::InvalidValue {
+                message: format!(
+                    "markup_in_basis_points in dynamic_currency_conversion should not exceed {MAX_CURRENCY_CONVERSION_MARKUP_IN_BASIS_POINTS}"
+                ),
+            });
+        }
+
+        if self.quote_validity_in_seconds.is_some_and(|validity| {
+            !(MIN_CURRENCY_CONVERSION_QUOTE_VALIDITY_IN_SECONDS
+                ..=MAX_CURRENCY_CONVERSION_QUOTE_VALIDITY_IN_SECONDS)
+                .contains(&validity)
+        }) {
+            return Err(errors::ValidationError::InvalidValue {
+                message: format!(
+                    "quote_validity_in_seconds in dynamic_currency_conversion should be between {MIN_CURRENCY_CONVERSION_QUOTE_VALIDITY_IN_SECONDS} and {MAX_CURRENCY_CONVERSION_QUOTE_VALIDITY_IN_SECONDS}"
+                ),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns the duration in seconds for which a quote can be accepted
+    pub fn get_quote_validity_in_seconds(&self) -> u32 {
+        self.quote_validity_in_seconds
+            .unwrap_or(DEFAULT_CURRENCY_CONVERSION_QUOTE_VALIDITY_IN_SECONDS)
+    }
+}
+
+/// Currency conversion accepted by the customer, to pay in the currency of their card
+#[derive(
+    Serialize, Deserialize, Debug, Clone, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
+)]
+#[diesel(sql_type = Jsonb)]
+pub struct CurrencyConversionDetails {
+    /// Identifier of the quote accepted by the customer
+    pub quote_id: String,
+    /// Amount of the payment in the currency of the merchant
+    #[schema(value_type = i64, example = 6540)]
+    pub original_amount: MinorUnit,
+    /// Currency of the merchant in which the payment was created
+    #[schema(value_type = Currency, example = "USD")]
+    pub original_currency: enums::Currency,
+    /// Amount of the payment in the currency of the card, including the markup
+    #[schema(value_type = i64, example = 6017)]
+    pub converted_amount: MinorUnit,
+    /// Currency of the card in which the customer pays
+    #[schema(value_type = Currency, example = "EUR")]
+    pub converted_currency: enums::Currency,
+    /// Exchange rate including the markup, applied to the amount in the currency of the merchant
+    #[schema(value_type = String, example = "0.92")]
+    pub exchange_rate: rust_decimal::Decimal,
+    /// Markup in basis points applied over the exchange rate
+    #[schema(example = 300)]
+    pub markup_in_basis_points: u16,
+}
+impl_to_sql_from_sql_json!(CurrencyConversionDetails);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_retry_policy() -> RetryPolicy {
+        RetryPolicy {
+            cascades: vec![RetryCascade {
+                error_category: enums::ErrorCategory::ProcessorDowntime,
+                connectors: vec![
+                    enums::RoutableConnectors::Stripe,
+                    enums::RoutableConnectors::Adyen,
+                ],
+            }],
+            connector_exclusion_window_in_seconds: Some(300),
+            soft_decline_only_networks: vec![enums::CardNetwork::Visa],
+            retry_delay_in_seconds: Some(30),
+        }
+    }
+
+    #[test]
+    fn test_validate_retry_policy() {
+        assert!(get_retry_policy().validate().is_ok());
+
+        let mut retry_policy = get_retry_policy();
+        retry_policy.cascades.push(RetryCascade {
+            error_category: enums::ErrorCategory::ProcessorDowntime,
+            connectors: vec![enums::RoutableConnectors::Checkout],
+        });
+        assert!(retry_policy.validate().is_err());
+
+        let mut retry_policy = get_retry_policy();
+        retry_policy.cascades = vec![RetryCascade {
+            error_category: enums::ErrorCategory::ProcessorDeclineUnauthorized,
+            connectors: vec![
+                enums::RoutableConnectors::Stripe,
+                enums::RoutableConnectors::Stripe,
+            ],
+        }];
+        assert!(retry_policy.validate().is_err());
+
+        let mut retry_policy = get_retry_policy();
+        retry_policy.cascades = vec![RetryCascade {
+            error_category: enums::ErrorCategory::ProcessorDeclineUnauthorized,
+            connectors: vec![],
+        }];
+        assert!(retry_policy.validate().is_err());
+
+        let mut retry_policy = get_retry_policy();
+        retry_policy.connector_exclusion_window_in_seconds =
+            Some(MAX_CONNECTOR_EXCLUSION_WINDOW_IN_SECONDS + 1);
+        assert!(retry_policy.validate().is_err());
+
+        let mut retry_policy = get_retry_policy();
+        retry_policy.retry_delay_in_seconds = Some(MAX_RETRY_DELAY_IN_SECONDS + 1);
+        assert!(retry_policy.validate().is_err());
+    }
+
+    #[test]
+    fn test_is_retry_allowed() {
+        let retry_policy = get_retry_policy();
+
+        // Payments of the soft decline only networks are retried only on soft declines
+        assert!(retry_policy.is_retry_allowed(
+            Some(&enums::CardNetwork::Visa),
+            Some(enums::ErrorCategory::ProcessorDowntime)
+        ));
+        assert!(!retry_policy.is_retry_allowed(
+            Some(&enums::CardNetwork::Visa),
+            Some(enums::ErrorCategory::IssueWithPaymentMethod)
+        ));
+        assert!(!retry_policy.is_retry_allowed(Some(&enums::CardNetwork::Visa), None));
+
+        // Payments of other networks are retried on any failure
+        assert!(retry_policy.is_retry_allowed(
+            Some(&enums::CardNetwork::Mastercard),
+            Some(enums::ErrorCategory::IssueWithPaymentMethod)
+        ));
+        assert!(retry_policy.is_retry_allowed(None, None));
+    }
+
+    #[test]
+    fn test_should_exclude_failed_connector() {
+        let retry_policy = get_retry_policy();
+
+        assert!(retry_policy
+            .should_exclude_failed_connector(Some(enums::ErrorCategory::ProcessorDowntime)));
+        assert!(!retry_policy
+            .should_exclude_failed_connector(Some(enums::ErrorCategory::IssueWithPaymentMethod)));
+        assert!(!retry_policy.should_exclude_failed_connector(None));
+
+        let retry_policy = RetryPolicy {
+            connector_exclusion_window_in_seconds: None,
+            ..get_retry_policy()
+        };
+        assert!(!retry_policy
+            .should_exclude_failed_connector(Some(enums::ErrorCategory::ProcessorDowntime)));
+    }
+
+    #[test]
+    fn test_is_empty() {
+        assert!(!get_retry_policy().is_empty());
+        assert!(RetryPolicy {
+            cascades: vec![],
+            connector_exclusion_window_in_seconds: None,
+            soft_decline_only_networks: vec![],
+            retry_delay_in_seconds: None,
+        }
+        .is_empty());
+    }
+}
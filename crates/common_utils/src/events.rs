@@ -109,6 +109,12 @@ pub enum ApiEventsType {
     PaymentMethodCollectLink {
         link_id: String,
     },
+    PaymentMethodExport {
+        export_id: String,
+    },
+    PaymentMethodExportRecipientKey {
+        key_id: String,
+    },
     Poll {
         poll_id: String,
     },
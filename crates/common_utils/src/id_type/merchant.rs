@@ -9,7 +9,7 @@ use crate::{
     date_time,
     errors::{CustomResult, ValidationError},
     generate_id_with_default_len,
-    id_type::{AlphaNumericId, LengthId},
+    id_type::{AlphaNumericId, LengthId, ProfileId},
     new_type::MerchantName,
     types::keymanager,
 };
@@ -203,6 +203,15 @@ impl MerchantId {
         format!("should_call_gsm_{}", self.get_string_repr())
     }
 
+    /// get_routing_temporal_config_key
+    pub fn get_routing_temporal_config_key(&self, profile_id: &ProfileId) -> String {
+        format!(
+            "routing_temporal_config_{}_{}",
+            self.get_string_repr(),
+            profile_id.get_string_repr()
+        )
+    }
+
     /// get_max_auto_single_connector_payout_retries_enabled_
     pub fn get_max_auto_single_connector_payout_retries_enabled(
         &self,
@@ -76,6 +76,11 @@ pub struct Profile {
     pub three_ds_decision_rule_algorithm: Option<serde_json::Value>,
     pub acquirer_config_map: Option<common_types::domain::AcquirerConfigMap>,
     pub merchant_category_code: Option<common_enums::MerchantCategoryCode>,
+    pub payment_intent_expiry: Option<i64>,
+    pub is_auto_reauthorization_enabled: Option<bool>,
+    pub retry_policy: Option<common_types::payments::RetryPolicy>,
+    pub dynamic_currency_conversion:
+        Option<common_types::payments::DynamicCurrencyConversionConfig>,
 }
 
 #[cfg(feature = "v1")]
@@ -132,6 +137,11 @@ pub struct ProfileNew {
     pub is_iframe_redirection_enabled: Option<bool>,
     pub is_pre_network_tokenization_enabled: Option<bool>,
     pub merchant_category_code: Option<common_enums::MerchantCategoryCode>,
+    pub payment_intent_expiry: Option<i64>,
+    pub is_auto_reauthorization_enabled: Option<bool>,
+    pub retry_policy: Option<common_types::payments::RetryPolicy>,
+    pub dynamic_currency_conversion:
+        Option<common_types::payments::DynamicCurrencyConversionConfig>,
 }
 
 #[cfg(feature = "v1")]
@@ -188,6 +198,11 @@ pub struct ProfileUpdateInternal {
     pub three_ds_decision_rule_algorithm: Option<serde_json::Value>,
     pub acquirer_config_map: Option<common_types::domain::AcquirerConfigMap>,
     pub merchant_category_code: Option<common_enums::MerchantCategoryCode>,
+    pub payment_intent_expiry: Option<i64>,
+    pub is_auto_reauthorization_enabled: Option<bool>,
+    pub retry_policy: Option<Option<common_types::payments::RetryPolicy>>,
+    pub dynamic_currency_conversion:
+        Option<common_types::payments::DynamicCurrencyConversionConfig>,
 }
 
 #[cfg(feature = "v1")]
@@ -241,6 +256,10 @@ impl ProfileUpdateInternal {
             three_ds_decision_rule_algorithm,
             acquirer_config_map,
             merchant_category_code,
+            payment_intent_expiry,
+            is_auto_reauthorization_enabled,
+            retry_policy,
+            dynamic_currency_conversion,
         } = self;
         Profile {
             profile_id: source.profile_id,
@@ -325,6 +344,12 @@ impl ProfileUpdateInternal {
                 .or(source.three_ds_decision_rule_algorithm),
             acquirer_config_map: acquirer_config_map.or(source.acquirer_config_map),
             merchant_category_code: merchant_category_code.or(source.merchant_category_code),
+            payment_intent_expiry: payment_intent_expiry.or(source.payment_intent_expiry),
+            is_auto_reauthorization_enabled: is_auto_reauthorization_enabled
+                .or(source.is_auto_reauthorization_enabled),
+            retry_policy: retry_policy.unwrap_or(source.retry_policy),
+            dynamic_currency_conversion: dynamic_currency_conversion
+                .or(source.dynamic_currency_conversion),
         }
     }
 }
@@ -387,6 +412,11 @@ pub struct Profile {
     pub three_ds_decision_rule_algorithm: Option<serde_json::Value>,
     pub acquirer_config_map: Option<common_types::domain::AcquirerConfigMap>,
     pub merchant_category_code: Option<common_enums::MerchantCategoryCode>,
+    pub payment_intent_expiry: Option<i64>,
+    pub is_auto_reauthorization_enabled: Option<bool>,
+    pub retry_policy: Option<common_types::payments::RetryPolicy>,
+    pub dynamic_currency_conversion:
+        Option<common_types::payments::DynamicCurrencyConversionConfig>,
     pub routing_algorithm_id: Option<common_utils::id_type::RoutingId>,
     pub order_fulfillment_time: Option<i64>,
     pub order_fulfillment_time_origin: Option<common_enums::OrderFulfillmentTimeOrigin>,
@@ -400,6 +430,7 @@ pub struct Profile {
     pub external_vault_connector_details: Option<ExternalVaultConnectorDetails>,
     pub revenue_recovery_retry_algorithm_type: Option<common_enums::RevenueRecoveryAlgorithmType>,
     pub revenue_recovery_retry_algorithm_data: Option<RevenueRecoveryAlgorithmData>,
+    pub payment_method_duplicate_policy: Option<common_enums::PaymentMethodDuplicatePolicy>,
 }
 
 impl Profile {
@@ -459,6 +490,11 @@ pub struct ProfileNew {
     pub is_debit_routing_enabled: bool,
     pub merchant_business_country: Option<common_enums::CountryAlpha2>,
     pub merchant_category_code: Option<common_enums::MerchantCategoryCode>,
+    pub payment_intent_expiry: Option<i64>,
+    pub is_auto_reauthorization_enabled: Option<bool>,
+    pub retry_policy: Option<common_types::payments::RetryPolicy>,
+    pub dynamic_currency_conversion:
+        Option<common_types::payments::DynamicCurrencyConversionConfig>,
     pub routing_algorithm_id: Option<common_utils::id_type::RoutingId>,
     pub order_fulfillment_time: Option<i64>,
     pub order_fulfillment_time_origin: Option<common_enums::OrderFulfillmentTimeOrigin>,
@@ -474,6 +510,7 @@ pub struct ProfileNew {
     pub is_iframe_redirection_enabled: Option<bool>,
     pub is_external_vault_enabled: Option<bool>,
     pub external_vault_connector_details: Option<ExternalVaultConnectorDetails>,
+    pub payment_method_duplicate_policy: Option<common_enums::PaymentMethodDuplicatePolicy>,
 }
 
 #[cfg(feature = "v2")]
@@ -518,6 +555,11 @@ pub struct ProfileUpdateInternal {
     pub is_debit_routing_enabled: Option<bool>,
     pub merchant_business_country: Option<common_enums::CountryAlpha2>,
     pub merchant_category_code: Option<common_enums::MerchantCategoryCode>,
+    pub payment_intent_expiry: Option<i64>,
+    pub is_auto_reauthorization_enabled: Option<bool>,
+    pub retry_policy: Option<Option<common_types::payments::RetryPolicy>>,
+    pub dynamic_currency_conversion:
+        Option<common_types::payments::DynamicCurrencyConversionConfig>,
     pub routing_algorithm_id: Option<common_utils::id_type::RoutingId>,
     pub order_fulfillment_time: Option<i64>,
     pub order_fulfillment_time_origin: Option<common_enums::OrderFulfillmentTimeOrigin>,
@@ -532,6 +574,7 @@ pub struct ProfileUpdateInternal {
     pub is_iframe_redirection_enabled: Option<bool>,
     pub is_external_vault_enabled: Option<bool>,
     pub external_vault_connector_details: Option<ExternalVaultConnectorDetails>,
+    pub payment_method_duplicate_policy: Option<common_enums::PaymentMethodDuplicatePolicy>,
 }
 
 #[cfg(feature = "v2")]
@@ -587,6 +630,11 @@ impl ProfileUpdateInternal {
             is_external_vault_enabled,
             external_vault_connector_details,
             merchant_category_code,
+            payment_intent_expiry,
+            is_auto_reauthorization_enabled,
+            retry_policy,
+            dynamic_currency_conversion,
+            payment_method_duplicate_policy,
         } = self;
         Profile {
             id: source.id,
@@ -681,6 +729,14 @@ impl ProfileUpdateInternal {
             three_ds_decision_rule_algorithm: None,
             acquirer_config_map: None,
             merchant_category_code: merchant_category_code.or(source.merchant_category_code),
+            payment_intent_expiry: payment_intent_expiry.or(source.payment_intent_expiry),
+            is_auto_reauthorization_enabled: is_auto_reauthorization_enabled
+                .or(source.is_auto_reauthorization_enabled),
+            retry_policy: retry_policy.unwrap_or(source.retry_policy),
+            dynamic_currency_conversion: dynamic_currency_conversion
+                .or(source.dynamic_currency_conversion),
+            payment_method_duplicate_policy: payment_method_duplicate_policy
+                .or(source.payment_method_duplicate_policy),
         }
     }
 }
@@ -747,6 +803,7 @@ pub struct WebhookDetails {
     pub payment_created_enabled: Option<bool>,
     pub payment_succeeded_enabled: Option<bool>,
     pub payment_failed_enabled: Option<bool>,
+    pub payment_authorization_expiring_enabled: Option<bool>,
 }
 
 common_utils::impl_to_sql_from_sql_json!(WebhookDetails);
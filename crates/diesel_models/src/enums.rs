@@ -58,6 +58,7 @@ pub enum RoutingAlgorithmKind {
     Advanced,
     Dynamic,
     ThreeDsDecisionRule,
+    LeastCost,
 }
 
 #[derive(
@@ -79,6 +80,7 @@ pub enum EventObjectType {
     RefundDetails,
     DisputeDetails,
     MandateDetails,
+    PaymentMethodDetails,
     PayoutDetails,
 }
 
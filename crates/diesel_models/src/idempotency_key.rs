@@ -0,0 +1,29 @@
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+
+use crate::schema::idempotency_key;
+
+#[derive(Clone, Debug, Eq, Insertable, PartialEq)]
+#[diesel(table_name = idempotency_key)]
+pub struct IdempotencyKeyNew {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub idempotency_key: String,
+    pub fingerprint: String,
+    pub response: serde_json::Value,
+    pub created_at: time::PrimitiveDateTime,
+    pub expires_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable)]
+#[diesel(
+    table_name = idempotency_key,
+    primary_key(merchant_id, idempotency_key),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct IdempotencyKey {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub idempotency_key: String,
+    pub fingerprint: String,
+    pub response: serde_json::Value,
+    pub created_at: time::PrimitiveDateTime,
+    pub expires_at: time::PrimitiveDateTime,
+}
@@ -23,6 +23,7 @@ pub mod file;
 pub mod fraud_check;
 pub mod generic_link;
 pub mod gsm;
+pub mod idempotency_key;
 #[cfg(feature = "kv_store")]
 pub mod kv;
 pub mod locker_mock_up;
@@ -33,6 +34,7 @@ pub mod merchant_key_store;
 pub mod organization;
 pub mod payment_attempt;
 pub mod payment_intent;
+pub mod payment_ledger;
 pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
@@ -66,10 +68,10 @@ pub type StorageResult<T> = error_stack::Result<T, errors::DatabaseError>;
 pub type PgPooledConn = async_bb8_diesel::Connection<diesel::PgConnection>;
 pub use self::{
     address::*, api_keys::*, callback_mapper::*, cards_info::*, configs::*, customers::*,
-    dispute::*, ephemeral_key::*, events::*, file::*, generic_link::*, locker_mock_up::*,
-    mandate::*, merchant_account::*, merchant_connector_account::*, payment_attempt::*,
-    payment_intent::*, payment_method::*, payout_attempt::*, payouts::*, process_tracker::*,
-    refund::*, reverse_lookup::*, user_authentication_method::*,
+    dispute::*, ephemeral_key::*, events::*, file::*, generic_link::*, idempotency_key::*,
+    locker_mock_up::*, mandate::*, merchant_account::*, merchant_connector_account::*,
+    payment_attempt::*, payment_intent::*, payment_method::*, payout_attempt::*, payouts::*,
+    process_tracker::*, refund::*, reverse_lookup::*, user_authentication_method::*,
 };
 
 /// The types and implementations provided by this module are required for the schema generated by
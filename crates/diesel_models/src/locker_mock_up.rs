@@ -1,4 +1,4 @@
-use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
 
 use crate::schema::locker_mock_up;
 
@@ -40,3 +40,12 @@ pub struct LockerMockUpNew {
     pub nickname: Option<String>,
     pub enc_card_data: Option<String>,
 }
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = locker_mock_up)]
+pub struct LockerMockUpUpdate {
+    pub card_fingerprint: String,
+    pub card_global_fingerprint: String,
+    pub customer_id: Option<common_utils::id_type::CustomerId>,
+    pub enc_card_data: Option<String>,
+}
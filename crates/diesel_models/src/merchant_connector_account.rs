@@ -55,6 +55,7 @@ pub struct MerchantConnectorAccount {
     pub connector_wallets_details: Option<Encryption>,
     pub version: common_enums::ApiVersion,
     pub id: Option<id_type::MerchantConnectorAccountId>,
+    pub fee_schedule: Option<common_types::connector_fees::ConnectorFeeSchedule>,
 }
 
 #[cfg(feature = "v1")]
@@ -101,6 +102,7 @@ pub struct MerchantConnectorAccount {
     pub version: common_enums::ApiVersion,
     pub id: id_type::MerchantConnectorAccountId,
     pub feature_metadata: Option<MerchantConnectorAccountFeatureMetadata>,
+    pub fee_schedule: Option<common_types::connector_fees::ConnectorFeeSchedule>,
 }
 
 #[cfg(feature = "v2")]
@@ -142,6 +144,7 @@ pub struct MerchantConnectorAccountNew {
     pub connector_wallets_details: Option<Encryption>,
     pub version: common_enums::ApiVersion,
     pub id: Option<id_type::MerchantConnectorAccountId>,
+    pub fee_schedule: Option<common_types::connector_fees::ConnectorFeeSchedule>,
 }
 
 #[cfg(feature = "v2")]
@@ -172,6 +175,7 @@ pub struct MerchantConnectorAccountNew {
     pub version: common_enums::ApiVersion,
     pub id: id_type::MerchantConnectorAccountId,
     pub feature_metadata: Option<MerchantConnectorAccountFeatureMetadata>,
+    pub fee_schedule: Option<common_types::connector_fees::ConnectorFeeSchedule>,
 }
 
 #[cfg(feature = "v1")]
@@ -198,6 +202,7 @@ pub struct MerchantConnectorAccountUpdateInternal {
     pub status: Option<storage_enums::ConnectorStatus>,
     pub connector_wallets_details: Option<Encryption>,
     pub additional_merchant_data: Option<Encryption>,
+    pub fee_schedule: Option<Option<common_types::connector_fees::ConnectorFeeSchedule>>,
 }
 
 #[cfg(feature = "v2")]
@@ -222,6 +227,7 @@ pub struct MerchantConnectorAccountUpdateInternal {
     pub connector_wallets_details: Option<Encryption>,
     pub additional_merchant_data: Option<Encryption>,
     pub feature_metadata: Option<MerchantConnectorAccountFeatureMetadata>,
+    pub fee_schedule: Option<Option<common_types::connector_fees::ConnectorFeeSchedule>>,
 }
 
 #[cfg(feature = "v1")]
@@ -246,6 +252,7 @@ impl MerchantConnectorAccountUpdateInternal {
             modified_at: self.modified_at.unwrap_or(source.modified_at),
             pm_auth_config: self.pm_auth_config,
             status: self.status.unwrap_or(source.status),
+            fee_schedule: self.fee_schedule.unwrap_or(source.fee_schedule),
 
             ..source
         }
@@ -270,6 +277,7 @@ impl MerchantConnectorAccountUpdateInternal {
             pm_auth_config: self.pm_auth_config,
             status: self.status.unwrap_or(source.status),
             feature_metadata: self.feature_metadata,
+            fee_schedule: self.fee_schedule.unwrap_or(source.fee_schedule),
 
             ..source
         }
@@ -203,6 +203,13 @@ pub struct PaymentAttempt {
     pub processor_merchant_id: Option<id_type::MerchantId>,
     pub created_by: Option<String>,
     pub setup_future_usage_applied: Option<storage_enums::FutureUsage>,
+    pub authorization_reversal_status: Option<storage_enums::AuthorizationReversalStatus>,
+    pub amount_reversed: Option<MinorUnit>,
+    pub installment_plan: Option<common_types::payments::InstallmentPlan>,
+    pub currency_conversion: Option<common_types::payments::CurrencyConversionDetails>,
+    pub cancellation_reason_code: Option<storage_enums::PaymentCancellationReason>,
+    pub authorized_at: Option<PrimitiveDateTime>,
+    pub captured_at: Option<PrimitiveDateTime>,
 }
 
 #[cfg(feature = "v1")]
@@ -422,6 +429,13 @@ pub struct PaymentAttemptNew {
     pub processor_merchant_id: Option<id_type::MerchantId>,
     pub created_by: Option<String>,
     pub setup_future_usage_applied: Option<storage_enums::FutureUsage>,
+    pub authorization_reversal_status: Option<storage_enums::AuthorizationReversalStatus>,
+    pub amount_reversed: Option<MinorUnit>,
+    pub installment_plan: Option<common_types::payments::InstallmentPlan>,
+    pub currency_conversion: Option<common_types::payments::CurrencyConversionDetails>,
+    pub cancellation_reason_code: Option<storage_enums::PaymentCancellationReason>,
+    pub authorized_at: Option<PrimitiveDateTime>,
+    pub captured_at: Option<PrimitiveDateTime>,
 }
 
 #[cfg(feature = "v1")]
@@ -495,10 +509,28 @@ pub enum PaymentAttemptUpdate {
         order_tax_amount: Option<MinorUnit>,
         connector_mandate_detail: Option<ConnectorMandateReferenceId>,
         card_discovery: Option<storage_enums::CardDiscovery>,
+        capture_on: Option<PrimitiveDateTime>,
+        installment_plan: Option<common_types::payments::InstallmentPlan>,
+        currency_conversion: Option<common_types::payments::CurrencyConversionDetails>,
     },
     VoidUpdate {
         status: storage_enums::AttemptStatus,
         cancellation_reason: Option<String>,
+        cancellation_reason_code: Option<storage_enums::PaymentCancellationReason>,
+        updated_by: String,
+    },
+    AuthorizationExpiryUpdate {
+        authorized_at: PrimitiveDateTime,
+        capture_before: PrimitiveDateTime,
+        updated_by: String,
+    },
+    AuthorizationReversalUpdate {
+        status: Option<storage_enums::AttemptStatus>,
+        authorization_reversal_status: storage_enums::AuthorizationReversalStatus,
+        amount_reversed: Option<MinorUnit>,
+        amount_capturable: Option<MinorUnit>,
+        error_code: Option<Option<String>>,
+        error_message: Option<Option<String>>,
         updated_by: String,
     },
     PaymentMethodDetailsUpdate {
@@ -937,6 +969,14 @@ pub struct PaymentAttemptUpdateInternal {
     pub issuer_error_code: Option<String>,
     pub issuer_error_message: Option<String>,
     pub setup_future_usage_applied: Option<storage_enums::FutureUsage>,
+    pub authorization_reversal_status: Option<storage_enums::AuthorizationReversalStatus>,
+    pub amount_reversed: Option<MinorUnit>,
+    pub installment_plan: Option<common_types::payments::InstallmentPlan>,
+    pub currency_conversion: Option<common_types::payments::CurrencyConversionDetails>,
+    pub cancellation_reason_code: Option<storage_enums::PaymentCancellationReason>,
+    pub authorized_at: Option<PrimitiveDateTime>,
+    pub captured_at: Option<PrimitiveDateTime>,
+    pub capture_on: Option<PrimitiveDateTime>,
 }
 
 #[cfg(feature = "v1")]
@@ -971,6 +1011,11 @@ impl PaymentAttemptUpdateInternal {
             .and_then(|card| card.get("card_network"))
             .and_then(|network| network.as_str())
             .map(|network| network.to_string());
+        if update_internal.status == Some(storage_enums::AttemptStatus::Charged)
+            && source.captured_at.is_none()
+        {
+            update_internal.captured_at = Some(update_internal.modified_at);
+        }
         update_internal
     }
 }
@@ -1126,6 +1171,14 @@ impl PaymentAttemptUpdate {
             issuer_error_code,
             issuer_error_message,
             setup_future_usage_applied,
+            authorization_reversal_status,
+            amount_reversed,
+            installment_plan,
+            currency_conversion,
+            cancellation_reason_code,
+            authorized_at,
+            captured_at,
+            capture_on,
         } = PaymentAttemptUpdateInternal::from(self).populate_derived_fields(&source);
         PaymentAttempt {
             amount: amount.unwrap_or(source.amount),
@@ -1192,6 +1245,15 @@ impl PaymentAttemptUpdate {
             issuer_error_message: issuer_error_message.or(source.issuer_error_message),
             setup_future_usage_applied: setup_future_usage_applied
                 .or(source.setup_future_usage_applied),
+            authorization_reversal_status: authorization_reversal_status
+                .or(source.authorization_reversal_status),
+            amount_reversed: amount_reversed.or(source.amount_reversed),
+            installment_plan: installment_plan.or(source.installment_plan),
+            currency_conversion: currency_conversion.or(source.currency_conversion),
+            cancellation_reason_code: cancellation_reason_code.or(source.cancellation_reason_code),
+            authorized_at: authorized_at.or(source.authorized_at),
+            captured_at: captured_at.or(source.captured_at),
+            capture_on: capture_on.or(source.capture_on),
             ..source
         }
     }
@@ -2250,6 +2312,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 issuer_error_code: None,
                 issuer_error_message: None,
                 setup_future_usage_applied: None,
+                authorization_reversal_status: None,
+                amount_reversed: None,
+                installment_plan: None,
+                currency_conversion: None,
+                cancellation_reason_code: None,
+                authorized_at: None,
+                captured_at: None,
+                capture_on: None,
             },
             PaymentAttemptUpdate::AuthenticationTypeUpdate {
                 authentication_type,
@@ -2312,6 +2382,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 issuer_error_code: None,
                 issuer_error_message: None,
                 setup_future_usage_applied: None,
+                authorization_reversal_status: None,
+                amount_reversed: None,
+                installment_plan: None,
+                currency_conversion: None,
+                cancellation_reason_code: None,
+                authorized_at: None,
+                captured_at: None,
+                capture_on: None,
             },
             PaymentAttemptUpdate::ConfirmUpdate {
                 amount,
@@ -2348,6 +2426,9 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount,
                 connector_mandate_detail,
                 card_discovery,
+                capture_on,
+                installment_plan,
+                currency_conversion,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -2406,10 +2487,19 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 issuer_error_code: None,
                 issuer_error_message: None,
                 setup_future_usage_applied: None,
+                authorization_reversal_status: None,
+                amount_reversed: None,
+                installment_plan,
+                currency_conversion,
+                cancellation_reason_code: None,
+                authorized_at: None,
+                captured_at: None,
+                capture_on,
             },
             PaymentAttemptUpdate::VoidUpdate {
                 status,
                 cancellation_reason,
+                cancellation_reason_code,
                 updated_by,
             } => Self {
                 status: Some(status),
@@ -2469,6 +2559,160 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 issuer_error_code: None,
                 issuer_error_message: None,
                 setup_future_usage_applied: None,
+                authorization_reversal_status: None,
+                amount_reversed: None,
+                installment_plan: None,
+                currency_conversion: None,
+                cancellation_reason_code,
+                authorized_at: None,
+                captured_at: None,
+                capture_on: None,
+            },
+            PaymentAttemptUpdate::AuthorizationExpiryUpdate {
+                authorized_at,
+                capture_before,
+                updated_by,
+            } => Self {
+                capture_before: Some(capture_before),
+                modified_at: common_utils::date_time::now(),
+                updated_by,
+                status: None,
+                cancellation_reason: None,
+                amount: None,
+                net_amount: None,
+                currency: None,
+                connector_transaction_id: None,
+                amount_to_capture: None,
+                connector: None,
+                authentication_type: None,
+                payment_method: None,
+                error_message: None,
+                payment_method_id: None,
+                mandate_id: None,
+                browser_info: None,
+                payment_token: None,
+                error_code: None,
+                connector_metadata: None,
+                payment_method_data: None,
+                payment_method_type: None,
+                payment_experience: None,
+                business_sub_label: None,
+                straight_through_algorithm: None,
+                preprocessing_step_id: None,
+                error_reason: None,
+                capture_method: None,
+                connector_response_reference_id: None,
+                multiple_capture_count: None,
+                surcharge_amount: None,
+                tax_amount: None,
+                amount_capturable: None,
+                merchant_connector_id: None,
+                authentication_data: None,
+                encoded_data: None,
+                unified_code: None,
+                unified_message: None,
+                external_three_ds_authentication_attempted: None,
+                authentication_connector: None,
+                authentication_id: None,
+                fingerprint_id: None,
+                payment_method_billing_address_id: None,
+                client_source: None,
+                client_version: None,
+                customer_acceptance: None,
+                card_network: None,
+                shipping_cost: None,
+                order_tax_amount: None,
+                extended_authorization_applied: None,
+                processor_transaction_data: None,
+                connector_mandate_detail: None,
+                card_discovery: None,
+                charges: None,
+                issuer_error_code: None,
+                issuer_error_message: None,
+                setup_future_usage_applied: None,
+                authorization_reversal_status: None,
+                amount_reversed: None,
+                installment_plan: None,
+                currency_conversion: None,
+                cancellation_reason_code: None,
+                authorized_at: Some(authorized_at),
+                captured_at: None,
+                capture_on: None,
+            },
+            PaymentAttemptUpdate::AuthorizationReversalUpdate {
+                status,
+                authorization_reversal_status,
+                amount_reversed,
+                amount_capturable,
+                error_code,
+                error_message,
+                updated_by,
+            } => Self {
+                status,
+                cancellation_reason: None,
+                modified_at: common_utils::date_time::now(),
+                updated_by,
+                amount: None,
+                net_amount: None,
+                currency: None,
+                connector_transaction_id: None,
+                amount_to_capture: None,
+                connector: None,
+                authentication_type: None,
+                payment_method: None,
+                error_message,
+                payment_method_id: None,
+                mandate_id: None,
+                browser_info: None,
+                payment_token: None,
+                error_code,
+                connector_metadata: None,
+                payment_method_data: None,
+                payment_method_type: None,
+                payment_experience: None,
+                business_sub_label: None,
+                straight_through_algorithm: None,
+                preprocessing_step_id: None,
+                error_reason: None,
+                capture_method: None,
+                connector_response_reference_id: None,
+                multiple_capture_count: None,
+                surcharge_amount: None,
+                tax_amount: None,
+                amount_capturable,
+                merchant_connector_id: None,
+                authentication_data: None,
+                encoded_data: None,
+                unified_code: None,
+                unified_message: None,
+                external_three_ds_authentication_attempted: None,
+                authentication_connector: None,
+                authentication_id: None,
+                fingerprint_id: None,
+                payment_method_billing_address_id: None,
+                client_source: None,
+                client_version: None,
+                customer_acceptance: None,
+                card_network: None,
+                shipping_cost: None,
+                order_tax_amount: None,
+                capture_before: None,
+                extended_authorization_applied: None,
+                processor_transaction_data: None,
+                connector_mandate_detail: None,
+                card_discovery: None,
+                charges: None,
+                issuer_error_code: None,
+                issuer_error_message: None,
+                setup_future_usage_applied: None,
+                authorization_reversal_status: Some(authorization_reversal_status),
+                amount_reversed,
+                installment_plan: None,
+                currency_conversion: None,
+                cancellation_reason_code: None,
+                authorized_at: None,
+                captured_at: None,
+                capture_on: None,
             },
             PaymentAttemptUpdate::RejectUpdate {
                 status,
@@ -2533,6 +2777,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 issuer_error_code: None,
                 issuer_error_message: None,
                 setup_future_usage_applied: None,
+                authorization_reversal_status: None,
+                amount_reversed: None,
+                installment_plan: None,
+                currency_conversion: None,
+                cancellation_reason_code: None,
+                authorized_at: None,
+                captured_at: None,
+                capture_on: None,
             },
             PaymentAttemptUpdate::BlocklistUpdate {
                 status,
@@ -2597,6 +2849,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 issuer_error_code: None,
                 issuer_error_message: None,
                 setup_future_usage_applied: None,
+                authorization_reversal_status: None,
+                amount_reversed: None,
+                installment_plan: None,
+                currency_conversion: None,
+                cancellation_reason_code: None,
+                authorized_at: None,
+                captured_at: None,
+                capture_on: None,
             },
             PaymentAttemptUpdate::ConnectorMandateDetailUpdate {
                 connector_mandate_detail,
@@ -2659,6 +2919,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 issuer_error_code: None,
                 issuer_error_message: None,
                 setup_future_usage_applied: None,
+                authorization_reversal_status: None,
+                amount_reversed: None,
+                installment_plan: None,
+                currency_conversion: None,
+                cancellation_reason_code: None,
+                authorized_at: None,
+                captured_at: None,
+                capture_on: None,
             },
             PaymentAttemptUpdate::PaymentMethodDetailsUpdate {
                 payment_method_id,
@@ -2721,6 +2989,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 issuer_error_code: None,
                 issuer_error_message: None,
                 setup_future_usage_applied: None,
+                authorization_reversal_status: None,
+                amount_reversed: None,
+                installment_plan: None,
+                currency_conversion: None,
+                cancellation_reason_code: None,
+                authorized_at: None,
+                captured_at: None,
+                capture_on: None,
             },
             PaymentAttemptUpdate::ResponseUpdate {
                 status,
@@ -2811,6 +3087,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     issuer_error_code: None,
                     issuer_error_message: None,
                     setup_future_usage_applied,
+                    authorization_reversal_status: None,
+                    amount_reversed: None,
+                    installment_plan: None,
+                    currency_conversion: None,
+                    cancellation_reason_code: None,
+                    authorized_at: None,
+                    captured_at: None,
+                    capture_on: None,
                 }
             }
             PaymentAttemptUpdate::ErrorUpdate {
@@ -2892,6 +3176,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     card_discovery: None,
                     charges: None,
                     setup_future_usage_applied: None,
+                    authorization_reversal_status: None,
+                    amount_reversed: None,
+                    installment_plan: None,
+                    currency_conversion: None,
+                    cancellation_reason_code: None,
+                    authorized_at: None,
+                    captured_at: None,
+                    capture_on: None,
                 }
             }
             PaymentAttemptUpdate::StatusUpdate { status, updated_by } => Self {
@@ -2952,6 +3244,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 issuer_error_code: None,
                 issuer_error_message: None,
                 setup_future_usage_applied: None,
+                authorization_reversal_status: None,
+                amount_reversed: None,
+                installment_plan: None,
+                currency_conversion: None,
+                cancellation_reason_code: None,
+                authorized_at: None,
+                captured_at: None,
+                capture_on: None,
             },
             PaymentAttemptUpdate::UpdateTrackers {
                 payment_token,
@@ -3020,6 +3320,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 issuer_error_code: None,
                 issuer_error_message: None,
                 setup_future_usage_applied: None,
+                authorization_reversal_status: None,
+                amount_reversed: None,
+                installment_plan: None,
+                currency_conversion: None,
+                cancellation_reason_code: None,
+                authorized_at: None,
+                captured_at: None,
+                capture_on: None,
             },
             PaymentAttemptUpdate::UnresolvedResponseUpdate {
                 status,
@@ -3095,6 +3403,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     issuer_error_code: None,
                     issuer_error_message: None,
                     setup_future_usage_applied: None,
+                    authorization_reversal_status: None,
+                    amount_reversed: None,
+                    installment_plan: None,
+                    currency_conversion: None,
+                    cancellation_reason_code: None,
+                    authorized_at: None,
+                    captured_at: None,
+                    capture_on: None,
                 }
             }
             PaymentAttemptUpdate::PreprocessingUpdate {
@@ -3169,6 +3485,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     issuer_error_code: None,
                     issuer_error_message: None,
                     setup_future_usage_applied: None,
+                    authorization_reversal_status: None,
+                    amount_reversed: None,
+                    installment_plan: None,
+                    currency_conversion: None,
+                    cancellation_reason_code: None,
+                    authorized_at: None,
+                    captured_at: None,
+                    capture_on: None,
                 }
             }
             PaymentAttemptUpdate::CaptureUpdate {
@@ -3233,6 +3557,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 issuer_error_code: None,
                 issuer_error_message: None,
                 setup_future_usage_applied: None,
+                authorization_reversal_status: None,
+                amount_reversed: None,
+                installment_plan: None,
+                currency_conversion: None,
+                cancellation_reason_code: None,
+                authorized_at: None,
+                captured_at: None,
+                capture_on: None,
             },
             PaymentAttemptUpdate::AmountToCaptureUpdate {
                 status,
@@ -3296,6 +3628,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 issuer_error_code: None,
                 issuer_error_message: None,
                 setup_future_usage_applied: None,
+                authorization_reversal_status: None,
+                amount_reversed: None,
+                installment_plan: None,
+                currency_conversion: None,
+                cancellation_reason_code: None,
+                authorized_at: None,
+                captured_at: None,
+                capture_on: None,
             },
             PaymentAttemptUpdate::ConnectorResponse {
                 authentication_data,
@@ -3368,6 +3708,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     issuer_error_code: None,
                     issuer_error_message: None,
                     setup_future_usage_applied: None,
+                    authorization_reversal_status: None,
+                    amount_reversed: None,
+                    installment_plan: None,
+                    currency_conversion: None,
+                    cancellation_reason_code: None,
+                    authorized_at: None,
+                    captured_at: None,
+                    capture_on: None,
                 }
             }
             PaymentAttemptUpdate::IncrementalAuthorizationAmountUpdate {
@@ -3431,6 +3779,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 issuer_error_code: None,
                 issuer_error_message: None,
                 setup_future_usage_applied: None,
+                authorization_reversal_status: None,
+                amount_reversed: None,
+                installment_plan: None,
+                currency_conversion: None,
+                cancellation_reason_code: None,
+                authorized_at: None,
+                captured_at: None,
+                capture_on: None,
             },
             PaymentAttemptUpdate::AuthenticationUpdate {
                 status,
@@ -3496,6 +3852,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 issuer_error_code: None,
                 issuer_error_message: None,
                 setup_future_usage_applied: None,
+                authorization_reversal_status: None,
+                amount_reversed: None,
+                installment_plan: None,
+                currency_conversion: None,
+                cancellation_reason_code: None,
+                authorized_at: None,
+                captured_at: None,
+                capture_on: None,
             },
             PaymentAttemptUpdate::ManualUpdate {
                 status,
@@ -3570,6 +3934,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     issuer_error_code: None,
                     issuer_error_message: None,
                     setup_future_usage_applied: None,
+                    authorization_reversal_status: None,
+                    amount_reversed: None,
+                    installment_plan: None,
+                    currency_conversion: None,
+                    cancellation_reason_code: None,
+                    authorized_at: None,
+                    captured_at: None,
+                    capture_on: None,
                 }
             }
             PaymentAttemptUpdate::PostSessionTokensUpdate {
@@ -3633,6 +4005,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 issuer_error_code: None,
                 issuer_error_message: None,
                 setup_future_usage_applied: None,
+                authorization_reversal_status: None,
+                amount_reversed: None,
+                installment_plan: None,
+                currency_conversion: None,
+                cancellation_reason_code: None,
+                authorized_at: None,
+                captured_at: None,
+                capture_on: None,
             },
         }
     }
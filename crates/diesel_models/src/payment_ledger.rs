@@ -0,0 +1,35 @@
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+
+use crate::schema::payment_ledger_entry;
+
+#[derive(Clone, Debug, Eq, Insertable, PartialEq)]
+#[diesel(table_name = payment_ledger_entry)]
+pub struct PaymentLedgerEntryNew {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub attempt_id: String,
+    pub reference_id: String,
+    pub entry_type: common_enums::LedgerEntryType,
+    pub debit_account: common_enums::LedgerAccount,
+    pub credit_account: common_enums::LedgerAccount,
+    pub amount: common_utils::types::MinorUnit,
+    pub currency: common_enums::Currency,
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = payment_ledger_entry, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct PaymentLedgerEntry {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub attempt_id: String,
+    pub reference_id: String,
+    pub entry_type: common_enums::LedgerEntryType,
+    pub debit_account: common_enums::LedgerAccount,
+    pub credit_account: common_enums::LedgerAccount,
+    pub amount: common_utils::types::MinorUnit,
+    pub currency: common_enums::Currency,
+    pub created_at: time::PrimitiveDateTime,
+}
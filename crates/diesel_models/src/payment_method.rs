@@ -60,6 +60,7 @@ pub struct PaymentMethod {
     pub network_token_requestor_reference_id: Option<String>,
     pub network_token_locker_id: Option<String>,
     pub network_token_payment_method_data: Option<Encryption>,
+    pub network_token_status: Option<storage_enums::NetworkTokenStatus>,
 }
 
 #[cfg(feature = "v2")]
@@ -143,6 +144,7 @@ pub struct PaymentMethodNew {
     pub network_token_requestor_reference_id: Option<String>,
     pub network_token_locker_id: Option<String>,
     pub network_token_payment_method_data: Option<Encryption>,
+    pub network_token_status: Option<storage_enums::NetworkTokenStatus>,
 }
 
 #[cfg(feature = "v2")]
@@ -220,6 +222,9 @@ pub enum PaymentMethodUpdate {
     StatusUpdate {
         status: Option<storage_enums::PaymentMethodStatus>,
     },
+    LockerIdUpdate {
+        locker_id: Option<String>,
+    },
     AdditionalDataUpdate {
         payment_method_data: Option<Encryption>,
         status: Option<storage_enums::PaymentMethodStatus>,
@@ -239,6 +244,10 @@ pub enum PaymentMethodUpdate {
         network_token_locker_id: Option<String>,
         network_token_payment_method_data: Option<Encryption>,
     },
+    NetworkTokenStatusUpdate {
+        network_token_status: Option<storage_enums::NetworkTokenStatus>,
+        network_token_payment_method_data: Option<Encryption>,
+    },
     ConnectorNetworkTransactionIdAndMandateDetailsUpdate {
         connector_mandate_details: Option<pii::SecretSerdeValue>,
         network_transaction_id: Option<Secret<String>>,
@@ -282,6 +291,9 @@ pub enum PaymentMethodUpdate {
     ConnectorMandateDetailsUpdate {
         connector_mandate_details: Option<CommonMandateReference>,
     },
+    LockerFingerprintIdUpdate {
+        locker_fingerprint_id: String,
+    },
 }
 
 impl PaymentMethodUpdate {
@@ -388,6 +400,7 @@ pub struct PaymentMethodUpdateInternal {
     network_token_locker_id: Option<String>,
     network_token_payment_method_data: Option<Encryption>,
     scheme: Option<String>,
+    network_token_status: Option<storage_enums::NetworkTokenStatus>,
 }
 
 #[cfg(feature = "v1")]
@@ -410,6 +423,7 @@ impl PaymentMethodUpdateInternal {
             network_token_locker_id,
             network_token_payment_method_data,
             scheme,
+            network_token_status,
         } = self;
 
         PaymentMethod {
@@ -450,6 +464,7 @@ impl PaymentMethodUpdateInternal {
             network_token_locker_id: network_token_locker_id.or(source.network_token_locker_id),
             network_token_payment_method_data: network_token_payment_method_data
                 .or(source.network_token_payment_method_data),
+            network_token_status: network_token_status.or(source.network_token_status),
         }
     }
 }
@@ -478,6 +493,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
                 scheme: None,
+                network_token_status: None,
             },
             PaymentMethodUpdate::PaymentMethodDataUpdate {
                 payment_method_data,
@@ -498,6 +514,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
                 scheme: None,
+                network_token_status: None,
             },
             PaymentMethodUpdate::LastUsedUpdate { last_used_at } => Self {
                 metadata: None,
@@ -516,6 +533,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
                 scheme: None,
+                network_token_status: None,
             },
             PaymentMethodUpdate::UpdatePaymentMethodDataAndLastUsed {
                 payment_method_data,
@@ -538,6 +556,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
                 scheme,
+                network_token_status: None,
             },
             PaymentMethodUpdate::NetworkTransactionIdAndStatusUpdate {
                 network_transaction_id,
@@ -559,6 +578,26 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
                 scheme: None,
+                network_token_status: None,
+            },
+            PaymentMethodUpdate::LockerIdUpdate { locker_id } => Self {
+                metadata: None,
+                payment_method_data: None,
+                last_used_at: None,
+                network_transaction_id: None,
+                status: None,
+                locker_id,
+                network_token_requestor_reference_id: None,
+                payment_method: None,
+                connector_mandate_details: None,
+                updated_by: None,
+                payment_method_issuer: None,
+                payment_method_type: None,
+                last_modified: common_utils::date_time::now(),
+                network_token_locker_id: None,
+                network_token_payment_method_data: None,
+                scheme: None,
+                network_token_status: None,
             },
             PaymentMethodUpdate::StatusUpdate { status } => Self {
                 metadata: None,
@@ -577,6 +616,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
                 scheme: None,
+                network_token_status: None,
             },
             PaymentMethodUpdate::AdditionalDataUpdate {
                 payment_method_data,
@@ -589,6 +629,9 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_locker_id,
                 network_token_payment_method_data,
             } => Self {
+                network_token_status: get_network_token_status_for_new_token(
+                    network_token_requestor_reference_id.as_ref(),
+                ),
                 metadata: None,
                 payment_method_data,
                 last_used_at: None,
@@ -625,12 +668,16 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
                 scheme: None,
+                network_token_status: None,
             },
             PaymentMethodUpdate::NetworkTokenDataUpdate {
                 network_token_requestor_reference_id,
                 network_token_locker_id,
                 network_token_payment_method_data,
             } => Self {
+                network_token_status: get_network_token_status_for_new_token(
+                    network_token_requestor_reference_id.as_ref(),
+                ),
                 metadata: None,
                 payment_method_data: None,
                 last_used_at: None,
@@ -648,6 +695,28 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_payment_method_data,
                 scheme: None,
             },
+            PaymentMethodUpdate::NetworkTokenStatusUpdate {
+                network_token_status,
+                network_token_payment_method_data,
+            } => Self {
+                metadata: None,
+                payment_method_data: None,
+                last_used_at: None,
+                status: None,
+                locker_id: None,
+                payment_method: None,
+                connector_mandate_details: None,
+                updated_by: None,
+                payment_method_issuer: None,
+                payment_method_type: None,
+                last_modified: common_utils::date_time::now(),
+                network_transaction_id: None,
+                network_token_requestor_reference_id: None,
+                network_token_locker_id: None,
+                network_token_payment_method_data,
+                scheme: None,
+                network_token_status,
+            },
             PaymentMethodUpdate::ConnectorNetworkTransactionIdAndMandateDetailsUpdate {
                 connector_mandate_details,
                 network_transaction_id,
@@ -669,11 +738,21 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
                 scheme: None,
+                network_token_status: None,
             },
         }
     }
 }
 
+/// A network token stored on the payment method is active until the token service notifies
+/// otherwise
+#[cfg(feature = "v1")]
+fn get_network_token_status_for_new_token(
+    network_token_requestor_reference_id: Option<&String>,
+) -> Option<storage_enums::NetworkTokenStatus> {
+    network_token_requestor_reference_id.map(|_| storage_enums::NetworkTokenStatus::Active)
+}
+
 #[cfg(feature = "v2")]
 impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
     fn from(payment_method_update: PaymentMethodUpdate) -> Self {
@@ -820,6 +899,25 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 locker_fingerprint_id: None,
                 external_vault_source: None,
             },
+            PaymentMethodUpdate::LockerFingerprintIdUpdate {
+                locker_fingerprint_id,
+            } => Self {
+                payment_method_data: None,
+                last_used_at: None,
+                status: None,
+                locker_id: None,
+                payment_method_type_v2: None,
+                connector_mandate_details: None,
+                network_transaction_id: None,
+                updated_by: None,
+                payment_method_subtype: None,
+                last_modified: common_utils::date_time::now(),
+                network_token_locker_id: None,
+                network_token_requestor_reference_id: None,
+                network_token_payment_method_data: None,
+                locker_fingerprint_id: Some(locker_fingerprint_id),
+                external_vault_source: None,
+            },
         }
     }
 }
@@ -868,6 +966,7 @@ impl From<&PaymentMethodNew> for PaymentMethod {
             network_token_payment_method_data: payment_method_new
                 .network_token_payment_method_data
                 .clone(),
+            network_token_status: payment_method_new.network_token_status,
         }
     }
 }
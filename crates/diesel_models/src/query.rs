@@ -21,6 +21,7 @@ pub mod fraud_check;
 pub mod generic_link;
 pub mod generics;
 pub mod gsm;
+pub mod idempotency_key;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
@@ -29,6 +30,7 @@ pub mod merchant_key_store;
 pub mod organization;
 pub mod payment_attempt;
 pub mod payment_intent;
+pub mod payment_ledger;
 pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
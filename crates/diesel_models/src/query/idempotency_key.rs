@@ -0,0 +1,44 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    idempotency_key::{IdempotencyKey, IdempotencyKeyNew},
+    schema::idempotency_key::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl IdempotencyKeyNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<IdempotencyKey> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl IdempotencyKey {
+    pub async fn find_by_merchant_id_idempotency_key(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        idempotency_key: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::idempotency_key.eq(idempotency_key.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn delete_by_merchant_id_idempotency_key(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        idempotency_key: &str,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::idempotency_key.eq(idempotency_key.to_owned())),
+        )
+        .await
+    }
+}
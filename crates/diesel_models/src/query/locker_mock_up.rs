@@ -1,8 +1,8 @@
-use diesel::{associations::HasTable, ExpressionMethods};
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
 
 use super::generics;
 use crate::{
-    locker_mock_up::{LockerMockUp, LockerMockUpNew},
+    locker_mock_up::{LockerMockUp, LockerMockUpNew, LockerMockUpUpdate},
     schema::locker_mock_up::dsl,
     PgPooledConn, StorageResult,
 };
@@ -29,4 +29,39 @@ impl LockerMockUp {
         )
         .await
     }
+
+    pub async fn delete_by_merchant_id_card_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        card_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::card_id.eq(card_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_card_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        card_id: &str,
+        locker_mock_up_update: LockerMockUpUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::card_id.eq(card_id.to_owned())),
+            locker_mock_up_update,
+        )
+        .await
+    }
 }
@@ -175,6 +175,54 @@ impl PaymentMethod {
         .await
     }
 
+    /// Finds the payment methods of the merchant with the status which were created at or before
+    /// `created_before`, in the order of their creation.
+    ///
+    /// The results are paginated by the `(created_at, payment_method_id)` of the last payment
+    /// method of the previous page rather than an offset, so that payment methods are neither
+    /// skipped nor repeated when other payment methods change their status between pages.
+    pub async fn find_by_merchant_id_status_after(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        status: storage_enums::PaymentMethodStatus,
+        created_before: time::PrimitiveDateTime,
+        after: Option<(time::PrimitiveDateTime, &str)>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        let predicate = dsl::merchant_id
+            .eq(merchant_id.to_owned())
+            .and(dsl::status.eq(status))
+            .and(dsl::created_at.le(created_before));
+        let order = Some((dsl::created_at.asc(), dsl::payment_method_id.asc()));
+
+        match after {
+            Some((created_at, payment_method_id)) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    predicate.and(
+                        dsl::created_at.gt(created_at).or(dsl::created_at
+                            .eq(created_at)
+                            .and(dsl::payment_method_id.gt(payment_method_id.to_owned()))),
+                    ),
+                    Some(limit),
+                    None,
+                    order,
+                )
+                .await
+            }
+            None => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    predicate,
+                    Some(limit),
+                    None,
+                    order,
+                )
+                .await
+            }
+        }
+    }
+
     pub async fn update_with_payment_method_id(
         self,
         conn: &PgPooledConn,
@@ -278,6 +326,57 @@ impl PaymentMethod {
         .await
     }
 
+    pub async fn find_by_merchant_id_fingerprint_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fingerprint_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::locker_fingerprint_id.eq(fingerprint_id.to_owned())),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    /// Finds a page of the payment methods of the merchant ordered by their id, after the payment
+    /// method with the id
+    pub async fn find_by_merchant_id_after_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        after: Option<&common_utils::id_type::GlobalPaymentMethodId>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        let predicate = dsl::merchant_id.eq(merchant_id.to_owned());
+
+        match after {
+            Some(after) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    predicate.and(pm_id.gt(after.to_owned())),
+                    Some(limit),
+                    None,
+                    Some(pm_id.asc()),
+                )
+                .await
+            }
+            None => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    predicate,
+                    Some(limit),
+                    None,
+                    Some(pm_id.asc()),
+                )
+                .await
+            }
+        }
+    }
+
     pub async fn get_count_by_merchant_id_status(
         conn: &PgPooledConn,
         merchant_id: &common_utils::id_type::MerchantId,
@@ -1,4 +1,6 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
+use diesel::{
+    associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table, TextExpressionMethods,
+};
 use router_env::{instrument, tracing};
 use time::PrimitiveDateTime;
 
@@ -91,6 +93,34 @@ impl ProcessTracker {
         .await
     }
 
+    /// Finds the processes of the runner in any of the statuses, whose id starts with the prefix
+    #[instrument(skip(conn))]
+    pub async fn find_processes_by_runner_id_prefix_status(
+        conn: &PgPooledConn,
+        runner: &str,
+        id_prefix: &str,
+        statuses: Vec<enums::ProcessTrackerStatus>,
+    ) -> StorageResult<Vec<Self>> {
+        let id_pattern = format!(
+            "{}%",
+            id_prefix
+                .replace('\\', "\\\\")
+                .replace('%', "\\%")
+                .replace('_', "\\_")
+        );
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::runner
+                .eq(runner.to_owned())
+                .and(dsl::id.like(id_pattern))
+                .and(dsl::status.eq_any(statuses)),
+            None,
+            None,
+            Some(dsl::schedule_time.asc()),
+        )
+        .await
+    }
+
     #[instrument(skip(conn))]
     pub async fn find_processes_to_clean(
         conn: &PgPooledConn,
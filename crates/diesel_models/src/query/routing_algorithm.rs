@@ -60,6 +60,7 @@ impl RoutingAlgorithm {
                 dsl::created_at,
                 dsl::modified_at,
                 dsl::algorithm_for,
+                dsl::parent_algorithm_id,
             ))
             .filter(
                 dsl::algorithm_id
@@ -76,6 +77,7 @@ impl RoutingAlgorithm {
                 PrimitiveDateTime,
                 PrimitiveDateTime,
                 enums::TransactionType,
+                Option<common_utils::id_type::RoutingId>,
             )>(conn)
             .await
             .change_context(DatabaseError::Others)?
@@ -92,6 +94,7 @@ impl RoutingAlgorithm {
                     created_at,
                     modified_at,
                     algorithm_for,
+                    parent_algorithm_id,
                 )| {
                     RoutingProfileMetadata {
                         profile_id,
@@ -102,6 +105,7 @@ impl RoutingAlgorithm {
                         created_at,
                         modified_at,
                         algorithm_for,
+                        parent_algorithm_id,
                     }
                 },
             )
@@ -123,6 +127,7 @@ impl RoutingAlgorithm {
                 dsl::created_at,
                 dsl::modified_at,
                 dsl::algorithm_for,
+                dsl::parent_algorithm_id,
             ))
             .filter(dsl::profile_id.eq(profile_id.to_owned()))
             .limit(limit)
@@ -136,6 +141,7 @@ impl RoutingAlgorithm {
                 PrimitiveDateTime,
                 PrimitiveDateTime,
                 enums::TransactionType,
+                Option<common_utils::id_type::RoutingId>,
             )>(conn)
             .await
             .change_context(DatabaseError::Others)?
@@ -150,6 +156,7 @@ impl RoutingAlgorithm {
                     created_at,
                     modified_at,
                     algorithm_for,
+                    parent_algorithm_id,
                 )| {
                     RoutingProfileMetadata {
                         algorithm_id,
@@ -160,6 +167,7 @@ impl RoutingAlgorithm {
                         modified_at,
                         algorithm_for,
                         profile_id,
+                        parent_algorithm_id,
                     }
                 },
             )
@@ -182,6 +190,7 @@ impl RoutingAlgorithm {
                 dsl::created_at,
                 dsl::modified_at,
                 dsl::algorithm_for,
+                dsl::parent_algorithm_id,
             ))
             .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
             .limit(limit)
@@ -196,6 +205,7 @@ impl RoutingAlgorithm {
                 PrimitiveDateTime,
                 PrimitiveDateTime,
                 enums::TransactionType,
+                Option<common_utils::id_type::RoutingId>,
             )>(conn)
             .await
             .change_context(DatabaseError::Others)?
@@ -210,6 +220,7 @@ impl RoutingAlgorithm {
                     created_at,
                     modified_at,
                     algorithm_for,
+                    parent_algorithm_id,
                 )| {
                     RoutingProfileMetadata {
                         profile_id,
@@ -220,6 +231,7 @@ impl RoutingAlgorithm {
                         created_at,
                         modified_at,
                         algorithm_for,
+                        parent_algorithm_id,
                     }
                 },
             )
@@ -243,6 +255,7 @@ impl RoutingAlgorithm {
                 dsl::created_at,
                 dsl::modified_at,
                 dsl::algorithm_for,
+                dsl::parent_algorithm_id,
             ))
             .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
             .filter(dsl::algorithm_for.eq(transaction_type.to_owned()))
@@ -258,6 +271,7 @@ impl RoutingAlgorithm {
                 PrimitiveDateTime,
                 PrimitiveDateTime,
                 enums::TransactionType,
+                Option<common_utils::id_type::RoutingId>,
             )>(conn)
             .await
             .change_context(DatabaseError::Others)?
@@ -272,6 +286,7 @@ impl RoutingAlgorithm {
                     created_at,
                     modified_at,
                     algorithm_for,
+                    parent_algorithm_id,
                 )| {
                     RoutingProfileMetadata {
                         profile_id,
@@ -282,6 +297,7 @@ impl RoutingAlgorithm {
                         created_at,
                         modified_at,
                         algorithm_for,
+                        parent_algorithm_id,
                     }
                 },
             )
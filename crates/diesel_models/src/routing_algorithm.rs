@@ -18,6 +18,7 @@ pub struct RoutingAlgorithm {
     pub modified_at: time::PrimitiveDateTime,
     pub algorithm_for: enums::TransactionType,
     pub decision_engine_routing_id: Option<String>,
+    pub parent_algorithm_id: Option<id_type::RoutingId>,
 }
 
 pub struct RoutingAlgorithmMetadata {
@@ -39,4 +40,5 @@ pub struct RoutingProfileMetadata {
     pub created_at: time::PrimitiveDateTime,
     pub modified_at: time::PrimitiveDateTime,
     pub algorithm_for: enums::TransactionType,
+    pub parent_algorithm_id: Option<id_type::RoutingId>,
 }
@@ -231,6 +231,10 @@ diesel::table! {
         acquirer_config_map -> Nullable<Jsonb>,
         #[max_length = 16]
         merchant_category_code -> Nullable<Varchar>,
+        payment_intent_expiry -> Nullable<Int8>,
+        is_auto_reauthorization_enabled -> Nullable<Bool>,
+        retry_policy -> Nullable<Jsonb>,
+        dynamic_currency_conversion -> Nullable<Jsonb>,
     }
 }
 
@@ -604,6 +608,23 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    idempotency_key (merchant_id, idempotency_key) {
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 255]
+        idempotency_key -> Varchar,
+        #[max_length = 64]
+        fingerprint -> Varchar,
+        response -> Jsonb,
+        created_at -> Timestamp,
+        expires_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -800,6 +821,7 @@ diesel::table! {
         version -> ApiVersion,
         #[max_length = 64]
         id -> Nullable<Varchar>,
+        fee_schedule -> Nullable<Jsonb>,
     }
 }
 
@@ -950,6 +972,15 @@ diesel::table! {
         #[max_length = 255]
         created_by -> Nullable<Varchar>,
         setup_future_usage_applied -> Nullable<FutureUsage>,
+        #[max_length = 32]
+        authorization_reversal_status -> Nullable<Varchar>,
+        amount_reversed -> Nullable<Int8>,
+        installment_plan -> Nullable<Jsonb>,
+        currency_conversion -> Nullable<Jsonb>,
+        #[max_length = 32]
+        cancellation_reason_code -> Nullable<Varchar>,
+        authorized_at -> Nullable<Timestamp>,
+        captured_at -> Nullable<Timestamp>,
     }
 }
 
@@ -1047,6 +1078,33 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_ledger_entry (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        attempt_id -> Varchar,
+        #[max_length = 64]
+        reference_id -> Varchar,
+        #[max_length = 32]
+        entry_type -> Varchar,
+        #[max_length = 32]
+        debit_account -> Varchar,
+        #[max_length = 32]
+        credit_account -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1135,6 +1193,8 @@ diesel::table! {
         #[max_length = 64]
         network_token_locker_id -> Nullable<Varchar>,
         network_token_payment_method_data -> Nullable<Bytea>,
+        #[max_length = 32]
+        network_token_status -> Nullable<Varchar>,
     }
 }
 
@@ -1418,6 +1478,8 @@ diesel::table! {
         algorithm_for -> TransactionType,
         #[max_length = 64]
         decision_engine_routing_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        parent_algorithm_id -> Nullable<Varchar>,
     }
 }
 
@@ -1587,6 +1649,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     fraud_check,
     gateway_status_map,
     generic_link,
+    idempotency_key,
     incremental_authorization,
     locker_mock_up,
     mandate,
@@ -1596,6 +1659,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     organization,
     payment_attempt,
     payment_intent,
+    payment_ledger_entry,
     payment_link,
     payment_methods,
     payout_attempt,
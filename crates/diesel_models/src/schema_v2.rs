@@ -226,6 +226,10 @@ diesel::table! {
         acquirer_config_map -> Nullable<Jsonb>,
         #[max_length = 16]
         merchant_category_code -> Nullable<Varchar>,
+        payment_intent_expiry -> Nullable<Int8>,
+        is_auto_reauthorization_enabled -> Nullable<Bool>,
+        retry_policy -> Nullable<Jsonb>,
+        dynamic_currency_conversion -> Nullable<Jsonb>,
         #[max_length = 64]
         routing_algorithm_id -> Nullable<Varchar>,
         order_fulfillment_time -> Nullable<Int8>,
@@ -241,6 +245,8 @@ diesel::table! {
         external_vault_connector_details -> Nullable<Jsonb>,
         revenue_recovery_retry_algorithm_type -> Nullable<RevenueRecoveryAlgorithmType>,
         revenue_recovery_retry_algorithm_data -> Nullable<Jsonb>,
+        #[max_length = 32]
+        payment_method_duplicate_policy -> Nullable<Varchar>,
     }
 }
 
@@ -616,6 +622,23 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    idempotency_key (merchant_id, idempotency_key) {
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 255]
+        idempotency_key -> Varchar,
+        #[max_length = 64]
+        fingerprint -> Varchar,
+        response -> Jsonb,
+        created_at -> Timestamp,
+        expires_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -781,6 +804,7 @@ diesel::table! {
         #[max_length = 64]
         id -> Varchar,
         feature_metadata -> Nullable<Jsonb>,
+        fee_schedule -> Nullable<Jsonb>,
     }
 }
 
@@ -1003,6 +1027,33 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_ledger_entry (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        attempt_id -> Varchar,
+        #[max_length = 64]
+        reference_id -> Varchar,
+        #[max_length = 32]
+        entry_type -> Varchar,
+        #[max_length = 32]
+        debit_account -> Varchar,
+        #[max_length = 32]
+        credit_account -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1351,6 +1402,8 @@ diesel::table! {
         algorithm_for -> TransactionType,
         #[max_length = 64]
         decision_engine_routing_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        parent_algorithm_id -> Nullable<Varchar>,
     }
 }
 
@@ -1540,6 +1593,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     fraud_check,
     gateway_status_map,
     generic_link,
+    idempotency_key,
     incremental_authorization,
     locker_mock_up,
     mandate,
@@ -1549,6 +1603,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     organization,
     payment_attempt,
     payment_intent,
+    payment_ledger_entry,
     payment_link,
     payment_methods,
     payout_attempt,
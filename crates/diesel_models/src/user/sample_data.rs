@@ -216,6 +216,13 @@ pub struct PaymentAttemptBatchNew {
     pub processor_merchant_id: Option<common_utils::id_type::MerchantId>,
     pub created_by: Option<String>,
     pub setup_future_usage_applied: Option<common_enums::FutureUsage>,
+    pub authorization_reversal_status: Option<common_enums::AuthorizationReversalStatus>,
+    pub amount_reversed: Option<MinorUnit>,
+    pub installment_plan: Option<common_types::payments::InstallmentPlan>,
+    pub currency_conversion: Option<common_types::payments::CurrencyConversionDetails>,
+    pub cancellation_reason_code: Option<common_enums::PaymentCancellationReason>,
+    pub authorized_at: Option<PrimitiveDateTime>,
+    pub captured_at: Option<PrimitiveDateTime>,
 }
 
 #[cfg(feature = "v1")]
@@ -301,6 +308,13 @@ impl PaymentAttemptBatchNew {
             processor_merchant_id: self.processor_merchant_id,
             created_by: self.created_by,
             setup_future_usage_applied: self.setup_future_usage_applied,
+            authorization_reversal_status: self.authorization_reversal_status,
+            amount_reversed: self.amount_reversed,
+            installment_plan: self.installment_plan,
+            currency_conversion: self.currency_conversion,
+            cancellation_reason_code: self.cancellation_reason_code,
+            authorized_at: self.authorized_at,
+            captured_at: self.captured_at,
         }
     }
 }
@@ -47,6 +47,7 @@ fn get_program_data() -> (ast::Program<DummyOutput>, inputs::BackendInput) {
             billing_country: Some(enums::Country::France),
             business_label: None,
             setup_future_usage: None,
+            installment_interval: None,
         },
         payment_method: inputs::PaymentMethodInput {
             payment_method: Some(enums::PaymentMethod::PayLater),
@@ -59,6 +60,7 @@ fn get_program_data() -> (ast::Program<DummyOutput>, inputs::BackendInput) {
             payment_type: None,
         },
         issuer_data: None,
+        temporal_data: None,
         acquirer_data: None,
         customer_device_data: None,
     };
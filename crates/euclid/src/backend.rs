@@ -13,6 +13,10 @@ use crate::frontend::ast;
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct BackendOutput<O> {
     pub rule_name: Option<String>,
+    /// Position of the matched rule in the program, `None` when the default selection was used
+    pub rule_index: Option<usize>,
+    /// Position of the top level statement of the matched rule whose condition was satisfied
+    pub statement_index: Option<usize>,
     pub connector_selection: O,
 }
 
@@ -3,7 +3,9 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     enums,
-    frontend::dir::enums::{CustomerDeviceDisplaySize, CustomerDevicePlatform, CustomerDeviceType},
+    frontend::dir::enums::{
+        CustomerDeviceDisplaySize, CustomerDevicePlatform, CustomerDeviceType, PaymentDayOfWeek,
+    },
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -31,6 +33,7 @@ pub struct PaymentInput {
     pub billing_country: Option<enums::Country>,
     pub business_label: Option<String>,
     pub setup_future_usage: Option<enums::SetupFutureUsage>,
+    pub installment_interval: Option<enums::InstallmentInterval>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -52,6 +55,18 @@ pub struct IssuerDataInput {
     pub country: Option<enums::Country>,
 }
 
+/// Payment time attributes, resolved in the merchant's timezone
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemporalDataInput {
+    /// Hour of the day, in the range 0-23
+    pub hour_of_day: u8,
+    pub day_of_week: PaymentDayOfWeek,
+    /// Date in the YYYYMMDD format, e.g. 20241225
+    pub date: u32,
+    /// Names of the configured holiday calendars which contain `date`
+    pub holiday_calendars: Vec<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BackendInput {
     pub metadata: Option<FxHashMap<String, String>>,
@@ -60,5 +75,6 @@ pub struct BackendInput {
     pub acquirer_data: Option<AcquirerDataInput>,
     pub customer_device_data: Option<CustomerDeviceDataInput>,
     pub issuer_data: Option<IssuerDataInput>,
+    pub temporal_data: Option<TemporalDataInput>,
     pub mandate: MandateData,
 }
@@ -70,6 +70,8 @@ where
                 (Number(n), Equal, NumberComparisonArray(ncvec)) => {
                     Self::eval_number_comparison_array(*n, ncvec)
                 }
+                (EnumVariantArray(values), Equal, StrValue(s)) => Ok(values.contains(s)),
+                (EnumVariantArray(values), NotEqual, StrValue(s)) => Ok(!values.contains(s)),
                 _ => Err(types::InterpreterError {
                     error_type: types::InterpreterErrorType::InvalidComparison,
                     metadata: comparison.metadata.clone(),
@@ -120,26 +122,27 @@ where
         Ok(true)
     }
 
+    /// Returns the position of the first statement that is satisfied
     fn eval_rule_statements(
         statements: &[ast::IfStatement],
         ctx: &types::Context,
-    ) -> Result<bool, types::InterpreterError> {
-        for stmt in statements {
+    ) -> Result<Option<usize>, types::InterpreterError> {
+        for (index, stmt) in statements.iter().enumerate() {
             let res = Self::eval_if_statement(stmt, ctx)?;
 
             if res {
-                return Ok(true);
+                return Ok(Some(index));
             }
         }
 
-        Ok(false)
+        Ok(None)
     }
 
     #[inline]
     fn eval_rule(
         rule: &ast::Rule<O>,
         ctx: &types::Context,
-    ) -> Result<bool, types::InterpreterError> {
+    ) -> Result<Option<usize>, types::InterpreterError> {
         Self::eval_rule_statements(&rule.statements, ctx)
     }
 
@@ -147,13 +150,15 @@ where
         program: &ast::Program<O>,
         ctx: &types::Context,
     ) -> Result<backend::BackendOutput<O>, types::InterpreterError> {
-        for rule in &program.rules {
+        for (rule_index, rule) in program.rules.iter().enumerate() {
             let res = Self::eval_rule(rule, ctx)?;
 
-            if res {
+            if let Some(statement_index) = res {
                 return Ok(backend::BackendOutput {
                     connector_selection: rule.connector_selection.clone(),
                     rule_name: Some(rule.name.clone()),
+                    rule_index: Some(rule_index),
+                    statement_index: Some(statement_index),
                 });
             }
         }
@@ -161,6 +166,8 @@ where
         Ok(backend::BackendOutput {
             connector_selection: program.default_selection.clone(),
             rule_name: None,
+            rule_index: None,
+            statement_index: None,
         })
     }
 }
@@ -1,5 +1,6 @@
 use std::{collections::HashMap, fmt, ops::Deref, string::ToString};
 
+use common_utils::types::MinorUnit;
 use serde::Serialize;
 
 use crate::{backend::inputs, frontend::ast::ValueType, types::EuclidKey};
@@ -74,6 +75,41 @@ impl From<inputs::BackendInput> for Context {
                 EuclidKey::PaymentCurrency.to_string(),
                 Some(ValueType::EnumVariant(input.payment.currency.to_string())),
             ),
+            (
+                EuclidKey::InstallmentInterval.to_string(),
+                input
+                    .payment
+                    .installment_interval
+                    .map(|ii| ValueType::EnumVariant(ii.to_string())),
+            ),
+            (
+                EuclidKey::PaymentHourOfDay.to_string(),
+                input.temporal_data.as_ref().map(|temporal| {
+                    ValueType::Number(MinorUnit::new(i64::from(temporal.hour_of_day)))
+                }),
+            ),
+            (
+                EuclidKey::PaymentDayOfWeek.to_string(),
+                input
+                    .temporal_data
+                    .as_ref()
+                    .map(|temporal| ValueType::EnumVariant(temporal.day_of_week.to_string())),
+            ),
+            (
+                EuclidKey::PaymentDate.to_string(),
+                input
+                    .temporal_data
+                    .as_ref()
+                    .map(|temporal| ValueType::Number(MinorUnit::new(i64::from(temporal.date)))),
+            ),
+            // A date can fall in several holiday calendars, so all of them are held and a
+            // comparison against a calendar checks whether it is one of them
+            (
+                EuclidKey::PaymentHolidayCalendar.to_string(),
+                input
+                    .temporal_data
+                    .map(|temporal| ValueType::EnumVariantArray(temporal.holiday_calendars)),
+            ),
         ]);
 
         Self(ctx)
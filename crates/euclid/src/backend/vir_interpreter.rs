@@ -51,10 +51,11 @@ where
         }
     }
 
-    fn eval_rule(rule: &vir::ValuedRule<O>, ctx: &types::Context) -> bool {
+    /// Returns the position of the first statement of the rule that is satisfied
+    fn eval_rule(rule: &vir::ValuedRule<O>, ctx: &types::Context) -> Option<usize> {
         rule.statements
             .iter()
-            .any(|stmt| Self::eval_statement(stmt, ctx))
+            .position(|stmt| Self::eval_statement(stmt, ctx))
     }
 
     fn eval_program(
@@ -64,15 +65,23 @@ where
         program
             .rules
             .iter()
-            .find(|rule| Self::eval_rule(rule, ctx))
+            .enumerate()
+            .find_map(|(rule_index, rule)| {
+                Self::eval_rule(rule, ctx)
+                    .map(|statement_index| (rule_index, statement_index, rule))
+            })
             .map_or_else(
                 || backend::BackendOutput {
                     connector_selection: program.default_selection.clone(),
                     rule_name: None,
+                    rule_index: None,
+                    statement_index: None,
                 },
-                |rule| backend::BackendOutput {
+                |(rule_index, statement_index, rule)| backend::BackendOutput {
                     connector_selection: rule.connector_selection.clone(),
                     rule_name: Some(rule.name.clone()),
+                    rule_index: Some(rule_index),
+                    statement_index: Some(statement_index),
                 },
             )
     }
@@ -111,7 +120,7 @@ mod test {
     use rustc_hash::FxHashMap;
 
     use super::*;
-    use crate::{enums, types::DummyOutput};
+    use crate::{enums, frontend::dir::enums::PaymentDayOfWeek, types::DummyOutput};
 
     #[test]
     fn test_execution() {
@@ -142,6 +151,7 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                installment_interval: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -156,6 +166,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            temporal_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -185,6 +196,7 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                installment_interval: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -199,6 +211,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            temporal_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -229,6 +242,7 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                installment_interval: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -243,6 +257,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            temporal_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -273,6 +288,7 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                installment_interval: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -287,6 +303,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            temporal_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -317,6 +334,7 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                installment_interval: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -331,6 +349,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            temporal_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -361,6 +380,7 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                installment_interval: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -375,6 +395,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            temporal_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -405,6 +426,7 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                installment_interval: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -419,6 +441,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            temporal_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -449,6 +472,7 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                installment_interval: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -463,6 +487,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            temporal_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -493,6 +518,7 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: Some(enums::SetupFutureUsage::OffSession),
+                installment_interval: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -507,6 +533,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            temporal_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -539,6 +566,7 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                installment_interval: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -553,6 +581,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            temporal_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -583,6 +612,7 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                installment_interval: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -597,6 +627,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            temporal_data: None,
         };
         let mut inp_equal = inp_greater.clone();
         inp_equal.payment.amount = MinorUnit::new(123);
@@ -636,6 +667,7 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                installment_interval: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -650,6 +682,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            temporal_data: None,
         };
         let mut inp_equal = inp_lower.clone();
         inp_equal.payment.amount = MinorUnit::new(123);
@@ -665,4 +698,164 @@ mod test {
             "rule_1"
         );
     }
+
+    #[test]
+    fn test_payment_time_keys() {
+        let program_str = r#"
+        default: ["stripe", "adyen"]
+
+        rule_1: ["adyen"]
+        {
+           payment_holiday_calendar = "us_federal"
+        }
+
+        rule_2: ["checkout"]
+        {
+           payment_day_of_week = (saturday, sunday)
+        }
+
+        rule_3: ["paypal"]
+        {
+           payment_hour_of_day >= 2 & payment_hour_of_day < 4 & payment_date <= 20241231
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let inp_holiday = inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: MinorUnit::new(120),
+                card_bin: None,
+                currency: enums::Currency::USD,
+                authentication_type: Some(enums::AuthenticationType::NoThreeDs),
+                capture_method: Some(enums::CaptureMethod::Automatic),
+                business_country: Some(enums::Country::UnitedStatesOfAmerica),
+                billing_country: Some(enums::Country::France),
+                business_label: None,
+                setup_future_usage: None,
+                installment_interval: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::PayLater),
+                payment_method_type: Some(enums::PaymentMethodType::Affirm),
+                card_network: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+            acquirer_data: None,
+            customer_device_data: None,
+            issuer_data: None,
+            temporal_data: Some(inputs::TemporalDataInput {
+                hour_of_day: 10,
+                day_of_week: PaymentDayOfWeek::Wednesday,
+                date: 20241225,
+                holiday_calendars: vec!["us_federal".to_string()],
+            }),
+        };
+
+        let mut inp_weekend = inp_holiday.clone();
+        inp_weekend.temporal_data = Some(inputs::TemporalDataInput {
+            hour_of_day: 10,
+            day_of_week: PaymentDayOfWeek::Sunday,
+            date: 20241229,
+            holiday_calendars: vec![],
+        });
+
+        let mut inp_maintenance = inp_holiday.clone();
+        inp_maintenance.temporal_data = Some(inputs::TemporalDataInput {
+            hour_of_day: 3,
+            day_of_week: PaymentDayOfWeek::Tuesday,
+            date: 20241210,
+            holiday_calendars: vec![],
+        });
+
+        let mut inp_no_time = inp_holiday.clone();
+        inp_no_time.temporal_data = None;
+
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+        let result_holiday = backend.execute(inp_holiday).expect("Execution");
+        let result_weekend = backend.execute(inp_weekend).expect("Execution");
+        let result_maintenance = backend.execute(inp_maintenance).expect("Execution");
+        let result_no_time = backend.execute(inp_no_time).expect("Execution");
+        assert_eq!(
+            result_holiday.rule_name.expect("Rule Name").as_str(),
+            "rule_1"
+        );
+        assert_eq!(
+            result_weekend.rule_name.expect("Rule Name").as_str(),
+            "rule_2"
+        );
+        assert_eq!(
+            result_maintenance.rule_name.expect("Rule Name").as_str(),
+            "rule_3"
+        );
+        assert!(result_no_time.rule_name.is_none());
+    }
+
+    #[test]
+    fn test_matched_rule_and_statement_index() {
+        let program_str = r#"
+        default: ["stripe"]
+
+        rule_1: ["adyen"]
+        {
+            pay_later = klarna
+        }
+
+        rule_2: ["checkout"]
+        {
+            pay_later = klarna
+            pay_later = affirm
+        }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let make_input = |payment_method_type| inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: MinorUnit::new(32),
+                card_bin: None,
+                currency: enums::Currency::USD,
+                authentication_type: None,
+                capture_method: None,
+                business_country: None,
+                billing_country: None,
+                business_label: None,
+                setup_future_usage: None,
+                installment_interval: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::PayLater),
+                payment_method_type: Some(payment_method_type),
+                card_network: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+            acquirer_data: None,
+            customer_device_data: None,
+            issuer_data: None,
+            temporal_data: None,
+        };
+
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+
+        let result_affirm = backend
+            .execute(make_input(enums::PaymentMethodType::Affirm))
+            .expect("Execution");
+        assert_eq!(result_affirm.rule_name.as_deref(), Some("rule_2"));
+        assert_eq!(result_affirm.rule_index, Some(1));
+        assert_eq!(result_affirm.statement_index, Some(1));
+
+        let result_default = backend
+            .execute(make_input(enums::PaymentMethodType::AfterpayClearpay))
+            .expect("Execution");
+        assert!(result_default.rule_name.is_none());
+        assert!(result_default.rule_index.is_none());
+        assert!(result_default.statement_index.is_none());
+    }
 }
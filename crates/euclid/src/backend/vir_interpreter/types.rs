@@ -1,3 +1,4 @@
+use common_utils::types::MinorUnit;
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
@@ -57,6 +58,7 @@ impl Context {
         let acquirer_data = input.acquirer_data;
         let customer_device_data = input.customer_device_data;
         let issuer_data = input.issuer_data;
+        let temporal_data = input.temporal_data;
         let payment_mandate = input.mandate;
 
         let mut enum_values: FxHashSet<EuclidValue> =
@@ -106,6 +108,9 @@ impl Context {
         if let Some(setup_future_usage) = payment.setup_future_usage {
             enum_values.insert(EuclidValue::SetupFutureUsage(setup_future_usage));
         }
+        if let Some(installment_interval) = payment.installment_interval {
+            enum_values.insert(EuclidValue::InstallmentInterval(installment_interval));
+        }
         if let Some(payment_type) = payment_mandate.payment_type {
             enum_values.insert(EuclidValue::PaymentType(payment_type));
         }
@@ -143,7 +148,7 @@ impl Context {
             }
         }
 
-        let numeric_values: FxHashMap<EuclidKey, EuclidValue> = FxHashMap::from_iter([(
+        let mut numeric_values: FxHashMap<EuclidKey, EuclidValue> = FxHashMap::from_iter([(
             EuclidKey::PaymentAmount,
             EuclidValue::PaymentAmount(types::NumValue {
                 number: payment.amount,
@@ -151,6 +156,30 @@ impl Context {
             }),
         )]);
 
+        // Handle payment time data
+        if let Some(temporal) = temporal_data {
+            enum_values.insert(EuclidValue::PaymentDayOfWeek(temporal.day_of_week));
+            for holiday_calendar in temporal.holiday_calendars {
+                enum_values.insert(EuclidValue::PaymentHolidayCalendar(StrValue {
+                    value: holiday_calendar,
+                }));
+            }
+            numeric_values.insert(
+                EuclidKey::PaymentHourOfDay,
+                EuclidValue::PaymentHourOfDay(types::NumValue {
+                    number: MinorUnit::new(i64::from(temporal.hour_of_day)),
+                    refinement: None,
+                }),
+            );
+            numeric_values.insert(
+                EuclidKey::PaymentDate,
+                EuclidValue::PaymentDate(types::NumValue {
+                    number: MinorUnit::new(i64::from(temporal.date)),
+                    refinement: None,
+                }),
+            );
+        }
+
         Self {
             atomic_values: enum_values,
             numeric_values,
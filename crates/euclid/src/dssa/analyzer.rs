@@ -4,7 +4,7 @@
 //! in the Euclid Rule DSL. These include standard control flow analyses like testing
 //! conflicting assertions, to Domain Specific Analyses making use of the
 //! [`Knowledge Graph Framework`](crate::dssa::graph).
-use hyperswitch_constraint_graph::{ConstraintGraph, Memoization};
+use hyperswitch_constraint_graph::{self as cgraph, ConstraintGraph, Memoization};
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
@@ -220,14 +220,259 @@ fn perform_context_analyses(
     Ok(())
 }
 
-pub fn analyze<O: EuclidAnalysable + EuclidDirFilter>(
-    program: ast::Program<O>,
-    knowledge_graph: Option<&ConstraintGraph<dir::DirValue>>,
-) -> Result<vir::ValuedProgram<O>, types::AnalysisError> {
-    let dir_program = ast::lowering::lower_program(program)?;
+/// Checks whether `antecedent` being true guarantees that `consequent` is true.
+///
+/// Both comparisons are expected to be on the same key.
+fn comparison_implies(antecedent: &dir::DirComparison, consequent: &dir::DirComparison) -> bool {
+    let value_implies = |value: &dir::DirValue, consequent_value: &dir::DirValue| match (
+        value.get_num_value(),
+        consequent_value.get_num_value(),
+    ) {
+        (Some(num_value), Some(consequent_num_value)) => consequent_num_value.fits(&num_value),
+        _ => value == consequent_value,
+    };
 
-    let selection_data = state_machine::make_connector_selection_data(&dir_program);
-    let mut ctx_manager = state_machine::AnalysisContextManager::new(&dir_program, &selection_data);
+    match (&antecedent.logic, &consequent.logic) {
+        (
+            dir::DirComparisonLogic::PositiveDisjunction,
+            dir::DirComparisonLogic::PositiveDisjunction,
+        ) => antecedent.values.iter().all(|value| {
+            consequent
+                .values
+                .iter()
+                .any(|consequent_value| value_implies(value, consequent_value))
+        }),
+        (
+            dir::DirComparisonLogic::PositiveDisjunction,
+            dir::DirComparisonLogic::NegativeConjunction,
+        ) => antecedent
+            .values
+            .iter()
+            .all(|value| value.get_num_value().is_none() && !consequent.values.contains(value)),
+        (
+            dir::DirComparisonLogic::NegativeConjunction,
+            dir::DirComparisonLogic::NegativeConjunction,
+        ) => consequent
+            .values
+            .iter()
+            .all(|value| antecedent.values.contains(value)),
+        (
+            dir::DirComparisonLogic::NegativeConjunction,
+            dir::DirComparisonLogic::PositiveDisjunction,
+        ) => {
+            // The values left after the negation have to be a subset of the asserted values
+            antecedent
+                .values
+                .first()
+                .and_then(|value| value.get_key().kind.get_value_set())
+                .is_some_and(|value_set| {
+                    value_set
+                        .iter()
+                        .filter(|value| !antecedent.values.contains(*value))
+                        .all(|value| consequent.values.contains(value))
+                })
+        }
+    }
+}
+
+/// Checks whether every comparison in `consequent` is implied by a comparison on the same key
+/// in `antecedent`, in which case the path `consequent` is true whenever `antecedent` is.
+fn path_implies(antecedent: &[&dir::DirComparison], consequent: &[&dir::DirComparison]) -> bool {
+    consequent.iter().all(|consequent_comparison| {
+        let key = consequent_comparison
+            .values
+            .first()
+            .map(|value| value.get_key());
+
+        antecedent.iter().any(|comparison| {
+            key.is_some()
+                && comparison.values.first().map(|value| value.get_key()) == key
+                && comparison_implies(comparison, consequent_comparison)
+        })
+    })
+}
+
+/// Flattens an if statement into its conjunctive paths, one for every innermost statement.
+fn get_statement_paths(statement: &dir::DirIfStatement) -> Vec<Vec<&dir::DirComparison>> {
+    let condition = statement.condition.iter().collect::<Vec<_>>();
+
+    match &statement.nested {
+        Some(nested) => nested
+            .iter()
+            .flat_map(get_statement_paths)
+            .map(|path| condition.iter().copied().chain(path).collect())
+            .collect(),
+        None => vec![condition],
+    }
+}
+
+/// Analyses rules and top level statements that can never be matched, as every payment they
+/// match is already matched by an earlier rule.
+///
+/// For example,
+/// ```notrust
+/// rule_1: payment_method = card
+/// rule_2: payment_method = card & amount > 1000
+/// ```notrust
+/// `rule_2` is never selected since every payment it matches is matched by `rule_1` first.
+pub fn analyze_unreachable_rules<O>(program: &dir::DirProgram<O>) -> Vec<types::AnalysisWarning> {
+    let mut warnings = Vec::new();
+    let mut earlier_paths: Vec<(&str, Vec<&dir::DirComparison>)> = Vec::new();
+
+    for rule in &program.rules {
+        let statement_paths = rule
+            .statements
+            .iter()
+            .map(get_statement_paths)
+            .collect::<Vec<_>>();
+
+        let statement_shadowing = statement_paths
+            .iter()
+            .map(|paths| {
+                let mut shadowed_by = Vec::new();
+
+                for path in paths {
+                    let shadowing_rule = earlier_paths
+                        .iter()
+                        .find(|(_, earlier_path)| path_implies(path, earlier_path))
+                        .map(|(rule_name, _)| rule_name.to_string())?;
+
+                    if !shadowed_by.contains(&shadowing_rule) {
+                        shadowed_by.push(shadowing_rule);
+                    }
+                }
+
+                (!paths.is_empty()).then_some(shadowed_by)
+            })
+            .collect::<Vec<_>>();
+
+        if !statement_shadowing.is_empty() && statement_shadowing.iter().all(Option::is_some) {
+            let mut shadowed_by: Vec<String> = Vec::new();
+            for rule_name in statement_shadowing.into_iter().flatten().flatten() {
+                if !shadowed_by.contains(&rule_name) {
+                    shadowed_by.push(rule_name);
+                }
+            }
+
+            warnings.push(types::AnalysisWarning::UnreachableRule {
+                rule_name: rule.name.clone(),
+                shadowed_by,
+            });
+        } else {
+            warnings.extend(statement_shadowing.into_iter().enumerate().filter_map(
+                |(statement_index, shadowed_by)| {
+                    shadowed_by.map(|shadowed_by| types::AnalysisWarning::ShadowedStatement {
+                        rule_name: rule.name.clone(),
+                        statement_index,
+                        shadowed_by,
+                    })
+                },
+            ));
+        }
+
+        earlier_paths.extend(
+            statement_paths
+                .into_iter()
+                .flatten()
+                .map(|path| (rule.name.as_str(), path)),
+        );
+    }
+
+    warnings
+}
+
+/// Analyses connectors in the outputs of the program that are not present in the knowledge
+/// graph built from the merchant connector accounts of the profile.
+pub fn analyze_unavailable_connectors<O: EuclidAnalysable>(
+    program: &dir::DirProgram<O>,
+    mca_graph: &ConstraintGraph<dir::DirValue>,
+) -> Vec<types::AnalysisWarning> {
+    let selections = std::iter::once((None, &program.default_selection)).chain(
+        program
+            .rules
+            .iter()
+            .map(|rule| (Some(&rule.name), &rule.connector_selection)),
+    );
+
+    let mut warnings = Vec::new();
+    for (rule_name, selection) in selections {
+        let mut unavailable_connectors: Vec<String> = Vec::new();
+
+        for (value, _) in selection.get_dir_value_for_analysis(
+            rule_name
+                .cloned()
+                .unwrap_or_else(|| "default_selection".to_string()),
+        ) {
+            let dir::DirValue::Connector(ref connector_choice) = value else {
+                continue;
+            };
+
+            let connector = connector_choice.connector.to_string();
+            if !mca_graph
+                .value_map
+                .contains_key(&cgraph::NodeValue::Value(value))
+                && !unavailable_connectors.contains(&connector)
+            {
+                unavailable_connectors.push(connector);
+            }
+        }
+
+        warnings.extend(unavailable_connectors.into_iter().map(|connector| {
+            types::AnalysisWarning::UnavailableConnector {
+                rule_name: rule_name.cloned(),
+                connector,
+            }
+        }));
+    }
+
+    warnings
+}
+
+/// Analyses volume split outputs whose split percentages do not add up to 100.
+pub fn analyze_volume_splits<O: EuclidAnalysable>(
+    program: &dir::DirProgram<O>,
+) -> Vec<types::AnalysisWarning> {
+    std::iter::once((None, &program.default_selection))
+        .chain(
+            program
+                .rules
+                .iter()
+                .map(|rule| (Some(&rule.name), &rule.connector_selection)),
+        )
+        .filter_map(|(rule_name, selection)| {
+            selection
+                .get_volume_split_total()
+                .filter(|total| *total != 100)
+                .map(|total| types::AnalysisWarning::InvalidVolumeSplit {
+                    rule_name: rule_name.cloned(),
+                    total,
+                })
+        })
+        .collect()
+}
+
+/// Runs the analyses that flag likely misconfigurations in a program without making it invalid.
+/// Connector availability is only analysed when the knowledge graph of the profile is provided.
+pub fn get_program_warnings<O: EuclidAnalysable>(
+    program: &dir::DirProgram<O>,
+    mca_graph: Option<&ConstraintGraph<dir::DirValue>>,
+) -> Vec<types::AnalysisWarning> {
+    let mut warnings = analyze_unreachable_rules(program);
+
+    if let Some(graph) = mca_graph {
+        warnings.extend(analyze_unavailable_connectors(program, graph));
+    }
+
+    warnings.extend(analyze_volume_splits(program));
+    warnings
+}
+
+fn perform_program_analyses<O: EuclidAnalysable>(
+    dir_program: &dir::DirProgram<O>,
+    knowledge_graph: Option<&ConstraintGraph<dir::DirValue>>,
+) -> Result<(), types::AnalysisError> {
+    let selection_data = state_machine::make_connector_selection_data(dir_program);
+    let mut ctx_manager = state_machine::AnalysisContextManager::new(dir_program, &selection_data);
     while let Some(ctx) = ctx_manager.advance().map_err(|err| types::AnalysisError {
         metadata: Default::default(),
         error_type: types::AnalysisErrorType::StateMachine(err),
@@ -235,9 +480,33 @@ pub fn analyze<O: EuclidAnalysable + EuclidDirFilter>(
         perform_context_analyses(ctx, knowledge_graph.unwrap_or(&truth::ANALYSIS_GRAPH))?;
     }
 
+    Ok(())
+}
+
+pub fn analyze<O: EuclidAnalysable + EuclidDirFilter>(
+    program: ast::Program<O>,
+    knowledge_graph: Option<&ConstraintGraph<dir::DirValue>>,
+) -> Result<vir::ValuedProgram<O>, types::AnalysisError> {
+    let dir_program = ast::lowering::lower_program(program)?;
+    perform_program_analyses(&dir_program, knowledge_graph)?;
+
     dir::lowering::lower_program(dir_program)
 }
 
+/// Performs the same analyses as [`analyze`], additionally returning the warnings from
+/// [`get_program_warnings`]. The knowledge graph is expected to be the one built from the
+/// merchant connector accounts of the profile.
+pub fn analyze_with_warnings<O: EuclidAnalysable + EuclidDirFilter>(
+    program: ast::Program<O>,
+    knowledge_graph: Option<&ConstraintGraph<dir::DirValue>>,
+) -> Result<(vir::ValuedProgram<O>, Vec<types::AnalysisWarning>), types::AnalysisError> {
+    let dir_program = ast::lowering::lower_program(program)?;
+    perform_program_analyses(&dir_program, knowledge_graph)?;
+    let warnings = get_program_warnings(&dir_program, knowledge_graph);
+
+    Ok((dir::lowering::lower_program(dir_program)?, warnings))
+}
+
 #[cfg(all(test, feature = "ast_parser"))]
 mod tests {
     #![allow(clippy::panic, clippy::expect_used)]
@@ -378,6 +647,85 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_unreachable_rule_detection() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            rule_1: ["stripe"]
+            {
+                payment_method = card {
+                    amount > 100
+                }
+                payment_method /= (card, wallet)
+            }
+
+            rule_2: ["adyen"]
+            {
+                payment_method = card & amount > 500 & capture_method = manual
+            }
+
+            rule_3: ["adyen"]
+            {
+                payment_method = pay_later
+            }
+
+            rule_4: ["checkout"]
+            {
+                payment_method = (card, wallet)
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let dir_program = ast::lowering::lower_program(program).expect("Lowering");
+        let warnings = analyze_unreachable_rules(&dir_program);
+
+        assert_eq!(
+            warnings.len(),
+            2,
+            "Expected rule_2 and rule_3 to be reported"
+        );
+        assert!(warnings.iter().all(|warning| matches!(
+            warning,
+            types::AnalysisWarning::UnreachableRule { shadowed_by, .. }
+                if shadowed_by == &vec!["rule_1".to_string()]
+        )));
+        assert_eq!(warnings[0].get_rule_name(), Some("rule_2"));
+        assert_eq!(warnings[1].get_rule_name(), Some("rule_3"));
+    }
+
+    #[test]
+    fn test_shadowed_statement_detection() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            rule_1: ["stripe"]
+            {
+                amount > 1000
+            }
+
+            rule_2: ["adyen"]
+            {
+                payment_method = wallet
+                amount > 5000 & capture_method = manual
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let dir_program = ast::lowering::lower_program(program).expect("Lowering");
+        let warnings = analyze_unreachable_rules(&dir_program);
+
+        assert_eq!(warnings.len(), 1);
+        assert!(matches!(
+            &warnings[0],
+            types::AnalysisWarning::ShadowedStatement {
+                rule_name,
+                statement_index: 1,
+                shadowed_by,
+            } if rule_name == "rule_2" && shadowed_by == &vec!["rule_1".to_string()]
+        ));
+    }
+
     #[test]
     fn test_negation_graph_analysis() {
         let graph = knowledge! {
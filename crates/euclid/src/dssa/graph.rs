@@ -6,7 +6,7 @@ use rustc_hash::{FxHashMap, FxHashSet};
 use crate::{
     dssa::types,
     frontend::dir,
-    types::{DataType, Metadata},
+    types::{DataType, Metadata, NumValueRefinement},
 };
 
 pub mod euclid_graph_prelude {
@@ -67,6 +67,7 @@ impl cgraph::NodeViz for dir::DirValue {
             Self::GiftCardType(gct) => gct.to_string(),
             Self::BusinessLabel(bl) => bl.value.to_string(),
             Self::SetupFutureUsage(sfu) => sfu.to_string(),
+            Self::InstallmentInterval(ii) => ii.to_string(),
             Self::CardRedirectType(crt) => crt.to_string(),
             Self::RealTimePaymentType(rtpt) => rtpt.to_string(),
             Self::OpenBankingType(ob) => ob.to_string(),
@@ -82,6 +83,10 @@ impl cgraph::NodeViz for dir::DirValue {
             }
             Self::AcquirerCountry(acquirer_country) => acquirer_country.to_string(),
             Self::AcquirerFraudRate(acquirer_fraud_rate) => acquirer_fraud_rate.number.to_string(),
+            Self::PaymentHourOfDay(hour_of_day) => hour_of_day.number.to_string(),
+            Self::PaymentDayOfWeek(day_of_week) => day_of_week.to_string(),
+            Self::PaymentDate(date) => date.number.to_string(),
+            Self::PaymentHolidayCalendar(holiday_calendar) => holiday_calendar.value.clone(),
         }
     }
 }
@@ -288,6 +293,24 @@ pub trait CgraphExt {
         memo: &mut cgraph::Memoization<dir::DirValue>,
         domains: Option<&[String]>,
     ) -> Result<(), AnalysisError<dir::DirValue>>;
+
+    /// Explains why [`CgraphExt::check_value_validity`] finds the value invalid, returns `None`
+    /// for valid values
+    fn explain_value_validity(
+        &self,
+        val: dir::DirValue,
+        analysis_ctx: &AnalysisContext,
+        domains: Option<&[String]>,
+    ) -> Result<Option<TraceExplanation>, cgraph::GraphError<dir::DirValue>>;
+
+    /// Explains why [`CgraphExt::key_value_analysis`] fails for the value, returns `None` when
+    /// the analysis succeeds
+    fn explain_key_value_analysis(
+        &self,
+        val: dir::DirValue,
+        ctx: &AnalysisContext,
+        domains: Option<&[String]>,
+    ) -> Result<Option<TraceExplanation>, cgraph::GraphError<dir::DirValue>>;
 }
 
 impl CgraphExt for cgraph::ConstraintGraph<dir::DirValue> {
@@ -505,6 +528,222 @@ impl CgraphExt for cgraph::ConstraintGraph<dir::DirValue> {
 
         Ok(())
     }
+
+    fn explain_value_validity(
+        &self,
+        val: dir::DirValue,
+        analysis_ctx: &AnalysisContext,
+        domains: Option<&[String]>,
+    ) -> Result<Option<TraceExplanation>, cgraph::GraphError<dir::DirValue>> {
+        let Some(node_id) = self.value_map.get(&cgraph::NodeValue::Value(val.clone())) else {
+            return Ok(Some(TraceExplanation {
+                message: format!("{} is not configured", describe_dir_value(&val)),
+                causes: Vec::new(),
+            }));
+        };
+
+        // The traces are owned by the memoization, so the explanation has to be built before
+        // it is dropped
+        let mut memo = cgraph::Memoization::new();
+        match self.check_node(
+            analysis_ctx,
+            *node_id,
+            cgraph::Relation::Positive,
+            cgraph::Strength::Weak,
+            &mut memo,
+            &mut cgraph::CycleCheck::new(),
+            domains,
+        ) {
+            Ok(()) => Ok(None),
+            Err(error) => Ok(Some(TraceExplanation::from_trace(
+                &error.get_analysis_trace()?,
+            ))),
+        }
+    }
+
+    fn explain_key_value_analysis(
+        &self,
+        val: dir::DirValue,
+        ctx: &AnalysisContext,
+        domains: Option<&[String]>,
+    ) -> Result<Option<TraceExplanation>, cgraph::GraphError<dir::DirValue>> {
+        let mut memo = cgraph::Memoization::new();
+        match self.key_value_analysis(val, ctx, &mut memo, &mut cgraph::CycleCheck::new(), domains)
+        {
+            Ok(()) => Ok(None),
+            Err(error) => Ok(Some(TraceExplanation::from_trace(
+                &error.get_analysis_trace()?,
+            ))),
+        }
+    }
+}
+
+/// Human readable explanation of an analysis trace. Every node describes a requirement that was
+/// not met, and its causes describe why.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct TraceExplanation {
+    pub message: String,
+    pub causes: Vec<TraceExplanation>,
+}
+
+impl TraceExplanation {
+    /// Builds the explanation of an analysis trace. Traces are only available while the
+    /// memoization used for the analysis is alive.
+    pub fn from_trace(trace: &Weak<cgraph::AnalysisTrace<dir::DirValue>>) -> Self {
+        let Some(trace) = trace.upgrade() else {
+            return Self {
+                message: "The analysis trace is no longer available".to_string(),
+                causes: Vec::new(),
+            };
+        };
+
+        let (message, info, causes) = match trace.as_ref() {
+            cgraph::AnalysisTrace::Value {
+                value,
+                relation,
+                predecessors,
+                info,
+                ..
+            } => {
+                let value = describe_node_value(value);
+                match (relation, predecessors) {
+                    (cgraph::Relation::Positive, None) => {
+                        (format!("{value} is required"), info, Vec::new())
+                    }
+                    (cgraph::Relation::Negative, None) => {
+                        (format!("{value} is not allowed"), info, Vec::new())
+                    }
+                    (_, Some(cgraph::error::ValueTracePredecessor::Mandatory(predecessor))) => (
+                        format!("{value} is not possible as its requirement is not met"),
+                        info,
+                        vec![Self::from_trace(predecessor)],
+                    ),
+                    (_, Some(cgraph::error::ValueTracePredecessor::OneOf(predecessors))) => (
+                        format!("{value} is not possible as none of its requirements are met"),
+                        info,
+                        Self::from_traces(predecessors),
+                    ),
+                }
+            }
+            cgraph::AnalysisTrace::AllAggregation {
+                unsatisfied, info, ..
+            } => (
+                "All of the following are required".to_string(),
+                info,
+                Self::from_traces(unsatisfied),
+            ),
+            cgraph::AnalysisTrace::AnyAggregation {
+                unsatisfied, info, ..
+            } => (
+                "At least one of the following is required".to_string(),
+                info,
+                Self::from_traces(unsatisfied),
+            ),
+            cgraph::AnalysisTrace::InAggregation {
+                expected,
+                found,
+                relation,
+                info,
+                ..
+            } => {
+                let key = expected
+                    .first()
+                    .map(|value| describe_key(&value.get_key()))
+                    .unwrap_or_default();
+                let mut expected = expected
+                    .iter()
+                    .map(cgraph::NodeViz::viz)
+                    .collect::<Vec<_>>();
+                expected.sort();
+                let expected = expected.join(", ");
+
+                let message = match (relation, found) {
+                    (cgraph::Relation::Positive, Some(found)) => format!(
+                        "{key} {} is not one of the supported values: {expected}",
+                        cgraph::NodeViz::viz(found)
+                    ),
+                    (cgraph::Relation::Positive, None) => {
+                        format!("{key} is required to be one of: {expected}")
+                    }
+                    (cgraph::Relation::Negative, Some(found)) => format!(
+                        "{key} {} is one of the unsupported values: {expected}",
+                        cgraph::NodeViz::viz(found)
+                    ),
+                    (cgraph::Relation::Negative, None) => {
+                        format!("{key} must not be one of: {expected}")
+                    }
+                };
+
+                (message, info, Vec::new())
+            }
+            cgraph::AnalysisTrace::Contradiction { .. } => (
+                "Contradicting requirements were found for the same value".to_string(),
+                &None,
+                Vec::new(),
+            ),
+        };
+
+        let message = match info {
+            Some(info) => format!("{message} ({info})"),
+            None => message,
+        };
+
+        Self { message, causes }
+    }
+
+    fn from_traces(traces: &[Weak<cgraph::AnalysisTrace<dir::DirValue>>]) -> Vec<Self> {
+        traces.iter().map(Self::from_trace).collect()
+    }
+
+    /// Builds the explanation of a graph error, see [`TraceExplanation::from_trace`]
+    pub fn from_graph_error(error: &cgraph::GraphError<dir::DirValue>) -> Self {
+        match error {
+            cgraph::GraphError::AnalysisError(trace) => Self::from_trace(trace),
+            other => Self {
+                message: other.to_string(),
+                causes: Vec::new(),
+            },
+        }
+    }
+}
+
+fn describe_key(key: &dir::DirKey) -> String {
+    match &key.value {
+        Some(value) => format!("{} {value}", key.kind),
+        None => key.kind.to_string(),
+    }
+}
+
+fn describe_node_value(value: &cgraph::NodeValue<dir::DirValue>) -> String {
+    match value {
+        cgraph::NodeValue::Key(key) => describe_key(key),
+        cgraph::NodeValue::Value(value) => describe_dir_value(value),
+    }
+}
+
+fn describe_dir_value(value: &dir::DirValue) -> String {
+    let operator = match value
+        .get_num_value()
+        .and_then(|num_value| num_value.refinement)
+    {
+        None => "=",
+        Some(NumValueRefinement::NotEqual) => "/=",
+        Some(NumValueRefinement::GreaterThan) => ">",
+        Some(NumValueRefinement::LessThan) => "<",
+        Some(NumValueRefinement::GreaterThanEqual) => ">=",
+        Some(NumValueRefinement::LessThanEqual) => "<=",
+    };
+
+    match value {
+        dir::DirValue::MetaData(metadata) => {
+            format!("metadata {} = {}", metadata.key, metadata.value)
+        }
+        _ => format!(
+            "{} {operator} {}",
+            value.get_key().kind,
+            cgraph::NodeViz::viz(value)
+        ),
+    }
 }
 
 #[cfg(test)]
@@ -541,6 +780,45 @@ mod test {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_key_value_analysis_explanation() {
+        let graph = knowledge! {
+            PaymentMethod(Card) ->> CaptureMethod(Automatic);
+        };
+
+        let explanation = graph
+            .explain_key_value_analysis(
+                dirval!(CaptureMethod = Automatic),
+                &AnalysisContext::from_dir_values([dirval!(CaptureMethod = Automatic)]),
+                None,
+            )
+            .expect("Explanation")
+            .expect("Value should be invalid");
+
+        assert_eq!(
+            explanation.message,
+            "capture_method = automatic is not possible as its requirement is not met"
+        );
+        assert_eq!(explanation.causes.len(), 1);
+        assert_eq!(
+            explanation.causes[0].message,
+            "payment_method = card is required"
+        );
+
+        let explanation = graph
+            .explain_key_value_analysis(
+                dirval!(CaptureMethod = Automatic),
+                &AnalysisContext::from_dir_values([
+                    dirval!(CaptureMethod = Automatic),
+                    dirval!(PaymentMethod = Card),
+                ]),
+                None,
+            )
+            .expect("Explanation");
+
+        assert!(explanation.is_none());
+    }
+
     #[test]
     fn test_strong_positive_relation_failure() {
         let graph = knowledge! {
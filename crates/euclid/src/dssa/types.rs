@@ -10,6 +10,11 @@ use crate::{
 
 pub trait EuclidAnalysable: Sized {
     fn get_dir_value_for_analysis(&self, rule_name: String) -> Vec<(dir::DirValue, Metadata)>;
+
+    /// Sum of the split percentages, for outputs that distribute traffic by volume
+    fn get_volume_split_total(&self) -> Option<u64> {
+        None
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -154,6 +159,91 @@ pub enum AnalysisErrorType {
     NotSupported,
 }
 
+/// Findings of the static analysis that do not make a program invalid, but most likely point to
+/// a misconfiguration
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", content = "info", rename_all = "snake_case")]
+pub enum AnalysisWarning {
+    /// Every statement of the rule is covered by the statements of earlier rules
+    UnreachableRule {
+        rule_name: String,
+        shadowed_by: Vec<String>,
+    },
+    /// A top level statement of the rule is covered by the statements of earlier rules
+    ShadowedStatement {
+        rule_name: String,
+        statement_index: usize,
+        shadowed_by: Vec<String>,
+    },
+    /// The connector is not present in the knowledge graph of the profile
+    UnavailableConnector {
+        rule_name: Option<String>,
+        connector: String,
+    },
+    InvalidVolumeSplit {
+        rule_name: Option<String>,
+        total: u64,
+    },
+}
+
+impl AnalysisWarning {
+    /// Name of the rule the warning was raised for, `None` for the default selection
+    pub fn get_rule_name(&self) -> Option<&str> {
+        match self {
+            Self::UnreachableRule { rule_name, .. } | Self::ShadowedStatement { rule_name, .. } => {
+                Some(rule_name)
+            }
+            Self::UnavailableConnector { rule_name, .. }
+            | Self::InvalidVolumeSplit { rule_name, .. } => rule_name.as_deref(),
+        }
+    }
+}
+
+impl fmt::Display for AnalysisWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let selection = |rule_name: &Option<String>| {
+            rule_name
+                .as_ref()
+                .map_or("the default selection".to_string(), |name| {
+                    format!("rule '{name}'")
+                })
+        };
+
+        match self {
+            Self::UnreachableRule {
+                rule_name,
+                shadowed_by,
+            } => write!(
+                f,
+                "Rule '{rule_name}' can never be selected as its conditions are covered by the earlier rules {}",
+                shadowed_by.join(", ")
+            ),
+            Self::ShadowedStatement {
+                rule_name,
+                statement_index,
+                shadowed_by,
+            } => write!(
+                f,
+                "Statement {statement_index} of rule '{rule_name}' can never be matched as its conditions are covered by the earlier rules {}",
+                shadowed_by.join(", ")
+            ),
+            Self::UnavailableConnector {
+                rule_name,
+                connector,
+            } => write!(
+                f,
+                "Connector '{connector}' in {} is not enabled for the profile",
+                selection(rule_name)
+            ),
+            Self::InvalidVolumeSplit { rule_name, total } => write!(
+                f,
+                "Volume split of {} adds up to {total} instead of 100",
+                selection(rule_name)
+            ),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum ValueType {
     EnumVariants(Vec<EuclidValue>),
@@ -1,6 +1,7 @@
 pub mod lowering;
 #[cfg(feature = "ast_parser")]
 pub mod parser;
+pub mod printer;
 
 use common_enums::RoutableConnectors;
 use common_utils::types::MinorUnit;
@@ -158,6 +159,25 @@ pub struct Program<O> {
     pub metadata: Metadata,
 }
 
+impl<O> Program<O> {
+    /// Converts the output of the default selection and of every rule, keeping the rules intact
+    pub fn map_output<T>(self, mut f: impl FnMut(O) -> T) -> Program<T> {
+        Program {
+            default_selection: f(self.default_selection),
+            rules: self
+                .rules
+                .into_iter()
+                .map(|rule| Rule {
+                    name: rule.name,
+                    connector_selection: f(rule.connector_selection),
+                    statements: rule.statements,
+                })
+                .collect(),
+            metadata: self.metadata,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct RoutableConnectorChoice {
     #[serde(skip)]
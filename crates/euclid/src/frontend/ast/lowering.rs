@@ -240,6 +240,7 @@ fn lower_comparison_inner<O: EuclidDirFilter>(
         dir::DirKeyKind::BusinessCountry => lower_enum!(BusinessCountry, value),
         dir::DirKeyKind::BillingCountry => lower_enum!(BillingCountry, value),
         dir::DirKeyKind::SetupFutureUsage => lower_enum!(SetupFutureUsage, value),
+        dir::DirKeyKind::InstallmentInterval => lower_enum!(InstallmentInterval, value),
         dir::DirKeyKind::UpiType => lower_enum!(UpiType, value),
         dir::DirKeyKind::OpenBankingType => lower_enum!(OpenBankingType, value),
         dir::DirKeyKind::VoucherType => lower_enum!(VoucherType, value),
@@ -275,6 +276,10 @@ fn lower_comparison_inner<O: EuclidDirFilter>(
         dir::DirKeyKind::CustomerDeviceDisplaySize => lower_enum!(CustomerDeviceDisplaySize, value),
         dir::DirKeyKind::AcquirerCountry => lower_enum!(AcquirerCountry, value),
         dir::DirKeyKind::AcquirerFraudRate => lower_number!(AcquirerFraudRate, value, comparison),
+        dir::DirKeyKind::PaymentHourOfDay => lower_number!(PaymentHourOfDay, value, comparison),
+        dir::DirKeyKind::PaymentDayOfWeek => lower_enum!(PaymentDayOfWeek, value),
+        dir::DirKeyKind::PaymentDate => lower_number!(PaymentDate, value, comparison),
+        dir::DirKeyKind::PaymentHolidayCalendar => lower_str!(PaymentHolidayCalendar, value),
     }
 }
 
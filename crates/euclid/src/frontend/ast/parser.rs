@@ -1,9 +1,53 @@
-use common_utils::types::MinorUnit;
+//! Parser for the textual representation of routing programs
+//!
+//! ```text
+//! // Line comments are ignored
+//! @metadata {"owner": "payments-ops"}
+//! default: [stripe, adyen("mca_123")]
+//!
+//! card_rule: {stripe: 60%, adyen: 40%}
+//! {
+//!     payment_method = card & amount >= 1000 {
+//!         card_network = (visa, mastercard)
+//!     }
+//!     "merchant_tier" = "gold"
+//! }
+//!
+//! "weekend traffic": [checkout]
+//! {
+//!     payment_day_of_week = (saturday, sunday) @metadata {"source": "dashboard"}
+//! }
+//! ```
+//!
+//! - Values are numbers (`100`), enum variants (`card`), strings (`"abc"`), metadata values
+//!   (`meta("key", "value")`) and arrays of numbers, number comparisons or enum variants, such as
+//!   `(10, 20)`, `(> 10, < 20)` and `(visa, mastercard)`. Arrays hold at least one value.
+//! - A bare connector (`stripe`) is a connector only choice, while a connector followed by
+//!   parentheses (`stripe()` or `stripe("mca_123")`) is a full choice.
+//! - `"key" = "value"` is a shorthand for `metadata = meta("key", "value")`.
+//! - The metadata of the program and of a comparison is written as a JSON object following
+//!   `@metadata`.
+//! - Rule names that are not identifiers are written as strings.
+//!
+//! [`super::printer::print_program`] produces this representation from a program.
+
+use std::str::FromStr;
+
+use common_enums::RoutableConnectors;
+use common_utils::{id_type, types::MinorUnit};
 use nom::{
-    branch, bytes::complete, character::complete as pchar, combinator, error, multi, sequence,
+    branch,
+    bytes::complete,
+    character::complete as pchar,
+    combinator,
+    error::{self, ParseError},
+    multi, sequence,
 };
 
-use crate::{frontend::ast, types::DummyOutput};
+use crate::{
+    frontend::ast,
+    types::{DummyOutput, Metadata},
+};
 pub type ParseResult<T, U> = nom::IResult<T, U, error::VerboseError<T>>;
 
 pub enum EuclidError {
@@ -11,6 +55,7 @@ pub enum EuclidError {
     InvalidConnector(String),
     InvalidOperator(String),
     InvalidNumber(String),
+    InvalidMerchantConnectorId(String),
 }
 
 pub trait EuclidParsable: Sized {
@@ -19,50 +64,124 @@ pub trait EuclidParsable: Sized {
 
 impl EuclidParsable for DummyOutput {
     fn parse_output(input: &str) -> ParseResult<&str, Self> {
-        let string_w = sequence::delimited(
-            skip_ws(complete::tag("\"")),
-            complete::take_while(|c| c != '"'),
-            skip_ws(complete::tag("\"")),
-        );
-        let full_sequence = multi::many0(sequence::preceded(
-            skip_ws(complete::tag(",")),
-            sequence::delimited(
-                skip_ws(complete::tag("\"")),
-                complete::take_while(|c| c != '"'),
-                skip_ws(complete::tag("\"")),
-            ),
-        ));
-        let sequence = sequence::pair(string_w, full_sequence);
         error::context(
             "dummy_strings",
             combinator::map(
                 sequence::delimited(
                     skip_ws(complete::tag("[")),
-                    sequence,
+                    multi::separated_list0(skip_ws(complete::tag(",")), skip_ws(string_str)),
                     skip_ws(complete::tag("]")),
                 ),
-                |out: (&str, Vec<&str>)| {
-                    let mut first = out.1;
-                    first.insert(0, out.0);
-                    let v = first.iter().map(|s| s.to_string()).collect();
-                    Self { outputs: v }
-                },
+                |outputs| Self { outputs },
+            ),
+        )(input)
+    }
+}
+impl EuclidParsable for ast::ConnectorSelection {
+    fn parse_output(input: &str) -> ParseResult<&str, Self> {
+        let volume_split = combinator::map(
+            sequence::separated_pair(
+                skip_ws(connector_choice),
+                skip_ws(complete::tag(":")),
+                skip_ws(percentage),
             ),
+            |(connector, split)| ast::ConnectorVolumeSplit { connector, split },
+        );
+
+        error::context(
+            "connector_selection",
+            branch::alt((
+                combinator::map(
+                    sequence::delimited(
+                        skip_ws(complete::tag("[")),
+                        multi::separated_list0(
+                            skip_ws(complete::tag(",")),
+                            skip_ws(connector_choice),
+                        ),
+                        skip_ws(complete::tag("]")),
+                    ),
+                    Self::Priority,
+                ),
+                combinator::map(
+                    sequence::delimited(
+                        skip_ws(complete::tag("{")),
+                        multi::separated_list0(skip_ws(complete::tag(",")), volume_split),
+                        skip_ws(complete::tag("}")),
+                    ),
+                    Self::VolumeSplit,
+                ),
+            )),
         )(input)
     }
 }
+
+/// Parses a connector along with an optional merchant connector id, e.g. `adyen("mca_123")`
+pub fn connector_choice(input: &str) -> ParseResult<&str, ast::RoutableConnectorChoice> {
+    let connector = combinator::map_res(identifier, |name: String| {
+        RoutableConnectors::from_str(&name).map_err(|_| EuclidError::InvalidConnector(name))
+    });
+
+    let merchant_connector_id = combinator::map_res(skip_ws(string_str), |id: String| {
+        id_type::MerchantConnectorAccountId::wrap(id.clone())
+            .map_err(|_| EuclidError::InvalidMerchantConnectorId(id))
+    });
+
+    // A bare connector is a connector only choice, while a connector followed by parentheses,
+    // e.g. `stripe()` or `stripe("mca_123")`, is a full choice
+    let full_choice = sequence::delimited(
+        skip_ws(complete::tag("(")),
+        combinator::opt(merchant_connector_id),
+        skip_ws(complete::tag(")")),
+    );
+
+    error::context(
+        "connector_choice",
+        combinator::map(
+            sequence::pair(connector, combinator::opt(full_choice)),
+            |(connector, full_choice)| match full_choice {
+                Some(merchant_connector_id) => ast::RoutableConnectorChoice {
+                    choice_kind: ast::RoutableChoiceKind::FullStruct,
+                    connector,
+                    merchant_connector_id,
+                },
+                None => ast::RoutableConnectorChoice {
+                    choice_kind: ast::RoutableChoiceKind::OnlyConnector,
+                    connector,
+                    merchant_connector_id: None,
+                },
+            },
+        ),
+    )(input)
+}
+
+/// Skips whitespace and `//` line comments
+pub fn ws_or_comment(input: &str) -> ParseResult<&str, ()> {
+    let comment = combinator::recognize(sequence::pair(
+        complete::tag("//"),
+        complete::take_while(|c: char| c != '\n'),
+    ));
+
+    combinator::value(
+        (),
+        multi::many0_count(branch::alt((pchar::multispace1, comment))),
+    )(input)
+}
+
 pub fn skip_ws<'a, F, O>(inner: F) -> impl FnMut(&'a str) -> ParseResult<&'a str, O>
 where
     F: FnMut(&'a str) -> ParseResult<&'a str, O> + 'a,
 {
-    sequence::preceded(pchar::multispace0, inner)
+    sequence::preceded(ws_or_comment, inner)
 }
 
 pub fn num_i64(input: &str) -> ParseResult<&str, i64> {
     error::context(
         "num_i32",
         combinator::map_res(
-            complete::take_while1(|c: char| c.is_ascii_digit()),
+            combinator::recognize(sequence::pair(
+                combinator::opt(complete::tag("-")),
+                complete::take_while1(|c: char| c.is_ascii_digit()),
+            )),
             |o: &str| {
                 o.parse::<i64>()
                     .map_err(|_| EuclidError::InvalidNumber(o.to_string()))
@@ -71,20 +190,74 @@ pub fn num_i64(input: &str) -> ParseResult<&str, i64> {
     )(input)
 }
 
+/// Parses a double quoted string, in which `\"` and `\\` are the only escape sequences
 pub fn string_str(input: &str) -> ParseResult<&str, String> {
+    fn string_body(input: &str) -> ParseResult<&str, String> {
+        let mut value = String::new();
+        let mut chars = input.char_indices();
+
+        while let Some((index, c)) = chars.next() {
+            match c {
+                '"' => return Ok((input.split_at(index).1, value)),
+                '\\' => match chars.next() {
+                    Some((_, escaped @ ('"' | '\\'))) => value.push(escaped),
+                    _ => {
+                        return Err(nom::Err::Error(error::VerboseError::from_error_kind(
+                            input.split_at(index).1,
+                            error::ErrorKind::Escaped,
+                        )))
+                    }
+                },
+                c => value.push(c),
+            }
+        }
+
+        Err(nom::Err::Error(error::VerboseError::from_error_kind(
+            input,
+            error::ErrorKind::Eof,
+        )))
+    }
+
     error::context(
         "String",
+        sequence::delimited(complete::tag("\""), string_body, complete::tag("\"")),
+    )(input)
+}
+
+/// Parses a comparison key, which may contain dots, e.g. `payment.method`
+pub fn key_identifier(input: &str) -> ParseResult<&str, String> {
+    error::context(
+        "key_identifier",
         combinator::map(
-            sequence::delimited(
-                complete::tag("\""),
-                complete::take_while1(|c: char| c != '"'),
-                complete::tag("\""),
-            ),
-            |val: &str| val.to_string(),
+            combinator::recognize(sequence::pair(
+                complete::take_while1(|c: char| c.is_ascii_alphabetic() || c == '_'),
+                complete::take_while(|c: char| c.is_ascii_alphanumeric() || c == '_' || c == '.'),
+            )),
+            |key: &str| key.to_string(),
         ),
     )(input)
 }
 
+/// Parses a JSON object following `@metadata`
+pub fn metadata_annotation(input: &str) -> ParseResult<&str, Metadata> {
+    fn metadata_json(input: &str) -> ParseResult<&str, Metadata> {
+        let mut stream = serde_json::Deserializer::from_str(input).into_iter::<Metadata>();
+
+        match stream.next() {
+            Some(Ok(metadata)) => Ok((input.split_at(stream.byte_offset()).1, metadata)),
+            _ => Err(nom::Err::Error(error::VerboseError::from_error_kind(
+                input,
+                error::ErrorKind::Verify,
+            ))),
+        }
+    }
+
+    error::context(
+        "metadata",
+        sequence::preceded(complete::tag("@metadata"), skip_ws(metadata_json)),
+    )(input)
+}
+
 pub fn identifier(input: &str) -> ParseResult<&str, String> {
     error::context(
         "identifier",
@@ -102,8 +275,8 @@ pub fn percentage(input: &str) -> ParseResult<&str, u8> {
         "volume_split_percentage",
         combinator::map_res(
             sequence::terminated(
-                complete::take_while_m_n(1, 2, |c: char| c.is_ascii_digit()),
-                complete::tag("%"),
+                complete::take_while_m_n(1, 3, |c: char| c.is_ascii_digit()),
+                skip_ws(complete::tag("%")),
             ),
             |o: &str| {
                 o.parse::<u8>()
@@ -120,6 +293,27 @@ pub fn number_value(input: &str) -> ParseResult<&str, ast::ValueType> {
     )(input)
 }
 
+pub fn metadata_value(input: &str) -> ParseResult<&str, ast::ValueType> {
+    error::context(
+        "metadata_value",
+        combinator::map(
+            sequence::preceded(
+                complete::tag("meta"),
+                sequence::delimited(
+                    skip_ws(complete::tag("(")),
+                    sequence::separated_pair(
+                        skip_ws(string_str),
+                        skip_ws(complete::tag(",")),
+                        skip_ws(string_str),
+                    ),
+                    skip_ws(complete::tag(")")),
+                ),
+            ),
+            |(key, value)| ast::ValueType::MetadataVariant(ast::MetadataValue { key, value }),
+        ),
+    )(input)
+}
+
 pub fn str_value(input: &str) -> ParseResult<&str, ast::ValueType> {
     error::context(
         "str_value",
@@ -197,26 +391,10 @@ pub fn enum_variant_array_value(input: &str) -> ParseResult<&str, ast::ValueType
 }
 
 pub fn number_comparison(input: &str) -> ParseResult<&str, ast::NumberComparison> {
-    let operator = combinator::map_res(
-        branch::alt((
-            complete::tag(">="),
-            complete::tag("<="),
-            complete::tag(">"),
-            complete::tag("<"),
-        )),
-        |s: &str| match s {
-            ">=" => Ok(ast::ComparisonType::GreaterThanEqual),
-            "<=" => Ok(ast::ComparisonType::LessThanEqual),
-            ">" => Ok(ast::ComparisonType::GreaterThan),
-            "<" => Ok(ast::ComparisonType::LessThan),
-            _ => Err(EuclidError::InvalidOperator(s.to_string())),
-        },
-    );
-
     error::context(
         "number_comparison",
         combinator::map(
-            sequence::pair(operator, num_i64),
+            sequence::pair(comparison_type, skip_ws(num_i64)),
             |tup: (ast::ComparisonType, i64)| ast::NumberComparison {
                 comparison_type: tup.0,
                 number: MinorUnit::new(tup.1),
@@ -255,6 +433,7 @@ pub fn value_type(input: &str) -> ParseResult<&str, ast::ValueType> {
         "value_type",
         branch::alt((
             number_value,
+            metadata_value,
             enum_variant_value,
             enum_variant_array_value,
             number_array_value,
@@ -294,17 +473,23 @@ pub fn comparison(input: &str) -> ParseResult<&str, ast::Comparison> {
         "condition",
         combinator::map(
             sequence::tuple((
-                skip_ws(complete::take_while1(|c: char| {
-                    c.is_ascii_alphabetic() || c == '.' || c == '_'
-                })),
+                skip_ws(key_identifier),
                 skip_ws(comparison_type),
                 skip_ws(value_type),
+                combinator::opt(skip_ws(metadata_annotation)),
             )),
-            |tup: (&str, ast::ComparisonType, ast::ValueType)| ast::Comparison {
-                lhs: tup.0.to_string(),
-                comparison: tup.1,
-                value: tup.2,
-                metadata: std::collections::HashMap::new(),
+            |tup: (
+                String,
+                ast::ComparisonType,
+                ast::ValueType,
+                Option<Metadata>,
+            )| {
+                ast::Comparison {
+                    lhs: tup.0,
+                    comparison: tup.1,
+                    value: tup.2,
+                    metadata: tup.3.unwrap_or_default(),
+                }
             },
         ),
     )(input)
@@ -318,15 +503,16 @@ pub fn arbitrary_comparison(input: &str) -> ParseResult<&str, ast::Comparison> {
                 skip_ws(string_str),
                 skip_ws(comparison_type),
                 skip_ws(string_str),
+                combinator::opt(skip_ws(metadata_annotation)),
             )),
-            |tup: (String, ast::ComparisonType, String)| ast::Comparison {
+            |tup: (String, ast::ComparisonType, String, Option<Metadata>)| ast::Comparison {
                 lhs: "metadata".to_string(),
                 comparison: tup.1,
                 value: ast::ValueType::MetadataVariant(ast::MetadataValue {
                     key: tup.0,
                     value: tup.2,
                 }),
-                metadata: std::collections::HashMap::new(),
+                metadata: tup.3.unwrap_or_default(),
             },
         ),
     )(input)
@@ -335,7 +521,10 @@ pub fn arbitrary_comparison(input: &str) -> ParseResult<&str, ast::Comparison> {
 pub fn comparison_array(input: &str) -> ParseResult<&str, Vec<ast::Comparison>> {
     let many_with_ampersand = error::context(
         "many_with_amp",
-        multi::many0(sequence::preceded(skip_ws(complete::tag("&")), comparison)),
+        multi::many0(sequence::preceded(
+            skip_ws(complete::tag("&")),
+            branch::alt((comparison, arbitrary_comparison)),
+        )),
     );
 
     let full_sequence = sequence::pair(
@@ -380,23 +569,14 @@ pub fn rule_conditions_array(input: &str) -> ParseResult<&str, Vec<ast::IfStatem
         "rules_array",
         sequence::delimited(
             skip_ws(complete::tag("{")),
-            multi::many1(if_statement),
+            multi::many0(if_statement),
             skip_ws(complete::tag("}")),
         ),
     )(input)
 }
 
 pub fn rule<O: EuclidParsable>(input: &str) -> ParseResult<&str, ast::Rule<O>> {
-    let rule_name = error::context(
-        "rule_name",
-        combinator::map(
-            skip_ws(sequence::pair(
-                complete::take_while1(|c: char| c.is_ascii_alphabetic() || c == '_'),
-                complete::take_while(|c: char| c.is_ascii_alphanumeric() || c == '_'),
-            )),
-            |out: (&str, &str)| out.0.to_string() + out.1,
-        ),
-    );
+    let rule_name = error::context("rule_name", skip_ws(branch::alt((identifier, string_str))));
 
     let connector_selection = error::context(
         "parse_output",
@@ -434,12 +614,27 @@ pub fn program<O: EuclidParsable + 'static>(input: &str) -> ParseResult<&str, as
     error::context(
         "program",
         combinator::map(
-            sequence::pair(default_output, multi::many1(skip_ws(rule::<O>))),
-            |tup: (O, Vec<ast::Rule<O>>)| ast::Program {
-                default_selection: tup.0,
-                rules: tup.1,
-                metadata: std::collections::HashMap::new(),
+            sequence::tuple((
+                combinator::opt(skip_ws(metadata_annotation)),
+                default_output,
+                multi::many0(skip_ws(rule::<O>)),
+            )),
+            |tup: (Option<Metadata>, O, Vec<ast::Rule<O>>)| ast::Program {
+                default_selection: tup.1,
+                rules: tup.2,
+                metadata: tup.0.unwrap_or_default(),
             },
         ),
     )(input)
 }
+
+/// Parses the complete textual representation of a program, returning a readable description
+/// of the location and cause of the failure otherwise
+pub fn parse_program<O: EuclidParsable + 'static>(input: &str) -> Result<ast::Program<O>, String> {
+    sequence::terminated(program::<O>, skip_ws(combinator::eof))(input)
+        .map(|(_, program)| program)
+        .map_err(|err| match err {
+            nom::Err::Error(err) | nom::Err::Failure(err) => error::convert_error(input, err),
+            nom::Err::Incomplete(_) => "Incomplete program".to_string(),
+        })
+}
@@ -0,0 +1,415 @@
+//! Pretty-printer for the textual representation of routing programs
+//!
+//! The output of [`print_program`] can be parsed back into the same program with
+//! `parser::parse_program` when the `ast_parser` feature is enabled.
+
+use std::collections::BTreeMap;
+
+use crate::{
+    frontend::ast,
+    types::{DummyOutput, Metadata},
+};
+
+const INDENT: &str = "    ";
+
+pub trait EuclidPrintable {
+    fn print_output(&self) -> String;
+}
+
+impl EuclidPrintable for DummyOutput {
+    fn print_output(&self) -> String {
+        let outputs = self
+            .outputs
+            .iter()
+            .map(|output| print_string(output))
+            .collect::<Vec<_>>();
+
+        format!("[{}]", outputs.join(", "))
+    }
+}
+
+impl EuclidPrintable for ast::ConnectorSelection {
+    fn print_output(&self) -> String {
+        match self {
+            Self::Priority(connectors) => {
+                let connectors = connectors
+                    .iter()
+                    .map(print_connector_choice)
+                    .collect::<Vec<_>>();
+
+                format!("[{}]", connectors.join(", "))
+            }
+            Self::VolumeSplit(splits) => {
+                let splits = splits
+                    .iter()
+                    .map(|split| {
+                        format!(
+                            "{}: {}%",
+                            print_connector_choice(&split.connector),
+                            split.split
+                        )
+                    })
+                    .collect::<Vec<_>>();
+
+                format!("{{{}}}", splits.join(", "))
+            }
+        }
+    }
+}
+
+/// Prints the program in its textual representation. Rules and nested statements are printed
+/// in their original order, and metadata keys are sorted so that the output is stable.
+///
+/// Comparisons against empty arrays have no textual representation, since the type of the array
+/// cannot be told from its values, and fail the printing.
+pub fn print_program<O: EuclidPrintable>(program: &ast::Program<O>) -> Result<String, String> {
+    let mut output = String::new();
+
+    if !program.metadata.is_empty() {
+        output.push_str(&format!(
+            "@metadata {}\n",
+            print_metadata(&program.metadata)
+        ));
+    }
+
+    output.push_str(&format!(
+        "default: {}\n",
+        program.default_selection.print_output()
+    ));
+
+    for rule in &program.rules {
+        output.push_str(&format!(
+            "\n{}: {}\n{{\n",
+            print_rule_name(&rule.name),
+            rule.connector_selection.print_output()
+        ));
+
+        for statement in &rule.statements {
+            print_if_statement(&mut output, statement, 1)?;
+        }
+
+        output.push_str("}\n");
+    }
+
+    Ok(output)
+}
+
+fn print_if_statement(
+    output: &mut String,
+    statement: &ast::IfStatement,
+    depth: usize,
+) -> Result<(), String> {
+    let indent = INDENT.repeat(depth);
+    let condition = statement
+        .condition
+        .iter()
+        .map(print_comparison)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    output.push_str(&indent);
+    output.push_str(&condition.join(" & "));
+
+    match &statement.nested {
+        Some(nested) => {
+            output.push_str(" {\n");
+            for nested_statement in nested {
+                print_if_statement(output, nested_statement, depth + 1)?;
+            }
+            output.push_str(&indent);
+            output.push_str("}\n");
+        }
+        None => output.push('\n'),
+    }
+
+    Ok(())
+}
+
+fn print_comparison(comparison: &ast::Comparison) -> Result<String, String> {
+    let operator = print_comparison_type(&comparison.comparison);
+
+    let mut printed = match &comparison.value {
+        ast::ValueType::MetadataVariant(metadata) if comparison.lhs == "metadata" => format!(
+            "{} {} {}",
+            print_string(&metadata.key),
+            operator,
+            print_string(&metadata.value)
+        ),
+        value => {
+            let value = print_value(value).ok_or_else(|| {
+                format!(
+                    "The comparison on '{}' has an empty array of values",
+                    comparison.lhs
+                )
+            })?;
+            format!("{} {} {}", comparison.lhs, operator, value)
+        }
+    };
+
+    if !comparison.metadata.is_empty() {
+        printed.push_str(&format!(
+            " @metadata {}",
+            print_metadata(&comparison.metadata)
+        ));
+    }
+
+    Ok(printed)
+}
+
+/// Returns `None` for empty arrays
+fn print_value(value: &ast::ValueType) -> Option<String> {
+    let printed = match value {
+        ast::ValueType::Number(number) => number.get_amount_as_i64().to_string(),
+        ast::ValueType::EnumVariant(variant) => variant.clone(),
+        ast::ValueType::MetadataVariant(metadata) => format!(
+            "meta({}, {})",
+            print_string(&metadata.key),
+            print_string(&metadata.value)
+        ),
+        ast::ValueType::StrValue(value) => print_string(value),
+        ast::ValueType::NumberArray(numbers) if numbers.is_empty() => return None,
+        ast::ValueType::EnumVariantArray(variants) if variants.is_empty() => return None,
+        ast::ValueType::NumberComparisonArray(comparisons) if comparisons.is_empty() => {
+            return None
+        }
+        ast::ValueType::NumberArray(numbers) => {
+            let numbers = numbers
+                .iter()
+                .map(|number| number.get_amount_as_i64().to_string())
+                .collect::<Vec<_>>();
+
+            format!("({})", numbers.join(", "))
+        }
+        ast::ValueType::EnumVariantArray(variants) => format!("({})", variants.join(", ")),
+        ast::ValueType::NumberComparisonArray(comparisons) => {
+            let comparisons = comparisons
+                .iter()
+                .map(|comparison| {
+                    format!(
+                        "{} {}",
+                        print_comparison_type(&comparison.comparison_type),
+                        comparison.number.get_amount_as_i64()
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            format!("({})", comparisons.join(", "))
+        }
+    };
+
+    Some(printed)
+}
+
+fn print_comparison_type(comparison_type: &ast::ComparisonType) -> &'static str {
+    match comparison_type {
+        ast::ComparisonType::Equal => "=",
+        ast::ComparisonType::NotEqual => "/=",
+        ast::ComparisonType::LessThan => "<",
+        ast::ComparisonType::LessThanEqual => "<=",
+        ast::ComparisonType::GreaterThan => ">",
+        ast::ComparisonType::GreaterThanEqual => ">=",
+    }
+}
+
+/// Connector only choices are printed as the bare connector, and full choices with parentheses
+/// holding the merchant connector id, if any
+fn print_connector_choice(choice: &ast::RoutableConnectorChoice) -> String {
+    match (&choice.choice_kind, &choice.merchant_connector_id) {
+        (_, Some(merchant_connector_id)) => format!(
+            "{}({})",
+            choice.connector,
+            print_string(merchant_connector_id.get_string_repr())
+        ),
+        (ast::RoutableChoiceKind::FullStruct, None) => format!("{}()", choice.connector),
+        (ast::RoutableChoiceKind::OnlyConnector, None) => choice.connector.to_string(),
+    }
+}
+
+/// Rule names that are not identifiers are printed as strings
+fn print_rule_name(name: &str) -> String {
+    let mut chars = name.chars();
+    let is_identifier = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+    if is_identifier {
+        name.to_string()
+    } else {
+        print_string(name)
+    }
+}
+
+fn print_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn print_metadata(metadata: &Metadata) -> String {
+    let sorted = metadata.iter().collect::<BTreeMap<_, _>>();
+    serde_json::to_string(&sorted).unwrap_or_else(|_| "{}".to_string())
+}
+
+#[cfg(all(test, feature = "ast_parser"))]
+mod tests {
+    #![allow(clippy::expect_used, clippy::panic)]
+
+    use super::*;
+    use crate::frontend::ast::parser;
+
+    #[test]
+    fn test_connector_selection_program_round_trip() {
+        let program_str = r#"
+        // Program level metadata is preserved
+        @metadata {"owner": "payments-ops"}
+        default: [stripe, adyen("mca_123")]
+
+        card_rule: {stripe: 60%, adyen: 40%}
+        {
+            payment_method = card & amount >= 1000 {
+                card_network = (visa, mastercard)
+                amount = (> 100, <= 5000) & "tier" = "gold"
+            }
+            billing_country /= (india, china) @metadata {"source": "dashboard"}
+        }
+
+        "weekend \"traffic\"": [checkout]
+        {
+            business_label = "EU \\ retail" & metadata = meta("channel", "app")
+        }
+
+        empty_rule: []
+        {
+        }
+        "#;
+
+        let program = parser::parse_program::<ast::ConnectorSelection>(program_str)
+            .expect("Program should be parsed");
+        assert_eq!(program.rules.len(), 3);
+
+        let printed = print_program(&program).expect("Program should be printed");
+        let reparsed = parser::parse_program::<ast::ConnectorSelection>(&printed)
+            .expect("Printed program should be parsed");
+
+        assert_eq!(
+            serde_json::to_value(&program).expect("Program"),
+            serde_json::to_value(&reparsed).expect("Reparsed program")
+        );
+        assert_eq!(
+            printed,
+            print_program(&reparsed).expect("Reparsed program should be printed")
+        );
+    }
+
+    #[test]
+    fn test_connector_choice_kind_round_trip() {
+        let program_str = r#"
+        default: [stripe, adyen(), checkout("mca_123")]
+        "#;
+
+        let program = parser::parse_program::<ast::ConnectorSelection>(program_str)
+            .expect("Program should be parsed");
+        let printed = print_program(&program).expect("Program should be printed");
+        let reparsed = parser::parse_program::<ast::ConnectorSelection>(&printed)
+            .expect("Printed program should be parsed");
+
+        let ast::ConnectorSelection::Priority(choices) = reparsed.default_selection else {
+            panic!("Expected a priority selection");
+        };
+        let choice_kinds = choices
+            .iter()
+            .map(|choice| {
+                (
+                    choice.connector.to_string(),
+                    matches!(choice.choice_kind, ast::RoutableChoiceKind::OnlyConnector),
+                    choice
+                        .merchant_connector_id
+                        .as_ref()
+                        .map(|id| id.get_string_repr().to_string()),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            choice_kinds,
+            vec![
+                ("stripe".to_string(), true, None),
+                ("adyen".to_string(), false, None),
+                ("checkout".to_string(), false, Some("mca_123".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dummy_output_program_round_trip() {
+        let program = ast::Program {
+            metadata: Metadata::new(),
+            default_selection: DummyOutput { outputs: vec![] },
+            rules: vec![ast::Rule {
+                name: "quoted".to_string(),
+                connector_selection: DummyOutput {
+                    outputs: vec!["say \"hi\"".to_string(), "back\\slash".to_string()],
+                },
+                statements: vec![],
+            }],
+        };
+
+        let printed = print_program(&program).expect("Program should be printed");
+        let reparsed = parser::parse_program::<DummyOutput>(&printed)
+            .expect("Printed program should be parsed");
+
+        assert!(reparsed.default_selection.outputs.is_empty());
+        assert_eq!(
+            reparsed
+                .rules
+                .first()
+                .map(|rule| rule.connector_selection.outputs.clone()),
+            Some(vec!["say \"hi\"".to_string(), "back\\slash".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_print_program_rejects_empty_arrays() {
+        let program = ast::Program {
+            metadata: Metadata::new(),
+            default_selection: ast::ConnectorSelection::Priority(vec![]),
+            rules: vec![ast::Rule {
+                name: "empty_array".to_string(),
+                connector_selection: ast::ConnectorSelection::Priority(vec![]),
+                statements: vec![ast::IfStatement {
+                    condition: vec![ast::Comparison {
+                        lhs: "card_network".to_string(),
+                        comparison: ast::ComparisonType::Equal,
+                        value: ast::ValueType::EnumVariantArray(vec![]),
+                        metadata: Metadata::new(),
+                    }],
+                    nested: None,
+                }],
+            }],
+        };
+
+        assert!(print_program(&program).is_err());
+    }
+
+    #[test]
+    fn test_parse_program_reports_trailing_input() {
+        let program_str = r#"
+        default: [stripe]
+
+        rule_1: [adyen]
+        {
+            payment_method = card
+        }
+        }
+        "#;
+
+        assert!(parser::parse_program::<ast::ConnectorSelection>(program_str).is_err());
+    }
+
+    #[test]
+    fn test_parse_program_rejects_unknown_connector() {
+        let program_str = r#"
+        default: [not_a_connector]
+        "#;
+
+        assert!(parser::parse_program::<ast::ConnectorSelection>(program_str).is_err());
+    }
+}
@@ -45,7 +45,7 @@ macro_rules! dirval {
     }};
 }
 
-#[derive(Debug, Clone, Hash, PartialEq, Eq, serde::Serialize)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct DirKey {
     pub kind: DirKeyKind,
     pub value: Option<String>,
@@ -64,6 +64,7 @@ impl DirKey {
     PartialEq,
     Eq,
     serde::Serialize,
+    serde::Deserialize,
     strum::Display,
     strum::EnumIter,
     strum::VariantNames,
@@ -257,6 +258,13 @@ pub enum DirKeyKind {
     )]
     #[serde(rename = "setup_future_usage")]
     SetupFutureUsage,
+    #[strum(
+        serialize = "installment_interval",
+        detailed_message = "Interval of the installment plan requested for the payment",
+        props(Category = "Payments")
+    )]
+    #[serde(rename = "installment_interval")]
+    InstallmentInterval,
     #[strum(
         serialize = "card_redirect",
         detailed_message = "Supported types of Card Redirect payment method",
@@ -334,6 +342,34 @@ pub enum DirKeyKind {
     )]
     #[serde(rename = "acquirer_fraud_rate")]
     AcquirerFraudRate,
+    #[strum(
+        serialize = "payment_hour_of_day",
+        detailed_message = "Hour of the day (0-23) at which the payment was initiated, in the merchant's timezone",
+        props(Category = "Payment Time")
+    )]
+    #[serde(rename = "payment_hour_of_day")]
+    PaymentHourOfDay,
+    #[strum(
+        serialize = "payment_day_of_week",
+        detailed_message = "Day of the week on which the payment was initiated, in the merchant's timezone",
+        props(Category = "Payment Time")
+    )]
+    #[serde(rename = "payment_day_of_week")]
+    PaymentDayOfWeek,
+    #[strum(
+        serialize = "payment_date",
+        detailed_message = "Date on which the payment was initiated in the merchant's timezone, in YYYYMMDD format",
+        props(Category = "Payment Time")
+    )]
+    #[serde(rename = "payment_date")]
+    PaymentDate,
+    #[strum(
+        serialize = "payment_holiday_calendar",
+        detailed_message = "Name of a configured holiday calendar that contains the payment date",
+        props(Category = "Payment Time")
+    )]
+    #[serde(rename = "payment_holiday_calendar")]
+    PaymentHolidayCalendar,
 }
 
 pub trait EuclidDirFilter: Sized
@@ -380,6 +416,7 @@ impl DirKeyKind {
             Self::BankDebitType => types::DataType::EnumVariant,
             Self::BusinessLabel => types::DataType::StrValue,
             Self::SetupFutureUsage => types::DataType::EnumVariant,
+            Self::InstallmentInterval => types::DataType::EnumVariant,
             Self::CardRedirectType => types::DataType::EnumVariant,
             Self::RealTimePaymentType => types::DataType::EnumVariant,
             Self::OpenBankingType => types::DataType::EnumVariant,
@@ -391,6 +428,10 @@ impl DirKeyKind {
             Self::CustomerDeviceDisplaySize => types::DataType::EnumVariant,
             Self::AcquirerCountry => types::DataType::EnumVariant,
             Self::AcquirerFraudRate => types::DataType::Number,
+            Self::PaymentHourOfDay => types::DataType::Number,
+            Self::PaymentDayOfWeek => types::DataType::EnumVariant,
+            Self::PaymentDate => types::DataType::Number,
+            Self::PaymentHolidayCalendar => types::DataType::StrValue,
         }
     }
     pub fn get_value_set(&self) -> Option<Vec<DirValue>> {
@@ -508,6 +549,11 @@ impl DirKeyKind {
                     .map(DirValue::SetupFutureUsage)
                     .collect(),
             ),
+            Self::InstallmentInterval => Some(
+                enums::InstallmentInterval::iter()
+                    .map(DirValue::InstallmentInterval)
+                    .collect(),
+            ),
             Self::CardRedirectType => Some(
                 enums::CardRedirectType::iter()
                     .map(DirValue::CardRedirectType)
@@ -555,12 +601,28 @@ impl DirKeyKind {
                     .collect(),
             ),
             Self::AcquirerFraudRate => None,
+            Self::PaymentHourOfDay => None,
+            Self::PaymentDayOfWeek => Some(
+                enums::PaymentDayOfWeek::iter()
+                    .map(DirValue::PaymentDayOfWeek)
+                    .collect(),
+            ),
+            Self::PaymentDate => None,
+            Self::PaymentHolidayCalendar => None,
         }
     }
 }
 
 #[derive(
-    Debug, Clone, Hash, PartialEq, Eq, serde::Serialize, strum::Display, strum::VariantNames,
+    Debug,
+    Clone,
+    Hash,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::VariantNames,
 )]
 #[serde(tag = "key", content = "value")]
 pub enum DirValue {
@@ -612,12 +674,14 @@ pub enum DirValue {
     BusinessCountry(enums::Country),
     #[serde(rename = "billing_country")]
     BillingCountry(enums::Country),
-    #[serde(skip_deserializing, rename = "connector")]
+    #[serde(rename = "connector")]
     Connector(Box<ast::ConnectorChoice>),
     #[serde(rename = "business_label")]
     BusinessLabel(types::StrValue),
     #[serde(rename = "setup_future_usage")]
     SetupFutureUsage(enums::SetupFutureUsage),
+    #[serde(rename = "installment_interval")]
+    InstallmentInterval(enums::InstallmentInterval),
     #[serde(rename = "card_redirect")]
     CardRedirectType(enums::CardRedirectType),
     #[serde(rename = "real_time_payment")]
@@ -640,6 +704,14 @@ pub enum DirValue {
     AcquirerCountry(enums::Country),
     #[serde(rename = "acquirer_fraud_rate")]
     AcquirerFraudRate(types::NumValue),
+    #[serde(rename = "payment_hour_of_day")]
+    PaymentHourOfDay(types::NumValue),
+    #[serde(rename = "payment_day_of_week")]
+    PaymentDayOfWeek(enums::PaymentDayOfWeek),
+    #[serde(rename = "payment_date")]
+    PaymentDate(types::NumValue),
+    #[serde(rename = "payment_holiday_calendar")]
+    PaymentHolidayCalendar(types::StrValue),
 }
 
 impl DirValue {
@@ -670,6 +742,7 @@ impl DirValue {
             Self::PaymentType(_) => (DirKeyKind::PaymentType, None),
             Self::BusinessLabel(_) => (DirKeyKind::BusinessLabel, None),
             Self::SetupFutureUsage(_) => (DirKeyKind::SetupFutureUsage, None),
+            Self::InstallmentInterval(_) => (DirKeyKind::InstallmentInterval, None),
             Self::CardRedirectType(_) => (DirKeyKind::CardRedirectType, None),
             Self::VoucherType(_) => (DirKeyKind::VoucherType, None),
             Self::GiftCardType(_) => (DirKeyKind::GiftCardType, None),
@@ -683,6 +756,10 @@ impl DirValue {
             Self::CustomerDeviceDisplaySize(_) => (DirKeyKind::CustomerDeviceDisplaySize, None),
             Self::AcquirerCountry(_) => (DirKeyKind::AcquirerCountry, None),
             Self::AcquirerFraudRate(_) => (DirKeyKind::AcquirerFraudRate, None),
+            Self::PaymentHourOfDay(_) => (DirKeyKind::PaymentHourOfDay, None),
+            Self::PaymentDayOfWeek(_) => (DirKeyKind::PaymentDayOfWeek, None),
+            Self::PaymentDate(_) => (DirKeyKind::PaymentDate, None),
+            Self::PaymentHolidayCalendar(_) => (DirKeyKind::PaymentHolidayCalendar, None),
         };
 
         DirKey::new(kind, data)
@@ -716,6 +793,7 @@ impl DirValue {
             Self::PaymentType(_) => None,
             Self::BusinessLabel(_) => None,
             Self::SetupFutureUsage(_) => None,
+            Self::InstallmentInterval(_) => None,
             Self::CardRedirectType(_) => None,
             Self::RealTimePaymentType(_) => None,
             Self::OpenBankingType(_) => None,
@@ -727,6 +805,10 @@ impl DirValue {
             Self::CustomerDeviceDisplaySize(_) => None,
             Self::AcquirerCountry(_) => None,
             Self::AcquirerFraudRate(_) => None,
+            Self::PaymentHourOfDay(_) => None,
+            Self::PaymentDayOfWeek(_) => None,
+            Self::PaymentDate(_) => None,
+            Self::PaymentHolidayCalendar(_) => None,
         }
     }
 
@@ -734,6 +816,7 @@ impl DirValue {
         match self {
             Self::CardBin(val) => Some(val.clone()),
             Self::IssuerName(val) => Some(val.clone()),
+            Self::PaymentHolidayCalendar(val) => Some(val.clone()),
             _ => None,
         }
     }
@@ -742,6 +825,8 @@ impl DirValue {
         match self {
             Self::PaymentAmount(val) => Some(val.clone()),
             Self::AcquirerFraudRate(val) => Some(val.clone()),
+            Self::PaymentHourOfDay(val) => Some(val.clone()),
+            Self::PaymentDate(val) => Some(val.clone()),
             _ => None,
         }
     }
@@ -772,6 +857,7 @@ impl DirValue {
             (Self::Connector(c1), Self::Connector(c2)) => c1 == c2,
             (Self::BusinessLabel(bl1), Self::BusinessLabel(bl2)) => bl1 == bl2,
             (Self::SetupFutureUsage(sfu1), Self::SetupFutureUsage(sfu2)) => sfu1 == sfu2,
+            (Self::InstallmentInterval(ii1), Self::InstallmentInterval(ii2)) => ii1 == ii2,
             (Self::UpiType(ut1), Self::UpiType(ut2)) => ut1 == ut2,
             (Self::VoucherType(vt1), Self::VoucherType(vt2)) => vt1 == vt2,
             (Self::CardRedirectType(crt1), Self::CardRedirectType(crt2)) => crt1 == crt2,
@@ -782,6 +868,10 @@ impl DirValue {
             (Self::CustomerDeviceDisplaySize(s1), Self::CustomerDeviceDisplaySize(s2)) => s1 == s2,
             (Self::AcquirerCountry(c1), Self::AcquirerCountry(c2)) => c1 == c2,
             (Self::AcquirerFraudRate(r1), Self::AcquirerFraudRate(r2)) => r1 == r2,
+            (Self::PaymentHourOfDay(h1), Self::PaymentHourOfDay(h2)) => h1 == h2,
+            (Self::PaymentDayOfWeek(d1), Self::PaymentDayOfWeek(d2)) => d1 == d2,
+            (Self::PaymentDate(d1), Self::PaymentDate(d2)) => d1 == d2,
+            (Self::PaymentHolidayCalendar(c1), Self::PaymentHolidayCalendar(c2)) => c1 == c2,
             _ => false,
         }
     }
@@ -5,8 +5,8 @@ use crate::enums::collect_variants;
 pub use crate::enums::{
     AuthenticationType, CaptureMethod, CardNetwork, Country, Country as BusinessCountry,
     Country as BillingCountry, Country as IssuerCountry, Country as AcquirerCountry, CountryAlpha2,
-    Currency as PaymentCurrency, MandateAcceptanceType, MandateType, PaymentMethod, PaymentType,
-    RoutableConnectors, SetupFutureUsage,
+    Currency as PaymentCurrency, InstallmentInterval, MandateAcceptanceType, MandateType,
+    PaymentMethod, PaymentType, RoutableConnectors, SetupFutureUsage,
 };
 #[cfg(feature = "payouts")]
 pub use crate::enums::{PayoutBankTransferType, PayoutType, PayoutWalletType};
@@ -479,6 +479,33 @@ pub enum CustomerDeviceDisplaySize {
     Size800x1280, // Common Android tablet
 }
 
+/// Day of the week on which a payment was initiated, in the merchant's timezone
+#[derive(
+    Clone,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    strum::Display,
+    strum::VariantNames,
+    strum::EnumIter,
+    strum::EnumString,
+    serde::Serialize,
+    serde::Deserialize,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentDayOfWeek {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
 collect_variants!(CardType);
 collect_variants!(PayLaterType);
 collect_variants!(WalletType);
@@ -497,3 +524,4 @@ collect_variants!(MobilePaymentType);
 collect_variants!(CustomerDeviceType);
 collect_variants!(CustomerDevicePlatform);
 collect_variants!(CustomerDeviceDisplaySize);
+collect_variants!(PaymentDayOfWeek);
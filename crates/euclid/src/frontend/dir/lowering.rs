@@ -261,6 +261,7 @@ fn lower_value(dir_value: dir::DirValue) -> Result<EuclidValue, AnalysisErrorTyp
         dir::DirValue::RewardType(rt) => EuclidValue::PaymentMethodType(rt.into()),
         dir::DirValue::BusinessLabel(bl) => EuclidValue::BusinessLabel(bl),
         dir::DirValue::SetupFutureUsage(sfu) => EuclidValue::SetupFutureUsage(sfu),
+        dir::DirValue::InstallmentInterval(ii) => EuclidValue::InstallmentInterval(ii),
         dir::DirValue::OpenBankingType(ob) => EuclidValue::PaymentMethodType(ob.into()),
         dir::DirValue::MobilePaymentType(mp) => EuclidValue::PaymentMethodType(mp.into()),
         dir::DirValue::IssuerName(str_value) => EuclidValue::IssuerName(str_value),
@@ -276,6 +277,12 @@ fn lower_value(dir_value: dir::DirValue) -> Result<EuclidValue, AnalysisErrorTyp
         }
         dir::DirValue::AcquirerCountry(country) => EuclidValue::AcquirerCountry(country),
         dir::DirValue::AcquirerFraudRate(num_value) => EuclidValue::AcquirerFraudRate(num_value),
+        dir::DirValue::PaymentHourOfDay(num_value) => EuclidValue::PaymentHourOfDay(num_value),
+        dir::DirValue::PaymentDayOfWeek(day_of_week) => EuclidValue::PaymentDayOfWeek(day_of_week),
+        dir::DirValue::PaymentDate(num_value) => EuclidValue::PaymentDate(num_value),
+        dir::DirValue::PaymentHolidayCalendar(str_value) => {
+            EuclidValue::PaymentHolidayCalendar(str_value)
+        }
     })
 }
 
@@ -11,7 +11,10 @@ use crate::{
     frontend::{
         ast,
         dir::{
-            enums::{CustomerDeviceDisplaySize, CustomerDevicePlatform, CustomerDeviceType},
+            enums::{
+                CustomerDeviceDisplaySize, CustomerDevicePlatform, CustomerDeviceType,
+                PaymentDayOfWeek,
+            },
             DirKeyKind, DirValue, EuclidDirFilter,
         },
     },
@@ -63,6 +66,8 @@ pub enum EuclidKey {
     BusinessLabel,
     #[strum(serialize = "setup_future_usage")]
     SetupFutureUsage,
+    #[strum(serialize = "installment_interval")]
+    InstallmentInterval,
     #[strum(serialize = "issuer_name")]
     IssuerName,
     #[strum(serialize = "issuer_country")]
@@ -77,6 +82,14 @@ pub enum EuclidKey {
     CustomerDeviceDisplaySize,
     #[strum(serialize = "customer_device_platform")]
     CustomerDevicePlatform,
+    #[strum(serialize = "payment_hour_of_day")]
+    PaymentHourOfDay,
+    #[strum(serialize = "payment_day_of_week")]
+    PaymentDayOfWeek,
+    #[strum(serialize = "payment_date")]
+    PaymentDate,
+    #[strum(serialize = "payment_holiday_calendar")]
+    PaymentHolidayCalendar,
 }
 
 impl EuclidDirFilter for DummyOutput {
@@ -95,6 +108,11 @@ impl EuclidDirFilter for DummyOutput {
         DirKeyKind::MandateType,
         DirKeyKind::PaymentType,
         DirKeyKind::SetupFutureUsage,
+        DirKeyKind::InstallmentInterval,
+        DirKeyKind::PaymentHourOfDay,
+        DirKeyKind::PaymentDayOfWeek,
+        DirKeyKind::PaymentDate,
+        DirKeyKind::PaymentHolidayCalendar,
     ];
 }
 impl EuclidAnalysable for DummyOutput {
@@ -156,6 +174,7 @@ impl EuclidKey {
             Self::PaymentType => DataType::EnumVariant,
             Self::BusinessLabel => DataType::StrValue,
             Self::SetupFutureUsage => DataType::EnumVariant,
+            Self::InstallmentInterval => DataType::EnumVariant,
             Self::IssuerName => DataType::StrValue,
             Self::IssuerCountry => DataType::EnumVariant,
             Self::AcquirerCountry => DataType::EnumVariant,
@@ -163,6 +182,10 @@ impl EuclidKey {
             Self::CustomerDeviceType => DataType::EnumVariant,
             Self::CustomerDeviceDisplaySize => DataType::EnumVariant,
             Self::CustomerDevicePlatform => DataType::EnumVariant,
+            Self::PaymentHourOfDay => DataType::Number,
+            Self::PaymentDayOfWeek => DataType::EnumVariant,
+            Self::PaymentDate => DataType::Number,
+            Self::PaymentHolidayCalendar => DataType::StrValue,
         }
     }
 }
@@ -278,6 +301,7 @@ pub enum EuclidValue {
     BillingCountry(enums::Country),
     BusinessLabel(StrValue),
     SetupFutureUsage(enums::SetupFutureUsage),
+    InstallmentInterval(enums::InstallmentInterval),
     IssuerName(StrValue),
     IssuerCountry(enums::Country),
     AcquirerCountry(enums::Country),
@@ -285,12 +309,18 @@ pub enum EuclidValue {
     CustomerDeviceType(CustomerDeviceType),
     CustomerDeviceDisplaySize(CustomerDeviceDisplaySize),
     CustomerDevicePlatform(CustomerDevicePlatform),
+    PaymentHourOfDay(NumValue),
+    PaymentDayOfWeek(PaymentDayOfWeek),
+    PaymentDate(NumValue),
+    PaymentHolidayCalendar(StrValue),
 }
 
 impl EuclidValue {
     pub fn get_num_value(&self) -> Option<NumValue> {
         match self {
             Self::PaymentAmount(val) => Some(val.clone()),
+            Self::PaymentHourOfDay(val) => Some(val.clone()),
+            Self::PaymentDate(val) => Some(val.clone()),
             _ => None,
         }
     }
@@ -313,6 +343,7 @@ impl EuclidValue {
             Self::BillingCountry(_) => EuclidKey::BillingCountry,
             Self::BusinessLabel(_) => EuclidKey::BusinessLabel,
             Self::SetupFutureUsage(_) => EuclidKey::SetupFutureUsage,
+            Self::InstallmentInterval(_) => EuclidKey::InstallmentInterval,
             Self::IssuerName(_) => EuclidKey::IssuerName,
             Self::IssuerCountry(_) => EuclidKey::IssuerCountry,
             Self::AcquirerCountry(_) => EuclidKey::AcquirerCountry,
@@ -320,6 +351,10 @@ impl EuclidValue {
             Self::CustomerDeviceType(_) => EuclidKey::CustomerDeviceType,
             Self::CustomerDeviceDisplaySize(_) => EuclidKey::CustomerDeviceDisplaySize,
             Self::CustomerDevicePlatform(_) => EuclidKey::CustomerDevicePlatform,
+            Self::PaymentHourOfDay(_) => EuclidKey::PaymentHourOfDay,
+            Self::PaymentDayOfWeek(_) => EuclidKey::PaymentDayOfWeek,
+            Self::PaymentDate(_) => EuclidKey::PaymentDate,
+            Self::PaymentHolidayCalendar(_) => EuclidKey::PaymentHolidayCalendar,
         }
     }
 }
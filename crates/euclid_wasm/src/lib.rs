@@ -8,7 +8,8 @@ use std::{
 };
 
 use api_models::{
-    enums as api_model_enums, routing::ConnectorSelection,
+    enums as api_model_enums,
+    routing::{ConnectorSelection, RoutingAnalysisWarning},
     surcharge_decision_configs::SurchargeDecisionConfigs,
 };
 use common_enums::RoutableConnectors;
@@ -211,11 +212,21 @@ pub fn get_valid_connectors_for_rule(rule: JsValue) -> JsResult {
     Ok(serde_wasm_bindgen::to_value(&valid_connectors)?)
 }
 
+/// Analyses the program, failing for invalid programs and returning the warnings for programs
+/// that are valid but likely misconfigured. Connector availability is only analysed once the
+/// knowledge graph is seeded.
 #[wasm_bindgen(js_name = analyzeProgram)]
 pub fn analyze_program(js_program: JsValue) -> JsResult {
     let program: ast::Program<ConnectorSelection> = serde_wasm_bindgen::from_value(js_program)?;
-    analyzer::analyze(program, SEED_DATA.get().map(|sd| &sd.cgraph)).err_to_js()?;
-    Ok(JsValue::NULL)
+    let (_, warnings) =
+        analyzer::analyze_with_warnings(program, SEED_DATA.get().map(|sd| &sd.cgraph))
+            .err_to_js()?;
+    let warnings = warnings
+        .into_iter()
+        .map(RoutingAnalysisWarning::from)
+        .collect::<Vec<_>>();
+
+    Ok(serde_wasm_bindgen::to_value(&warnings)?)
 }
 
 #[wasm_bindgen(js_name = runProgram)]
@@ -231,6 +242,23 @@ pub fn run_program(program: JsValue, input: JsValue) -> JsResult {
     Ok(serde_wasm_bindgen::to_value(&res)?)
 }
 
+#[wasm_bindgen(js_name = parseRoutingDsl)]
+pub fn parse_routing_dsl(dsl: &str) -> JsResult {
+    let program = ast::parser::parse_program::<ast::ConnectorSelection>(dsl).err_to_js()?;
+    let program = program.map_output(ConnectorSelection::from);
+    analyzer::analyze(program.clone(), None).err_to_js()?;
+
+    Ok(serde_wasm_bindgen::to_value(&program)?)
+}
+
+#[wasm_bindgen(js_name = printRoutingDsl)]
+pub fn print_routing_dsl(js_program: JsValue) -> Result<String, JsValue> {
+    let program: ast::Program<ConnectorSelection> = serde_wasm_bindgen::from_value(js_program)?;
+    let program = program.map_output(ast::ConnectorSelection::from);
+
+    ast::printer::print_program(&program).err_to_js()
+}
+
 #[wasm_bindgen(js_name = getAllConnectors)]
 pub fn get_all_connectors() -> JsResult {
     Ok(serde_wasm_bindgen::to_value(RoutableConnectors::VARIANTS)?)
@@ -309,6 +337,7 @@ pub fn get_variant_values(key: &str) -> Result<JsValue, JsValue> {
         dir::DirKeyKind::BankTransferType => dir_enums::BankTransferType::VARIANTS,
         dir::DirKeyKind::UpiType => dir_enums::UpiType::VARIANTS,
         dir::DirKeyKind::SetupFutureUsage => dir_enums::SetupFutureUsage::VARIANTS,
+        dir::DirKeyKind::InstallmentInterval => dir_enums::InstallmentInterval::VARIANTS,
         dir::DirKeyKind::PaymentType => dir_enums::PaymentType::VARIANTS,
         dir::DirKeyKind::MandateType => dir_enums::MandateType::VARIANTS,
         dir::DirKeyKind::MandateAcceptanceType => dir_enums::MandateAcceptanceType::VARIANTS,
@@ -326,6 +355,7 @@ pub fn get_variant_values(key: &str) -> Result<JsValue, JsValue> {
         dir::DirKeyKind::CustomerDeviceDisplaySize => {
             dir_enums::CustomerDeviceDisplaySize::VARIANTS
         }
+        dir::DirKeyKind::PaymentDayOfWeek => dir_enums::PaymentDayOfWeek::VARIANTS,
 
         dir::DirKeyKind::PaymentAmount
         | dir::DirKeyKind::Connector
@@ -333,7 +363,10 @@ pub fn get_variant_values(key: &str) -> Result<JsValue, JsValue> {
         | dir::DirKeyKind::BusinessLabel
         | dir::DirKeyKind::MetaData
         | dir::DirKeyKind::IssuerName
-        | dir::DirKeyKind::AcquirerFraudRate => Err("Key does not have variants".to_string())?,
+        | dir::DirKeyKind::AcquirerFraudRate
+        | dir::DirKeyKind::PaymentHourOfDay
+        | dir::DirKeyKind::PaymentDate
+        | dir::DirKeyKind::PaymentHolidayCalendar => Err("Key does not have variants".to_string())?,
     };
 
     Ok(serde_wasm_bindgen::to_value(variants)?)
@@ -0,0 +1,297 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use common_utils::{date_time, errors::CustomResult};
+use error_stack::report;
+use hyperswitch_interfaces::card_account_updater::{
+    CardAccountUpdateRequest, CardAccountUpdateResponse, CardAccountUpdateStatus,
+    CardAccountUpdaterError, CardAccountUpdaterInterface,
+};
+use masking::{PeekInterface, Secret};
+use router_env::logger;
+
+/// Mock card account updater configuration, used to test the card account updater flows
+/// without an updater provider
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(default)]
+pub struct MockCardAccountUpdaterConfig {
+    /// Number of years by which the expiry of expiring cards is extended
+    pub expiry_extension_in_years: u16,
+
+    /// Card numbers of reissued cards, keyed by the card number they replace
+    pub reissued_card_numbers: HashMap<String, String>,
+
+    /// Card numbers of the accounts closed by the issuer
+    pub closed_card_numbers: HashSet<String>,
+}
+
+impl Default for MockCardAccountUpdaterConfig {
+    fn default() -> Self {
+        Self {
+            expiry_extension_in_years: 3,
+            reissued_card_numbers: HashMap::new(),
+            closed_card_numbers: HashSet::new(),
+        }
+    }
+}
+
+impl MockCardAccountUpdaterConfig {
+    /// Validates the mock card account updater configuration
+    pub(super) fn validate(&self) -> Result<(), InvalidCardAccountUpdaterConfig> {
+        use common_utils::fp_utils::when;
+
+        when(self.expiry_extension_in_years == 0, || {
+            Err(InvalidCardAccountUpdaterConfig(
+                "expiry extension must be greater than 0",
+            ))
+        })
+    }
+
+    /// Reports cards of closed accounts and reissued card numbers as configured, and extends the
+    /// expiry of the other cards expiring within a year
+    fn get_card_account_update(
+        &self,
+        card: CardAccountUpdateRequest,
+        current_year: u16,
+        current_month: u8,
+    ) -> CardAccountUpdateResponse {
+        let card_number = card.card_number.peek();
+        let expiry = parse_card_expiry(&card.card_exp_month, &card.card_exp_year);
+        let extended_expiry = expiry.map(|(month, year)| {
+            (
+                month,
+                year.max(current_year)
+                    .saturating_add(self.expiry_extension_in_years),
+            )
+        });
+
+        let (status, card_number, expiry) = if self.closed_card_numbers.contains(card_number) {
+            (CardAccountUpdateStatus::AccountClosed, None, None)
+        } else if let Some(reissued_card_number) = self.reissued_card_numbers.get(card_number) {
+            (
+                CardAccountUpdateStatus::NewAccountNumber,
+                Some(Secret::new(reissued_card_number.clone())),
+                extended_expiry,
+            )
+        } else if expiry.is_some_and(|(month, year)| {
+            get_month_count(year, month) < get_month_count(current_year, current_month) + 12
+        }) {
+            (CardAccountUpdateStatus::NewExpiry, None, extended_expiry)
+        } else {
+            (CardAccountUpdateStatus::NoUpdate, None, None)
+        };
+
+        CardAccountUpdateResponse {
+            reference_id: card.reference_id,
+            status,
+            card_number,
+            card_exp_month: expiry.map(|(month, _)| Secret::new(format!("{month:02}"))),
+            card_exp_year: expiry.map(|(_, year)| Secret::new(year.to_string())),
+        }
+    }
+}
+
+/// Parses the expiry month and year of a card, with two digit years considered to be in the
+/// current century
+fn parse_card_expiry(
+    card_exp_month: &Secret<String>,
+    card_exp_year: &Secret<String>,
+) -> Option<(u8, u16)> {
+    let month = card_exp_month
+        .peek()
+        .trim()
+        .parse::<u8>()
+        .ok()
+        .filter(|month| (1..=12).contains(month))?;
+    let year = card_exp_year.peek().trim().parse::<u16>().ok()?;
+
+    Some((month, if year < 100 { year + 2000 } else { year }))
+}
+
+fn get_month_count(year: u16, month: u8) -> u32 {
+    u32::from(year) * 12 + u32::from(month)
+}
+
+/// Error thrown when the card account updater config is invalid
+#[derive(Debug, Clone)]
+pub struct InvalidCardAccountUpdaterConfig(pub &'static str);
+
+impl std::error::Error for InvalidCardAccountUpdaterConfig {}
+
+impl std::fmt::Display for InvalidCardAccountUpdaterConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "card_account_updater: {}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+/// NoCardAccountUpdater struct
+pub struct NoCardAccountUpdater;
+
+/// Enum representing the supported card account updater providers
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(tag = "updater")]
+#[serde(rename_all = "snake_case")]
+pub enum CardAccountUpdaterConfig {
+    /// Mock card account updater
+    Mock {
+        /// Mock card account updater configuration
+        #[serde(default)]
+        mock: MockCardAccountUpdaterConfig,
+    },
+
+    /// No card account updater configuration
+    #[default]
+    NoCardAccountUpdater,
+}
+
+impl CardAccountUpdaterConfig {
+    /// Verifies that the client configuration is usable
+    pub fn validate(&self) -> Result<(), InvalidCardAccountUpdaterConfig> {
+        match self {
+            Self::Mock { mock } => mock.validate(),
+            Self::NoCardAccountUpdater => Ok(()),
+        }
+    }
+
+    /// Checks whether a card account updater provider is configured
+    pub fn is_enabled(&self) -> bool {
+        !matches!(self, Self::NoCardAccountUpdater)
+    }
+
+    /// Retrieves the appropriate card account updater client based on the configuration.
+    pub async fn get_card_account_updater_client(&self) -> Arc<dyn CardAccountUpdaterInterface> {
+        match self {
+            Self::Mock { mock } => Arc::new(mock.clone()),
+            Self::NoCardAccountUpdater => Arc::new(NoCardAccountUpdater),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl CardAccountUpdaterInterface for NoCardAccountUpdater {
+    async fn get_card_account_updates(
+        &self,
+        _cards: Vec<CardAccountUpdateRequest>,
+    ) -> CustomResult<Vec<CardAccountUpdateResponse>, CardAccountUpdaterError> {
+        logger::info!("No card account updater configured!");
+        Err(report!(CardAccountUpdaterError::NotConfigured))
+    }
+}
+
+#[async_trait::async_trait]
+impl CardAccountUpdaterInterface for MockCardAccountUpdaterConfig {
+    async fn get_card_account_updates(
+        &self,
+        cards: Vec<CardAccountUpdateRequest>,
+    ) -> CustomResult<Vec<CardAccountUpdateResponse>, CardAccountUpdaterError> {
+        let now = date_time::now();
+        let current_year = u16::try_from(now.year()).unwrap_or(u16::MAX);
+        let current_month = u8::from(now.month());
+
+        Ok(cards
+            .into_iter()
+            .map(|card| self.get_card_account_update(card, current_year, current_month))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_card(
+        card_number: &str,
+        card_exp_month: &str,
+        card_exp_year: &str,
+    ) -> CardAccountUpdateRequest {
+        CardAccountUpdateRequest {
+            reference_id: "pm_123".to_string(),
+            card_number: Secret::new(card_number.to_string()),
+            card_exp_month: Secret::new(card_exp_month.to_string()),
+            card_exp_year: Secret::new(card_exp_year.to_string()),
+        }
+    }
+
+    fn get_mock_config() -> MockCardAccountUpdaterConfig {
+        MockCardAccountUpdaterConfig {
+            expiry_extension_in_years: 3,
+            reissued_card_numbers: HashMap::from([(
+                "4111111111111111".to_string(),
+                "4242424242424242".to_string(),
+            )]),
+            closed_card_numbers: HashSet::from(["5555555555554444".to_string()]),
+        }
+    }
+
+    #[test]
+    fn test_mock_extends_expiry_of_expiring_cards() {
+        let update = get_mock_config().get_card_account_update(
+            get_card("4000000000000002", "03", "26"),
+            2025,
+            6,
+        );
+
+        assert_eq!(update.status, CardAccountUpdateStatus::NewExpiry);
+        assert_eq!(update.reference_id, "pm_123");
+        assert!(update.card_number.is_none());
+        assert_eq!(update.card_exp_month.unwrap().peek(), "03");
+        assert_eq!(update.card_exp_year.unwrap().peek(), "2029");
+    }
+
+    #[test]
+    fn test_mock_extends_expiry_of_expired_cards_from_current_year() {
+        let update = get_mock_config().get_card_account_update(
+            get_card("4000000000000002", "1", "2023"),
+            2025,
+            6,
+        );
+
+        assert_eq!(update.status, CardAccountUpdateStatus::NewExpiry);
+        assert_eq!(update.card_exp_month.unwrap().peek(), "01");
+        assert_eq!(update.card_exp_year.unwrap().peek(), "2028");
+    }
+
+    #[test]
+    fn test_mock_does_not_update_cards_valid_for_more_than_a_year() {
+        let update = get_mock_config().get_card_account_update(
+            get_card("4000000000000002", "06", "2026"),
+            2025,
+            6,
+        );
+
+        assert_eq!(update.status, CardAccountUpdateStatus::NoUpdate);
+        assert!(update.card_exp_month.is_none());
+        assert!(update.card_exp_year.is_none());
+    }
+
+    #[test]
+    fn test_mock_reports_reissued_card_numbers() {
+        let update = get_mock_config().get_card_account_update(
+            get_card("4111111111111111", "12", "2030"),
+            2025,
+            6,
+        );
+
+        assert_eq!(update.status, CardAccountUpdateStatus::NewAccountNumber);
+        assert_eq!(update.card_number.unwrap().peek(), "4242424242424242");
+        assert_eq!(update.card_exp_year.unwrap().peek(), "2033");
+    }
+
+    #[test]
+    fn test_mock_reports_closed_accounts() {
+        let update = get_mock_config().get_card_account_update(
+            get_card("5555555555554444", "12", "2025"),
+            2025,
+            6,
+        );
+
+        assert_eq!(update.status, CardAccountUpdateStatus::AccountClosed);
+        assert!(update.card_number.is_none());
+        assert!(update.card_exp_month.is_none());
+    }
+}
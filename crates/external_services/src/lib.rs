@@ -28,6 +28,9 @@ pub mod managers;
 /// crm module
 pub mod crm;
 
+/// card account updater module
+pub mod card_account_updater;
+
 /// Crate specific constants
 #[cfg(feature = "aws_kms")]
 pub mod consts {
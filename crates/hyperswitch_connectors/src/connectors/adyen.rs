@@ -35,9 +35,10 @@ use hyperswitch_domain_models::{
         SyncRequestType, UploadFileRequestData,
     },
     router_response_types::{
-        AcceptDisputeResponse, ConnectorInfo, DefendDisputeResponse, PaymentMethodDetails,
-        PaymentsResponseData, RefundsResponseData, RetrieveFileResponse, SubmitEvidenceResponse,
-        SupportedPaymentMethods, SupportedPaymentMethodsExt, UploadFileResponse,
+        AcceptDisputeResponse, ConnectorInfo, DefendDisputeResponse, InstallmentPlanSupport,
+        PaymentMethodDetails, PaymentsResponseData, RefundsResponseData, RetrieveFileResponse,
+        SubmitEvidenceResponse, SupportedPaymentMethods, SupportedPaymentMethodsExt,
+        UploadFileResponse,
     },
     types::{
         PaymentsAuthorizeRouterData, PaymentsCancelRouterData, PaymentsCaptureRouterData,
@@ -3029,6 +3030,22 @@ static ADYEN_SUPPORTED_WEBHOOK_FLOWS: &[enums::EventClass] = &[
     enums::EventClass::Mandates,
 ];
 
+static ADYEN_INSTALLMENT_PLANS: InstallmentPlanSupport = InstallmentPlanSupport {
+    supported_countries: &[
+        enums::CountryAlpha2::BR,
+        enums::CountryAlpha2::CL,
+        enums::CountryAlpha2::CO,
+        enums::CountryAlpha2::JP,
+        enums::CountryAlpha2::MX,
+        enums::CountryAlpha2::PE,
+        enums::CountryAlpha2::TR,
+    ],
+    supported_intervals: &[enums::InstallmentInterval::Monthly],
+    min_installment_count: 2,
+    max_installment_count: 24,
+    is_interest_bearing_supported: true,
+};
+
 impl ConnectorSpecifications for Adyen {
     fn get_connector_about(&self) -> Option<&'static ConnectorInfo> {
         Some(&ADYEN_CONNECTOR_INFO)
@@ -3041,4 +3058,8 @@ impl ConnectorSpecifications for Adyen {
     fn get_supported_webhook_flows(&self) -> Option<&'static [enums::EventClass]> {
         Some(ADYEN_SUPPORTED_WEBHOOK_FLOWS)
     }
+
+    fn get_supported_installment_plans(&self) -> Option<&'static InstallmentPlanSupport> {
+        Some(&ADYEN_INSTALLMENT_PLANS)
+    }
 }
@@ -290,6 +290,7 @@ pub struct AdyenPaymentRequest<'a> {
     splits: Option<Vec<AdyenSplitData>>,
     store: Option<String>,
     device_fingerprint: Option<Secret<String>>,
+    installments: Option<AdyenInstallments>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -313,6 +314,32 @@ struct AdyenMpiData {
     eci: Option<String>,
 }
 
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AdyenInstallments {
+    value: u8,
+    plan: Option<AdyenInstallmentPlan>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum AdyenInstallmentPlan {
+    WithInterest,
+}
+
+impl From<common_types::payments::InstallmentPlan> for AdyenInstallments {
+    fn from(installment_plan: common_types::payments::InstallmentPlan) -> Self {
+        Self {
+            value: installment_plan.count,
+            // Installments without a plan are processed as regular, interest free installments
+            plan: installment_plan
+                .is_interest_bearing
+                .then_some(AdyenInstallmentPlan::WithInterest),
+        }
+    }
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct AdyenBrowserInfo {
@@ -2884,6 +2911,7 @@ impl
             store,
             splits,
             device_fingerprint,
+            installments: None,
         })
     }
 }
@@ -2970,6 +2998,11 @@ impl TryFrom<(&AdyenRouterData<&PaymentsAuthorizeRouterData>, &Card)> for AdyenP
             store,
             splits,
             device_fingerprint,
+            installments: item
+                .router_data
+                .request
+                .installment_plan
+                .map(AdyenInstallments::from),
         })
     }
 }
@@ -3051,6 +3084,7 @@ impl
             store,
             splits,
             device_fingerprint,
+            installments: None,
         };
         Ok(request)
     }
@@ -3126,6 +3160,7 @@ impl TryFrom<(&AdyenRouterData<&PaymentsAuthorizeRouterData>, &VoucherData)>
             store,
             splits,
             device_fingerprint,
+            installments: None,
         };
         Ok(request)
     }
@@ -3202,6 +3237,7 @@ impl
             store,
             splits,
             device_fingerprint,
+            installments: None,
         };
         Ok(request)
     }
@@ -3278,6 +3314,7 @@ impl
             store,
             splits,
             device_fingerprint,
+            installments: None,
         };
         Ok(request)
     }
@@ -3358,6 +3395,7 @@ impl
             store,
             splits,
             device_fingerprint,
+            installments: None,
         })
     }
 }
@@ -3480,6 +3518,7 @@ impl TryFrom<(&AdyenRouterData<&PaymentsAuthorizeRouterData>, &WalletData)>
             store,
             splits,
             device_fingerprint,
+            installments: None,
         })
     }
 }
@@ -3572,6 +3611,7 @@ impl
             store,
             splits,
             device_fingerprint,
+            installments: None,
         })
     }
 }
@@ -3653,6 +3693,7 @@ impl
             store,
             splits,
             device_fingerprint,
+            installments: None,
         })
     }
 }
@@ -5902,6 +5943,7 @@ impl
             store,
             splits,
             device_fingerprint,
+            installments: None,
         })
     }
 }
@@ -1155,4 +1155,14 @@ impl ConnectorSpecifications for Authorizedotnet {
     fn get_supported_webhook_flows(&self) -> Option<&'static [enums::EventClass]> {
         Some(&AUTHORIZEDOTNET_SUPPORTED_WEBHOOK_FLOWS)
     }
+
+    fn is_post_capture_void_supported(&self) -> bool {
+        true
+    }
+
+    // Transactions are batched for settlement at 3:00 PM Pacific time by default, which is 22:00
+    // UTC during daylight saving time
+    fn get_settlement_cutoff_time(&self) -> time::Time {
+        time::Time::MIDNIGHT - time::Duration::hours(2)
+    }
 }
@@ -29,7 +29,10 @@ use hyperswitch_domain_models::{
         PaymentsCaptureData, PaymentsIncrementalAuthorizationData, PaymentsPreProcessingData,
         PaymentsSessionData, PaymentsSyncData, RefundsData, SetupMandateRequestData,
     },
-    router_response_types::{MandateRevokeResponseData, PaymentsResponseData, RefundsResponseData},
+    router_response_types::{
+        InstallmentPlanSupport, MandateRevokeResponseData, PaymentsResponseData,
+        RefundsResponseData,
+    },
     types::{
         MandateRevokeRouterData, PaymentsAuthorizeRouterData, PaymentsCancelRouterData,
         PaymentsCaptureRouterData, PaymentsCompleteAuthorizeRouterData,
@@ -1741,4 +1744,25 @@ impl webhooks::IncomingWebhook for Cybersource {
     }
 }
 
-impl ConnectorSpecifications for Cybersource {}
+static CYBERSOURCE_INSTALLMENT_PLANS: InstallmentPlanSupport = InstallmentPlanSupport {
+    supported_countries: &[
+        enums::CountryAlpha2::BR,
+        enums::CountryAlpha2::CO,
+        enums::CountryAlpha2::MX,
+        enums::CountryAlpha2::PE,
+    ],
+    supported_intervals: &[enums::InstallmentInterval::Monthly],
+    min_installment_count: 2,
+    max_installment_count: 18,
+    is_interest_bearing_supported: true,
+};
+
+impl ConnectorSpecifications for Cybersource {
+    fn is_partial_authorization_reversal_supported(&self) -> bool {
+        true
+    }
+
+    fn get_supported_installment_plans(&self) -> Option<&'static InstallmentPlanSupport> {
+        Some(&CYBERSOURCE_INSTALLMENT_PLANS)
+    }
+}
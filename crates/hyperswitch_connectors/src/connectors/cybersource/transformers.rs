@@ -7,7 +7,7 @@ use common_utils::{
     consts,
     ext_traits::{OptionExt, ValueExt},
     pii,
-    types::{SemanticVersion, StringMajorUnit},
+    types::{SemanticVersion, StringMajorUnit, StringMajorUnitForConnector},
 };
 use error_stack::ResultExt;
 #[cfg(feature = "payouts")]
@@ -136,6 +136,7 @@ impl TryFrom<&SetupMandateRouterData> for CybersourceZeroMandateRequest {
             amount_details: Amount {
                 total_amount: StringMajorUnit::zero(),
                 currency: item.request.currency,
+                currency_conversion: None,
             },
             bill_to: Some(bill_to),
         };
@@ -352,6 +353,39 @@ pub struct CybersourcePaymentsRequest {
     consumer_authentication_information: Option<CybersourceConsumerAuthInformation>,
     #[serde(skip_serializing_if = "Option::is_none")]
     merchant_defined_information: Option<Vec<MerchantDefinedInformation>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    installment_information: Option<CybersourceInstallmentInformation>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CybersourceInstallmentInformation {
+    total_count: String,
+    plan_type: CybersourceInstallmentPlanType,
+}
+
+/// Plan type of the installments, as per who funds the interest of the installments
+#[derive(Debug, Serialize)]
+pub enum CybersourceInstallmentPlanType {
+    /// Interest free installments funded by the merchant
+    #[serde(rename = "1")]
+    MerchantFunded,
+    /// Installments in which the interest is paid by the customer to the issuer
+    #[serde(rename = "2")]
+    IssuerFunded,
+}
+
+impl From<common_types::payments::InstallmentPlan> for CybersourceInstallmentInformation {
+    fn from(installment_plan: common_types::payments::InstallmentPlan) -> Self {
+        Self {
+            total_count: installment_plan.count.to_string(),
+            plan_type: if installment_plan.is_interest_bearing {
+                CybersourceInstallmentPlanType::IssuerFunded
+            } else {
+                CybersourceInstallmentPlanType::MerchantFunded
+            },
+        }
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -603,6 +637,34 @@ pub struct OrderInformation {
 pub struct Amount {
     total_amount: StringMajorUnit,
     currency: api_models::enums::Currency,
+    #[serde(flatten)]
+    currency_conversion: Option<CybersourceCurrencyConversion>,
+}
+
+/// Conversion of the amount to the currency of the card, accepted by the customer
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CybersourceCurrencyConversion {
+    foreign_amount: StringMajorUnit,
+    foreign_currency: api_models::enums::Currency,
+    exchange_rate: String,
+}
+
+impl TryFrom<&common_types::payments::CurrencyConversionDetails> for CybersourceCurrencyConversion {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        currency_conversion: &common_types::payments::CurrencyConversionDetails,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            foreign_amount: utils::convert_amount(
+                &StringMajorUnitForConnector,
+                currency_conversion.converted_amount,
+                currency_conversion.converted_currency,
+            )?,
+            foreign_currency: currency_conversion.converted_currency,
+            exchange_rate: currency_conversion.exchange_rate.normalize().to_string(),
+        })
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -1096,6 +1158,7 @@ impl
             amount_details: Amount {
                 total_amount: item.amount.to_owned(),
                 currency: item.router_data.request.currency,
+                currency_conversion: None,
             },
             bill_to,
         }
@@ -1118,6 +1181,7 @@ impl
             amount_details: Amount {
                 total_amount: item.amount.to_owned(),
                 currency: item.router_data.request.currency,
+                currency_conversion: None,
             },
             bill_to: Some(bill_to),
         }
@@ -1242,7 +1306,15 @@ impl
             .get_billing_email()
             .or(item.router_data.request.get_email())?;
         let bill_to = build_bill_to(item.router_data.get_optional_billing(), email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let mut order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        // The customer pays in the currency of their card if they accepted a currency conversion
+        order_information.amount_details.currency_conversion = item
+            .router_data
+            .request
+            .currency_conversion
+            .as_ref()
+            .map(CybersourceCurrencyConversion::try_from)
+            .transpose()?;
 
         let additional_card_network = item
             .router_data
@@ -1346,6 +1418,11 @@ impl
             client_reference_information,
             consumer_authentication_information,
             merchant_defined_information,
+            installment_information: item
+                .router_data
+                .request
+                .installment_plan
+                .map(CybersourceInstallmentInformation::from),
         })
     }
 }
@@ -1443,6 +1520,7 @@ impl
             client_reference_information,
             consumer_authentication_information,
             merchant_defined_information,
+            installment_information: None,
         })
     }
 }
@@ -1525,6 +1603,7 @@ impl
             client_reference_information,
             consumer_authentication_information,
             merchant_defined_information,
+            installment_information: None,
         })
     }
 }
@@ -1611,6 +1690,7 @@ impl
             client_reference_information,
             consumer_authentication_information: None,
             merchant_defined_information,
+            installment_information: None,
         })
     }
 }
@@ -1713,6 +1793,7 @@ impl
             client_reference_information,
             consumer_authentication_information,
             merchant_defined_information,
+            installment_information: None,
         })
     }
 }
@@ -1788,6 +1869,7 @@ impl
                 eci_raw: None,
             }),
             merchant_defined_information,
+            installment_information: None,
         })
     }
 }
@@ -1838,6 +1920,7 @@ impl
             client_reference_information,
             consumer_authentication_information: None,
             merchant_defined_information,
+            installment_information: None,
         })
     }
 }
@@ -1925,6 +2008,7 @@ impl
                 eci_raw: None,
             }),
             merchant_defined_information,
+            installment_information: None,
         })
     }
 }
@@ -1972,6 +2056,7 @@ impl
             client_reference_information,
             consumer_authentication_information: None,
             merchant_defined_information,
+            installment_information: None,
         })
     }
 }
@@ -2105,6 +2190,7 @@ impl TryFrom<&CybersourceRouterData<&PaymentsAuthorizeRouterData>> for Cybersour
                                         order_information,
                                         client_reference_information,
                                         merchant_defined_information,
+                                        installment_information: None,
                                         consumer_authentication_information: Some(
                                             CybersourceConsumerAuthInformation {
                                                 ucaf_collection_indicator,
@@ -2274,6 +2360,7 @@ impl TryFrom<(&CybersourceRouterData<&PaymentsAuthorizeRouterData>, String)>
             order_information,
             client_reference_information,
             merchant_defined_information,
+            installment_information: None,
             consumer_authentication_information: None,
         })
     }
@@ -2413,6 +2500,7 @@ impl TryFrom<&CybersourceRouterData<&PaymentsCaptureRouterData>>
                 amount_details: Amount {
                     total_amount: item.amount.clone(),
                     currency: item.router_data.request.currency,
+                    currency_conversion: None,
                 },
                 bill_to: None,
             },
@@ -2508,6 +2596,7 @@ impl TryFrom<&CybersourceRouterData<&PaymentsCancelRouterData>> for CybersourceV
                             field_name: "Currency",
                         },
                     )?,
+                    currency_conversion: None,
                 },
                 reason: value
                     .router_data
@@ -3733,6 +3822,7 @@ impl<F> TryFrom<&CybersourceRouterData<&RefundsRouterData<F>>> for CybersourceRe
                 amount_details: Amount {
                     total_amount: item.amount.clone(),
                     currency: item.router_data.request.currency,
+                    currency_conversion: None,
                 },
             },
             client_reference_information: ClientReferenceInformation {
@@ -3965,6 +4055,7 @@ impl TryFrom<&CybersourceRouterData<&PayoutsRouterData<PoFulfill>>>
                     amount_details: Amount {
                         total_amount: item.amount.to_owned(),
                         currency: item.router_data.request.destination_currency,
+                        currency_conversion: None,
                     },
                 };
 
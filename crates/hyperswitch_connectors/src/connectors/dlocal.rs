@@ -26,8 +26,8 @@ use hyperswitch_domain_models::{
         RefundsData, SetupMandateRequestData,
     },
     router_response_types::{
-        ConnectorInfo, PaymentMethodDetails, PaymentsResponseData, RefundsResponseData,
-        SupportedPaymentMethods, SupportedPaymentMethodsExt,
+        ConnectorInfo, InstallmentPlanSupport, PaymentMethodDetails, PaymentsResponseData,
+        RefundsResponseData, SupportedPaymentMethods, SupportedPaymentMethodsExt,
     },
     types::{
         PaymentsAuthorizeRouterData, PaymentsCancelRouterData, PaymentsCaptureRouterData,
@@ -751,6 +751,22 @@ lazy_static! {
 
     static ref DLOCAL_SUPPORTED_WEBHOOK_FLOWS: Vec<enums::EventClass> = Vec::new();
 
+    static ref DLOCAL_INSTALLMENT_PLANS: InstallmentPlanSupport = InstallmentPlanSupport {
+        supported_countries: &[
+            enums::CountryAlpha2::AR,
+            enums::CountryAlpha2::BR,
+            enums::CountryAlpha2::CL,
+            enums::CountryAlpha2::CO,
+            enums::CountryAlpha2::MX,
+            enums::CountryAlpha2::PE,
+            enums::CountryAlpha2::UY,
+        ],
+        supported_intervals: &[enums::InstallmentInterval::Monthly],
+        min_installment_count: 2,
+        max_installment_count: 24,
+        is_interest_bearing_supported: true,
+    };
+
 }
 
 impl ConnectorSpecifications for Dlocal {
@@ -765,4 +781,8 @@ impl ConnectorSpecifications for Dlocal {
     fn get_supported_webhook_flows(&self) -> Option<&'static [enums::EventClass]> {
         Some(&*DLOCAL_SUPPORTED_WEBHOOK_FLOWS)
     }
+
+    fn get_supported_installment_plans(&self) -> Option<&'static InstallmentPlanSupport> {
+        Some(&*DLOCAL_INSTALLMENT_PLANS)
+    }
 }
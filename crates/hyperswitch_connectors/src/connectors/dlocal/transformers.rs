@@ -140,9 +140,14 @@ impl TryFrom<&DlocalRouterData<&types::PaymentsAuthorizeRouterData>> for DlocalP
                         installments: item
                             .router_data
                             .request
-                            .mandate_id
-                            .clone()
-                            .map(|_| "1".to_string()),
+                            .installment_plan
+                            .map(|installment_plan| installment_plan.count.to_string())
+                            .or(item
+                                .router_data
+                                .request
+                                .mandate_id
+                                .as_ref()
+                                .map(|_| "1".to_string())),
                     }),
                     order_id: item.router_data.connector_request_reference_id.clone(),
                     three_dsecure: match item.router_data.auth_type {
@@ -3396,12 +3396,33 @@ pub struct CancelRequest {
 impl TryFrom<&PaymentsCancelRouterData> for CancelRequest {
     type Error = error_stack::Report<ConnectorError>;
     fn try_from(item: &PaymentsCancelRouterData) -> Result<Self, Self::Error> {
+        // Stripe only accepts a fixed set of reasons, the free text reason is sent when the
+        // structured reason has no equivalent
+        let cancellation_reason = item
+            .request
+            .cancellation_reason_code
+            .and_then(get_stripe_cancellation_reason)
+            .map(ToString::to_string)
+            .or_else(|| item.request.cancellation_reason.clone());
         Ok(Self {
-            cancellation_reason: item.request.cancellation_reason.clone(),
+            cancellation_reason,
         })
     }
 }
 
+fn get_stripe_cancellation_reason(
+    cancellation_reason: enums::PaymentCancellationReason,
+) -> Option<&'static str> {
+    match cancellation_reason {
+        enums::PaymentCancellationReason::Duplicate => Some("duplicate"),
+        enums::PaymentCancellationReason::Fraudulent => Some("fraudulent"),
+        enums::PaymentCancellationReason::RequestedByCustomer => Some("requested_by_customer"),
+        enums::PaymentCancellationReason::Abandoned => Some("abandoned"),
+        enums::PaymentCancellationReason::OrderNotFulfilled
+        | enums::PaymentCancellationReason::Other => None,
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct UpdateMetadataRequest {
     #[serde(flatten)]
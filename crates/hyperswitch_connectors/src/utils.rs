@@ -6091,6 +6091,8 @@ pub(crate) fn convert_setup_mandate_router_data_to_authorize_router_data(
         merchant_config_currency: None,
         connector_testing_data: data.request.connector_testing_data.clone(),
         order_id: None,
+        installment_plan: None,
+        currency_conversion: None,
     }
 }
 
@@ -65,6 +65,8 @@ pub enum GraphError<V: ValueNode> {
     NoInAggregatorValues,
     #[error("Error during analysis: {0:#?}")]
     AnalysisError(Weak<AnalysisTrace<V>>),
+    #[error("Unable to serialize or load the graph: {reason}")]
+    Serialization { reason: String },
 }
 
 impl<V: ValueNode> GraphError<V> {
@@ -2,11 +2,15 @@ pub mod builder;
 mod dense_map;
 pub mod error;
 pub mod graph;
+pub mod serialization;
 pub mod types;
 
 pub use builder::ConstraintGraphBuilder;
 pub use error::{AnalysisTrace, GraphError};
 pub use graph::ConstraintGraph;
+pub use serialization::{
+    SerializedConstraintGraph, SerializedConstraintGraphDiff, SERIALIZED_GRAPH_VERSION,
+};
 #[cfg(feature = "viz")]
 pub use types::NodeViz;
 pub use types::{
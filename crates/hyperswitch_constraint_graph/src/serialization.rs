@@ -0,0 +1,463 @@
+//! Stable serialized representation of a [`ConstraintGraph`].
+//!
+//! Node, edge and domain ids are the positions of the corresponding entries in the serialized
+//! lists, so a graph that is serialized and loaded back keeps all of its ids. Predecessor and
+//! successor lists and the value map are derived from the edges and nodes while loading.
+//!
+//! The format is plain data, which allows graphs to be precompiled and shared between processes,
+//! and two versions of a graph to be compared by diffing their serialized forms.
+
+use std::sync::{Arc, Mutex, OnceLock};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    dense_map::{DenseMap, EntityId},
+    error::GraphError,
+    graph::ConstraintGraph,
+    types::{
+        DomainId, DomainIdentifier, DomainInfo, Edge, EdgeId, Metadata, Node, NodeId, NodeType,
+        NodeValue, Relation, Strength, ValueNode,
+    },
+};
+
+/// Version of the serialized format, bumped whenever the format changes in an incompatible way
+pub const SERIALIZED_GRAPH_VERSION: u16 = 1;
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(bound(
+    serialize = "V: serde::Serialize",
+    deserialize = "V: serde::de::DeserializeOwned, V::Key: serde::de::DeserializeOwned"
+))]
+pub struct SerializedConstraintGraph<V: ValueNode> {
+    pub version: u16,
+    pub domains: Vec<SerializedDomain>,
+    pub nodes: Vec<SerializedNode<V>>,
+    pub edges: Vec<SerializedEdge>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SerializedDomain {
+    pub identifier: String,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(bound(
+    serialize = "V: serde::Serialize",
+    deserialize = "V: serde::de::DeserializeOwned, V::Key: serde::de::DeserializeOwned"
+))]
+pub struct SerializedNode<V: ValueNode> {
+    pub node_type: SerializedNodeType<V>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub info: Option<String>,
+    /// Metadata of the node, kept in its JSON form as the concrete type is not known on load
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(
+    tag = "type",
+    content = "value",
+    rename_all = "snake_case",
+    bound(
+        serialize = "V: serde::Serialize",
+        deserialize = "V: serde::de::DeserializeOwned, V::Key: serde::de::DeserializeOwned"
+    )
+)]
+pub enum SerializedNodeType<V: ValueNode> {
+    AllAggregator,
+    AnyAggregator,
+    InAggregator(Vec<V>),
+    Value(NodeValue<V>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SerializedEdge {
+    pub pred: usize,
+    pub succ: usize,
+    pub strength: Strength,
+    pub relation: Relation,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain: Option<usize>,
+}
+
+/// Differences between two versions of a serialized graph. Nodes and edges are compared by their
+/// contents rather than their ids, since the same node can get a different id in another build.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[serde(bound(serialize = "V: serde::Serialize"))]
+pub struct SerializedConstraintGraphDiff<V: ValueNode> {
+    pub added_domains: Vec<SerializedDomain>,
+    pub removed_domains: Vec<SerializedDomain>,
+    pub added_nodes: Vec<SerializedNode<V>>,
+    pub removed_nodes: Vec<SerializedNode<V>>,
+    pub added_edges: Vec<SerializedEdgeDiff<V>>,
+    pub removed_edges: Vec<SerializedEdgeDiff<V>>,
+}
+
+/// An edge of a graph diff, holding the nodes and the domain it connects instead of their ids
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[serde(bound(serialize = "V: serde::Serialize"))]
+pub struct SerializedEdgeDiff<V: ValueNode> {
+    pub pred: SerializedNode<V>,
+    pub succ: SerializedNode<V>,
+    pub strength: Strength,
+    pub relation: Relation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+}
+
+impl<V: ValueNode> SerializedConstraintGraphDiff<V> {
+    pub fn is_empty(&self) -> bool {
+        self.added_domains.is_empty()
+            && self.removed_domains.is_empty()
+            && self.added_nodes.is_empty()
+            && self.removed_nodes.is_empty()
+            && self.added_edges.is_empty()
+            && self.removed_edges.is_empty()
+    }
+}
+
+fn to_json_string<V: ValueNode, T: serde::Serialize + ?Sized>(
+    value: &T,
+) -> Result<String, GraphError<V>> {
+    serde_json::to_string(value).map_err(|error| GraphError::Serialization {
+        reason: error.to_string(),
+    })
+}
+
+/// Key identifying a node by its contents. The values of an `in` aggregator come from a set, so
+/// they are sorted to not depend on the order they were serialized in.
+fn get_node_key<V: ValueNode>(node: &SerializedNode<V>) -> Result<String, GraphError<V>> {
+    let node_type = match &node.node_type {
+        SerializedNodeType::InAggregator(values) => {
+            let mut values = values
+                .iter()
+                .map(to_json_string::<V, _>)
+                .collect::<Result<Vec<_>, _>>()?;
+            values.sort();
+            to_json_string::<V, _>(&("in_aggregator", values))?
+        }
+        node_type => to_json_string::<V, _>(node_type)?,
+    };
+
+    to_json_string::<V, _>(&(node_type, &node.info, &node.metadata))
+}
+
+/// Splits the keyed items of two versions into the items only present in the newer version and
+/// the items only present in the older one, keeping their order and counting repeated keys
+fn diff_keyed_items<T>(older: Vec<(String, T)>, newer: Vec<(String, T)>) -> (Vec<T>, Vec<T>) {
+    fn count_keys<T>(items: &[(String, T)]) -> FxHashMap<String, usize> {
+        let mut counts = FxHashMap::default();
+        for (key, _) in items {
+            *counts.entry(key.clone()).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    fn retain_unmatched<T>(
+        items: Vec<(String, T)>,
+        mut counts: FxHashMap<String, usize>,
+    ) -> Vec<T> {
+        items
+            .into_iter()
+            .filter_map(|(key, item)| match counts.get_mut(&key) {
+                Some(count) if *count > 0 => {
+                    *count -= 1;
+                    None
+                }
+                _ => Some(item),
+            })
+            .collect()
+    }
+
+    let older_counts = count_keys(&older);
+    let newer_counts = count_keys(&newer);
+
+    (
+        retain_unmatched(newer, older_counts),
+        retain_unmatched(older, newer_counts),
+    )
+}
+
+/// Node infos are `&'static str`s, infos of loaded graphs are leaked once per distinct string so
+/// that loading the same graph repeatedly does not grow the memory usage.
+fn intern_info(info: String) -> &'static str {
+    static INFOS: OnceLock<Mutex<FxHashSet<&'static str>>> = OnceLock::new();
+
+    let mut infos = INFOS
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    if let Some(&interned) = infos.get(info.as_str()) {
+        return interned;
+    }
+
+    let interned: &'static str = Box::leak(info.into_boxed_str());
+    infos.insert(interned);
+    interned
+}
+
+impl<V: ValueNode> SerializedConstraintGraph<V> {
+    /// Returns the domains, nodes and edges which were added and removed in `newer` with respect
+    /// to this version of the graph
+    pub fn diff(&self, newer: &Self) -> Result<SerializedConstraintGraphDiff<V>, GraphError<V>> {
+        let (added_domains, removed_domains) =
+            diff_keyed_items(self.get_keyed_domains()?, newer.get_keyed_domains()?);
+        let (added_nodes, removed_nodes) =
+            diff_keyed_items(self.get_keyed_nodes()?, newer.get_keyed_nodes()?);
+        let (added_edges, removed_edges) =
+            diff_keyed_items(self.get_keyed_edges()?, newer.get_keyed_edges()?);
+
+        Ok(SerializedConstraintGraphDiff {
+            added_domains,
+            removed_domains,
+            added_nodes,
+            removed_nodes,
+            added_edges,
+            removed_edges,
+        })
+    }
+
+    fn get_keyed_domains(&self) -> Result<Vec<(String, SerializedDomain)>, GraphError<V>> {
+        self.domains
+            .iter()
+            .map(|domain| Ok((to_json_string::<V, _>(domain)?, domain.clone())))
+            .collect()
+    }
+
+    fn get_keyed_nodes(&self) -> Result<Vec<(String, SerializedNode<V>)>, GraphError<V>> {
+        self.nodes
+            .iter()
+            .map(|node| Ok((get_node_key(node)?, node.clone())))
+            .collect()
+    }
+
+    fn get_keyed_edges(&self) -> Result<Vec<(String, SerializedEdgeDiff<V>)>, GraphError<V>> {
+        let node_keys = self
+            .nodes
+            .iter()
+            .map(get_node_key)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.edges
+            .iter()
+            .map(|edge| {
+                let pred = self.nodes.get(edge.pred).ok_or(GraphError::NodeNotFound)?;
+                let succ = self.nodes.get(edge.succ).ok_or(GraphError::NodeNotFound)?;
+                let domain = edge
+                    .domain
+                    .map(|domain| {
+                        self.domains
+                            .get(domain)
+                            .map(|domain| domain.identifier.clone())
+                            .ok_or(GraphError::DomainNotFound)
+                    })
+                    .transpose()?;
+
+                let key = to_json_string::<V, _>(&(
+                    node_keys.get(edge.pred).ok_or(GraphError::NodeNotFound)?,
+                    node_keys.get(edge.succ).ok_or(GraphError::NodeNotFound)?,
+                    edge.strength,
+                    edge.relation,
+                    &domain,
+                ))?;
+
+                Ok((
+                    key,
+                    SerializedEdgeDiff {
+                        pred: pred.clone(),
+                        succ: succ.clone(),
+                        strength: edge.strength,
+                        relation: edge.relation,
+                        domain,
+                    },
+                ))
+            })
+            .collect()
+    }
+}
+
+impl<V> ConstraintGraph<V>
+where
+    V: ValueNode,
+{
+    pub fn to_serialized(&self) -> Result<SerializedConstraintGraph<V>, GraphError<V>> {
+        let domains = self
+            .domain
+            .values()
+            .map(|domain| SerializedDomain {
+                identifier: domain.domain_identifier.into_inner(),
+                description: domain.domain_description.clone(),
+            })
+            .collect();
+
+        let nodes = self
+            .nodes
+            .iter()
+            .map(|(node_id, node)| {
+                let node_type = match &node.node_type {
+                    NodeType::AllAggregator => SerializedNodeType::AllAggregator,
+                    NodeType::AnyAggregator => SerializedNodeType::AnyAggregator,
+                    NodeType::InAggregator(values) => {
+                        SerializedNodeType::InAggregator(values.iter().cloned().collect())
+                    }
+                    NodeType::Value(value) => SerializedNodeType::Value(value.clone()),
+                };
+
+                let metadata = self
+                    .node_metadata
+                    .get(node_id)
+                    .and_then(Option::as_ref)
+                    .map(|metadata| serde_json::to_value(metadata.as_ref()))
+                    .transpose()
+                    .map_err(|error| GraphError::Serialization {
+                        reason: error.to_string(),
+                    })?;
+
+                Ok(SerializedNode {
+                    node_type,
+                    info: self
+                        .node_info
+                        .get(node_id)
+                        .copied()
+                        .flatten()
+                        .map(ToString::to_string),
+                    metadata,
+                })
+            })
+            .collect::<Result<_, GraphError<V>>>()?;
+
+        let edges = self
+            .edges
+            .values()
+            .map(|edge| SerializedEdge {
+                pred: edge.pred.get_id(),
+                succ: edge.succ.get_id(),
+                strength: edge.strength,
+                relation: edge.relation,
+                domain: edge.domain.map(|domain_id| domain_id.get_id()),
+            })
+            .collect();
+
+        Ok(SerializedConstraintGraph {
+            version: SERIALIZED_GRAPH_VERSION,
+            domains,
+            nodes,
+            edges,
+        })
+    }
+
+    pub fn from_serialized(
+        serialized: SerializedConstraintGraph<V>,
+    ) -> Result<Self, GraphError<V>> {
+        if serialized.version != SERIALIZED_GRAPH_VERSION {
+            return Err(GraphError::Serialization {
+                reason: format!(
+                    "Unsupported graph version {}, expected {SERIALIZED_GRAPH_VERSION}",
+                    serialized.version
+                ),
+            });
+        }
+
+        let mut domain = DenseMap::<DomainId, DomainInfo>::new();
+        let mut domain_identifier_map = FxHashMap::default();
+
+        for serialized_domain in serialized.domains {
+            let domain_identifier = DomainIdentifier::new(serialized_domain.identifier);
+            let domain_id = domain.push(DomainInfo {
+                domain_identifier: domain_identifier.clone(),
+                domain_description: serialized_domain.description,
+            });
+
+            if domain_identifier_map
+                .insert(domain_identifier, domain_id)
+                .is_some()
+            {
+                return Err(GraphError::MalformedGraph {
+                    reason: "Duplicate domain identifier".to_string(),
+                });
+            }
+        }
+
+        let mut nodes = DenseMap::<NodeId, Node<V>>::new();
+        let mut node_info = DenseMap::<NodeId, Option<&'static str>>::new();
+        let mut node_metadata = DenseMap::<NodeId, Option<Arc<dyn Metadata>>>::new();
+        let mut value_map = FxHashMap::default();
+
+        for serialized_node in serialized.nodes {
+            let node_type = match serialized_node.node_type {
+                SerializedNodeType::AllAggregator => NodeType::AllAggregator,
+                SerializedNodeType::AnyAggregator => NodeType::AnyAggregator,
+                SerializedNodeType::InAggregator(values) => {
+                    NodeType::InAggregator(FxHashSet::from_iter(values))
+                }
+                SerializedNodeType::Value(value) => NodeType::Value(value),
+            };
+
+            let value = match &node_type {
+                NodeType::Value(value) => Some(value.clone()),
+                _ => None,
+            };
+
+            let node_id = nodes.push(Node::new(node_type));
+            node_info.push(serialized_node.info.map(intern_info));
+            node_metadata.push(
+                serialized_node
+                    .metadata
+                    .map(|metadata| -> Arc<dyn Metadata> { Arc::new(metadata) }),
+            );
+
+            if let Some(value) = value {
+                if value_map.insert(value, node_id).is_some() {
+                    return Err(GraphError::MalformedGraph {
+                        reason: "Duplicate value node".to_string(),
+                    });
+                }
+            }
+        }
+
+        let mut edges = DenseMap::<EdgeId, Edge>::new();
+
+        for serialized_edge in serialized.edges {
+            let pred = NodeId::with_id(serialized_edge.pred);
+            let succ = NodeId::with_id(serialized_edge.succ);
+            let edge_domain = serialized_edge.domain.map(DomainId::with_id);
+
+            if edge_domain.is_some_and(|domain_id| !domain.contains_key(domain_id)) {
+                return Err(GraphError::DomainNotFound);
+            }
+
+            let edge_id = edges.push(Edge {
+                strength: serialized_edge.strength,
+                relation: serialized_edge.relation,
+                pred,
+                succ,
+                domain: edge_domain,
+            });
+
+            nodes
+                .get_mut(pred)
+                .ok_or(GraphError::NodeNotFound)?
+                .succs
+                .push(edge_id);
+            nodes
+                .get_mut(succ)
+                .ok_or(GraphError::NodeNotFound)?
+                .preds
+                .push(edge_id);
+        }
+
+        Ok(Self {
+            domain,
+            domain_identifier_map,
+            nodes,
+            edges,
+            value_map,
+            node_info,
+            node_metadata,
+        })
+    }
+}
@@ -53,8 +53,13 @@ pub enum NodeType<V: ValueNode> {
     Value(NodeValue<V>),
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize)]
-#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(
+    tag = "type",
+    content = "value",
+    rename_all = "snake_case",
+    bound(deserialize = "V: serde::Deserialize<'de>, V::Key: serde::Deserialize<'de>")
+)]
 pub enum NodeValue<V: ValueNode> {
     Key(<V as ValueNode>::Key),
     Value(V),
@@ -72,7 +77,17 @@ pub struct EdgeId(usize);
 impl_entity!(EdgeId);
 
 #[derive(
-    Debug, Clone, Copy, serde::Serialize, PartialEq, Eq, Hash, strum::Display, PartialOrd, Ord,
+    Debug,
+    Clone,
+    Copy,
+    serde::Serialize,
+    serde::Deserialize,
+    PartialEq,
+    Eq,
+    Hash,
+    strum::Display,
+    PartialOrd,
+    Ord,
 )]
 pub enum Strength {
     Weak,
@@ -86,7 +101,9 @@ impl Strength {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::Display, serde::Serialize)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, strum::Display, serde::Serialize, serde::Deserialize,
+)]
 #[serde(rename_all = "snake_case")]
 pub enum Relation {
     Positive,
@@ -18,6 +18,16 @@ pub enum ApplicationResponse<R> {
     FileData((Vec<u8>, mime::Mime)),
     JsonWithHeaders((R, Vec<(String, masking::Maskable<String>)>)),
     GenericLinkForm(Box<GenericLinks>),
+    /// Response of an earlier request with the same idempotency key, sent as is
+    Replayed(StoredResponse),
+}
+
+/// Response sent for a request, stored to be sent again for retries of the request
+#[derive(Debug, Eq, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct StoredResponse {
+    pub status_code: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
 }
 
 impl<R> ApplicationResponse<R> {
@@ -34,6 +44,7 @@ impl<R> ApplicationResponse<R> {
             | Self::PaymentLinkForm(_)
             | Self::FileData(_)
             | Self::GenericLinkForm(_)
+            | Self::Replayed(_)
             | Self::StatusOk => Err(common_utils::errors::ValidationError::InvalidValue {
                 message: "expected either Json or JsonWithHeaders Response".to_string(),
             }
@@ -78,6 +78,11 @@ pub struct Profile {
     pub three_ds_decision_rule_algorithm: Option<serde_json::Value>,
     pub acquirer_config_map: Option<common_types::domain::AcquirerConfigMap>,
     pub merchant_category_code: Option<api_enums::MerchantCategoryCode>,
+    pub payment_intent_expiry: Option<i64>,
+    pub is_auto_reauthorization_enabled: Option<bool>,
+    pub retry_policy: Option<common_types::payments::RetryPolicy>,
+    pub dynamic_currency_conversion:
+        Option<common_types::payments::DynamicCurrencyConversionConfig>,
 }
 
 #[cfg(feature = "v1")]
@@ -132,6 +137,11 @@ pub struct ProfileSetter {
     pub is_iframe_redirection_enabled: Option<bool>,
     pub is_pre_network_tokenization_enabled: bool,
     pub merchant_category_code: Option<api_enums::MerchantCategoryCode>,
+    pub payment_intent_expiry: Option<i64>,
+    pub is_auto_reauthorization_enabled: Option<bool>,
+    pub retry_policy: Option<common_types::payments::RetryPolicy>,
+    pub dynamic_currency_conversion:
+        Option<common_types::payments::DynamicCurrencyConversionConfig>,
 }
 
 #[cfg(feature = "v1")]
@@ -193,6 +203,10 @@ impl From<ProfileSetter> for Profile {
             three_ds_decision_rule_algorithm: None, // three_ds_decision_rule_algorithm is not yet created during profile creation
             acquirer_config_map: None,
             merchant_category_code: value.merchant_category_code,
+            payment_intent_expiry: value.payment_intent_expiry,
+            is_auto_reauthorization_enabled: value.is_auto_reauthorization_enabled,
+            retry_policy: value.retry_policy,
+            dynamic_currency_conversion: value.dynamic_currency_conversion,
         }
     }
 }
@@ -254,6 +268,11 @@ pub struct ProfileGeneralUpdate {
     pub is_iframe_redirection_enabled: Option<bool>,
     pub is_pre_network_tokenization_enabled: Option<bool>,
     pub merchant_category_code: Option<api_enums::MerchantCategoryCode>,
+    pub payment_intent_expiry: Option<i64>,
+    pub is_auto_reauthorization_enabled: Option<bool>,
+    pub retry_policy: Option<Option<common_types::payments::RetryPolicy>>,
+    pub dynamic_currency_conversion:
+        Option<common_types::payments::DynamicCurrencyConversionConfig>,
 }
 
 #[cfg(feature = "v1")]
@@ -334,6 +353,10 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     is_iframe_redirection_enabled,
                     is_pre_network_tokenization_enabled,
                     merchant_category_code,
+                    payment_intent_expiry,
+                    is_auto_reauthorization_enabled,
+                    retry_policy,
+                    dynamic_currency_conversion,
                 } = *update;
 
                 Self {
@@ -385,6 +408,10 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     three_ds_decision_rule_algorithm: None,
                     acquirer_config_map: None,
                     merchant_category_code,
+                    payment_intent_expiry,
+                    is_auto_reauthorization_enabled,
+                    retry_policy,
+                    dynamic_currency_conversion,
                 }
             }
             ProfileUpdate::RoutingAlgorithmUpdate {
@@ -439,6 +466,10 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 three_ds_decision_rule_algorithm,
                 acquirer_config_map: None,
                 merchant_category_code: None,
+                payment_intent_expiry: None,
+                is_auto_reauthorization_enabled: None,
+                retry_policy: None,
+                dynamic_currency_conversion: None,
             },
             ProfileUpdate::DynamicRoutingAlgorithmUpdate {
                 dynamic_routing_algorithm,
@@ -490,6 +521,10 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 three_ds_decision_rule_algorithm: None,
                 acquirer_config_map: None,
                 merchant_category_code: None,
+                payment_intent_expiry: None,
+                is_auto_reauthorization_enabled: None,
+                retry_policy: None,
+                dynamic_currency_conversion: None,
             },
             ProfileUpdate::ExtendedCardInfoUpdate {
                 is_extended_card_info_enabled,
@@ -541,6 +576,10 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 three_ds_decision_rule_algorithm: None,
                 acquirer_config_map: None,
                 merchant_category_code: None,
+                payment_intent_expiry: None,
+                is_auto_reauthorization_enabled: None,
+                retry_policy: None,
+                dynamic_currency_conversion: None,
             },
             ProfileUpdate::ConnectorAgnosticMitUpdate {
                 is_connector_agnostic_mit_enabled,
@@ -592,6 +631,10 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 three_ds_decision_rule_algorithm: None,
                 acquirer_config_map: None,
                 merchant_category_code: None,
+                payment_intent_expiry: None,
+                is_auto_reauthorization_enabled: None,
+                retry_policy: None,
+                dynamic_currency_conversion: None,
             },
             ProfileUpdate::NetworkTokenizationUpdate {
                 is_network_tokenization_enabled,
@@ -643,6 +686,10 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 three_ds_decision_rule_algorithm: None,
                 acquirer_config_map: None,
                 merchant_category_code: None,
+                payment_intent_expiry: None,
+                is_auto_reauthorization_enabled: None,
+                retry_policy: None,
+                dynamic_currency_conversion: None,
             },
             ProfileUpdate::CardTestingSecretKeyUpdate {
                 card_testing_secret_key,
@@ -694,6 +741,10 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 three_ds_decision_rule_algorithm: None,
                 acquirer_config_map: None,
                 merchant_category_code: None,
+                payment_intent_expiry: None,
+                is_auto_reauthorization_enabled: None,
+                retry_policy: None,
+                dynamic_currency_conversion: None,
             },
             ProfileUpdate::AcquirerConfigMapUpdate {
                 acquirer_config_map,
@@ -745,6 +796,10 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 three_ds_decision_rule_algorithm: None,
                 acquirer_config_map,
                 merchant_category_code: None,
+                payment_intent_expiry: None,
+                is_auto_reauthorization_enabled: None,
+                retry_policy: None,
+                dynamic_currency_conversion: None,
             },
         }
     }
@@ -816,6 +871,10 @@ impl super::behaviour::Conversion for Profile {
             three_ds_decision_rule_algorithm: self.three_ds_decision_rule_algorithm,
             acquirer_config_map: self.acquirer_config_map,
             merchant_category_code: self.merchant_category_code,
+            payment_intent_expiry: self.payment_intent_expiry,
+            is_auto_reauthorization_enabled: self.is_auto_reauthorization_enabled,
+            retry_policy: self.retry_policy,
+            dynamic_currency_conversion: self.dynamic_currency_conversion,
         })
     }
 
@@ -913,6 +972,10 @@ impl super::behaviour::Conversion for Profile {
                 three_ds_decision_rule_algorithm: item.three_ds_decision_rule_algorithm,
                 acquirer_config_map: item.acquirer_config_map,
                 merchant_category_code: item.merchant_category_code,
+                payment_intent_expiry: item.payment_intent_expiry,
+                is_auto_reauthorization_enabled: item.is_auto_reauthorization_enabled,
+                retry_policy: item.retry_policy,
+                dynamic_currency_conversion: item.dynamic_currency_conversion,
             })
         }
         .await
@@ -977,6 +1040,10 @@ impl super::behaviour::Conversion for Profile {
             is_iframe_redirection_enabled: self.is_iframe_redirection_enabled,
             is_pre_network_tokenization_enabled: Some(self.is_pre_network_tokenization_enabled),
             merchant_category_code: self.merchant_category_code,
+            payment_intent_expiry: self.payment_intent_expiry,
+            is_auto_reauthorization_enabled: self.is_auto_reauthorization_enabled,
+            retry_policy: self.retry_policy,
+            dynamic_currency_conversion: self.dynamic_currency_conversion,
         })
     }
 }
@@ -1037,6 +1104,12 @@ pub struct Profile {
     pub is_external_vault_enabled: Option<bool>,
     pub external_vault_connector_details: Option<ExternalVaultConnectorDetails>,
     pub merchant_category_code: Option<api_enums::MerchantCategoryCode>,
+    pub payment_intent_expiry: Option<i64>,
+    pub is_auto_reauthorization_enabled: Option<bool>,
+    pub retry_policy: Option<common_types::payments::RetryPolicy>,
+    pub dynamic_currency_conversion:
+        Option<common_types::payments::DynamicCurrencyConversionConfig>,
+    pub payment_method_duplicate_policy: Option<common_enums::PaymentMethodDuplicatePolicy>,
 }
 
 #[cfg(feature = "v2")]
@@ -1093,6 +1166,12 @@ pub struct ProfileSetter {
     pub is_external_vault_enabled: Option<bool>,
     pub external_vault_connector_details: Option<ExternalVaultConnectorDetails>,
     pub merchant_category_code: Option<api_enums::MerchantCategoryCode>,
+    pub payment_intent_expiry: Option<i64>,
+    pub is_auto_reauthorization_enabled: Option<bool>,
+    pub retry_policy: Option<common_types::payments::RetryPolicy>,
+    pub dynamic_currency_conversion:
+        Option<common_types::payments::DynamicCurrencyConversionConfig>,
+    pub payment_method_duplicate_policy: Option<common_enums::PaymentMethodDuplicatePolicy>,
 }
 
 #[cfg(feature = "v2")]
@@ -1154,6 +1233,11 @@ impl From<ProfileSetter> for Profile {
             is_external_vault_enabled: value.is_external_vault_enabled,
             external_vault_connector_details: value.external_vault_connector_details,
             merchant_category_code: value.merchant_category_code,
+            payment_intent_expiry: value.payment_intent_expiry,
+            is_auto_reauthorization_enabled: value.is_auto_reauthorization_enabled,
+            retry_policy: value.retry_policy,
+            dynamic_currency_conversion: value.dynamic_currency_conversion,
+            payment_method_duplicate_policy: value.payment_method_duplicate_policy,
         }
     }
 }
@@ -1194,6 +1278,13 @@ impl Profile {
     pub fn is_vault_sdk_enabled(&self) -> bool {
         self.external_vault_connector_details.is_some()
     }
+
+    #[cfg(feature = "v2")]
+    pub fn get_payment_method_duplicate_policy(
+        &self,
+    ) -> common_enums::PaymentMethodDuplicatePolicy {
+        self.payment_method_duplicate_policy.unwrap_or_default()
+    }
 }
 
 #[cfg(feature = "v2")]
@@ -1234,6 +1325,12 @@ pub struct ProfileGeneralUpdate {
     pub is_external_vault_enabled: Option<bool>,
     pub external_vault_connector_details: Option<ExternalVaultConnectorDetails>,
     pub merchant_category_code: Option<api_enums::MerchantCategoryCode>,
+    pub payment_intent_expiry: Option<i64>,
+    pub is_auto_reauthorization_enabled: Option<bool>,
+    pub retry_policy: Option<Option<common_types::payments::RetryPolicy>>,
+    pub dynamic_currency_conversion:
+        Option<common_types::payments::DynamicCurrencyConversionConfig>,
+    pub payment_method_duplicate_policy: Option<common_enums::PaymentMethodDuplicatePolicy>,
 }
 
 #[cfg(feature = "v2")]
@@ -1313,6 +1410,11 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     is_external_vault_enabled,
                     external_vault_connector_details,
                     merchant_category_code,
+                    payment_intent_expiry,
+                    is_auto_reauthorization_enabled,
+                    retry_policy,
+                    dynamic_currency_conversion,
+                    payment_method_duplicate_policy,
                 } = *update;
                 Self {
                     profile_name,
@@ -1365,6 +1467,11 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     is_external_vault_enabled,
                     external_vault_connector_details,
                     merchant_category_code,
+                    payment_intent_expiry,
+                    is_auto_reauthorization_enabled,
+                    retry_policy,
+                    dynamic_currency_conversion,
+                    payment_method_duplicate_policy,
                 }
             }
             ProfileUpdate::RoutingAlgorithmUpdate {
@@ -1420,6 +1527,11 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_external_vault_enabled: None,
                 external_vault_connector_details: None,
                 merchant_category_code: None,
+                payment_intent_expiry: None,
+                is_auto_reauthorization_enabled: None,
+                retry_policy: None,
+                dynamic_currency_conversion: None,
+                payment_method_duplicate_policy: None,
             },
             ProfileUpdate::ExtendedCardInfoUpdate {
                 is_extended_card_info_enabled,
@@ -1473,6 +1585,11 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_external_vault_enabled: None,
                 external_vault_connector_details: None,
                 merchant_category_code: None,
+                payment_intent_expiry: None,
+                is_auto_reauthorization_enabled: None,
+                retry_policy: None,
+                dynamic_currency_conversion: None,
+                payment_method_duplicate_policy: None,
             },
             ProfileUpdate::ConnectorAgnosticMitUpdate {
                 is_connector_agnostic_mit_enabled,
@@ -1526,6 +1643,11 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_external_vault_enabled: None,
                 external_vault_connector_details: None,
                 merchant_category_code: None,
+                payment_intent_expiry: None,
+                is_auto_reauthorization_enabled: None,
+                retry_policy: None,
+                dynamic_currency_conversion: None,
+                payment_method_duplicate_policy: None,
             },
             ProfileUpdate::DefaultRoutingFallbackUpdate {
                 default_fallback_routing,
@@ -1579,6 +1701,11 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_external_vault_enabled: None,
                 external_vault_connector_details: None,
                 merchant_category_code: None,
+                payment_intent_expiry: None,
+                is_auto_reauthorization_enabled: None,
+                retry_policy: None,
+                dynamic_currency_conversion: None,
+                payment_method_duplicate_policy: None,
             },
             ProfileUpdate::NetworkTokenizationUpdate {
                 is_network_tokenization_enabled,
@@ -1632,6 +1759,11 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_external_vault_enabled: None,
                 external_vault_connector_details: None,
                 merchant_category_code: None,
+                payment_intent_expiry: None,
+                is_auto_reauthorization_enabled: None,
+                retry_policy: None,
+                dynamic_currency_conversion: None,
+                payment_method_duplicate_policy: None,
             },
             ProfileUpdate::CollectCvvDuringPaymentUpdate {
                 should_collect_cvv_during_payment,
@@ -1685,6 +1817,11 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_external_vault_enabled: None,
                 external_vault_connector_details: None,
                 merchant_category_code: None,
+                payment_intent_expiry: None,
+                is_auto_reauthorization_enabled: None,
+                retry_policy: None,
+                dynamic_currency_conversion: None,
+                payment_method_duplicate_policy: None,
             },
             ProfileUpdate::DecisionManagerRecordUpdate {
                 three_ds_decision_manager_config,
@@ -1738,6 +1875,11 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_external_vault_enabled: None,
                 external_vault_connector_details: None,
                 merchant_category_code: None,
+                payment_intent_expiry: None,
+                is_auto_reauthorization_enabled: None,
+                retry_policy: None,
+                dynamic_currency_conversion: None,
+                payment_method_duplicate_policy: None,
             },
             ProfileUpdate::CardTestingSecretKeyUpdate {
                 card_testing_secret_key,
@@ -1791,6 +1933,11 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_external_vault_enabled: None,
                 external_vault_connector_details: None,
                 merchant_category_code: None,
+                payment_intent_expiry: None,
+                is_auto_reauthorization_enabled: None,
+                retry_policy: None,
+                dynamic_currency_conversion: None,
+                payment_method_duplicate_policy: None,
             },
             ProfileUpdate::RevenueRecoveryAlgorithmUpdate {
                 revenue_recovery_retry_algorithm_type,
@@ -1845,6 +1992,11 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_external_vault_enabled: None,
                 external_vault_connector_details: None,
                 merchant_category_code: None,
+                payment_intent_expiry: None,
+                is_auto_reauthorization_enabled: None,
+                retry_policy: None,
+                dynamic_currency_conversion: None,
+                payment_method_duplicate_policy: None,
             },
         }
     }
@@ -1922,6 +2074,11 @@ impl super::behaviour::Conversion for Profile {
             three_ds_decision_rule_algorithm: None,
             acquirer_config_map: None,
             merchant_category_code: self.merchant_category_code,
+            payment_intent_expiry: self.payment_intent_expiry,
+            is_auto_reauthorization_enabled: self.is_auto_reauthorization_enabled,
+            retry_policy: self.retry_policy,
+            dynamic_currency_conversion: self.dynamic_currency_conversion,
+            payment_method_duplicate_policy: self.payment_method_duplicate_policy,
         })
     }
 
@@ -2016,6 +2173,11 @@ impl super::behaviour::Conversion for Profile {
                 is_external_vault_enabled: item.is_external_vault_enabled,
                 external_vault_connector_details: item.external_vault_connector_details,
                 merchant_category_code: item.merchant_category_code,
+                payment_intent_expiry: item.payment_intent_expiry,
+                is_auto_reauthorization_enabled: item.is_auto_reauthorization_enabled,
+                retry_policy: item.retry_policy,
+                dynamic_currency_conversion: item.dynamic_currency_conversion,
+                payment_method_duplicate_policy: item.payment_method_duplicate_policy,
             })
         }
         .await
@@ -2085,6 +2247,11 @@ impl super::behaviour::Conversion for Profile {
             is_external_vault_enabled: self.is_external_vault_enabled,
             external_vault_connector_details: self.external_vault_connector_details,
             merchant_category_code: self.merchant_category_code,
+            payment_intent_expiry: self.payment_intent_expiry,
+            is_auto_reauthorization_enabled: self.is_auto_reauthorization_enabled,
+            retry_policy: self.retry_policy,
+            dynamic_currency_conversion: self.dynamic_currency_conversion,
+            payment_method_duplicate_policy: self.payment_method_duplicate_policy,
         })
     }
 }
@@ -290,6 +290,10 @@ pub enum ApiErrorResponse {
     InvalidPlatformOperation,
     #[error(error_type = ErrorType::InvalidRequestError, code = "IR_45", message = "External vault failed during processing with connector")]
     ExternalVaultFailed,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_46", message = "Idempotency key was already used for a different request")]
+    IdempotencyKeyReused,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_47", message = "A request with the same idempotency key is being processed")]
+    IdempotentRequestInProgress,
     #[error(error_type = ErrorType::InvalidRequestError, code = "WE_01", message = "Failed to authenticate the webhook")]
     WebhookAuthenticationFailed,
     #[error(error_type = ErrorType::InvalidRequestError, code = "WE_02", message = "Bad request received in webhook")]
@@ -651,6 +655,12 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::ExternalVaultFailed => {
                 AER::BadRequest(ApiError::new("IR", 45, "External Vault failed while processing with connector.", None))
             },
+            Self::IdempotencyKeyReused => {
+                AER::Conflict(ApiError::new("IR", 46, "Idempotency key was already used for a different request", None))
+            },
+            Self::IdempotentRequestInProgress => {
+                AER::Conflict(ApiError::new("IR", 47, "A request with the same idempotency key is being processed, retry after it completes", None))
+            },
 
             Self::WebhookAuthenticationFailed => {
                 AER::Unauthorized(ApiError::new("WE", 1, "Webhook authentication failed", None))
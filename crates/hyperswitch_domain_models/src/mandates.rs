@@ -412,6 +412,29 @@ impl CommonMandateReference {
             }
         }
     }
+
+    #[cfg(feature = "v2")]
+    /// Merge the references of another payment method, retaining the existing reference when
+    /// both have a reference for the same connector
+    pub fn merge(&mut self, other: Self) {
+        if let Some(other_payments) = other.payments {
+            let payments = self
+                .payments
+                .get_or_insert_with(|| PaymentsTokenReference(HashMap::new()));
+            for (connector_id, record) in other_payments.0 {
+                payments.entry(connector_id).or_insert(record);
+            }
+        }
+
+        if let Some(other_payouts) = other.payouts {
+            let payouts = self
+                .payouts
+                .get_or_insert_with(|| PayoutsMandateReference(HashMap::new()));
+            for (connector_id, record) in other_payouts.0 {
+                payouts.entry(connector_id).or_insert(record);
+            }
+        }
+    }
 }
 
 impl From<diesel_models::CommonMandateReference> for CommonMandateReference {
@@ -63,6 +63,7 @@ pub struct MerchantConnectorAccount {
     #[encrypt]
     pub additional_merchant_data: Option<Encryptable<Secret<Value>>>,
     pub version: common_enums::ApiVersion,
+    pub fee_schedule: Option<common_types::connector_fees::ConnectorFeeSchedule>,
 }
 
 #[cfg(feature = "v1")]
@@ -120,6 +121,7 @@ pub struct MerchantConnectorAccount {
     pub additional_merchant_data: Option<Encryptable<Secret<Value>>>,
     pub version: common_enums::ApiVersion,
     pub feature_metadata: Option<MerchantConnectorAccountFeatureMetadata>,
+    pub fee_schedule: Option<common_types::connector_fees::ConnectorFeeSchedule>,
 }
 
 #[cfg(feature = "v2")]
@@ -333,6 +335,7 @@ pub enum MerchantConnectorAccountUpdate {
         status: Option<enums::ConnectorStatus>,
         connector_wallets_details: Box<Option<Encryptable<pii::SecretSerdeValue>>>,
         additional_merchant_data: Box<Option<Encryptable<pii::SecretSerdeValue>>>,
+        fee_schedule: Box<Option<Option<common_types::connector_fees::ConnectorFeeSchedule>>>,
     },
     ConnectorWalletDetailsUpdate {
         connector_wallets_details: Encryptable<pii::SecretSerdeValue>,
@@ -357,6 +360,7 @@ pub enum MerchantConnectorAccountUpdate {
         connector_wallets_details: Box<Option<Encryptable<pii::SecretSerdeValue>>>,
         additional_merchant_data: Box<Option<Encryptable<pii::SecretSerdeValue>>>,
         feature_metadata: Box<Option<MerchantConnectorAccountFeatureMetadata>>,
+        fee_schedule: Box<Option<Option<common_types::connector_fees::ConnectorFeeSchedule>>>,
     },
     ConnectorWalletDetailsUpdate {
         connector_wallets_details: Encryptable<pii::SecretSerdeValue>,
@@ -398,6 +402,7 @@ impl behaviour::Conversion for MerchantConnectorAccount {
                 connector_wallets_details: self.connector_wallets_details.map(Encryption::from),
                 additional_merchant_data: self.additional_merchant_data.map(|data| data.into()),
                 version: self.version,
+                fee_schedule: self.fee_schedule,
             },
         )
     }
@@ -463,6 +468,7 @@ impl behaviour::Conversion for MerchantConnectorAccount {
             connector_wallets_details: decrypted_data.connector_wallets_details,
             additional_merchant_data: decrypted_data.additional_merchant_data,
             version: other.version,
+            fee_schedule: other.fee_schedule,
         })
     }
 
@@ -495,6 +501,7 @@ impl behaviour::Conversion for MerchantConnectorAccount {
             connector_wallets_details: self.connector_wallets_details.map(Encryption::from),
             additional_merchant_data: self.additional_merchant_data.map(|data| data.into()),
             version: self.version,
+            fee_schedule: self.fee_schedule,
         })
     }
 }
@@ -529,6 +536,7 @@ impl behaviour::Conversion for MerchantConnectorAccount {
                 additional_merchant_data: self.additional_merchant_data.map(|data| data.into()),
                 version: self.version,
                 feature_metadata: self.feature_metadata.map(From::from),
+                fee_schedule: self.fee_schedule,
             },
         )
     }
@@ -588,6 +596,7 @@ impl behaviour::Conversion for MerchantConnectorAccount {
             additional_merchant_data: decrypted_data.additional_merchant_data,
             version: other.version,
             feature_metadata: other.feature_metadata.map(From::from),
+            fee_schedule: other.fee_schedule,
         })
     }
 
@@ -615,6 +624,7 @@ impl behaviour::Conversion for MerchantConnectorAccount {
             additional_merchant_data: self.additional_merchant_data.map(|data| data.into()),
             version: self.version,
             feature_metadata: self.feature_metadata.map(From::from),
+            fee_schedule: self.fee_schedule,
         })
     }
 }
@@ -640,6 +650,7 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 status,
                 connector_wallets_details,
                 additional_merchant_data,
+                fee_schedule,
             } => Self {
                 connector_type,
                 connector_name,
@@ -659,6 +670,7 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 status,
                 connector_wallets_details: connector_wallets_details.map(Encryption::from),
                 additional_merchant_data: additional_merchant_data.map(Encryption::from),
+                fee_schedule: *fee_schedule,
             },
             MerchantConnectorAccountUpdate::ConnectorWalletDetailsUpdate {
                 connector_wallets_details,
@@ -681,6 +693,7 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 pm_auth_config: None,
                 status: None,
                 additional_merchant_data: None,
+                fee_schedule: None,
             },
         }
     }
@@ -705,6 +718,7 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 connector_wallets_details,
                 additional_merchant_data,
                 feature_metadata,
+                fee_schedule,
             } => Self {
                 connector_type,
                 connector_account_details: connector_account_details.map(Encryption::from),
@@ -721,6 +735,7 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 connector_wallets_details: connector_wallets_details.map(Encryption::from),
                 additional_merchant_data: additional_merchant_data.map(Encryption::from),
                 feature_metadata: feature_metadata.map(From::from),
+                fee_schedule: *fee_schedule,
             },
             MerchantConnectorAccountUpdate::ConnectorWalletDetailsUpdate {
                 connector_wallets_details,
@@ -740,6 +755,7 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 status: None,
                 additional_merchant_data: None,
                 feature_metadata: None,
+                fee_schedule: None,
             },
         }
     }
@@ -83,6 +83,7 @@ pub struct PaymentMethod {
     pub network_token_requestor_reference_id: Option<String>,
     pub network_token_locker_id: Option<String>,
     pub network_token_payment_method_data: OptionalEncryptableValue,
+    pub network_token_status: Option<storage_enums::NetworkTokenStatus>,
 }
 
 #[cfg(feature = "v2")]
@@ -123,6 +124,57 @@ pub struct PaymentMethod {
         Option<Encryptable<domain_payment_method_data::PaymentMethodsData>>,
 }
 
+/// Position of a payment method in the payment methods of a merchant ordered by their creation,
+/// from which the next page of payment methods is fetched
+#[cfg(feature = "v1")]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PaymentMethodListCursor {
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    pub payment_method_id: String,
+}
+
+/// Page of the payment methods of a merchant, ordered by their creation
+#[cfg(feature = "v1")]
+#[derive(Clone, Debug)]
+pub struct PaymentMethodListConstraints {
+    pub status: storage_enums::PaymentMethodStatus,
+    /// Payment methods created after this time are not listed
+    pub created_before: PrimitiveDateTime,
+    /// Payment methods are listed from after this position
+    pub after: Option<PaymentMethodListCursor>,
+    pub limit: i64,
+}
+
+#[cfg(feature = "v1")]
+impl PaymentMethodListConstraints {
+    /// Whether a payment method with the status, creation time and id is listed in the page,
+    /// ignoring the limit
+    pub fn is_listed(
+        &self,
+        status: storage_enums::PaymentMethodStatus,
+        created_at: PrimitiveDateTime,
+        payment_method_id: &str,
+    ) -> bool {
+        status == self.status
+            && created_at <= self.created_before
+            && self.after.as_ref().map_or(true, |cursor| {
+                (created_at, payment_method_id)
+                    > (cursor.created_at, cursor.payment_method_id.as_str())
+            })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl From<&PaymentMethod> for PaymentMethodListCursor {
+    fn from(payment_method: &PaymentMethod) -> Self {
+        Self {
+            created_at: payment_method.created_at,
+            payment_method_id: payment_method.payment_method_id.clone(),
+        }
+    }
+}
+
 impl PaymentMethod {
     #[cfg(feature = "v1")]
     pub fn get_id(&self) -> &String {
@@ -173,6 +225,20 @@ impl PaymentMethod {
         self.payment_method_subtype
     }
 
+    /// Checks whether the network token of the payment method can be used for payments.
+    /// Network tokens stored before their status was tracked are considered to be active.
+    #[cfg(feature = "v1")]
+    pub fn is_network_token_active(&self) -> bool {
+        self.network_token_requestor_reference_id.is_some()
+            && !matches!(
+                self.network_token_status,
+                Some(
+                    storage_enums::NetworkTokenStatus::Suspended
+                        | storage_enums::NetworkTokenStatus::Deleted
+                )
+            )
+    }
+
     #[cfg(feature = "v1")]
     pub fn get_common_mandate_reference(&self) -> Result<CommonMandateReference, ParsingError> {
         let payments_data = self
@@ -291,6 +357,7 @@ impl super::behaviour::Conversion for PaymentMethod {
             network_token_payment_method_data: self
                 .network_token_payment_method_data
                 .map(|val| val.into()),
+            network_token_status: self.network_token_status,
         })
     }
 
@@ -378,6 +445,7 @@ impl super::behaviour::Conversion for PaymentMethod {
                         .and_then(|val| val.try_into_optionaloperation())
                     })
                     .await?,
+                network_token_status: item.network_token_status,
             })
         }
         .await
@@ -426,6 +494,7 @@ impl super::behaviour::Conversion for PaymentMethod {
             network_token_payment_method_data: self
                 .network_token_payment_method_data
                 .map(|val| val.into()),
+            network_token_status: self.network_token_status,
         })
     }
 }
@@ -787,6 +856,15 @@ pub trait PaymentMethodInterface {
         storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<Vec<PaymentMethod>, Self::Error>;
 
+    #[cfg(feature = "v1")]
+    async fn find_payment_method_list_by_merchant_id_constraints(
+        &self,
+        state: &keymanager::KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        constraints: &PaymentMethodListConstraints,
+    ) -> CustomResult<Vec<PaymentMethod>, Self::Error>;
+
     #[cfg(feature = "v1")]
     async fn get_payment_method_count_by_customer_id_merchant_id_status(
         &self,
@@ -834,6 +912,40 @@ pub trait PaymentMethodInterface {
         fingerprint_id: &str,
     ) -> CustomResult<PaymentMethod, Self::Error>;
 
+    #[cfg(feature = "v2")]
+    async fn find_payment_method_list_by_merchant_id_fingerprint_id(
+        &self,
+        state: &keymanager::KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        fingerprint_id: &str,
+    ) -> CustomResult<Vec<PaymentMethod>, Self::Error>;
+
+    /// Finds a page of the payment methods of the merchant ordered by their id, after the payment
+    /// method with the id
+    #[cfg(feature = "v2")]
+    async fn find_payment_method_list_by_merchant_id_after_id(
+        &self,
+        state: &keymanager::KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        after: Option<&id_type::GlobalPaymentMethodId>,
+        limit: i64,
+    ) -> CustomResult<Vec<PaymentMethod>, Self::Error>;
+
+    /// Updates the payment method, deactivates its duplicates and updates the mandates to refer
+    /// to the payment method in a single transaction
+    #[cfg(feature = "v2")]
+    async fn merge_payment_methods(
+        &self,
+        state: &keymanager::KeyManagerState,
+        key_store: &MerchantKeyStore,
+        payment_method: PaymentMethod,
+        payment_method_update: PaymentMethodUpdate,
+        duplicates: Vec<PaymentMethod>,
+        mandate_ids: Vec<String>,
+    ) -> CustomResult<PaymentMethod, Self::Error>;
+
     #[cfg(feature = "v1")]
     async fn delete_payment_method_by_merchant_id_payment_method_id(
         &self,
@@ -959,6 +1071,7 @@ mod tests {
             network_token_requestor_reference_id: None,
             network_token_locker_id: None,
             network_token_payment_method_data: None,
+            network_token_status: None,
         };
         payment_method.clone()
     }
@@ -896,6 +896,20 @@ pub struct PaymentAttempt {
     /// merchantwho invoked the resource based api (identifier) and through what source (Api, Jwt(Dashboard))
     pub created_by: Option<CreatedBy>,
     pub setup_future_usage_applied: Option<storage_enums::FutureUsage>,
+    /// Status of the release of the uncaptured remainder of a partially captured authorization
+    pub authorization_reversal_status: Option<storage_enums::AuthorizationReversalStatus>,
+    /// Uncaptured amount of the authorization that was released by the connector
+    pub amount_reversed: Option<MinorUnit>,
+    /// Installment plan requested by the customer for the payment
+    pub installment_plan: Option<common_types::payments::InstallmentPlan>,
+    /// Currency conversion accepted by the customer to pay in the currency of their card
+    pub currency_conversion: Option<common_types::payments::CurrencyConversionDetails>,
+    /// Reason for which the merchant cancelled the payment, from a fixed set of values
+    pub cancellation_reason_code: Option<storage_enums::PaymentCancellationReason>,
+    /// Time at which the payment was authorized, from which the expiry of the authorization is
+    /// computed
+    pub authorized_at: Option<PrimitiveDateTime>,
+    pub captured_at: Option<PrimitiveDateTime>,
 }
 
 #[cfg(feature = "v1")]
@@ -1150,6 +1164,20 @@ pub struct PaymentAttemptNew {
     /// merchantwho invoked the resource based api (identifier) and through what source (Api, Jwt(Dashboard))
     pub created_by: Option<CreatedBy>,
     pub setup_future_usage_applied: Option<storage_enums::FutureUsage>,
+    /// Status of the release of the uncaptured remainder of a partially captured authorization
+    pub authorization_reversal_status: Option<storage_enums::AuthorizationReversalStatus>,
+    /// Uncaptured amount of the authorization that was released by the connector
+    pub amount_reversed: Option<MinorUnit>,
+    /// Installment plan requested by the customer for the payment
+    pub installment_plan: Option<common_types::payments::InstallmentPlan>,
+    /// Currency conversion accepted by the customer to pay in the currency of their card
+    pub currency_conversion: Option<common_types::payments::CurrencyConversionDetails>,
+    /// Reason for which the merchant cancelled the payment, from a fixed set of values
+    pub cancellation_reason_code: Option<storage_enums::PaymentCancellationReason>,
+    /// Time at which the payment was authorized, from which the expiry of the authorization is
+    /// computed
+    pub authorized_at: Option<PrimitiveDateTime>,
+    pub captured_at: Option<PrimitiveDateTime>,
 }
 
 #[cfg(feature = "v1")]
@@ -1217,6 +1245,9 @@ pub enum PaymentAttemptUpdate {
         customer_acceptance: Option<pii::SecretSerdeValue>,
         connector_mandate_detail: Option<ConnectorMandateReferenceId>,
         card_discovery: Option<common_enums::CardDiscovery>,
+        capture_on: Option<PrimitiveDateTime>,
+        installment_plan: Option<common_types::payments::InstallmentPlan>,
+        currency_conversion: Option<common_types::payments::CurrencyConversionDetails>,
     },
     RejectUpdate {
         status: storage_enums::AttemptStatus,
@@ -1241,6 +1272,21 @@ pub enum PaymentAttemptUpdate {
     VoidUpdate {
         status: storage_enums::AttemptStatus,
         cancellation_reason: Option<String>,
+        cancellation_reason_code: Option<storage_enums::PaymentCancellationReason>,
+        updated_by: String,
+    },
+    AuthorizationExpiryUpdate {
+        authorized_at: PrimitiveDateTime,
+        capture_before: PrimitiveDateTime,
+        updated_by: String,
+    },
+    AuthorizationReversalUpdate {
+        status: Option<storage_enums::AttemptStatus>,
+        authorization_reversal_status: storage_enums::AuthorizationReversalStatus,
+        amount_reversed: Option<MinorUnit>,
+        amount_capturable: Option<MinorUnit>,
+        error_code: Option<Option<String>>,
+        error_message: Option<Option<String>>,
         updated_by: String,
     },
     ResponseUpdate {
@@ -1475,6 +1521,9 @@ impl PaymentAttemptUpdate {
                 customer_acceptance,
                 connector_mandate_detail,
                 card_discovery,
+                capture_on,
+                installment_plan,
+                currency_conversion,
             } => DieselPaymentAttemptUpdate::ConfirmUpdate {
                 amount: net_amount.get_order_amount(),
                 currency,
@@ -1510,14 +1559,45 @@ impl PaymentAttemptUpdate {
                 order_tax_amount: net_amount.get_order_tax_amount(),
                 connector_mandate_detail,
                 card_discovery,
+                capture_on,
+                installment_plan,
+                currency_conversion,
             },
             Self::VoidUpdate {
                 status,
                 cancellation_reason,
+                cancellation_reason_code,
                 updated_by,
             } => DieselPaymentAttemptUpdate::VoidUpdate {
                 status,
                 cancellation_reason,
+                cancellation_reason_code,
+                updated_by,
+            },
+            Self::AuthorizationExpiryUpdate {
+                authorized_at,
+                capture_before,
+                updated_by,
+            } => DieselPaymentAttemptUpdate::AuthorizationExpiryUpdate {
+                authorized_at,
+                capture_before,
+                updated_by,
+            },
+            Self::AuthorizationReversalUpdate {
+                status,
+                authorization_reversal_status,
+                amount_reversed,
+                amount_capturable,
+                error_code,
+                error_message,
+                updated_by,
+            } => DieselPaymentAttemptUpdate::AuthorizationReversalUpdate {
+                status,
+                authorization_reversal_status,
+                amount_reversed,
+                amount_capturable,
+                error_code,
+                error_message,
                 updated_by,
             },
             Self::ResponseUpdate {
@@ -1907,6 +1987,13 @@ impl behaviour::Conversion for PaymentAttempt {
             issuer_error_code: self.issuer_error_code,
             issuer_error_message: self.issuer_error_message,
             setup_future_usage_applied: self.setup_future_usage_applied,
+            authorization_reversal_status: self.authorization_reversal_status,
+            amount_reversed: self.amount_reversed,
+            installment_plan: self.installment_plan,
+            currency_conversion: self.currency_conversion,
+            cancellation_reason_code: self.cancellation_reason_code,
+            authorized_at: self.authorized_at,
+            captured_at: self.captured_at,
             // Below fields are deprecated. Please add any new fields above this line.
             connector_transaction_data: None,
             processor_merchant_id: Some(self.processor_merchant_id),
@@ -2006,6 +2093,13 @@ impl behaviour::Conversion for PaymentAttempt {
                     .created_by
                     .and_then(|created_by| created_by.parse::<CreatedBy>().ok()),
                 setup_future_usage_applied: storage_model.setup_future_usage_applied,
+                authorization_reversal_status: storage_model.authorization_reversal_status,
+                amount_reversed: storage_model.amount_reversed,
+                installment_plan: storage_model.installment_plan,
+                currency_conversion: storage_model.currency_conversion,
+                cancellation_reason_code: storage_model.cancellation_reason_code,
+                authorized_at: storage_model.authorized_at,
+                captured_at: storage_model.captured_at,
             })
         }
         .await
@@ -2094,6 +2188,13 @@ impl behaviour::Conversion for PaymentAttempt {
             processor_merchant_id: Some(self.processor_merchant_id),
             created_by: self.created_by.map(|cb| cb.to_string()),
             setup_future_usage_applied: self.setup_future_usage_applied,
+            authorization_reversal_status: self.authorization_reversal_status,
+            amount_reversed: self.amount_reversed,
+            installment_plan: self.installment_plan,
+            currency_conversion: self.currency_conversion,
+            cancellation_reason_code: self.cancellation_reason_code,
+            authorized_at: self.authorized_at,
+            captured_at: self.captured_at,
         })
     }
 }
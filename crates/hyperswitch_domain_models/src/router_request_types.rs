@@ -79,6 +79,8 @@ pub struct PaymentsAuthorizeData {
     pub merchant_config_currency: Option<storage_enums::Currency>,
     pub connector_testing_data: Option<pii::SecretSerdeValue>,
     pub order_id: Option<String>,
+    pub installment_plan: Option<common_types::payments::InstallmentPlan>,
+    pub currency_conversion: Option<common_types::payments::CurrencyConversionDetails>,
 }
 #[derive(Debug, Clone)]
 pub struct PaymentsPostSessionTokensData {
@@ -522,6 +524,7 @@ pub struct PaymentsCancelData {
     pub currency: Option<storage_enums::Currency>,
     pub connector_transaction_id: String,
     pub cancellation_reason: Option<String>,
+    pub cancellation_reason_code: Option<storage_enums::PaymentCancellationReason>,
     pub connector_meta: Option<serde_json::Value>,
     pub browser_info: Option<BrowserInformation>,
     pub metadata: Option<serde_json::Value>,
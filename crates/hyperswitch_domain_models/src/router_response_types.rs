@@ -589,6 +589,38 @@ pub struct ConnectorInfo {
     pub connector_type: common_enums::PaymentConnectorCategory,
 }
 
+/// Installment plans which a connector can process for card payments
+#[derive(Debug, Clone)]
+pub struct InstallmentPlanSupport {
+    /// Issuing countries of the cards for which installment plans are offered
+    pub supported_countries: &'static [common_enums::CountryAlpha2],
+    /// Intervals at which the installments can be charged
+    pub supported_intervals: &'static [common_enums::InstallmentInterval],
+    /// Minimum number of installments of a plan
+    pub min_installment_count: u8,
+    /// Maximum number of installments of a plan
+    pub max_installment_count: u8,
+    /// Whether plans in which the installments bear interest for the customer are supported
+    pub is_interest_bearing_supported: bool,
+}
+
+impl InstallmentPlanSupport {
+    /// Checks whether the installment plan can be processed for a card issued in the country
+    pub fn supports(
+        &self,
+        installment_plan: &common_types::payments::InstallmentPlan,
+        card_issuing_country: Option<common_enums::CountryAlpha2>,
+    ) -> bool {
+        (self.min_installment_count..=self.max_installment_count).contains(&installment_plan.count)
+            && self
+                .supported_intervals
+                .contains(&installment_plan.interval)
+            && (self.is_interest_bearing_supported || !installment_plan.is_interest_bearing)
+            && card_issuing_country
+                .map_or(true, |country| self.supported_countries.contains(&country))
+    }
+}
+
 pub trait SupportedPaymentMethodsExt {
     fn add(
         &mut self,
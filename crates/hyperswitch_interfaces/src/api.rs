@@ -58,8 +58,8 @@ use hyperswitch_domain_models::{
         AccessTokenRequestData, MandateRevokeRequestData, VerifyWebhookSourceRequestData,
     },
     router_response_types::{
-        ConnectorInfo, MandateRevokeResponseData, PaymentMethodDetails, SupportedPaymentMethods,
-        VerifyWebhookSourceResponseData,
+        ConnectorInfo, InstallmentPlanSupport, MandateRevokeResponseData, PaymentMethodDetails,
+        SupportedPaymentMethods, VerifyWebhookSourceResponseData,
     },
 };
 use masking::Maskable;
@@ -383,6 +383,29 @@ pub trait ConnectorSpecifications {
         None
     }
 
+    /// Whether the connector can release the uncaptured remainder of a partially captured
+    /// authorization through the void flow
+    fn is_partial_authorization_reversal_supported(&self) -> bool {
+        false
+    }
+
+    /// Whether the connector can void a captured payment through the void flow, until the capture
+    /// is settled
+    fn is_post_capture_void_supported(&self) -> bool {
+        false
+    }
+
+    /// Time of the day (in UTC) at which the connector settles the captures of the day, after which
+    /// a capture can no longer be voided
+    fn get_settlement_cutoff_time(&self) -> time::Time {
+        time::Time::MIDNIGHT
+    }
+
+    /// Installment plans which the connector can process for card payments
+    fn get_supported_installment_plans(&self) -> Option<&'static InstallmentPlanSupport> {
+        None
+    }
+
     #[cfg(feature = "v2")]
     /// Generate connector request reference ID
     fn generate_connector_request_reference_id(
@@ -0,0 +1,86 @@
+//! Card account updater interface and error types
+
+use common_utils::errors::CustomResult;
+use masking::Secret;
+
+/// Stored card sent to the card account updater
+#[derive(Clone, Debug)]
+pub struct CardAccountUpdateRequest {
+    /// Reference of the stored card, returned as is in the update of the card
+    pub reference_id: String,
+
+    /// Card number of the stored card
+    pub card_number: Secret<String>,
+
+    /// Expiry month of the stored card
+    pub card_exp_month: Secret<String>,
+
+    /// Expiry year of the stored card
+    pub card_exp_year: Secret<String>,
+}
+
+/// Update of a stored card reported by the card account updater
+#[derive(Clone, Debug)]
+pub struct CardAccountUpdateResponse {
+    /// Reference of the stored card, as sent in the request
+    pub reference_id: String,
+
+    /// Outcome of the lookup of the card with the issuer
+    pub status: CardAccountUpdateStatus,
+
+    /// Card number of the reissued card, when the card number changed
+    pub card_number: Option<Secret<String>>,
+
+    /// Expiry month of the reissued card
+    pub card_exp_month: Option<Secret<String>>,
+
+    /// Expiry year of the reissued card
+    pub card_exp_year: Option<Secret<String>>,
+}
+
+/// Outcome of the lookup of a stored card with its issuer
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CardAccountUpdateStatus {
+    /// The card was reissued with a new expiry
+    NewExpiry,
+
+    /// The card was reissued with a new card number, and possibly a new expiry
+    NewAccountNumber,
+
+    /// The account of the card was closed by the issuer
+    AccountClosed,
+
+    /// The issuer does not share updates of the card, and the cardholder has to be contacted
+    ContactCardholder,
+
+    /// The issuer has no update for the card
+    NoUpdate,
+}
+
+/// Trait defining the interface for card account updater providers
+#[async_trait::async_trait]
+pub trait CardAccountUpdaterInterface: Send + Sync {
+    /// Looks up the given stored cards with their issuers, and returns the updates of the cards
+    async fn get_card_account_updates(
+        &self,
+        cards: Vec<CardAccountUpdateRequest>,
+    ) -> CustomResult<Vec<CardAccountUpdateResponse>, CardAccountUpdaterError>;
+}
+
+/// Errors that may occur while looking up stored cards with the card account updater
+#[derive(Debug, thiserror::Error)]
+pub enum CardAccountUpdaterError {
+    /// No card account updater is configured
+    #[error("No card account updater is configured")]
+    NotConfigured,
+
+    /// The request to the card account updater failed
+    #[error("Failed to send the request to the card account updater")]
+    RequestFailed,
+
+    /// The response of the card account updater could not be processed
+    #[error("Failed to process the response of the card account updater")]
+    ResponseHandlingFailed,
+}
@@ -8,7 +8,7 @@ use hyperswitch_domain_models::{
     payment_method_data::PaymentMethodData,
     router_data::{ConnectorAuthType, ErrorResponse, RouterData},
     router_data_v2::RouterDataV2,
-    router_response_types::{ConnectorInfo, SupportedPaymentMethods},
+    router_response_types::{ConnectorInfo, InstallmentPlanSupport, SupportedPaymentMethods},
 };
 
 use crate::{
@@ -519,6 +519,34 @@ impl ConnectorSpecifications for ConnectorEnum {
         }
     }
 
+    fn is_partial_authorization_reversal_supported(&self) -> bool {
+        match self {
+            Self::Old(connector) => connector.is_partial_authorization_reversal_supported(),
+            Self::New(connector) => connector.is_partial_authorization_reversal_supported(),
+        }
+    }
+
+    fn is_post_capture_void_supported(&self) -> bool {
+        match self {
+            Self::Old(connector) => connector.is_post_capture_void_supported(),
+            Self::New(connector) => connector.is_post_capture_void_supported(),
+        }
+    }
+
+    fn get_settlement_cutoff_time(&self) -> time::Time {
+        match self {
+            Self::Old(connector) => connector.get_settlement_cutoff_time(),
+            Self::New(connector) => connector.get_settlement_cutoff_time(),
+        }
+    }
+
+    fn get_supported_installment_plans(&self) -> Option<&'static InstallmentPlanSupport> {
+        match self {
+            Self::Old(connector) => connector.get_supported_installment_plans(),
+            Self::New(connector) => connector.get_supported_installment_plans(),
+        }
+    }
+
     #[cfg(feature = "v2")]
     /// Generate connector request reference ID
     fn generate_connector_request_reference_id(
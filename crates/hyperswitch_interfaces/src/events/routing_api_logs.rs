@@ -17,6 +17,8 @@ pub enum RoutingEngine {
     IntelligentRouter,
     /// Decision engine for routing
     DecisionEngine,
+    /// Static routing evaluated within the router
+    StaticRouting,
 }
 
 /// Method type enum
@@ -27,6 +29,8 @@ pub enum ApiMethod {
     Grpc,
     /// Rest call
     Rest(Method),
+    /// Evaluated in process, without a network call
+    Internal,
 }
 
 impl fmt::Display for ApiMethod {
@@ -34,6 +38,7 @@ impl fmt::Display for ApiMethod {
         match self {
             Self::Grpc => write!(f, "Grpc"),
             Self::Rest(method) => write!(f, "Rest ({})", method),
+            Self::Internal => write!(f, "Internal"),
         }
     }
 }
@@ -58,6 +63,7 @@ pub struct RoutingEvent {
     request_id: String,
     routing_engine: RoutingEngine,
     routing_approach: Option<String>,
+    routing_rule: Option<String>,
 }
 
 impl RoutingEvent {
@@ -96,6 +102,7 @@ impl RoutingEvent {
             routing_engine,
             payment_connector: None,
             routing_approach: None,
+            routing_rule: None,
         }
     }
 
@@ -164,6 +171,11 @@ impl RoutingEvent {
         self.routing_approach = Some(approach);
     }
 
+    /// set the rule of the static routing algorithm which chose the connectors
+    pub fn set_routing_rule(&mut self, rule: String) {
+        self.routing_rule = Some(rule);
+    }
+
     /// Returns the request ID of the event.
     pub fn get_request_id(&self) -> &str {
         &self.request_id
@@ -3,6 +3,7 @@
 
 pub mod api;
 pub mod authentication;
+pub mod card_account_updater;
 /// Configuration related functionalities
 pub mod configs;
 /// Connector integration interface module
@@ -17,10 +17,10 @@ use hyperswitch_constraint_graph::{CycleCheck, Memoization};
 use kgraph_utils::{error::KgraphError, transformers::IntoDirValue, types::CountryCurrencyFilter};
 
 #[cfg(feature = "v1")]
-fn build_test_data(
+fn build_test_mca(
     total_enabled: usize,
     total_pm_types: usize,
-) -> hyperswitch_constraint_graph::ConstraintGraph<dir::DirValue> {
+) -> api_models::admin::MerchantConnectorResponse {
     use api_models::{admin::*, payment_methods::*};
 
     let mut pms_enabled: Vec<PaymentMethodsEnabled> = Vec::new();
@@ -98,14 +98,22 @@ fn build_test_data(
         status: api_enums::ConnectorStatus::Inactive,
         additional_merchant_data: None,
         connector_wallets_details: None,
+        fee_schedule: None,
     };
+    stripe_account
+}
+
+#[cfg(feature = "v1")]
+fn build_test_data(
+    total_enabled: usize,
+    total_pm_types: usize,
+) -> hyperswitch_constraint_graph::ConstraintGraph<dir::DirValue> {
     let config = CountryCurrencyFilter {
         connector_configs: HashMap::new(),
         default_configs: None,
     };
 
-    #[cfg(feature = "v1")]
-    kgraph_utils::mca::make_mca_graph(vec![stripe_account], &config)
+    kgraph_utils::mca::make_mca_graph(vec![build_test_mca(total_enabled, total_pm_types)], &config)
         .expect("Failed graph construction")
 }
 
@@ -154,7 +162,45 @@ fn evaluation(c: &mut Criterion) {
 }
 
 #[cfg(feature = "v1")]
-criterion_group!(benches, evaluation);
+fn serialization(c: &mut Criterion) {
+    let config = CountryCurrencyFilter {
+        connector_configs: HashMap::new(),
+        default_configs: None,
+    };
+
+    for (name, total_enabled, total_pm_types) in [("Small", 3, 8), ("Big", 20, 20)] {
+        let mca = build_test_mca(total_enabled, total_pm_types);
+        let serialized = serde_json::to_vec(
+            &kgraph_utils::mca::make_mca_graph(vec![mca.clone()], &config)
+                .expect("Failed graph construction")
+                .to_serialized()
+                .expect("Failed graph serialization"),
+        )
+        .expect("Failed graph encoding");
+
+        c.bench_function(&format!("MCA {name} Graph Rebuild"), |b| {
+            b.iter(|| {
+                kgraph_utils::mca::make_mca_graph(black_box(vec![mca.clone()]), &config)
+                    .expect("Failed graph construction")
+            });
+        });
+
+        c.bench_function(&format!("MCA {name} Graph Load"), |b| {
+            b.iter(|| {
+                hyperswitch_constraint_graph::ConstraintGraph::from_serialized(
+                    serde_json::from_slice::<
+                        hyperswitch_constraint_graph::SerializedConstraintGraph<dir::DirValue>,
+                    >(black_box(&serialized))
+                    .expect("Failed graph decoding"),
+                )
+                .expect("Failed graph load")
+            });
+        });
+    }
+}
+
+#[cfg(feature = "v1")]
+criterion_group!(benches, evaluation, serialization);
 #[cfg(feature = "v1")]
 criterion_main!(benches);
 
@@ -165,6 +165,29 @@ fn get_dir_value_payment_method(
     }
 }
 
+/// Payments requesting an installment plan are not routed to payment method types which do not
+/// have installments enabled
+fn compile_installments_disabled(
+    builder: &mut cgraph::ConstraintGraphBuilder<dir::DirValue>,
+) -> Result<(cgraph::NodeId, cgraph::Relation, cgraph::Strength), KgraphError> {
+    let installment_interval_info = "Installment Intervals";
+    let installment_interval_id = builder
+        .make_in_aggregator(
+            api_enums::InstallmentInterval::iter()
+                .map(dir::DirValue::InstallmentInterval)
+                .collect(),
+            Some(installment_interval_info),
+            None::<()>,
+        )
+        .map_err(KgraphError::GraphConstructionError)?;
+
+    Ok((
+        installment_interval_id,
+        cgraph::Relation::Negative,
+        cgraph::Strength::Weak,
+    ))
+}
+
 #[cfg(feature = "v2")]
 fn compile_request_pm_types(
     builder: &mut cgraph::ConstraintGraphBuilder<dir::DirValue>,
@@ -213,6 +236,10 @@ fn compile_request_pm_types(
         }
     }
 
+    if pm_types.installment_payment_enabled != Some(true) {
+        agg_nodes.push(compile_installments_disabled(builder)?);
+    }
+
     let currencies_data = pm_types
         .accepted_currencies
         .and_then(|accepted_currencies| match accepted_currencies {
@@ -407,6 +434,10 @@ fn compile_request_pm_types(
         }
     }
 
+    if pm_types.installment_payment_enabled != Some(true) {
+        agg_nodes.push(compile_installments_disabled(builder)?);
+    }
+
     let currencies_data = pm_types
         .accepted_currencies
         .and_then(|accepted_currencies| match accepted_currencies {
@@ -1052,7 +1083,9 @@ mod tests {
         dirval,
         dssa::graph::{AnalysisContext, CgraphExt},
     };
-    use hyperswitch_constraint_graph::{ConstraintGraph, CycleCheck, Memoization};
+    use hyperswitch_constraint_graph::{
+        ConstraintGraph, CycleCheck, Memoization, SerializedConstraintGraph,
+    };
 
     use super::*;
     use crate::types as kgraph_types;
@@ -1163,7 +1196,7 @@ mod tests {
                         minimum_amount: Some(MinorUnit::new(10)),
                         maximum_amount: Some(MinorUnit::new(1000)),
                         recurring_enabled: Some(true),
-                        installment_payment_enabled: Some(true),
+                        installment_payment_enabled: Some(false),
                     },
                 ]),
             }]),
@@ -1175,6 +1208,7 @@ mod tests {
             status: api_enums::ConnectorStatus::Inactive,
             additional_merchant_data: None,
             connector_wallets_details: None,
+            fee_schedule: None,
         };
 
         let config_map = kgraph_types::CountryCurrencyFilter {
@@ -1263,6 +1297,52 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_installment_enabled_success_case() {
+        let graph = build_test_data();
+
+        let result = graph.key_value_analysis(
+            dirval!(Connector = Stripe),
+            &AnalysisContext::from_dir_values([
+                dirval!(Connector = Stripe),
+                dirval!(PaymentMethod = Card),
+                dirval!(CardType = Credit),
+                dirval!(CardNetwork = Visa),
+                dirval!(PaymentCurrency = INR),
+                dirval!(PaymentAmount = 101),
+                dirval!(InstallmentInterval = Monthly),
+            ]),
+            &mut Memoization::new(),
+            &mut CycleCheck::new(),
+            None,
+        );
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_installment_disabled_failure_case() {
+        let graph = build_test_data();
+
+        let result = graph.key_value_analysis(
+            dirval!(Connector = Stripe),
+            &AnalysisContext::from_dir_values([
+                dirval!(Connector = Stripe),
+                dirval!(PaymentMethod = Card),
+                dirval!(CardType = Debit),
+                dirval!(CardNetwork = Maestro),
+                dirval!(PaymentCurrency = GBP),
+                dirval!(PaymentAmount = 100),
+                dirval!(InstallmentInterval = Monthly),
+            ]),
+            &mut Memoization::new(),
+            &mut CycleCheck::new(),
+            None,
+        );
+
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_single_mismatch_failure_case() {
         let graph = build_test_data();
@@ -1561,4 +1641,96 @@ mod tests {
         );
         assert!(result.is_err(), "bluesnap validation failed");
     }
+
+    #[test]
+    fn test_serialized_graph_round_trip() {
+        let graph = build_test_data();
+        let serialized = graph.to_serialized().expect("serialized graph");
+        let encoded = serde_json::to_string(&serialized).expect("encoded graph");
+
+        let loaded = ConstraintGraph::from_serialized(
+            serde_json::from_str::<SerializedConstraintGraph<dir::DirValue>>(&encoded)
+                .expect("decoded graph"),
+        )
+        .expect("loaded graph");
+
+        assert_eq!(
+            loaded.to_serialized().expect("serialized graph"),
+            serialized
+        );
+        assert_eq!(loaded.value_map, graph.value_map);
+
+        let context = AnalysisContext::from_dir_values([
+            dirval!(Connector = Stripe),
+            dirval!(PaymentMethod = Card),
+            dirval!(CardType = Credit),
+            dirval!(CardNetwork = Visa),
+            dirval!(PaymentCurrency = INR),
+            dirval!(PaymentAmount = 101),
+        ]);
+        let result = loaded.key_value_analysis(
+            dirval!(Connector = Stripe),
+            &context,
+            &mut Memoization::new(),
+            &mut CycleCheck::new(),
+            None,
+        );
+        assert!(result.is_ok());
+
+        let context = AnalysisContext::from_dir_values([
+            dirval!(Connector = Stripe),
+            dirval!(PaymentMethod = Card),
+            dirval!(CardType = Debit),
+            dirval!(CardNetwork = Maestro),
+            dirval!(PaymentCurrency = PHP),
+            dirval!(PaymentAmount = 100),
+        ]);
+        let result = loaded.key_value_analysis(
+            dirval!(Connector = Stripe),
+            &context,
+            &mut Memoization::new(),
+            &mut CycleCheck::new(),
+            None,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_serialized_graph_diff() {
+        let serialized = build_test_data().to_serialized().expect("serialized graph");
+        let rebuilt = build_test_data().to_serialized().expect("serialized graph");
+
+        // Two builds from the same accounts do not differ, even if their ids do
+        assert!(serialized.diff(&rebuilt).expect("graph diff").is_empty());
+
+        let empty = make_mca_graph(
+            vec![],
+            &kgraph_types::CountryCurrencyFilter {
+                connector_configs: HashMap::new(),
+                default_configs: None,
+            },
+        )
+        .expect("empty graph")
+        .to_serialized()
+        .expect("serialized graph");
+
+        let diff = empty.diff(&serialized).expect("graph diff");
+        assert!(!diff.added_nodes.is_empty());
+        assert!(!diff.added_edges.is_empty());
+        assert!(diff.removed_nodes.is_empty());
+        assert!(diff.removed_edges.is_empty());
+
+        let reverse_diff = serialized.diff(&empty).expect("graph diff");
+        assert_eq!(reverse_diff.removed_nodes, diff.added_nodes);
+        assert_eq!(reverse_diff.removed_edges, diff.added_edges);
+        assert!(reverse_diff.added_nodes.is_empty());
+    }
+
+    #[test]
+    fn test_serialized_graph_version_mismatch() {
+        let mut serialized = build_test_data().to_serialized().expect("serialized graph");
+        serialized.version += 1;
+
+        assert!(ConstraintGraph::from_serialized(serialized).is_err());
+    }
 }
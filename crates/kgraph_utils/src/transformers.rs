@@ -61,6 +61,9 @@ impl IntoContext for BackendInput {
         if let Some(setup_future_usage) = self.payment.setup_future_usage {
             ctx.push(dir::DirValue::SetupFutureUsage(setup_future_usage));
         }
+        if let Some(installment_interval) = self.payment.installment_interval {
+            ctx.push(dir::DirValue::InstallmentInterval(installment_interval));
+        }
         if let Some(mandate_acceptance_type) = self.mandate.mandate_acceptance_type {
             ctx.push(dir::DirValue::MandateAcceptanceType(
                 mandate_acceptance_type,
@@ -77,6 +77,11 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payments::payments_capture,
         routes::payments::payments_connector_session,
         routes::payments::payments_cancel,
+        routes::payments::payments_cancel_post_capture,
+        routes::payments::payments_reverse_authorization,
+        routes::payments::payments_ledger_retrieve,
+        routes::payments::payments_installment_plans,
+        routes::payments::payments_currency_conversion_quote,
         routes::payments::payments_list,
         routes::payments::payments_incremental_authorization,
         routes::payment_link::payment_link_retrieve,
@@ -141,6 +146,12 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payment_method::payment_method_retrieve_api,
         routes::payment_method::payment_method_update_api,
         routes::payment_method::payment_method_delete_api,
+        routes::payment_method::payment_method_export_create_api,
+        routes::payment_method::payment_method_export_retrieve_api,
+        routes::payment_method::payment_method_export_download_api,
+        routes::payment_method::payment_method_export_recipient_key_create_api,
+        routes::payment_method::payment_method_export_recipient_key_list_api,
+        routes::payment_method::payment_method_export_recipient_key_approve_api,
 
         // Routes for Profile
         routes::profile::profile_create,
@@ -157,6 +168,20 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::routing::routing_create_config,
         routes::routing::routing_link_config,
         routes::routing::routing_retrieve_config,
+        routes::routing::routing_simulate_config,
+        routes::routing::routing_dsl_parse,
+        routes::routing::routing_dsl_print,
+        routes::routing::routing_rollback_config,
+        routes::routing::routing_diff_config,
+        routes::routing::routing_schedule_activation,
+        routes::routing::routing_list_scheduled_activations,
+        routes::routing::routing_cancel_scheduled_activation,
+        routes::routing::routing_upsert_temporal_config,
+        routes::routing::routing_retrieve_temporal_config,
+        routes::routing::routing_delete_temporal_config,
+        routes::routing::routing_eligibility_explanation,
+        routes::routing::routing_link_shadow_config,
+        routes::routing::routing_unlink_shadow_config,
         routes::routing::list_routing_configs,
         routes::routing::routing_unlink_config,
         routes::routing::routing_update_default_config,
@@ -280,6 +305,16 @@ Never share your secret api keys. Keep them guarded and secure.
         common_types::three_ds_decision_rule_engine::ThreeDSDecisionRule,
         common_types::domain::AcquirerConfigMap,
         common_types::domain::AcquirerConfig,
+        common_types::connector_fees::ConnectorFeeSchedule,
+        common_types::connector_fees::CurrencyFeeSchedule,
+        common_types::connector_fees::CardNetworkFee,
+        common_types::connector_fees::CardTypeFee,
+        common_types::connector_fees::CrossBorderFee,
+        common_types::payments::RetryPolicy,
+        common_types::payments::InstallmentPlan,
+        common_types::payments::RetryCascade,
+        common_types::payments::DynamicCurrencyConversionConfig,
+        common_types::payments::CurrencyConversionDetails,
         api_models::payment_methods::PaymentMethodListResponse,
         api_models::payment_methods::ResponsePaymentMethodsEnabled,
         api_models::payment_methods::ResponsePaymentMethodTypes,
@@ -289,6 +324,12 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payment_methods::BankTransferTypes,
         api_models::payment_methods::CustomerPaymentMethodsListResponse,
         api_models::payment_methods::PaymentMethodDeleteResponse,
+        api_models::payment_methods::PaymentMethodExportRequest,
+        api_models::payment_methods::PaymentMethodExportResponse,
+        api_models::payment_methods::PaymentMethodExportStatus,
+        api_models::payment_methods::PaymentMethodExportRecipientKeyCreateRequest,
+        api_models::payment_methods::PaymentMethodExportRecipientKeyResponse,
+        api_models::payment_methods::PaymentMethodExportRecipientKeyStatus,
         api_models::payment_methods::PaymentMethodUpdate,
         api_models::payment_methods::CustomerDefaultPaymentMethodResponse,
         api_models::payment_methods::CardDetailFromLocker,
@@ -340,6 +381,11 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::ReconStatus,
         api_models::enums::ConnectorStatus,
         api_models::enums::AuthorizationStatus,
+        api_models::enums::AuthorizationReversalStatus,
+        api_models::enums::PaymentCancellationReason,
+        api_models::enums::LedgerEntryType,
+        api_models::enums::LedgerAccount,
+        api_models::enums::InstallmentInterval,
         api_models::enums::ElementPosition,
         api_models::enums::ElementSize,
         api_models::enums::SizeVariants,
@@ -664,9 +710,42 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PaymentLinkInitiateRequest,
         api_models::payouts::PayoutLinkInitiateRequest,
         api_models::payments::ExtendedCardInfoResponse,
+        api_models::payments::PaymentLedgerResponse,
+        api_models::payments::PaymentLedgerEntryResponse,
+        api_models::payments::PaymentLedgerBalances,
+        api_models::payments::PaymentLedgerMismatch,
+        api_models::payments::InstallmentPlansRequest,
+        api_models::payments::InstallmentPlansResponse,
+        api_models::payments::ConnectorInstallmentPlans,
+        api_models::payments::InstallmentPlanOption,
+        api_models::payments::CurrencyConversionQuoteRequest,
+        api_models::payments::CurrencyConversionQuoteResponse,
         api_models::payments::GooglePayAssuranceDetails,
         api_models::routing::RoutingConfigRequest,
+        api_models::routing::RoutingSimulationRequest,
+        api_models::routing::RoutingSimulationResponse,
+        api_models::routing::RoutingDslParseRequest,
+        api_models::routing::RoutingDslParseResponse,
+        api_models::routing::RoutingDslPrintRequest,
+        api_models::routing::RoutingDslPrintResponse,
+        api_models::routing::RoutingTemporalConfig,
+        api_models::routing::RoutingTemporalConfigResponse,
+        api_models::routing::RoutingRollbackRequest,
+        api_models::routing::RoutingAlgorithmDiffResponse,
+        api_models::routing::RoutingAlgorithmChanges,
+        api_models::routing::RoutingRuleChange,
+        api_models::routing::ConnectorWeightChange,
+        api_models::routing::RoutingActivationScheduleRequest,
+        api_models::routing::RoutingActivationScheduleResponse,
+        api_models::routing::ConnectorSimulationStats,
         api_models::routing::RoutingDictionaryRecord,
+        api_models::routing::RoutingAnalysisWarning,
+        api_models::routing::RoutingAnalysisWarningType,
+        api_models::routing::RoutingEligibilityExplanationRequest,
+        api_models::routing::RoutingEligibilityExplanationResponse,
+        api_models::routing::ConnectorEligibilityExplanation,
+        api_models::routing::PaymentMethodEligibilityExplanation,
+        api_models::routing::ConstraintGraphExplanation,
         api_models::routing::RoutingKind,
         api_models::routing::RoutableConnectorChoice,
         api_models::routing::DynamicRoutingFeatures,
@@ -136,6 +136,8 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payment_method::payment_method_update_api,
         routes::payment_method::payment_method_retrieve_api,
         routes::payment_method::payment_method_delete_api,
+        routes::payment_method::payment_method_duplicates_list_api,
+        routes::payment_method::payment_method_duplicates_merge_api,
         routes::payment_method::list_customer_payment_method_api,
 
         //Routes for payment method session
@@ -183,6 +185,9 @@ Never share your secret api keys. Keep them guarded and secure.
         common_types::domain::AdyenSplitData,
         common_types::payments::XenditSplitRequest,
         common_types::payments::XenditSplitRoute,
+        common_types::payments::RetryPolicy,
+        common_types::payments::RetryCascade,
+        common_types::payments::DynamicCurrencyConversionConfig,
         common_types::payments::XenditChargeResponseData,
         common_types::payments::XenditMultipleSplitResponse,
         common_types::payments::XenditMultipleSplitRequest,
@@ -198,6 +203,11 @@ Never share your secret api keys. Keep them guarded and secure.
         common_types::payments::StripeChargeResponseData,
         common_types::three_ds_decision_rule_engine::ThreeDSDecisionRule,
         common_types::three_ds_decision_rule_engine::ThreeDSDecision,
+        common_types::connector_fees::ConnectorFeeSchedule,
+        common_types::connector_fees::CurrencyFeeSchedule,
+        common_types::connector_fees::CardNetworkFee,
+        common_types::connector_fees::CardTypeFee,
+        common_types::connector_fees::CrossBorderFee,
         common_utils::request::Method,
         api_models::refunds::RefundsCreateRequest,
         api_models::refunds::RefundErrorDetails,
@@ -245,6 +255,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payment_methods::BankDebitTypes,
         api_models::payment_methods::BankTransferTypes,
         api_models::payment_methods::PaymentMethodDeleteResponse,
+        api_models::payment_methods::PaymentMethodDuplicatesResponse,
+        api_models::payment_methods::PaymentMethodMergeRequest,
+        api_models::payment_methods::PaymentMethodMergeResponse,
         api_models::payment_methods::PaymentMethodUpdate,
         api_models::payment_methods::PaymentMethodUpdateData,
         api_models::payment_methods::CardDetailFromLocker,
@@ -300,12 +313,14 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::ReconStatus,
         api_models::enums::ConnectorStatus,
         api_models::enums::AuthorizationStatus,
+        api_models::enums::PaymentCancellationReason,
         api_models::enums::ElementPosition,
         api_models::enums::ElementSize,
         api_models::enums::SizeVariants,
         api_models::enums::MerchantProductType,
         api_models::enums::PaymentLinkDetailsLayout,
         api_models::enums::PaymentMethodStatus,
+        api_models::enums::PaymentMethodDuplicatePolicy,
         api_models::enums::PaymentConnectorCategory,
         api_models::enums::FeatureStatus,
         api_models::enums::OrderFulfillmentTimeOrigin,
@@ -650,6 +665,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::GooglePayAssuranceDetails,
         api_models::routing::RoutingConfigRequest,
         api_models::routing::RoutingDictionaryRecord,
+        api_models::routing::RoutingAnalysisWarning,
+        api_models::routing::RoutingAnalysisWarningType,
         api_models::routing::RoutingKind,
         api_models::routing::RoutableConnectorChoice,
         api_models::routing::LinkedRoutingConfigRetrieveResponse,
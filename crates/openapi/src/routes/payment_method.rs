@@ -177,6 +177,121 @@ pub async fn payment_method_update_api() {}
 #[cfg(feature = "v1")]
 pub async fn payment_method_delete_api() {}
 
+/// Payment Method - Create Export
+///
+/// Creates an export of the cards, network tokens and connector mandate references of the active payment methods of the merchant. The export file is generated asynchronously and is encrypted as a JWE with an approved public key of the recipient.
+#[utoipa::path(
+    post,
+    path = "/payment_methods/export",
+    request_body = PaymentMethodExportRequest,
+    responses(
+        (status = 200, description = "Payment Method Export Created", body = PaymentMethodExportResponse),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Payment Methods",
+    operation_id = "Create a Payment Method Export",
+    security(("jwt_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn payment_method_export_create_api() {}
+
+/// Payment Method - Retrieve Export
+///
+/// Retrieves the status of an export of payment methods.
+#[utoipa::path(
+    get,
+    path = "/payment_methods/export/{export_id}",
+    params (
+        ("export_id" = String, Path, description = "The unique identifier for the Payment Method Export"),
+    ),
+    responses(
+        (status = 200, description = "Payment Method Export Retrieved", body = PaymentMethodExportResponse),
+        (status = 404, description = "Payment Method Export does not exist in records")
+    ),
+    tag = "Payment Methods",
+    operation_id = "Retrieve a Payment Method Export",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn payment_method_export_retrieve_api() {}
+
+/// Payment Method - Download Export
+///
+/// Downloads a part of a completed export of payment methods. Every part is encrypted separately.
+#[utoipa::path(
+    get,
+    path = "/payment_methods/export/{export_id}/parts/{part_number}",
+    params (
+        ("export_id" = String, Path, description = "The unique identifier for the Payment Method Export"),
+        ("part_number" = usize, Path, description = "The number of the part to download, starting from 1"),
+    ),
+    responses(
+        (status = 200, description = "Part of the Payment Method Export, encrypted as a JWE", content_type = "application/jose"),
+        (status = 400, description = "Payment Method Export is not completed"),
+        (status = 404, description = "Payment Method Export or the part does not exist in records")
+    ),
+    tag = "Payment Methods",
+    operation_id = "Download a Payment Method Export",
+    security(("jwt_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn payment_method_export_download_api() {}
+
+/// Payment Method - Register Export Recipient Key
+///
+/// Registers the public key of a recipient of payment method exports. The key can be used for exports once it is approved.
+#[utoipa::path(
+    post,
+    path = "/payment_methods/export_recipient_keys",
+    request_body = PaymentMethodExportRecipientKeyCreateRequest,
+    responses(
+        (status = 200, description = "Payment Method Export Recipient Key Registered", body = PaymentMethodExportRecipientKeyResponse),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Payment Methods",
+    operation_id = "Register a Payment Method Export Recipient Key",
+    security(("jwt_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn payment_method_export_recipient_key_create_api() {}
+
+/// Payment Method - List Export Recipient Keys
+///
+/// Lists the public keys of the recipients of payment method exports registered by the merchant.
+#[utoipa::path(
+    get,
+    path = "/payment_methods/export_recipient_keys",
+    responses(
+        (status = 200, description = "Payment Method Export Recipient Keys Retrieved", body = Vec<PaymentMethodExportRecipientKeyResponse>),
+    ),
+    tag = "Payment Methods",
+    operation_id = "List Payment Method Export Recipient Keys",
+    security(("jwt_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn payment_method_export_recipient_key_list_api() {}
+
+/// Payment Method - Approve Export Recipient Key
+///
+/// Approves a public key of a recipient of payment method exports registered by the merchant.
+#[utoipa::path(
+    post,
+    path = "/payment_methods/export_recipient_keys/{merchant_id}/{key_id}/approve",
+    params (
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account"),
+        ("key_id" = String, Path, description = "The unique identifier for the Payment Method Export Recipient Key"),
+    ),
+    responses(
+        (status = 200, description = "Payment Method Export Recipient Key Approved", body = PaymentMethodExportRecipientKeyResponse),
+        (status = 404, description = "Payment Method Export Recipient Key does not exist in records")
+    ),
+    tag = "Payment Methods",
+    operation_id = "Approve a Payment Method Export Recipient Key",
+    security(("admin_api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn payment_method_export_recipient_key_approve_api() {}
+
 /// Payment Method - Set Default Payment Method for Customer
 ///
 /// Set the Payment Method as Default for the Customer.
@@ -322,6 +437,48 @@ pub async fn payment_method_update_api() {}
 #[cfg(feature = "v2")]
 pub async fn payment_method_delete_api() {}
 
+/// Payment Method - List Duplicates
+///
+/// Lists the active payment methods of the merchant with the same fingerprint as the payment method.
+#[utoipa::path(
+    get,
+    path = "/v2/payment-methods/{id}/duplicates",
+    params (
+        ("id" = String, Path, description = "The unique identifier for the Payment Method"),
+    ),
+    responses(
+        (status = 200, description = "Payment Method Duplicates Retrieved", body = PaymentMethodDuplicatesResponse),
+        (status = 404, description = "Payment Method Not Found"),
+    ),
+    tag = "Payment Methods",
+    operation_id = "List Payment Method Duplicates",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v2")]
+pub async fn payment_method_duplicates_list_api() {}
+
+/// Payment Method - Merge Duplicates
+///
+/// Merges duplicates into the payment method. Mandates and connector tokens of the duplicates are moved to the payment method and the duplicates are deactivated.
+#[utoipa::path(
+    post,
+    path = "/v2/payment-methods/{id}/duplicates/merge",
+    params (
+        ("id" = String, Path, description = "The unique identifier for the Payment Method"),
+    ),
+    request_body = PaymentMethodMergeRequest,
+    responses(
+        (status = 200, description = "Payment Method Duplicates Merged", body = PaymentMethodMergeResponse),
+        (status = 400, description = "Invalid Data"),
+        (status = 404, description = "Payment Method Not Found"),
+    ),
+    tag = "Payment Methods",
+    operation_id = "Merge Payment Method Duplicates",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v2")]
+pub async fn payment_method_duplicates_merge_api() {}
+
 /// Payment Method - List Customer Saved Payment Methods
 ///
 /// List the payment methods saved for a customer
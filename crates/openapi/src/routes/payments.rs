@@ -659,6 +659,130 @@ pub fn payments_connector_session() {}
 )]
 pub fn payments_cancel() {}
 
+/// Payments - Cancel Post Capture
+///
+/// Cancels a payment which has already been captured. The capture is voided with the connector when it supports voiding captures and the payment was captured on the same day, before the capture is settled. Otherwise, or if the connector fails to void the capture, the captured amount is refunded instead.
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/cancel_post_capture",
+    request_body (
+        content = PaymentsCancelRequest,
+        examples(
+            (
+                "Cancel the captured payment with minimal fields" = (
+                    value = json!({})
+                )
+            ),
+            (
+                "Cancel the captured payment with a reason" = (
+                    value = json!({"cancellation_reason_code": "duplicate"})
+                )
+            ),
+        )
+    ),
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Payment capture voided or refunded", body = PaymentsResponse),
+        (status = 400, description = "Missing mandatory fields")
+    ),
+    tag = "Payments",
+    operation_id = "Cancel a Captured Payment",
+    security(("api_key" = []))
+)]
+pub fn payments_cancel_post_capture() {}
+
+/// Payments - Reverse Authorization
+///
+/// Releases the uncaptured amount of the authorization of a partially captured payment, so that it is no longer held on the customer's payment method. This is applicable for payments with capture method `manual_multiple` in the `partially_captured_and_capturable` status, on connectors which support releasing the uncaptured remainder of an authorization.
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/reverse_authorization",
+    request_body (
+        content = PaymentsCancelRequest,
+        examples(
+            (
+                "Release the uncaptured amount with minimal fields" = (
+                    value = json!({})
+                )
+            ),
+            (
+                "Release the uncaptured amount with a reason" = (
+                    value = json!({"cancellation_reason": "order_partially_fulfilled"})
+                )
+            ),
+        )
+    ),
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Uncaptured amount released", body = PaymentsResponse),
+        (status = 400, description = "Missing mandatory fields")
+    ),
+    tag = "Payments",
+    operation_id = "Reverse the uncaptured Authorization of a Payment",
+    security(("api_key" = []))
+)]
+pub fn payments_reverse_authorization() {}
+
+/// Payments - Ledger
+///
+/// Retrieves the ledger of a payment, the immutable entries recorded for its authorization, captures, voids, refunds and chargebacks, along with the balances computed from them. Balances which do not match the amounts recorded on the payment, its refunds or its disputes are returned as mismatches.
+#[utoipa::path(
+    get,
+    path = "/payments/{payment_id}/ledger",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Ledger of the payment retrieved", body = PaymentLedgerResponse),
+        (status = 404, description = "No payment found")
+    ),
+    tag = "Payments",
+    operation_id = "Retrieve the Ledger of a Payment",
+    security(("api_key" = []))
+)]
+pub fn payments_ledger_retrieve() {}
+
+/// Payments - Installment Plans
+///
+/// Lists the installment plans which can be offered for a card, based on the connectors of the merchant which have installments enabled for cards and the country in which the card was issued. The amount of each installment is returned for interest free plans.
+#[utoipa::path(
+    post,
+    path = "/payments/installment_plans",
+    request_body=InstallmentPlansRequest,
+    responses(
+        (status = 200, description = "Installment plans listed", body = InstallmentPlansResponse),
+        (status = 400, description = "Invalid card BIN")
+    ),
+    tag = "Payments",
+    operation_id = "List Installment Plans for a Card",
+    security(("api_key" = []))
+)]
+pub fn payments_installment_plans() {}
+
+/// Payments - Currency Conversion Quote
+///
+/// Quotes the conversion of the payment amount to the currency of the card, based on the country in which the card was issued and the markup configured for the profile. The quote can be accepted until it expires, by passing its identifier as `currency_conversion_quote_id` when confirming the payment with the same card.
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/currency_conversion_quote",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    request_body=CurrencyConversionQuoteRequest,
+    responses(
+        (status = 200, description = "Currency conversion quoted", body = CurrencyConversionQuoteResponse),
+        (status = 400, description = "Currency conversion is not available for the payment or the card")
+    ),
+    tag = "Payments",
+    operation_id = "Quote Currency Conversion for a Payment",
+    security(("api_key" = []), ("publishable_key" = []))
+)]
+pub fn payments_currency_conversion_quote() {}
+
 /// Payments - List
 ///
 /// To list the *payments*
@@ -86,6 +86,317 @@ pub async fn routing_link_config() {}
 )]
 pub async fn routing_retrieve_config() {}
 
+#[cfg(feature = "v1")]
+/// Routing - Simulate
+///
+/// Replay the most recent payments of a profile through a draft routing algorithm and report
+/// how the connector distribution and the expected success rate would change
+#[utoipa::path(
+    post,
+    path = "/routing/simulate",
+    request_body = RoutingSimulationRequest,
+    responses(
+        (status = 200, description = "Routing simulation report", body = RoutingSimulationResponse),
+        (status = 400, description = "Request body is malformed"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Simulate a routing algorithm",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_simulate_config() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Parse DSL
+///
+/// Parse a routing program written in the textual routing DSL into its JSON representation
+#[utoipa::path(
+    post,
+    path = "/routing/dsl/parse",
+    request_body = RoutingDslParseRequest,
+    responses(
+        (status = 200, description = "Parsed routing program", body = RoutingDslParseResponse),
+        (status = 400, description = "Routing program is malformed or fails the analysis"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Parse a routing program",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_dsl_parse() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Print DSL
+///
+/// Print a routing program in the textual routing DSL
+#[utoipa::path(
+    post,
+    path = "/routing/dsl/print",
+    request_body = RoutingDslPrintRequest,
+    responses(
+        (status = 200, description = "Routing program in the textual routing DSL", body = RoutingDslPrintResponse),
+        (status = 400, description = "Request body is malformed or the program compares against an empty array"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Print a routing program",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_dsl_print() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Rollback
+///
+/// Re-activate the routing config that was active before the current one for a profile
+#[utoipa::path(
+    post,
+    path = "/routing/rollback",
+    request_body = RoutingRollbackRequest,
+    responses(
+        (status = 200, description = "Routing config rolled back", body = RoutingDictionaryRecord),
+        (status = 400, description = "Request body is malformed"),
+        (status = 412, description = "No previous routing config to roll back to"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Rollback the active routing config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_rollback_config() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Diff
+///
+/// Compare a routing config against its parent config, or against another config of the same
+/// profile
+#[utoipa::path(
+    get,
+    path = "/routing/{routing_algorithm_id}/diff",
+    params(
+        ("routing_algorithm_id" = String, Path, description = "The unique identifier for a config"),
+        ("base_algorithm_id" = Option<String>, Query, description = "The config to compare against, defaults to the parent config"),
+    ),
+    responses(
+        (status = 200, description = "Changes between the two routing configs", body = RoutingAlgorithmDiffResponse),
+        (status = 400, description = "No config to compare against"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Diff routing configs",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_diff_config() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Schedule activation
+///
+/// Schedule a routing config to be activated for its profile at a future time
+#[utoipa::path(
+    post,
+    path = "/routing/{routing_algorithm_id}/activate/schedule",
+    params(
+        ("routing_algorithm_id" = String, Path, description = "The unique identifier for a config"),
+    ),
+    request_body = RoutingActivationScheduleRequest,
+    responses(
+        (status = 200, description = "Routing config activation scheduled", body = RoutingActivationScheduleResponse),
+        (status = 400, description = "Activation time is not in the future"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Schedule a routing config activation",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_schedule_activation() {}
+
+#[cfg(feature = "v1")]
+/// Routing - List scheduled activations
+///
+/// List the routing config activations of a profile that are scheduled and yet to be applied
+#[utoipa::path(
+    get,
+    path = "/routing/activations/scheduled",
+    params(
+        ("profile_id" = String, Query, description = "The unique identifier for the profile"),
+        ("transaction_type" = Option<TransactionType>, Query, description = "The transaction type of the scheduled activations"),
+    ),
+    responses(
+        (status = 200, description = "Scheduled activations retrieved", body = Vec<RoutingActivationScheduleResponse>),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "List scheduled routing config activations",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_list_scheduled_activations() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Cancel scheduled activation
+///
+/// Cancel a scheduled routing config activation that is yet to be applied
+#[utoipa::path(
+    delete,
+    path = "/routing/activations/scheduled/{activation_id}",
+    params(
+        ("activation_id" = String, Path, description = "The unique identifier for the scheduled activation"),
+    ),
+    responses(
+        (status = 200, description = "Scheduled activation cancelled", body = RoutingActivationScheduleResponse),
+        (status = 412, description = "Scheduled activation is already applied or cancelled"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Cancel a scheduled routing config activation",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_cancel_scheduled_activation() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Upsert payment time config
+///
+/// Create or update the timezone and holiday calendars used to resolve the payment time keys of the routing DSL for a profile
+#[utoipa::path(
+    put,
+    path = "/routing/temporal_config/profile/{profile_id}",
+    params(
+        ("profile_id" = String, Path, description = "The unique identifier for the profile"),
+    ),
+    request_body = RoutingTemporalConfig,
+    responses(
+        (status = 200, description = "Payment time config saved", body = RoutingTemporalConfigResponse),
+        (status = 400, description = "Invalid UTC offset or holiday calendar"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Upsert the payment time routing config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_upsert_temporal_config() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Retrieve payment time config
+///
+/// Retrieve the timezone and holiday calendars used to resolve the payment time keys of the routing DSL for a profile
+#[utoipa::path(
+    get,
+    path = "/routing/temporal_config/profile/{profile_id}",
+    params(
+        ("profile_id" = String, Path, description = "The unique identifier for the profile"),
+    ),
+    responses(
+        (status = 200, description = "Payment time config retrieved", body = RoutingTemporalConfigResponse),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Retrieve the payment time routing config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_retrieve_temporal_config() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Delete payment time config
+///
+/// Delete the payment time config of a profile, after which its payment time keys are resolved in UTC
+#[utoipa::path(
+    delete,
+    path = "/routing/temporal_config/profile/{profile_id}",
+    params(
+        ("profile_id" = String, Path, description = "The unique identifier for the profile"),
+    ),
+    responses(
+        (status = 200, description = "Payment time config deleted"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Delete the payment time routing config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_delete_temporal_config() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Explain eligibility
+///
+/// Explain why connectors and payment method types are eligible or not for a payment, or for a
+/// given routing context, based on the connector and payment method filter configuration
+#[utoipa::path(
+    post,
+    path = "/routing/eligibility/explain",
+    request_body = RoutingEligibilityExplanationRequest,
+    responses(
+        (status = 200, description = "Eligibility explained", body = RoutingEligibilityExplanationResponse),
+        (status = 400, description = "Request body is malformed"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Explain connector and payment method eligibility",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_eligibility_explanation() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Activate shadow config
+///
+/// Evaluate a routing config alongside the active one on every payment of the profile, without
+/// affecting the routing decision
+#[utoipa::path(
+    post,
+    path = "/routing/{routing_algorithm_id}/shadow",
+    params(
+        ("routing_algorithm_id" = String, Path, description = "The unique identifier for a config"),
+    ),
+    responses(
+        (status = 200, description = "Shadow routing config activated", body = RoutingDictionaryRecord),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 400, description = "Bad request")
+    ),
+   tag = "Routing",
+   operation_id = "Activate a shadow routing config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_link_shadow_config() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Deactivate shadow config
+///
+/// Stop evaluating the shadow routing config of a profile
+#[utoipa::path(
+    post,
+    path = "/routing/shadow/deactivate",
+    request_body = RoutingConfigRequest,
+    responses(
+        (status = 200, description = "Successfully deactivated the shadow routing config", body = RoutingDictionaryRecord),
+        (status = 500, description = "Internal server error"),
+        (status = 400, description = "Malformed request"),
+        (status = 403, description = "Malformed request"),
+        (status = 422, description = "Unprocessable request")
+    ),
+   tag = "Routing",
+   operation_id = "Deactivate the shadow routing config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_unlink_shadow_config() {}
+
 #[cfg(feature = "v2")]
 /// Routing - Retrieve
 ///
@@ -587,6 +587,7 @@ pub async fn skip_locker_call_and_migrate_payment_method(
                 network_token_requestor_reference_id: None,
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
+                network_token_status: None,
             },
             merchant_context.get_merchant_account().storage_scheme,
         )
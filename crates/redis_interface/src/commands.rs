@@ -195,6 +195,50 @@ impl super::RedisConnectionPool {
         }
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn get_multiple_keys<V>(
+        &self,
+        keys: &[RedisKey],
+    ) -> CustomResult<Vec<Option<V>>, errors::RedisError>
+    where
+        V: FromRedis + Unpin + Send + 'static,
+    {
+        if keys.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let tenant_aware_keys: Vec<String> =
+            keys.iter().map(|key| key.tenant_aware_key(self)).collect();
+
+        match self
+            .pool
+            .mget(tenant_aware_keys)
+            .await
+            .change_context(errors::RedisError::GetFailed)
+        {
+            Ok(v) => Ok(v),
+            Err(_err) => {
+                #[cfg(not(feature = "multitenancy_fallback"))]
+                {
+                    Err(_err)
+                }
+
+                #[cfg(feature = "multitenancy_fallback")]
+                {
+                    let tenant_unaware_keys: Vec<String> = keys
+                        .iter()
+                        .map(|key| key.tenant_unaware_key(self))
+                        .collect();
+
+                    self.pool
+                        .mget(tenant_unaware_keys)
+                        .await
+                        .change_context(errors::RedisError::GetFailed)
+                }
+            }
+        }
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn exists<V>(&self, key: &RedisKey) -> CustomResult<bool, errors::RedisError>
     where
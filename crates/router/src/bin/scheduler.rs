@@ -324,6 +324,41 @@ impl ProcessTrackerWorkflows<routes::SessionState> for WorkflowRunner {
                 storage::ProcessTrackerRunner::PassiveRecoveryWorkflow => {
                     Ok(Box::new(workflows::revenue_recovery::ExecutePcrWorkflow))
                 }
+                storage::ProcessTrackerRunner::RoutingActivationWorkflow => Ok(Box::new(
+                    workflows::routing_activation::RoutingActivationWorkflow,
+                )),
+                storage::ProcessTrackerRunner::PaymentIntentExpiryWorkflow => Ok(Box::new(
+                    workflows::payment_intent_expiry::PaymentIntentExpiryWorkflow,
+                )),
+                storage::ProcessTrackerRunner::ScheduledCaptureWorkflow => Ok(Box::new(
+                    workflows::scheduled_capture::ScheduledCaptureWorkflow,
+                )),
+                storage::ProcessTrackerRunner::AuthorizationExpiryWorkflow => Ok(Box::new(
+                    workflows::authorization_expiry::AuthorizationExpiryWorkflow,
+                )),
+                storage::ProcessTrackerRunner::CardAccountUpdaterWorkflow => Ok(Box::new(
+                    workflows::card_account_updater::CardAccountUpdaterWorkflow,
+                )),
+                storage::ProcessTrackerRunner::CardExpiryScanWorkflow => Ok(Box::new(
+                    workflows::card_account_updater::CardExpiryScanWorkflow,
+                )),
+                storage::ProcessTrackerRunner::NetworkTokenStatusCheckWorkflow => Ok(Box::new(
+                    workflows::network_token_status_check::NetworkTokenStatusCheckWorkflow,
+                )),
+                storage::ProcessTrackerRunner::PaymentMethodExportWorkflow => Ok(Box::new(
+                    workflows::payment_method_export::PaymentMethodExportWorkflow,
+                )),
+                storage::ProcessTrackerRunner::PaymentLedgerWorkflow => {
+                    Ok(Box::new(workflows::payment_ledger::PaymentLedgerWorkflow))
+                }
+                storage::ProcessTrackerRunner::PaymentMethodFingerprintBackfillWorkflow => {
+                    Ok(Box::new(
+                        workflows::payment_method_fingerprint_backfill::PaymentMethodFingerprintBackfillWorkflow,
+                    ))
+                }
+                storage::ProcessTrackerRunner::PaymentRetryWorkflow => {
+                    Ok(Box::new(workflows::payment_retry::PaymentRetryWorkflow))
+                }
             }
         };
 
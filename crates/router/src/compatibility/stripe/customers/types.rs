@@ -195,7 +195,7 @@ pub struct CustomerPaymentMethodListResponse {
     pub data: Vec<PaymentMethodData>,
 }
 
-#[derive(Default, Serialize, PartialEq, Eq)]
+#[derive(Default, Debug, Serialize, PartialEq, Eq)]
 pub struct PaymentMethodData {
     pub id: Option<String>,
     pub object: &'static str,
@@ -203,7 +203,7 @@ pub struct PaymentMethodData {
     pub created: Option<time::PrimitiveDateTime>,
 }
 
-#[derive(Default, Serialize, PartialEq, Eq)]
+#[derive(Default, Debug, Serialize, PartialEq, Eq)]
 pub struct CardDetails {
     pub country: Option<String>,
     pub last4: Option<String>,
@@ -240,6 +240,19 @@ impl From<api_types::CustomerPaymentMethod> for PaymentMethodData {
     }
 }
 
+#[cfg(feature = "v1")]
+impl From<api_types::PaymentMethodResponse> for PaymentMethodData {
+    fn from(item: api_types::PaymentMethodResponse) -> Self {
+        let card = item.card.map(From::from);
+        Self {
+            id: Some(item.payment_method_id),
+            object: "payment_method",
+            card,
+            created: item.created,
+        }
+    }
+}
+
 #[cfg(feature = "v1")]
 impl From<api_types::CardDetailFromLocker> for CardDetails {
     fn from(item: api_types::CardDetailFromLocker) -> Self {
@@ -66,6 +66,12 @@ pub enum StripeErrorCode {
     #[error(error_type = StripeErrorType::ApiError, code = "external_vault_failed", message = "external vault has failed")]
     ExternalVaultFailed,
 
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "idempotency_key_in_use", message = "Idempotency key was already used for a different request")]
+    IdempotencyKeyReused,
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "idempotency_key_in_use", message = "A request with the same idempotency key is being processed")]
+    IdempotentRequestInProgress,
+
     #[error(error_type = StripeErrorType::ApiError, code = "internal_server_error", message = "Server is down")]
     InternalServerError,
 
@@ -511,6 +517,10 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::RefundFailed { data: _ } => Self::RefundFailed, // Nothing at stripe to map
             errors::ApiErrorResponse::PayoutFailed { data: _ } => Self::PayoutFailed,
             errors::ApiErrorResponse::ExternalVaultFailed => Self::ExternalVaultFailed,
+            errors::ApiErrorResponse::IdempotencyKeyReused => Self::IdempotencyKeyReused,
+            errors::ApiErrorResponse::IdempotentRequestInProgress => {
+                Self::IdempotentRequestInProgress
+            }
 
             errors::ApiErrorResponse::MandateUpdateFailed
             | errors::ApiErrorResponse::MandateSerializationFailed
@@ -787,6 +797,7 @@ impl actix_web::ResponseError for StripeErrorCode {
                 StatusCode::from_u16(*code).unwrap_or(StatusCode::OK)
             }
             Self::LockTimeout => StatusCode::LOCKED,
+            Self::IdempotencyKeyReused | Self::IdempotentRequestInProgress => StatusCode::CONFLICT,
             Self::ProfileAcquirerNotFound => StatusCode::NOT_FOUND,
         }
     }
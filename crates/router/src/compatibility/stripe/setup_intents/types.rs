@@ -324,7 +324,7 @@ impl From<api_enums::IntentStatus> for StripeSetupStatus {
                 logger::error!("Invalid status change");
                 Self::Canceled
             }
-            api_enums::IntentStatus::Cancelled => Self::Canceled,
+            api_enums::IntentStatus::Cancelled | api_enums::IntentStatus::Expired => Self::Canceled,
         }
     }
 }
@@ -339,6 +339,17 @@ pub enum CancellationReason {
     Abandoned,
 }
 
+impl From<CancellationReason> for api_enums::PaymentCancellationReason {
+    fn from(item: CancellationReason) -> Self {
+        match item {
+            CancellationReason::Duplicate => Self::Duplicate,
+            CancellationReason::Fraudulent => Self::Fraudulent,
+            CancellationReason::RequestedByCustomer => Self::RequestedByCustomer,
+            CancellationReason::Abandoned => Self::Abandoned,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, Copy, Clone)]
 pub struct StripePaymentCancelRequest {
     cancellation_reason: Option<CancellationReason>,
@@ -348,6 +359,7 @@ impl From<StripePaymentCancelRequest> for payments::PaymentsCancelRequest {
     fn from(item: StripePaymentCancelRequest) -> Self {
         Self {
             cancellation_reason: item.cancellation_reason.map(|c| c.to_string()),
+            cancellation_reason_code: item.cancellation_reason.map(From::from),
             ..Self::default()
         }
     }
@@ -12,7 +12,8 @@ use router_env::logger;
 use serde::Serialize;
 
 use super::{
-    payment_intents::types::StripePaymentIntentResponse, refunds::types::StripeRefundResponse,
+    customers::types::PaymentMethodData, payment_intents::types::StripePaymentIntentResponse,
+    refunds::types::StripeRefundResponse,
 };
 use crate::{
     core::{
@@ -85,6 +86,7 @@ pub enum StripeWebhookObject {
     Refund(StripeRefundResponse),
     Dispute(StripeDisputeResponse),
     Mandate(StripeMandateResponse),
+    PaymentMethod(PaymentMethodData),
     #[cfg(feature = "payouts")]
     Payout(StripePayoutResponse),
 }
@@ -268,9 +270,15 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::PaymentFailed => "payment_intent.payment_failed",
         api_models::enums::EventType::PaymentProcessing => "payment_intent.processing",
         api_models::enums::EventType::PaymentCancelled => "payment_intent.canceled",
+        // stripe cancels abandoned payment intents instead of expiring them
+        api_models::enums::EventType::PaymentExpired => "payment_intent.canceled",
 
         // the below are not really stripe compatible because stripe doesn't provide this
         api_models::enums::EventType::ActionRequired => "action.required",
+        api_models::enums::EventType::PaymentAuthorizationExpiring => {
+            "payment_intent.authorization_expiring"
+        }
+        api_models::enums::EventType::PaymentReauthorized => "payment_intent.reauthorized",
         api_models::enums::EventType::RefundSucceeded => "refund.succeeded",
         api_models::enums::EventType::RefundFailed => "refund.failed",
         api_models::enums::EventType::DisputeOpened => "dispute.failed",
@@ -282,6 +290,7 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::DisputeLost => "dispute.lost",
         api_models::enums::EventType::MandateActive => "mandate.active",
         api_models::enums::EventType::MandateRevoked => "mandate.revoked",
+        api_models::enums::EventType::PaymentMethodUpdated => "payment_method.updated",
 
         // as per this doc https://stripe.com/docs/api/events/types#event_types-payment_intent.amount_capturable_updated
         api_models::enums::EventType::PaymentAuthorized => {
@@ -336,6 +345,9 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             api::OutgoingWebhookContent::MandateDetails(mandate) => {
                 Self::Mandate((*mandate).into())
             }
+            api::OutgoingWebhookContent::PaymentMethodDetails(payment_method) => {
+                Self::PaymentMethod((*payment_method).into())
+            }
             #[cfg(feature = "payouts")]
             api::OutgoingWebhookContent::PayoutDetails(payout) => Self::Payout((*payout).into()),
         }
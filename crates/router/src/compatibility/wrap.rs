@@ -10,14 +10,14 @@ use crate::{
     events::api_logs::ApiEventMetric,
     routes::{
         app::{AppStateInfo, ReqState},
-        AppState, SessionState,
+        lock_utils, AppState, SessionState,
     },
     services::{self, api, authentication as auth, logger},
 };
 
 #[instrument(skip(request, payload, state, func, api_authentication))]
 pub async fn compatibility_api_wrap<'a, 'b, U, T, Q, F, Fut, S, E, E2>(
-    flow: impl router_env::types::FlowMetric,
+    flow: impl router_env::types::FlowMetric + lock_utils::IdempotentFlow,
     state: Arc<AppState>,
     request: &'a HttpRequest,
     payload: T,
@@ -44,6 +44,7 @@ where
     let start_instant = Instant::now();
     logger::info!(tag = ?Tag::BeginRequest, payload = ?payload);
 
+    let mut idempotent_request = None;
     let server_wrap_util_res = api::server_wrap_util(
         &flow,
         state.clone().into(),
@@ -53,6 +54,8 @@ where
         func,
         api_authentication,
         lock_action,
+        flow.supports_idempotency(),
+        &mut idempotent_request,
     )
     .await
     .map(|response| {
@@ -106,6 +109,7 @@ where
             }
         }
         Ok(api::ApplicationResponse::StatusOk) => api::http_response_ok(),
+        Ok(api::ApplicationResponse::Replayed(response)) => api::http_response_replayed(response),
         Ok(api::ApplicationResponse::TextPlain(text)) => api::http_response_plaintext(text),
         Ok(api::ApplicationResponse::FileData((file_data, content_type))) => {
             api::http_response_file_data(file_data, content_type)
@@ -179,6 +183,12 @@ where
         Err(error) => api::log_and_return_error_response(error),
     };
 
+    // The response is stored in the compatibility format, in which it is replayed
+    let res = match idempotent_request {
+        Some(idempotent_request) => idempotent_request.complete(res).await,
+        None => res,
+    };
+
     let response_code = res.status().as_u16();
     let end_instant = Instant::now();
     let request_duration = end_instant.saturating_duration_since(start_instant);
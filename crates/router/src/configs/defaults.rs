@@ -101,6 +101,38 @@ impl Default for super::settings::EphemeralConfig {
     }
 }
 
+impl Default for super::settings::CardAccountUpdaterSettings {
+    fn default() -> Self {
+        Self {
+            batch_size: 100,
+            batch_interval_in_seconds: 3600,
+            expiry_window_in_days: 30,
+            expiry_scan_interval_in_seconds: 86400,
+            provider: Default::default(),
+        }
+    }
+}
+
+impl Default for super::settings::NetworkTokenLifecycleSettings {
+    fn default() -> Self {
+        Self {
+            status_check_interval_in_hours: 24,
+            status_check_batch_size: 100,
+            notification_tolerance_in_seconds: 300,
+            cryptogram_cache_ttl_in_seconds: 0,
+        }
+    }
+}
+
+impl Default for super::settings::IdempotencyConfig {
+    fn default() -> Self {
+        Self {
+            in_progress_ttl_in_seconds: 300,
+            response_ttl_in_seconds: 86400,
+        }
+    }
+}
+
 #[cfg(feature = "kv_store")]
 impl Default for super::settings::DrainerSettings {
     fn default() -> Self {
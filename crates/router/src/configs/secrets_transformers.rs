@@ -314,11 +314,15 @@ impl SecretsHandler for settings::NetworkTokenizationService {
         let private_key = secret_management_client
             .get_secret(network_tokenization.private_key.clone())
             .await?;
+        let webhook_secret = secret_management_client
+            .get_secret(network_tokenization.webhook_secret.clone())
+            .await?;
 
         Ok(value.transition_state(|network_tokenization| Self {
             public_key,
             private_key,
             token_service_api_key,
+            webhook_secret,
             ..network_tokenization
         }))
     }
@@ -501,6 +505,7 @@ pub(crate) async fn fetch_raw_secrets(
         multiple_api_version_supported_connectors: conf.multiple_api_version_supported_connectors,
         applepay_merchant_configs,
         lock_settings: conf.lock_settings,
+        idempotency: conf.idempotency,
         temp_locker_enable_config: conf.temp_locker_enable_config,
         generic_link: conf.generic_link,
         payment_link: conf.payment_link,
@@ -526,6 +531,8 @@ pub(crate) async fn fetch_raw_secrets(
         locker_based_open_banking_connectors: conf.locker_based_open_banking_connectors,
         grpc_client: conf.grpc_client,
         crm: conf.crm,
+        card_account_updater: conf.card_account_updater,
+        network_token_lifecycle: conf.network_token_lifecycle,
         #[cfg(feature = "v2")]
         cell_information: conf.cell_information,
         network_tokenization_supported_card_networks: conf
@@ -13,6 +13,7 @@ use error_stack::ResultExt;
 #[cfg(feature = "email")]
 use external_services::email::EmailSettings;
 use external_services::{
+    card_account_updater::CardAccountUpdaterConfig,
     crm::CrmManagerConfig,
     file_storage::FileStorageConfig,
     grpc_client::GrpcClientSettings,
@@ -102,6 +103,7 @@ pub struct Settings<S: SecretState> {
     pub email: EmailSettings,
     pub user: UserSettings,
     pub crm: CrmManagerConfig,
+    pub card_account_updater: CardAccountUpdaterSettings,
     pub cors: CorsSettings,
     pub mandates: Mandates,
     pub zero_mandates: ZeroMandates,
@@ -123,6 +125,7 @@ pub struct Settings<S: SecretState> {
     pub multiple_api_version_supported_connectors: MultipleApiVersionSupportedConnectors,
     pub applepay_merchant_configs: SecretStateContainer<ApplepayMerchantConfigs, S>,
     pub lock_settings: LockSettings,
+    pub idempotency: IdempotencyConfig,
     pub temp_locker_enable_config: TempLockerEnableConfig,
     pub generic_link: GenericLink,
     pub payment_link: PaymentLink,
@@ -150,6 +153,7 @@ pub struct Settings<S: SecretState> {
     pub cell_information: CellInformation,
     pub network_tokenization_supported_card_networks: NetworkTokenizationSupportedCardNetworks,
     pub network_tokenization_service: Option<SecretStateContainer<NetworkTokenizationService, S>>,
+    pub network_token_lifecycle: NetworkTokenLifecycleSettings,
     pub network_tokenization_supported_connectors: NetworkTokenizationSupportedConnectors,
     pub theme: ThemeSettings,
     pub platform: Platform,
@@ -601,6 +605,23 @@ pub struct NetworkTokenizationService {
     pub key_id: String,
     pub delete_token_url: url::Url,
     pub check_token_status_url: url::Url,
+    pub webhook_secret: Secret<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct NetworkTokenLifecycleSettings {
+    /// Interval between the status checks of a network token with the token service, in hours
+    pub status_check_interval_in_hours: i64,
+    /// Number of payment methods of a merchant whose network tokens are checked in a single run of
+    /// the status check
+    pub status_check_batch_size: usize,
+    /// Maximum age of a lifecycle notification, in seconds. Older notifications are rejected as
+    /// replays
+    pub notification_tolerance_in_seconds: i64,
+    /// Duration for which a network token fetched from the token service is reused, in seconds.
+    /// Fetched tokens are not cached when set to 0
+    pub cryptogram_cache_ttl_in_seconds: i64,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -749,6 +770,30 @@ pub struct EphemeralConfig {
     pub validity: i64,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct IdempotencyConfig {
+    /// Time after which a request that has not completed no longer blocks retries, in seconds
+    pub in_progress_ttl_in_seconds: i64,
+    /// Time for which the response of a request is replayed on retries, in seconds
+    pub response_ttl_in_seconds: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct CardAccountUpdaterSettings {
+    /// Maximum number of stored cards sent to the card account updater in a single request
+    pub batch_size: usize,
+    /// Delay between the batches of stored cards sent to the card account updater, in seconds
+    pub batch_interval_in_seconds: i64,
+    /// Stored cards expiring within this number of days are sent to the card account updater
+    pub expiry_window_in_days: i64,
+    /// Delay between the scans of the stored cards of a merchant for expiring cards, in seconds
+    pub expiry_scan_interval_in_seconds: i64,
+    /// Card account updater provider
+    pub provider: CardAccountUpdaterConfig,
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 #[serde(default)]
 pub struct Jwekey {
@@ -1033,6 +1078,9 @@ impl Settings<SecuredSecret> {
             .validate()
             .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.to_string()))?;
 
+        self.card_account_updater.validate()?;
+        self.network_token_lifecycle.validate()?;
+
         self.lock_settings.validate()?;
         self.events.validate()?;
 
@@ -318,3 +318,67 @@ impl super::settings::Platform {
         })
     }
 }
+
+impl super::settings::CardAccountUpdaterSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.batch_size == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "card account updater batch size must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.batch_interval_in_seconds <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "card account updater batch interval must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.expiry_window_in_days < 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "card account updater expiry window must not be negative".into(),
+            ))
+        })?;
+
+        when(self.expiry_scan_interval_in_seconds <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "card account updater expiry scan interval must be greater than 0".into(),
+            ))
+        })?;
+
+        self.provider
+            .validate()
+            .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.to_string()))
+    }
+}
+
+impl super::settings::NetworkTokenLifecycleSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.status_check_interval_in_hours <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "network token status check interval must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.status_check_batch_size == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "network token status check batch size must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.notification_tolerance_in_seconds <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "network token notification tolerance must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.cryptogram_cache_ttl_in_seconds < 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "network token cryptogram cache TTL must not be negative".into(),
+            ))
+        })
+    }
+}
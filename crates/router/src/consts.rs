@@ -56,6 +56,10 @@ pub(crate) const MERCHANT_ID_FIELD_EXTENSION_ID: &str = "1.2.840.113635.100.6.32
 
 pub const MAX_ROUTING_CONFIGS_PER_MERCHANT: usize = 100;
 pub const ROUTING_CONFIG_ID_LENGTH: usize = 10;
+/// Maximum number of payments replayed in a single routing simulation
+pub const ROUTING_SIMULATION_MAX_LIMIT: u32 = 1000;
+/// Number of payments whose addresses are fetched concurrently in a routing simulation
+pub const ROUTING_SIMULATION_ADDRESS_LOOKUP_CONCURRENCY: usize = 10;
 
 pub const LOCKER_REDIS_PREFIX: &str = "LOCKER_PM_TOKEN";
 pub const LOCKER_REDIS_EXPIRY_SECONDS: u32 = 60 * 15; // 15 minutes
@@ -105,6 +109,27 @@ pub const MAX_SESSION_EXPIRY: u32 = 7890000;
 /// Min payment session expiry
 pub const MIN_SESSION_EXPIRY: u32 = 60;
 
+/// Max payment intent expiry
+pub const MAX_PAYMENT_INTENT_EXPIRY: u32 = 7890000;
+
+/// Min payment intent expiry
+pub const MIN_PAYMENT_INTENT_EXPIRY: u32 = 60;
+
+/// Min delay in seconds after confirmation for a scheduled capture
+pub const MIN_SCHEDULED_CAPTURE_DELAY: i64 = 60;
+
+/// Max delay in seconds after confirmation for a scheduled capture, authorizations are usually
+/// held by the issuer for at least 7 days
+pub const MAX_SCHEDULED_CAPTURE_DELAY: i64 = 7 * 24 * 60 * 60;
+
+/// Number of days for which an authorization is assumed to be held by the issuer, when the card
+/// network of the payment is not known
+pub const DEFAULT_AUTHORIZATION_VALIDITY_IN_DAYS: u16 = 7;
+
+/// Time in seconds before an authorization expires, at which it is re-authorized or the merchant is
+/// notified about the upcoming expiry
+pub const AUTHORIZATION_EXPIRY_NOTICE_PERIOD_IN_SECONDS: i64 = 24 * 60 * 60;
+
 /// Max payment intent fulfillment expiry
 pub const MAX_INTENT_FULFILLMENT_EXPIRY: u32 = 1800;
 
@@ -129,6 +154,9 @@ pub const DEFAULT_POLL_FREQUENCY: i8 = 5;
 pub(crate) const REDUCE_ACCESS_TOKEN_EXPIRY_TIME: u8 = 15;
 pub const CONNECTOR_CREDS_TOKEN_TTL: i64 = 900;
 
+/// Time for which a precompiled constraint graph is kept in redis, in seconds
+pub const PRECOMPILED_CGRAPH_TTL_IN_SECONDS: i64 = 24 * 60 * 60;
+
 //max_amount allowed is 999999999 in minor units
 pub const MAX_ALLOWED_AMOUNT: i64 = 999999999;
 
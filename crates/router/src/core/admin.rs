@@ -31,7 +31,7 @@ use crate::{
         encryption::transfer_encryption_key,
         errors::{self, RouterResponse, RouterResult, StorageErrorExt},
         payment_methods::{cards, transformers},
-        payments::helpers,
+        payments::{self, helpers},
         pm_auth::helpers::PaymentAuthConnectorDataExt,
         routing, utils as core_utils,
     },
@@ -2455,6 +2455,10 @@ impl MerchantConnectorAccountUpdateBridge for api_models::admin::MerchantConnect
             additional_merchant_data: Box::new(encrypted_data.additional_merchant_data),
             connector_wallets_details: Box::new(encrypted_data.connector_wallets_details),
             feature_metadata: Box::new(feature_metadata),
+            fee_schedule: Box::new(
+                self.fee_schedule
+                    .map(|fee_schedule| (!fee_schedule.is_empty()).then_some(fee_schedule)),
+            ),
         })
     }
 }
@@ -2634,6 +2638,10 @@ impl MerchantConnectorAccountUpdateBridge for api_models::admin::MerchantConnect
             status: Some(connector_status),
             additional_merchant_data: Box::new(encrypted_data.additional_merchant_data),
             connector_wallets_details: Box::new(encrypted_data.connector_wallets_details),
+            fee_schedule: Box::new(
+                self.fee_schedule
+                    .map(|fee_schedule| (!fee_schedule.is_empty()).then_some(fee_schedule)),
+            ),
         })
     }
 }
@@ -2792,6 +2800,10 @@ impl MerchantConnectorAccountCreateBridge for api::MerchantConnectorCreate {
             additional_merchant_data: encrypted_data.additional_merchant_data,
             version: common_types::consts::API_VERSION,
             feature_metadata,
+            fee_schedule: self
+                .fee_schedule
+                .clone()
+                .filter(|fee_schedule| !fee_schedule.is_empty()),
         })
     }
 
@@ -2996,6 +3008,10 @@ impl MerchantConnectorAccountCreateBridge for api::MerchantConnectorCreate {
             business_sub_label: self.business_sub_label.clone(),
             additional_merchant_data: encrypted_data.additional_merchant_data,
             version: common_types::consts::API_VERSION,
+            fee_schedule: self
+                .fee_schedule
+                .clone()
+                .filter(|fee_schedule| !fee_schedule.is_empty()),
         })
     }
 
@@ -3193,6 +3209,13 @@ pub async fn create_connector(
         .retrieve_and_update_default_fallback_routing_algorithm_if_routable_connector_exists()
         .await?;
 
+    payments::routing::precompile_merchant_cgraph(
+        &state,
+        merchant_context.get_merchant_key_store(),
+        &mca.profile_id,
+        &mca.connector_type.into(),
+    );
+
     metrics::MCA_CREATE.add(
         1,
         router_env::metric_attributes!(
@@ -3474,6 +3497,13 @@ pub async fn update_connector(
             )
         })?;
 
+    payments::routing::precompile_merchant_cgraph(
+        &state,
+        &key_store,
+        &updated_mca.profile_id,
+        &updated_mca.connector_type.into(),
+    );
+
     let response = updated_mca.foreign_try_into()?;
 
     Ok(service_api::ApplicationResponse::Json(response))
@@ -3543,6 +3573,13 @@ pub async fn delete_connector(
         .retrieve_and_delete_from_default_fallback_routing_algorithm_if_routable_connector_exists()
         .await?;
 
+    payments::routing::precompile_merchant_cgraph(
+        &state,
+        &key_store,
+        &mca.profile_id,
+        &mca.connector_type.into(),
+    );
+
     let response = api::MerchantConnectorDeleteResponse {
         merchant_id,
         merchant_connector_id,
@@ -3848,6 +3885,26 @@ impl ProfileCreateBridge for api::ProfileCreate {
             helpers::validate_session_expiry(session_expiry.to_owned())?;
         }
 
+        if let Some(payment_intent_expiry) = self.payment_intent_expiry {
+            helpers::validate_payment_intent_expiry(payment_intent_expiry)?;
+        }
+
+        if let Some(retry_policy) = &self.retry_policy {
+            retry_policy.validate().map_err(|error| {
+                errors::ApiErrorResponse::InvalidRequestData {
+                    message: error.to_string(),
+                }
+            })?;
+        }
+
+        if let Some(dynamic_currency_conversion) = &self.dynamic_currency_conversion {
+            dynamic_currency_conversion.validate().map_err(|error| {
+                errors::ApiErrorResponse::InvalidRequestData {
+                    message: error.to_string(),
+                }
+            })?;
+        }
+
         if let Some(intent_fulfillment_expiry) = self.intent_fulfillment_time {
             helpers::validate_intent_fulfillment_expiry(intent_fulfillment_expiry)?;
         }
@@ -4053,6 +4110,12 @@ impl ProfileCreateBridge for api::ProfileCreate {
                 .is_pre_network_tokenization_enabled
                 .unwrap_or_default(),
             merchant_category_code: self.merchant_category_code,
+            payment_intent_expiry: self.payment_intent_expiry.map(i64::from),
+            is_auto_reauthorization_enabled: self.is_auto_reauthorization_enabled,
+            retry_policy: self
+                .retry_policy
+                .filter(|retry_policy| !retry_policy.is_empty()),
+            dynamic_currency_conversion: self.dynamic_currency_conversion,
         }))
     }
 
@@ -4067,6 +4130,26 @@ impl ProfileCreateBridge for api::ProfileCreate {
             helpers::validate_session_expiry(session_expiry.to_owned())?;
         }
 
+        if let Some(payment_intent_expiry) = self.payment_intent_expiry {
+            helpers::validate_payment_intent_expiry(payment_intent_expiry)?;
+        }
+
+        if let Some(retry_policy) = &self.retry_policy {
+            retry_policy.validate().map_err(|error| {
+                errors::ApiErrorResponse::InvalidRequestData {
+                    message: error.to_string(),
+                }
+            })?;
+        }
+
+        if let Some(dynamic_currency_conversion) = &self.dynamic_currency_conversion {
+            dynamic_currency_conversion.validate().map_err(|error| {
+                errors::ApiErrorResponse::InvalidRequestData {
+                    message: error.to_string(),
+                }
+            })?;
+        }
+
         // Generate a unique profile id
         // TODO: the profile_id should be generated from the profile_name
         let profile_id = common_utils::generate_profile_id_of_default_length();
@@ -4202,6 +4285,13 @@ impl ProfileCreateBridge for api::ProfileCreate {
                 .external_vault_connector_details
                 .map(ForeignInto::foreign_into),
             merchant_category_code: self.merchant_category_code,
+            payment_intent_expiry: self.payment_intent_expiry.map(i64::from),
+            is_auto_reauthorization_enabled: self.is_auto_reauthorization_enabled,
+            retry_policy: self
+                .retry_policy
+                .filter(|retry_policy| !retry_policy.is_empty()),
+            dynamic_currency_conversion: self.dynamic_currency_conversion,
+            payment_method_duplicate_policy: self.payment_method_duplicate_policy,
         }))
     }
 }
@@ -4363,6 +4453,26 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
             helpers::validate_session_expiry(session_expiry.to_owned())?;
         }
 
+        if let Some(payment_intent_expiry) = self.payment_intent_expiry {
+            helpers::validate_payment_intent_expiry(payment_intent_expiry)?;
+        }
+
+        if let Some(retry_policy) = &self.retry_policy {
+            retry_policy.validate().map_err(|error| {
+                errors::ApiErrorResponse::InvalidRequestData {
+                    message: error.to_string(),
+                }
+            })?;
+        }
+
+        if let Some(dynamic_currency_conversion) = &self.dynamic_currency_conversion {
+            dynamic_currency_conversion.validate().map_err(|error| {
+                errors::ApiErrorResponse::InvalidRequestData {
+                    message: error.to_string(),
+                }
+            })?;
+        }
+
         if let Some(intent_fulfillment_expiry) = self.intent_fulfillment_time {
             helpers::validate_intent_fulfillment_expiry(intent_fulfillment_expiry)?;
         }
@@ -4536,6 +4646,12 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
                 is_iframe_redirection_enabled: self.is_iframe_redirection_enabled,
                 is_pre_network_tokenization_enabled: self.is_pre_network_tokenization_enabled,
                 merchant_category_code: self.merchant_category_code,
+                payment_intent_expiry: self.payment_intent_expiry.map(i64::from),
+                is_auto_reauthorization_enabled: self.is_auto_reauthorization_enabled,
+                retry_policy: self
+                    .retry_policy
+                    .map(|retry_policy| (!retry_policy.is_empty()).then_some(retry_policy)),
+                dynamic_currency_conversion: self.dynamic_currency_conversion,
             },
         )))
     }
@@ -4554,6 +4670,26 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
             helpers::validate_session_expiry(session_expiry.to_owned())?;
         }
 
+        if let Some(payment_intent_expiry) = self.payment_intent_expiry {
+            helpers::validate_payment_intent_expiry(payment_intent_expiry)?;
+        }
+
+        if let Some(retry_policy) = &self.retry_policy {
+            retry_policy.validate().map_err(|error| {
+                errors::ApiErrorResponse::InvalidRequestData {
+                    message: error.to_string(),
+                }
+            })?;
+        }
+
+        if let Some(dynamic_currency_conversion) = &self.dynamic_currency_conversion {
+            dynamic_currency_conversion.validate().map_err(|error| {
+                errors::ApiErrorResponse::InvalidRequestData {
+                    message: error.to_string(),
+                }
+            })?;
+        }
+
         let webhook_details = self.webhook_details.map(ForeignInto::foreign_into);
 
         let payment_link_config = self
@@ -4676,6 +4812,13 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
                     .external_vault_connector_details
                     .map(ForeignInto::foreign_into),
                 merchant_category_code: self.merchant_category_code,
+                payment_intent_expiry: self.payment_intent_expiry.map(i64::from),
+                is_auto_reauthorization_enabled: self.is_auto_reauthorization_enabled,
+                retry_policy: self
+                    .retry_policy
+                    .map(|retry_policy| (!retry_policy.is_empty()).then_some(retry_policy)),
+                dynamic_currency_conversion: self.dynamic_currency_conversion,
+                payment_method_duplicate_policy: self.payment_method_duplicate_policy,
             },
         )))
     }
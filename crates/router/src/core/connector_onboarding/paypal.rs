@@ -166,6 +166,7 @@ pub async fn update_mca(
         test_mode: None,
         additional_merchant_data: None,
         connector_wallets_details: None,
+        fee_schedule: None,
     };
     #[cfg(feature = "v2")]
     let request = MerchantConnectorUpdate {
@@ -183,6 +184,7 @@ pub async fn update_mca(
         additional_merchant_data: None,
         connector_wallets_details: None,
         feature_metadata: None,
+        fee_schedule: None,
     };
     let mca_response =
         admin::update_connector(state.clone(), &merchant_id, None, &connector_id, request).await?;
@@ -13,6 +13,8 @@ use super::{
     errors::{self, ConnectorErrorExt, RouterResponse, StorageErrorExt},
     metrics,
 };
+#[cfg(feature = "v1")]
+use crate::core::ledger;
 use crate::{
     core::{files, payments, utils as core_utils},
     routes::SessionState,
@@ -243,6 +245,7 @@ pub async fn accept_dispute(
         .attach_printable_lazy(|| {
             format!("Unable to update dispute with dispute_id: {dispute_id}")
         })?;
+    ledger::record_dispute_entries(&state, &updated_dispute).await;
     let dispute_response = api_models::disputes::DisputeResponse::foreign_from(updated_dispute);
     Ok(services::ApplicationResponse::Json(dispute_response))
 }
@@ -417,6 +420,7 @@ pub async fn submit_evidence(
         .attach_printable_lazy(|| {
             format!("Unable to update dispute with dispute_id: {dispute_id}")
         })?;
+    ledger::record_dispute_entries(&state, &updated_dispute).await;
     let dispute_response = api_models::disputes::DisputeResponse::foreign_from(updated_dispute);
     Ok(services::ApplicationResponse::Json(dispute_response))
 }
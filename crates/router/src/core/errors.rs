@@ -386,6 +386,10 @@ pub enum RoutingError {
     OpenRouterError(String),
     #[error("Invalid transaction type")]
     InvalidTransactionType,
+    #[error("failed to fetch the payment time routing config for the profile")]
+    TemporalConfigFetchFailed,
+    #[error("failed to fetch the fee schedules of the connectors for the profile")]
+    ConnectorFeeScheduleFetchFailed,
 }
 
 #[derive(Debug, Clone, thiserror::Error)]
@@ -252,6 +252,9 @@ where
             let cancel_req = api_models::payments::PaymentsCancelRequest {
                 payment_id: frm_data.payment_intent.get_id().to_owned(),
                 cancellation_reason: frm_data.fraud_check.frm_error.clone(),
+                cancellation_reason_code: Some(
+                    api_models::enums::PaymentCancellationReason::Fraudulent,
+                ),
                 merchant_connector_details: None,
             };
             let cancel_res = Box::pin(payments::payments_core::<
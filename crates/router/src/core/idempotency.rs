@@ -0,0 +1,655 @@
+//! Deduplication of retried requests using the `Idempotency-Key` request header.
+//!
+//! The first request made with a key is marked as in progress in redis while it is processed, and
+//! the response sent for it is stored once it is rendered, including error responses. The stored
+//! response is kept in redis and in the `idempotency_key` table, so that it outlives the loss of
+//! the redis entry. Retries of the request with the same key receive the stored response without
+//! being processed again, while reusing the key for a different request is rejected. Requests which
+//! could not be processed at all, or which failed with a server error, release the key so that they
+//! can be retried, while a key whose response could not be stored stays locked until the in
+//! progress record expires. Only the content type and the headers set by the application are stored
+//! with the response, so that headers specific to the request are not replayed.
+
+use actix_web::{
+    body::{BoxBody, MessageBody},
+    HttpRequest, HttpResponse,
+};
+use common_utils::{
+    crypto::{GenerateDigest, Sha256},
+    date_time,
+    ext_traits::{Encode, ValueExt},
+};
+use error_stack::{report, ResultExt};
+use redis_interface as redis;
+use router_env::{instrument, logger, tracing};
+
+use super::errors::{self, RouterResult};
+use crate::{
+    db::idempotency_key::IdempotencyKeyInterface,
+    headers,
+    routes::{app::SessionStateInfo, SessionState},
+    services::api::StoredResponse,
+    types::storage,
+};
+
+pub const IDEMPOTENCY_KEY_PREFIX: &str = "IDEMPOTENCY";
+
+const IDEMPOTENCY_KEY_MAX_LENGTH: usize = 255;
+
+/// Headers of the response which are stored to be replayed
+const STORED_RESPONSE_HEADERS: [&str; 4] = [
+    headers::CONTENT_TYPE,
+    headers::X_PAYMENT_CONFIRM_SOURCE,
+    headers::CONNECTOR_HTTP_STATUS_CODE,
+    common_utils::consts::X_HS_LATENCY,
+];
+
+fn is_stored_response_header(header_name: &str) -> bool {
+    STORED_RESPONSE_HEADERS
+        .iter()
+        .any(|stored_header| stored_header.eq_ignore_ascii_case(header_name))
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+enum IdempotencyRecord {
+    InProgress {
+        fingerprint: String,
+    },
+    Completed {
+        fingerprint: String,
+        response: StoredResponse,
+    },
+}
+
+impl IdempotencyRecord {
+    fn get_fingerprint(&self) -> &str {
+        match self {
+            Self::InProgress { fingerprint } | Self::Completed { fingerprint, .. } => fingerprint,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum IdempotencyStatus {
+    /// No request was made earlier with the key, the request has to be processed
+    New,
+    /// The request was completed earlier, its response has to be sent again
+    Replay(StoredResponse),
+}
+
+#[derive(Clone, Debug)]
+pub struct IdempotencyInput {
+    pub idempotency_key: String,
+    /// Digest of the method, path, query and body of the request, used to detect reuse of the key
+    /// for a different request
+    fingerprint: String,
+}
+
+impl IdempotencyInput {
+    /// Builds the input from the `Idempotency-Key` header of the request, `None` if the header is
+    /// not present
+    pub fn from_request<T>(request: &HttpRequest, payload: &T) -> RouterResult<Option<Self>>
+    where
+        T: serde::Serialize,
+    {
+        let Some(header_value) = request.headers().get(headers::IDEMPOTENCY_KEY) else {
+            return Ok(None);
+        };
+
+        let idempotency_key = header_value
+            .to_str()
+            .ok()
+            .map(str::trim)
+            .filter(|key| !key.is_empty() && key.len() <= IDEMPOTENCY_KEY_MAX_LENGTH)
+            .ok_or_else(|| {
+                report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "`{}` header must be a non empty string of at most {} characters",
+                        headers::IDEMPOTENCY_KEY,
+                        IDEMPOTENCY_KEY_MAX_LENGTH
+                    ),
+                })
+            })?;
+
+        let body = serde_json::to_vec(payload)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize request for computing its fingerprint")?;
+
+        let message = [
+            request.method().as_str().as_bytes(),
+            request.path().as_bytes(),
+            request.query_string().as_bytes(),
+            body.as_slice(),
+        ]
+        .join(&b'\n');
+
+        let fingerprint = Sha256
+            .generate_digest(&message)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to compute request fingerprint")?;
+
+        Ok(Some(Self {
+            idempotency_key: idempotency_key.to_owned(),
+            fingerprint: hex::encode(fingerprint),
+        }))
+    }
+
+    fn get_redis_key(&self, merchant_id: &common_utils::id_type::MerchantId) -> String {
+        format!(
+            "{}_{}_{}",
+            IDEMPOTENCY_KEY_PREFIX,
+            merchant_id.get_string_repr(),
+            self.idempotency_key
+        )
+    }
+
+    /// Marks the request as in progress, or returns the stored response if the request was
+    /// completed earlier, looking it up in the database if it is no longer in redis
+    #[instrument(skip_all)]
+    pub async fn begin<A>(
+        &self,
+        state: &A,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> RouterResult<IdempotencyStatus>
+    where
+        A: SessionStateInfo,
+    {
+        let redis_conn = state
+            .store()
+            .get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+        let redis_key = self.get_redis_key(merchant_id);
+
+        let setnx_reply = redis_conn
+            .serialize_and_set_key_if_not_exist(
+                &redis_key.as_str().into(),
+                IdempotencyRecord::InProgress {
+                    fingerprint: self.fingerprint.clone(),
+                },
+                Some(state.conf().idempotency.in_progress_ttl_in_seconds),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+        if setnx_reply == redis::SetnxReply::KeySet {
+            return match self.find_stored_record(state, merchant_id).await {
+                Ok(Some(record)) => self.get_status(record),
+                Ok(None) => Ok(IdempotencyStatus::New),
+                Err(error) => {
+                    self.release(state, merchant_id).await;
+                    Err(error)
+                }
+            };
+        }
+
+        let record = redis_conn
+            .get_and_deserialize_key::<IdempotencyRecord>(
+                &redis_key.as_str().into(),
+                "IdempotencyRecord",
+            )
+            .await
+            .map_err(|error| match error.current_context() {
+                // The record expired after the key was found to exist
+                redis::errors::RedisError::NotFound => {
+                    error.change_context(errors::ApiErrorResponse::IdempotentRequestInProgress)
+                }
+                _ => error.change_context(errors::ApiErrorResponse::InternalServerError),
+            })?;
+
+        self.get_status(record)
+    }
+
+    fn get_status(&self, record: IdempotencyRecord) -> RouterResult<IdempotencyStatus> {
+        if record.get_fingerprint() != self.fingerprint {
+            return Err(report!(errors::ApiErrorResponse::IdempotencyKeyReused));
+        }
+
+        match record {
+            IdempotencyRecord::InProgress { .. } => Err(report!(
+                errors::ApiErrorResponse::IdempotentRequestInProgress
+            )),
+            IdempotencyRecord::Completed { response, .. } => {
+                logger::info!(
+                    "Replaying response for idempotency key {}",
+                    self.idempotency_key
+                );
+                Ok(IdempotencyStatus::Replay(response))
+            }
+        }
+    }
+
+    /// Finds the response stored in the database for the key, caching it in redis in place of the
+    /// in progress record
+    async fn find_stored_record<A>(
+        &self,
+        state: &A,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> RouterResult<Option<IdempotencyRecord>>
+    where
+        A: SessionStateInfo,
+    {
+        let db = state.store();
+        let stored_key = match db
+            .find_idempotency_key_by_merchant_id_idempotency_key(merchant_id, &self.idempotency_key)
+            .await
+        {
+            Ok(stored_key) => stored_key,
+            Err(error) if error.current_context().is_db_not_found() => return Ok(None),
+            Err(error) => {
+                return Err(error.change_context(errors::ApiErrorResponse::InternalServerError))
+                    .attach_printable(
+                        "Failed to find the stored response of the idempotent request",
+                    )
+            }
+        };
+
+        let time_to_live = (stored_key.expires_at - date_time::now()).whole_seconds();
+        if time_to_live <= 0 {
+            db.delete_idempotency_key_by_merchant_id_idempotency_key(
+                merchant_id,
+                &self.idempotency_key,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to delete the expired response of the idempotent request")?;
+            return Ok(None);
+        }
+
+        let record = IdempotencyRecord::Completed {
+            fingerprint: stored_key.fingerprint,
+            response: stored_key
+                .response
+                .parse_value("StoredResponse")
+                .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        };
+
+        state
+            .store()
+            .get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?
+            .serialize_and_set_key_with_expiry(
+                &self.get_redis_key(merchant_id).as_str().into(),
+                &record,
+                time_to_live,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to cache the stored response of the idempotent request")?;
+
+        Ok(Some(record))
+    }
+
+    /// Stores the response sent for the request in the database and in redis
+    #[instrument(skip_all)]
+    pub async fn complete<A>(
+        &self,
+        state: &A,
+        merchant_id: &common_utils::id_type::MerchantId,
+        response: StoredResponse,
+    ) -> RouterResult<()>
+    where
+        A: SessionStateInfo,
+    {
+        let response_ttl = state.conf().idempotency.response_ttl_in_seconds;
+        let created_at = date_time::now();
+
+        let idempotency_key = storage::IdempotencyKeyNew {
+            merchant_id: merchant_id.to_owned(),
+            idempotency_key: self.idempotency_key.clone(),
+            fingerprint: self.fingerprint.clone(),
+            response: response
+                .encode_to_value()
+                .change_context(errors::ApiErrorResponse::InternalServerError)?,
+            created_at,
+            expires_at: created_at.saturating_add(time::Duration::seconds(response_ttl)),
+        };
+
+        state
+            .store()
+            .insert_idempotency_key(idempotency_key)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to store the response of the idempotent request")?;
+
+        state
+            .store()
+            .get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?
+            .serialize_and_set_key_with_expiry(
+                &self.get_redis_key(merchant_id).as_str().into(),
+                IdempotencyRecord::Completed {
+                    fingerprint: self.fingerprint.clone(),
+                    response,
+                },
+                response_ttl,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to cache the response of the idempotent request")
+    }
+
+    /// Releases the key of a request which was not processed, so that it can be retried
+    #[instrument(skip_all)]
+    pub async fn release<A>(&self, state: &A, merchant_id: &common_utils::id_type::MerchantId)
+    where
+        A: SessionStateInfo,
+    {
+        let release_result = match state.store().get_redis_conn() {
+            Ok(redis_conn) => redis_conn
+                .delete_key(&self.get_redis_key(merchant_id).as_str().into())
+                .await
+                .map(|_| ())
+                .change_context(errors::ApiErrorResponse::InternalServerError),
+            Err(error) => Err(error.change_context(errors::ApiErrorResponse::InternalServerError)),
+        };
+
+        if let Err(error) = release_result {
+            logger::error!(
+                ?error,
+                idempotency_key = %self.idempotency_key,
+                "Failed to release the idempotency key"
+            );
+        }
+    }
+}
+
+/// Request with an idempotency key which is being processed, whose response is stored once it is
+/// rendered
+pub struct IdempotentRequest {
+    input: IdempotencyInput,
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+}
+
+impl IdempotentRequest {
+    pub fn new(
+        input: IdempotencyInput,
+        state: SessionState,
+        merchant_id: common_utils::id_type::MerchantId,
+    ) -> Self {
+        Self {
+            input,
+            state,
+            merchant_id,
+        }
+    }
+
+    /// Stores the rendered response of the request and returns it to be sent. The key is released
+    /// if the request failed with a server error, and stays locked until the in progress record
+    /// expires if the response can not be stored.
+    pub async fn complete(self, response: HttpResponse) -> HttpResponse {
+        if response.status().is_server_error() {
+            self.input.release(&self.state, &self.merchant_id).await;
+            return response;
+        }
+
+        let (response, body) = response.into_parts();
+
+        let body = match body.try_into_bytes() {
+            Ok(body) => {
+                let stored_response =
+                    String::from_utf8(body.to_vec())
+                        .ok()
+                        .map(|stored_body| StoredResponse {
+                            status_code: response.status().as_u16(),
+                            headers: response
+                                .headers()
+                                .iter()
+                                .filter(|(name, _)| is_stored_response_header(name.as_str()))
+                                .filter_map(|(name, value)| {
+                                    value
+                                        .to_str()
+                                        .ok()
+                                        .map(|value| (name.to_string(), value.to_owned()))
+                                })
+                                .collect(),
+                            body: stored_body,
+                        });
+
+                match stored_response {
+                    Some(stored_response) => {
+                        if let Err(error) = self
+                            .input
+                            .complete(&self.state, &self.merchant_id, stored_response)
+                            .await
+                        {
+                            logger::error!(
+                                idempotency_error = ?error,
+                                idempotency_key = %self.input.idempotency_key
+                            );
+                        }
+                    }
+                    None => logger::warn!(
+                        idempotency_key = %self.input.idempotency_key,
+                        "Response of the idempotent request can not be stored"
+                    ),
+                }
+                BoxBody::new(body)
+            }
+            Err(body) => {
+                logger::warn!(
+                    idempotency_key = %self.input.idempotency_key,
+                    "Streamed response of the idempotent request can not be stored"
+                );
+                body
+            }
+        };
+
+        response.set_body(body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use std::sync::Arc;
+
+    use actix_web::test::TestRequest;
+
+    use super::*;
+    use crate::{configs::settings::Settings, db::StorageImpl, routes, services};
+
+    fn get_idempotency_input(idempotency_key: &str, amount: i64) -> IdempotencyInput {
+        let request = TestRequest::post()
+            .uri("/payments")
+            .insert_header((headers::IDEMPOTENCY_KEY, idempotency_key))
+            .to_http_request();
+
+        IdempotencyInput::from_request(&request, &serde_json::json!({ "amount": amount }))
+            .unwrap()
+            .expect("Idempotency input not built from the request")
+    }
+
+    fn get_stored_response() -> StoredResponse {
+        StoredResponse {
+            status_code: 400,
+            headers: vec![(
+                headers::CONNECTOR_HTTP_STATUS_CODE.to_string(),
+                "400".to_string(),
+            )],
+            body: r#"{"error":{"code":"IR_06"}}"#.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_from_request_without_idempotency_key() {
+        let request = TestRequest::post().uri("/payments").to_http_request();
+
+        let idempotency_input =
+            IdempotencyInput::from_request(&request, &serde_json::json!({ "amount": 100 }))
+                .unwrap();
+
+        assert!(idempotency_input.is_none());
+    }
+
+    #[test]
+    fn test_from_request_with_invalid_idempotency_key() {
+        for idempotency_key in [" ".to_string(), "a".repeat(IDEMPOTENCY_KEY_MAX_LENGTH + 1)] {
+            let request = TestRequest::post()
+                .uri("/payments")
+                .insert_header((headers::IDEMPOTENCY_KEY, idempotency_key))
+                .to_http_request();
+
+            let idempotency_input =
+                IdempotencyInput::from_request(&request, &serde_json::json!({ "amount": 100 }));
+
+            assert!(idempotency_input.is_err());
+        }
+    }
+
+    #[test]
+    fn test_fingerprint_depends_on_the_request() {
+        let idempotency_input = get_idempotency_input("key_1", 100);
+
+        assert_eq!(
+            idempotency_input.fingerprint,
+            get_idempotency_input("key_2", 100).fingerprint
+        );
+        assert_ne!(
+            idempotency_input.fingerprint,
+            get_idempotency_input("key_1", 200).fingerprint
+        );
+    }
+
+    #[test]
+    fn test_get_status() {
+        let idempotency_input = get_idempotency_input("key_1", 100);
+
+        let status = idempotency_input
+            .get_status(IdempotencyRecord::Completed {
+                fingerprint: idempotency_input.fingerprint.clone(),
+                response: get_stored_response(),
+            })
+            .unwrap();
+        assert!(
+            matches!(status, IdempotencyStatus::Replay(response) if response == get_stored_response())
+        );
+
+        let error = idempotency_input
+            .get_status(IdempotencyRecord::InProgress {
+                fingerprint: idempotency_input.fingerprint.clone(),
+            })
+            .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::IdempotentRequestInProgress
+        ));
+
+        let error = idempotency_input
+            .get_status(IdempotencyRecord::Completed {
+                fingerprint: get_idempotency_input("key_1", 200).fingerprint,
+                response: get_stored_response(),
+            })
+            .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::IdempotencyKeyReused
+        ));
+    }
+
+    #[test]
+    fn test_is_stored_response_header() {
+        assert!(is_stored_response_header("content-type"));
+        assert!(is_stored_response_header(
+            headers::CONNECTOR_HTTP_STATUS_CODE
+        ));
+        assert!(!is_stored_response_header("x-request-id"));
+        assert!(!is_stored_response_header("set-cookie"));
+    }
+
+    async fn get_session_state() -> SessionState {
+        let (tx, _) = tokio::sync::oneshot::channel();
+        let app_state = Box::pin(routes::AppState::with_storage(
+            Settings::default(),
+            StorageImpl::Mock,
+            tx,
+            Box::new(services::MockApiClient),
+        ))
+        .await;
+        Arc::new(app_state)
+            .get_session_state(
+                &common_utils::id_type::TenantId::try_from_string("public".to_string()).unwrap(),
+                None,
+                || {},
+            )
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_key_is_released_on_server_error() {
+        let state = &get_session_state().await;
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+        let idempotency_input = get_idempotency_input("key_2", 100);
+
+        let status = idempotency_input.begin(state, &merchant_id).await.unwrap();
+        assert!(matches!(status, IdempotencyStatus::New));
+
+        IdempotentRequest::new(
+            idempotency_input.clone(),
+            state.clone(),
+            merchant_id.clone(),
+        )
+        .complete(HttpResponse::InternalServerError().finish())
+        .await;
+
+        let status = idempotency_input.begin(state, &merchant_id).await.unwrap();
+        assert!(matches!(status, IdempotencyStatus::New));
+    }
+
+    #[tokio::test]
+    async fn test_stored_response_is_replayed_from_the_database() {
+        let state = &get_session_state().await;
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+        let idempotency_input = get_idempotency_input("key_1", 100);
+
+        let status = idempotency_input.begin(state, &merchant_id).await.unwrap();
+        assert!(matches!(status, IdempotencyStatus::New));
+
+        IdempotentRequest::new(
+            idempotency_input.clone(),
+            state.clone(),
+            merchant_id.clone(),
+        )
+        .complete(
+            HttpResponse::BadRequest()
+                .insert_header((headers::CONNECTOR_HTTP_STATUS_CODE, "400"))
+                .insert_header((headers::X_REQUEST_ID, "request_1"))
+                .body(get_stored_response().body),
+        )
+        .await;
+
+        // The response is found in the database once it is no longer in redis
+        state
+            .store
+            .get_redis_conn()
+            .unwrap()
+            .delete_key(
+                &idempotency_input
+                    .get_redis_key(&merchant_id)
+                    .as_str()
+                    .into(),
+            )
+            .await
+            .unwrap();
+
+        let status = idempotency_input.begin(state, &merchant_id).await.unwrap();
+        let IdempotencyStatus::Replay(response) = status else {
+            panic!("Stored response not replayed");
+        };
+        assert_eq!(response.status_code, 400);
+        assert_eq!(response.body, get_stored_response().body);
+        assert!(response.headers.contains(&(
+            headers::CONNECTOR_HTTP_STATUS_CODE.to_string(),
+            "400".to_string()
+        )));
+        assert!(!response
+            .headers
+            .iter()
+            .any(|(name, _)| name.eq_ignore_ascii_case(headers::X_REQUEST_ID)));
+    }
+}
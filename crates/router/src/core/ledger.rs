@@ -0,0 +1,898 @@
+use std::collections::HashSet;
+
+use api_models::payments as payment_types;
+use common_utils::{id_type, types::MinorUnit};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+use scheduler::utils as pt_utils;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, CustomResult, RouterResponse, StorageErrorExt},
+        metrics,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{
+        domain,
+        storage::{self, enums},
+    },
+};
+
+const CAPTURED_AMOUNT_BALANCE: &str = "captured_amount";
+const REFUNDED_AMOUNT_BALANCE: &str = "refunded_amount";
+const CHARGED_BACK_AMOUNT_BALANCE: &str = "charged_back_amount";
+const AUTHORIZED_AMOUNT_BALANCE: &str = "authorized_amount";
+
+const PAYMENT_LEDGER_TASK: &str = "PAYMENT_LEDGER";
+const PAYMENT_LEDGER_TAG: &str = "LEDGER";
+
+/// Delay before the ledger task records the entries which failed to be recorded
+pub const PAYMENT_LEDGER_RETRY_INTERVAL_IN_SECONDS: i64 = 60;
+
+/// An amount movement to be recorded in the ledger, identified by the attempt, capture, refund or
+/// dispute which caused it
+struct LedgerEntryDetails {
+    reference_id: String,
+    entry_type: enums::LedgerEntryType,
+    amount: MinorUnit,
+}
+
+/// Accounts between which an entry of the given type moves the amount, as `(debit, credit)`
+fn get_entry_accounts(
+    entry_type: enums::LedgerEntryType,
+) -> (enums::LedgerAccount, enums::LedgerAccount) {
+    match entry_type {
+        enums::LedgerEntryType::Authorize => (
+            enums::LedgerAccount::AuthorizationHold,
+            enums::LedgerAccount::Customer,
+        ),
+        enums::LedgerEntryType::Capture => (
+            enums::LedgerAccount::MerchantBalance,
+            enums::LedgerAccount::AuthorizationHold,
+        ),
+        enums::LedgerEntryType::Void | enums::LedgerEntryType::AuthorizationReversal => (
+            enums::LedgerAccount::Customer,
+            enums::LedgerAccount::AuthorizationHold,
+        ),
+        enums::LedgerEntryType::Refund => (
+            enums::LedgerAccount::Customer,
+            enums::LedgerAccount::MerchantBalance,
+        ),
+        enums::LedgerEntryType::Chargeback => (
+            enums::LedgerAccount::Chargeback,
+            enums::LedgerAccount::MerchantBalance,
+        ),
+        enums::LedgerEntryType::ChargebackReversal => (
+            enums::LedgerAccount::MerchantBalance,
+            enums::LedgerAccount::Chargeback,
+        ),
+    }
+}
+
+/// Entries releasing an authorization hold can only be recorded once the authorization is recorded
+fn is_authorization_required(entry_type: enums::LedgerEntryType) -> bool {
+    matches!(
+        entry_type,
+        enums::LedgerEntryType::Capture
+            | enums::LedgerEntryType::Void
+            | enums::LedgerEntryType::AuthorizationReversal
+    )
+}
+
+/// Balance of the account, the amounts moved to it less the amounts moved from it
+fn get_account_balance(
+    entries: &[storage::PaymentLedgerEntry],
+    account: enums::LedgerAccount,
+) -> MinorUnit {
+    entries.iter().fold(MinorUnit::zero(), |balance, entry| {
+        if entry.debit_account == account {
+            balance + entry.amount
+        } else if entry.credit_account == account {
+            balance - entry.amount
+        } else {
+            balance
+        }
+    })
+}
+
+fn get_entry_type_total(
+    entries: &[storage::PaymentLedgerEntry],
+    entry_type: enums::LedgerEntryType,
+) -> MinorUnit {
+    entries
+        .iter()
+        .filter(|entry| entry.entry_type == entry_type)
+        .map(|entry| entry.amount)
+        .sum()
+}
+
+fn get_ledger_balances(
+    entries: &[storage::PaymentLedgerEntry],
+) -> payment_types::PaymentLedgerBalances {
+    payment_types::PaymentLedgerBalances {
+        authorized_amount: get_account_balance(entries, enums::LedgerAccount::AuthorizationHold),
+        captured_amount: get_entry_type_total(entries, enums::LedgerEntryType::Capture),
+        refunded_amount: get_entry_type_total(entries, enums::LedgerEntryType::Refund),
+        charged_back_amount: get_account_balance(entries, enums::LedgerAccount::Chargeback),
+        net_settled_amount: get_account_balance(entries, enums::LedgerAccount::MerchantBalance),
+    }
+}
+
+/// A dispute withdraws its amount from the merchant once it moves past the pre-dispute stage, and
+/// returns it once the dispute is won or cancelled
+fn get_dispute_ledger_entry_types(
+    dispute_stage: enums::DisputeStage,
+    dispute_status: enums::DisputeStatus,
+) -> Vec<enums::LedgerEntryType> {
+    if dispute_stage == enums::DisputeStage::PreDispute {
+        return Vec::new();
+    }
+
+    match dispute_status {
+        enums::DisputeStatus::DisputeWon | enums::DisputeStatus::DisputeCancelled => vec![
+            enums::LedgerEntryType::Chargeback,
+            enums::LedgerEntryType::ChargebackReversal,
+        ],
+        enums::DisputeStatus::DisputeOpened
+        | enums::DisputeStatus::DisputeExpired
+        | enums::DisputeStatus::DisputeAccepted
+        | enums::DisputeStatus::DisputeChallenged
+        | enums::DisputeStatus::DisputeLost => vec![enums::LedgerEntryType::Chargeback],
+    }
+}
+
+/// Records the entries which are not recorded yet. Entries are immutable, so an entry is recorded
+/// at most once for every reference and entry type.
+async fn record_entries(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+    attempt_id: &str,
+    currency: enums::Currency,
+    entries: Vec<LedgerEntryDetails>,
+) -> CustomResult<(), errors::StorageError> {
+    if entries.is_empty() {
+        return Ok(());
+    }
+
+    let db = &*state.store;
+    let existing_entries = db
+        .find_payment_ledger_entries_by_merchant_id_payment_id(merchant_id, payment_id)
+        .await
+        .attach_printable("Failed to fetch the ledger entries of the payment")?;
+
+    let mut recorded_entries = existing_entries
+        .iter()
+        .map(|entry| (entry.reference_id.clone(), entry.entry_type))
+        .collect::<HashSet<_>>();
+    let is_authorization_recorded = existing_entries.iter().any(|entry| {
+        entry.attempt_id == attempt_id && entry.entry_type == enums::LedgerEntryType::Authorize
+    }) || entries
+        .iter()
+        .any(|entry| entry.entry_type == enums::LedgerEntryType::Authorize);
+
+    for entry in entries {
+        if entry.amount <= MinorUnit::zero()
+            || (is_authorization_required(entry.entry_type) && !is_authorization_recorded)
+            || !recorded_entries.insert((entry.reference_id.clone(), entry.entry_type))
+        {
+            continue;
+        }
+
+        let (debit_account, credit_account) = get_entry_accounts(entry.entry_type);
+        let ledger_entry = storage::PaymentLedgerEntryNew {
+            id: common_utils::generate_id(consts::ID_LENGTH, "ple"),
+            merchant_id: merchant_id.to_owned(),
+            payment_id: payment_id.to_owned(),
+            attempt_id: attempt_id.to_owned(),
+            reference_id: entry.reference_id,
+            entry_type: entry.entry_type,
+            debit_account,
+            credit_account,
+            amount: entry.amount,
+            currency,
+            created_at: common_utils::date_time::now(),
+        };
+
+        match db.insert_payment_ledger_entry(ledger_entry).await {
+            Ok(_) => (),
+            // The entry was recorded by a concurrent request
+            Err(error) if error.current_context().is_db_unique_violation() => {
+                logger::debug!(entry_type = ?entry.entry_type, "Ledger entry is already recorded");
+            }
+            Err(error) => {
+                return Err(error).attach_printable_lazy(|| {
+                    format!("Failed to record the {:?} ledger entry", entry.entry_type)
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The authorization, captures, void and authorization reversal of the payment attempt. The
+/// captures of an attempt with multiple captures are only known from the captures themselves.
+fn get_payment_entries(
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    captures: Option<Vec<&storage::Capture>>,
+) -> Vec<LedgerEntryDetails> {
+    let attempt_id = &payment_attempt.attempt_id;
+    let mut entries = Vec::new();
+
+    if matches!(
+        payment_attempt.status,
+        enums::AttemptStatus::Authorized
+            | enums::AttemptStatus::Charged
+            | enums::AttemptStatus::PartialCharged
+            | enums::AttemptStatus::PartialChargedAndChargeable
+            | enums::AttemptStatus::CaptureInitiated
+            | enums::AttemptStatus::CaptureFailed
+            | enums::AttemptStatus::VoidInitiated
+            | enums::AttemptStatus::VoidFailed
+    ) {
+        entries.push(LedgerEntryDetails {
+            reference_id: attempt_id.clone(),
+            entry_type: enums::LedgerEntryType::Authorize,
+            amount: payment_attempt.get_total_amount(),
+        });
+    }
+
+    // Only the active attempt of the payment can have been captured
+    let amount_captured = if payment_intent.active_attempt.get_id() == *attempt_id {
+        payment_intent.amount_captured.unwrap_or_default()
+    } else {
+        MinorUnit::zero()
+    };
+    match captures {
+        Some(captures) => entries.extend(
+            captures
+                .into_iter()
+                .filter(|capture| capture.status == enums::CaptureStatus::Charged)
+                .map(|capture| LedgerEntryDetails {
+                    reference_id: capture.capture_id.clone(),
+                    entry_type: enums::LedgerEntryType::Capture,
+                    amount: capture.amount,
+                }),
+        ),
+        None if payment_attempt.multiple_capture_count > Some(0) => (),
+        None => {
+            if matches!(
+                payment_attempt.status,
+                enums::AttemptStatus::Charged
+                    | enums::AttemptStatus::PartialCharged
+                    | enums::AttemptStatus::PartialChargedAndChargeable
+            ) {
+                entries.push(LedgerEntryDetails {
+                    reference_id: attempt_id.clone(),
+                    entry_type: enums::LedgerEntryType::Capture,
+                    amount: amount_captured,
+                });
+            }
+
+            // A partial capture of a single capture payment releases the uncaptured remainder
+            if payment_attempt.status == enums::AttemptStatus::PartialCharged {
+                entries.push(LedgerEntryDetails {
+                    reference_id: attempt_id.clone(),
+                    entry_type: enums::LedgerEntryType::AuthorizationReversal,
+                    amount: payment_attempt.get_total_amount() - amount_captured,
+                });
+            }
+        }
+    }
+
+    if payment_attempt.status == enums::AttemptStatus::Voided {
+        entries.push(LedgerEntryDetails {
+            reference_id: attempt_id.clone(),
+            entry_type: enums::LedgerEntryType::Void,
+            amount: payment_attempt.get_total_amount() - amount_captured,
+        });
+    }
+
+    if payment_attempt.authorization_reversal_status
+        == Some(enums::AuthorizationReversalStatus::Success)
+    {
+        entries.push(LedgerEntryDetails {
+            reference_id: attempt_id.clone(),
+            entry_type: enums::LedgerEntryType::AuthorizationReversal,
+            amount: payment_attempt.amount_reversed.unwrap_or_default(),
+        });
+    }
+
+    entries
+}
+
+async fn try_record_payment_entries(
+    state: &SessionState,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    captures: Option<Vec<&storage::Capture>>,
+) -> CustomResult<(), errors::StorageError> {
+    let Some(currency) = payment_attempt.currency.or(payment_intent.currency) else {
+        return Ok(());
+    };
+
+    record_entries(
+        state,
+        &payment_intent.merchant_id,
+        &payment_intent.payment_id,
+        &payment_attempt.attempt_id,
+        currency,
+        get_payment_entries(payment_intent, payment_attempt, captures),
+    )
+    .await
+}
+
+async fn try_record_refund_entry(
+    state: &SessionState,
+    refund: &storage::Refund,
+) -> CustomResult<(), errors::StorageError> {
+    if refund.refund_status != enums::RefundStatus::Success {
+        return Ok(());
+    }
+
+    record_entries(
+        state,
+        &refund.merchant_id,
+        &refund.payment_id,
+        &refund.attempt_id,
+        refund.currency,
+        vec![LedgerEntryDetails {
+            reference_id: refund.refund_id.clone(),
+            entry_type: enums::LedgerEntryType::Refund,
+            amount: refund.refund_amount,
+        }],
+    )
+    .await
+}
+
+async fn try_record_dispute_entries(
+    state: &SessionState,
+    dispute: &storage::Dispute,
+) -> CustomResult<(), errors::StorageError> {
+    // Recording the entries is not retried, as the currency would not be known on a retry either
+    let Some(currency) = dispute
+        .dispute_currency
+        .or_else(|| dispute.currency.parse().ok())
+    else {
+        logger::error!(
+            dispute_id = %dispute.dispute_id,
+            "Failed to determine the currency of the dispute for the ledger"
+        );
+        return Ok(());
+    };
+
+    let entries = get_dispute_ledger_entry_types(dispute.dispute_stage, dispute.dispute_status)
+        .into_iter()
+        .map(|entry_type| LedgerEntryDetails {
+            reference_id: dispute.dispute_id.clone(),
+            entry_type,
+            amount: dispute.dispute_amount,
+        })
+        .collect();
+
+    record_entries(
+        state,
+        &dispute.merchant_id,
+        &dispute.payment_id,
+        &dispute.attempt_id,
+        currency,
+        entries,
+    )
+    .await
+}
+
+/// The ledger never fails the flow which moved the amount. The entries which failed to be recorded
+/// are recorded by the ledger task of the payment instead, which records all the missing entries
+/// of the payment.
+async fn handle_recording_failure(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+    result: CustomResult<(), errors::StorageError>,
+) {
+    let Err(error) = result else {
+        return;
+    };
+
+    logger::error!(
+        ?error,
+        ?payment_id,
+        "Failed to record the ledger entries of the payment"
+    );
+    metrics::PAYMENT_LEDGER_RECORDING_FAILURE.add(
+        1,
+        router_env::metric_attributes!(("merchant_id", merchant_id.clone())),
+    );
+
+    add_payment_ledger_task(&*state.store, merchant_id, payment_id)
+        .await
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                ?payment_id,
+                "Failed to add the ledger task of the payment"
+            )
+        })
+        .ok();
+}
+
+/// Records the authorization, captures, void and authorization reversal of the payment attempt
+#[instrument(skip_all)]
+pub async fn record_payment_entries(
+    state: &SessionState,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    captures: Option<Vec<&storage::Capture>>,
+) {
+    let result = try_record_payment_entries(state, payment_intent, payment_attempt, captures).await;
+    handle_recording_failure(
+        state,
+        &payment_intent.merchant_id,
+        &payment_intent.payment_id,
+        result,
+    )
+    .await;
+}
+
+/// Records the refund once it succeeds
+#[instrument(skip_all)]
+pub async fn record_refund_entry(state: &SessionState, refund: &storage::Refund) {
+    let result = try_record_refund_entry(state, refund).await;
+    handle_recording_failure(state, &refund.merchant_id, &refund.payment_id, result).await;
+}
+
+/// Records the chargeback of the dispute, and its reversal once the dispute is won or cancelled
+#[instrument(skip_all)]
+pub async fn record_dispute_entries(state: &SessionState, dispute: &storage::Dispute) {
+    let result = try_record_dispute_entries(state, dispute).await;
+    handle_recording_failure(state, &dispute.merchant_id, &dispute.payment_id, result).await;
+}
+
+/// Adds the ledger task of the payment, rescheduling it if it has already finished
+pub async fn add_payment_ledger_task(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+) -> CustomResult<(), errors::StorageError> {
+    let runner = storage::ProcessTrackerRunner::PaymentLedgerWorkflow;
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        PAYMENT_LEDGER_TASK,
+        payment_id.get_string_repr(),
+        merchant_id,
+    );
+    let schedule_time = common_utils::date_time::now().saturating_add(time::Duration::seconds(
+        PAYMENT_LEDGER_RETRY_INTERVAL_IN_SECONDS,
+    ));
+
+    if let Some(process) = db.find_process_by_id(&process_tracker_id).await? {
+        return if process.status == enums::ProcessTrackerStatus::Finish {
+            db.as_scheduler()
+                .reset_process(process, schedule_time)
+                .await
+        } else {
+            Ok(())
+        };
+    }
+
+    let tracking_data = storage::PaymentLedgerTrackingData {
+        merchant_id: merchant_id.clone(),
+        payment_id: payment_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        PAYMENT_LEDGER_TASK,
+        runner,
+        [PAYMENT_LEDGER_TAG],
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .map(|_| ())
+        .or_else(|error| {
+            if error.current_context().is_db_unique_violation() {
+                Ok(())
+            } else {
+                Err(error)
+            }
+        })
+}
+
+/// Records the entries of the payment, its refunds and its disputes which are not recorded yet
+#[instrument(skip_all)]
+pub async fn record_missing_entries(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_id: &id_type::PaymentId,
+) -> CustomResult<(), errors::StorageError> {
+    let db = &*state.store;
+    let merchant_id = merchant_account.get_id();
+    let storage_scheme = merchant_account.storage_scheme;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &state.into(),
+            payment_id,
+            merchant_id,
+            key_store,
+            storage_scheme,
+        )
+        .await?;
+
+    // Earlier attempts of the payment may have been authorized and voided or reversed as well
+    let payment_attempts = db
+        .find_attempts_by_merchant_id_payment_id(merchant_id, payment_id, storage_scheme)
+        .await?;
+
+    for payment_attempt in &payment_attempts {
+        let captures = if payment_attempt.multiple_capture_count > Some(0) {
+            Some(
+                db.find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
+                    merchant_id,
+                    payment_id,
+                    &payment_attempt.attempt_id,
+                    storage_scheme,
+                )
+                .await?,
+            )
+        } else {
+            None
+        };
+
+        try_record_payment_entries(
+            state,
+            &payment_intent,
+            payment_attempt,
+            captures.as_ref().map(|captures| captures.iter().collect()),
+        )
+        .await?;
+    }
+
+    let refunds = db
+        .find_refund_by_payment_id_merchant_id(payment_id, merchant_id, storage_scheme)
+        .await?;
+    for refund in &refunds {
+        try_record_refund_entry(state, refund).await?;
+    }
+
+    let disputes = db
+        .find_disputes_by_merchant_id_payment_id(merchant_id, payment_id)
+        .await?;
+    for dispute in &disputes {
+        try_record_dispute_entries(state, dispute).await?;
+    }
+
+    Ok(())
+}
+
+fn get_balance_mismatch(
+    balance: &str,
+    ledger_amount: MinorUnit,
+    expected_amount: MinorUnit,
+) -> Option<payment_types::PaymentLedgerMismatch> {
+    (ledger_amount != expected_amount).then(|| payment_types::PaymentLedgerMismatch {
+        balance: balance.to_string(),
+        ledger_amount,
+        expected_amount,
+    })
+}
+
+/// Returns the ledger entries and balances of the payment, along with the balances which do not
+/// match the amounts recorded on the payment, its refunds and its disputes
+#[instrument(skip_all)]
+pub async fn retrieve_payment_ledger(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    payment_id: id_type::PaymentId,
+) -> RouterResponse<payment_types::PaymentLedgerResponse> {
+    let db = &*state.store;
+    let merchant_account = merchant_context.get_merchant_account();
+    let merchant_id = merchant_account.get_id();
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &(&state).into(),
+            &payment_id,
+            merchant_id,
+            merchant_context.get_merchant_key_store(),
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let payment_attempts = db
+        .find_attempts_by_merchant_id_payment_id(
+            merchant_id,
+            &payment_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the attempts of the payment")?;
+
+    let ledger_entries = db
+        .find_payment_ledger_entries_by_merchant_id_payment_id(merchant_id, &payment_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the ledger entries of the payment")?;
+
+    let refunds = db
+        .find_refund_by_payment_id_merchant_id(
+            &payment_id,
+            merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the refunds of the payment")?;
+
+    let disputes = db
+        .find_disputes_by_merchant_id_payment_id(merchant_id, &payment_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the disputes of the payment")?;
+
+    let balances = get_ledger_balances(&ledger_entries);
+
+    let expected_refunded_amount = refunds
+        .iter()
+        .filter(|refund| refund.refund_status == enums::RefundStatus::Success)
+        .map(|refund| refund.refund_amount)
+        .sum();
+    let expected_charged_back_amount = disputes
+        .iter()
+        .filter(|dispute| {
+            get_dispute_ledger_entry_types(dispute.dispute_stage, dispute.dispute_status).len() == 1
+        })
+        .map(|dispute| dispute.dispute_amount)
+        .sum();
+    let expected_authorized_amount = payment_attempts
+        .iter()
+        .filter(|payment_attempt| {
+            matches!(
+                payment_attempt.status,
+                enums::AttemptStatus::Authorized
+                    | enums::AttemptStatus::PartialChargedAndChargeable
+            )
+        })
+        .map(|payment_attempt| payment_attempt.amount_capturable)
+        .sum();
+
+    let mismatches = [
+        get_balance_mismatch(
+            CAPTURED_AMOUNT_BALANCE,
+            balances.captured_amount,
+            payment_intent.amount_captured.unwrap_or_default(),
+        ),
+        get_balance_mismatch(
+            REFUNDED_AMOUNT_BALANCE,
+            balances.refunded_amount,
+            expected_refunded_amount,
+        ),
+        get_balance_mismatch(
+            CHARGED_BACK_AMOUNT_BALANCE,
+            balances.charged_back_amount,
+            expected_charged_back_amount,
+        ),
+        get_balance_mismatch(
+            AUTHORIZED_AMOUNT_BALANCE,
+            balances.authorized_amount,
+            expected_authorized_amount,
+        ),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
+
+    if !mismatches.is_empty() {
+        logger::warn!(?mismatches, "Payment ledger does not match the payment");
+    }
+
+    let entries = ledger_entries
+        .into_iter()
+        .map(|entry| payment_types::PaymentLedgerEntryResponse {
+            entry_id: entry.id,
+            attempt_id: entry.attempt_id,
+            reference_id: entry.reference_id,
+            entry_type: entry.entry_type,
+            debit_account: entry.debit_account,
+            credit_account: entry.credit_account,
+            amount: entry.amount,
+            currency: entry.currency,
+            created_at: entry.created_at,
+        })
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        payment_types::PaymentLedgerResponse {
+            payment_id,
+            entries,
+            balances,
+            mismatches,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    const ALL_ENTRY_TYPES: [enums::LedgerEntryType; 7] = [
+        enums::LedgerEntryType::Authorize,
+        enums::LedgerEntryType::Capture,
+        enums::LedgerEntryType::Void,
+        enums::LedgerEntryType::AuthorizationReversal,
+        enums::LedgerEntryType::Refund,
+        enums::LedgerEntryType::Chargeback,
+        enums::LedgerEntryType::ChargebackReversal,
+    ];
+
+    fn ledger_entry(
+        reference_id: &str,
+        entry_type: enums::LedgerEntryType,
+        amount: i64,
+    ) -> storage::PaymentLedgerEntry {
+        let (debit_account, credit_account) = get_entry_accounts(entry_type);
+        storage::PaymentLedgerEntry {
+            id: format!("ple_{reference_id}_{entry_type}"),
+            merchant_id: id_type::MerchantId::try_from(std::borrow::Cow::from("merchant")).unwrap(),
+            payment_id: id_type::PaymentId::try_from(std::borrow::Cow::from("pay_ledger")).unwrap(),
+            attempt_id: "pay_ledger_1".to_string(),
+            reference_id: reference_id.to_string(),
+            entry_type,
+            debit_account,
+            credit_account,
+            amount: MinorUnit::new(amount),
+            currency: enums::Currency::USD,
+            created_at: common_utils::date_time::now(),
+        }
+    }
+
+    #[test]
+    fn test_get_entry_accounts() {
+        assert_eq!(
+            get_entry_accounts(enums::LedgerEntryType::Authorize),
+            (
+                enums::LedgerAccount::AuthorizationHold,
+                enums::LedgerAccount::Customer
+            )
+        );
+        assert_eq!(
+            get_entry_accounts(enums::LedgerEntryType::Capture),
+            (
+                enums::LedgerAccount::MerchantBalance,
+                enums::LedgerAccount::AuthorizationHold
+            )
+        );
+        assert_eq!(
+            get_entry_accounts(enums::LedgerEntryType::Refund),
+            (
+                enums::LedgerAccount::Customer,
+                enums::LedgerAccount::MerchantBalance
+            )
+        );
+
+        // Every entry moves the amount between two different accounts, and reversals move it back
+        for entry_type in ALL_ENTRY_TYPES {
+            let (debit_account, credit_account) = get_entry_accounts(entry_type);
+            assert_ne!(debit_account, credit_account, "{entry_type}");
+        }
+        assert_eq!(
+            get_entry_accounts(enums::LedgerEntryType::Void),
+            get_entry_accounts(enums::LedgerEntryType::AuthorizationReversal)
+        );
+        let (chargeback_debit, chargeback_credit) =
+            get_entry_accounts(enums::LedgerEntryType::Chargeback);
+        assert_eq!(
+            get_entry_accounts(enums::LedgerEntryType::ChargebackReversal),
+            (chargeback_credit, chargeback_debit)
+        );
+    }
+
+    #[test]
+    fn test_get_ledger_balances() {
+        let entries = vec![
+            ledger_entry("att_1", enums::LedgerEntryType::Authorize, 1000),
+            ledger_entry("cap_1", enums::LedgerEntryType::Capture, 300),
+            ledger_entry("cap_2", enums::LedgerEntryType::Capture, 400),
+            ledger_entry("ref_1", enums::LedgerEntryType::Refund, 100),
+            ledger_entry("dp_1", enums::LedgerEntryType::Chargeback, 200),
+            ledger_entry("dp_2", enums::LedgerEntryType::Chargeback, 50),
+            ledger_entry("dp_2", enums::LedgerEntryType::ChargebackReversal, 50),
+        ];
+
+        let balances = get_ledger_balances(&entries);
+
+        assert_eq!(balances.authorized_amount, MinorUnit::new(300));
+        assert_eq!(balances.captured_amount, MinorUnit::new(700));
+        assert_eq!(balances.refunded_amount, MinorUnit::new(100));
+        assert_eq!(balances.charged_back_amount, MinorUnit::new(200));
+        assert_eq!(balances.net_settled_amount, MinorUnit::new(400));
+    }
+
+    #[test]
+    fn test_get_ledger_balances_after_release() {
+        let voided_entries = vec![
+            ledger_entry("att_1", enums::LedgerEntryType::Authorize, 1000),
+            ledger_entry("att_1", enums::LedgerEntryType::Void, 1000),
+        ];
+        let balances = get_ledger_balances(&voided_entries);
+        assert_eq!(balances.authorized_amount, MinorUnit::zero());
+        assert_eq!(balances.captured_amount, MinorUnit::zero());
+        assert_eq!(balances.net_settled_amount, MinorUnit::zero());
+
+        let reversed_entries = vec![
+            ledger_entry("att_1", enums::LedgerEntryType::Authorize, 1000),
+            ledger_entry("att_1", enums::LedgerEntryType::Capture, 600),
+            ledger_entry("att_1", enums::LedgerEntryType::AuthorizationReversal, 400),
+        ];
+        let balances = get_ledger_balances(&reversed_entries);
+        assert_eq!(balances.authorized_amount, MinorUnit::zero());
+        assert_eq!(balances.captured_amount, MinorUnit::new(600));
+        assert_eq!(balances.net_settled_amount, MinorUnit::new(600));
+
+        let balances = get_ledger_balances(&[]);
+        assert_eq!(balances.authorized_amount, MinorUnit::zero());
+        assert_eq!(balances.net_settled_amount, MinorUnit::zero());
+    }
+
+    #[test]
+    fn test_get_dispute_ledger_entry_types() {
+        for dispute_status in [
+            enums::DisputeStatus::DisputeOpened,
+            enums::DisputeStatus::DisputeWon,
+            enums::DisputeStatus::DisputeLost,
+        ] {
+            assert!(
+                get_dispute_ledger_entry_types(enums::DisputeStage::PreDispute, dispute_status)
+                    .is_empty(),
+                "{dispute_status}"
+            );
+        }
+
+        for dispute_stage in [
+            enums::DisputeStage::Dispute,
+            enums::DisputeStage::PreArbitration,
+        ] {
+            for dispute_status in [
+                enums::DisputeStatus::DisputeOpened,
+                enums::DisputeStatus::DisputeExpired,
+                enums::DisputeStatus::DisputeAccepted,
+                enums::DisputeStatus::DisputeChallenged,
+                enums::DisputeStatus::DisputeLost,
+            ] {
+                assert_eq!(
+                    get_dispute_ledger_entry_types(dispute_stage, dispute_status),
+                    vec![enums::LedgerEntryType::Chargeback],
+                    "{dispute_stage} {dispute_status}"
+                );
+            }
+
+            for dispute_status in [
+                enums::DisputeStatus::DisputeWon,
+                enums::DisputeStatus::DisputeCancelled,
+            ] {
+                assert_eq!(
+                    get_dispute_ledger_entry_types(dispute_stage, dispute_status),
+                    vec![
+                        enums::LedgerEntryType::Chargeback,
+                        enums::LedgerEntryType::ChargebackReversal
+                    ],
+                    "{dispute_stage} {dispute_status}"
+                );
+            }
+        }
+    }
+}
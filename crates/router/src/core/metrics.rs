@@ -81,6 +81,54 @@ counter_metric!(
     ROUTING_RETRIEVE_CONFIG_FOR_PROFILE_SUCCESS_RESPONSE,
     GLOBAL_METER
 );
+counter_metric!(ROUTING_LINK_SHADOW_CONFIG, GLOBAL_METER);
+counter_metric!(ROUTING_LINK_SHADOW_CONFIG_SUCCESS_RESPONSE, GLOBAL_METER);
+counter_metric!(ROUTING_UNLINK_SHADOW_CONFIG, GLOBAL_METER);
+counter_metric!(ROUTING_UNLINK_SHADOW_CONFIG_SUCCESS_RESPONSE, GLOBAL_METER);
+counter_metric!(SHADOW_ROUTING_EVALUATION_COUNT, GLOBAL_METER);
+counter_metric!(ROUTING_RULE_HIT_COUNT, GLOBAL_METER); // No. of payments routed by each rule of the active advanced algorithm
+counter_metric!(ROUTING_SIMULATE_REQUEST_RECEIVED, GLOBAL_METER);
+counter_metric!(ROUTING_SIMULATE_SUCCESS_RESPONSE, GLOBAL_METER);
+counter_metric!(ROUTING_DSL_PARSE, GLOBAL_METER);
+counter_metric!(ROUTING_DSL_PARSE_SUCCESS_RESPONSE, GLOBAL_METER);
+counter_metric!(ROUTING_DSL_PRINT, GLOBAL_METER);
+counter_metric!(ROUTING_DSL_PRINT_SUCCESS_RESPONSE, GLOBAL_METER);
+counter_metric!(ROUTING_ROLLBACK_CONFIG, GLOBAL_METER);
+counter_metric!(ROUTING_ROLLBACK_CONFIG_SUCCESS_RESPONSE, GLOBAL_METER);
+counter_metric!(ROUTING_DIFF_CONFIG, GLOBAL_METER);
+counter_metric!(ROUTING_DIFF_CONFIG_SUCCESS_RESPONSE, GLOBAL_METER);
+counter_metric!(ROUTING_SCHEDULE_ACTIVATION, GLOBAL_METER);
+counter_metric!(ROUTING_SCHEDULE_ACTIVATION_SUCCESS_RESPONSE, GLOBAL_METER);
+counter_metric!(ROUTING_LIST_SCHEDULED_ACTIVATIONS, GLOBAL_METER);
+counter_metric!(
+    ROUTING_LIST_SCHEDULED_ACTIVATIONS_SUCCESS_RESPONSE,
+    GLOBAL_METER
+);
+counter_metric!(ROUTING_CANCEL_SCHEDULED_ACTIVATION, GLOBAL_METER);
+counter_metric!(
+    ROUTING_CANCEL_SCHEDULED_ACTIVATION_SUCCESS_RESPONSE,
+    GLOBAL_METER
+);
+counter_metric!(ROUTING_UPSERT_TEMPORAL_CONFIG, GLOBAL_METER);
+counter_metric!(
+    ROUTING_UPSERT_TEMPORAL_CONFIG_SUCCESS_RESPONSE,
+    GLOBAL_METER
+);
+counter_metric!(ROUTING_RETRIEVE_TEMPORAL_CONFIG, GLOBAL_METER);
+counter_metric!(
+    ROUTING_RETRIEVE_TEMPORAL_CONFIG_SUCCESS_RESPONSE,
+    GLOBAL_METER
+);
+counter_metric!(ROUTING_DELETE_TEMPORAL_CONFIG, GLOBAL_METER);
+counter_metric!(
+    ROUTING_DELETE_TEMPORAL_CONFIG_SUCCESS_RESPONSE,
+    GLOBAL_METER
+);
+counter_metric!(ROUTING_ELIGIBILITY_EXPLANATION, GLOBAL_METER);
+counter_metric!(
+    ROUTING_ELIGIBILITY_EXPLANATION_SUCCESS_RESPONSE,
+    GLOBAL_METER
+);
 counter_metric!(DYNAMIC_SUCCESS_BASED_ROUTING, GLOBAL_METER);
 counter_metric!(DYNAMIC_CONTRACT_BASED_ROUTING, GLOBAL_METER);
 
@@ -89,3 +137,5 @@ counter_metric!(PARTIAL_AUTH_FAILURE, GLOBAL_METER);
 
 counter_metric!(API_KEY_REQUEST_INITIATED, GLOBAL_METER);
 counter_metric!(API_KEY_REQUEST_COMPLETED, GLOBAL_METER);
+
+counter_metric!(PAYMENT_LEDGER_RECORDING_FAILURE, GLOBAL_METER);
@@ -205,6 +205,7 @@ pub async fn form_payment_link_data(
         payment_intent.status,
         &[
             storage_enums::IntentStatus::Cancelled,
+            storage_enums::IntentStatus::Expired,
             storage_enums::IntentStatus::Failed,
             storage_enums::IntentStatus::Processing,
             storage_enums::IntentStatus::RequiresCapture,
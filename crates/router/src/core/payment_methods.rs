@@ -1,4 +1,12 @@
+#[cfg(feature = "v1")]
+pub mod card_account_updater;
 pub mod cards;
+#[cfg(feature = "v2")]
+pub mod duplicates;
+#[cfg(feature = "v1")]
+pub mod export;
+#[cfg(feature = "v1")]
+pub mod network_token_lifecycle;
 pub mod network_tokenization;
 pub mod surcharge_decision_configs;
 #[cfg(feature = "v1")]
@@ -942,6 +950,35 @@ pub async fn create_payment_method_core(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Unable to parse Payment method billing address")?;
 
+    let payment_method_data = domain::PaymentMethodVaultingData::from(req.payment_method_data)
+        .populate_bin_details_for_payment_method(state)
+        .await;
+
+    // Payment methods saved in an external vault are not fingerprinted
+    let fingerprint_id = match profile.is_external_vault_enabled() {
+        true => None,
+        false => {
+            Some(duplicates::get_fingerprint_id(state, &payment_method_data, merchant_id).await?)
+        }
+    };
+
+    if let Some(fingerprint_id) = fingerprint_id.as_deref() {
+        if let Some(existing_payment_method) = duplicates::apply_duplicate_policy(
+            state,
+            merchant_context,
+            profile,
+            &customer_id,
+            fingerprint_id,
+        )
+        .await?
+        {
+            let resp =
+                pm_transforms::generate_payment_method_response(&existing_payment_method, &None)?;
+
+            return Ok((resp, existing_payment_method));
+        }
+    }
+
     let payment_method_id =
         id_type::GlobalPaymentMethodId::generate(&state.conf.cell_information.id)
             .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -960,17 +997,13 @@ pub async fn create_payment_method_core(
     .await
     .attach_printable("failed to add payment method to db")?;
 
-    let payment_method_data = domain::PaymentMethodVaultingData::from(req.payment_method_data)
-        .populate_bin_details_for_payment_method(state)
-        .await;
-
     let vaulting_result = vault_payment_method(
         state,
         &payment_method_data,
         merchant_context,
         profile,
         None,
-        &customer_id,
+        fingerprint_id,
     )
     .await;
 
@@ -1812,34 +1845,20 @@ pub async fn vault_payment_method_internal(
     pmd: &domain::PaymentMethodVaultingData,
     merchant_context: &domain::MerchantContext,
     existing_vault_id: Option<domain::VaultId>,
-    customer_id: &id_type::GlobalCustomerId,
+    fingerprint_id: Option<String>,
 ) -> RouterResult<pm_types::AddVaultResponse> {
-    let db = &*state.store;
-
-    // get fingerprint_id from vault
-    let fingerprint_id_from_vault =
-        vault::get_fingerprint_id_from_vault(state, pmd, customer_id.get_string_repr().to_owned())
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed to get fingerprint_id from vault")?;
-
-    // throw back error if payment method is duplicated
-    when(
-        db.find_payment_method_by_fingerprint_id(
-            &(state.into()),
-            merchant_context.get_merchant_key_store(),
-            &fingerprint_id_from_vault,
-        )
-        .await
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Failed to find payment method by fingerprint_id")
-        .inspect_err(|e| logger::error!("Vault Fingerprint_id error: {:?}", e))
-        .is_ok(),
-        || {
-            Err(report!(errors::ApiErrorResponse::DuplicatePaymentMethod)
-                .attach_printable("Cannot vault duplicate payment method"))
-        },
-    )?;
+    // get fingerprint_id from vault, unless it was already computed for the duplicate check
+    let fingerprint_id_from_vault = match fingerprint_id {
+        Some(fingerprint_id) => fingerprint_id,
+        None => {
+            duplicates::get_fingerprint_id(
+                state,
+                pmd,
+                merchant_context.get_merchant_account().get_id(),
+            )
+            .await?
+        }
+    };
 
     let mut resp_from_vault =
         vault::add_payment_method_to_vault(state, merchant_context, pmd, existing_vault_id)
@@ -1861,6 +1880,22 @@ pub async fn vault_payment_method_external(
     merchant_account: &domain::MerchantAccount,
     merchant_connector_account: payment_helpers::MerchantConnectorAccountType,
 ) -> RouterResult<pm_types::AddVaultResponse> {
+    if state.conf.locker.mock_locker {
+        let data = serde_json::to_value(pmd)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encode the payment method to be vaulted")?;
+
+        return vault::external_adapter::add_vaulting_data(
+            &vault::external_adapter::get_mock_locker_vault_adapter(state),
+            merchant_account.get_id(),
+            None,
+            data,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to vault payment method in the mock locker");
+    }
+
     let router_data = core_utils::construct_vault_router_data(
         state,
         merchant_account,
@@ -1948,7 +1983,7 @@ pub async fn vault_payment_method(
     merchant_context: &domain::MerchantContext,
     profile: &domain::Profile,
     existing_vault_id: Option<domain::VaultId>,
-    customer_id: &id_type::GlobalCustomerId,
+    fingerprint_id: Option<String>,
 ) -> RouterResult<pm_types::AddVaultResponse> {
     let is_external_vault_enabled = profile.is_external_vault_enabled();
 
@@ -1990,7 +2025,7 @@ pub async fn vault_payment_method(
                 pmd,
                 merchant_context,
                 existing_vault_id,
-                customer_id,
+                fingerprint_id,
             )
             .await
         }
@@ -2377,7 +2412,7 @@ pub async fn update_payment_method_core(
                     // using current vault_id for now,
                     // will have to refactor this to generate new one on each vaulting later on
                     current_vault_id,
-                    &payment_method.customer_id,
+                    None,
                 )
                 .await
                 .attach_printable("Failed to add payment method in vault")?,
@@ -0,0 +1,602 @@
+//! Card account updater for stored cards
+//!
+//! Stored cards are enrolled for an update with the card account updater when they are about to
+//! expire, or when a payment with them is declined due to an issue with the card. Expiring cards
+//! are found by a payment made with them, and by the card expiry scan workflow which scans the
+//! stored cards of every merchant periodically. The enrolled cards are queued in redis per
+//! merchant, and are sent to the card account updater in batches by the card account updater
+//! workflow, which applies the updates to the stored cards and notifies the merchant with a
+//! payment method updated webhook. A batch is removed from the queue only after it is processed,
+//! so that the cards of a batch which was interrupted are sent again.
+
+use std::str::FromStr;
+
+use ::payment_methods::controller::PaymentMethodsController;
+use api_models::{
+    payment_methods::{Card, CardDetailsPaymentMethod, PaymentMethodsData},
+    webhooks,
+};
+use common_utils::{
+    date_time,
+    ext_traits::{AsyncExt, Encode, ValueExt},
+    id_type,
+};
+use error_stack::ResultExt;
+use hyperswitch_interfaces::card_account_updater::{
+    CardAccountUpdateResponse, CardAccountUpdateStatus,
+};
+use masking::{ExposeInterface, PeekInterface, Secret};
+use redis_interface as redis;
+use router_env::{instrument, logger, tracing};
+use scheduler::utils as pt_utils;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, CustomResult, RouterResult},
+        payment_methods::{cards, vault},
+        webhooks as webhooks_core,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    types::{
+        api, domain,
+        storage::{self, enums},
+    },
+};
+
+pub const CARD_ACCOUNT_UPDATER_TASK: &str = "CARD_ACCOUNT_UPDATER";
+pub const CARD_ACCOUNT_UPDATER_TAG: &str = "CARD_ACCOUNT_UPDATER";
+
+pub const CARD_EXPIRY_SCAN_TASK: &str = "CARD_EXPIRY_SCAN";
+pub const CARD_EXPIRY_SCAN_TAG: &str = "CARD_ACCOUNT_UPDATER";
+
+/// Time for which a card is not enrolled again after being enrolled for an update, so that a card
+/// is not sent to the card account updater for every payment made with it
+const ENROLLMENT_EXPIRY_IN_SECONDS: i64 = 7 * 24 * 60 * 60;
+
+/// Reason for which a stored card is enrolled for an update
+#[derive(Clone, Copy, Debug, serde::Serialize, serde::Deserialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CardAccountUpdateReason {
+    /// The card expires within the configured expiry window
+    Expiring,
+
+    /// A payment with the card was declined due to an issue with the card
+    Declined,
+}
+
+/// Stored card queued for an update with the card account updater
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct CardAccountUpdaterEnrollment {
+    pub payment_method_id: String,
+
+    /// Profile to which the webhook of the update of the card is sent, which is the profile of the
+    /// payment for which the card was enrolled, or the default profile of the merchant for cards
+    /// enrolled by the expiry scan. The merchant is not notified if there is no such profile.
+    pub profile_id: Option<id_type::ProfileId>,
+
+    pub reason: CardAccountUpdateReason,
+}
+
+pub fn get_card_account_updater_queue_key(merchant_id: &id_type::MerchantId) -> String {
+    format!(
+        "card_account_updater_queue_{}",
+        merchant_id.get_string_repr()
+    )
+}
+
+fn get_card_account_updater_enrollment_key(
+    merchant_id: &id_type::MerchantId,
+    payment_method_id: &str,
+) -> String {
+    format!(
+        "card_account_updater_enrollment_{}_{payment_method_id}",
+        merchant_id.get_string_repr()
+    )
+}
+
+pub fn get_card_account_updater_process_tracker_id(merchant_id: &id_type::MerchantId) -> String {
+    pt_utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::CardAccountUpdaterWorkflow,
+        CARD_ACCOUNT_UPDATER_TASK,
+        merchant_id.get_string_repr(),
+        merchant_id,
+    )
+}
+
+pub fn get_card_expiry_scan_process_tracker_id(merchant_id: &id_type::MerchantId) -> String {
+    pt_utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::CardExpiryScanWorkflow,
+        CARD_EXPIRY_SCAN_TASK,
+        merchant_id.get_string_repr(),
+        merchant_id,
+    )
+}
+
+/// Returns the card details stored in the payment method data of a card payment method
+pub fn get_card_details_from_payment_method(
+    payment_method: &domain::PaymentMethod,
+) -> Option<CardDetailsPaymentMethod> {
+    payment_method
+        .payment_method_data
+        .clone()
+        .map(|payment_method_data| payment_method_data.into_inner().expose())
+        .and_then(|value| {
+            value
+                .parse_value::<PaymentMethodsData>("PaymentMethodsData")
+                .map_err(|error| {
+                    logger::error!(?error, "Failed to deserialize payment method data")
+                })
+                .ok()
+        })
+        .and_then(|payment_method_data| match payment_method_data {
+            PaymentMethodsData::Card(card) => Some(card),
+            PaymentMethodsData::BankDetails(_) | PaymentMethodsData::WalletDetails(_) => None,
+        })
+}
+
+/// Returns the last day on which a card with the given expiry can be used
+fn get_card_expiry_date(
+    card_exp_month: &Secret<String>,
+    card_exp_year: &Secret<String>,
+) -> Option<time::Date> {
+    let month = card_exp_month.peek().trim().parse::<u8>().ok()?;
+    let month = time::Month::try_from(month).ok()?;
+    let year = card_exp_year.peek().trim().parse::<i32>().ok()?;
+    let year = if year < 100 { year + 2000 } else { year };
+
+    time::Date::from_calendar_date(year, month, time::util::days_in_year_month(year, month)).ok()
+}
+
+fn is_card_expiring(card: &CardDetailsPaymentMethod, expiry_window_in_days: i64) -> bool {
+    let expiry_window_end = date_time::now()
+        .date()
+        .saturating_add(time::Duration::days(expiry_window_in_days));
+
+    card.expiry_month
+        .as_ref()
+        .zip(card.expiry_year.as_ref())
+        .and_then(|(card_exp_month, card_exp_year)| {
+            get_card_expiry_date(card_exp_month, card_exp_year)
+        })
+        .is_some_and(|expiry_date| expiry_date <= expiry_window_end)
+}
+
+/// Determines whether the stored card used for a payment has to be enrolled for an update, based
+/// on the outcome of the payment
+pub fn get_card_account_update_reason(
+    state: &SessionState,
+    payment_method: &domain::PaymentMethod,
+    attempt_status: enums::AttemptStatus,
+    error_category: Option<enums::ErrorCategory>,
+) -> Option<CardAccountUpdateReason> {
+    if !is_card_account_updater_applicable(state, payment_method) {
+        return None;
+    }
+
+    match attempt_status {
+        enums::AttemptStatus::Failure => (error_category
+            == Some(enums::ErrorCategory::IssueWithPaymentMethod))
+        .then_some(CardAccountUpdateReason::Declined),
+        enums::AttemptStatus::Charged | enums::AttemptStatus::Authorized => {
+            is_stored_card_expiring(state, payment_method)
+                .then_some(CardAccountUpdateReason::Expiring)
+        }
+        _ => None,
+    }
+}
+
+/// Whether the payment method is an active card which can be updated by the card account updater
+fn is_card_account_updater_applicable(
+    state: &SessionState,
+    payment_method: &domain::PaymentMethod,
+) -> bool {
+    state.conf.card_account_updater.provider.is_enabled()
+        && payment_method.get_payment_method_type() == Some(enums::PaymentMethod::Card)
+        && payment_method.status == enums::PaymentMethodStatus::Active
+}
+
+fn is_stored_card_expiring(state: &SessionState, payment_method: &domain::PaymentMethod) -> bool {
+    get_card_details_from_payment_method(payment_method).is_some_and(|card| {
+        is_card_expiring(&card, state.conf.card_account_updater.expiry_window_in_days)
+    })
+}
+
+/// Enrolls the stored cards among the payment methods which are about to expire for an update,
+/// notifying the profile for the updates of the cards
+pub async fn enroll_expiring_cards_for_card_account_update(
+    state: &SessionState,
+    payment_methods: &[domain::PaymentMethod],
+    profile_id: Option<&id_type::ProfileId>,
+) {
+    for payment_method in payment_methods.iter().filter(|payment_method| {
+        is_card_account_updater_applicable(state, payment_method)
+            && is_stored_card_expiring(state, payment_method)
+    }) {
+        enroll_payment_method_for_card_account_update(
+            state,
+            payment_method,
+            profile_id,
+            CardAccountUpdateReason::Expiring,
+        )
+        .await
+        .map_err(|error| {
+            logger::error!(
+                payment_method_id = %payment_method.payment_method_id,
+                ?error,
+                "Failed to enroll the expiring card for the card account update"
+            )
+        })
+        .ok();
+    }
+}
+
+/// Queues the stored card for an update with the card account updater, and schedules the card
+/// account updater task of the merchant to process the queue
+#[instrument(skip_all)]
+pub async fn enroll_payment_method_for_card_account_update(
+    state: &SessionState,
+    payment_method: &domain::PaymentMethod,
+    profile_id: Option<&id_type::ProfileId>,
+    reason: CardAccountUpdateReason,
+) -> RouterResult<()> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let enrollment_key = get_card_account_updater_enrollment_key(
+        &payment_method.merchant_id,
+        &payment_method.payment_method_id,
+    );
+    let setnx_reply = redis_conn
+        .set_key_if_not_exists_with_expiry(
+            &enrollment_key.as_str().into(),
+            reason.to_string(),
+            Some(ENROLLMENT_EXPIRY_IN_SECONDS),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to set the card account updater enrollment key in redis")?;
+
+    // The card was enrolled recently, and is either queued or was already sent to the updater
+    if setnx_reply != redis::SetnxReply::KeySet {
+        return Ok(());
+    }
+
+    let enrollment = CardAccountUpdaterEnrollment {
+        payment_method_id: payment_method.payment_method_id.clone(),
+        profile_id: profile_id.cloned(),
+        reason,
+    }
+    .encode_to_string_of_json()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to serialize card account updater enrollment")?;
+
+    if let Err(error) = redis_conn
+        .append_elements_to_list(
+            &get_card_account_updater_queue_key(&payment_method.merchant_id)
+                .as_str()
+                .into(),
+            vec![enrollment],
+        )
+        .await
+    {
+        // The enrollment key is removed, so that the card can be enrolled again rather than being
+        // skipped until the key expires
+        redis_conn
+            .delete_key(&enrollment_key.as_str().into())
+            .await
+            .map_err(|error| {
+                logger::error!(
+                    ?error,
+                    "Failed to delete the card account updater enrollment key"
+                )
+            })
+            .ok();
+
+        return Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to queue the card for the card account updater"));
+    }
+
+    let schedule_time = date_time::now().saturating_add(time::Duration::seconds(
+        state.conf.card_account_updater.batch_interval_in_seconds,
+    ));
+
+    add_card_account_updater_task(&*state.store, &payment_method.merchant_id, schedule_time)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to add the card account updater task to process tracker")
+}
+
+/// Schedules the card account updater task of the merchant, unless the task is already scheduled
+pub async fn add_card_account_updater_task(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    schedule_time: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::StorageError> {
+    let process_tracker_id = get_card_account_updater_process_tracker_id(merchant_id);
+
+    if let Some(process) = db.find_process_by_id(&process_tracker_id).await? {
+        return if process.status == enums::ProcessTrackerStatus::Finish {
+            db.as_scheduler()
+                .reset_process(process, schedule_time)
+                .await
+        } else {
+            Ok(())
+        };
+    }
+
+    let tracking_data = storage::CardAccountUpdaterTrackingData {
+        merchant_id: merchant_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        CARD_ACCOUNT_UPDATER_TASK,
+        storage::ProcessTrackerRunner::CardAccountUpdaterWorkflow,
+        [CARD_ACCOUNT_UPDATER_TAG],
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    // The task may have been added by a concurrent enrollment of another card
+    db.insert_process(process_tracker_entry)
+        .await
+        .map(|_| ())
+        .or_else(|error| {
+            if error.current_context().is_db_unique_violation() {
+                Ok(())
+            } else {
+                Err(error)
+            }
+        })
+}
+
+/// Schedules the periodic scan of the stored cards of the merchant for expiring cards when a card
+/// is saved, unless the scan is already scheduled
+#[instrument(skip_all)]
+pub async fn schedule_card_expiry_scan(
+    state: &SessionState,
+    payment_method: &domain::PaymentMethod,
+) {
+    if !is_card_account_updater_applicable(state, payment_method) {
+        return;
+    }
+
+    add_card_expiry_scan_task(&*state.store, &payment_method.merchant_id)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to schedule the card expiry scan"))
+        .ok();
+}
+
+/// Adds the card expiry scan task of the merchant, which reschedules itself after every scan
+pub async fn add_card_expiry_scan_task(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+) -> CustomResult<(), errors::StorageError> {
+    let process_tracker_id = get_card_expiry_scan_process_tracker_id(merchant_id);
+
+    // The scan is finished only while the card account updater is disabled
+    if let Some(process) = db.find_process_by_id(&process_tracker_id).await? {
+        return if process.status == enums::ProcessTrackerStatus::Finish {
+            db.as_scheduler()
+                .reset_process(process, date_time::now())
+                .await
+        } else {
+            Ok(())
+        };
+    }
+
+    let tracking_data = storage::CardExpiryScanTrackingData {
+        merchant_id: merchant_id.clone(),
+        scan_started_at: None,
+        cursor: None,
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        CARD_EXPIRY_SCAN_TASK,
+        storage::ProcessTrackerRunner::CardExpiryScanWorkflow,
+        [CARD_EXPIRY_SCAN_TAG],
+        tracking_data,
+        None,
+        date_time::now(),
+        common_types::consts::API_VERSION,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .map(|_| ())
+        .or_else(|error| {
+            if error.current_context().is_db_unique_violation() {
+                Ok(())
+            } else {
+                Err(error)
+            }
+        })
+}
+
+/// Identifier of the object of the webhook notifying an update of a stored card, unique for each
+/// update so that the events of the updates of a card are not considered duplicates of each other
+fn get_card_account_update_object_id(payment_method_id: &str) -> String {
+    common_utils::generate_id(consts::ID_LENGTH, payment_method_id)
+}
+
+/// Applies the update of a stored card reported by the card account updater to the payment method
+/// and the card stored in the locker, and notifies the merchant about the update through the
+/// profile, if any
+#[instrument(skip_all)]
+pub async fn apply_card_account_update(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    business_profile: Option<&domain::Profile>,
+    payment_method: domain::PaymentMethod,
+    card: Card,
+    update: CardAccountUpdateResponse,
+) -> RouterResult<()> {
+    let key_manager_state = &state.into();
+    let payment_method_id = payment_method.payment_method_id.clone();
+    let created_at = payment_method.created_at;
+
+    let mut payment_method = payment_method;
+    let payment_method_update = match update.status {
+        CardAccountUpdateStatus::NewExpiry | CardAccountUpdateStatus::NewAccountNumber => {
+            let existing_card = api::CardDetail {
+                card_number: card.card_number.clone(),
+                card_exp_month: card.card_exp_month.clone(),
+                card_exp_year: card.card_exp_year.clone(),
+                card_holder_name: card.name_on_card.clone(),
+                nick_name: card.nick_name.clone().map(Secret::new),
+                card_issuing_country: None,
+                card_network: None,
+                card_issuer: None,
+                card_type: None,
+            };
+
+            let card_number = update
+                .card_number
+                .map(|card_number| ::cards::CardNumber::from_str(card_number.peek()))
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Invalid card number received from the card account updater")?
+                .unwrap_or(card.card_number);
+
+            let updated_card = api::CardDetail {
+                card_number,
+                card_exp_month: update.card_exp_month.unwrap_or(card.card_exp_month),
+                card_exp_year: update.card_exp_year.unwrap_or(card.card_exp_year),
+                card_holder_name: card.name_on_card,
+                nick_name: card.nick_name.map(Secret::new),
+                card_issuing_country: None,
+                card_network: None,
+                card_issuer: None,
+                card_type: None,
+            };
+
+            payment_method = vault::replace_card_in_locker(
+                state,
+                merchant_context,
+                payment_method,
+                existing_card,
+                updated_card.clone(),
+            )
+            .await?;
+
+            let payment_method_data = get_card_details_from_payment_method(&payment_method)
+                .map(|card_details| {
+                    PaymentMethodsData::Card(CardDetailsPaymentMethod {
+                        last4_digits: Some(updated_card.card_number.get_last4()),
+                        card_isin: Some(updated_card.card_number.get_card_isin()),
+                        expiry_month: Some(updated_card.card_exp_month.clone()),
+                        expiry_year: Some(updated_card.card_exp_year.clone()),
+                        ..card_details
+                    })
+                })
+                .async_map(|payment_method_data| {
+                    cards::create_encrypted_data(
+                        key_manager_state,
+                        merchant_context.get_merchant_key_store(),
+                        payment_method_data,
+                    )
+                })
+                .await
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Unable to encrypt payment method data")?;
+
+            storage::PaymentMethodUpdate::PaymentMethodDataUpdate {
+                payment_method_data: payment_method_data.map(Into::into),
+            }
+        }
+        CardAccountUpdateStatus::AccountClosed => storage::PaymentMethodUpdate::StatusUpdate {
+            status: Some(enums::PaymentMethodStatus::Inactive),
+        },
+        CardAccountUpdateStatus::ContactCardholder | CardAccountUpdateStatus::NoUpdate => {
+            logger::info!(
+                %payment_method_id,
+                status = %update.status,
+                "No update of the card received from the card account updater"
+            );
+            return Ok(());
+        }
+    };
+
+    state
+        .store
+        .update_payment_method(
+            key_manager_state,
+            merchant_context.get_merchant_key_store(),
+            payment_method,
+            payment_method_update,
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update payment method in db")?;
+
+    let payment_method_response = cards::PmCards {
+        state,
+        merchant_context,
+    }
+    .retrieve_payment_method(api::PaymentMethodId {
+        payment_method_id: payment_method_id.clone(),
+    })
+    .await?
+    .get_json_body()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to retrieve the updated payment method")?;
+
+    let Some(business_profile) = business_profile else {
+        logger::info!(
+            %payment_method_id,
+            "Not notifying the update of the card as there is no profile to notify"
+        );
+        return Ok(());
+    };
+
+    Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_context.clone(),
+        business_profile.clone(),
+        enums::EventType::PaymentMethodUpdated,
+        enums::EventClass::PaymentMethods,
+        get_card_account_update_object_id(&payment_method_id),
+        enums::EventObjectType::PaymentMethodDetails,
+        webhooks::OutgoingWebhookContent::PaymentMethodDetails(Box::new(payment_method_response)),
+        Some(created_at),
+    ))
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::webhooks::utils as webhooks_utils;
+
+    #[test]
+    fn test_updates_of_a_card_have_distinct_idempotent_event_ids() {
+        let payment_method_id = "pm_card_account_update";
+        let idempotent_event_ids = [
+            get_card_account_update_object_id(payment_method_id),
+            get_card_account_update_object_id(payment_method_id),
+        ]
+        .map(|primary_object_id| {
+            assert!(primary_object_id.starts_with(payment_method_id));
+            webhooks_utils::get_idempotent_event_id(
+                &primary_object_id,
+                enums::EventType::PaymentMethodUpdated,
+                enums::WebhookDeliveryAttempt::InitialAttempt,
+            )
+        });
+
+        let [first_update_event_id, second_update_event_id] = idempotent_event_ids;
+        assert_ne!(first_update_event_id, second_update_event_id);
+    }
+}
@@ -65,8 +65,8 @@ use super::surcharge_decision_configs::{
 use super::tokenize::NetworkTokenizationProcess;
 #[cfg(feature = "v1")]
 use crate::core::payment_methods::{
-    add_payment_method_status_update_task, tokenize,
-    utils::{get_merchant_pm_filter_graph, make_pm_graph, refresh_pm_filters_cache},
+    add_payment_method_status_update_task, card_account_updater, network_token_lifecycle, tokenize,
+    utils::get_or_build_pm_filter_graph,
 };
 #[cfg(feature = "v1")]
 use crate::routes::app::SessionStateInfo;
@@ -149,6 +149,9 @@ impl PaymentMethodsController for PmCards<'_> {
         );
 
         let current_time = common_utils::date_time::now();
+        let network_token_status = network_token_requestor_reference_id
+            .as_ref()
+            .map(|_| enums::NetworkTokenStatus::Active);
 
         let response = db
             .insert_payment_method(
@@ -189,6 +192,7 @@ impl PaymentMethodsController for PmCards<'_> {
                     network_token_requestor_reference_id,
                     network_token_locker_id,
                     network_token_payment_method_data,
+                    network_token_status,
                 },
                 self.merchant_context.get_merchant_account().storage_scheme,
             )
@@ -196,6 +200,9 @@ impl PaymentMethodsController for PmCards<'_> {
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Failed to add payment method in db")?;
 
+        network_token_lifecycle::schedule_network_token_status_check(self.state, &response).await;
+        card_account_updater::schedule_card_expiry_scan(self.state, &response).await;
+
         if customer.default_payment_method_id.is_none() && req.payment_method.is_some() {
             let _ = self
                 .set_default_payment_method(merchant_id, customer_id, payment_method_id.to_owned())
@@ -423,19 +430,26 @@ impl PaymentMethodsController for PmCards<'_> {
                         pm_id
                     ))?;
 
-                db.update_payment_method(
-                    &self.state.into(),
-                    key_store,
-                    existing_pm,
-                    pm_update,
-                    self.merchant_context.get_merchant_account().storage_scheme,
+                let updated_pm = db
+                    .update_payment_method(
+                        &self.state.into(),
+                        key_store,
+                        existing_pm,
+                        pm_update,
+                        self.merchant_context.get_merchant_account().storage_scheme,
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable(format!(
+                        "Failed to update payment method for existing pm_id: {:?} in db",
+                        pm_id
+                    ))?;
+
+                network_token_lifecycle::schedule_network_token_status_check(
+                    self.state,
+                    &updated_pm,
                 )
-                .await
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable(format!(
-                    "Failed to update payment method for existing pm_id: {:?} in db",
-                    pm_id
-                ))?;
+                .await;
 
                 logger::debug!("Network token added to locker and payment method updated");
                 Ok(true)
@@ -2708,94 +2722,33 @@ pub async fn list_payment_methods(
     logger::debug!(mca_before_filtering=?filtered_mcas);
 
     let mut response: Vec<ResponsePaymentMethodIntermediate> = vec![];
-    // Key creation for storing PM_FILTER_CGRAPH
-    let key = {
-        format!(
-            "pm_filters_cgraph_{}_{}",
-            merchant_context
-                .get_merchant_account()
-                .get_id()
-                .get_string_repr(),
-            profile_id.get_string_repr()
-        )
-    };
-
-    if let Some(graph) = get_merchant_pm_filter_graph(&state, &key).await {
-        // Derivation of PM_FILTER_CGRAPH from MokaCache successful
-        for mca in &filtered_mcas {
-            let payment_methods = match &mca.payment_methods_enabled {
-                Some(pm) => pm,
-                None => continue,
-            };
-            filter_payment_methods(
-                &graph,
-                mca.get_id(),
-                payment_methods,
-                &mut req,
-                &mut response,
-                payment_intent.as_ref(),
-                payment_attempt.as_ref(),
-                billing_address.as_ref(),
-                mca.connector_name.clone(),
-                &state.conf,
-            )
-            .await?;
-        }
-    } else {
-        // No PM_FILTER_CGRAPH Cache present in MokaCache
-        let mut builder = cgraph::ConstraintGraphBuilder::new();
-        for mca in &filtered_mcas {
-            let domain_id = builder.make_domain(
-                mca.get_id().get_string_repr().to_string(),
-                mca.connector_name.as_str(),
-            );
-
-            let Ok(domain_id) = domain_id else {
-                logger::error!("Failed to construct domain for list payment methods");
-                return Err(errors::ApiErrorResponse::InternalServerError.into());
-            };
-
-            let payment_methods = match &mca.payment_methods_enabled {
-                Some(pm) => pm,
-                None => continue,
-            };
-            if let Err(e) = make_pm_graph(
-                &mut builder,
-                domain_id,
-                payment_methods,
-                mca.connector_name.clone(),
-                pm_config_mapping,
-                &state.conf.mandates.supported_payment_methods,
-                &state.conf.mandates.update_mandate_supported,
-            ) {
-                logger::error!(
-                    "Failed to construct constraint graph for list payment methods {e:?}"
-                );
-            }
-        }
-
-        // Refreshing our CGraph cache
-        let graph = refresh_pm_filters_cache(&state, &key, builder.build()).await;
+    let graph = get_or_build_pm_filter_graph(
+        &state,
+        merchant_context.get_merchant_account().get_id(),
+        profile_id,
+        &filtered_mcas,
+        pm_config_mapping,
+    )
+    .await?;
 
-        for mca in &filtered_mcas {
-            let payment_methods = match &mca.payment_methods_enabled {
-                Some(pm) => pm,
-                None => continue,
-            };
-            filter_payment_methods(
-                &graph,
-                mca.get_id().clone(),
-                payment_methods,
-                &mut req,
-                &mut response,
-                payment_intent.as_ref(),
-                payment_attempt.as_ref(),
-                billing_address.as_ref(),
-                mca.connector_name.clone(),
-                &state.conf,
-            )
-            .await?;
-        }
+    for mca in &filtered_mcas {
+        let payment_methods = match &mca.payment_methods_enabled {
+            Some(pm) => pm,
+            None => continue,
+        };
+        filter_payment_methods(
+            &graph,
+            mca.get_id(),
+            payment_methods,
+            &mut req,
+            &mut response,
+            payment_intent.as_ref(),
+            payment_attempt.as_ref(),
+            billing_address.as_ref(),
+            mca.connector_name.clone(),
+            &state.conf,
+        )
+        .await?;
     }
     logger::info!(
         "The Payment Methods available after Constraint Graph filtering are {:?}",
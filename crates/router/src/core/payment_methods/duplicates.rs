@@ -0,0 +1,536 @@
+//! Detection and merging of duplicate payment methods
+//!
+//! Payment methods saved in the vault are fingerprinted with a key scoped to the merchant, so that
+//! the same payment method saved for one customer more than once, or saved for different customers
+//! of the merchant, has the same fingerprint. The duplicate policy of the profile decides whether a
+//! duplicate saved for the same customer is rejected, replaced by the payment method already saved
+//! or allowed, while the merchant can list the duplicates of a payment method and merge them into
+//! it. Only active payment methods are considered to be duplicates. The fingerprints of payment
+//! methods saved when fingerprints were scoped to the customer are backfilled by the
+//! `PaymentMethodFingerprintBackfillWorkflow`.
+
+use api_models::payment_methods as api_payment_methods;
+use common_utils::{fp_utils::when, id_type};
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::mandates::CommonMandateReference;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payment_methods::{transformers as pm_transforms, vault},
+    },
+    routes::SessionState,
+    services,
+    types::{
+        domain,
+        storage::{self, enums},
+    },
+};
+
+/// Returns the fingerprint of the payment method data computed by the vault, scoped to the
+/// merchant
+pub async fn get_fingerprint_id(
+    state: &SessionState,
+    payment_method_data: &domain::PaymentMethodVaultingData,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<String> {
+    vault::get_fingerprint_id_from_vault(
+        state,
+        payment_method_data,
+        merchant_id.get_string_repr().to_owned(),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to get fingerprint_id from vault")
+}
+
+/// Number of payment methods whose fingerprint is recomputed in a run of the fingerprint backfill
+pub const FINGERPRINT_BACKFILL_BATCH_SIZE: i64 = 100;
+
+/// Recomputes the fingerprints of a page of the payment methods of the merchant, after the payment
+/// method with the id. Payment methods saved before fingerprints were scoped to the merchant were
+/// fingerprinted with a key scoped to the customer, and would otherwise never be detected as
+/// duplicates. Returns the last payment method of the page, or `None` once the last page is
+/// backfilled.
+#[instrument(skip_all)]
+pub async fn backfill_fingerprint_ids(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    after: Option<&id_type::GlobalPaymentMethodId>,
+) -> RouterResult<Option<id_type::GlobalPaymentMethodId>> {
+    let payment_methods = state
+        .store
+        .find_payment_method_list_by_merchant_id_after_id(
+            &state.into(),
+            merchant_context.get_merchant_key_store(),
+            merchant_context.get_merchant_account().get_id(),
+            after,
+            FINGERPRINT_BACKFILL_BATCH_SIZE,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the payment methods of the merchant")?;
+
+    for payment_method in &payment_methods {
+        // A payment method which cannot be fingerprinted does not stop the backfill of the others
+        if let Err(error) = backfill_fingerprint_id(state, merchant_context, payment_method).await {
+            logger::error!(
+                payment_method_id = ?payment_method.id,
+                ?error,
+                "Failed to backfill the fingerprint of the payment method"
+            );
+        }
+    }
+
+    let is_last_page = i64::try_from(payment_methods.len())
+        .map_or(false, |count| count < FINGERPRINT_BACKFILL_BATCH_SIZE);
+
+    Ok(payment_methods
+        .last()
+        .filter(|_| !is_last_page)
+        .map(|payment_method| payment_method.id.clone()))
+}
+
+async fn backfill_fingerprint_id(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_method: &domain::PaymentMethod,
+) -> RouterResult<()> {
+    // Payment methods saved in an external vault are not fingerprinted
+    let (Some(vault_id), Some(fingerprint_id)) = (
+        payment_method.locker_id.as_ref(),
+        payment_method.locker_fingerprint_id.as_deref(),
+    ) else {
+        return Ok(());
+    };
+
+    let vault_data =
+        vault::retrieve_payment_method_from_vault_internal(state, merchant_context, vault_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to retrieve the payment method from vault")?;
+
+    let merchant_fingerprint_id = get_fingerprint_id(
+        state,
+        &vault_data.data,
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?;
+
+    if merchant_fingerprint_id != fingerprint_id {
+        state
+            .store
+            .update_payment_method(
+                &state.into(),
+                merchant_context.get_merchant_key_store(),
+                payment_method.clone(),
+                storage::PaymentMethodUpdate::LockerFingerprintIdUpdate {
+                    locker_fingerprint_id: merchant_fingerprint_id,
+                },
+                merchant_context.get_merchant_account().storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the fingerprint of the payment method")?;
+    }
+
+    Ok(())
+}
+
+/// Returns the active payment methods of the merchant with the fingerprint, ordered by the time
+/// they were created
+async fn find_duplicate_payment_methods(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    fingerprint_id: &str,
+) -> RouterResult<Vec<domain::PaymentMethod>> {
+    let payment_methods = state
+        .store
+        .find_payment_method_list_by_merchant_id_fingerprint_id(
+            &state.into(),
+            merchant_context.get_merchant_key_store(),
+            merchant_context.get_merchant_account().get_id(),
+            fingerprint_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find payment methods by fingerprint_id")?;
+
+    Ok(payment_methods
+        .into_iter()
+        .filter(|payment_method| payment_method.status == enums::PaymentMethodStatus::Active)
+        .collect())
+}
+
+/// Applies the duplicate policy of the profile to a payment method being saved for the customer.
+/// Returns the payment method already saved for the customer if it has to be used instead of
+/// saving the payment method again.
+#[instrument(skip_all)]
+pub async fn apply_duplicate_policy(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    profile: &domain::Profile,
+    customer_id: &id_type::GlobalCustomerId,
+    fingerprint_id: &str,
+) -> RouterResult<Option<domain::PaymentMethod>> {
+    let duplicate_policy = profile.get_payment_method_duplicate_policy();
+    if duplicate_policy == enums::PaymentMethodDuplicatePolicy::Allow {
+        return Ok(None);
+    }
+
+    let Some(existing_payment_method) =
+        find_duplicate_payment_methods(state, merchant_context, fingerprint_id)
+            .await?
+            .into_iter()
+            .find(|payment_method| payment_method.customer_id == *customer_id)
+    else {
+        return Ok(None);
+    };
+
+    if !should_reuse_existing_payment_method(duplicate_policy)? {
+        return Ok(None);
+    }
+
+    logger::info!(
+        payment_method_id = ?existing_payment_method.id,
+        "Reusing the payment method already saved for the customer"
+    );
+    Ok(Some(existing_payment_method))
+}
+
+/// Whether the payment method already saved for the customer has to be used instead of saving
+/// its duplicate, fails if the policy rejects duplicates
+fn should_reuse_existing_payment_method(
+    duplicate_policy: enums::PaymentMethodDuplicatePolicy,
+) -> RouterResult<bool> {
+    match duplicate_policy {
+        enums::PaymentMethodDuplicatePolicy::Reject => {
+            Err(report!(errors::ApiErrorResponse::DuplicatePaymentMethod))
+                .attach_printable("Cannot save duplicate payment method for the customer")
+        }
+        enums::PaymentMethodDuplicatePolicy::ReuseExisting => Ok(true),
+        enums::PaymentMethodDuplicatePolicy::Allow => Ok(false),
+    }
+}
+
+/// Adds the connector tokens of the duplicates to those of the payment method, retaining the
+/// token of the payment method, or of the earlier duplicate, for a connector
+fn merge_connector_mandate_details(
+    connector_mandate_details: Option<CommonMandateReference>,
+    duplicate_connector_mandate_details: impl Iterator<Item = CommonMandateReference>,
+) -> Option<CommonMandateReference> {
+    duplicate_connector_mandate_details.fold(
+        connector_mandate_details,
+        |connector_mandate_details, duplicate_connector_mandate_details| {
+            Some(match connector_mandate_details {
+                Some(mut connector_mandate_details) => {
+                    connector_mandate_details.merge(duplicate_connector_mandate_details);
+                    connector_mandate_details
+                }
+                None => duplicate_connector_mandate_details,
+            })
+        },
+    )
+}
+
+async fn find_active_payment_method(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_method_id: &id_type::GlobalPaymentMethodId,
+) -> RouterResult<domain::PaymentMethod> {
+    let payment_method = state
+        .store
+        .find_payment_method(
+            &state.into(),
+            merchant_context.get_merchant_key_store(),
+            payment_method_id,
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    when(
+        payment_method.merchant_id != *merchant_context.get_merchant_account().get_id(),
+        || Err(report!(errors::ApiErrorResponse::PaymentMethodNotFound)),
+    )?;
+
+    when(
+        payment_method.status != enums::PaymentMethodStatus::Active,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Payment method {} is not active",
+                    payment_method.id.get_string_repr()
+                ),
+            }))
+        },
+    )?;
+
+    Ok(payment_method)
+}
+
+/// Lists the active payment methods of the merchant with the same fingerprint as the payment
+/// method, saved for the same customer or for other customers
+#[instrument(skip_all)]
+pub async fn list_payment_method_duplicates(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    payment_method_id: id_type::GlobalPaymentMethodId,
+) -> RouterResponse<api_payment_methods::PaymentMethodDuplicatesResponse> {
+    let payment_method =
+        find_active_payment_method(&state, &merchant_context, &payment_method_id).await?;
+
+    let duplicates = match payment_method.locker_fingerprint_id.as_deref() {
+        Some(fingerprint_id) => {
+            find_duplicate_payment_methods(&state, &merchant_context, fingerprint_id)
+                .await?
+                .iter()
+                .filter(|duplicate| duplicate.id != payment_method.id)
+                .map(|duplicate| pm_transforms::generate_payment_method_response(duplicate, &None))
+                .collect::<RouterResult<Vec<_>>>()?
+        }
+        // Payment methods saved in an external vault are not fingerprinted
+        None => Vec::new(),
+    };
+
+    Ok(services::ApplicationResponse::Json(
+        api_payment_methods::PaymentMethodDuplicatesResponse {
+            id: payment_method.id,
+            duplicates,
+        },
+    ))
+}
+
+/// Merges the duplicates into the payment method. The mandates of the duplicates are updated to
+/// refer to the payment method and their connector tokens are added to the payment method, unless
+/// the payment method already has a token for the connector. The duplicates are then deactivated.
+/// The payment method, its duplicates and the mandates are updated in a single transaction.
+#[instrument(skip_all)]
+pub async fn merge_payment_method_duplicates(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    payment_method_id: id_type::GlobalPaymentMethodId,
+    request: api_payment_methods::PaymentMethodMergeRequest,
+) -> RouterResponse<api_payment_methods::PaymentMethodMergeResponse> {
+    let db = &*state.store;
+    let key_manager_state = &(&state).into();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    when(request.duplicate_payment_method_ids.is_empty(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "duplicate_payment_method_ids must not be empty".to_string(),
+        })
+    })?;
+
+    when(
+        request
+            .duplicate_payment_method_ids
+            .contains(&payment_method_id),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "A payment method cannot be merged into itself".to_string(),
+            })
+        },
+    )?;
+
+    let payment_method =
+        find_active_payment_method(&state, &merchant_context, &payment_method_id).await?;
+    let fingerprint_id = payment_method
+        .locker_fingerprint_id
+        .clone()
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Payment method saved in an external vault cannot be merged".to_string(),
+            })
+        })?;
+
+    let mut duplicates = Vec::with_capacity(request.duplicate_payment_method_ids.len());
+    for duplicate_payment_method_id in request.duplicate_payment_method_ids {
+        if duplicates
+            .iter()
+            .any(|duplicate: &domain::PaymentMethod| duplicate.id == duplicate_payment_method_id)
+        {
+            continue;
+        }
+
+        let duplicate =
+            find_active_payment_method(&state, &merchant_context, &duplicate_payment_method_id)
+                .await?;
+
+        when(
+            duplicate.locker_fingerprint_id.as_deref() != Some(fingerprint_id.as_str()),
+            || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "Payment method {} is not a duplicate of payment method {}",
+                        duplicate.id.get_string_repr(),
+                        payment_method.id.get_string_repr()
+                    ),
+                })
+            },
+        )?;
+
+        duplicates.push(duplicate);
+    }
+
+    let mut mandate_ids = Vec::new();
+    for duplicate in &duplicates {
+        mandate_ids.extend(
+            db.find_mandate_by_global_customer_id(&duplicate.customer_id)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the mandates of the customer")?
+                .into_iter()
+                .filter(|mandate| {
+                    mandate.merchant_id == *merchant_id
+                        && mandate.payment_method_id == *duplicate.id.get_string_repr()
+                })
+                .map(|mandate| mandate.mandate_id),
+        );
+    }
+
+    let connector_mandate_details = merge_connector_mandate_details(
+        payment_method.connector_mandate_details.clone(),
+        duplicates
+            .iter()
+            .filter_map(|duplicate| duplicate.connector_mandate_details.clone()),
+    );
+    let merged_payment_method_ids = duplicates
+        .iter()
+        .map(|duplicate| duplicate.id.clone())
+        .collect::<Vec<_>>();
+    let updated_mandates_count = mandate_ids.len();
+
+    let payment_method = db
+        .merge_payment_methods(
+            key_manager_state,
+            merchant_context.get_merchant_key_store(),
+            payment_method,
+            storage::PaymentMethodUpdate::ConnectorMandateDetailsUpdate {
+                connector_mandate_details: connector_mandate_details.map(From::from),
+            },
+            duplicates,
+            mandate_ids,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to merge the duplicate payment methods")?;
+
+    logger::info!(
+        payment_method_id = ?payment_method.id,
+        ?merged_payment_method_ids,
+        updated_mandates_count,
+        "Merged duplicate payment methods"
+    );
+
+    Ok(services::ApplicationResponse::Json(
+        api_payment_methods::PaymentMethodMergeResponse {
+            payment_method: pm_transforms::generate_payment_method_response(
+                &payment_method,
+                &None,
+            )?,
+            merged_payment_method_ids,
+            updated_mandates_count,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::collections::HashMap;
+
+    use hyperswitch_domain_models::mandates::{
+        ConnectorTokenReferenceRecord, PaymentsTokenReference,
+    };
+
+    use super::*;
+
+    fn connector_mandate_details(tokens: &[(&str, &str)]) -> CommonMandateReference {
+        CommonMandateReference {
+            payments: Some(PaymentsTokenReference(HashMap::from_iter(
+                tokens.iter().map(|(mca_id, connector_token)| {
+                    (
+                        id_type::MerchantConnectorAccountId::wrap(mca_id.to_string()).unwrap(),
+                        ConnectorTokenReferenceRecord {
+                            connector_token: connector_token.to_string(),
+                            payment_method_subtype: None,
+                            original_payment_authorized_amount: None,
+                            original_payment_authorized_currency: None,
+                            metadata: None,
+                            connector_token_status: common_enums::ConnectorTokenStatus::Active,
+                            connector_token_request_reference_id: None,
+                        },
+                    )
+                }),
+            ))),
+            payouts: None,
+        }
+    }
+
+    fn get_connector_token(details: &CommonMandateReference, mca_id: &str) -> Option<String> {
+        details
+            .payments
+            .as_ref()?
+            .0
+            .get(&id_type::MerchantConnectorAccountId::wrap(mca_id.to_string()).unwrap())
+            .map(|record| record.connector_token.clone())
+    }
+
+    #[test]
+    fn test_should_reuse_existing_payment_method() {
+        assert!(should_reuse_existing_payment_method(
+            enums::PaymentMethodDuplicatePolicy::ReuseExisting
+        )
+        .unwrap());
+        assert!(
+            !should_reuse_existing_payment_method(enums::PaymentMethodDuplicatePolicy::Allow)
+                .unwrap()
+        );
+        assert!(matches!(
+            should_reuse_existing_payment_method(enums::PaymentMethodDuplicatePolicy::Reject)
+                .unwrap_err()
+                .current_context(),
+            errors::ApiErrorResponse::DuplicatePaymentMethod
+        ));
+    }
+
+    #[test]
+    fn test_merge_connector_mandate_details() {
+        let merged = merge_connector_mandate_details(
+            Some(connector_mandate_details(&[("mca_stripe", "pm_primary")])),
+            vec![
+                connector_mandate_details(&[
+                    ("mca_stripe", "pm_duplicate"),
+                    ("mca_adyen", "adyen_first"),
+                ]),
+                connector_mandate_details(&[("mca_adyen", "adyen_second")]),
+            ]
+            .into_iter(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_connector_token(&merged, "mca_stripe").as_deref(),
+            Some("pm_primary")
+        );
+        assert_eq!(
+            get_connector_token(&merged, "mca_adyen").as_deref(),
+            Some("adyen_first")
+        );
+
+        let merged = merge_connector_mandate_details(
+            None,
+            vec![connector_mandate_details(&[("mca_adyen", "adyen_first")])].into_iter(),
+        )
+        .unwrap();
+        assert_eq!(
+            get_connector_token(&merged, "mca_adyen").as_deref(),
+            Some("adyen_first")
+        );
+
+        assert!(merge_connector_mandate_details(None, std::iter::empty()).is_none());
+    }
+}
@@ -0,0 +1,692 @@
+//! Lifecycle of the network tokens stored against payment methods
+//!
+//! Network tokens are updated, suspended, resumed and deleted by the card networks. The token
+//! service notifies these changes with lifecycle notifications, and the status of the token is
+//! additionally checked with the token service periodically by the network token status check
+//! workflow. The status and the updated details of the token are propagated to the payment
+//! method, and payments fall back to the card when the token is not active.
+
+use ::payment_methods::controller::PaymentMethodsController;
+use api_models::payment_methods::{CardDetailsPaymentMethod, PaymentMethodsData};
+use common_utils::{
+    crypto::{self, VerifySignature},
+    date_time,
+    ext_traits::{AsyncExt, ByteSliceExt, ValueExt},
+    id_type,
+};
+use error_stack::{report, ResultExt};
+use masking::{ExposeInterface, PeekInterface, Secret};
+use redis_interface as redis;
+use router_env::{instrument, logger, tracing};
+use scheduler::utils as pt_utils;
+
+use crate::{
+    core::{
+        errors::{self, CustomResult, RouterResponse, RouterResult},
+        payment_methods::{cards, network_tokenization},
+    },
+    db::StorageInterface,
+    headers,
+    routes::SessionState,
+    services,
+    types::{
+        domain, payment_methods as pm_types,
+        storage::{self, enums},
+    },
+};
+
+pub const NETWORK_TOKEN_STATUS_CHECK_TASK: &str = "NETWORK_TOKEN_STATUS_CHECK";
+pub const NETWORK_TOKEN_STATUS_CHECK_TAG: &str = "NETWORK_TOKEN";
+
+/// Details of a network token updated by the card network
+#[derive(Debug, Default)]
+pub struct NetworkTokenDetailsUpdate {
+    pub token_expiry_month: Option<Secret<String>>,
+    pub token_expiry_year: Option<Secret<String>>,
+    pub token_last_four: Option<String>,
+}
+
+/// Handles a lifecycle notification of a network token sent by the token service. Notifications
+/// older than the configured tolerance are rejected, and notifications which were already received
+/// are discarded, so that a captured notification cannot be replayed.
+#[instrument(skip_all)]
+pub async fn handle_network_token_lifecycle_notification(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request_headers: &actix_web::http::header::HeaderMap,
+    body: &[u8],
+) -> RouterResponse<()> {
+    let network_tokenization_service = state.conf.network_tokenization_service.as_ref().ok_or(
+        errors::ApiErrorResponse::NotSupported {
+            message: "Network tokenization service is not configured".to_string(),
+        },
+    )?;
+
+    verify_lifecycle_notification_signature(
+        &network_tokenization_service.get_inner().webhook_secret,
+        request_headers,
+        body,
+    )?;
+
+    let notification = body
+        .parse_struct::<pm_types::NetworkTokenLifecycleNotification>(
+            "NetworkTokenLifecycleNotification",
+        )
+        .change_context(errors::ApiErrorResponse::WebhookUnprocessableEntity)?;
+
+    let notification_tolerance_in_seconds = state
+        .conf
+        .network_token_lifecycle
+        .notification_tolerance_in_seconds;
+    validate_lifecycle_notification_timestamp(
+        notification.timestamp,
+        date_time::now_unix_timestamp(),
+        notification_tolerance_in_seconds,
+    )?;
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    // The notification is remembered beyond the tolerance, after which a replay of the
+    // notification is rejected by its timestamp
+    let notification_key = get_lifecycle_notification_key(
+        merchant_context.get_merchant_account().get_id(),
+        &notification.notification_id,
+    );
+    let setnx_reply = redis_conn
+        .set_key_if_not_exists_with_expiry(
+            &notification_key.as_str().into(),
+            notification.event_type.to_string(),
+            Some(notification_tolerance_in_seconds.saturating_mul(2)),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to set the network token lifecycle notification key in redis")?;
+
+    if setnx_reply != redis::SetnxReply::KeySet {
+        logger::info!(
+            notification_id = %notification.notification_id,
+            "Discarding network token lifecycle notification which was already received"
+        );
+        return Ok(services::ApplicationResponse::StatusOk);
+    }
+
+    if let Err(error) = apply_lifecycle_notification(&state, &merchant_context, notification).await
+    {
+        // The notification key is removed, so that the token service can send the notification
+        // again
+        redis_conn
+            .delete_key(&notification_key.as_str().into())
+            .await
+            .map_err(|error| {
+                logger::error!(
+                    ?error,
+                    "Failed to delete the network token lifecycle notification key"
+                )
+            })
+            .ok();
+
+        return Err(error);
+    }
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+fn get_lifecycle_notification_key(
+    merchant_id: &id_type::MerchantId,
+    notification_id: &str,
+) -> String {
+    format!(
+        "network_token_lifecycle_notification_{}_{notification_id}",
+        merchant_id.get_string_repr()
+    )
+}
+
+/// Rejects a lifecycle notification sent more than the tolerance away from the current time
+fn validate_lifecycle_notification_timestamp(
+    timestamp: i64,
+    current_timestamp: i64,
+    notification_tolerance_in_seconds: i64,
+) -> RouterResult<()> {
+    common_utils::fp_utils::when(
+        current_timestamp.abs_diff(timestamp) > notification_tolerance_in_seconds.unsigned_abs(),
+        || {
+            Err(report!(
+                errors::ApiErrorResponse::WebhookAuthenticationFailed
+            ))
+            .attach_printable(format!(
+                "Network token lifecycle notification sent at {timestamp} is outside the tolerance"
+            ))
+        },
+    )
+}
+
+/// Applies the change of the network token notified by the token service to the payment method
+async fn apply_lifecycle_notification(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    notification: pm_types::NetworkTokenLifecycleNotification,
+) -> RouterResult<()> {
+    let payment_method = find_payment_method_by_network_token_requestor_reference_id(
+        state,
+        merchant_context,
+        &notification.customer_id,
+        &notification.card_reference,
+    )
+    .await?;
+
+    logger::info!(
+        payment_method_id = %payment_method.payment_method_id,
+        event_type = %notification.event_type,
+        "Received network token lifecycle notification"
+    );
+
+    let (network_token_status, network_token_details_update) =
+        get_network_token_status_update(notification);
+
+    update_network_token_details(
+        state,
+        merchant_context,
+        payment_method,
+        network_token_status,
+        network_token_details_update,
+    )
+    .await
+    .map(|_| ())
+}
+
+/// Returns the status of the network token and the updated details of the token, if any, notified
+/// by a lifecycle notification
+fn get_network_token_status_update(
+    notification: pm_types::NetworkTokenLifecycleNotification,
+) -> (enums::NetworkTokenStatus, Option<NetworkTokenDetailsUpdate>) {
+    match notification.event_type {
+        pm_types::NetworkTokenLifecycleEventType::TokenUpdated => (
+            enums::NetworkTokenStatus::Active,
+            Some(NetworkTokenDetailsUpdate {
+                token_expiry_month: notification.token_expiry_month,
+                token_expiry_year: notification.token_expiry_year,
+                token_last_four: notification.token_last_four,
+            }),
+        ),
+        pm_types::NetworkTokenLifecycleEventType::TokenSuspended => {
+            (enums::NetworkTokenStatus::Suspended, None)
+        }
+        pm_types::NetworkTokenLifecycleEventType::TokenResumed => {
+            (enums::NetworkTokenStatus::Active, None)
+        }
+        pm_types::NetworkTokenLifecycleEventType::TokenDeleted => {
+            (enums::NetworkTokenStatus::Deleted, None)
+        }
+    }
+}
+
+/// Verifies the HMAC-SHA256 signature of a lifecycle notification, sent hex encoded in the
+/// signature header
+fn verify_lifecycle_notification_signature(
+    webhook_secret: &Secret<String>,
+    request_headers: &actix_web::http::header::HeaderMap,
+    body: &[u8],
+) -> RouterResult<()> {
+    let signature = request_headers
+        .get(headers::X_NETWORK_TOKEN_SIGNATURE)
+        .and_then(|signature| signature.to_str().ok())
+        .and_then(|signature| hex::decode(signature).ok())
+        .ok_or(errors::ApiErrorResponse::WebhookAuthenticationFailed)
+        .attach_printable("Missing or invalid network token lifecycle notification signature")?;
+
+    let is_signature_valid = crypto::HmacSha256
+        .verify_signature(webhook_secret.peek().as_bytes(), &signature, body)
+        .change_context(errors::ApiErrorResponse::WebhookAuthenticationFailed)?;
+
+    common_utils::fp_utils::when(!is_signature_valid, || {
+        Err(report!(
+            errors::ApiErrorResponse::WebhookAuthenticationFailed
+        ))
+        .attach_printable("Network token lifecycle notification signature mismatch")
+    })
+}
+
+async fn find_payment_method_by_network_token_requestor_reference_id(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    customer_id: &id_type::CustomerId,
+    network_token_requestor_reference_id: &str,
+) -> RouterResult<domain::PaymentMethod> {
+    state
+        .store
+        .find_payment_method_by_customer_id_merchant_id_list(
+            &state.into(),
+            merchant_context.get_merchant_key_store(),
+            customer_id,
+            merchant_context.get_merchant_account().get_id(),
+            None,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the payment methods of the customer")?
+        .into_iter()
+        .find(|payment_method| {
+            payment_method
+                .network_token_requestor_reference_id
+                .as_deref()
+                == Some(network_token_requestor_reference_id)
+        })
+        .ok_or_else(|| report!(errors::ApiErrorResponse::PaymentMethodNotFound))
+}
+
+/// Returns the locker id of the network token to be deleted from the locker for the status. A
+/// payment method without a network token locker id has only the card stored in the locker, which
+/// must be kept so that payments can fall back to the card.
+fn get_network_token_locker_id_to_delete(
+    network_token_status: enums::NetworkTokenStatus,
+    network_token_locker_id: Option<&String>,
+) -> Option<&String> {
+    network_token_locker_id.filter(|_| network_token_status == enums::NetworkTokenStatus::Deleted)
+}
+
+/// Updates the status and the details of the network token stored against the payment method.
+/// The token is removed from the locker once it is deleted by the card network.
+pub async fn update_network_token_details(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_method: domain::PaymentMethod,
+    network_token_status: enums::NetworkTokenStatus,
+    network_token_details_update: Option<NetworkTokenDetailsUpdate>,
+) -> RouterResult<domain::PaymentMethod> {
+    let key_manager_state = &state.into();
+
+    let network_token_payment_method_data = network_token_details_update
+        .and_then(|details_update| {
+            get_network_token_details_from_payment_method(&payment_method).map(|token_details| {
+                PaymentMethodsData::Card(CardDetailsPaymentMethod {
+                    expiry_month: details_update
+                        .token_expiry_month
+                        .or(token_details.expiry_month),
+                    expiry_year: details_update
+                        .token_expiry_year
+                        .or(token_details.expiry_year),
+                    last4_digits: details_update
+                        .token_last_four
+                        .or(token_details.last4_digits),
+                    ..token_details
+                })
+            })
+        })
+        .async_map(|network_token_payment_method_data| {
+            cards::create_encrypted_data(
+                key_manager_state,
+                merchant_context.get_merchant_key_store(),
+                network_token_payment_method_data,
+            )
+        })
+        .await
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to encrypt network token payment method data")?;
+
+    if let Some(network_token_locker_id) = get_network_token_locker_id_to_delete(
+        network_token_status,
+        payment_method.network_token_locker_id.as_ref(),
+    ) {
+        cards::PmCards {
+            state,
+            merchant_context,
+        }
+        .delete_card_from_locker(
+            &payment_method.customer_id,
+            &payment_method.merchant_id,
+            network_token_locker_id,
+        )
+        .await
+        .map_err(|error| {
+            logger::error!(?error, "Failed to delete the network token from the locker")
+        })
+        .ok();
+    }
+
+    let payment_method_update = storage::PaymentMethodUpdate::NetworkTokenStatusUpdate {
+        network_token_status: Some(network_token_status),
+        network_token_payment_method_data: network_token_payment_method_data.map(Into::into),
+    };
+
+    let payment_method = state
+        .store
+        .update_payment_method(
+            key_manager_state,
+            merchant_context.get_merchant_key_store(),
+            payment_method,
+            payment_method_update,
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update network token status of payment method in db")?;
+
+    if let Some(network_token_requestor_reference_id) =
+        &payment_method.network_token_requestor_reference_id
+    {
+        network_tokenization::delete_cached_network_token(
+            state,
+            &payment_method.merchant_id,
+            network_token_requestor_reference_id,
+        )
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to delete the cached network token"))
+        .ok();
+    }
+
+    Ok(payment_method)
+}
+
+/// Returns the network token details stored in the network token data of the payment method
+pub fn get_network_token_details_from_payment_method(
+    payment_method: &domain::PaymentMethod,
+) -> Option<CardDetailsPaymentMethod> {
+    payment_method
+        .network_token_payment_method_data
+        .clone()
+        .map(|network_token_data| network_token_data.into_inner().expose())
+        .and_then(|value| {
+            value
+                .parse_value::<PaymentMethodsData>("PaymentMethodsData")
+                .map_err(|error| logger::error!(?error, "Failed to deserialize network token data"))
+                .ok()
+        })
+        .and_then(|network_token_data| match network_token_data {
+            PaymentMethodsData::Card(token_details) => Some(token_details),
+            PaymentMethodsData::BankDetails(_) | PaymentMethodsData::WalletDetails(_) => None,
+        })
+}
+
+pub fn get_network_token_status_check_process_tracker_id(
+    merchant_id: &id_type::MerchantId,
+) -> String {
+    pt_utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::NetworkTokenStatusCheckWorkflow,
+        NETWORK_TOKEN_STATUS_CHECK_TASK,
+        merchant_id.get_string_repr(),
+        merchant_id,
+    )
+}
+
+/// Whether the network token of the payment method is checked with the token service
+pub fn is_network_token_status_check_applicable(payment_method: &domain::PaymentMethod) -> bool {
+    payment_method
+        .network_token_requestor_reference_id
+        .is_some()
+        && payment_method.network_token_status != Some(enums::NetworkTokenStatus::Deleted)
+}
+
+/// Schedules the periodic status check of the network tokens of the merchant, unless the check is
+/// already scheduled. A single task checks the network tokens of all the payment methods of the
+/// merchant in batches.
+pub async fn add_network_token_status_check_task(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    schedule_time: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::StorageError> {
+    let process_tracker_id = get_network_token_status_check_process_tracker_id(merchant_id);
+
+    // The check is finished while the merchant has no network tokens to check
+    if let Some(process) = db.find_process_by_id(&process_tracker_id).await? {
+        return if process.status == enums::ProcessTrackerStatus::Finish {
+            db.as_scheduler()
+                .reset_process(process, schedule_time)
+                .await
+        } else {
+            Ok(())
+        };
+    }
+
+    let tracking_data = storage::NetworkTokenStatusCheckTrackingData {
+        merchant_id: merchant_id.clone(),
+        check_started_at: None,
+        cursor: None,
+        network_tokens_found: false,
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        NETWORK_TOKEN_STATUS_CHECK_TASK,
+        storage::ProcessTrackerRunner::NetworkTokenStatusCheckWorkflow,
+        [NETWORK_TOKEN_STATUS_CHECK_TAG],
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    // The task may have been added for a concurrently stored network token
+    db.insert_process(process_tracker_entry)
+        .await
+        .map(|_| ())
+        .or_else(|error| {
+            if error.current_context().is_db_unique_violation() {
+                Ok(())
+            } else {
+                Err(error)
+            }
+        })
+}
+
+/// Schedules the status check of the network tokens of the merchant when a network token is stored
+/// against the payment method, logging the failure as the payment method is usable without the
+/// status check
+pub async fn schedule_network_token_status_check(
+    state: &SessionState,
+    payment_method: &domain::PaymentMethod,
+) {
+    if !is_network_token_status_check_applicable(payment_method) {
+        return;
+    }
+
+    let schedule_time = date_time::now().saturating_add(time::Duration::hours(
+        state
+            .conf
+            .network_token_lifecycle
+            .status_check_interval_in_hours,
+    ));
+    add_network_token_status_check_task(&*state.store, &payment_method.merchant_id, schedule_time)
+        .await
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to add the network token status check task to process tracker"
+            )
+        })
+        .ok();
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use actix_web::http::header::{HeaderMap, HeaderName, HeaderValue};
+    use common_utils::crypto::SignMessage;
+
+    use super::*;
+
+    const WEBHOOK_SECRET: &str = "network_token_webhook_secret";
+
+    fn get_signature_headers(signature: &str) -> HeaderMap {
+        let mut request_headers = HeaderMap::new();
+        request_headers.insert(
+            HeaderName::from_static("x-network-token-signature"),
+            HeaderValue::from_str(signature).unwrap(),
+        );
+        request_headers
+    }
+
+    fn get_notification(event_type: &str) -> pm_types::NetworkTokenLifecycleNotification {
+        serde_json::from_value(serde_json::json!({
+            "notification_id": "notification_1",
+            "timestamp": 1_751_800_000,
+            "event_type": event_type,
+            "card_reference": "card_reference_1",
+            "customer_id": "customer_1",
+            "token_expiry_month": "12",
+            "token_expiry_year": "2030",
+            "token_last_four": "4242",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_verify_lifecycle_notification_signature() {
+        let webhook_secret = Secret::new(WEBHOOK_SECRET.to_string());
+        let body = br#"{"event_type":"TOKEN_SUSPENDED"}"#;
+        let signature = hex::encode(
+            crypto::HmacSha256
+                .sign_message(WEBHOOK_SECRET.as_bytes(), body)
+                .unwrap(),
+        );
+
+        assert!(verify_lifecycle_notification_signature(
+            &webhook_secret,
+            &get_signature_headers(&signature),
+            body
+        )
+        .is_ok());
+
+        // The body was changed after it was signed
+        assert!(verify_lifecycle_notification_signature(
+            &webhook_secret,
+            &get_signature_headers(&signature),
+            br#"{"event_type":"TOKEN_DELETED"}"#
+        )
+        .is_err());
+
+        // The body was signed with another secret
+        assert!(verify_lifecycle_notification_signature(
+            &Secret::new("another_secret".to_string()),
+            &get_signature_headers(&signature),
+            body
+        )
+        .is_err());
+
+        // The signature is not hex encoded
+        assert!(verify_lifecycle_notification_signature(
+            &webhook_secret,
+            &get_signature_headers("not_a_signature"),
+            body
+        )
+        .is_err());
+
+        assert!(
+            verify_lifecycle_notification_signature(&webhook_secret, &HeaderMap::new(), body)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_validate_lifecycle_notification_timestamp() {
+        let current_timestamp = 1_751_800_000;
+
+        assert!(validate_lifecycle_notification_timestamp(
+            current_timestamp,
+            current_timestamp,
+            300
+        )
+        .is_ok());
+        assert!(validate_lifecycle_notification_timestamp(
+            current_timestamp - 300,
+            current_timestamp,
+            300
+        )
+        .is_ok());
+        // Clocks of the token service running ahead within the tolerance
+        assert!(validate_lifecycle_notification_timestamp(
+            current_timestamp + 60,
+            current_timestamp,
+            300
+        )
+        .is_ok());
+        // A notification replayed after the tolerance
+        assert!(validate_lifecycle_notification_timestamp(
+            current_timestamp - 301,
+            current_timestamp,
+            300
+        )
+        .is_err());
+        assert!(validate_lifecycle_notification_timestamp(
+            current_timestamp + 301,
+            current_timestamp,
+            300
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_get_network_token_status_update() {
+        let (network_token_status, network_token_details_update) =
+            get_network_token_status_update(get_notification("TOKEN_UPDATED"));
+        assert_eq!(network_token_status, enums::NetworkTokenStatus::Active);
+        let network_token_details_update = network_token_details_update.unwrap();
+        assert_eq!(
+            network_token_details_update
+                .token_expiry_month
+                .as_ref()
+                .map(PeekInterface::peek)
+                .map(String::as_str),
+            Some("12")
+        );
+        assert_eq!(
+            network_token_details_update
+                .token_expiry_year
+                .as_ref()
+                .map(PeekInterface::peek)
+                .map(String::as_str),
+            Some("2030")
+        );
+        assert_eq!(
+            network_token_details_update.token_last_four.as_deref(),
+            Some("4242")
+        );
+
+        for (event_type, expected_network_token_status) in [
+            ("TOKEN_SUSPENDED", enums::NetworkTokenStatus::Suspended),
+            ("TOKEN_RESUMED", enums::NetworkTokenStatus::Active),
+            ("TOKEN_DELETED", enums::NetworkTokenStatus::Deleted),
+        ] {
+            let (network_token_status, network_token_details_update) =
+                get_network_token_status_update(get_notification(event_type));
+            assert_eq!(network_token_status, expected_network_token_status);
+            // Only updated tokens carry the details of the token
+            assert!(network_token_details_update.is_none());
+        }
+    }
+
+    #[test]
+    fn test_get_network_token_locker_id_to_delete() {
+        let network_token_locker_id = "network_token_locker_id".to_string();
+
+        assert_eq!(
+            get_network_token_locker_id_to_delete(
+                enums::NetworkTokenStatus::Deleted,
+                Some(&network_token_locker_id)
+            ),
+            Some(&network_token_locker_id)
+        );
+        // The card stored in the locker is kept when the network token is not stored separately
+        assert_eq!(
+            get_network_token_locker_id_to_delete(enums::NetworkTokenStatus::Deleted, None),
+            None
+        );
+        for network_token_status in [
+            enums::NetworkTokenStatus::Active,
+            enums::NetworkTokenStatus::Suspended,
+        ] {
+            assert_eq!(
+                get_network_token_locker_id_to_delete(
+                    network_token_status,
+                    Some(&network_token_locker_id)
+                ),
+                None
+            );
+        }
+    }
+}
@@ -7,6 +7,11 @@ use ::payment_methods::controller::PaymentMethodsController;
 use api_models::payment_methods as api_payment_methods;
 #[cfg(feature = "v2")]
 use cards::{CardNumber, NetworkToken};
+#[cfg(feature = "v1")]
+use common_utils::{
+    crypto::{DecodeMessage, EncodeMessage, GcmAes256},
+    ext_traits::ByteSliceExt,
+};
 use common_utils::{
     errors::CustomResult,
     ext_traits::{BytesExt, Encode},
@@ -507,35 +512,46 @@ pub async fn get_token_from_tokenization_service(
     state: &routes::SessionState,
     network_token_requestor_ref_id: String,
     pm_data: &domain::PaymentMethod,
+    merchant_key_store: &domain::MerchantKeyStore,
 ) -> errors::RouterResult<domain::NetworkTokenData> {
-    let token_response =
-        if let Some(network_tokenization_service) = &state.conf.network_tokenization_service {
-            record_operation_time(
-                async {
-                    get_network_token(
+    let cached_network_token = get_cached_network_token(
+        state,
+        pm_data,
+        &network_token_requestor_ref_id,
+        merchant_key_store,
+    )
+    .await
+    .inspect_err(|error| logger::error!(?error, "Failed to fetch cached network token"))
+    .ok()
+    .flatten();
+
+    let network_token = match cached_network_token {
+        Some(network_token) => {
+            logger::info!("Using cached network token");
+            network_token
+        }
+        None => {
+            let network_token = pm_types::CachedNetworkToken::from(
+                fetch_token_from_tokenization_service(
+                    state,
+                    network_token_requestor_ref_id.clone(),
+                    pm_data,
+                )
+                .await?,
+            );
+            cache_network_token(
                 state,
-                pm_data.customer_id.clone(),
-                network_token_requestor_ref_id,
-                network_tokenization_service.get_inner(),
-            )
-            .await
-            .inspect_err(
-                |e| logger::error!(error=?e, "Error while fetching token from tokenization service")
-            )
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Fetch network token failed")
-                },
-                &metrics::FETCH_NETWORK_TOKEN_TIME,
-                &[],
+                pm_data,
+                &network_token_requestor_ref_id,
+                merchant_key_store,
+                &network_token,
             )
             .await
-        } else {
-            Err(errors::NetworkTokenizationError::NetworkTokenizationServiceNotConfigured)
-                .inspect_err(|err| {
-                    logger::error!(error=? err);
-                })
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-        }?;
+            .inspect_err(|error| logger::error!(?error, "Failed to cache network token"))
+            .ok();
+            network_token
+        }
+    };
 
     let token_decrypted = pm_data
         .network_token_payment_method_data
@@ -552,17 +568,17 @@ pub async fn get_token_from_tokenization_service(
         .attach_printable("Failed to obtain decrypted token object from db")?;
 
     let network_token_data = domain::NetworkTokenData {
-        token_number: token_response.authentication_details.token,
-        token_cryptogram: Some(token_response.authentication_details.cryptogram),
+        token_number: network_token.token,
+        token_cryptogram: Some(network_token.cryptogram),
         token_exp_month: token_decrypted
             .expiry_month
-            .unwrap_or(token_response.token_details.exp_month),
+            .unwrap_or(network_token.token_details.exp_month),
         token_exp_year: token_decrypted
             .expiry_year
-            .unwrap_or(token_response.token_details.exp_year),
+            .unwrap_or(network_token.token_details.exp_year),
         nick_name: token_decrypted.card_holder_name,
         card_issuer: None,
-        card_network: Some(token_response.network),
+        card_network: Some(network_token.network),
         card_type: None,
         card_issuing_country: None,
         bank_code: None,
@@ -571,6 +587,166 @@ pub async fn get_token_from_tokenization_service(
     Ok(network_token_data)
 }
 
+#[cfg(feature = "v1")]
+async fn fetch_token_from_tokenization_service(
+    state: &routes::SessionState,
+    network_token_requestor_ref_id: String,
+    pm_data: &domain::PaymentMethod,
+) -> errors::RouterResult<pm_types::TokenResponse> {
+    let token_response =
+        if let Some(network_tokenization_service) = &state.conf.network_tokenization_service {
+            record_operation_time(
+                async {
+                    get_network_token(
+                state,
+                pm_data.customer_id.clone(),
+                network_token_requestor_ref_id,
+                network_tokenization_service.get_inner(),
+            )
+            .await
+            .inspect_err(
+                |e| logger::error!(error=?e, "Error while fetching token from tokenization service")
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Fetch network token failed")
+                },
+                &metrics::FETCH_NETWORK_TOKEN_TIME,
+                &[],
+            )
+            .await
+        } else {
+            Err(errors::NetworkTokenizationError::NetworkTokenizationServiceNotConfigured)
+                .inspect_err(|err| {
+                    logger::error!(error=? err);
+                })
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+        }?;
+
+    Ok(token_response)
+}
+
+#[cfg(feature = "v1")]
+fn get_network_token_cache_key(
+    merchant_id: &id_type::MerchantId,
+    network_token_requestor_ref_id: &str,
+) -> String {
+    format!(
+        "network_token_{}_{network_token_requestor_ref_id}",
+        merchant_id.get_string_repr()
+    )
+}
+
+/// Fetches the network token and cryptogram cached for the payment method, if any
+#[cfg(feature = "v1")]
+async fn get_cached_network_token(
+    state: &routes::SessionState,
+    pm_data: &domain::PaymentMethod,
+    network_token_requestor_ref_id: &str,
+    merchant_key_store: &domain::MerchantKeyStore,
+) -> errors::RouterResult<Option<pm_types::CachedNetworkToken>> {
+    if state
+        .conf
+        .network_token_lifecycle
+        .cryptogram_cache_ttl_in_seconds
+        == 0
+    {
+        return Ok(None);
+    }
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let cache_key =
+        get_network_token_cache_key(&pm_data.merchant_id, network_token_requestor_ref_id);
+    let encrypted_network_token = redis_conn
+        .get_key::<Option<Vec<u8>>>(&cache_key.as_str().into())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch cached network token from redis")?;
+
+    encrypted_network_token
+        .map(|encrypted_network_token| {
+            let network_token = GcmAes256
+                .decode_message(
+                    merchant_key_store.key.get_inner().peek(),
+                    Secret::new(encrypted_network_token),
+                )
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to decrypt cached network token")?;
+
+            network_token
+                .parse_struct::<pm_types::CachedNetworkToken>("CachedNetworkToken")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+        })
+        .transpose()
+}
+
+/// Caches the network token and cryptogram fetched for the payment method, so that retried
+/// attempts do not fetch a new cryptogram from the token service
+#[cfg(feature = "v1")]
+async fn cache_network_token(
+    state: &routes::SessionState,
+    pm_data: &domain::PaymentMethod,
+    network_token_requestor_ref_id: &str,
+    merchant_key_store: &domain::MerchantKeyStore,
+    network_token: &pm_types::CachedNetworkToken,
+) -> errors::RouterResult<()> {
+    let ttl = state
+        .conf
+        .network_token_lifecycle
+        .cryptogram_cache_ttl_in_seconds;
+    if ttl == 0 {
+        return Ok(());
+    }
+
+    let encrypted_network_token = GcmAes256
+        .encode_message(
+            merchant_key_store.key.get_inner().peek(),
+            network_token
+                .encode_to_vec()
+                .change_context(errors::ApiErrorResponse::InternalServerError)?
+                .as_slice(),
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encrypt network token for caching")?;
+
+    let cache_key =
+        get_network_token_cache_key(&pm_data.merchant_id, network_token_requestor_ref_id);
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .set_key_with_expiry(&cache_key.as_str().into(), encrypted_network_token, ttl)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to cache network token in redis")
+}
+
+/// Removes the network token and cryptogram cached for the payment method, so that changes made
+/// to the token by the token service take effect immediately
+#[cfg(feature = "v1")]
+pub async fn delete_cached_network_token(
+    state: &routes::SessionState,
+    merchant_id: &id_type::MerchantId,
+    network_token_requestor_ref_id: &str,
+) -> errors::RouterResult<()> {
+    let cache_key = get_network_token_cache_key(merchant_id, network_token_requestor_ref_id);
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .delete_key(&cache_key.as_str().into())
+        .await
+        .map(|_| ())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to delete cached network token from redis")
+}
+
 #[cfg(feature = "v2")]
 pub async fn get_token_from_tokenization_service(
     state: &routes::SessionState,
@@ -721,6 +897,32 @@ pub async fn check_token_status_with_tokenization_service(
     tokenization_service: &settings::NetworkTokenizationService,
 ) -> CustomResult<(Option<Secret<String>>, Option<Secret<String>>), errors::NetworkTokenizationError>
 {
+    let token_status = get_token_status_from_tokenization_service(
+        state,
+        customer_id,
+        network_token_requestor_reference_id,
+        tokenization_service,
+    )
+    .await?;
+
+    match token_status.token_status {
+        pm_types::TokenStatus::Active => Ok((
+            Some(token_status.token_expiry_month),
+            Some(token_status.token_expiry_year),
+        )),
+        pm_types::TokenStatus::Inactive
+        | pm_types::TokenStatus::Suspended
+        | pm_types::TokenStatus::Deleted => Ok((None, None)),
+    }
+}
+
+#[cfg(feature = "v1")]
+pub async fn get_token_status_from_tokenization_service(
+    state: &routes::SessionState,
+    customer_id: &id_type::CustomerId,
+    network_token_requestor_reference_id: String,
+    tokenization_service: &settings::NetworkTokenizationService,
+) -> CustomResult<pm_types::CheckTokenStatusResponsePayload, errors::NetworkTokenizationError> {
     let mut request = services::Request::new(
         services::Method::Post,
         tokenization_service.check_token_status_url.as_str(),
@@ -778,13 +980,7 @@ pub async fn check_token_status_with_tokenization_service(
         .parse_struct("Delete Network Tokenization Response")
         .change_context(errors::NetworkTokenizationError::ResponseDeserializationFailed)?;
 
-    match check_token_status_response.payload.token_status {
-        pm_types::TokenStatus::Active => Ok((
-            Some(check_token_status_response.payload.token_expiry_month),
-            Some(check_token_status_response.payload.token_expiry_year),
-        )),
-        pm_types::TokenStatus::Inactive => Ok((None, None)),
-    }
+    Ok(check_token_status_response.payload)
 }
 
 #[cfg(feature = "v2")]
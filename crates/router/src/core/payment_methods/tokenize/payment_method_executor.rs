@@ -11,7 +11,7 @@ use super::{
     NetworkTokenizationResponse, State, TransitionTo,
 };
 use crate::{
-    core::payment_methods::transformers as pm_transformers,
+    core::payment_methods::{network_token_lifecycle, transformers as pm_transformers},
     errors::{self, RouterResult},
     types::{api, domain},
 };
@@ -396,7 +396,8 @@ impl CardNetworkTokenizeExecutor<'_, domain::TokenizePaymentMethodRequest> {
             network_token_locker_id: Some(store_token_response.card_reference.clone()),
             network_token_payment_method_data: Some(enc_token_data.into()),
         };
-        self.state
+        let payment_method = self
+            .state
             .store
             .update_payment_method(
                 &self.state.into(),
@@ -407,6 +408,11 @@ impl CardNetworkTokenizeExecutor<'_, domain::TokenizePaymentMethodRequest> {
             )
             .await
             .inspect_err(|err| logger::info!("Error updating payment method: {:?}", err))
-            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+        network_token_lifecycle::schedule_network_token_status_check(self.state, &payment_method)
+            .await;
+
+        Ok(payment_method)
     }
 }
@@ -16,15 +16,11 @@ use kgraph_utils::{error::KgraphError, transformers::IntoDirValue};
 use masking::ExposeInterface;
 use storage_impl::redis::cache::{CacheKey, PM_FILTERS_CGRAPH_CACHE};
 
-use crate::{configs::settings, routes::SessionState};
 #[cfg(feature = "v2")]
-use crate::{
-    db::{
-        errors,
-        storage::{self, enums as storage_enums},
-    },
-    services::logger,
-};
+use crate::db::storage::{self, enums as storage_enums};
+#[cfg(feature = "v1")]
+use crate::types::domain;
+use crate::{configs::settings, db::errors, routes::SessionState, services::logger};
 
 pub fn make_pm_graph(
     builder: &mut cgraph::ConstraintGraphBuilder<dir::DirValue>,
@@ -65,6 +61,61 @@ pub async fn get_merchant_pm_filter_graph(
         .await
 }
 
+/// Retrieves the payment method filter graph of the profile from the cache, building it from the
+/// merchant connector accounts of the profile and caching it when absent
+#[cfg(feature = "v1")]
+pub async fn get_or_build_pm_filter_graph<'a>(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    profile_id: &common_utils::id_type::ProfileId,
+    merchant_connector_accounts: impl IntoIterator<Item = &'a domain::MerchantConnectorAccount>,
+    pm_config_mapping: &settings::ConnectorFilters,
+) -> errors::RouterResult<Arc<cgraph::ConstraintGraph<dir::DirValue>>> {
+    let key = format!(
+        "pm_filters_cgraph_{}_{}",
+        merchant_id.get_string_repr(),
+        profile_id.get_string_repr()
+    );
+
+    if let Some(graph) = get_merchant_pm_filter_graph(state, &key).await {
+        return Ok(graph);
+    }
+
+    let mut builder = cgraph::ConstraintGraphBuilder::new();
+    for mca in merchant_connector_accounts {
+        let domain_id = builder
+            .make_domain(
+                mca.get_id().get_string_repr().to_string(),
+                mca.connector_name.as_str(),
+            )
+            .map_err(|error| {
+                logger::error!(
+                    ?error,
+                    "Failed to construct domain for list payment methods"
+                );
+                errors::ApiErrorResponse::InternalServerError
+            })?;
+
+        let Some(payment_methods) = &mca.payment_methods_enabled else {
+            continue;
+        };
+
+        if let Err(e) = make_pm_graph(
+            &mut builder,
+            domain_id,
+            payment_methods,
+            mca.connector_name.clone(),
+            pm_config_mapping,
+            &state.conf.mandates.supported_payment_methods,
+            &state.conf.mandates.update_mandate_supported,
+        ) {
+            logger::error!("Failed to construct constraint graph for list payment methods {e:?}");
+        }
+    }
+
+    Ok(refresh_pm_filters_cache(state, &key, builder.build()).await)
+}
+
 pub async fn refresh_pm_filters_cache(
     state: &SessionState,
     key: &str,
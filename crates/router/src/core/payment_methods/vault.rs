@@ -1,3 +1,5 @@
+#[cfg(feature = "v1")]
+use ::payment_methods::controller::PaymentMethodsController;
 use common_enums::PaymentMethodType;
 #[cfg(feature = "v2")]
 use common_utils::request;
@@ -47,6 +49,8 @@ use crate::{
     utils::{ext_traits::OptionExt, ConnectorResponseExt},
 };
 
+pub mod external_adapter;
+
 const VAULT_SERVICE_NAME: &str = "CARD";
 
 pub struct SupplementaryVaultData {
@@ -1242,6 +1246,12 @@ pub async fn call_to_vault<V: pm_types::VaultingInterface>(
     payload: Vec<u8>,
 ) -> CustomResult<String, errors::VaultError> {
     let locker = &state.conf.locker;
+
+    if locker.mock_locker {
+        let adapter = external_adapter::get_mock_locker_vault_adapter(state);
+        return V::call_external_vault_adapter(&adapter, &payload).await;
+    }
+
     let jwekey = state.conf.jwekey.get_inner();
 
     let request =
@@ -1389,6 +1399,28 @@ pub async fn retrieve_payment_method_from_vault_external(
     pm: &domain::PaymentMethod,
     merchant_connector_account: payment_helpers::MerchantConnectorAccountType,
 ) -> RouterResult<pm_types::VaultRetrieveResponse> {
+    if state.conf.locker.mock_locker {
+        let vault_id = pm
+            .locker_id
+            .as_ref()
+            .get_required_value("locker_id")
+            .attach_printable("Missing locker_id in PaymentMethod")?;
+
+        let data = external_adapter::retrieve_vaulting_data(
+            &external_adapter::get_mock_locker_vault_adapter(state),
+            merchant_account.get_id(),
+            vault_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve payment method from the mock locker")?;
+
+        return serde_json::from_value(data)
+            .map(|data| pm_types::VaultRetrieveResponse { data })
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the payment method from the mock locker");
+    }
+
     let connector_vault_id = pm
         .locker_id
         .clone()
@@ -1636,6 +1668,17 @@ pub async fn delete_payment_method_data_from_vault_external(
     merchant_connector_account: payment_helpers::MerchantConnectorAccountType,
     vault_id: domain::VaultId,
 ) -> RouterResult<pm_types::VaultDeleteResponse> {
+    if state.conf.locker.mock_locker {
+        return external_adapter::delete_vaulting_data(
+            &external_adapter::get_mock_locker_vault_adapter(state),
+            merchant_account.get_id(),
+            &vault_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to delete payment method from the mock locker");
+    }
+
     let connector_vault_id = vault_id.get_string_repr().to_owned();
 
     let router_data = core_utils::construct_vault_router_data(
@@ -1765,6 +1808,160 @@ pub async fn delete_payment_method_data_from_vault(
     }
 }
 
+/// Replaces the card stored in the locker for the payment method with the given card, and returns
+/// the payment method updated with the locker reference of the new card.
+///
+/// The new card is added to the locker and its reference is saved on the payment method before the
+/// old card is deleted, so that the payment method refers to a card in the locker throughout. The
+/// locker matches a card with the same number as a stored card of the customer to the stored card
+/// instead of adding it, so when only the expiry of the card changes, the stored card is replaced
+/// under its reference and restored if the new card cannot be added.
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn replace_card_in_locker(
+    state: &routes::SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_method: domain::PaymentMethod,
+    existing_card: api::CardDetail,
+    card: api::CardDetail,
+) -> RouterResult<domain::PaymentMethod> {
+    let locker_id = payment_method
+        .locker_id
+        .clone()
+        .unwrap_or(payment_method.payment_method_id.clone());
+
+    let payment_method_create = api::PaymentMethodCreate {
+        payment_method: payment_method.get_payment_method_type(),
+        payment_method_type: payment_method.get_payment_method_subtype(),
+        payment_method_issuer: payment_method.payment_method_issuer.clone(),
+        payment_method_issuer_code: payment_method.payment_method_issuer_code,
+        #[cfg(feature = "payouts")]
+        bank_transfer: None,
+        card: Some(card.clone()),
+        #[cfg(feature = "payouts")]
+        wallet: None,
+        metadata: None,
+        customer_id: Some(payment_method.customer_id.clone()),
+        client_secret: payment_method.client_secret.clone(),
+        payment_method_data: None,
+        card_network: None,
+        billing: None,
+        connector_mandate_details: None,
+        network_transaction_id: None,
+    };
+
+    let cards = super::cards::PmCards {
+        state,
+        merchant_context,
+    };
+
+    let (response, duplication_check) = Box::pin(cards.add_card_to_locker(
+        payment_method_create.clone(),
+        &card,
+        &payment_method.customer_id,
+        None,
+    ))
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to add the replaced card to locker")?;
+
+    if response.payment_method_id == locker_id {
+        if duplication_check == Some(super::transformers::DataDuplicationCheck::MetaDataChanged) {
+            replace_card_in_locker_with_same_reference(
+                &cards,
+                payment_method_create,
+                &payment_method.customer_id,
+                &locker_id,
+                &existing_card,
+                &card,
+            )
+            .await?;
+        }
+        return Ok(payment_method);
+    }
+
+    let payment_method = state
+        .store
+        .update_payment_method(
+            &state.into(),
+            merchant_context.get_merchant_key_store(),
+            payment_method,
+            storage::PaymentMethodUpdate::LockerIdUpdate {
+                locker_id: Some(response.payment_method_id),
+            },
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the locker reference of the payment method")?;
+
+    // The payment method no longer refers to the old card, so a failure to delete it only leaves
+    // an unused card in the locker
+    if let Err(error) = cards
+        .delete_card_from_locker(
+            &payment_method.customer_id,
+            &payment_method.merchant_id,
+            &locker_id,
+        )
+        .await
+    {
+        logger::error!(
+            payment_method_id = %payment_method.payment_method_id,
+            ?error,
+            "Failed to delete the replaced card from locker"
+        );
+    }
+
+    Ok(payment_method)
+}
+
+/// Replaces the card stored in the locker under the reference, adding the existing card back
+/// under the same reference if the new card cannot be added
+#[cfg(feature = "v1")]
+async fn replace_card_in_locker_with_same_reference(
+    cards: &super::cards::PmCards<'_>,
+    payment_method_create: api::PaymentMethodCreate,
+    customer_id: &id_type::CustomerId,
+    locker_id: &str,
+    existing_card: &api::CardDetail,
+    card: &api::CardDetail,
+) -> RouterResult<()> {
+    cards
+        .delete_card_from_locker(
+            customer_id,
+            cards.merchant_context.get_merchant_account().get_id(),
+            locker_id,
+        )
+        .await?;
+
+    let add_card_result = Box::pin(cards.add_card_to_locker(
+        payment_method_create.clone(),
+        card,
+        customer_id,
+        Some(locker_id),
+    ))
+    .await;
+
+    if let Err(error) = add_card_result {
+        logger::error!(?error, "Failed to add the replaced card to locker");
+
+        Box::pin(cards.add_card_to_locker(
+            payment_method_create,
+            existing_card,
+            customer_id,
+            Some(locker_id),
+        ))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to restore the card in locker")?;
+
+        return Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Failed to add the replaced card to locker");
+    }
+
+    Ok(())
+}
+
 // ********************************************** PROCESS TRACKER **********************************************
 
 pub async fn add_delete_tokenized_data_task(
@@ -0,0 +1,365 @@
+//! Adapter interface for external vaults.
+//!
+//! An external vault adapter stores payment method data outside of Hyperswitch and exposes the
+//! operations the payment method core relies on: storing, retrieving and deleting vaulted data,
+//! generating fingerprints for duplicate detection and forwarding requests to third parties with
+//! the vaulted data substituted in. Every adapter is expected to pass the checks in the
+//! `conformance` module, which is built for tests and with the `vault_conformance` feature.
+//!
+//! When the locker is mocked, the requests to the vault service and to the external vault
+//! connectors are served by the [`mock_locker`] adapter.
+
+#[cfg(any(test, feature = "vault_conformance"))]
+pub mod conformance;
+pub mod mock_locker;
+
+use std::collections::BTreeMap;
+
+use common_utils::{
+    crypto::{HmacSha512, SignMessage},
+    id_type,
+    request::{self, Method},
+};
+use error_stack::{report, ResultExt};
+#[cfg(feature = "v2")]
+use masking::ExposeInterface;
+use masking::{Maskable, PeekInterface, Secret};
+
+#[cfg(feature = "v2")]
+use crate::types::{domain, payment_methods as pm_types};
+use crate::{
+    core::errors::{self, CustomResult},
+    routes::SessionState,
+    services, types,
+};
+
+/// Flow name of the requests forwarded by a vault proxy
+const VAULT_PROXY_FORWARD_FLOW: &str = "VaultProxyForward";
+
+/// Prefix of a placeholder referring to vaulted data in a proxy request, as in `{{$card_number}}`
+pub const PROXY_PLACEHOLDER_PREFIX: &str = "{{$";
+
+/// Suffix of a placeholder referring to vaulted data in a proxy request
+pub const PROXY_PLACEHOLDER_SUFFIX: &str = "}}";
+
+#[derive(Debug, Clone)]
+pub struct ExternalVaultStoreRequest {
+    pub merchant_id: id_type::MerchantId,
+    /// Identifier to store the data under, replacing any data the merchant has stored under it.
+    /// The vault generates an identifier if this is not provided.
+    pub vault_id: Option<String>,
+    pub customer_id: Option<id_type::CustomerId>,
+    pub data: Secret<serde_json::Value>,
+    pub fingerprint_key: Secret<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExternalVaultStoreResponse {
+    pub vault_id: String,
+    pub fingerprint_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExternalVaultRetrieveRequest {
+    pub merchant_id: id_type::MerchantId,
+    pub vault_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExternalVaultRetrieveResponse {
+    pub data: Secret<serde_json::Value>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExternalVaultDeleteRequest {
+    pub merchant_id: id_type::MerchantId,
+    pub vault_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExternalVaultDeleteResponse {
+    pub vault_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExternalVaultFingerprintRequest {
+    pub data: Secret<serde_json::Value>,
+    pub fingerprint_key: Secret<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExternalVaultFingerprintResponse {
+    pub fingerprint_id: String,
+}
+
+/// Request to be forwarded to `destination_url` after the placeholders in `body` are replaced
+/// with the vaulted data identified by `vault_id`
+#[derive(Debug, Clone)]
+pub struct ExternalVaultProxyRequest {
+    pub merchant_id: id_type::MerchantId,
+    pub vault_id: String,
+    pub destination_url: String,
+    pub method: Method,
+    pub headers: Vec<(String, Maskable<String>)>,
+    pub body: serde_json::Value,
+}
+
+#[async_trait::async_trait]
+pub trait ExternalVaultAdapter: Send + Sync {
+    /// Name of the adapter, used in logs and conformance failures
+    fn get_adapter_name(&self) -> &'static str;
+
+    /// Stores the data in the vault and returns the identifier to retrieve it with
+    async fn store(
+        &self,
+        request: ExternalVaultStoreRequest,
+    ) -> CustomResult<ExternalVaultStoreResponse, errors::VaultError>;
+
+    /// Retrieves the data stored for the merchant under the vault identifier
+    async fn retrieve(
+        &self,
+        request: ExternalVaultRetrieveRequest,
+    ) -> CustomResult<ExternalVaultRetrieveResponse, errors::VaultError>;
+
+    /// Deletes the data stored for the merchant under the vault identifier
+    async fn delete(
+        &self,
+        request: ExternalVaultDeleteRequest,
+    ) -> CustomResult<ExternalVaultDeleteResponse, errors::VaultError>;
+
+    /// Generates a fingerprint of the data without storing it in the vault
+    async fn get_fingerprint(
+        &self,
+        request: ExternalVaultFingerprintRequest,
+    ) -> CustomResult<ExternalVaultFingerprintResponse, errors::VaultError>;
+
+    /// Forwards the request to the destination with the vaulted data substituted in
+    async fn proxy_forward(
+        &self,
+        request: ExternalVaultProxyRequest,
+    ) -> CustomResult<types::Response, errors::VaultError>;
+}
+
+/// Transport used by adapters which forward proxy requests from within the application
+#[async_trait::async_trait]
+pub trait VaultProxyTransport: Send + Sync {
+    async fn send(
+        &self,
+        request: request::Request,
+    ) -> CustomResult<types::Response, errors::VaultError>;
+}
+
+#[async_trait::async_trait]
+impl VaultProxyTransport for SessionState {
+    async fn send(
+        &self,
+        request: request::Request,
+    ) -> CustomResult<types::Response, errors::VaultError> {
+        services::call_connector_api(self, request, VAULT_PROXY_FORWARD_FLOW)
+            .await
+            .change_context(errors::VaultError::VaultAPIError)
+            .attach_printable("Failed to forward the proxy request")?
+            .map_err(|error_response| {
+                report!(errors::VaultError::VaultAPIError).attach_printable(format!(
+                    "Proxy destination responded with status code {}",
+                    error_response.status_code
+                ))
+            })
+    }
+}
+
+/// Adapter used in place of the vault service and of the external vault connectors when the
+/// locker is mocked
+#[cfg(feature = "v2")]
+pub fn get_mock_locker_vault_adapter(
+    state: &SessionState,
+) -> mock_locker::MockLockerVaultAdapter<'_> {
+    mock_locker::MockLockerVaultAdapter::new(&*state.store, state)
+}
+
+/// Stores the payment method data in the vault. The data is fingerprinted with a key scoped to
+/// the merchant, as the vault service does.
+#[cfg(feature = "v2")]
+pub async fn add_vaulting_data(
+    adapter: &dyn ExternalVaultAdapter,
+    merchant_id: &id_type::MerchantId,
+    vault_id: Option<&domain::VaultId>,
+    data: serde_json::Value,
+) -> CustomResult<pm_types::AddVaultResponse, errors::VaultError> {
+    let response = adapter
+        .store(ExternalVaultStoreRequest {
+            merchant_id: merchant_id.to_owned(),
+            vault_id: vault_id.map(|vault_id| vault_id.get_string_repr().to_owned()),
+            customer_id: None,
+            data: Secret::new(data),
+            fingerprint_key: Secret::new(merchant_id.get_string_repr().to_owned()),
+        })
+        .await?;
+
+    Ok(pm_types::AddVaultResponse {
+        entity_id: Some(merchant_id.to_owned()),
+        vault_id: domain::VaultId::generate(response.vault_id),
+        fingerprint_id: Some(response.fingerprint_id),
+    })
+}
+
+#[cfg(feature = "v2")]
+pub async fn retrieve_vaulting_data(
+    adapter: &dyn ExternalVaultAdapter,
+    merchant_id: &id_type::MerchantId,
+    vault_id: &domain::VaultId,
+) -> CustomResult<serde_json::Value, errors::VaultError> {
+    adapter
+        .retrieve(ExternalVaultRetrieveRequest {
+            merchant_id: merchant_id.to_owned(),
+            vault_id: vault_id.get_string_repr().to_owned(),
+        })
+        .await
+        .map(|response| response.data.expose())
+}
+
+#[cfg(feature = "v2")]
+pub async fn delete_vaulting_data(
+    adapter: &dyn ExternalVaultAdapter,
+    merchant_id: &id_type::MerchantId,
+    vault_id: &domain::VaultId,
+) -> CustomResult<pm_types::VaultDeleteResponse, errors::VaultError> {
+    let response = adapter
+        .delete(ExternalVaultDeleteRequest {
+            merchant_id: merchant_id.to_owned(),
+            vault_id: vault_id.get_string_repr().to_owned(),
+        })
+        .await?;
+
+    Ok(pm_types::VaultDeleteResponse {
+        entity_id: merchant_id.to_owned(),
+        vault_id: domain::VaultId::generate(response.vault_id),
+    })
+}
+
+/// Parses the payload of a request to the vault service, so that it can be served by an adapter
+#[cfg(feature = "v2")]
+pub fn parse_vault_payload<T: serde::de::DeserializeOwned>(
+    payload: &[u8],
+) -> CustomResult<T, errors::VaultError> {
+    serde_json::from_slice(payload)
+        .change_context(errors::VaultError::RequestEncodingFailed)
+        .attach_printable("Failed to parse the vault request")
+}
+
+/// Encodes a response of an adapter the way the vault service responds
+#[cfg(feature = "v2")]
+pub fn encode_vault_response<T: serde::Serialize>(
+    response: &T,
+) -> CustomResult<String, errors::VaultError> {
+    serde_json::to_string(response)
+        .change_context(errors::VaultError::ResponseDeserializationFailed)
+        .attach_printable("Failed to encode the vault response")
+}
+
+/// Generates a fingerprint of the data which does not depend on the order of the object keys
+pub fn generate_fingerprint(
+    data: &serde_json::Value,
+    fingerprint_key: &Secret<String>,
+) -> CustomResult<String, errors::VaultError> {
+    let canonical_data = serde_json::to_vec(&canonicalize(data))
+        .change_context(errors::VaultError::RequestEncodingFailed)
+        .attach_printable("Failed to encode the data to be fingerprinted")?;
+
+    HmacSha512
+        .sign_message(fingerprint_key.peek().as_bytes(), &canonical_data)
+        .change_context(errors::VaultError::GenerateFingerprintFailed)
+        .map(hex::encode)
+}
+
+fn canonicalize(value: &serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => serde_json::Value::Object(
+            map.iter()
+                .map(|(key, value)| (key.clone(), canonicalize(value)))
+                .collect::<BTreeMap<_, _>>()
+                .into_iter()
+                .collect(),
+        ),
+        serde_json::Value::Array(values) => {
+            serde_json::Value::Array(values.iter().map(canonicalize).collect())
+        }
+        serde_json::Value::Null
+        | serde_json::Value::Bool(_)
+        | serde_json::Value::Number(_)
+        | serde_json::Value::String(_) => value.clone(),
+    }
+}
+
+/// Replaces the placeholders in the request template with the vaulted data.
+///
+/// A placeholder holds a `.` separated path into the vaulted data, as in `{{$card.card_number}}`.
+/// A string consisting of a single placeholder is replaced by the referenced value as is, while
+/// placeholders embedded in a longer string are replaced by the string form of the value.
+pub fn substitute_vaulted_data(
+    template: &serde_json::Value,
+    vaulted_data: &serde_json::Value,
+) -> CustomResult<serde_json::Value, errors::VaultError> {
+    match template {
+        serde_json::Value::String(template) => substitute_in_string(template, vaulted_data),
+        serde_json::Value::Object(map) => map
+            .iter()
+            .map(|(key, value)| {
+                substitute_vaulted_data(value, vaulted_data).map(|value| (key.clone(), value))
+            })
+            .collect::<Result<serde_json::Map<_, _>, _>>()
+            .map(serde_json::Value::Object),
+        serde_json::Value::Array(values) => values
+            .iter()
+            .map(|value| substitute_vaulted_data(value, vaulted_data))
+            .collect::<Result<Vec<_>, _>>()
+            .map(serde_json::Value::Array),
+        serde_json::Value::Null | serde_json::Value::Bool(_) | serde_json::Value::Number(_) => {
+            Ok(template.clone())
+        }
+    }
+}
+
+fn substitute_in_string(
+    template: &str,
+    vaulted_data: &serde_json::Value,
+) -> CustomResult<serde_json::Value, errors::VaultError> {
+    if let Some(path) = template
+        .strip_prefix(PROXY_PLACEHOLDER_PREFIX)
+        .and_then(|rest| rest.strip_suffix(PROXY_PLACEHOLDER_SUFFIX))
+        .filter(|path| !path.contains(PROXY_PLACEHOLDER_SUFFIX))
+    {
+        return lookup_vaulted_value(path, vaulted_data).cloned();
+    }
+
+    let mut substituted = String::with_capacity(template.len());
+    let mut remaining = template;
+
+    while let Some((before, after_prefix)) = remaining.split_once(PROXY_PLACEHOLDER_PREFIX) {
+        let (path, after_placeholder) = after_prefix
+            .split_once(PROXY_PLACEHOLDER_SUFFIX)
+            .ok_or(errors::VaultError::RequestEncodingFailed)
+            .attach_printable("Unterminated placeholder in the proxy request")?;
+
+        substituted.push_str(before);
+        match lookup_vaulted_value(path, vaulted_data)? {
+            serde_json::Value::String(value) => substituted.push_str(value),
+            value => substituted.push_str(&value.to_string()),
+        }
+        remaining = after_placeholder;
+    }
+    substituted.push_str(remaining);
+
+    Ok(serde_json::Value::String(substituted))
+}
+
+fn lookup_vaulted_value<'a>(
+    path: &str,
+    vaulted_data: &'a serde_json::Value,
+) -> CustomResult<&'a serde_json::Value, errors::VaultError> {
+    path.trim()
+        .split('.')
+        .try_fold(vaulted_data, |value, key| value.get(key))
+        .ok_or_else(|| report!(errors::VaultError::FetchPaymentMethodFailed))
+        .attach_printable_lazy(|| format!("Vaulted data does not contain the field `{path}`"))
+}
@@ -0,0 +1,518 @@
+//! Conformance checks every external vault adapter is expected to pass.
+//!
+//! The checks exercise the adapter only through [`ExternalVaultAdapter`], so they can be run
+//! against any implementation from its tests with [`run_conformance_suite`].
+
+use common_utils::{id_type, request::Method};
+use masking::{PeekInterface, Secret};
+
+use super::{
+    ExternalVaultAdapter, ExternalVaultDeleteRequest, ExternalVaultFingerprintRequest,
+    ExternalVaultProxyRequest, ExternalVaultRetrieveRequest, ExternalVaultStoreRequest,
+};
+
+pub struct ConformanceContext {
+    /// Merchant for which the data is vaulted
+    pub merchant_id: id_type::MerchantId,
+    /// Merchant which must not be able to access the data vaulted for `merchant_id`
+    pub other_merchant_id: id_type::MerchantId,
+    pub fingerprint_key: Secret<String>,
+    /// Endpoint which responds with the body of the request it received
+    pub proxy_echo_url: String,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Vault adapter `{adapter}` failed the `{check}` conformance check: {reason}")]
+pub struct ConformanceFailure {
+    pub adapter: &'static str,
+    pub check: &'static str,
+    pub reason: String,
+}
+
+type CheckResult = Result<(), String>;
+
+/// Runs all the conformance checks against the adapter, stopping at the first failure
+pub async fn run_conformance_suite(
+    adapter: &dyn ExternalVaultAdapter,
+    context: &ConformanceContext,
+) -> Result<(), ConformanceFailure> {
+    let failure = move |check: &'static str| {
+        move |reason: String| ConformanceFailure {
+            adapter: adapter.get_adapter_name(),
+            check,
+            reason,
+        }
+    };
+
+    check_store_and_retrieve(adapter, context)
+        .await
+        .map_err(failure("store_and_retrieve"))?;
+    check_store_generates_unique_vault_ids(adapter, context)
+        .await
+        .map_err(failure("store_generates_unique_vault_ids"))?;
+    check_store_under_vault_id(adapter, context)
+        .await
+        .map_err(failure("store_under_vault_id"))?;
+    check_fingerprint(adapter, context)
+        .await
+        .map_err(failure("fingerprint"))?;
+    check_retrieve_unknown_vault_id(adapter, context)
+        .await
+        .map_err(failure("retrieve_unknown_vault_id"))?;
+    check_merchant_isolation(adapter, context)
+        .await
+        .map_err(failure("merchant_isolation"))?;
+    check_delete(adapter, context)
+        .await
+        .map_err(failure("delete"))?;
+    check_proxy_forward(adapter, context)
+        .await
+        .map_err(failure("proxy_forward"))?;
+    check_proxy_forward_unknown_field(adapter, context)
+        .await
+        .map_err(failure("proxy_forward_unknown_field"))?;
+
+    Ok(())
+}
+
+fn sample_card_data() -> serde_json::Value {
+    serde_json::json!({
+        "card": {
+            "card_number": "4242424242424242",
+            "card_exp_month": "03",
+            "card_exp_year": "2030",
+            "card_holder_name": "John Doe"
+        }
+    })
+}
+
+fn other_sample_card_data() -> serde_json::Value {
+    serde_json::json!({
+        "card": {
+            "card_number": "4000000000000002",
+            "card_exp_month": "11",
+            "card_exp_year": "2031",
+            "card_holder_name": "Jane Doe"
+        }
+    })
+}
+
+fn ensure(condition: bool, reason: &str) -> CheckResult {
+    if condition {
+        Ok(())
+    } else {
+        Err(reason.to_string())
+    }
+}
+
+async fn store(
+    adapter: &dyn ExternalVaultAdapter,
+    context: &ConformanceContext,
+    data: serde_json::Value,
+) -> Result<super::ExternalVaultStoreResponse, String> {
+    store_under_vault_id(adapter, &context.merchant_id, context, None, data).await
+}
+
+async fn store_under_vault_id(
+    adapter: &dyn ExternalVaultAdapter,
+    merchant_id: &id_type::MerchantId,
+    context: &ConformanceContext,
+    vault_id: Option<&str>,
+    data: serde_json::Value,
+) -> Result<super::ExternalVaultStoreResponse, String> {
+    adapter
+        .store(ExternalVaultStoreRequest {
+            merchant_id: merchant_id.clone(),
+            vault_id: vault_id.map(ToOwned::to_owned),
+            customer_id: None,
+            data: Secret::new(data),
+            fingerprint_key: context.fingerprint_key.clone(),
+        })
+        .await
+        .map_err(|error| format!("store failed: {}", error.current_context()))
+}
+
+async fn retrieve(
+    adapter: &dyn ExternalVaultAdapter,
+    merchant_id: &id_type::MerchantId,
+    vault_id: &str,
+) -> Result<serde_json::Value, String> {
+    adapter
+        .retrieve(ExternalVaultRetrieveRequest {
+            merchant_id: merchant_id.clone(),
+            vault_id: vault_id.to_string(),
+        })
+        .await
+        .map(|response| response.data.peek().clone())
+        .map_err(|error| format!("retrieve failed: {}", error.current_context()))
+}
+
+async fn delete(
+    adapter: &dyn ExternalVaultAdapter,
+    merchant_id: &id_type::MerchantId,
+    vault_id: &str,
+) -> Result<String, String> {
+    adapter
+        .delete(ExternalVaultDeleteRequest {
+            merchant_id: merchant_id.clone(),
+            vault_id: vault_id.to_string(),
+        })
+        .await
+        .map(|response| response.vault_id)
+        .map_err(|error| format!("delete failed: {}", error.current_context()))
+}
+
+async fn get_fingerprint(
+    adapter: &dyn ExternalVaultAdapter,
+    data: serde_json::Value,
+    fingerprint_key: &Secret<String>,
+) -> Result<String, String> {
+    adapter
+        .get_fingerprint(ExternalVaultFingerprintRequest {
+            data: Secret::new(data),
+            fingerprint_key: fingerprint_key.clone(),
+        })
+        .await
+        .map(|response| response.fingerprint_id)
+        .map_err(|error| format!("get_fingerprint failed: {}", error.current_context()))
+}
+
+async fn proxy_forward(
+    adapter: &dyn ExternalVaultAdapter,
+    context: &ConformanceContext,
+    merchant_id: &id_type::MerchantId,
+    vault_id: &str,
+    body: serde_json::Value,
+) -> Result<serde_json::Value, String> {
+    let response = adapter
+        .proxy_forward(ExternalVaultProxyRequest {
+            merchant_id: merchant_id.clone(),
+            vault_id: vault_id.to_string(),
+            destination_url: context.proxy_echo_url.clone(),
+            method: Method::Post,
+            headers: vec![(
+                "Content-Type".to_string(),
+                "application/json".to_string().into(),
+            )],
+            body,
+        })
+        .await
+        .map_err(|error| format!("proxy_forward failed: {}", error.current_context()))?;
+
+    ensure(
+        (200..300).contains(&response.status_code),
+        &format!(
+            "proxy_forward responded with status code {}",
+            response.status_code
+        ),
+    )?;
+
+    serde_json::from_slice(&response.response)
+        .map_err(|error| format!("proxy_forward response is not valid JSON: {error}"))
+}
+
+async fn check_store_and_retrieve(
+    adapter: &dyn ExternalVaultAdapter,
+    context: &ConformanceContext,
+) -> CheckResult {
+    let stored = store(adapter, context, sample_card_data()).await?;
+    ensure(
+        !stored.vault_id.is_empty(),
+        "store returned an empty vault id",
+    )?;
+    ensure(
+        !stored.fingerprint_id.is_empty(),
+        "store returned an empty fingerprint",
+    )?;
+
+    let retrieved = retrieve(adapter, &context.merchant_id, &stored.vault_id).await?;
+    ensure(
+        retrieved == sample_card_data(),
+        "retrieve returned data different from the stored data",
+    )?;
+
+    delete(adapter, &context.merchant_id, &stored.vault_id)
+        .await
+        .map(|_| ())
+}
+
+async fn check_store_generates_unique_vault_ids(
+    adapter: &dyn ExternalVaultAdapter,
+    context: &ConformanceContext,
+) -> CheckResult {
+    let first = store(adapter, context, sample_card_data()).await?;
+    let second = store(adapter, context, sample_card_data()).await?;
+
+    let result = ensure(
+        first.vault_id != second.vault_id,
+        "storing the same data twice returned the same vault id",
+    )
+    .and_then(|()| {
+        ensure(
+            first.fingerprint_id == second.fingerprint_id,
+            "storing the same data twice returned different fingerprints",
+        )
+    });
+
+    delete(adapter, &context.merchant_id, &first.vault_id).await?;
+    delete(adapter, &context.merchant_id, &second.vault_id).await?;
+
+    result
+}
+
+async fn check_store_under_vault_id(
+    adapter: &dyn ExternalVaultAdapter,
+    context: &ConformanceContext,
+) -> CheckResult {
+    let vault_id = uuid::Uuid::new_v4().to_string();
+
+    let stored = store_under_vault_id(
+        adapter,
+        &context.merchant_id,
+        context,
+        Some(&vault_id),
+        sample_card_data(),
+    )
+    .await?;
+
+    let result = async {
+        ensure(
+            stored.vault_id == vault_id,
+            "store did not use the provided vault id",
+        )?;
+
+        let replaced = store_under_vault_id(
+            adapter,
+            &context.merchant_id,
+            context,
+            Some(&vault_id),
+            other_sample_card_data(),
+        )
+        .await?;
+        ensure(
+            replaced.vault_id == vault_id,
+            "store did not use the provided vault id when replacing the data",
+        )?;
+        ensure(
+            retrieve(adapter, &context.merchant_id, &vault_id).await? == other_sample_card_data(),
+            "store did not replace the data stored under the provided vault id",
+        )?;
+
+        // Another merchant may or may not be allowed to use the same vault id, but must not
+        // replace the data stored under it
+        if let Ok(other_merchant_stored) = store_under_vault_id(
+            adapter,
+            &context.other_merchant_id,
+            context,
+            Some(&vault_id),
+            sample_card_data(),
+        )
+        .await
+        {
+            let _ = delete(
+                adapter,
+                &context.other_merchant_id,
+                &other_merchant_stored.vault_id,
+            )
+            .await;
+        }
+        ensure(
+            retrieve(adapter, &context.merchant_id, &vault_id).await? == other_sample_card_data(),
+            "store replaced the data stored under the vault id by another merchant",
+        )
+    }
+    .await;
+
+    delete(adapter, &context.merchant_id, &vault_id).await?;
+
+    result
+}
+
+async fn check_fingerprint(
+    adapter: &dyn ExternalVaultAdapter,
+    context: &ConformanceContext,
+) -> CheckResult {
+    let fingerprint =
+        get_fingerprint(adapter, sample_card_data(), &context.fingerprint_key).await?;
+    let repeated_fingerprint =
+        get_fingerprint(adapter, sample_card_data(), &context.fingerprint_key).await?;
+    ensure(
+        fingerprint == repeated_fingerprint,
+        "get_fingerprint is not deterministic",
+    )?;
+
+    let other_data_fingerprint =
+        get_fingerprint(adapter, other_sample_card_data(), &context.fingerprint_key).await?;
+    ensure(
+        fingerprint != other_data_fingerprint,
+        "get_fingerprint returned the same fingerprint for different data",
+    )?;
+
+    let other_key = Secret::new(format!("{}_other", context.fingerprint_key.peek()));
+    let other_key_fingerprint = get_fingerprint(adapter, sample_card_data(), &other_key).await?;
+    ensure(
+        fingerprint != other_key_fingerprint,
+        "get_fingerprint returned the same fingerprint for different keys",
+    )?;
+
+    let stored = store(adapter, context, sample_card_data()).await?;
+    delete(adapter, &context.merchant_id, &stored.vault_id).await?;
+    ensure(
+        stored.fingerprint_id == fingerprint,
+        "store and get_fingerprint returned different fingerprints for the same data",
+    )
+}
+
+async fn check_retrieve_unknown_vault_id(
+    adapter: &dyn ExternalVaultAdapter,
+    context: &ConformanceContext,
+) -> CheckResult {
+    let unknown_vault_id = uuid::Uuid::new_v4().to_string();
+
+    ensure(
+        retrieve(adapter, &context.merchant_id, &unknown_vault_id)
+            .await
+            .is_err(),
+        "retrieve succeeded for an unknown vault id",
+    )?;
+    ensure(
+        delete(adapter, &context.merchant_id, &unknown_vault_id)
+            .await
+            .is_err(),
+        "delete succeeded for an unknown vault id",
+    )
+}
+
+async fn check_merchant_isolation(
+    adapter: &dyn ExternalVaultAdapter,
+    context: &ConformanceContext,
+) -> CheckResult {
+    let stored = store(adapter, context, sample_card_data()).await?;
+
+    let result = async {
+        ensure(
+            retrieve(adapter, &context.other_merchant_id, &stored.vault_id)
+                .await
+                .is_err(),
+            "retrieve succeeded for data vaulted by another merchant",
+        )?;
+        ensure(
+            proxy_forward(
+                adapter,
+                context,
+                &context.other_merchant_id,
+                &stored.vault_id,
+                serde_json::json!({ "number": "{{$card.card_number}}" }),
+            )
+            .await
+            .is_err(),
+            "proxy_forward succeeded for data vaulted by another merchant",
+        )?;
+        ensure(
+            delete(adapter, &context.other_merchant_id, &stored.vault_id)
+                .await
+                .is_err(),
+            "delete succeeded for data vaulted by another merchant",
+        )?;
+        retrieve(adapter, &context.merchant_id, &stored.vault_id)
+            .await
+            .map(|_| ())
+            .map_err(|reason| format!("{reason} after another merchant attempted a delete"))
+    }
+    .await;
+
+    // The data would already be deleted if the adapter let another merchant delete it
+    let _ = delete(adapter, &context.merchant_id, &stored.vault_id).await;
+
+    result
+}
+
+async fn check_delete(
+    adapter: &dyn ExternalVaultAdapter,
+    context: &ConformanceContext,
+) -> CheckResult {
+    let stored = store(adapter, context, sample_card_data()).await?;
+
+    let deleted_vault_id = delete(adapter, &context.merchant_id, &stored.vault_id).await?;
+    ensure(
+        deleted_vault_id == stored.vault_id,
+        "delete returned a vault id different from the deleted one",
+    )?;
+    ensure(
+        retrieve(adapter, &context.merchant_id, &stored.vault_id)
+            .await
+            .is_err(),
+        "retrieve succeeded for deleted data",
+    )?;
+    ensure(
+        delete(adapter, &context.merchant_id, &stored.vault_id)
+            .await
+            .is_err(),
+        "delete succeeded for already deleted data",
+    )
+}
+
+async fn check_proxy_forward(
+    adapter: &dyn ExternalVaultAdapter,
+    context: &ConformanceContext,
+) -> CheckResult {
+    let stored = store(adapter, context, sample_card_data()).await?;
+
+    let result = proxy_forward(
+        adapter,
+        context,
+        &context.merchant_id,
+        &stored.vault_id,
+        serde_json::json!({
+            "source": {
+                "number": "{{$card.card_number}}",
+                "expiry": "{{$card.card_exp_month}}/{{$card.card_exp_year}}"
+            },
+            "card": "{{$card}}",
+            "reference": "order_1"
+        }),
+    )
+    .await
+    .and_then(|echoed_body| {
+        ensure(
+            echoed_body
+                == serde_json::json!({
+                    "source": {
+                        "number": "4242424242424242",
+                        "expiry": "03/2030"
+                    },
+                    "card": sample_card_data().get("card"),
+                    "reference": "order_1"
+                }),
+            "proxy_forward did not substitute the vaulted data into the forwarded request",
+        )
+    });
+
+    delete(adapter, &context.merchant_id, &stored.vault_id).await?;
+
+    result
+}
+
+async fn check_proxy_forward_unknown_field(
+    adapter: &dyn ExternalVaultAdapter,
+    context: &ConformanceContext,
+) -> CheckResult {
+    let stored = store(adapter, context, sample_card_data()).await?;
+
+    let result = ensure(
+        proxy_forward(
+            adapter,
+            context,
+            &context.merchant_id,
+            &stored.vault_id,
+            serde_json::json!({ "cvc": "{{$card.card_cvc}}" }),
+        )
+        .await
+        .is_err(),
+        "proxy_forward succeeded with a placeholder for a field missing from the vaulted data",
+    );
+
+    delete(adapter, &context.merchant_id, &stored.vault_id).await?;
+
+    result
+}
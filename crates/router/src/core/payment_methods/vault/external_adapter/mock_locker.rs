@@ -0,0 +1,392 @@
+//! Reference implementation of an external vault adapter backed by the `locker_mock_up` table.
+//!
+//! The vaulted data is encrypted with the master key of the store before it is written to the
+//! table, the same way a vault holds the data encrypted with its own key.
+
+use common_utils::{
+    crypto::{DecodeMessage, EncodeMessage, GcmAes256},
+    request,
+};
+use error_stack::{report, ResultExt};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, tracing};
+
+use super::{
+    generate_fingerprint, substitute_vaulted_data, ExternalVaultAdapter,
+    ExternalVaultDeleteRequest, ExternalVaultDeleteResponse, ExternalVaultFingerprintRequest,
+    ExternalVaultFingerprintResponse, ExternalVaultProxyRequest, ExternalVaultRetrieveRequest,
+    ExternalVaultRetrieveResponse, ExternalVaultStoreRequest, ExternalVaultStoreResponse,
+    VaultProxyTransport,
+};
+use crate::{
+    consts,
+    core::errors::{self, CustomResult},
+    db, types,
+    types::storage,
+};
+
+pub struct MockLockerVaultAdapter<'a> {
+    db: &'a dyn db::StorageInterface,
+    proxy_transport: &'a dyn VaultProxyTransport,
+}
+
+impl<'a> MockLockerVaultAdapter<'a> {
+    pub fn new(
+        db: &'a dyn db::StorageInterface,
+        proxy_transport: &'a dyn VaultProxyTransport,
+    ) -> Self {
+        Self {
+            db,
+            proxy_transport,
+        }
+    }
+
+    async fn find_locker_entry(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        vault_id: &str,
+    ) -> CustomResult<storage::LockerMockUp, errors::VaultError> {
+        let locker_mock_up = self
+            .db
+            .find_locker_by_card_id(vault_id)
+            .await
+            .change_context(errors::VaultError::FetchPaymentMethodFailed)
+            .attach_printable("Failed to find the vaulted data in the mock locker")?;
+
+        // Data vaulted by other merchants is reported the same way as missing data
+        if &locker_mock_up.merchant_id != merchant_id {
+            return Err(report!(errors::VaultError::FetchPaymentMethodFailed))
+                .attach_printable("Failed to find the vaulted data in the mock locker");
+        }
+
+        Ok(locker_mock_up)
+    }
+
+    async fn retrieve_vaulted_data(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        vault_id: &str,
+    ) -> CustomResult<serde_json::Value, errors::VaultError> {
+        let encrypted_data = self
+            .find_locker_entry(merchant_id, vault_id)
+            .await?
+            .enc_card_data
+            .ok_or(errors::VaultError::FetchPaymentMethodFailed)
+            .attach_printable("Mock locker entry does not contain any vaulted data")?;
+
+        let encrypted_data = hex::decode(encrypted_data)
+            .change_context(errors::VaultError::ResponseDeserializationFailed)
+            .attach_printable("Failed to decode the vaulted data from the mock locker")?;
+
+        let data = GcmAes256
+            .decode_message(self.db.get_master_key(), Secret::new(encrypted_data))
+            .change_context(errors::VaultError::ResponseDecryptionFailed)
+            .attach_printable("Failed to decrypt the vaulted data from the mock locker")?;
+
+        serde_json::from_slice(&data)
+            .change_context(errors::VaultError::ResponseDeserializationFailed)
+            .attach_printable("Failed to parse the vaulted data from the mock locker")
+    }
+
+    fn encrypt_vaulted_data(
+        &self,
+        data: &serde_json::Value,
+    ) -> CustomResult<String, errors::VaultError> {
+        let data = serde_json::to_vec(data)
+            .change_context(errors::VaultError::RequestEncodingFailed)
+            .attach_printable("Failed to encode the data to be vaulted")?;
+
+        GcmAes256
+            .encode_message(self.db.get_master_key(), &data)
+            .change_context(errors::VaultError::RequestEncryptionFailed)
+            .attach_printable("Failed to encrypt the data to be vaulted")
+            .map(hex::encode)
+    }
+}
+
+#[async_trait::async_trait]
+impl ExternalVaultAdapter for MockLockerVaultAdapter<'_> {
+    fn get_adapter_name(&self) -> &'static str {
+        "mock_locker"
+    }
+
+    #[instrument(skip_all)]
+    async fn store(
+        &self,
+        request: ExternalVaultStoreRequest,
+    ) -> CustomResult<ExternalVaultStoreResponse, errors::VaultError> {
+        let fingerprint_id = generate_fingerprint(request.data.peek(), &request.fingerprint_key)?;
+        let encrypted_data = self.encrypt_vaulted_data(request.data.peek())?;
+
+        // The data stored by the merchant under the vault id is replaced in place, while the
+        // data of other merchants is left untouched and fails the insert below
+        if let Some(vault_id) = request.vault_id.as_deref() {
+            match self
+                .db
+                .update_locker_mock_up_by_merchant_id_card_id(
+                    &request.merchant_id,
+                    vault_id,
+                    storage::LockerMockUpUpdate {
+                        card_fingerprint: fingerprint_id.clone(),
+                        card_global_fingerprint: fingerprint_id.clone(),
+                        customer_id: request.customer_id.clone(),
+                        enc_card_data: Some(encrypted_data.clone()),
+                    },
+                )
+                .await
+            {
+                Ok(locker_mock_up) => {
+                    return Ok(ExternalVaultStoreResponse {
+                        vault_id: locker_mock_up.card_id,
+                        fingerprint_id,
+                    })
+                }
+                Err(error) if error.current_context().is_db_not_found() => {}
+                Err(error) => Err(error
+                    .change_context(errors::VaultError::SavePaymentMethodFailed)
+                    .attach_printable("Failed to replace the vaulted data in the mock locker"))?,
+            }
+        }
+
+        let locker_mock_up = storage::LockerMockUpNew {
+            card_id: request
+                .vault_id
+                .unwrap_or_else(|| common_utils::generate_id(consts::ID_LENGTH, "vault")),
+            external_id: uuid::Uuid::new_v4().to_string(),
+            card_fingerprint: fingerprint_id.clone(),
+            card_global_fingerprint: fingerprint_id.clone(),
+            merchant_id: request.merchant_id,
+            card_number: String::new(),
+            card_exp_year: String::new(),
+            card_exp_month: String::new(),
+            name_on_card: None,
+            card_cvc: None,
+            payment_method_id: None,
+            customer_id: request.customer_id,
+            nickname: None,
+            enc_card_data: Some(encrypted_data),
+        };
+
+        let locker_mock_up = self
+            .db
+            .insert_locker_mock_up(locker_mock_up)
+            .await
+            .change_context(errors::VaultError::SavePaymentMethodFailed)
+            .attach_printable("Failed to insert the vaulted data into the mock locker")?;
+
+        Ok(ExternalVaultStoreResponse {
+            vault_id: locker_mock_up.card_id,
+            fingerprint_id,
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn retrieve(
+        &self,
+        request: ExternalVaultRetrieveRequest,
+    ) -> CustomResult<ExternalVaultRetrieveResponse, errors::VaultError> {
+        self.retrieve_vaulted_data(&request.merchant_id, &request.vault_id)
+            .await
+            .map(|data| ExternalVaultRetrieveResponse {
+                data: Secret::new(data),
+            })
+    }
+
+    #[instrument(skip_all)]
+    async fn delete(
+        &self,
+        request: ExternalVaultDeleteRequest,
+    ) -> CustomResult<ExternalVaultDeleteResponse, errors::VaultError> {
+        // Deleting by the merchant and the vault id together leaves the data vaulted by other
+        // merchants untouched
+        let locker_mock_up = self
+            .db
+            .delete_locker_mock_up_by_merchant_id_card_id(&request.merchant_id, &request.vault_id)
+            .await
+            .change_context(errors::VaultError::DeleteCardFailed)
+            .attach_printable("Failed to delete the vaulted data from the mock locker")?;
+
+        Ok(ExternalVaultDeleteResponse {
+            vault_id: locker_mock_up.card_id,
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn get_fingerprint(
+        &self,
+        request: ExternalVaultFingerprintRequest,
+    ) -> CustomResult<ExternalVaultFingerprintResponse, errors::VaultError> {
+        generate_fingerprint(request.data.peek(), &request.fingerprint_key)
+            .map(|fingerprint_id| ExternalVaultFingerprintResponse { fingerprint_id })
+    }
+
+    #[instrument(skip_all)]
+    async fn proxy_forward(
+        &self,
+        request: ExternalVaultProxyRequest,
+    ) -> CustomResult<types::Response, errors::VaultError> {
+        let vaulted_data = self
+            .retrieve_vaulted_data(&request.merchant_id, &request.vault_id)
+            .await?;
+        let body = substitute_vaulted_data(&request.body, &vaulted_data)?;
+
+        let mut proxy_request = request::Request::new(request.method, &request.destination_url);
+        proxy_request.add_default_headers();
+        for (header, value) in request.headers {
+            proxy_request.add_header(&header, value);
+        }
+        proxy_request.set_body(request::RequestContent::Json(Box::new(body)));
+
+        self.proxy_transport.send(proxy_request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_utils::{id_type, request};
+    use masking::{ExposeInterface, Secret};
+    use storage_impl::MockDb;
+
+    use super::MockLockerVaultAdapter;
+    use crate::{
+        core::{
+            errors::{self, CustomResult},
+            payment_methods::vault::external_adapter::{
+                conformance, ExternalVaultAdapter, ExternalVaultStoreRequest, VaultProxyTransport,
+            },
+        },
+        db::locker_mock_up::LockerMockUpInterface,
+        types,
+    };
+
+    /// Transport which responds with the body of the request it was asked to send
+    struct EchoTransport;
+
+    #[async_trait::async_trait]
+    impl VaultProxyTransport for EchoTransport {
+        async fn send(
+            &self,
+            request: request::Request,
+        ) -> CustomResult<types::Response, errors::VaultError> {
+            let body = request
+                .body
+                .map(|body| body.get_inner_value().expose())
+                .unwrap_or_default();
+
+            Ok(types::Response {
+                headers: None,
+                response: body.into(),
+                status_code: 200,
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn mock_locker_vault_adapter_passes_conformance_suite() {
+        #[allow(clippy::expect_used)]
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        let adapter = MockLockerVaultAdapter::new(&mockdb, &EchoTransport);
+
+        #[allow(clippy::expect_used)]
+        let context = conformance::ConformanceContext {
+            merchant_id: id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .expect("Failed to create merchant id"),
+            other_merchant_id: id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_2"))
+                .expect("Failed to create merchant id"),
+            fingerprint_key: Secret::new("fingerprint_key".to_string()),
+            proxy_echo_url: "https://echo.example.com/post".to_string(),
+        };
+
+        let result = conformance::run_conformance_suite(&adapter, &context).await;
+
+        assert!(result.is_ok(), "{result:?}");
+    }
+
+    #[tokio::test]
+    async fn mock_locker_vault_adapter_does_not_store_plain_data() {
+        #[allow(clippy::expect_used)]
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        let adapter = MockLockerVaultAdapter::new(&mockdb, &EchoTransport);
+
+        #[allow(clippy::expect_used)]
+        let stored = adapter
+            .store(ExternalVaultStoreRequest {
+                merchant_id: id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                    .expect("Failed to create merchant id"),
+                vault_id: None,
+                customer_id: None,
+                data: Secret::new(serde_json::json!({ "card_number": "4242424242424242" })),
+                fingerprint_key: Secret::new("fingerprint_key".to_string()),
+            })
+            .await
+            .expect("Failed to store the data");
+
+        #[allow(clippy::expect_used)]
+        let encrypted_data = mockdb
+            .find_locker_by_card_id(&stored.vault_id)
+            .await
+            .expect("Failed to find the locker entry")
+            .enc_card_data
+            .expect("Locker entry does not contain any vaulted data");
+
+        assert!(!encrypted_data.contains("4242424242424242"));
+        assert!(serde_json::from_str::<serde_json::Value>(&encrypted_data).is_err());
+    }
+
+    #[tokio::test]
+    async fn mock_locker_vault_adapter_replaces_vaulted_data_in_place() {
+        #[allow(clippy::expect_used)]
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        let adapter = MockLockerVaultAdapter::new(&mockdb, &EchoTransport);
+        #[allow(clippy::expect_used)]
+        let merchant_id = id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+            .expect("Failed to create merchant id");
+        let get_store_request =
+            |vault_id: Option<String>, card_number: &str| ExternalVaultStoreRequest {
+                merchant_id: merchant_id.clone(),
+                vault_id,
+                customer_id: None,
+                data: Secret::new(serde_json::json!({ "card_number": card_number })),
+                fingerprint_key: Secret::new("fingerprint_key".to_string()),
+            };
+
+        #[allow(clippy::expect_used)]
+        let stored = adapter
+            .store(get_store_request(None, "4242424242424242"))
+            .await
+            .expect("Failed to store the data");
+        #[allow(clippy::expect_used)]
+        let locker_entry = mockdb
+            .find_locker_by_card_id(&stored.vault_id)
+            .await
+            .expect("Failed to find the locker entry");
+
+        #[allow(clippy::expect_used)]
+        let replaced = adapter
+            .store(get_store_request(
+                Some(stored.vault_id.clone()),
+                "4000056655665556",
+            ))
+            .await
+            .expect("Failed to replace the data");
+        #[allow(clippy::expect_used)]
+        let replaced_locker_entry = mockdb
+            .find_locker_by_card_id(&stored.vault_id)
+            .await
+            .expect("Failed to find the locker entry");
+
+        assert_eq!(replaced.vault_id, stored.vault_id);
+        assert_ne!(replaced.fingerprint_id, stored.fingerprint_id);
+        assert_eq!(replaced_locker_entry.external_id, locker_entry.external_id);
+        assert_ne!(
+            replaced_locker_entry.enc_card_data,
+            locker_entry.enc_card_data
+        );
+    }
+}
@@ -1,8 +1,12 @@
 pub mod access_token;
 pub mod conditional_configs;
 pub mod customers;
+#[cfg(feature = "v1")]
+pub mod dynamic_currency_conversion;
 pub mod flows;
 pub mod helpers;
+#[cfg(feature = "v1")]
+pub mod installments;
 pub mod operations;
 
 #[cfg(feature = "retry")]
@@ -77,9 +81,10 @@ use time;
 
 #[cfg(feature = "v1")]
 pub use self::operations::{
-    PaymentApprove, PaymentCancel, PaymentCapture, PaymentConfirm, PaymentCreate,
-    PaymentIncrementalAuthorization, PaymentPostSessionTokens, PaymentReject, PaymentSession,
-    PaymentSessionUpdate, PaymentStatus, PaymentUpdate, PaymentUpdateMetadata,
+    PaymentApprove, PaymentCancel, PaymentCancelPostCapture, PaymentCapture, PaymentConfirm,
+    PaymentCreate, PaymentIncrementalAuthorization, PaymentPostSessionTokens, PaymentReject,
+    PaymentReverseAuthorization, PaymentSession, PaymentSessionUpdate, PaymentStatus,
+    PaymentUpdate, PaymentUpdateMetadata,
 };
 use self::{
     conditional_configs::perform_decision_management,
@@ -97,6 +102,8 @@ use crate::core::fraud_check as frm_core;
 #[cfg(feature = "v2")]
 use crate::core::payment_methods::vault;
 #[cfg(feature = "v1")]
+use crate::core::refunds;
+#[cfg(feature = "v1")]
 use crate::core::routing::helpers as routing_helpers;
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use crate::types::api::convert_connector_data_to_routable_connectors;
@@ -563,6 +570,8 @@ where
                 _ => (),
             };
 
+            let attempt_status_before_connector_call = payment_data.get_payment_attempt().status;
+
             payment_data = match connector_details {
                 ConnectorCallType::PreDetermined(ref connector) => {
                     #[cfg(all(feature = "dynamic_routing", feature = "v1"))]
@@ -885,6 +894,22 @@ where
                 ))
                 .await?;
             }
+
+            if payment_data.get_payment_attempt().status == storage_enums::AttemptStatus::Authorized
+                && attempt_status_before_connector_call != storage_enums::AttemptStatus::Authorized
+            {
+                track_authorization_expiry(
+                    state,
+                    merchant_context,
+                    &business_profile,
+                    &mut payment_data,
+                )
+                .await
+                .map_err(|error| {
+                    logger::error!(?error, "Failed to track the expiry of the authorization")
+                })
+                .ok();
+            }
         } else {
             (_, payment_data) = operation
                 .to_update_tracker()?
@@ -1695,6 +1720,213 @@ where
     )
 }
 
+/// Captures the payment, and releases the uncaptured amount of the authorization afterwards if
+/// requested through `refund_uncaptured_amount` and the payment is still partially capturable
+#[cfg(feature = "v1")]
+pub async fn payments_capture_core(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<id_type::ProfileId>,
+    req: api::PaymentsCaptureRequest,
+) -> RouterResponse<api::PaymentsResponse> {
+    let release_uncaptured_amount = req.refund_uncaptured_amount.unwrap_or(false);
+    let payment_id = req.payment_id.clone();
+
+    let capture_response = Box::pin(payments_core::<
+        api::Capture,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        PaymentData<api::Capture>,
+    >(
+        state.clone(),
+        req_state.clone(),
+        merchant_context.clone(),
+        profile_id.clone(),
+        PaymentCapture,
+        req,
+        services::AuthFlow::Merchant,
+        CallConnectorAction::Trigger,
+        None,
+        HeaderPayload::default(),
+    ))
+    .await?;
+
+    let is_uncaptured_amount_held = matches!(
+        &capture_response,
+        services::ApplicationResponse::JsonWithHeaders((response, _))
+            if response.status == storage_enums::IntentStatus::PartiallyCapturedAndCapturable
+    );
+
+    if !(release_uncaptured_amount && is_uncaptured_amount_held) {
+        return Ok(capture_response);
+    }
+
+    let reverse_authorization_request = api::PaymentsCancelRequest {
+        payment_id,
+        cancellation_reason: None,
+        cancellation_reason_code: None,
+        merchant_connector_details: None,
+    };
+
+    Box::pin(payments_core::<
+        api::Void,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        PaymentData<api::Void>,
+    >(
+        state,
+        req_state,
+        merchant_context,
+        profile_id,
+        PaymentReverseAuthorization,
+        reverse_authorization_request,
+        services::AuthFlow::Merchant,
+        CallConnectorAction::Trigger,
+        None,
+        HeaderPayload::default(),
+    ))
+    .await
+}
+
+/// Whether the void of a capture went through, given the status of the payment after the void.
+/// The payment remains succeeded when the connector does not void the capture.
+#[cfg(feature = "v1")]
+fn is_capture_voided(payment_status: Option<storage_enums::IntentStatus>) -> bool {
+    payment_status == Some(storage_enums::IntentStatus::Cancelled)
+}
+
+/// Refund of the full captured amount, used when the capture of the payment cannot be voided
+#[cfg(feature = "v1")]
+fn get_post_capture_refund_request(req: &api::PaymentsCancelRequest) -> api::RefundRequest {
+    api::RefundRequest {
+        payment_id: req.payment_id.clone(),
+        reason: req.cancellation_reason.clone().or_else(|| {
+            req.cancellation_reason_code
+                .map(|cancellation_reason_code| cancellation_reason_code.to_string())
+        }),
+        refund_type: Some(api::RefundType::Instant),
+        ..Default::default()
+    }
+}
+
+/// Cancels a captured payment. The capture is voided with the connector when it can still be
+/// voided, which avoids the fees of a refund, and the captured amount is refunded otherwise or if
+/// the connector fails to void the capture.
+#[cfg(feature = "v1")]
+pub async fn payments_cancel_post_capture_core(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<id_type::ProfileId>,
+    req: api::PaymentsCancelRequest,
+) -> RouterResponse<api::PaymentsResponse> {
+    let db = &*state.store;
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &(&state).into(),
+            &req.payment_id,
+            merchant_id,
+            merchant_context.get_merchant_key_store(),
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    helpers::validate_payment_status_against_allowed_statuses(
+        payment_intent.status,
+        &[storage_enums::IntentStatus::Succeeded],
+        "cancel the capture of",
+    )?;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            &payment_intent.payment_id,
+            merchant_id,
+            payment_intent.active_attempt.get_id().as_str(),
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    if helpers::is_post_capture_void_possible(&state, &payment_attempt)? {
+        let void_response = Box::pin(payments_core::<
+            api::Void,
+            api::PaymentsResponse,
+            _,
+            _,
+            _,
+            PaymentData<api::Void>,
+        >(
+            state.clone(),
+            req_state.clone(),
+            merchant_context.clone(),
+            profile_id.clone(),
+            PaymentCancelPostCapture,
+            req.clone(),
+            services::AuthFlow::Merchant,
+            CallConnectorAction::Trigger,
+            None,
+            HeaderPayload::default(),
+        ))
+        .await?;
+
+        let payment_status = match &void_response {
+            services::ApplicationResponse::JsonWithHeaders((response, _)) => Some(response.status),
+            _ => None,
+        };
+
+        if is_capture_voided(payment_status) {
+            return Ok(void_response);
+        }
+
+        logger::info!("Capture could not be voided, refunding the captured amount instead");
+    }
+
+    Box::pin(refunds::refund_create_core(
+        state.clone(),
+        merchant_context.clone(),
+        profile_id.clone(),
+        get_post_capture_refund_request(&req),
+    ))
+    .await?;
+
+    let retrieve_request = api::PaymentsRetrieveRequest {
+        resource_id: api::PaymentIdType::PaymentIntentId(req.payment_id),
+        merchant_id: Some(merchant_id.clone()),
+        force_sync: false,
+        ..Default::default()
+    };
+
+    Box::pin(payments_core::<
+        api::PSync,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        PaymentData<api::PSync>,
+    >(
+        state,
+        req_state,
+        merchant_context,
+        profile_id,
+        PaymentStatus,
+        retrieve_request,
+        services::AuthFlow::Merchant,
+        CallConnectorAction::Avoid,
+        None,
+        HeaderPayload::default(),
+    ))
+    .await
+}
+
 #[cfg(feature = "v1")]
 #[allow(clippy::too_many_arguments)]
 pub async fn proxy_for_payments_core<F, Res, Req, Op, FData, D>(
@@ -2738,6 +2970,7 @@ impl ValidateStatusForOperation for &PaymentRedirectSync {
             | common_enums::IntentStatus::Conflicted
             | common_enums::IntentStatus::Failed
             | common_enums::IntentStatus::Cancelled
+            | common_enums::IntentStatus::Expired
             | common_enums::IntentStatus::Processing
             | common_enums::IntentStatus::RequiresPaymentMethod
             | common_enums::IntentStatus::RequiresMerchantAction
@@ -5829,6 +6062,14 @@ where
             storage_enums::IntentStatus::RequiresCapture
                 | storage_enums::IntentStatus::PartiallyCapturedAndCapturable
         ),
+        "PaymentReverseAuthorization" => matches!(
+            payment_data.get_payment_intent().status,
+            storage_enums::IntentStatus::PartiallyCapturedAndCapturable
+        ),
+        "PaymentCancelPostCapture" => matches!(
+            payment_data.get_payment_intent().status,
+            storage_enums::IntentStatus::Succeeded
+        ),
         "PaymentCapture" => {
             matches!(
                 payment_data.get_payment_intent().status,
@@ -6292,6 +6533,237 @@ pub async fn add_process_sync_task(
     Ok(())
 }
 
+#[cfg(feature = "v1")]
+pub async fn add_payment_intent_expiry_task(
+    db: &dyn StorageInterface,
+    payment_intent: &storage::PaymentIntent,
+    payment_intent_expiry: i64,
+) -> CustomResult<(), errors::StorageError> {
+    let tracking_data = storage::PaymentIntentExpiryTrackingData {
+        merchant_id: payment_intent.merchant_id.clone(),
+        payment_id: payment_intent.get_id().to_owned(),
+    };
+    let runner = storage::ProcessTrackerRunner::PaymentIntentExpiryWorkflow;
+    let task = "PAYMENT_INTENT_EXPIRY";
+    let tag = ["EXPIRY", "PAYMENT"];
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        task,
+        payment_intent.get_id().get_string_repr(),
+        &payment_intent.merchant_id,
+    );
+    let schedule_time = payment_intent
+        .created_at
+        .saturating_add(time::Duration::seconds(payment_intent_expiry));
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+#[cfg(feature = "v1")]
+fn get_scheduled_capture_process_tracker_id(payment_attempt: &storage::PaymentAttempt) -> String {
+    pt_utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::ScheduledCaptureWorkflow,
+        "SCHEDULED_CAPTURE",
+        &payment_attempt.attempt_id,
+        &payment_attempt.merchant_id,
+    )
+}
+
+/// Schedules the capture of the payment attempt at the given time, the attempt is confirmed and
+/// hence scheduled only once
+#[cfg(feature = "v1")]
+pub async fn add_scheduled_capture_task(
+    db: &dyn StorageInterface,
+    payment_attempt: &storage::PaymentAttempt,
+    schedule_time: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::StorageError> {
+    let process_tracker_id = get_scheduled_capture_process_tracker_id(payment_attempt);
+    let tracking_data = storage::ScheduledCaptureTrackingData {
+        merchant_id: payment_attempt.merchant_id.clone(),
+        payment_id: payment_attempt.payment_id.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+    };
+    let runner = storage::ProcessTrackerRunner::ScheduledCaptureWorkflow;
+    let task = "SCHEDULED_CAPTURE";
+    let tag = ["CAPTURE", "PAYMENT"];
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+/// Revokes the scheduled capture of the payment, when the payment is cancelled before it is
+/// captured
+#[cfg(feature = "v1")]
+pub async fn revoke_scheduled_capture_task(
+    db: &dyn StorageInterface,
+    payment_attempt: &storage::PaymentAttempt,
+) -> CustomResult<(), errors::StorageError> {
+    db.process_tracker_update_process_status_by_ids(
+        vec![get_scheduled_capture_process_tracker_id(payment_attempt)],
+        storage::ProcessTrackerUpdate::StatusUpdate {
+            status: storage_enums::ProcessTrackerStatus::Finish,
+            business_status: Some(String::from(storage::business_status::REVOKED)),
+        },
+    )
+    .await?;
+    Ok(())
+}
+
+/// Records the time at which the payment was authorized and the time at which its authorization
+/// expires, and schedules the re-authorization of the payment or the notification of the merchant
+/// before it expires
+#[cfg(feature = "v1")]
+async fn track_authorization_expiry<F, D>(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    business_profile: &domain::Profile,
+    payment_data: &mut D,
+) -> RouterResult<()>
+where
+    F: Send + Clone + Sync,
+    D: OperationSessionGetters<F> + OperationSessionSetters<F> + Send + Sync + Clone,
+{
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+    let authorized_at = common_utils::date_time::now();
+
+    // The expiry provided by the connector is preferred over the one computed from the rules of
+    // the card network
+    let authorization_expiry = match payment_data.get_payment_attempt().capture_before {
+        Some(capture_before) => capture_before,
+        None => helpers::get_authorization_expiry(
+            payment_data.get_payment_attempt(),
+            authorized_at,
+            business_profile.merchant_category_code,
+        )?,
+    };
+
+    let payment_attempt = state
+        .store
+        .update_payment_attempt_with_attempt_id(
+            payment_data.get_payment_attempt().clone(),
+            storage::PaymentAttemptUpdate::AuthorizationExpiryUpdate {
+                authorized_at,
+                capture_before: authorization_expiry,
+                updated_by: storage_scheme.to_string(),
+            },
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    payment_data.set_payment_attempt(payment_attempt);
+
+    // Nothing is done before the authorization expires unless the payment can be re-authorized or
+    // the merchant wants to be notified about it
+    if !is_authorization_expiry_handled(business_profile) {
+        return Ok(());
+    }
+
+    let schedule_time = std::cmp::max(
+        authorization_expiry.saturating_sub(time::Duration::seconds(
+            consts::AUTHORIZATION_EXPIRY_NOTICE_PERIOD_IN_SECONDS,
+        )),
+        common_utils::date_time::now(),
+    );
+
+    add_authorization_expiry_task(
+        &*state.store,
+        payment_data.get_payment_attempt(),
+        schedule_time,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to add the authorization expiry task to process tracker")
+}
+
+/// Whether the profile re-authorizes payments or notifies the merchant before their
+/// authorizations expire
+#[cfg(feature = "v1")]
+fn is_authorization_expiry_handled(business_profile: &domain::Profile) -> bool {
+    business_profile
+        .is_auto_reauthorization_enabled
+        .unwrap_or(false)
+        || business_profile
+            .webhook_details
+            .as_ref()
+            .and_then(|webhook_details| webhook_details.payment_authorization_expiring_enabled)
+            .unwrap_or(false)
+}
+
+#[cfg(feature = "v1")]
+fn get_authorization_expiry_process_tracker_id(
+    payment_attempt: &storage::PaymentAttempt,
+) -> String {
+    pt_utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::AuthorizationExpiryWorkflow,
+        "AUTHORIZATION_EXPIRY",
+        payment_attempt.payment_id.get_string_repr(),
+        &payment_attempt.merchant_id,
+    )
+}
+
+/// Schedules the handling of the expiry of the authorization of the payment, rescheduling the task
+/// of an earlier authorization of the payment if one exists
+#[cfg(feature = "v1")]
+pub async fn add_authorization_expiry_task(
+    db: &dyn StorageInterface,
+    payment_attempt: &storage::PaymentAttempt,
+    schedule_time: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::StorageError> {
+    let process_tracker_id = get_authorization_expiry_process_tracker_id(payment_attempt);
+
+    if let Some(process) = db.find_process_by_id(&process_tracker_id).await? {
+        return db
+            .as_scheduler()
+            .reset_process(process, schedule_time)
+            .await;
+    }
+
+    let tracking_data = storage::AuthorizationExpiryTrackingData {
+        merchant_id: payment_attempt.merchant_id.clone(),
+        payment_id: payment_attempt.payment_id.clone(),
+    };
+    let runner = storage::ProcessTrackerRunner::AuthorizationExpiryWorkflow;
+    let task = "AUTHORIZATION_EXPIRY";
+    let tag = ["AUTHORIZATION", "PAYMENT"];
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
 #[cfg(feature = "v2")]
 pub async fn reset_process_sync_task(
     db: &dyn StorageInterface,
@@ -7536,9 +8008,7 @@ pub fn is_network_token_with_network_transaction_id_flow(
         payment_method_info.get_payment_method_type(),
         payment_method_info.network_transaction_id.clone(),
         payment_method_info.network_token_locker_id.is_some(),
-        payment_method_info
-            .network_token_requestor_reference_id
-            .is_some(),
+        payment_method_info.is_network_token_active(),
     ) {
         (
             Some(true),
@@ -7674,6 +8144,7 @@ pub async fn route_connector_v2_for_payments(
     let connectors = routing::perform_static_routing_v1(
         state,
         merchant_context.get_merchant_account().get_id(),
+        merchant_context.get_merchant_key_store(),
         routing_algorithm_id,
         business_profile,
         &TransactionData::Payment(transaction_data.clone()),
@@ -7726,28 +8197,37 @@ where
     F: Send + Clone,
     D: OperationSessionGetters<F> + OperationSessionSetters<F> + Send + Sync + Clone,
 {
-    let routing_algorithm_id = {
-        let routing_algorithm = business_profile.routing_algorithm.clone();
-
-        let algorithm_ref = routing_algorithm
-            .map(|ra| ra.parse_value::<api::routing::RoutingAlgorithmRef>("RoutingAlgorithmRef"))
-            .transpose()
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Could not decode merchant routing algorithm ref")?
-            .unwrap_or_default();
-        algorithm_ref.algorithm_id
-    };
+    let algorithm_ref = business_profile
+        .routing_algorithm
+        .clone()
+        .map(|ra| ra.parse_value::<api::routing::RoutingAlgorithmRef>("RoutingAlgorithmRef"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Could not decode merchant routing algorithm ref")?
+        .unwrap_or_default();
 
     let connectors = routing::perform_static_routing_v1(
         state,
         merchant_context.get_merchant_account().get_id(),
-        routing_algorithm_id.as_ref(),
+        merchant_context.get_merchant_key_store(),
+        algorithm_ref.algorithm_id.as_ref(),
         business_profile,
         &TransactionData::Payment(transaction_data.clone()),
     )
     .await
     .change_context(errors::ApiErrorResponse::InternalServerError)?;
 
+    if let Some(shadow_algorithm_id) = algorithm_ref.shadow_algorithm_id.as_ref() {
+        routing::perform_shadow_static_routing_v1(
+            state,
+            merchant_context.get_merchant_key_store(),
+            business_profile,
+            shadow_algorithm_id,
+            &transaction_data,
+            &connectors,
+        );
+    }
+
     #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
     let payment_attempt = transaction_data.payment_attempt.clone();
 
@@ -7918,6 +8398,7 @@ pub async fn route_connector_v1_for_payouts(
     let connectors = routing::perform_static_routing_v1(
         state,
         merchant_context.get_merchant_account().get_id(),
+        merchant_context.get_merchant_key_store(),
         routing_algorithm_id.as_ref(),
         business_profile,
         &TransactionData::Payout(transaction_data),
@@ -10040,3 +10521,58 @@ impl<F: Clone> OperationSessionSetters<F> for PaymentCaptureData<F> {
         todo!()
     }
 }
+
+#[cfg(feature = "v1")]
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_is_capture_voided() {
+        assert!(is_capture_voided(Some(
+            storage_enums::IntentStatus::Cancelled
+        )));
+        // The payment remains succeeded when the connector fails to void the capture
+        assert!(!is_capture_voided(Some(
+            storage_enums::IntentStatus::Succeeded
+        )));
+        assert!(!is_capture_voided(None));
+    }
+
+    #[test]
+    fn test_get_post_capture_refund_request() {
+        let payment_id = id_type::PaymentId::try_from(std::borrow::Cow::Borrowed("pay_1")).unwrap();
+        let req = api::PaymentsCancelRequest {
+            payment_id: payment_id.clone(),
+            cancellation_reason: None,
+            cancellation_reason_code: Some(
+                storage_enums::PaymentCancellationReason::RequestedByCustomer,
+            ),
+            merchant_connector_details: None,
+        };
+
+        let refund_request = get_post_capture_refund_request(&req);
+        assert_eq!(refund_request.payment_id, payment_id);
+        assert_eq!(
+            refund_request.reason.as_deref(),
+            Some("requested_by_customer")
+        );
+        assert!(matches!(
+            refund_request.refund_type,
+            Some(api::RefundType::Instant)
+        ));
+        // The full captured amount is refunded
+        assert_eq!(refund_request.amount, None);
+
+        let req = api::PaymentsCancelRequest {
+            cancellation_reason: Some("Order cancelled".to_string()),
+            ..req
+        };
+        assert_eq!(
+            get_post_capture_refund_request(&req).reason.as_deref(),
+            Some("Order cancelled")
+        );
+    }
+}
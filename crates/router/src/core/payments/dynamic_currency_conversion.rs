@@ -0,0 +1,375 @@
+use api_models::payments as payment_types;
+use common_types::payments::CurrencyConversionDetails;
+use common_utils::{date_time, ext_traits::OptionExt, id_type, types::MinorUnit};
+use error_stack::{report, ResultExt};
+use masking::PeekInterface;
+use router_env::{instrument, logger, tracing};
+use rust_decimal::{prelude::ToPrimitive, Decimal, RoundingStrategy};
+
+use super::helpers;
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services,
+    types::{domain, storage, storage::enums},
+    utils::{self, currency as forex},
+};
+
+/// Prefix of the identifiers of currency conversion quotes
+const CURRENCY_CONVERSION_QUOTE_ID_PREFIX: &str = "dcc";
+
+/// Quote offered to the customer of a payment, stored until it expires
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct CurrencyConversionQuote {
+    payment_id: id_type::PaymentId,
+    merchant_id: id_type::MerchantId,
+    card_isin: String,
+    details: CurrencyConversionDetails,
+}
+
+fn get_currency_conversion_quote_key(quote_id: &str) -> String {
+    format!("currency_conversion_quote_{quote_id}")
+}
+
+/// Quotes the conversion of the payment amount to the currency of the card, with the markup
+/// configured for the profile. The quote can be accepted when confirming the payment, until it
+/// expires
+#[instrument(skip_all)]
+pub async fn create_currency_conversion_quote(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: payment_types::CurrencyConversionQuoteRequest,
+) -> RouterResponse<payment_types::CurrencyConversionQuoteResponse> {
+    let db = &*state.store;
+    let key_manager_state = &(&state).into();
+    let merchant_account = merchant_context.get_merchant_account();
+    let card_isin = helpers::get_card_isin_from_bin(request.card_bin.peek())?;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            key_manager_state,
+            &request.payment_id,
+            merchant_account.get_id(),
+            merchant_context.get_merchant_key_store(),
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    helpers::authenticate_client_secret(request.client_secret.as_ref(), &payment_intent)?;
+
+    helpers::validate_payment_status_against_allowed_statuses(
+        payment_intent.status,
+        &[
+            enums::IntentStatus::RequiresPaymentMethod,
+            enums::IntentStatus::RequiresConfirmation,
+        ],
+        "quote currency conversion for",
+    )?;
+
+    let profile_id = payment_intent
+        .profile_id
+        .as_ref()
+        .get_required_value("profile_id")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Could not find profile_id in payment intent")?;
+
+    let business_profile = db
+        .find_business_profile_by_profile_id(
+            key_manager_state,
+            merchant_context.get_merchant_key_store(),
+            profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let dynamic_currency_conversion = business_profile.dynamic_currency_conversion.ok_or(
+        errors::ApiErrorResponse::PreconditionFailed {
+            message: "Dynamic currency conversion is not enabled for the profile".to_string(),
+        },
+    )?;
+
+    let original_currency = payment_intent
+        .currency
+        .get_required_value("currency")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Could not find currency in payment intent")?;
+
+    let converted_currency = helpers::get_card_issuing_country(db, card_isin)
+        .await
+        .and_then(enums::CountryAlpha2::from_alpha2_to_currency)
+        .filter(|card_currency| *card_currency != original_currency)
+        .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Currency conversion is not available for the card".to_string(),
+        })?;
+
+    let forex_rates = forex::get_forex_rates(
+        &state,
+        state
+            .conf
+            .forex_api
+            .get_inner()
+            .data_expiration_delay_in_seconds,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch the forex rates")?;
+
+    let details = get_currency_conversion_details(
+        &forex_rates.data,
+        payment_intent.amount,
+        original_currency,
+        converted_currency,
+        dynamic_currency_conversion.markup_in_basis_points,
+    )?;
+
+    let quote_validity_in_seconds = dynamic_currency_conversion.get_quote_validity_in_seconds();
+    let expires_at = date_time::now().saturating_add(time::Duration::seconds(i64::from(
+        quote_validity_in_seconds,
+    )));
+    let quote = CurrencyConversionQuote {
+        payment_id: payment_intent.payment_id.clone(),
+        merchant_id: merchant_account.get_id().clone(),
+        card_isin: card_isin.to_owned(),
+        details,
+    };
+
+    db.get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .serialize_and_set_key_with_expiry(
+            &get_currency_conversion_quote_key(&quote.details.quote_id).into(),
+            &quote,
+            i64::from(quote_validity_in_seconds),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store the currency conversion quote")?;
+
+    Ok(services::ApplicationResponse::Json(
+        payment_types::CurrencyConversionQuoteResponse {
+            payment_id: quote.payment_id,
+            quote: quote.details,
+            expires_at,
+        },
+    ))
+}
+
+/// Converts the amount of the payment to the currency of the card with the markup applied over the
+/// exchange rate. The exchange rate is rounded to six decimal places and the converted amount to the
+/// exponent of the currency of the card, rounding half away from zero.
+fn get_currency_conversion_details(
+    forex_rates: &currency_conversion::types::ExchangeRates,
+    original_amount: MinorUnit,
+    original_currency: enums::Currency,
+    converted_currency: enums::Currency,
+    markup_in_basis_points: u16,
+) -> RouterResult<CurrencyConversionDetails> {
+    let original_exponent = u32::from(original_currency.number_of_digits_after_decimal_point());
+    let converted_exponent = u32::from(converted_currency.number_of_digits_after_decimal_point());
+
+    // Exchange rate of one major unit of the currency of the merchant
+    let exchange_rate = currency_conversion::conversion::convert(
+        forex_rates,
+        original_currency,
+        converted_currency,
+        10_i64.pow(original_exponent),
+    )
+    .change_context(errors::ApiErrorResponse::CurrencyConversionFailed)
+    .attach_printable("Failed to compute the exchange rate")?;
+
+    let markup = Decimal::new(i64::from(markup_in_basis_points), 4);
+    let exchange_rate = exchange_rate
+        .checked_mul(Decimal::ONE + markup)
+        .map(|exchange_rate| {
+            exchange_rate.round_dp_with_strategy(6, RoundingStrategy::MidpointAwayFromZero)
+        })
+        .ok_or(errors::ApiErrorResponse::CurrencyConversionFailed)
+        .attach_printable("Failed to apply the markup over the exchange rate")?;
+
+    let converted_amount = Decimal::new(original_amount.get_amount_as_i64(), original_exponent)
+        .checked_mul(exchange_rate)
+        .map(|converted_amount| {
+            converted_amount
+                .round_dp_with_strategy(converted_exponent, RoundingStrategy::MidpointAwayFromZero)
+        })
+        .and_then(|converted_amount| {
+            converted_amount.checked_mul(Decimal::from(10_i64.pow(converted_exponent)))
+        })
+        .and_then(|converted_amount| converted_amount.to_i64())
+        .ok_or(errors::ApiErrorResponse::CurrencyConversionFailed)
+        .attach_printable("Failed to convert the amount of the payment")?;
+
+    Ok(CurrencyConversionDetails {
+        quote_id: common_utils::generate_id_with_default_len(CURRENCY_CONVERSION_QUOTE_ID_PREFIX),
+        original_amount,
+        original_currency,
+        converted_amount: MinorUnit::new(converted_amount),
+        converted_currency,
+        exchange_rate,
+        markup_in_basis_points,
+    })
+}
+
+/// Fetches the currency conversion quote accepted by the customer when confirming the payment, and
+/// validates that it was offered for the payment, its current amount and the card used for it
+#[instrument(skip_all)]
+pub async fn get_accepted_currency_conversion(
+    state: &SessionState,
+    payment_attempt: &storage::PaymentAttempt,
+    quote_id: &str,
+    card_isin: Option<String>,
+) -> RouterResult<CurrencyConversionDetails> {
+    let quote = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .get_and_deserialize_key::<CurrencyConversionQuote>(
+            &get_currency_conversion_quote_key(quote_id).into(),
+            "CurrencyConversionQuote",
+        )
+        .await
+        .map_err(|error| {
+            logger::info!(?error, "Failed to fetch the currency conversion quote");
+            report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "currency_conversion_quote_id is invalid or the quote has expired"
+                    .to_string(),
+            })
+        })?;
+
+    utils::when(
+        quote.payment_id != payment_attempt.payment_id
+            || quote.merchant_id != payment_attempt.merchant_id,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "currency_conversion_quote_id was not issued for this payment".to_string(),
+            }))
+        },
+    )?;
+
+    utils::when(
+        quote.details.original_amount != payment_attempt.net_amount.get_order_amount()
+            || Some(quote.details.original_currency) != payment_attempt.currency,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message:
+                    "The amount of the payment has changed since the currency conversion was quoted"
+                        .to_string(),
+            }))
+        },
+    )?;
+
+    utils::when(
+        card_isin.as_deref() != Some(quote.card_isin.as_str()),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The currency conversion quote can only be accepted with the card for which it was requested"
+                .to_string(),
+        }))
+        },
+    )?;
+
+    Ok(quote.details)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::collections::HashMap;
+
+    use currency_conversion::types::{CurrencyFactors, ExchangeRates};
+
+    use super::*;
+
+    /// Exchange rates with USD as the base currency
+    fn get_forex_rates() -> ExchangeRates {
+        ExchangeRates::new(
+            enums::Currency::USD,
+            HashMap::from([
+                (
+                    enums::Currency::EUR,
+                    CurrencyFactors::new(Decimal::new(9, 1), Decimal::new(1111111, 6)),
+                ),
+                (
+                    enums::Currency::JPY,
+                    CurrencyFactors::new(Decimal::new(150, 0), Decimal::new(6666667, 9)),
+                ),
+                (
+                    enums::Currency::KWD,
+                    CurrencyFactors::new(Decimal::new(307, 3), Decimal::new(3257329, 6)),
+                ),
+            ]),
+        )
+    }
+
+    fn convert(
+        original_amount: i64,
+        original_currency: enums::Currency,
+        converted_currency: enums::Currency,
+        markup_in_basis_points: u16,
+    ) -> CurrencyConversionDetails {
+        get_currency_conversion_details(
+            &get_forex_rates(),
+            MinorUnit::new(original_amount),
+            original_currency,
+            converted_currency,
+            markup_in_basis_points,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_markup_is_applied_over_the_exchange_rate() {
+        let details = convert(1000, enums::Currency::USD, enums::Currency::EUR, 300);
+
+        assert_eq!(details.exchange_rate, Decimal::new(927, 3));
+        assert_eq!(details.converted_amount, MinorUnit::new(927));
+        assert_eq!(details.original_amount, MinorUnit::new(1000));
+        assert_eq!(details.original_currency, enums::Currency::USD);
+        assert_eq!(details.converted_currency, enums::Currency::EUR);
+        assert_eq!(details.markup_in_basis_points, 300);
+    }
+
+    #[test]
+    fn test_converted_amount_is_rounded_half_away_from_zero() {
+        // 10.05 USD is 9.045 EUR
+        let details = convert(1005, enums::Currency::USD, enums::Currency::EUR, 0);
+        assert_eq!(details.converted_amount, MinorUnit::new(905));
+
+        // 10.01 USD is 9.27927 EUR with the markup
+        let details = convert(1001, enums::Currency::USD, enums::Currency::EUR, 300);
+        assert_eq!(details.converted_amount, MinorUnit::new(928));
+    }
+
+    #[test]
+    fn test_amounts_are_converted_between_currency_exponents() {
+        let details = convert(1000, enums::Currency::USD, enums::Currency::JPY, 0);
+        assert_eq!(details.exchange_rate, Decimal::new(150, 0));
+        assert_eq!(details.converted_amount, MinorUnit::new(1500));
+
+        let details = convert(1000, enums::Currency::USD, enums::Currency::KWD, 0);
+        assert_eq!(details.exchange_rate, Decimal::new(307, 3));
+        assert_eq!(details.converted_amount, MinorUnit::new(3070));
+
+        // The exchange rate of one yen is rounded to six decimal places
+        let details = convert(1000, enums::Currency::JPY, enums::Currency::USD, 0);
+        assert_eq!(details.exchange_rate, Decimal::new(6667, 6));
+        assert_eq!(details.converted_amount, MinorUnit::new(667));
+    }
+
+    #[test]
+    fn test_conversion_fails_without_exchange_rate() {
+        let details = get_currency_conversion_details(
+            &get_forex_rates(),
+            MinorUnit::new(1000),
+            enums::Currency::USD,
+            enums::Currency::GBP,
+            0,
+        );
+
+        assert!(details.is_err());
+    }
+}
@@ -41,6 +41,8 @@ use hyperswitch_domain_models::{
     },
     router_data::KlarnaSdkResponse,
 };
+#[cfg(feature = "v1")]
+use hyperswitch_interfaces::api::ConnectorSpecifications;
 use hyperswitch_interfaces::integrity::{CheckIntegrity, FlowIntegrity, GetIntegrityObject};
 use josekit::jwe;
 use masking::{ExposeInterface, PeekInterface, SwitchStrategy};
@@ -2073,7 +2075,7 @@ pub async fn retrieve_payment_method_data_with_permanent_token(
     _payment_method_id: &str,
     payment_intent: &PaymentIntent,
     card_token_data: Option<&domain::CardToken>,
-    _merchant_key_store: &domain::MerchantKeyStore,
+    merchant_key_store: &domain::MerchantKeyStore,
     _storage_scheme: enums::MerchantStorageScheme,
     mandate_id: Option<api_models::payments::MandateIds>,
     payment_method_info: domain::PaymentMethod,
@@ -2106,15 +2108,20 @@ pub async fn retrieve_payment_method_data_with_permanent_token(
         })
         .transpose()?;
 
+    // Payments fall back to the card when the network token is suspended or deleted by the
+    // token service
+    let active_network_token_requestor_reference_id = payment_method_info
+        .network_token_requestor_reference_id
+        .clone()
+        .filter(|_| payment_method_info.is_network_token_active());
+
     let vault_fetch_action = decide_payment_method_retrieval_action(
         business_profile.is_network_tokenization_enabled,
         mandate_id,
         connector_variant,
         network_tokenization_supported_connectors,
         should_retry_with_pan,
-        payment_method_info
-            .network_token_requestor_reference_id
-            .clone(),
+        active_network_token_requestor_reference_id,
     );
 
     let co_badged_card_data = payment_method_info
@@ -2160,6 +2167,7 @@ pub async fn retrieve_payment_method_data_with_permanent_token(
                 state,
                 network_token_requestor_ref_id,
                 &payment_method_info,
+                merchant_key_store,
             )
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -2852,6 +2860,181 @@ pub(crate) fn validate_amount_to_capture(
     )
 }
 
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub(crate) fn validate_partial_authorization_reversal_support(
+    state: &SessionState,
+    payment_attempt: &PaymentAttempt,
+) -> RouterResult<()> {
+    let connector_name = payment_attempt
+        .connector
+        .as_ref()
+        .get_required_value("connector")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Connector not found in payment attempt")?;
+
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        connector_name,
+        api::GetToken::Connector,
+        payment_attempt.merchant_connector_id.clone(),
+    )?;
+
+    utils::when(
+        !connector_data
+            .connector
+            .is_partial_authorization_reversal_supported(),
+        || {
+            Err(report!(errors::ApiErrorResponse::NotSupported {
+                message: format!(
+                    "Releasing the uncaptured amount of an authorization is not supported by {connector_name}"
+                ),
+            }))
+        },
+    )
+}
+
+/// Validates the card BIN provided in a request and returns the part of it which is used to look up
+/// the details of the card
+#[cfg(feature = "v1")]
+pub(crate) fn get_card_isin_from_bin(card_bin: &str) -> RouterResult<&str> {
+    const CARD_ISIN_LENGTH: usize = 6;
+    const MAX_CARD_BIN_LENGTH: usize = 8;
+
+    card_bin
+        .get(..CARD_ISIN_LENGTH)
+        .filter(|_| {
+            card_bin.len() <= MAX_CARD_BIN_LENGTH
+                && card_bin.chars().all(|character| character.is_ascii_digit())
+        })
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "card_bin should be between {CARD_ISIN_LENGTH} and {MAX_CARD_BIN_LENGTH} digits"
+                ),
+            })
+        })
+}
+
+/// Looks up the issuing country of a card from its BIN
+#[cfg(feature = "v1")]
+pub(crate) async fn get_card_issuing_country(
+    db: &dyn StorageInterface,
+    card_isin: &str,
+) -> Option<enums::CountryAlpha2> {
+    db.get_card_info(card_isin)
+        .await
+        .map_err(|error| logger::warn!(card_info_error=?error))
+        .ok()
+        .flatten()
+        .and_then(|card_info| card_info.country_code)
+        .and_then(|country_code| enums::CountryAlpha2::from_str(&country_code).ok())
+}
+
+/// Validates that the connector of the payment attempt can process the installment plan requested
+/// for the payment, for the issuing country of the card used for the payment
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub(crate) async fn validate_installment_plan_support(
+    state: &SessionState,
+    payment_attempt: &PaymentAttempt,
+    payment_method_data: Option<&domain::PaymentMethodData>,
+) -> RouterResult<()> {
+    let Some(installment_plan) = payment_attempt.installment_plan.as_ref() else {
+        return Ok(());
+    };
+
+    let Some(domain::PaymentMethodData::Card(card)) = payment_method_data else {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "installment_plan can be provided only for card payments".to_string(),
+        }));
+    };
+
+    let card_issuing_country =
+        get_card_issuing_country(&*state.store, &card.card_number.get_card_isin()).await;
+
+    let connector_name = payment_attempt
+        .connector
+        .as_ref()
+        .get_required_value("connector")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Connector not found in payment attempt")?;
+
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        connector_name,
+        api::GetToken::Connector,
+        payment_attempt.merchant_connector_id.clone(),
+    )?;
+
+    utils::when(
+        !connector_data
+            .connector
+            .get_supported_installment_plans()
+            .is_some_and(|installment_plans| {
+                installment_plans.supports(installment_plan, card_issuing_country)
+            }),
+        || {
+            Err(report!(errors::ApiErrorResponse::NotSupported {
+                message: format!(
+                    "The requested installment plan is not supported by {connector_name}"
+                ),
+            }))
+        },
+    )
+}
+
+/// Checks whether the capture of the payment can still be voided instead of being refunded. This
+/// requires the connector to support voiding captures, and the capture to not have been settled
+/// yet, which happens at the first settlement cutoff of the connector after the capture.
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub(crate) fn is_post_capture_void_possible(
+    state: &SessionState,
+    payment_attempt: &PaymentAttempt,
+) -> RouterResult<bool> {
+    let captured_at = match (payment_attempt.status, payment_attempt.captured_at) {
+        (storage_enums::AttemptStatus::Charged, Some(captured_at)) => captured_at,
+        _ => return Ok(false),
+    };
+
+    let connector_name = payment_attempt
+        .connector
+        .as_ref()
+        .get_required_value("connector")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Connector not found in payment attempt")?;
+
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        connector_name,
+        api::GetToken::Connector,
+        payment_attempt.merchant_connector_id.clone(),
+    )?;
+
+    Ok(connector_data.connector.is_post_capture_void_supported()
+        && common_utils::date_time::now()
+            < get_settlement_time(
+                captured_at,
+                connector_data.connector.get_settlement_cutoff_time(),
+            ))
+}
+
+/// Returns the time at which a capture made at `captured_at` is settled, which is the first
+/// occurrence of the settlement cutoff time of the connector after the capture.
+#[cfg(feature = "v1")]
+pub(crate) fn get_settlement_time(
+    captured_at: time::PrimitiveDateTime,
+    settlement_cutoff_time: time::Time,
+) -> time::PrimitiveDateTime {
+    let settlement_time = captured_at.replace_time(settlement_cutoff_time);
+    if settlement_time > captured_at {
+        settlement_time
+    } else {
+        settlement_time.saturating_add(time::Duration::days(1))
+    }
+}
+
 #[cfg(feature = "v1")]
 #[instrument(skip_all)]
 pub(crate) fn validate_payment_method_fields_present(
@@ -3831,6 +4014,95 @@ mod tests {
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_err())
     }
+
+    #[test]
+    fn test_get_settlement_time() {
+        use time::macros::{datetime, time};
+
+        // Captured before the cutoff, settled at the cutoff on the same day
+        assert_eq!(
+            get_settlement_time(datetime!(2025-07-01 10:30), time!(22:00)),
+            datetime!(2025-07-01 22:00)
+        );
+        // Captured after the cutoff, settled at the cutoff on the next day
+        assert_eq!(
+            get_settlement_time(datetime!(2025-07-01 22:30), time!(22:00)),
+            datetime!(2025-07-02 22:00)
+        );
+        // Captured exactly at the cutoff, settled with the next batch
+        assert_eq!(
+            get_settlement_time(datetime!(2025-07-01 22:00), time!(22:00)),
+            datetime!(2025-07-02 22:00)
+        );
+        // A cutoff at midnight settles the captures at the end of the day
+        assert_eq!(
+            get_settlement_time(datetime!(2025-12-31 23:59), time::Time::MIDNIGHT),
+            datetime!(2026-01-01 0:00)
+        );
+    }
+
+    #[test]
+    fn test_get_connector_capture_method() {
+        assert_eq!(
+            get_connector_capture_method(Some(storage_enums::CaptureMethod::Scheduled)),
+            Some(storage_enums::CaptureMethod::Manual)
+        );
+        assert_eq!(
+            get_connector_capture_method(Some(storage_enums::CaptureMethod::Automatic)),
+            Some(storage_enums::CaptureMethod::Automatic)
+        );
+        assert_eq!(get_connector_capture_method(None), None);
+    }
+
+    #[test]
+    fn test_get_scheduled_capture_time() {
+        let request = api_models::payments::PaymentsRequest {
+            capture_delay_in_seconds: Some(3600),
+            ..Default::default()
+        };
+        let capture_time = get_scheduled_capture_time(
+            &request,
+            Some(storage_enums::CaptureMethod::Scheduled),
+            true,
+        )
+        .unwrap()
+        .unwrap();
+        let delay = capture_time - common_utils::date_time::now();
+        assert!(delay > time::Duration::minutes(59) && delay <= time::Duration::hours(1));
+
+        // The capture time is accepted only for the scheduled capture method
+        assert!(get_scheduled_capture_time(
+            &request,
+            Some(storage_enums::CaptureMethod::Manual),
+            true
+        )
+        .is_err());
+        // The capture time is accepted only when the payment is confirmed
+        assert!(get_scheduled_capture_time(
+            &request,
+            Some(storage_enums::CaptureMethod::Scheduled),
+            false
+        )
+        .is_err());
+        // The capture time is required for the scheduled capture method at confirmation
+        assert!(get_scheduled_capture_time(
+            &api_models::payments::PaymentsRequest::default(),
+            Some(storage_enums::CaptureMethod::Scheduled),
+            true
+        )
+        .is_err());
+        // The capture time should not be too close to the confirmation
+        let request = api_models::payments::PaymentsRequest {
+            capture_delay_in_seconds: Some(10),
+            ..Default::default()
+        };
+        assert!(get_scheduled_capture_time(
+            &request,
+            Some(storage_enums::CaptureMethod::Scheduled),
+            true
+        )
+        .is_err());
+    }
 }
 
 // This function will be removed after moving this functionality to server_wrap and using cache instead of config
@@ -4264,6 +4536,7 @@ pub fn get_attempt_type(
             }
         }
         enums::IntentStatus::Cancelled
+        | enums::IntentStatus::Expired
         | enums::IntentStatus::RequiresCapture
         | enums::IntentStatus::PartiallyCaptured
         | enums::IntentStatus::PartiallyCapturedAndCapturable
@@ -4325,7 +4598,8 @@ impl AttemptType {
             payment_method_id: None,
             payment_method: None,
             capture_method: old_payment_attempt.capture_method,
-            capture_on: old_payment_attempt.capture_on,
+            // The capture is scheduled afresh when the new attempt is confirmed
+            capture_on: None,
             confirm: old_payment_attempt.confirm,
             authentication_type: old_payment_attempt.authentication_type,
             created_at,
@@ -4386,6 +4660,13 @@ impl AttemptType {
             processor_merchant_id: old_payment_attempt.processor_merchant_id,
             created_by: old_payment_attempt.created_by,
             setup_future_usage_applied: None,
+            authorization_reversal_status: None,
+            amount_reversed: None,
+            installment_plan: None,
+            currency_conversion: None,
+            cancellation_reason_code: None,
+            authorized_at: None,
+            captured_at: None,
         }
     }
 
@@ -4528,6 +4809,7 @@ pub fn is_manual_retry_allowed(
             | storage_enums::AttemptStatus::Failure => Some(true),
         },
         enums::IntentStatus::Cancelled
+        | enums::IntentStatus::Expired
         | enums::IntentStatus::RequiresCapture
         | enums::IntentStatus::PartiallyCaptured
         | enums::IntentStatus::PartiallyCapturedAndCapturable
@@ -6307,6 +6589,178 @@ pub fn validate_session_expiry(session_expiry: u32) -> Result<(), errors::ApiErr
     }
 }
 
+pub fn validate_payment_intent_expiry(
+    payment_intent_expiry: u32,
+) -> Result<(), errors::ApiErrorResponse> {
+    if !(consts::MIN_PAYMENT_INTENT_EXPIRY..=consts::MAX_PAYMENT_INTENT_EXPIRY)
+        .contains(&payment_intent_expiry)
+    {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "payment_intent_expiry should be between 60(1 min) to 7890000(3 months)."
+                .to_string(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// Computes the time at which a payment with the `scheduled` capture method has to be captured,
+/// from the capture time or the capture delay provided in the request
+#[cfg(feature = "v1")]
+pub fn get_scheduled_capture_time(
+    request: &api_models::payments::PaymentsRequest,
+    capture_method: Option<enums::CaptureMethod>,
+    is_confirm: bool,
+) -> RouterResult<Option<time::PrimitiveDateTime>> {
+    let now = common_utils::date_time::now();
+    let capture_time = match (request.capture_on, request.capture_delay_in_seconds) {
+        (Some(_), Some(_)) => {
+            return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "capture_on and capture_delay_in_seconds cannot be provided together"
+                    .to_string(),
+            }))
+        }
+        (Some(capture_on), None) => Some(capture_on),
+        (None, Some(capture_delay_in_seconds)) => {
+            Some(now.saturating_add(time::Duration::seconds(i64::from(capture_delay_in_seconds))))
+        }
+        (None, None) => None,
+    };
+
+    if capture_method != Some(enums::CaptureMethod::Scheduled) {
+        return match capture_time {
+            Some(_) => Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "capture_on or capture_delay_in_seconds can be provided only when capture_method is scheduled".to_string(),
+            })),
+            None => Ok(None),
+        };
+    }
+
+    if !is_confirm {
+        // The schedule is relative to the confirmation of the payment, so it is accepted only
+        // when the payment is confirmed
+        return match capture_time {
+            Some(_) => Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "capture_on or capture_delay_in_seconds can be provided only when the payment is confirmed".to_string(),
+            })),
+            None => Ok(None),
+        };
+    }
+
+    let capture_time = capture_time.ok_or(errors::ApiErrorResponse::MissingRequiredField {
+        field_name: "capture_on or capture_delay_in_seconds",
+    })?;
+
+    let min_capture_time =
+        now.saturating_add(time::Duration::seconds(consts::MIN_SCHEDULED_CAPTURE_DELAY));
+    let max_capture_time =
+        now.saturating_add(time::Duration::seconds(consts::MAX_SCHEDULED_CAPTURE_DELAY));
+    utils::when(
+        !(min_capture_time..=max_capture_time).contains(&capture_time),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "The scheduled capture time should be between {} seconds and {} seconds from now",
+                    consts::MIN_SCHEDULED_CAPTURE_DELAY,
+                    consts::MAX_SCHEDULED_CAPTURE_DELAY
+                ),
+            }))
+        },
+    )?;
+
+    Ok(Some(capture_time))
+}
+
+/// Capture method with which the payment is processed by the connector, a payment with the
+/// `scheduled` capture method is authorized with a manual capture and captured by the scheduled
+/// capture workflow at the scheduled time
+pub fn get_connector_capture_method(
+    capture_method: Option<enums::CaptureMethod>,
+) -> Option<enums::CaptureMethod> {
+    match capture_method {
+        Some(enums::CaptureMethod::Scheduled) => Some(enums::CaptureMethod::Manual),
+        capture_method => capture_method,
+    }
+}
+
+/// Validates the installment plan provided in the payment request. Installment plans are only
+/// offered for card payments which are not used to set up a mandate
+#[cfg(feature = "v1")]
+pub fn validate_installment_plan_request(
+    request: &api_models::payments::PaymentsRequest,
+) -> RouterResult<()> {
+    let Some(installment_plan) = request.installment_plan.as_ref() else {
+        return Ok(());
+    };
+
+    installment_plan
+        .validate()
+        .map_err(|error| errors::ApiErrorResponse::InvalidRequestData {
+            message: error.to_string(),
+        })?;
+
+    utils::when(
+        request
+            .payment_method
+            .is_some_and(|payment_method| payment_method != enums::PaymentMethod::Card),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "installment_plan can be provided only for card payments".to_string(),
+            }))
+        },
+    )?;
+
+    utils::when(
+        request.mandate_data.is_some()
+            || request.setup_future_usage == Some(enums::FutureUsage::OffSession),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "installment_plan cannot be provided for payments which set up a mandate"
+                    .to_string(),
+            }))
+        },
+    )
+}
+
+/// Computes the time at which the authorization of the payment attempt expires, as per the rules
+/// of the card network of the payment
+#[cfg(feature = "v1")]
+pub fn get_authorization_expiry(
+    payment_attempt: &PaymentAttempt,
+    authorized_at: time::PrimitiveDateTime,
+    merchant_category_code: Option<common_enums::MerchantCategoryCode>,
+) -> RouterResult<time::PrimitiveDateTime> {
+    let card_network = payment_attempt
+        .payment_method_data
+        .clone()
+        .map(|payment_method_data| {
+            payment_method_data
+                .parse_value::<api_models::payments::AdditionalPaymentData>("AdditionalPaymentData")
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the additional payment data of the payment attempt")?
+        .and_then(|additional_payment_data| match additional_payment_data {
+            api_models::payments::AdditionalPaymentData::Card(card_info) => card_info.card_network,
+            _ => None,
+        });
+
+    let is_extended_authorization_applied = payment_attempt
+        .extended_authorization_applied
+        .is_some_and(|extended_authorization_applied| *extended_authorization_applied);
+
+    let validity_in_days = card_network
+        .map(|card_network| {
+            card_network.get_authorization_validity_in_days(
+                merchant_category_code,
+                is_extended_authorization_applied,
+            )
+        })
+        .unwrap_or(consts::DEFAULT_AUTHORIZATION_VALIDITY_IN_DAYS);
+
+    Ok(authorized_at.saturating_add(time::Duration::days(i64::from(validity_in_days))))
+}
+
 pub fn get_recipient_id_for_open_banking(
     merchant_data: &AdditionalMerchantData,
 ) -> Result<Option<String>, errors::ApiErrorResponse> {
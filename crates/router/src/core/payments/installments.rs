@@ -0,0 +1,338 @@
+use api_models::payments as payment_types;
+use common_utils::{ext_traits::ValueExt, id_type, types::MinorUnit};
+use hyperswitch_domain_models::router_response_types::InstallmentPlanSupport;
+use hyperswitch_interfaces::api::ConnectorSpecifications;
+use masking::{ExposeInterface, PeekInterface};
+use router_env::{instrument, logger, tracing};
+
+use super::helpers;
+use crate::{
+    core::errors::{self, RouterResponse, StorageErrorExt},
+    routes::SessionState,
+    services,
+    types::{api, domain, storage::enums},
+};
+
+/// Lists the installment plans which can be offered for a card, through each connector of the
+/// merchant which has installments enabled for cards
+#[instrument(skip_all)]
+pub async fn list_installment_plans(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: payment_types::InstallmentPlansRequest,
+) -> RouterResponse<payment_types::InstallmentPlansResponse> {
+    let card_isin = helpers::get_card_isin_from_bin(request.card_bin.peek())?;
+
+    let db = &*state.store;
+    let card_issuing_country = helpers::get_card_issuing_country(db, card_isin).await;
+
+    let merchant_connector_accounts = db
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &(&state).into(),
+            merchant_context.get_merchant_account().get_id(),
+            false,
+            merchant_context.get_merchant_key_store(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let connectors = merchant_connector_accounts
+        .iter()
+        .filter(|merchant_connector_account| {
+            merchant_connector_account.connector_type == enums::ConnectorType::PaymentProcessor
+                && request.profile_id.as_ref().map_or(true, |profile_id| {
+                    &merchant_connector_account.profile_id == profile_id
+                })
+                && is_installment_enabled_for_cards(merchant_connector_account)
+        })
+        .filter_map(|merchant_connector_account| {
+            let installment_plans = get_supported_installment_plans(
+                &state,
+                &merchant_connector_account.connector_name,
+                merchant_connector_account.get_id(),
+            )?;
+
+            // Plans are not offered for cards which are known to be issued in a country in which
+            // the connector does not process installments
+            if card_issuing_country
+                .is_some_and(|country| !installment_plans.supported_countries.contains(&country))
+            {
+                return None;
+            }
+
+            Some(payment_types::ConnectorInstallmentPlans {
+                connector: merchant_connector_account.connector_name.clone(),
+                merchant_connector_id: merchant_connector_account.get_id(),
+                plans: get_installment_plan_options(installment_plans, request.amount),
+            })
+        })
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        payment_types::InstallmentPlansResponse {
+            card_issuing_country,
+            connectors,
+        },
+    ))
+}
+
+/// Checks whether installments are enabled for any card payment method type of the merchant
+/// connector account
+fn is_installment_enabled_for_cards(
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+) -> bool {
+    merchant_connector_account
+        .payment_methods_enabled
+        .iter()
+        .flatten()
+        .filter_map(|payment_methods_enabled| {
+            payment_methods_enabled
+                .clone()
+                .expose()
+                .parse_value::<api_models::admin::PaymentMethodsEnabled>("PaymentMethodsEnabled")
+                .map_err(|error| logger::warn!(payment_methods_enabled_parsing_error=?error))
+                .ok()
+        })
+        .filter(|payment_methods_enabled| {
+            payment_methods_enabled.payment_method == enums::PaymentMethod::Card
+        })
+        .flat_map(|payment_methods_enabled| {
+            payment_methods_enabled
+                .payment_method_types
+                .unwrap_or_default()
+        })
+        .any(|payment_method_type| payment_method_type.installment_payment_enabled == Some(true))
+}
+
+fn get_supported_installment_plans(
+    state: &SessionState,
+    connector_name: &str,
+    merchant_connector_id: id_type::MerchantConnectorAccountId,
+) -> Option<&'static InstallmentPlanSupport> {
+    api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        connector_name,
+        api::GetToken::Connector,
+        Some(merchant_connector_id),
+    )
+    .map_err(|error| logger::warn!(connector_data_error=?error))
+    .ok()?
+    .connector
+    .get_supported_installment_plans()
+}
+
+/// Installment plans which can be requested through a connector. The amount of each installment is
+/// only computed for interest free plans, as the interest of the other plans is applied by the
+/// issuer
+fn get_installment_plan_options(
+    installment_plans: &InstallmentPlanSupport,
+    amount: MinorUnit,
+) -> Vec<payment_types::InstallmentPlanOption> {
+    let min_installment_count = installment_plans
+        .min_installment_count
+        .max(common_types::payments::MIN_INSTALLMENT_COUNT);
+    let max_installment_count = installment_plans
+        .max_installment_count
+        .min(common_types::payments::MAX_INSTALLMENT_COUNT);
+    let interest_options: &[bool] = if installment_plans.is_interest_bearing_supported {
+        &[false, true]
+    } else {
+        &[false]
+    };
+
+    installment_plans
+        .supported_intervals
+        .iter()
+        .flat_map(|interval| {
+            (min_installment_count..=max_installment_count).flat_map(move |count| {
+                interest_options.iter().map(move |is_interest_bearing| {
+                    payment_types::InstallmentPlanOption {
+                        count,
+                        interval: *interval,
+                        is_interest_bearing: *is_interest_bearing,
+                        installment_amount: (!is_interest_bearing)
+                            .then(|| get_installment_amount(amount, count)),
+                    }
+                })
+            })
+        })
+        .collect()
+}
+
+/// Amount of each installment of an interest free plan, rounded up so that the installments cover
+/// the whole amount
+fn get_installment_amount(amount: MinorUnit, count: u8) -> MinorUnit {
+    let count = i64::from(count);
+    MinorUnit::new((amount.get_amount_as_i64() + count - 1) / count)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use super::*;
+    use crate::connector;
+
+    static TEST_INSTALLMENT_PLANS: InstallmentPlanSupport = InstallmentPlanSupport {
+        supported_countries: &[enums::CountryAlpha2::BR],
+        supported_intervals: &[
+            enums::InstallmentInterval::Monthly,
+            enums::InstallmentInterval::Weekly,
+        ],
+        min_installment_count: 2,
+        max_installment_count: 3,
+        is_interest_bearing_supported: true,
+    };
+
+    fn assert_installment_plans(
+        installment_plans: Option<&'static InstallmentPlanSupport>,
+        supported_countries: &[enums::CountryAlpha2],
+        max_installment_count: u8,
+    ) {
+        let installment_plans = installment_plans.expect("installment plans are supported");
+
+        assert_eq!(installment_plans.supported_countries, supported_countries);
+        assert_eq!(
+            installment_plans.supported_intervals,
+            &[enums::InstallmentInterval::Monthly]
+        );
+        assert_eq!(installment_plans.min_installment_count, 2);
+        assert_eq!(
+            installment_plans.max_installment_count,
+            max_installment_count
+        );
+        assert!(installment_plans.is_interest_bearing_supported);
+    }
+
+    #[test]
+    fn test_supported_installment_plans_of_connectors() {
+        assert_installment_plans(
+            connector::Dlocal.get_supported_installment_plans(),
+            &[
+                enums::CountryAlpha2::AR,
+                enums::CountryAlpha2::BR,
+                enums::CountryAlpha2::CL,
+                enums::CountryAlpha2::CO,
+                enums::CountryAlpha2::MX,
+                enums::CountryAlpha2::PE,
+                enums::CountryAlpha2::UY,
+            ],
+            24,
+        );
+        assert_installment_plans(
+            connector::Adyen::new().get_supported_installment_plans(),
+            &[
+                enums::CountryAlpha2::BR,
+                enums::CountryAlpha2::CL,
+                enums::CountryAlpha2::CO,
+                enums::CountryAlpha2::JP,
+                enums::CountryAlpha2::MX,
+                enums::CountryAlpha2::PE,
+                enums::CountryAlpha2::TR,
+            ],
+            24,
+        );
+        assert_installment_plans(
+            connector::Cybersource::new().get_supported_installment_plans(),
+            &[
+                enums::CountryAlpha2::BR,
+                enums::CountryAlpha2::CO,
+                enums::CountryAlpha2::MX,
+                enums::CountryAlpha2::PE,
+            ],
+            18,
+        );
+    }
+
+    #[test]
+    fn test_installment_plan_options() {
+        let options = get_installment_plan_options(&TEST_INSTALLMENT_PLANS, MinorUnit::new(1000));
+
+        let options = options
+            .into_iter()
+            .map(|option| {
+                (
+                    option.interval,
+                    option.count,
+                    option.is_interest_bearing,
+                    option.installment_amount,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            options,
+            vec![
+                (
+                    enums::InstallmentInterval::Monthly,
+                    2,
+                    false,
+                    Some(MinorUnit::new(500))
+                ),
+                (enums::InstallmentInterval::Monthly, 2, true, None),
+                (
+                    enums::InstallmentInterval::Monthly,
+                    3,
+                    false,
+                    Some(MinorUnit::new(334))
+                ),
+                (enums::InstallmentInterval::Monthly, 3, true, None),
+                (
+                    enums::InstallmentInterval::Weekly,
+                    2,
+                    false,
+                    Some(MinorUnit::new(500))
+                ),
+                (enums::InstallmentInterval::Weekly, 2, true, None),
+                (
+                    enums::InstallmentInterval::Weekly,
+                    3,
+                    false,
+                    Some(MinorUnit::new(334))
+                ),
+                (enums::InstallmentInterval::Weekly, 3, true, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_installment_plan_options_are_within_the_allowed_counts() {
+        let installment_plans = InstallmentPlanSupport {
+            min_installment_count: 1,
+            max_installment_count: u8::MAX,
+            is_interest_bearing_supported: false,
+            ..TEST_INSTALLMENT_PLANS
+        };
+
+        let options = get_installment_plan_options(&installment_plans, MinorUnit::new(1000));
+
+        assert!(options.iter().all(|option| !option.is_interest_bearing));
+        assert_eq!(
+            options.iter().map(|option| option.count).min(),
+            Some(common_types::payments::MIN_INSTALLMENT_COUNT)
+        );
+        assert_eq!(
+            options.iter().map(|option| option.count).max(),
+            Some(common_types::payments::MAX_INSTALLMENT_COUNT)
+        );
+    }
+
+    #[test]
+    fn test_installment_amount_is_rounded_up() {
+        assert_eq!(
+            get_installment_amount(MinorUnit::new(1200), 12),
+            MinorUnit::new(100)
+        );
+        assert_eq!(
+            get_installment_amount(MinorUnit::new(1000), 3),
+            MinorUnit::new(334)
+        );
+        assert_eq!(
+            get_installment_amount(MinorUnit::new(1), 2),
+            MinorUnit::new(1)
+        );
+        assert_eq!(
+            get_installment_amount(MinorUnit::new(0), 6),
+            MinorUnit::new(0)
+        );
+    }
+}
@@ -3,6 +3,8 @@ pub mod payment_approve;
 #[cfg(feature = "v1")]
 pub mod payment_cancel;
 #[cfg(feature = "v1")]
+pub mod payment_cancel_post_capture;
+#[cfg(feature = "v1")]
 pub mod payment_capture;
 #[cfg(feature = "v1")]
 pub mod payment_complete_authorize;
@@ -16,6 +18,8 @@ pub mod payment_post_session_tokens;
 pub mod payment_reject;
 pub mod payment_response;
 #[cfg(feature = "v1")]
+pub mod payment_reverse_authorization;
+#[cfg(feature = "v1")]
 pub mod payment_session;
 #[cfg(feature = "v2")]
 pub mod payment_session_intent;
@@ -69,10 +73,11 @@ pub use self::payment_update_intent::PaymentUpdateIntent;
 #[cfg(feature = "v1")]
 pub use self::{
     payment_approve::PaymentApprove, payment_cancel::PaymentCancel,
-    payment_capture::PaymentCapture, payment_confirm::PaymentConfirm,
-    payment_create::PaymentCreate, payment_post_session_tokens::PaymentPostSessionTokens,
-    payment_reject::PaymentReject, payment_session::PaymentSession, payment_start::PaymentStart,
-    payment_status::PaymentStatus, payment_update::PaymentUpdate,
+    payment_cancel_post_capture::PaymentCancelPostCapture, payment_capture::PaymentCapture,
+    payment_confirm::PaymentConfirm, payment_create::PaymentCreate,
+    payment_post_session_tokens::PaymentPostSessionTokens, payment_reject::PaymentReject,
+    payment_reverse_authorization::PaymentReverseAuthorization, payment_session::PaymentSession,
+    payment_start::PaymentStart, payment_status::PaymentStatus, payment_update::PaymentUpdate,
     payment_update_metadata::PaymentUpdateMetadata,
     payments_incremental_authorization::PaymentIncrementalAuthorization,
     tax_calculation::PaymentSessionUpdate,
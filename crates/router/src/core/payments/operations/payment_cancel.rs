@@ -11,7 +11,7 @@ use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, Valida
 use crate::{
     core::{
         errors::{self, RouterResult, StorageErrorExt},
-        payments::{helpers, operations, PaymentData},
+        payments::{self, helpers, operations, PaymentData},
     },
     events::audit_events::{AuditEvent, AuditEventType},
     routes::{app::ReqState, SessionState},
@@ -74,6 +74,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsCancelRe
                 enums::IntentStatus::Failed,
                 enums::IntentStatus::Succeeded,
                 enums::IntentStatus::Cancelled,
+                enums::IntentStatus::Expired,
                 enums::IntentStatus::Processing,
                 enums::IntentStatus::RequiresMerchantAction,
             ],
@@ -90,42 +91,10 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsCancelRe
             .await
             .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
-        let shipping_address = helpers::get_address_by_id(
-            state,
-            payment_intent.shipping_address_id.clone(),
-            merchant_context.get_merchant_key_store(),
-            &payment_intent.payment_id,
-            merchant_id,
-            merchant_context.get_merchant_account().storage_scheme,
-        )
-        .await?;
-
-        let billing_address = helpers::get_address_by_id(
-            state,
-            payment_intent.billing_address_id.clone(),
-            merchant_context.get_merchant_key_store(),
-            &payment_intent.payment_id,
-            merchant_id,
-            merchant_context.get_merchant_account().storage_scheme,
-        )
-        .await?;
-
-        let payment_method_billing = helpers::get_address_by_id(
-            state,
-            payment_attempt.payment_method_billing_address_id.clone(),
-            merchant_context.get_merchant_key_store(),
-            &payment_intent.payment_id,
-            merchant_id,
-            merchant_context.get_merchant_account().storage_scheme,
-        )
-        .await?;
-
-        let currency = payment_attempt.currency.get_required_value("currency")?;
-        let amount = payment_attempt.get_total_amount().into();
-
         payment_attempt
             .cancellation_reason
             .clone_from(&request.cancellation_reason);
+        payment_attempt.cancellation_reason_code = request.cancellation_reason_code;
 
         let creds_identifier = request
             .merchant_connector_details
@@ -145,76 +114,14 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsCancelRe
             .await
             .transpose()?;
 
-        let profile_id = payment_intent
-            .profile_id
-            .as_ref()
-            .get_required_value("profile_id")
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("'profile_id' not set in payment intent")?;
-
-        let business_profile = db
-            .find_business_profile_by_profile_id(
-                key_manager_state,
-                merchant_context.get_merchant_key_store(),
-                profile_id,
-            )
-            .await
-            .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
-                id: profile_id.get_string_repr().to_owned(),
-            })?;
-
-        let payment_data = PaymentData {
-            flow: PhantomData,
+        let (payment_data, business_profile) = get_payment_data_for_cancellation(
+            state,
+            merchant_context,
             payment_intent,
             payment_attempt,
-            currency,
-            amount,
-            email: None,
-            mandate_id: None,
-            mandate_connector: None,
-            setup_mandate: None,
-            customer_acceptance: None,
-            token: None,
-            token_data: None,
-            address: core_types::PaymentAddress::new(
-                shipping_address.as_ref().map(From::from),
-                billing_address.as_ref().map(From::from),
-                payment_method_billing.as_ref().map(From::from),
-                business_profile.use_billing_as_payment_method_billing,
-            ),
-            confirm: None,
-            payment_method_data: None,
-            payment_method_info: None,
-            force_sync: None,
-            all_keys_required: None,
-            refunds: vec![],
-            disputes: vec![],
-            attempts: None,
-            sessions_token: vec![],
-            card_cvc: None,
             creds_identifier,
-            pm_token: None,
-            connector_customer_id: None,
-            recurring_mandate_payment_data: None,
-            ephemeral_key: None,
-            multiple_capture_data: None,
-            redirect_response: None,
-            surcharge_details: None,
-            frm_message: None,
-            payment_link_data: None,
-            incremental_authorization_details: None,
-            authorizations: vec![],
-            authentication: None,
-            recurring_details: None,
-            poll_config: None,
-            tax_data: None,
-            session_id: None,
-            service_details: None,
-            card_testing_guard_data: None,
-            vault_operation: None,
-            threeds_method_comp_ind: None,
-            whole_connector_response: None,
-        };
+        )
+        .await?;
 
         let get_trackers_response = operations::GetTrackerResponse {
             operation: Box::new(self),
@@ -228,6 +135,126 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsCancelRe
     }
 }
 
+/// Builds the payment data of the operations voiding the payment, or a part of it, from the
+/// payment intent and the active attempt, along with the profile of the payment
+pub(super) async fn get_payment_data_for_cancellation<F: Clone>(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_intent: storage::PaymentIntent,
+    payment_attempt: storage::PaymentAttempt,
+    creds_identifier: Option<String>,
+) -> RouterResult<(PaymentData<F>, domain::Profile)> {
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+
+    let shipping_address = helpers::get_address_by_id(
+        state,
+        payment_intent.shipping_address_id.clone(),
+        merchant_context.get_merchant_key_store(),
+        &payment_intent.payment_id,
+        merchant_id,
+        storage_scheme,
+    )
+    .await?;
+
+    let billing_address = helpers::get_address_by_id(
+        state,
+        payment_intent.billing_address_id.clone(),
+        merchant_context.get_merchant_key_store(),
+        &payment_intent.payment_id,
+        merchant_id,
+        storage_scheme,
+    )
+    .await?;
+
+    let payment_method_billing = helpers::get_address_by_id(
+        state,
+        payment_attempt.payment_method_billing_address_id.clone(),
+        merchant_context.get_merchant_key_store(),
+        &payment_intent.payment_id,
+        merchant_id,
+        storage_scheme,
+    )
+    .await?;
+
+    let currency = payment_attempt.currency.get_required_value("currency")?;
+    let amount = payment_attempt.get_total_amount().into();
+
+    let profile_id = payment_intent
+        .profile_id
+        .as_ref()
+        .get_required_value("profile_id")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("'profile_id' not set in payment intent")?;
+
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(
+            &state.into(),
+            merchant_context.get_merchant_key_store(),
+            profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let payment_data = PaymentData {
+        flow: PhantomData,
+        payment_intent,
+        payment_attempt,
+        currency,
+        amount,
+        email: None,
+        mandate_id: None,
+        mandate_connector: None,
+        setup_mandate: None,
+        customer_acceptance: None,
+        token: None,
+        token_data: None,
+        address: core_types::PaymentAddress::new(
+            shipping_address.as_ref().map(From::from),
+            billing_address.as_ref().map(From::from),
+            payment_method_billing.as_ref().map(From::from),
+            business_profile.use_billing_as_payment_method_billing,
+        ),
+        confirm: None,
+        payment_method_data: None,
+        payment_method_info: None,
+        force_sync: None,
+        all_keys_required: None,
+        refunds: vec![],
+        disputes: vec![],
+        attempts: None,
+        sessions_token: vec![],
+        card_cvc: None,
+        creds_identifier,
+        pm_token: None,
+        connector_customer_id: None,
+        recurring_mandate_payment_data: None,
+        ephemeral_key: None,
+        multiple_capture_data: None,
+        redirect_response: None,
+        surcharge_details: None,
+        frm_message: None,
+        payment_link_data: None,
+        incremental_authorization_details: None,
+        authorizations: vec![],
+        authentication: None,
+        recurring_details: None,
+        poll_config: None,
+        tax_data: None,
+        session_id: None,
+        service_details: None,
+        card_testing_guard_data: None,
+        vault_operation: None,
+        threeds_method_comp_ind: None,
+        whole_connector_response: None,
+    };
+
+    Ok((payment_data, business_profile))
+}
+
 #[async_trait]
 impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsCancelRequest>
     for PaymentCancel
@@ -249,6 +276,7 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsCancelReques
         F: 'b + Send,
     {
         let cancellation_reason = payment_data.payment_attempt.cancellation_reason.clone();
+        let cancellation_reason_code = payment_data.payment_attempt.cancellation_reason_code;
         let (intent_status_update, attempt_status_update) =
             if payment_data.payment_intent.status != enums::IntentStatus::RequiresCapture {
                 let payment_intent_update = storage::PaymentIntentUpdate::PGStatusUpdate {
@@ -282,16 +310,27 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsCancelReques
                 storage::PaymentAttemptUpdate::VoidUpdate {
                     status: attempt_status_update,
                     cancellation_reason: cancellation_reason.clone(),
+                    cancellation_reason_code,
                     updated_by: storage_scheme.to_string(),
                 },
                 storage_scheme,
             )
             .await
             .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        // The payment is not captured at the scheduled time once it is cancelled
+        if payment_data.payment_attempt.capture_on.is_some() {
+            payments::revoke_scheduled_capture_task(&*state.store, &payment_data.payment_attempt)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while revoking the scheduled capture task")?;
+        }
+
         req_state
             .event_context
             .event(AuditEvent::new(AuditEventType::PaymentCancelled {
                 cancellation_reason,
+                cancellation_reason_code,
             }))
             .with(payment_data.to_event())
             .emit();
@@ -0,0 +1,178 @@
+use api_models::enums::FrmSuggestion;
+use async_trait::async_trait;
+use error_stack::{report, ResultExt};
+use router_derive;
+use router_env::{instrument, tracing};
+
+use super::{
+    payment_cancel, BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, ValidateRequest,
+};
+use crate::{
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        payments::{helpers, operations, PaymentData},
+    },
+    events::audit_events::{AuditEvent, AuditEventType},
+    routes::{app::ReqState, SessionState},
+    services,
+    types::{
+        api::{self, PaymentIdTypeExt},
+        domain,
+        storage::{self, enums},
+    },
+    utils,
+};
+
+#[derive(Debug, Clone, Copy, router_derive::PaymentOperation)]
+#[operation(operations = "all", flow = "cancel")]
+pub struct PaymentCancelPostCapture;
+
+type PaymentCancelPostCaptureOperation<'b, F> =
+    BoxedOperation<'b, F, api::PaymentsCancelRequest, PaymentData<F>>;
+
+#[async_trait]
+impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsCancelRequest>
+    for PaymentCancelPostCapture
+{
+    #[instrument(skip_all)]
+    async fn get_trackers<'a>(
+        &'a self,
+        state: &'a SessionState,
+        payment_id: &api::PaymentIdType,
+        request: &api::PaymentsCancelRequest,
+        merchant_context: &domain::MerchantContext,
+        _auth_flow: services::AuthFlow,
+        _header_payload: &hyperswitch_domain_models::payments::HeaderPayload,
+    ) -> RouterResult<
+        operations::GetTrackerResponse<'a, F, api::PaymentsCancelRequest, PaymentData<F>>,
+    > {
+        let db = &*state.store;
+        let key_manager_state = &state.into();
+
+        let merchant_id = merchant_context.get_merchant_account().get_id();
+        let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+        let payment_id = payment_id
+            .get_payment_intent_id()
+            .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                key_manager_state,
+                &payment_id,
+                merchant_id,
+                merchant_context.get_merchant_key_store(),
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        helpers::validate_payment_status_against_allowed_statuses(
+            payment_intent.status,
+            &[enums::IntentStatus::Succeeded],
+            "void the capture of",
+        )?;
+
+        let mut payment_attempt = db
+            .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+                &payment_intent.payment_id,
+                merchant_id,
+                payment_intent.active_attempt.get_id().as_str(),
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        utils::when(
+            !helpers::is_post_capture_void_possible(state, &payment_attempt)?,
+            || {
+                Err(report!(errors::ApiErrorResponse::NotSupported {
+                    message: "The capture of this payment can no longer be voided".to_string(),
+                }))
+            },
+        )?;
+
+        // The reason is only stored once the connector voids the capture, as the payment remains
+        // captured otherwise
+        payment_attempt
+            .cancellation_reason
+            .clone_from(&request.cancellation_reason);
+        payment_attempt.cancellation_reason_code = request.cancellation_reason_code;
+
+        let (payment_data, business_profile) = payment_cancel::get_payment_data_for_cancellation(
+            state,
+            merchant_context,
+            payment_intent,
+            payment_attempt,
+            None,
+        )
+        .await?;
+
+        let get_trackers_response = operations::GetTrackerResponse {
+            operation: Box::new(self),
+            customer_details: None,
+            payment_data,
+            business_profile,
+            mandate_type: None,
+        };
+
+        Ok(get_trackers_response)
+    }
+}
+
+#[async_trait]
+impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsCancelRequest>
+    for PaymentCancelPostCapture
+{
+    #[instrument(skip_all)]
+    async fn update_trackers<'b>(
+        &'b self,
+        _state: &'b SessionState,
+        req_state: ReqState,
+        payment_data: PaymentData<F>,
+        _customer: Option<domain::Customer>,
+        _storage_scheme: enums::MerchantStorageScheme,
+        _updated_customer: Option<storage::CustomerUpdate>,
+        _key_store: &domain::MerchantKeyStore,
+        _frm_suggestion: Option<FrmSuggestion>,
+        _header_payload: hyperswitch_domain_models::payments::HeaderPayload,
+    ) -> RouterResult<(PaymentCancelPostCaptureOperation<'b, F>, PaymentData<F>)>
+    where
+        F: 'b + Send,
+    {
+        req_state
+            .event_context
+            .event(AuditEvent::new(
+                AuditEventType::PaymentCancelledPostCapture {
+                    cancellation_reason: payment_data.payment_attempt.cancellation_reason.clone(),
+                    cancellation_reason_code: payment_data.payment_attempt.cancellation_reason_code,
+                },
+            ))
+            .with(payment_data.to_event())
+            .emit();
+        Ok((Box::new(self), payment_data))
+    }
+}
+
+impl<F: Send + Clone + Sync> ValidateRequest<F, api::PaymentsCancelRequest, PaymentData<F>>
+    for PaymentCancelPostCapture
+{
+    #[instrument(skip_all)]
+    fn validate_request<'a, 'b>(
+        &'b self,
+        request: &api::PaymentsCancelRequest,
+        merchant_context: &'a domain::MerchantContext,
+    ) -> RouterResult<(
+        PaymentCancelPostCaptureOperation<'b, F>,
+        operations::ValidateResult,
+    )> {
+        Ok((
+            Box::new(self),
+            operations::ValidateResult {
+                merchant_id: merchant_context.get_merchant_account().get_id().to_owned(),
+                payment_id: api::PaymentIdType::PaymentIntentId(request.payment_id.to_owned()),
+                storage_scheme: merchant_context.get_merchant_account().storage_scheme,
+                requeue: false,
+            },
+        ))
+    }
+}
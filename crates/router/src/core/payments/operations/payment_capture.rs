@@ -21,7 +21,7 @@ use crate::{
         domain,
         storage::{self, enums, payment_attempt::PaymentAttemptExt},
     },
-    utils::OptionExt,
+    utils::{self, OptionExt},
 };
 
 #[derive(Debug, Clone, Copy, router_derive::PaymentOperation)]
@@ -103,6 +103,18 @@ impl<F: Send + Clone + Sync> GetTracker<F, payments::PaymentData<F>, api::Paymen
 
         helpers::validate_capture_method(capture_method)?;
 
+        if request.refund_uncaptured_amount == Some(true) {
+            utils::when(
+                capture_method != enums::CaptureMethod::ManualMultiple,
+                || {
+                    Err(errors::ApiErrorResponse::PreconditionFailed {
+                        message: "The uncaptured amount can be released only for payments with capture method manual_multiple".to_string(),
+                    })
+                },
+            )?;
+            helpers::validate_partial_authorization_reversal_support(state, &payment_attempt)?;
+        }
+
         let multiple_capture_data = if capture_method == enums::CaptureMethod::ManualMultiple {
             let amount_to_capture = request
                 .amount_to_capture
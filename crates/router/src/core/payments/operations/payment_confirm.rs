@@ -35,8 +35,8 @@ use crate::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         mandate::helpers as m_helpers,
         payments::{
-            self, helpers, operations, populate_surcharge_details, CustomerDetails, PaymentAddress,
-            PaymentData,
+            self, dynamic_currency_conversion, helpers, operations, populate_surcharge_details,
+            CustomerDetails, PaymentAddress, PaymentData,
         },
         unified_authentication_service::{
             self as uas_utils,
@@ -125,6 +125,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
                 payment_intent.status,
                 &[
                     storage_enums::IntentStatus::Cancelled,
+                    storage_enums::IntentStatus::Expired,
                     storage_enums::IntentStatus::Succeeded,
                     storage_enums::IntentStatus::Processing,
                     storage_enums::IntentStatus::RequiresCapture,
@@ -137,6 +138,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
                 payment_intent.status,
                 &[
                     storage_enums::IntentStatus::Cancelled,
+                    storage_enums::IntentStatus::Expired,
                     storage_enums::IntentStatus::Succeeded,
                     storage_enums::IntentStatus::Processing,
                     storage_enums::IntentStatus::RequiresCapture,
@@ -403,6 +405,37 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
 
         payment_attempt.capture_method = request.capture_method.or(payment_attempt.capture_method);
 
+        if let Some(capture_on) =
+            helpers::get_scheduled_capture_time(request, payment_attempt.capture_method, true)?
+        {
+            payment_attempt.capture_on = Some(capture_on);
+        }
+
+        payment_attempt.installment_plan = request
+            .installment_plan
+            .or(payment_attempt.installment_plan);
+
+        if let Some(quote_id) = request.currency_conversion_quote_id.as_deref() {
+            let card_isin = request
+                .payment_method_data
+                .as_ref()
+                .and_then(|pmd| pmd.payment_method_data.as_ref())
+                .and_then(|pmd| match pmd {
+                    api::PaymentMethodData::Card(card) => Some(card.card_number.get_card_isin()),
+                    _ => None,
+                });
+
+            payment_attempt.currency_conversion = Some(
+                dynamic_currency_conversion::get_accepted_currency_conversion(
+                    state,
+                    &payment_attempt,
+                    quote_id,
+                    card_isin,
+                )
+                .await?,
+            );
+        }
+
         payment_attempt.customer_acceptance = request
             .customer_acceptance
             .clone()
@@ -1611,6 +1644,13 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for
                 _ => default_status_result,
             };
 
+        helpers::validate_installment_plan_support(
+            state,
+            &payment_data.payment_attempt,
+            payment_data.payment_method_data.as_ref(),
+        )
+        .await?;
+
         let connector = payment_data.payment_attempt.connector.clone();
         let merchant_connector_id = payment_data.payment_attempt.merchant_connector_id.clone();
 
@@ -1794,6 +1834,9 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for
                             .payment_attempt
                             .connector_mandate_detail,
                         card_discovery,
+                        capture_on: payment_data.payment_attempt.capture_on,
+                        installment_plan: payment_data.payment_attempt.installment_plan,
+                        currency_conversion: payment_data.payment_attempt.currency_conversion,
                     },
                     storage_scheme,
                 )
@@ -1935,6 +1978,17 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for
         payment_data.payment_intent = payment_intent;
         payment_data.payment_attempt = payment_attempt;
 
+        if let Some(capture_on) = payment_data.payment_attempt.capture_on {
+            payments::add_scheduled_capture_task(
+                &*state.store,
+                &payment_data.payment_attempt,
+                capture_on,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while adding scheduled capture task to process tracker")?;
+        }
+
         let client_src = payment_data.payment_attempt.client_source.clone();
         let client_ver = payment_data.payment_attempt.client_version.clone();
 
@@ -1979,6 +2033,7 @@ impl<F: Send + Clone + Sync> ValidateRequest<F, api::PaymentsRequest, PaymentDat
         })?;
 
         helpers::validate_payment_method_fields_present(request)?;
+        helpers::validate_installment_plan_request(request)?;
 
         let _mandate_type =
             helpers::validate_mandate(request, payments::is_operation_confirm(self))?;
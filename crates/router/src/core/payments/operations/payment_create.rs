@@ -346,6 +346,15 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
                 payment_id: payment_id.clone(),
             })?;
 
+        if let Some(payment_intent_expiry) = business_profile.payment_intent_expiry {
+            payments::add_payment_intent_expiry_task(db, &payment_intent, payment_intent_expiry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(
+                    "Failed while adding payment intent expiry task to process tracker",
+                )?;
+        }
+
         if let Some(order_details) = &request.order_details {
             helpers::validate_order_details_amount(
                 order_details.to_owned(),
@@ -861,6 +870,15 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for
             _ => None,
         };
 
+        if payment_data.confirm.unwrap_or(false) {
+            helpers::validate_installment_plan_support(
+                state,
+                &payment_data.payment_attempt,
+                payment_data.payment_method_data.as_ref(),
+            )
+            .await?;
+        }
+
         let payment_token = payment_data.token.clone();
         let connector = payment_data.payment_attempt.connector.clone();
         let straight_through_algorithm = payment_data
@@ -901,6 +919,17 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for
             .await
             .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
+        if let Some(capture_on) = payment_data.payment_attempt.capture_on {
+            payments::add_scheduled_capture_task(
+                &*state.store,
+                &payment_data.payment_attempt,
+                capture_on,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while adding scheduled capture task to process tracker")?;
+        }
+
         let customer_id = payment_data.payment_intent.customer_id.clone();
 
         let raw_customer_details = customer
@@ -1006,6 +1035,15 @@ impl<F: Send + Clone + Sync> ValidateRequest<F, api::PaymentsRequest, PaymentDat
         )?;
 
         helpers::validate_payment_method_fields_present(request)?;
+        helpers::validate_installment_plan_request(request)?;
+
+        // Quotes are requested for existing payments, so they can only be accepted at confirm
+        utils::when(request.currency_conversion_quote_id.is_some(), || {
+            Err(error_stack::report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "currency_conversion_quote_id can be provided only when confirming a payment"
+                    .to_string(),
+            }))
+        })?;
 
         let mandate_type =
             helpers::validate_mandate(request, payments::is_operation_confirm(self))?;
@@ -1132,6 +1170,12 @@ impl PaymentCreate {
 
         let created_at @ modified_at @ last_synced = Some(common_utils::date_time::now());
         let status = helpers::payment_attempt_status_fsm(payment_method_data, request.confirm);
+
+        let capture_on = helpers::get_scheduled_capture_time(
+            request,
+            request.capture_method,
+            request.confirm.unwrap_or(false),
+        )?;
         let (amount, currency) = (money.0, Some(money.1));
 
         let mut additional_pm_data = request
@@ -1293,7 +1337,7 @@ impl PaymentCreate {
                 currency,
                 payment_method,
                 capture_method: request.capture_method,
-                capture_on: request.capture_on,
+                capture_on,
                 confirm: request.confirm.unwrap_or(false),
                 created_at,
                 modified_at,
@@ -1362,6 +1406,13 @@ impl PaymentCreate {
                 processor_merchant_id: merchant_id.to_owned(),
                 created_by: None,
                 setup_future_usage_applied: request.setup_future_usage,
+                authorization_reversal_status: None,
+                amount_reversed: None,
+                installment_plan: request.installment_plan,
+                currency_conversion: None,
+                cancellation_reason_code: None,
+                authorized_at: None,
+                captured_at: None,
             },
             additional_pm_data,
 
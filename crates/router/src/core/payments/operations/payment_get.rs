@@ -42,6 +42,7 @@ impl ValidateStatusForOperation for PaymentGet {
             | common_enums::IntentStatus::PartiallyCapturedAndCapturable
             | common_enums::IntentStatus::PartiallyCaptured
             | common_enums::IntentStatus::Cancelled
+            | common_enums::IntentStatus::Expired
             | common_enums::IntentStatus::Conflicted => Ok(()),
             // These statuses are not valid for this operation
             common_enums::IntentStatus::RequiresConfirmation
@@ -60,6 +61,7 @@ impl ValidateStatusForOperation for PaymentGet {
                         common_enums::IntentStatus::PartiallyCapturedAndCapturable,
                         common_enums::IntentStatus::PartiallyCaptured,
                         common_enums::IntentStatus::Cancelled,
+                        common_enums::IntentStatus::Expired,
                     ]
                     .map(|enum_value| enum_value.to_string())
                     .join(", "),
@@ -68,6 +68,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, PaymentsCancelRequest
             payment_intent.status,
             &[
                 enums::IntentStatus::Cancelled,
+                enums::IntentStatus::Expired,
                 enums::IntentStatus::Failed,
                 enums::IntentStatus::Succeeded,
                 enums::IntentStatus::Processing,
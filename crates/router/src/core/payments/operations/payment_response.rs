@@ -24,6 +24,8 @@ use storage_impl::DataModelExt;
 use tracing_futures::Instrument;
 
 use super::{Operation, OperationSessionSetters, PostUpdateTracker};
+#[cfg(feature = "v1")]
+use crate::core::ledger;
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use crate::core::routing::helpers as routing_helpers;
 #[cfg(feature = "v2")]
@@ -1005,6 +1007,32 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsCancelData> f
     where
         F: 'b + Send,
     {
+        if payment_data.payment_attempt.authorization_reversal_status
+            == Some(enums::AuthorizationReversalStatus::Pending)
+        {
+            return Box::pin(authorization_reversal_response_update_tracker(
+                db,
+                payment_data,
+                router_data,
+                key_store,
+                storage_scheme,
+            ))
+            .await;
+        }
+
+        // Only captured payments reach the void flow with a succeeded status, when their capture is
+        // being voided before it is settled
+        if payment_data.payment_intent.status == enums::IntentStatus::Succeeded {
+            return Box::pin(post_capture_void_response_update_tracker(
+                db,
+                payment_data,
+                router_data,
+                key_store,
+                storage_scheme,
+            ))
+            .await;
+        }
+
         payment_data = Box::pin(payment_response_update_tracker(
             db,
             payment_data,
@@ -1346,6 +1374,184 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::CompleteAuthorizeData
     }
 }
 
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+async fn post_capture_void_response_update_tracker<F: Clone>(
+    state: &SessionState,
+    mut payment_data: PaymentData<F>,
+    router_data: types::RouterData<F, types::PaymentsCancelData, types::PaymentsResponseData>,
+    key_store: &domain::MerchantKeyStore,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> RouterResult<PaymentData<F>> {
+    // The payment remains captured when the connector does not void the capture, so that it can be
+    // refunded instead
+    if !is_capture_voided_by_connector(router_data.status, &router_data.response) {
+        return Ok(payment_data);
+    }
+
+    payment_data.payment_attempt = state
+        .store
+        .update_payment_attempt_with_attempt_id(
+            payment_data.payment_attempt.clone(),
+            storage::PaymentAttemptUpdate::VoidUpdate {
+                status: enums::AttemptStatus::Voided,
+                cancellation_reason: payment_data.payment_attempt.cancellation_reason.clone(),
+                cancellation_reason_code: payment_data.payment_attempt.cancellation_reason_code,
+                updated_by: storage_scheme.to_string(),
+            },
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    payment_data.payment_intent = state
+        .store
+        .update_payment_intent(
+            &state.into(),
+            payment_data.payment_intent.clone(),
+            storage::PaymentIntentUpdate::PGStatusUpdate {
+                status: enums::IntentStatus::Cancelled,
+                updated_by: storage_scheme.to_string(),
+                incremental_authorization_allowed: Some(false),
+            },
+            key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    ledger::record_payment_entries(
+        state,
+        &payment_data.payment_intent,
+        &payment_data.payment_attempt,
+        payment_data
+            .multiple_capture_data
+            .as_ref()
+            .map(|multiple_capture_data| multiple_capture_data.get_all_captures()),
+    )
+    .await;
+
+    Ok(payment_data)
+}
+
+/// Whether the connector voided the capture, given the status and the response of the void
+#[cfg(feature = "v1")]
+fn is_capture_voided_by_connector(
+    status: enums::AttemptStatus,
+    response: &Result<types::PaymentsResponseData, types::ErrorResponse>,
+) -> bool {
+    match response {
+        Ok(_) if status == enums::AttemptStatus::Voided => true,
+        Ok(_) => {
+            logger::info!(%status, "Void of the capture ended in an unexpected status");
+            false
+        }
+        Err(error_response) => {
+            logger::info!(
+                error_code = %error_response.code,
+                error_message = %error_response.message,
+                "Connector failed to void the capture"
+            );
+            false
+        }
+    }
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+async fn authorization_reversal_response_update_tracker<F: Clone>(
+    state: &SessionState,
+    mut payment_data: PaymentData<F>,
+    router_data: types::RouterData<F, types::PaymentsCancelData, types::PaymentsResponseData>,
+    key_store: &domain::MerchantKeyStore,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> RouterResult<PaymentData<F>> {
+    // The payment remains partially captured irrespective of the outcome of the reversal, only the
+    // uncaptured remainder of the authorization is released when the connector voids it
+    let (payment_attempt_update, payment_intent_update) = match &router_data.response {
+        Ok(_) if router_data.status == enums::AttemptStatus::Voided => (
+            storage::PaymentAttemptUpdate::AuthorizationReversalUpdate {
+                status: Some(enums::AttemptStatus::PartialCharged),
+                authorization_reversal_status: enums::AuthorizationReversalStatus::Success,
+                amount_reversed: router_data.request.minor_amount,
+                amount_capturable: Some(MinorUnit::zero()),
+                error_code: Some(None),
+                error_message: Some(None),
+                updated_by: storage_scheme.to_string(),
+            },
+            Some(storage::PaymentIntentUpdate::PGStatusUpdate {
+                status: enums::IntentStatus::PartiallyCaptured,
+                updated_by: storage_scheme.to_string(),
+                incremental_authorization_allowed: Some(false),
+            }),
+        ),
+        Ok(_) => (
+            storage::PaymentAttemptUpdate::AuthorizationReversalUpdate {
+                status: None,
+                authorization_reversal_status: enums::AuthorizationReversalStatus::Failure,
+                amount_reversed: None,
+                amount_capturable: None,
+                error_code: None,
+                error_message: Some(Some(format!(
+                    "Authorization reversal ended in unexpected status {}",
+                    router_data.status
+                ))),
+                updated_by: storage_scheme.to_string(),
+            },
+            None,
+        ),
+        Err(error_response) => (
+            storage::PaymentAttemptUpdate::AuthorizationReversalUpdate {
+                status: None,
+                authorization_reversal_status: enums::AuthorizationReversalStatus::Failure,
+                amount_reversed: None,
+                amount_capturable: None,
+                error_code: Some(Some(error_response.code.clone())),
+                error_message: Some(Some(error_response.message.clone())),
+                updated_by: storage_scheme.to_string(),
+            },
+            None,
+        ),
+    };
+
+    payment_data.payment_attempt = state
+        .store
+        .update_payment_attempt_with_attempt_id(
+            payment_data.payment_attempt.clone(),
+            payment_attempt_update,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    if let Some(payment_intent_update) = payment_intent_update {
+        payment_data.payment_intent = state
+            .store
+            .update_payment_intent(
+                &state.into(),
+                payment_data.payment_intent.clone(),
+                payment_intent_update,
+                key_store,
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    }
+
+    ledger::record_payment_entries(
+        state,
+        &payment_data.payment_intent,
+        &payment_data.payment_attempt,
+        payment_data
+            .multiple_capture_data
+            .as_ref()
+            .map(|multiple_capture_data| multiple_capture_data.get_all_captures()),
+    )
+    .await;
+
+    Ok(payment_data)
+}
+
 #[cfg(feature = "v1")]
 #[instrument(skip_all)]
 #[allow(clippy::too_many_arguments)]
@@ -2227,6 +2433,39 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
             .map(|info| info.status = status)
     });
 
+    if let (Some(payment_method), Some(profile_id)) = (
+        payment_data.payment_method_info.as_ref(),
+        payment_data.payment_intent.profile_id.as_ref(),
+    ) {
+        if let Some(reason) = payment_methods::card_account_updater::get_card_account_update_reason(
+            state,
+            payment_method,
+            payment_data.payment_attempt.status,
+            gsm_error_category,
+        ) {
+            payment_methods::card_account_updater::enroll_payment_method_for_card_account_update(
+                state,
+                payment_method,
+                Some(profile_id),
+                reason,
+            )
+            .await
+            .map_err(|error| logger::warn!(card_account_updater_enrollment_error=?error))
+            .ok();
+        }
+    }
+
+    ledger::record_payment_entries(
+        state,
+        &payment_data.payment_intent,
+        &payment_data.payment_attempt,
+        payment_data
+            .multiple_capture_data
+            .as_ref()
+            .map(|multiple_capture_data| multiple_capture_data.get_all_captures()),
+    )
+    .await;
+
     if payment_data.payment_attempt.status == enums::AttemptStatus::Failure {
         let _ = card_testing_guard_utils::increment_blocked_count_in_cache(
             state,
@@ -2990,3 +3229,43 @@ fn get_total_amount_captured<F: Clone, T: types::Capturable>(
         }
     }
 }
+
+#[cfg(feature = "v1")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_void_response() -> Result<types::PaymentsResponseData, types::ErrorResponse> {
+        Ok(types::PaymentsResponseData::TransactionResponse {
+            resource_id: types::ResponseId::ConnectorTransactionId("void_1".to_string()),
+            redirection_data: Box::new(None),
+            mandate_reference: Box::new(None),
+            connector_metadata: None,
+            network_txn_id: None,
+            connector_response_reference_id: None,
+            incremental_authorization_allowed: None,
+            charges: None,
+        })
+    }
+
+    #[test]
+    fn test_is_capture_voided_by_connector() {
+        assert!(is_capture_voided_by_connector(
+            enums::AttemptStatus::Voided,
+            &get_void_response()
+        ));
+        // The payment remains captured so that it can be refunded instead
+        assert!(!is_capture_voided_by_connector(
+            enums::AttemptStatus::Charged,
+            &get_void_response()
+        ));
+        assert!(!is_capture_voided_by_connector(
+            enums::AttemptStatus::VoidFailed,
+            &Err(types::ErrorResponse::default())
+        ));
+        assert!(!is_capture_voided_by_connector(
+            enums::AttemptStatus::Voided,
+            &Err(types::ErrorResponse::default())
+        ));
+    }
+}
@@ -0,0 +1,222 @@
+use api_models::enums::FrmSuggestion;
+use async_trait::async_trait;
+use common_utils::{ext_traits::AsyncExt, types::MinorUnit};
+use error_stack::ResultExt;
+use router_derive;
+use router_env::{instrument, tracing};
+
+use super::{
+    payment_cancel, BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, ValidateRequest,
+};
+use crate::{
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        payments::{helpers, operations, PaymentData},
+    },
+    events::audit_events::{AuditEvent, AuditEventType},
+    routes::{app::ReqState, SessionState},
+    services,
+    types::{
+        api::{self, PaymentIdTypeExt},
+        domain,
+        storage::{self, enums},
+    },
+    utils,
+};
+
+/// Cancellation reason recorded on the payment attempt when the request does not provide one
+const AUTHORIZATION_REVERSAL_CANCELLATION_REASON: &str = "release_uncaptured_amount";
+
+#[derive(Debug, Clone, Copy, router_derive::PaymentOperation)]
+#[operation(operations = "all", flow = "cancel")]
+pub struct PaymentReverseAuthorization;
+
+type PaymentReverseAuthorizationOperation<'b, F> =
+    BoxedOperation<'b, F, api::PaymentsCancelRequest, PaymentData<F>>;
+
+#[async_trait]
+impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsCancelRequest>
+    for PaymentReverseAuthorization
+{
+    #[instrument(skip_all)]
+    async fn get_trackers<'a>(
+        &'a self,
+        state: &'a SessionState,
+        payment_id: &api::PaymentIdType,
+        request: &api::PaymentsCancelRequest,
+        merchant_context: &domain::MerchantContext,
+        _auth_flow: services::AuthFlow,
+        _header_payload: &hyperswitch_domain_models::payments::HeaderPayload,
+    ) -> RouterResult<
+        operations::GetTrackerResponse<'a, F, api::PaymentsCancelRequest, PaymentData<F>>,
+    > {
+        let db = &*state.store;
+        let key_manager_state = &state.into();
+
+        let merchant_id = merchant_context.get_merchant_account().get_id();
+        let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+        let payment_id = payment_id
+            .get_payment_intent_id()
+            .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                key_manager_state,
+                &payment_id,
+                merchant_id,
+                merchant_context.get_merchant_key_store(),
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        helpers::validate_payment_status_against_allowed_statuses(
+            payment_intent.status,
+            &[enums::IntentStatus::PartiallyCapturedAndCapturable],
+            "release the uncaptured amount of",
+        )?;
+
+        let mut payment_attempt = db
+            .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+                &payment_intent.payment_id,
+                merchant_id,
+                payment_intent.active_attempt.get_id().as_str(),
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        utils::when(
+            payment_attempt.amount_capturable <= MinorUnit::zero(),
+            || {
+                Err(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "There is no uncaptured amount to be released for this payment"
+                        .to_string(),
+                })
+            },
+        )?;
+
+        helpers::validate_partial_authorization_reversal_support(state, &payment_attempt)?;
+
+        payment_attempt.cancellation_reason = request
+            .cancellation_reason
+            .clone()
+            .or_else(|| Some(AUTHORIZATION_REVERSAL_CANCELLATION_REASON.to_string()));
+        payment_attempt.authorization_reversal_status =
+            Some(enums::AuthorizationReversalStatus::Pending);
+
+        let creds_identifier = request
+            .merchant_connector_details
+            .as_ref()
+            .map(|mcd| mcd.creds_identifier.to_owned());
+        request
+            .merchant_connector_details
+            .to_owned()
+            .async_map(|mcd| async {
+                helpers::insert_merchant_connector_creds_to_config(
+                    db,
+                    merchant_context.get_merchant_account().get_id(),
+                    mcd,
+                )
+                .await
+            })
+            .await
+            .transpose()?;
+
+        let (payment_data, business_profile) = payment_cancel::get_payment_data_for_cancellation(
+            state,
+            merchant_context,
+            payment_intent,
+            payment_attempt,
+            creds_identifier,
+        )
+        .await?;
+
+        let get_trackers_response = operations::GetTrackerResponse {
+            operation: Box::new(self),
+            customer_details: None,
+            payment_data,
+            business_profile,
+            mandate_type: None,
+        };
+
+        Ok(get_trackers_response)
+    }
+}
+
+#[async_trait]
+impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsCancelRequest>
+    for PaymentReverseAuthorization
+{
+    #[instrument(skip_all)]
+    async fn update_trackers<'b>(
+        &'b self,
+        state: &'b SessionState,
+        req_state: ReqState,
+        payment_data: PaymentData<F>,
+        _customer: Option<domain::Customer>,
+        storage_scheme: enums::MerchantStorageScheme,
+        _updated_customer: Option<storage::CustomerUpdate>,
+        _key_store: &domain::MerchantKeyStore,
+        _frm_suggestion: Option<FrmSuggestion>,
+        _header_payload: hyperswitch_domain_models::payments::HeaderPayload,
+    ) -> RouterResult<(PaymentReverseAuthorizationOperation<'b, F>, PaymentData<F>)>
+    where
+        F: 'b + Send,
+    {
+        let cancellation_reason = payment_data.payment_attempt.cancellation_reason.clone();
+
+        state
+            .store
+            .update_payment_attempt_with_attempt_id(
+                payment_data.payment_attempt.clone(),
+                storage::PaymentAttemptUpdate::AuthorizationReversalUpdate {
+                    status: None,
+                    authorization_reversal_status: enums::AuthorizationReversalStatus::Pending,
+                    amount_reversed: None,
+                    amount_capturable: None,
+                    error_code: None,
+                    error_message: None,
+                    updated_by: storage_scheme.to_string(),
+                },
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        req_state
+            .event_context
+            .event(AuditEvent::new(
+                AuditEventType::PaymentAuthorizationReversed {
+                    cancellation_reason,
+                },
+            ))
+            .with(payment_data.to_event())
+            .emit();
+        Ok((Box::new(self), payment_data))
+    }
+}
+
+impl<F: Send + Clone + Sync> ValidateRequest<F, api::PaymentsCancelRequest, PaymentData<F>>
+    for PaymentReverseAuthorization
+{
+    #[instrument(skip_all)]
+    fn validate_request<'a, 'b>(
+        &'b self,
+        request: &api::PaymentsCancelRequest,
+        merchant_context: &'a domain::MerchantContext,
+    ) -> RouterResult<(
+        PaymentReverseAuthorizationOperation<'b, F>,
+        operations::ValidateResult,
+    )> {
+        Ok((
+            Box::new(self),
+            operations::ValidateResult {
+                merchant_id: merchant_context.get_merchant_account().get_id().to_owned(),
+                payment_id: api::PaymentIdType::PaymentIntentId(request.payment_id.to_owned()),
+                storage_scheme: merchant_context.get_merchant_account().storage_scheme,
+                requeue: false,
+            },
+        ))
+    }
+}
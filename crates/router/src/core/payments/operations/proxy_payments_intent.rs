@@ -48,6 +48,7 @@ impl ValidateStatusForOperation for PaymentProxyIntent {
             common_enums::IntentStatus::Conflicted
             | common_enums::IntentStatus::Succeeded
             | common_enums::IntentStatus::Cancelled
+            | common_enums::IntentStatus::Expired
             | common_enums::IntentStatus::RequiresCustomerAction
             | common_enums::IntentStatus::RequiresMerchantAction
             | common_enums::IntentStatus::RequiresCapture
@@ -204,6 +204,7 @@ fn validate_payment_status_for_payment_method_list(
         | common_enums::IntentStatus::Conflicted
         | common_enums::IntentStatus::Failed
         | common_enums::IntentStatus::Cancelled
+        | common_enums::IntentStatus::Expired
         | common_enums::IntentStatus::Processing
         | common_enums::IntentStatus::RequiresCustomerAction
         | common_enums::IntentStatus::RequiresMerchantAction
@@ -8,6 +8,8 @@ use router_env::{
     tracing::{self, instrument},
 };
 
+#[cfg(feature = "v1")]
+use crate::utils::OptionExt;
 use crate::{
     core::{
         errors::{self, RouterResult, StorageErrorExt},
@@ -27,6 +29,11 @@ use crate::{
     types::{self, api, domain, storage},
 };
 
+#[cfg(feature = "v1")]
+const PAYMENT_RETRY_TASK: &str = "PAYMENT_RETRY";
+#[cfg(feature = "v1")]
+const PAYMENT_RETRY_TAG: &str = "RETRY";
+
 #[instrument(skip_all)]
 #[allow(clippy::too_many_arguments)]
 #[cfg(feature = "v1")]
@@ -121,6 +128,8 @@ where
                 None => get_gsm(state, &router_data).await?,
             };
 
+            let error_category = gsm.as_ref().and_then(|gsm| gsm.error_category);
+
             match get_gsm_decision(gsm) {
                 api_models::gsm::GsmDecision::Retry => {
                     retries = get_retries(
@@ -137,6 +146,34 @@ where
                         break;
                     }
 
+                    if let Some(retry_policy) = business_profile.retry_policy.as_ref() {
+                        let card_network = match payment_data.get_payment_method_data() {
+                            Some(domain::PaymentMethodData::Card(card)) => {
+                                card.card_network.clone()
+                            }
+                            _ => None,
+                        };
+
+                        if !retry_policy.is_retry_allowed(card_network.as_ref(), error_category) {
+                            logger::info!(
+                                ?card_network,
+                                ?error_category,
+                                "retry_policy does not allow retrying the hard decline"
+                            );
+                            break;
+                        }
+
+                        if retry_policy.should_exclude_failed_connector(error_category) {
+                            exclude_failed_connector(
+                                state,
+                                business_profile,
+                                retry_policy,
+                                router_data.connector.as_str(),
+                            )
+                            .await;
+                        }
+                    }
+
                     if connector_routing_data.len() == 0 {
                         logger::info!("connectors exhausted for auto_retry payment");
                         metrics::AUTO_RETRY_EXHAUSTED_COUNT.add(1, &[]);
@@ -158,10 +195,68 @@ where
                     let connector = if should_retry_with_pan {
                         // If should_retry_with_pan is true, it indicates that we are retrying with PAN using the same connector.
                         original_connector_data.clone()
+                    } else if let Some(retry_policy) = business_profile.retry_policy.as_ref() {
+                        let Some(retry_connector) = get_connector_for_retry_policy(
+                            state,
+                            business_profile,
+                            retry_policy,
+                            error_category,
+                            &mut connector_routing_data,
+                        )
+                        .await
+                        else {
+                            logger::info!(
+                                ?error_category,
+                                "connectors of retry_policy exhausted for auto_retry payment"
+                            );
+                            metrics::AUTO_RETRY_EXHAUSTED_COUNT.add(1, &[]);
+                            break;
+                        };
+
+                        // The payment fails with this attempt, and is retried by the scheduler
+                        // after the delay
+                        if let Some(retry_delay) = retry_policy.retry_delay_in_seconds {
+                            let is_authorize_flow =
+                                get_flow_name::<F>().is_ok_and(|flow| flow == "Authorize");
+                            if is_no_three_ds_payment && is_authorize_flow {
+                                add_delayed_retry_task(
+                                    state,
+                                    payment_data,
+                                    merchant_context,
+                                    business_profile,
+                                    retry_connector,
+                                    connector_routing_data.as_slice(),
+                                    retries,
+                                    retry_delay,
+                                )
+                                .await;
+                            } else {
+                                logger::info!(
+                                    "retry_policy retries only payments which do not require authentication with a delay"
+                                );
+                            }
+                            break;
+                        }
+
+                        retry_connector.connector_data
                     } else {
                         super::get_connector_data(&mut connector_routing_data)?.connector_data
                     };
 
+                    metrics::AUTO_RETRY_ATTEMPT_COUNT.add(
+                        1,
+                        router_env::metric_attributes!(
+                            ("connector", connector.connector_name.to_string()),
+                            (
+                                "error_category",
+                                error_category
+                                    .map(|error_category| error_category.to_string())
+                                    .unwrap_or_default()
+                            ),
+                            ("retry_policy", business_profile.retry_policy.is_some()),
+                        ),
+                    );
+
                     router_data = do_retry(
                         &state.clone(),
                         req_state.clone(),
@@ -222,6 +317,224 @@ pub async fn is_step_up_enabled_for_merchant_connector(
         .unwrap_or(false)
 }
 
+#[cfg(feature = "v1")]
+fn get_connector_exclusion_key(profile: &domain::Profile, connector_name: &str) -> String {
+    // The profile id is the hash tag of the key, so that the exclusions of the connectors of a
+    // profile can be fetched with a single command
+    format!(
+        "retry_connector_exclusion_{{{}}}_{}",
+        profile.get_id().get_string_repr(),
+        connector_name
+    )
+}
+
+/// Excludes the connector which failed the payment from the retries of the payments of the
+/// profile, for the exclusion window of the retry policy. Only failures on the side of the
+/// connector exclude it, as decided by the retry policy
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+async fn exclude_failed_connector(
+    state: &app::SessionState,
+    profile: &domain::Profile,
+    retry_policy: &common_types::payments::RetryPolicy,
+    connector_name: &str,
+) {
+    let Some(exclusion_window) = retry_policy.connector_exclusion_window_in_seconds else {
+        return;
+    };
+
+    let key = get_connector_exclusion_key(profile, connector_name);
+    let result = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn
+            .set_key_with_expiry(&key.as_str().into(), true, i64::from(exclusion_window))
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError),
+        Err(error) => Err(error.change_context(errors::ApiErrorResponse::InternalServerError)),
+    };
+
+    if let Err(error) = result {
+        logger::error!(?error, "Failed to exclude the connector from retries");
+    }
+}
+
+/// Returns the connectors among the given ones which are in their retry exclusion window. The
+/// exclusions are fetched together, as the keys of a profile are stored in the same slot.
+#[cfg(feature = "v1")]
+async fn get_excluded_connectors(
+    state: &app::SessionState,
+    profile: &domain::Profile,
+    connector_names: &[String],
+) -> std::collections::HashSet<String> {
+    let keys = connector_names
+        .iter()
+        .map(|connector_name| get_connector_exclusion_key(profile, connector_name).into())
+        .collect::<Vec<redis_interface::RedisKey>>();
+
+    let result = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn
+            .get_multiple_keys::<String>(&keys)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError),
+        Err(error) => Err(error.change_context(errors::ApiErrorResponse::InternalServerError)),
+    };
+
+    // The connectors are not excluded when the exclusions can not be fetched
+    result
+        .map_err(|error| logger::error!(?error, "Failed to fetch the exclusions of the connectors"))
+        .unwrap_or_default()
+        .into_iter()
+        .zip(connector_names)
+        .filter_map(|(exclusion, connector_name)| exclusion.map(|_| connector_name.to_owned()))
+        .collect()
+}
+
+/// Picks the connector for the next retry as per the retry policy of the profile. Connectors in
+/// their exclusion window are skipped, and when a cascade is configured for the error category of
+/// the failure, the payment is retried only with the connectors of the cascade, in its order,
+/// which are eligible for the payment as per routing.
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+async fn get_connector_for_retry_policy(
+    state: &app::SessionState,
+    profile: &domain::Profile,
+    retry_policy: &common_types::payments::RetryPolicy,
+    error_category: Option<storage_enums::ErrorCategory>,
+    connector_routing_data: &mut IntoIter<api::ConnectorRoutingData>,
+) -> Option<api::ConnectorRoutingData> {
+    let routing_data = connector_routing_data.by_ref().collect::<Vec<_>>();
+    let connector_names = routing_data
+        .iter()
+        .map(|routing_data| routing_data.connector_data.connector_name.to_string())
+        .collect::<Vec<_>>();
+    let excluded_connectors = get_excluded_connectors(state, profile, &connector_names).await;
+
+    let mut eligible_connectors = Vec::new();
+    for (routing_data, connector_name) in routing_data.into_iter().zip(connector_names) {
+        if excluded_connectors.contains(&connector_name) {
+            logger::info!(%connector_name, "skipping connector in its retry exclusion window");
+        } else {
+            eligible_connectors.push(routing_data);
+        }
+    }
+
+    let position = match error_category.and_then(|category| retry_policy.get_cascade(category)) {
+        Some(cascade) => cascade.connectors.iter().find_map(|connector| {
+            eligible_connectors.iter().position(|routing_data| {
+                routing_data.connector_data.connector_name == types::Connector::from(*connector)
+            })
+        }),
+        None => (!eligible_connectors.is_empty()).then_some(0),
+    };
+
+    let connector = position.map(|position| eligible_connectors.remove(position));
+    *connector_routing_data = eligible_connectors.into_iter();
+    connector
+}
+
+/// Schedules the retry of the failed payment with the connectors after the retry delay of the
+/// retry policy. The payment method data of the payment is stored in the temporary locker, as it
+/// is no longer available once the payment has failed. Scheduling errors are logged, and the
+/// payment is then not retried.
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+#[allow(clippy::too_many_arguments)]
+async fn add_delayed_retry_task<F, D>(
+    state: &app::SessionState,
+    payment_data: &D,
+    merchant_context: &domain::MerchantContext,
+    business_profile: &domain::Profile,
+    retry_connector: api::ConnectorRoutingData,
+    remaining_connectors: &[api::ConnectorRoutingData],
+    retries: Option<i32>,
+    retry_delay: u32,
+) where
+    F: Clone + Send + Sync,
+    D: payments::OperationSessionGetters<F>,
+{
+    let result = async {
+        let payment_attempt = payment_data.get_payment_attempt();
+        let payment_method_data = payment_data
+            .get_payment_method_data()
+            .get_required_value("payment_method_data")?;
+        let payment_method = payment_attempt
+            .payment_method
+            .get_required_value("payment_method")?;
+
+        let payment_token = payments::helpers::store_in_vault_and_generate_ppmt(
+            state,
+            payment_method_data,
+            payment_data.get_payment_intent(),
+            payment_attempt,
+            payment_method,
+            merchant_context.get_merchant_key_store(),
+            Some(business_profile),
+        )
+        .await?;
+
+        // The connectors after the retry connector are used by the subsequent delayed retries,
+        // within the retries remaining for the payment
+        let connectors = std::iter::once(retry_connector)
+            .chain(remaining_connectors.iter().cloned())
+            .take(
+                retries
+                    .and_then(|retries| usize::try_from(retries).ok())
+                    .unwrap_or(1),
+            )
+            .collect::<Vec<_>>();
+        let connectors = api::convert_connector_data_to_routable_connectors(&connectors)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to convert the connectors of the delayed retry")?;
+
+        let runner = storage::ProcessTrackerRunner::PaymentRetryWorkflow;
+        let process_tracker_id = scheduler::utils::get_process_tracker_id(
+            runner,
+            PAYMENT_RETRY_TASK,
+            &payment_attempt.attempt_id,
+            &payment_attempt.merchant_id,
+        );
+        let tracking_data = storage::PaymentRetryTrackingData {
+            merchant_id: payment_attempt.merchant_id.clone(),
+            payment_id: payment_attempt.payment_id.clone(),
+            attempt_id: payment_attempt.attempt_id.clone(),
+            payment_token,
+            connectors,
+        };
+        let schedule_time = common_utils::date_time::now()
+            .saturating_add(time::Duration::seconds(i64::from(retry_delay)));
+
+        let process_tracker_entry = storage::ProcessTrackerNew::new(
+            process_tracker_id,
+            PAYMENT_RETRY_TASK,
+            runner,
+            [PAYMENT_RETRY_TAG, "PAYMENT"],
+            tracking_data,
+            None,
+            schedule_time,
+            common_types::consts::API_VERSION,
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to construct the delayed retry process tracker task")?;
+
+        state
+            .store
+            .insert_process(process_tracker_entry)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert the delayed retry process tracker task")?;
+
+        RouterResult::Ok(())
+    }
+    .await;
+
+    match result {
+        Ok(()) => logger::info!(retry_delay, "scheduled the delayed retry of the payment"),
+        Err(error) => logger::error!(
+            ?error,
+            "Failed to schedule the delayed retry of the payment"
+        ),
+    }
+}
+
 #[cfg(feature = "v1")]
 pub async fn get_merchant_max_auto_retries_enabled(
     db: &dyn StorageInterface,
@@ -689,6 +1002,13 @@ pub fn make_new_payment_attempt(
         processor_merchant_id: old_payment_attempt.processor_merchant_id,
         created_by: old_payment_attempt.created_by,
         setup_future_usage_applied: setup_future_usage_intent, // setup future usage is picked from intent for new payment attempt
+        authorization_reversal_status: None,
+        amount_reversed: None,
+        installment_plan: None,
+        currency_conversion: None,
+        cancellation_reason_code: None,
+        authorized_at: None,
+        captured_at: None,
     }
 }
 
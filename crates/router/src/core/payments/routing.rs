@@ -1,10 +1,11 @@
 mod transformers;
 pub mod utils;
-#[cfg(all(feature = "v1", feature = "dynamic_routing"))]
-use std::collections::hash_map;
-#[cfg(all(feature = "v1", feature = "dynamic_routing"))]
-use std::hash::{Hash, Hasher};
-use std::{collections::HashMap, str::FromStr, sync::Arc};
+use std::{
+    collections::{hash_map, HashMap},
+    hash::{Hash, Hasher},
+    str::FromStr,
+    sync::Arc,
+};
 
 #[cfg(feature = "v1")]
 use api_models::open_router::{self as or_types, DecidedGateway, OpenRouterDecideGatewayRequest};
@@ -35,7 +36,6 @@ use external_services::grpc_client::dynamic_routing::{
     DynamicRoutingError,
 };
 use hyperswitch_domain_models::address::Address;
-#[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use hyperswitch_interfaces::events::routing_api_logs::{ApiMethod, RoutingEngine, RoutingEvent};
 use kgraph_utils::{
     mca as mca_graph,
@@ -43,13 +43,15 @@ use kgraph_utils::{
     types::CountryCurrencyFilter,
 };
 use masking::{PeekInterface, Secret};
-use rand::distributions::{self, Distribution};
-#[cfg(all(feature = "v1", feature = "dynamic_routing"))]
-use rand::SeedableRng;
+use rand::{
+    distributions::{self, Distribution},
+    SeedableRng,
+};
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use router_env::{instrument, tracing};
 use rustc_hash::FxHashMap;
 use storage_impl::redis::cache::{CacheKey, CGRAPH_CACHE, ROUTING_CACHE};
+use tracing_futures::Instrument;
 use utils::perform_decision_euclid_routing;
 
 #[cfg(feature = "v2")]
@@ -60,9 +62,9 @@ use crate::core::payouts;
 use crate::core::routing::transformers::OpenRouterDecideGatewayRequestExt;
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use crate::headers;
-#[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use crate::routes::app::SessionStateInfo;
 use crate::{
+    consts,
     core::{
         errors, errors as oss_errors, payments::routing::utils::DecisionEngineApiHandler, routing,
     },
@@ -72,7 +74,7 @@ use crate::{
         domain, storage as oss_storage,
         transformers::{ForeignFrom, ForeignInto, ForeignTryFrom},
     },
-    utils::{OptionExt, ValueExt},
+    utils::{OptionExt, StringExt, ValueExt},
     SessionState,
 };
 
@@ -81,6 +83,161 @@ pub enum CachedAlgorithm {
     Priority(Vec<routing_types::RoutableConnectorChoice>),
     VolumeSplit(Vec<routing_types::ConnectorVolumeSplit>),
     Advanced(backend::VirInterpreterBackend<ConnectorSelection>),
+    LeastCost(Vec<routing_types::RoutableConnectorChoice>),
+}
+
+/// Fee schedules of the merchant connector accounts of a profile, used by least cost routing
+pub type ConnectorFeeSchedules = HashMap<
+    common_utils::id_type::MerchantConnectorAccountId,
+    common_types::connector_fees::ConnectorFeeSchedule,
+>;
+
+impl CachedAlgorithm {
+    pub fn from_static_routing_algorithm(
+        algorithm: routing_types::StaticRoutingAlgorithm,
+    ) -> RoutingResult<Self> {
+        Ok(match algorithm {
+            routing_types::StaticRoutingAlgorithm::Single(conn) => Self::Single(conn),
+            routing_types::StaticRoutingAlgorithm::Priority(plist) => Self::Priority(plist),
+            routing_types::StaticRoutingAlgorithm::VolumeSplit(splits) => Self::VolumeSplit(splits),
+            routing_types::StaticRoutingAlgorithm::Advanced(program) => {
+                let interpreter = backend::VirInterpreterBackend::with_program(program)
+                    .change_context(errors::RoutingError::DslBackendInitError)
+                    .attach_printable("Error initializing DSL interpreter backend")?;
+
+                Self::Advanced(interpreter)
+            }
+            routing_types::StaticRoutingAlgorithm::ThreeDsDecisionRule(_program) => {
+                Err(errors::RoutingError::InvalidRoutingAlgorithmStructure)
+                    .attach_printable("Unsupported algorithm received")?
+            }
+            routing_types::StaticRoutingAlgorithm::LeastCost(connectors) => {
+                Self::LeastCost(connectors)
+            }
+        })
+    }
+
+    /// Evaluates the algorithm for the given input without consulting the decision engine,
+    /// returning the connectors in the order they would be attempted. The fee schedules are
+    /// only used by least cost algorithms. When `rng_seed` is provided, volume splits pick the
+    /// same connector every time they are evaluated with that seed.
+    pub fn evaluate(
+        &self,
+        backend_input: dsl_inputs::BackendInput,
+        fee_schedules: &ConnectorFeeSchedules,
+        rng_seed: Option<&str>,
+    ) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
+        match self {
+            Self::Single(conn) => Ok(vec![(**conn).clone()]),
+            Self::Priority(plist) => Ok(plist.clone()),
+            Self::VolumeSplit(splits) => perform_seeded_volume_split(splits.to_vec(), rng_seed)
+                .change_context(errors::RoutingError::ConnectorSelectionFailed),
+            Self::Advanced(interpreter) => {
+                execute_dsl_and_get_connector_v1(backend_input, interpreter)
+            }
+            Self::LeastCost(connectors) => {
+                Ok(api_models::routing::sort_connectors_by_expected_fee(
+                    connectors,
+                    fee_schedules,
+                    &get_fee_calculation_input(&backend_input),
+                ))
+            }
+        }
+    }
+
+    pub fn is_least_cost(&self) -> bool {
+        matches!(self, Self::LeastCost(_))
+    }
+}
+
+/// Fetches the fee schedules configured on the enabled payment processors of the profile. The
+/// schedules are cached along with the routing config and redacted whenever a merchant connector
+/// account of the profile changes.
+pub async fn get_connector_fee_schedules(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &common_utils::id_type::ProfileId,
+) -> RoutingResult<Arc<ConnectorFeeSchedules>> {
+    let key = format!(
+        "fee_schedules_{}_{}",
+        key_store.merchant_id.get_string_repr(),
+        profile_id.get_string_repr()
+    );
+
+    let cached_fee_schedules = ROUTING_CACHE
+        .get_val::<Arc<ConnectorFeeSchedules>>(CacheKey {
+            key: key.clone(),
+            prefix: state.tenant.redis_key_prefix.clone(),
+        })
+        .await;
+
+    if let Some(fee_schedules) = cached_fee_schedules {
+        return Ok(fee_schedules);
+    }
+
+    let merchant_connector_accounts = state
+        .store
+        .list_enabled_connector_accounts_by_profile_id(
+            &state.into(),
+            profile_id,
+            key_store,
+            common_enums::ConnectorType::PaymentProcessor,
+        )
+        .await
+        .change_context(errors::RoutingError::ConnectorFeeScheduleFetchFailed)?;
+
+    let fee_schedules: Arc<ConnectorFeeSchedules> = Arc::new(
+        merchant_connector_accounts
+            .into_iter()
+            .filter_map(|mca| {
+                let mca_id = mca.get_id();
+                mca.fee_schedule.map(|fee_schedule| (mca_id, fee_schedule))
+            })
+            .collect(),
+    );
+
+    ROUTING_CACHE
+        .push(
+            CacheKey {
+                key,
+                prefix: state.tenant.redis_key_prefix.clone(),
+            },
+            Arc::clone(&fee_schedules),
+        )
+        .await;
+
+    Ok(fee_schedules)
+}
+
+/// Attributes of the payment which determine the fees charged by the connectors. The billing
+/// country is used in place of the card issuing country when the latter is not known.
+fn get_fee_calculation_input(
+    backend_input: &dsl_inputs::BackendInput,
+) -> common_types::connector_fees::FeeCalculationInput {
+    common_types::connector_fees::FeeCalculationInput {
+        amount: backend_input.payment.amount,
+        currency: backend_input.payment.currency,
+        card_network: backend_input.payment_method.card_network.clone(),
+        card_type: match backend_input.payment_method.payment_method_type {
+            Some(api_enums::PaymentMethodType::Credit) => Some(common_enums::CardType::Credit),
+            Some(api_enums::PaymentMethodType::Debit) => Some(common_enums::CardType::Debit),
+            _ => None,
+        },
+        issuer_country: backend_input
+            .issuer_data
+            .as_ref()
+            .and_then(|issuer_data| issuer_data.country)
+            .or(backend_input.payment.billing_country)
+            .map(api_enums::Country::to_alpha2),
+        business_country: backend_input
+            .payment
+            .business_country
+            .or(backend_input
+                .acquirer_data
+                .as_ref()
+                .and_then(|acquirer_data| acquirer_data.country))
+            .map(api_enums::Country::to_alpha2),
+    }
 }
 
 #[cfg(feature = "v1")]
@@ -179,6 +336,7 @@ pub fn make_dsl_input_for_payouts(
             .map(api_enums::Country::from_alpha2),
         business_label: payout_data.payout_attempt.business_label.clone(),
         setup_future_usage: None,
+        installment_interval: None,
     };
     let payment_method = dsl_inputs::PaymentMethodInput {
         payment_method: payout_data
@@ -199,6 +357,7 @@ pub fn make_dsl_input_for_payouts(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        temporal_data: None,
     })
 }
 
@@ -294,6 +453,7 @@ pub fn make_dsl_input(
             .map(api_enums::Country::from_alpha2),
         business_label: None,
         setup_future_usage: Some(payments_dsl_input.payment_intent.setup_future_usage),
+        installment_interval: None,
     };
 
     let metadata = payments_dsl_input
@@ -314,6 +474,7 @@ pub fn make_dsl_input(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        temporal_data: None,
     })
 }
 
@@ -411,6 +572,10 @@ pub fn make_dsl_input(
             .map(api_enums::Country::from_alpha2),
         business_label: payments_dsl_input.payment_intent.business_label.clone(),
         setup_future_usage: payments_dsl_input.payment_intent.setup_future_usage,
+        installment_interval: payments_dsl_input
+            .payment_attempt
+            .installment_plan
+            .map(|installment_plan| installment_plan.interval),
     };
 
     let metadata = payments_dsl_input
@@ -428,12 +593,57 @@ pub fn make_dsl_input(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        temporal_data: None,
+    })
+}
+
+/// Resolves the payment time keys of the routing DSL for `payment_time`, using the timezone and
+/// holiday calendars configured for the profile
+pub async fn get_temporal_data_input(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    profile_id: &common_utils::id_type::ProfileId,
+    payment_time: time::PrimitiveDateTime,
+) -> RoutingResult<dsl_inputs::TemporalDataInput> {
+    let key = merchant_id.get_routing_temporal_config_key(profile_id);
+
+    let temporal_config: api_models::routing::RoutingTemporalConfig = state
+        .store
+        .find_config_by_key_unwrap_or(&key, Some("{}".to_string()))
+        .await
+        .change_context(errors::RoutingError::TemporalConfigFetchFailed)?
+        .config
+        .parse_struct("RoutingTemporalConfig")
+        .change_context(errors::RoutingError::TemporalConfigFetchFailed)
+        .attach_printable("Payment time routing config has invalid structure")?;
+
+    let utc_offset =
+        time::UtcOffset::from_whole_seconds(i32::from(temporal_config.utc_offset_minutes) * 60)
+            .change_context(errors::RoutingError::TemporalConfigFetchFailed)
+            .attach_printable("Invalid UTC offset in payment time routing config")?;
+    let local_time = payment_time.assume_utc().to_offset(utc_offset);
+
+    let year = u32::try_from(local_time.year()).change_context(
+        errors::RoutingError::GenericConversionError {
+            from: "i32".to_string(),
+            to: "u32".to_string(),
+        },
+    )?;
+    let date =
+        year * 10000 + u32::from(u8::from(local_time.month())) * 100 + u32::from(local_time.day());
+
+    Ok(dsl_inputs::TemporalDataInput {
+        hour_of_day: local_time.hour(),
+        day_of_week: local_time.weekday().foreign_into(),
+        date,
+        holiday_calendars: temporal_config.get_holiday_calendars_for_date(date),
     })
 }
 
 pub async fn perform_static_routing_v1(
     state: &SessionState,
     merchant_id: &common_utils::id_type::MerchantId,
+    key_store: &domain::MerchantKeyStore,
     algorithm_id: Option<&common_utils::id_type::RoutingId>,
     business_profile: &domain::Profile,
     transaction_data: &routing::TransactionData<'_>,
@@ -474,13 +684,22 @@ pub async fn perform_static_routing_v1(
             .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
 
         CachedAlgorithm::Advanced(interpreter) => {
-            let backend_input = match transaction_data {
+            let mut backend_input = match transaction_data {
                 routing::TransactionData::Payment(payment_data) => make_dsl_input(payment_data)?,
                 #[cfg(feature = "payouts")]
                 routing::TransactionData::Payout(payout_data) => {
                     make_dsl_input_for_payouts(payout_data)?
                 }
             };
+            backend_input.temporal_data = Some(
+                get_temporal_data_input(
+                    state,
+                    merchant_id,
+                    business_profile.get_id(),
+                    common_utils::date_time::now(),
+                )
+                .await?,
+            );
 
             let de_euclid_connectors = perform_decision_euclid_routing(
                 state,
@@ -492,7 +711,16 @@ pub async fn perform_static_routing_v1(
                 // errors are ignored as this is just for diff checking as of now (optional flow).
                 logger::error!(decision_engine_euclid_evaluate_error=?e, "decision_engine_euclid: error in evaluation of rule")
             ).unwrap_or_default();
-            let routable_connectors = execute_dsl_and_get_connector_v1(backend_input, interpreter)?;
+            let (routable_connectors, rule_hit) =
+                execute_dsl_and_get_rule_hit_v1(backend_input, interpreter)?;
+            record_routing_rule_hit(
+                state,
+                business_profile,
+                algorithm_id,
+                transaction_data,
+                &rule_hit,
+                &routable_connectors,
+            );
             let connectors = routable_connectors
                 .iter()
                 .map(|c| c.connector.to_string())
@@ -504,6 +732,24 @@ pub async fn perform_static_routing_v1(
             );
             routable_connectors
         }
+
+        CachedAlgorithm::LeastCost(connectors) => {
+            let backend_input = match transaction_data {
+                routing::TransactionData::Payment(payment_data) => make_dsl_input(payment_data)?,
+                #[cfg(feature = "payouts")]
+                routing::TransactionData::Payout(payout_data) => {
+                    make_dsl_input_for_payouts(payout_data)?
+                }
+            };
+            let fee_schedules =
+                get_connector_fee_schedules(state, key_store, business_profile.get_id()).await?;
+
+            api_models::routing::sort_connectors_by_expected_fee(
+                connectors,
+                &fee_schedules,
+                &get_fee_calculation_input(&backend_input),
+            )
+        }
     })
 }
 
@@ -553,6 +799,143 @@ async fn ensure_algorithm_cached_v1(
     Ok(algorithm)
 }
 
+/// Evaluates the shadow algorithm of the profile for the payment and records how its decision
+/// compares with the connectors chosen by the active algorithm. The evaluation runs in the
+/// background, as the shadow decision is never used for routing the payment, and failures are only
+/// logged.
+#[cfg(feature = "v1")]
+pub fn perform_shadow_static_routing_v1(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &domain::Profile,
+    shadow_algorithm_id: &common_utils::id_type::RoutingId,
+    payments_dsl_input: &routing::PaymentsDslInput<'_>,
+    active_connectors: &[routing_types::RoutableConnectorChoice],
+) {
+    let profile_id = business_profile.get_id().to_owned();
+    let merchant_id = business_profile.merchant_id.to_owned();
+
+    let mut routing_event = RoutingEvent::new(
+        state.tenant.tenant_id.clone(),
+        "".to_string(),
+        "Shadow static routing Evaluate",
+        serde_json::json!({
+            "shadow_algorithm_id": shadow_algorithm_id,
+            "active_connectors": active_connectors,
+        }),
+        "".to_string(),
+        ApiMethod::Internal,
+        payments_dsl_input
+            .payment_attempt
+            .payment_id
+            .get_string_repr()
+            .to_string(),
+        profile_id.clone(),
+        merchant_id.clone(),
+        state.request_id,
+        RoutingEngine::StaticRouting,
+    );
+
+    // The input borrows from the payment, so it is built before the evaluation is spawned
+    let backend_input = make_dsl_input(payments_dsl_input);
+    let state = state.clone();
+    let key_store = key_store.clone();
+    let shadow_algorithm_id = shadow_algorithm_id.clone();
+    let active_connectors = active_connectors.to_vec();
+
+    tokio::spawn(
+        async move {
+            let shadow_connectors = async {
+                let key = format!(
+                    "routing_config_shadow_{}_{}",
+                    merchant_id.get_string_repr(),
+                    profile_id.get_string_repr(),
+                );
+
+                let cached_algorithm = ROUTING_CACHE
+                    .get_val::<Arc<CachedAlgorithm>>(CacheKey {
+                        key: key.clone(),
+                        prefix: state.tenant.redis_key_prefix.clone(),
+                    })
+                    .await;
+
+                let algorithm = match cached_algorithm {
+                    Some(algorithm) => algorithm,
+                    None => {
+                        refresh_routing_cache_v1(&state, key, &shadow_algorithm_id, &profile_id)
+                            .await?
+                    }
+                };
+
+                let mut backend_input = backend_input?;
+                if matches!(algorithm.as_ref(), CachedAlgorithm::Advanced(_)) {
+                    backend_input.temporal_data = Some(
+                        get_temporal_data_input(
+                            &state,
+                            &merchant_id,
+                            &profile_id,
+                            common_utils::date_time::now(),
+                        )
+                        .await?,
+                    );
+                }
+
+                let fee_schedules = if algorithm.is_least_cost() {
+                    get_connector_fee_schedules(&state, &key_store, &profile_id).await?
+                } else {
+                    Arc::default()
+                };
+
+                algorithm.evaluate(backend_input, &fee_schedules, None)
+            }
+            .await;
+
+            match shadow_connectors {
+                Ok(shadow_connectors) => {
+                    let active_connector = active_connectors.first();
+                    let shadow_connector = shadow_connectors.first();
+                    let is_diverged = active_connector != shadow_connector;
+
+                    logger::debug!(
+                        shadow_routing_is_diverged = is_diverged,
+                        "shadow static routing evaluated"
+                    );
+                    crate::core::metrics::SHADOW_ROUTING_EVALUATION_COUNT.add(
+                        1,
+                        router_env::metric_attributes!(
+                            ("profile_id", profile_id.get_string_repr().to_owned()),
+                            ("is_diverged", is_diverged),
+                        ),
+                    );
+
+                    routing_event.set_response_body(&serde_json::json!({
+                        "active_connector": active_connector,
+                        "shadow_connector": shadow_connector,
+                        "is_diverged": is_diverged,
+                    }));
+                    if let Some(connector) = active_connector {
+                        routing_event.set_payment_connector(connector.clone());
+                    }
+                    routing_event.set_routable_connectors(shadow_connectors);
+                    routing_event.set_status_code(200);
+                }
+                Err(error) => {
+                    logger::error!(
+                        shadow_routing_error=?error,
+                        "failed to evaluate shadow static routing"
+                    );
+                    routing_event.set_error(
+                        serde_json::json!({"error": error.current_context().to_string()}),
+                    );
+                }
+            }
+
+            state.event_handler().log_event(&routing_event);
+        }
+        .in_current_span(),
+    );
+}
+
 pub fn perform_straight_through_routing(
     algorithm: &routing_types::StraightThroughAlgorithm,
     creds_identifier: Option<&str>,
@@ -593,12 +976,50 @@ fn execute_dsl_and_get_connector_v1(
     backend_input: dsl_inputs::BackendInput,
     interpreter: &backend::VirInterpreterBackend<ConnectorSelection>,
 ) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
-    let routing_output: routing_types::StaticRoutingAlgorithm = interpreter
+    execute_dsl_and_get_rule_hit_v1(backend_input, interpreter).map(|(connectors, _)| connectors)
+}
+
+/// Label used in place of the rule name when no rule of the program matched
+const DEFAULT_SELECTION_RULE_LABEL: &str = "default_selection";
+
+/// Rule of an advanced algorithm whose connector selection was used for a payment
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RoutingRuleHit {
+    /// Name of the matched rule, `None` when the default selection of the program was used
+    pub rule_name: Option<String>,
+    /// Position of the matched rule in the program
+    pub rule_index: Option<usize>,
+    /// Position of the top level statement of the matched rule whose condition was satisfied
+    pub statement_index: Option<usize>,
+}
+
+impl RoutingRuleHit {
+    pub fn get_rule_label(&self) -> &str {
+        self.rule_name
+            .as_deref()
+            .unwrap_or(DEFAULT_SELECTION_RULE_LABEL)
+    }
+}
+
+/// Executes the program of an advanced algorithm, returning the chosen connectors along with the
+/// rule which chose them
+fn execute_dsl_and_get_rule_hit_v1(
+    backend_input: dsl_inputs::BackendInput,
+    interpreter: &backend::VirInterpreterBackend<ConnectorSelection>,
+) -> RoutingResult<(Vec<routing_types::RoutableConnectorChoice>, RoutingRuleHit)> {
+    let output = interpreter
         .execute(backend_input)
-        .map(|out| out.connector_selection.foreign_into())
         .change_context(errors::RoutingError::DslExecutionError)?;
 
-    Ok(match routing_output {
+    let rule_hit = RoutingRuleHit {
+        rule_name: output.rule_name,
+        rule_index: output.rule_index,
+        statement_index: output.statement_index,
+    };
+    let routing_output: routing_types::StaticRoutingAlgorithm =
+        output.connector_selection.foreign_into();
+
+    let connectors = match routing_output {
         routing_types::StaticRoutingAlgorithm::Priority(plist) => plist,
 
         routing_types::StaticRoutingAlgorithm::VolumeSplit(splits) => perform_volume_split(splits)
@@ -606,7 +1027,68 @@ fn execute_dsl_and_get_connector_v1(
 
         _ => Err(errors::RoutingError::DslIncorrectSelectionAlgorithm)
             .attach_printable("Unsupported algorithm received as a result of static routing")?,
-    })
+    };
+
+    Ok((connectors, rule_hit))
+}
+
+/// Records the rule of the active advanced algorithm which routed the payment, in the rule hit
+/// metrics and the routing analytics events
+fn record_routing_rule_hit(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    algorithm_id: &common_utils::id_type::RoutingId,
+    transaction_data: &routing::TransactionData<'_>,
+    rule_hit: &RoutingRuleHit,
+    connectors: &[routing_types::RoutableConnectorChoice],
+) {
+    let profile_id = business_profile.get_id();
+    let statement_index = rule_hit
+        .statement_index
+        .and_then(|index| i64::try_from(index).ok())
+        .unwrap_or(-1);
+
+    crate::core::metrics::ROUTING_RULE_HIT_COUNT.add(
+        1,
+        router_env::metric_attributes!(
+            ("profile_id", profile_id.get_string_repr().to_owned()),
+            ("algorithm_id", algorithm_id.get_string_repr().to_owned()),
+            ("rule", rule_hit.get_rule_label().to_owned()),
+            ("statement_index", statement_index),
+        ),
+    );
+
+    let transaction_id = match transaction_data {
+        routing::TransactionData::Payment(payment_data) => payment_data
+            .payment_attempt
+            .payment_id
+            .get_string_repr()
+            .to_string(),
+        #[cfg(feature = "payouts")]
+        routing::TransactionData::Payout(payout_data) => {
+            payout_data.payout_attempt.payout_id.clone()
+        }
+    };
+
+    let mut routing_event = RoutingEvent::new(
+        state.tenant.tenant_id.clone(),
+        "".to_string(),
+        "Static routing Evaluate",
+        serde_json::json!({ "algorithm_id": algorithm_id }),
+        "".to_string(),
+        ApiMethod::Internal,
+        transaction_id,
+        profile_id.to_owned(),
+        business_profile.merchant_id.to_owned(),
+        state.request_id,
+        RoutingEngine::StaticRouting,
+    );
+    routing_event.set_response_body(rule_hit);
+    routing_event.set_routing_rule(rule_hit.get_rule_label().to_owned());
+    routing_event.set_routable_connectors(connectors.to_vec());
+    routing_event.set_status_code(200);
+
+    state.event_handler().log_event(&routing_event);
 }
 
 pub async fn refresh_routing_cache_v1(
@@ -628,24 +1110,7 @@ pub async fn refresh_routing_cache_v1(
         algorithm
     };
 
-    let cached_algorithm = match algorithm {
-        routing_types::StaticRoutingAlgorithm::Single(conn) => CachedAlgorithm::Single(conn),
-        routing_types::StaticRoutingAlgorithm::Priority(plist) => CachedAlgorithm::Priority(plist),
-        routing_types::StaticRoutingAlgorithm::VolumeSplit(splits) => {
-            CachedAlgorithm::VolumeSplit(splits)
-        }
-        routing_types::StaticRoutingAlgorithm::Advanced(program) => {
-            let interpreter = backend::VirInterpreterBackend::with_program(program)
-                .change_context(errors::RoutingError::DslBackendInitError)
-                .attach_printable("Error initializing DSL interpreter backend")?;
-
-            CachedAlgorithm::Advanced(interpreter)
-        }
-        api_models::routing::StaticRoutingAlgorithm::ThreeDsDecisionRule(_program) => {
-            Err(errors::RoutingError::InvalidRoutingAlgorithmStructure)
-                .attach_printable("Unsupported algorithm received")?
-        }
-    };
+    let cached_algorithm = CachedAlgorithm::from_static_routing_algorithm(algorithm)?;
 
     let arc_cached_algorithm = Arc::new(cached_algorithm);
 
@@ -672,7 +1137,22 @@ pub fn perform_dynamic_routing_volume_split(
         .change_context(errors::RoutingError::VolumeSplitFailed)
         .attach_printable("Error creating weighted distribution for volume split")?;
 
-    let idx = if let Some(seed) = rng_seed {
+    let idx = sample_volume_split_index(&weighted_index, rng_seed);
+
+    let routing_choice = *splits
+        .get(idx)
+        .ok_or(errors::RoutingError::VolumeSplitFailed)
+        .attach_printable("Volume split index lookup failed")?;
+
+    Ok(routing_choice)
+}
+
+/// Samples an index of the volume split, deterministically for the same `rng_seed`
+fn sample_volume_split_index(
+    weighted_index: &distributions::WeightedIndex<u8>,
+    rng_seed: Option<&str>,
+) -> usize {
+    if let Some(seed) = rng_seed {
         let mut hasher = hash_map::DefaultHasher::new();
         seed.hash(&mut hasher);
         let hash = hasher.finish();
@@ -682,26 +1162,25 @@ pub fn perform_dynamic_routing_volume_split(
     } else {
         let mut rng = rand::thread_rng();
         weighted_index.sample(&mut rng)
-    };
-
-    let routing_choice = *splits
-        .get(idx)
-        .ok_or(errors::RoutingError::VolumeSplitFailed)
-        .attach_printable("Volume split index lookup failed")?;
-
-    Ok(routing_choice)
+    }
 }
 
 pub fn perform_volume_split(
+    splits: Vec<routing_types::ConnectorVolumeSplit>,
+) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
+    perform_seeded_volume_split(splits, None)
+}
+
+pub fn perform_seeded_volume_split(
     mut splits: Vec<routing_types::ConnectorVolumeSplit>,
+    rng_seed: Option<&str>,
 ) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
     let weights: Vec<u8> = splits.iter().map(|sp| sp.split).collect();
     let weighted_index = distributions::WeightedIndex::new(weights)
         .change_context(errors::RoutingError::VolumeSplitFailed)
         .attach_printable("Error creating weighted distribution for volume split")?;
 
-    let mut rng = rand::thread_rng();
-    let idx = weighted_index.sample(&mut rng);
+    let idx = sample_volume_split_index(&weighted_index, rng_seed);
 
     splits
         .get(idx)
@@ -723,30 +1202,7 @@ pub async fn get_merchant_cgraph(
     profile_id: &common_utils::id_type::ProfileId,
     transaction_type: &api_enums::TransactionType,
 ) -> RoutingResult<Arc<hyperswitch_constraint_graph::ConstraintGraph<euclid_dir::DirValue>>> {
-    let merchant_id = &key_store.merchant_id;
-
-    let key = {
-        match transaction_type {
-            api_enums::TransactionType::Payment => {
-                format!(
-                    "cgraph_{}_{}",
-                    merchant_id.get_string_repr(),
-                    profile_id.get_string_repr()
-                )
-            }
-            #[cfg(feature = "payouts")]
-            api_enums::TransactionType::Payout => {
-                format!(
-                    "cgraph_po_{}_{}",
-                    merchant_id.get_string_repr(),
-                    profile_id.get_string_repr()
-                )
-            }
-            api_enums::TransactionType::ThreeDsAuthentication => {
-                Err(errors::RoutingError::InvalidTransactionType)?
-            }
-        }
-    };
+    let key = get_cgraph_key(&key_store.merchant_id, profile_id, transaction_type)?;
 
     let cached_cgraph = CGRAPH_CACHE
         .get_val::<Arc<hyperswitch_constraint_graph::ConstraintGraph<euclid_dir::DirValue>>>(
@@ -759,6 +1215,17 @@ pub async fn get_merchant_cgraph(
 
     let cgraph = if let Some(graph) = cached_cgraph {
         graph
+    } else if let Some(graph) = get_precompiled_cgraph(state, &key).await {
+        CGRAPH_CACHE
+            .push(
+                CacheKey {
+                    key,
+                    prefix: state.tenant.redis_key_prefix.clone(),
+                },
+                Arc::clone(&graph),
+            )
+            .await;
+        graph
     } else {
         refresh_cgraph_cache(state, key_store, key.clone(), profile_id, transaction_type).await?
     };
@@ -766,7 +1233,240 @@ pub async fn get_merchant_cgraph(
     Ok(cgraph)
 }
 
+fn get_cgraph_key(
+    merchant_id: &common_utils::id_type::MerchantId,
+    profile_id: &common_utils::id_type::ProfileId,
+    transaction_type: &api_enums::TransactionType,
+) -> RoutingResult<String> {
+    match transaction_type {
+        api_enums::TransactionType::Payment => Ok(format!(
+            "cgraph_{}_{}",
+            merchant_id.get_string_repr(),
+            profile_id.get_string_repr()
+        )),
+        #[cfg(feature = "payouts")]
+        api_enums::TransactionType::Payout => Ok(format!(
+            "cgraph_po_{}_{}",
+            merchant_id.get_string_repr(),
+            profile_id.get_string_repr()
+        )),
+        api_enums::TransactionType::ThreeDsAuthentication => {
+            Err(errors::RoutingError::InvalidTransactionType.into())
+        }
+    }
+}
+
+/// A precompiled constraint graph along with the generation of the merchant connector
+/// accounts it was built from
+#[derive(serde::Serialize, serde::Deserialize)]
+struct PrecompiledCGraph {
+    generation: String,
+    graph: hyperswitch_constraint_graph::SerializedConstraintGraph<euclid_dir::DirValue>,
+}
+
+/// Key of the precompiled graph in redis. The redis key named after the in-memory cache key
+/// holds the generation of the merchant connector accounts instead, so that redacting the cache
+/// on a merchant connector account change also discards the precompiled graph, while keeping it
+/// around to be diffed against the rebuilt one.
+fn get_precompiled_cgraph_key(key: &str) -> String {
+    format!("{key}_precompiled")
+}
+
+async fn get_cgraph_generation(state: &SessionState, key: &str) -> RoutingResult<Option<String>> {
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::RoutingError::KgraphCacheFailure)
+        .attach_printable("Failed to get redis connection")?
+        .get_key::<Option<String>>(&key.into())
+        .await
+        .change_context(errors::RoutingError::KgraphCacheFailure)
+        .attach_printable("Failed to fetch the generation of the constraint graph")
+}
+
+/// Returns the current generation of the graph, starting a new one if there is none
+async fn get_or_start_cgraph_generation(state: &SessionState, key: &str) -> RoutingResult<String> {
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::RoutingError::KgraphCacheFailure)
+        .attach_printable("Failed to get redis connection")?
+        .set_key_if_not_exists_with_expiry(
+            &key.into(),
+            uuid::Uuid::new_v4().to_string(),
+            Some(consts::PRECOMPILED_CGRAPH_TTL_IN_SECONDS),
+        )
+        .await
+        .change_context(errors::RoutingError::KgraphCacheFailure)
+        .attach_printable("Failed to start a generation of the constraint graph")?;
+
+    get_cgraph_generation(state, key)
+        .await?
+        .ok_or(errors::RoutingError::KgraphCacheFailure)
+        .attach_printable("The generation of the constraint graph was removed while starting it")
+}
+
+async fn start_cgraph_generation(state: &SessionState, key: &str) -> RoutingResult<()> {
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::RoutingError::KgraphCacheFailure)
+        .attach_printable("Failed to get redis connection")?
+        .set_key_with_expiry(
+            &key.into(),
+            uuid::Uuid::new_v4().to_string(),
+            consts::PRECOMPILED_CGRAPH_TTL_IN_SECONDS,
+        )
+        .await
+        .change_context(errors::RoutingError::KgraphCacheFailure)
+        .attach_printable("Failed to start a generation of the constraint graph")
+}
+
+async fn get_precompiled_cgraph_envelope(
+    state: &SessionState,
+    key: &str,
+) -> Option<PrecompiledCGraph> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .map_err(|error| logger::error!(?error, "Failed to get redis connection"))
+        .ok()?;
+
+    redis_conn
+        .get_and_deserialize_key::<PrecompiledCGraph>(
+            &get_precompiled_cgraph_key(key).as_str().into(),
+            "PrecompiledCGraph",
+        )
+        .await
+        .map_err(|error| {
+            if !matches!(error.current_context(), errors::RedisError::NotFound) {
+                logger::error!(?error, "Failed to fetch the precompiled constraint graph");
+            }
+        })
+        .ok()
+}
+
+/// Loads the graph precompiled by another pod, or after a merchant connector account change,
+/// from redis. The graph is rebuilt by the caller if it is missing, cannot be loaded or was
+/// built from merchant connector accounts that have changed since.
+async fn get_precompiled_cgraph(
+    state: &SessionState,
+    key: &str,
+) -> Option<Arc<hyperswitch_constraint_graph::ConstraintGraph<euclid_dir::DirValue>>> {
+    let precompiled = get_precompiled_cgraph_envelope(state, key).await?;
+
+    let generation = get_cgraph_generation(state, key)
+        .await
+        .map_err(|error| logger::error!(?error))
+        .ok()?;
+
+    if generation.as_ref() != Some(&precompiled.generation) {
+        logger::info!("Discarding the precompiled constraint graph of an older generation");
+        return None;
+    }
+
+    hyperswitch_constraint_graph::ConstraintGraph::from_serialized(precompiled.graph)
+        .map(Arc::new)
+        .map_err(|error| logger::error!(?error, "Failed to load the precompiled constraint graph"))
+        .ok()
+}
+
+async fn store_precompiled_cgraph(
+    state: &SessionState,
+    key: &str,
+    generation: String,
+    cgraph: &hyperswitch_constraint_graph::ConstraintGraph<euclid_dir::DirValue>,
+) -> RoutingResult<()> {
+    let graph = cgraph
+        .to_serialized()
+        .change_context(errors::RoutingError::KgraphCacheFailure)
+        .attach_printable("Failed to serialize the constraint graph")?;
+
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::RoutingError::KgraphCacheFailure)
+        .attach_printable("Failed to get redis connection")?
+        .serialize_and_set_key_with_expiry(
+            &get_precompiled_cgraph_key(key).as_str().into(),
+            PrecompiledCGraph { generation, graph },
+            consts::PRECOMPILED_CGRAPH_TTL_IN_SECONDS,
+        )
+        .await
+        .change_context(errors::RoutingError::KgraphCacheFailure)
+        .attach_printable("Failed to store the precompiled constraint graph")
+}
+
+/// Rebuilds the constraint graph of the profile in the background and stores it in redis, so
+/// that the first payment after a merchant connector account change does not have to build it
+pub fn precompile_merchant_cgraph(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &common_utils::id_type::ProfileId,
+    transaction_type: &api_enums::TransactionType,
+) {
+    let state = state.clone();
+    let key_store = key_store.clone();
+    let profile_id = profile_id.clone();
+    let transaction_type = *transaction_type;
+
+    tokio::spawn(
+        async move {
+            if let Err(error) =
+                precompile_merchant_cgraph_inner(&state, &key_store, &profile_id, &transaction_type)
+                    .await
+            {
+                logger::error!(
+                    ?error,
+                    "Failed to precompile the constraint graph of the profile"
+                );
+            }
+        }
+        .in_current_span(),
+    );
+}
+
+async fn precompile_merchant_cgraph_inner(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &common_utils::id_type::ProfileId,
+    transaction_type: &api_enums::TransactionType,
+) -> RoutingResult<()> {
+    let key = get_cgraph_key(&key_store.merchant_id, profile_id, transaction_type)?;
+
+    // Any graph built before this point, including one that is still being built, belongs to
+    // the older generation and is not used once a new one is started
+    start_cgraph_generation(state, &key).await?;
+
+    let previous = get_precompiled_cgraph_envelope(state, &key).await;
+
+    let cgraph = refresh_cgraph_cache(state, key_store, key, profile_id, transaction_type).await?;
+
+    if let Some(previous) = previous {
+        let diff = cgraph
+            .to_serialized()
+            .and_then(|current| previous.graph.diff(&current))
+            .change_context(errors::RoutingError::KgraphCacheFailure)
+            .attach_printable("Failed to diff the constraint graph against the previous one")?;
+
+        logger::info!(
+            added_domains = diff.added_domains.len(),
+            removed_domains = diff.removed_domains.len(),
+            added_nodes = diff.added_nodes.len(),
+            removed_nodes = diff.removed_nodes.len(),
+            added_edges = diff.added_edges.len(),
+            removed_edges = diff.removed_edges.len(),
+            "Precompiled the constraint graph of the profile"
+        );
+    }
+
+    Ok(())
+}
+
 // #[cfg(feature = "v1")]
+/// Builds the constraint graph of the profile and caches it in redis and in memory. The graph is
+/// only cached if the merchant connector accounts have not changed while it was being built, so
+/// that a slow build does not replace the graph of a newer generation.
 pub async fn refresh_cgraph_cache(
     state: &SessionState,
     key_store: &domain::MerchantKeyStore,
@@ -774,6 +1474,12 @@ pub async fn refresh_cgraph_cache(
     profile_id: &common_utils::id_type::ProfileId,
     transaction_type: &api_enums::TransactionType,
 ) -> RoutingResult<Arc<hyperswitch_constraint_graph::ConstraintGraph<euclid_dir::DirValue>>> {
+    // A failure to fetch the generation only costs a rebuild on the next cache miss
+    let generation = get_or_start_cgraph_generation(state, &key)
+        .await
+        .map_err(|error| logger::error!(?error))
+        .ok();
+
     let mut merchant_connector_accounts = state
         .store
         .find_merchant_connector_account_by_merchant_id_and_disabled_list(
@@ -852,6 +1558,24 @@ pub async fn refresh_cgraph_cache(
             .attach_printable("when construction cgraph")?,
     );
 
+    if let Some(generation) = generation {
+        match get_cgraph_generation(state, &key).await {
+            Ok(current_generation) if current_generation.as_ref() == Some(&generation) => {
+                // The graph is still usable from the in-memory cache if it could not be stored
+                // in redis
+                if let Err(error) = store_precompiled_cgraph(state, &key, generation, &cgraph).await
+                {
+                    logger::error!(?error, "Failed to store the precompiled constraint graph");
+                }
+            }
+            Ok(_) => {
+                logger::info!("Not caching the constraint graph of an older generation");
+                return Ok(cgraph);
+            }
+            Err(error) => logger::error!(?error),
+        }
+    }
+
     CGRAPH_CACHE
         .push(
             CacheKey {
@@ -1074,6 +1798,7 @@ pub async fn perform_session_flow_routing<'a>(
         // business_label not available in payment_intent anymore
         business_label: None,
         setup_future_usage: Some(session_input.payment_intent.setup_future_usage),
+        installment_interval: None,
     };
 
     let metadata = session_input
@@ -1095,6 +1820,7 @@ pub async fn perform_session_flow_routing<'a>(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        temporal_data: None,
     };
 
     for connector_data in session_input.chosen.iter() {
@@ -1221,6 +1947,7 @@ pub async fn perform_session_flow_routing(
             .map(storage_enums::Country::from_alpha2),
         business_label: session_input.payment_intent.business_label.clone(),
         setup_future_usage: session_input.payment_intent.setup_future_usage,
+        installment_interval: None,
     };
 
     let metadata = session_input
@@ -1242,6 +1969,15 @@ pub async fn perform_session_flow_routing(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        temporal_data: Some(
+            get_temporal_data_input(
+                session_input.state,
+                &session_input.payment_intent.merchant_id,
+                &profile_id,
+                common_utils::date_time::now(),
+            )
+            .await?,
+        ),
     };
 
     for connector_data in session_input.chosen.iter() {
@@ -1343,6 +2079,20 @@ async fn perform_session_routing_for_pm_type(
                 session_pm_input.backend_input.clone(),
                 interpreter,
             )?,
+            CachedAlgorithm::LeastCost(connectors) => {
+                let fee_schedules = get_connector_fee_schedules(
+                    session_pm_input.state,
+                    session_pm_input.key_store,
+                    session_pm_input.profile_id,
+                )
+                .await?;
+
+                api_models::routing::sort_connectors_by_expected_fee(
+                    connectors,
+                    &fee_schedules,
+                    &get_fee_calculation_input(&session_pm_input.backend_input),
+                )
+            }
         }
     } else {
         routing::helpers::get_merchant_default_config(
@@ -1424,6 +2174,17 @@ async fn get_chosen_connectors<'a>(
                 session_pm_input.backend_input.clone(),
                 interpreter,
             )?,
+            CachedAlgorithm::LeastCost(connectors) => {
+                let fee_schedules =
+                    get_connector_fee_schedules(state, key_store, session_pm_input.profile_id)
+                        .await?;
+
+                api_models::routing::sort_connectors_by_expected_fee(
+                    connectors,
+                    &fee_schedules,
+                    &get_fee_calculation_input(&session_pm_input.backend_input),
+                )
+            }
         }
     } else {
         profile_wrapper
@@ -1527,6 +2288,9 @@ pub fn make_dsl_input_for_surcharge(
             .map(api_enums::Country::from_alpha2),
         business_label: payment_intent.business_label.clone(),
         setup_future_usage: payment_intent.setup_future_usage,
+        installment_interval: payment_attempt
+            .installment_plan
+            .map(|installment_plan| installment_plan.interval),
     };
 
     let metadata = payment_intent
@@ -1547,6 +2311,7 @@ pub fn make_dsl_input_for_surcharge(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        temporal_data: None,
     };
     Ok(backend_input)
 }
@@ -2,7 +2,10 @@ use std::collections::HashMap;
 
 use api_models::{self, routing as routing_types};
 use diesel_models::enums as storage_enums;
-use euclid::{enums as dsl_enums, frontend::ast as dsl_ast};
+use euclid::{
+    enums as dsl_enums,
+    frontend::{ast as dsl_ast, dir::enums as dsl_dir_enums},
+};
 use kgraph_utils::types;
 
 use crate::{
@@ -25,7 +28,10 @@ impl ForeignFrom<storage_enums::CaptureMethod> for Option<dsl_enums::CaptureMeth
             storage_enums::CaptureMethod::SequentialAutomatic => {
                 Some(dsl_enums::CaptureMethod::SequentialAutomatic)
             }
-            storage_enums::CaptureMethod::Manual => Some(dsl_enums::CaptureMethod::Manual),
+            // A payment with scheduled capture is authorized with a manual capture
+            storage_enums::CaptureMethod::Manual | storage_enums::CaptureMethod::Scheduled => {
+                Some(dsl_enums::CaptureMethod::Manual)
+            }
             _ => None,
         }
     }
@@ -58,6 +64,20 @@ impl ForeignFrom<storage_enums::MandateDataType> for dsl_enums::MandateType {
     }
 }
 
+impl ForeignFrom<time::Weekday> for dsl_dir_enums::PaymentDayOfWeek {
+    fn foreign_from(from: time::Weekday) -> Self {
+        match from {
+            time::Weekday::Monday => Self::Monday,
+            time::Weekday::Tuesday => Self::Tuesday,
+            time::Weekday::Wednesday => Self::Wednesday,
+            time::Weekday::Thursday => Self::Thursday,
+            time::Weekday::Friday => Self::Friday,
+            time::Weekday::Saturday => Self::Saturday,
+            time::Weekday::Sunday => Self::Sunday,
+        }
+    }
+}
+
 impl ForeignFrom<settings::PaymentMethodFilterKey> for types::PaymentMethodFilterKey {
     fn foreign_from(from: settings::PaymentMethodFilterKey) -> Self {
         match from {
@@ -431,6 +431,12 @@ pub fn convert_backend_input_to_routing_eval(
             Some(ValueType::EnumVariant(sfu.to_string())),
         );
     }
+    if let Some(interval) = input.payment.installment_interval {
+        params.insert(
+            "installment_interval".to_string(),
+            Some(ValueType::EnumVariant(interval.to_string())),
+        );
+    }
 
     // PaymentMethod
     if let Some(pm) = input.payment_method.payment_method {
@@ -472,6 +478,30 @@ pub fn convert_backend_input_to_routing_eval(
         );
     }
 
+    // Payment time
+    if let Some(temporal) = input.temporal_data {
+        params.insert(
+            "payment_hour_of_day".to_string(),
+            Some(ValueType::Number(u64::from(temporal.hour_of_day))),
+        );
+        params.insert(
+            "payment_day_of_week".to_string(),
+            Some(ValueType::EnumVariant(temporal.day_of_week.to_string())),
+        );
+        params.insert(
+            "payment_date".to_string(),
+            Some(ValueType::Number(u64::from(temporal.date))),
+        );
+        // A parameter holds a single value, so when the date falls in several holiday calendars
+        // only the first of them by name is evaluated
+        if let Some(holiday_calendar) = temporal.holiday_calendars.into_iter().next() {
+            params.insert(
+                "payment_holiday_calendar".to_string(),
+                Some(ValueType::StrValue(holiday_calendar)),
+            );
+        }
+    }
+
     // Metadata
     if let Some(meta) = input.metadata {
         for (k, v) in meta.into_iter() {
@@ -712,6 +742,7 @@ impl From<RoutingAlgorithmRecord> for routing_algorithm::RoutingProfileMetadata
             created_at: record.created_at,
             modified_at: record.modified_at,
             algorithm_for,
+            parent_algorithm_id: None,
         }
     }
 }
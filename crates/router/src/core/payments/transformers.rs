@@ -36,7 +36,7 @@ use crate::{
         payments::{self, helpers},
         utils as core_utils,
     },
-    headers::X_PAYMENT_CONFIRM_SOURCE,
+    headers::{CONNECTOR_HTTP_STATUS_CODE, X_PAYMENT_CONFIRM_SOURCE},
     routes::{metrics, SessionState},
     services::{self, RedirectForm},
     types::{
@@ -316,6 +316,8 @@ pub async fn construct_payment_router_data_for_authorize<'a>(
         merchant_config_currency: None,
         connector_testing_data: None,
         order_id: None,
+        installment_plan: None,
+        currency_conversion: None,
     };
     let connector_mandate_request_reference_id = payment_data
         .payment_attempt
@@ -2453,7 +2455,7 @@ where
     let mut headers = connector_http_status_code
         .map(|status_code| {
             vec![(
-                "connector_http_status_code".to_string(),
+                CONNECTOR_HTTP_STATUS_CODE.to_string(),
                 Maskable::new_normal(status_code.to_string()),
             )]
         })
@@ -2810,7 +2812,7 @@ where
             mandate_data,
             setup_future_usage: payment_attempt.setup_future_usage_applied,
             off_session: payment_intent.off_session,
-            capture_on: None,
+            capture_on: payment_attempt.capture_on,
             capture_method: payment_attempt.capture_method,
             payment_method: payment_attempt.payment_method,
             payment_method_data: payment_method_data_response,
@@ -2907,6 +2909,11 @@ where
             issuer_error_message: payment_attempt.issuer_error_message,
             is_iframe_redirection_enabled: payment_intent.is_iframe_redirection_enabled,
             whole_connector_response: payment_data.get_whole_connector_response(),
+            authorization_reversal_status: payment_attempt.authorization_reversal_status,
+            amount_reversed: payment_attempt.amount_reversed,
+            installment_plan: payment_attempt.installment_plan,
+            currency_conversion: payment_attempt.currency_conversion,
+            cancellation_reason_code: payment_attempt.cancellation_reason_code,
         };
 
         services::ApplicationResponse::JsonWithHeaders((payments_response, headers))
@@ -3200,7 +3207,12 @@ impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::Pay
             whole_connector_response: None,
             issuer_error_code: pa.issuer_error_code,
             issuer_error_message: pa.issuer_error_message,
-            is_iframe_redirection_enabled:pi.is_iframe_redirection_enabled
+            is_iframe_redirection_enabled:pi.is_iframe_redirection_enabled,
+            authorization_reversal_status: pa.authorization_reversal_status,
+            amount_reversed: pa.amount_reversed,
+            installment_plan: pa.installment_plan,
+            currency_conversion: pa.currency_conversion,
+            cancellation_reason_code: pa.cancellation_reason_code,
         }
     }
 }
@@ -3594,7 +3606,9 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
             confirm: payment_data.payment_attempt.confirm,
             statement_descriptor_suffix: payment_data.payment_intent.statement_descriptor_suffix,
             statement_descriptor: payment_data.payment_intent.statement_descriptor_name,
-            capture_method: payment_data.payment_attempt.capture_method,
+            capture_method: helpers::get_connector_capture_method(
+                payment_data.payment_attempt.capture_method,
+            ),
             amount: amount.get_amount_as_i64(),
             order_tax_amount: payment_data
                 .payment_attempt
@@ -3641,6 +3655,8 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
             merchant_config_currency,
             connector_testing_data,
             order_id: None,
+            installment_plan: payment_data.payment_attempt.installment_plan,
+            currency_conversion: payment_data.payment_attempt.currency_conversion,
         })
     }
 }
@@ -3660,7 +3676,8 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsSyncData
 
     fn try_from(additional_data: PaymentAdditionalData<'_, F>) -> Result<Self, Self::Error> {
         let payment_data = additional_data.payment_data;
-        let capture_method = payment_data.get_capture_method();
+        let capture_method =
+            helpers::get_connector_capture_method(payment_data.get_capture_method());
         let amount = payment_data.payment_attempt.get_total_amount();
 
         let payment_method_type = payment_data
@@ -3829,7 +3846,9 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsCaptureD
             merchant_connector_account_id,
         ));
         Ok(Self {
-            capture_method: payment_data.get_capture_method(),
+            capture_method: helpers::get_connector_capture_method(
+                payment_data.get_capture_method(),
+            ),
             amount_to_capture: amount_to_capture.get_amount_as_i64(), // This should be removed once we start moving to connector module
             minor_amount_to_capture: amount_to_capture,
             currency: payment_data.currency,
@@ -3889,7 +3908,14 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsCancelDa
             .change_context(errors::ApiErrorResponse::InvalidDataValue {
                 field_name: "browser_info",
             })?;
-        let amount = payment_data.payment_attempt.get_total_amount();
+        // Only the uncaptured remainder is voided when releasing a partially captured authorization
+        let amount = if payment_data.payment_attempt.authorization_reversal_status
+            == Some(enums::AuthorizationReversalStatus::Pending)
+        {
+            payment_data.payment_attempt.amount_capturable
+        } else {
+            payment_data.payment_attempt.get_total_amount()
+        };
 
         let router_base_url = &additional_data.router_base_url;
         let attempt = &payment_data.payment_attempt;
@@ -3905,7 +3931,8 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsCancelDa
             &attempt.merchant_id,
             merchant_connector_account_id,
         ));
-        let capture_method = payment_data.payment_attempt.capture_method;
+        let capture_method =
+            helpers::get_connector_capture_method(payment_data.payment_attempt.capture_method);
         Ok(Self {
             amount: Some(amount.get_amount_as_i64()), // This should be removed once we start moving to connector module
             minor_amount: Some(amount),
@@ -3915,6 +3942,7 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsCancelDa
                 .connector_transaction_id(payment_data.payment_attempt.clone())?
                 .ok_or(errors::ApiErrorResponse::ResourceIdNotFound)?,
             cancellation_reason: payment_data.payment_attempt.cancellation_reason,
+            cancellation_reason_code: payment_data.payment_attempt.cancellation_reason_code,
             connector_meta: payment_data.payment_attempt.connector_metadata,
             browser_info,
             metadata: payment_data.payment_intent.metadata,
@@ -4028,7 +4056,9 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsPostSess
             order_amount: payment_data.payment_intent.amount,
             currency: payment_data.currency,
             merchant_order_reference_id,
-            capture_method: payment_data.payment_attempt.capture_method,
+            capture_method: helpers::get_connector_capture_method(
+                payment_data.payment_attempt.capture_method,
+            ),
             shipping_cost: payment_data.payment_intent.shipping_cost,
             setup_future_usage: payment_data.payment_attempt.setup_future_usage_applied,
             router_return_url,
@@ -4464,7 +4494,9 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::SetupMandateRequ
             shipping_cost: payment_data.payment_intent.shipping_cost,
             webhook_url,
             complete_authorize_url,
-            capture_method: payment_data.payment_attempt.capture_method,
+            capture_method: helpers::get_connector_capture_method(
+                payment_data.payment_attempt.capture_method,
+            ),
             connector_testing_data,
         })
     }
@@ -4590,7 +4622,9 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::CompleteAuthoriz
             setup_mandate_details: payment_data.setup_mandate.clone(),
             confirm: payment_data.payment_attempt.confirm,
             statement_descriptor_suffix: payment_data.payment_intent.statement_descriptor_suffix,
-            capture_method: payment_data.payment_attempt.capture_method,
+            capture_method: helpers::get_connector_capture_method(
+                payment_data.payment_attempt.capture_method,
+            ),
             amount: amount.get_amount_as_i64(), // need to change once we move to connector module
             minor_amount: amount,
             currency: payment_data.currency,
@@ -4700,7 +4734,9 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsPreProce
             minor_amount: Some(amount),
             payment_method_type: payment_data.payment_attempt.payment_method_type,
             setup_mandate_details: payment_data.setup_mandate,
-            capture_method: payment_data.payment_attempt.capture_method,
+            capture_method: helpers::get_connector_capture_method(
+                payment_data.payment_attempt.capture_method,
+            ),
             order_details,
             router_return_url,
             webhook_url,
@@ -22,6 +22,7 @@ use crate::{
     consts,
     core::{
         errors::{self, ConnectorErrorExt, RouterResponse, RouterResult, StorageErrorExt},
+        ledger,
         payments::{self, access_token, helpers},
         refunds::transformers::SplitRefundInput,
         utils::{
@@ -417,6 +418,7 @@ pub async fn trigger_refund_to_gateway(
                 refund.refund_id
             )
         })?;
+    ledger::record_refund_entry(state, &response).await;
     utils::trigger_refund_outgoing_webhook(
         state,
         merchant_context,
@@ -752,6 +754,7 @@ pub async fn sync_refund_with_gateway(
                 refund.refund_id
             )
         })?;
+    ledger::record_refund_entry(state, &response).await;
     utils::trigger_refund_outgoing_webhook(
         state,
         merchant_context,
@@ -1156,7 +1159,7 @@ pub async fn refund_manual_update(
         refund_error_code: req.error_code,
         updated_by: merchant_account.storage_scheme.to_string(),
     };
-    state
+    let updated_refund = state
         .store
         .update_refund(
             refund.to_owned(),
@@ -1171,6 +1174,7 @@ pub async fn refund_manual_update(
                 refund.refund_id
             )
         })?;
+    ledger::record_refund_entry(&state, &updated_refund).await;
     Ok(services::ApplicationResponse::StatusOk)
 }
 
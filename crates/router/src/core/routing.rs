@@ -1,5 +1,7 @@
 pub mod helpers;
 pub mod transformers;
+#[cfg(all(feature = "v1", feature = "olap"))]
+use std::collections::HashMap;
 use std::collections::HashSet;
 
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
@@ -60,6 +62,11 @@ use crate::{
     utils::{self, OptionExt},
 };
 
+#[cfg(feature = "v1")]
+const ROUTING_ACTIVATION_TASK: &str = "ROUTING_ACTIVATION";
+#[cfg(feature = "v1")]
+const ROUTING_ACTIVATION_TAG: &str = "ROUTING";
+
 pub enum TransactionData<'a> {
     Payment(PaymentsDslInput<'a>),
     #[cfg(feature = "payouts")]
@@ -124,6 +131,7 @@ impl RoutingAlgorithmUpdate {
             modified_at: timestamp,
             algorithm_for: transaction_type,
             decision_engine_routing_id: None,
+            parent_algorithm_id: None,
         };
         Self(algo)
     }
@@ -339,6 +347,30 @@ pub async fn create_routing_algorithm_under_profile(
         .await?;
     }
 
+    if let Some(parent_algorithm_id) = request.parent_algorithm_id.as_ref() {
+        let parent_algorithm = db
+            .find_routing_algorithm_by_profile_id_algorithm_id(&profile_id, parent_algorithm_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::InvalidRequestData {
+                message: "parent_algorithm_id does not belong to the profile".to_string(),
+            })?;
+
+        utils::when(
+            parent_algorithm.algorithm_for != transaction_type
+                || parent_algorithm.kind == diesel_models::enums::RoutingAlgorithmKind::Dynamic,
+            || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "A {} routing algorithm cannot be a new version of the {} algorithm {}",
+                        transaction_type,
+                        parent_algorithm.algorithm_for,
+                        parent_algorithm_id.get_string_repr()
+                    ),
+                })
+            },
+        )?;
+    }
+
     let mut decision_engine_routing_id: Option<String> = None;
 
     if let Some(EuclidAlgorithm::Advanced(program)) = request.algorithm.clone() {
@@ -389,13 +421,22 @@ pub async fn create_routing_algorithm_under_profile(
         modified_at: timestamp,
         algorithm_for: transaction_type.to_owned(),
         decision_engine_routing_id,
+        parent_algorithm_id: request.parent_algorithm_id,
     };
     let record = db
         .insert_routing_algorithm(algo)
         .await
         .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
 
-    let new_record = record.foreign_into();
+    let mut new_record: routing_types::RoutingDictionaryRecord = record.foreign_into();
+    new_record.warnings = helpers::get_routing_algorithm_warnings(
+        &state,
+        merchant_context.get_merchant_key_store(),
+        &new_record.profile_id,
+        &algorithm,
+        &transaction_type,
+    )
+    .await;
 
     metrics::ROUTING_CREATE_SUCCESS_RESPONSE.add(1, &[]);
     Ok(service_api::ApplicationResponse::Json(new_record))
@@ -652,17 +693,10 @@ pub async fn link_routing_config(
         | diesel_models::enums::RoutingAlgorithmKind::Priority
         | diesel_models::enums::RoutingAlgorithmKind::Advanced
         | diesel_models::enums::RoutingAlgorithmKind::VolumeSplit
-        | diesel_models::enums::RoutingAlgorithmKind::ThreeDsDecisionRule => {
-            let mut routing_ref: routing_types::RoutingAlgorithmRef = business_profile
-                .routing_algorithm
-                .clone()
-                .map(|val| val.parse_value("RoutingAlgorithmRef"))
-                .transpose()
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable(
-                    "unable to deserialize routing algorithm ref from business profile",
-                )?
-                .unwrap_or_default();
+        | diesel_models::enums::RoutingAlgorithmKind::ThreeDsDecisionRule
+        | diesel_models::enums::RoutingAlgorithmKind::LeastCost => {
+            let mut routing_ref =
+                helpers::get_profile_routing_algorithm_ref(&business_profile, &transaction_type)?;
 
             utils::when(routing_algorithm.algorithm_for != transaction_type, || {
                 Err(errors::ApiErrorResponse::PreconditionFailed {
@@ -681,6 +715,10 @@ pub async fn link_routing_config(
                     })
                 },
             )?;
+            // Promoting the shadow algorithm to active completes the comparison
+            if routing_ref.shadow_algorithm_id.as_ref() == Some(&algorithm_id) {
+                routing_ref.update_shadow_algorithm_id(None);
+            }
             routing_ref.update_algorithm_id(algorithm_id);
             helpers::update_profile_active_algorithm_ref(
                 db,
@@ -744,31 +782,1454 @@ pub async fn retrieve_routing_algorithm_from_algorithm_id(
         db,
         key_manager_state,
         merchant_context.get_merchant_key_store(),
-        Some(&routing_algorithm.0.profile_id),
+        Some(&routing_algorithm.0.profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    let response = routing_types::MerchantRoutingAlgorithm::foreign_try_from(routing_algorithm.0)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to parse routing algorithm")?;
+
+    metrics::ROUTING_RETRIEVE_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::Json(response))
+}
+
+#[cfg(feature = "v1")]
+pub async fn retrieve_routing_algorithm_from_algorithm_id(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    algorithm_id: common_utils::id_type::RoutingId,
+) -> RouterResponse<routing_types::MerchantRoutingAlgorithm> {
+    metrics::ROUTING_RETRIEVE_CONFIG.add(1, &[]);
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let routing_algorithm = db
+        .find_routing_algorithm_by_algorithm_id_merchant_id(
+            &algorithm_id,
+            merchant_context.get_merchant_account().get_id(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&routing_algorithm.profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    let response = routing_types::MerchantRoutingAlgorithm::foreign_try_from(routing_algorithm)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to parse routing algorithm")?;
+
+    metrics::ROUTING_RETRIEVE_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::Json(response))
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub async fn simulate_routing_algorithm(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    request: routing_types::RoutingSimulationRequest,
+) -> RouterResponse<routing_types::RoutingSimulationResponse> {
+    use futures::stream::StreamExt;
+    use hyperswitch_domain_models::payments::payment_intent::{
+        PaymentIntentFetchConstraints, PaymentIntentListParams,
+    };
+
+    metrics::ROUTING_SIMULATE_REQUEST_RECEIVED.add(1, &[]);
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&request.profile_id),
+        merchant_id,
+    )
+    .await?
+    .get_required_value("Profile")?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    if request
+        .algorithm
+        .should_validate_connectors_in_routing_config()
+    {
+        helpers::validate_connectors_in_routing_config(
+            &state,
+            merchant_context.get_merchant_key_store(),
+            merchant_id,
+            &request.profile_id,
+            &request.algorithm,
+        )
+        .await?;
+    }
+
+    let draft_algorithm =
+        payments_routing::CachedAlgorithm::from_static_routing_algorithm(request.algorithm)
+            .change_context(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Unable to initialize the routing algorithm for simulation".to_string(),
+            })?;
+
+    let fee_schedules = if draft_algorithm.is_least_cost() {
+        payments_routing::get_connector_fee_schedules(
+            &state,
+            merchant_context.get_merchant_key_store(),
+            &request.profile_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the fee schedules of the connectors")?
+    } else {
+        std::sync::Arc::default()
+    };
+
+    let limit = request
+        .limit
+        .map_or(crate::consts::ROUTING_SIMULATION_MAX_LIMIT, |limit| {
+            std::cmp::min(limit, crate::consts::ROUTING_SIMULATION_MAX_LIMIT)
+        });
+
+    let fetch_constraints =
+        PaymentIntentFetchConstraints::List(Box::new(PaymentIntentListParams {
+            offset: 0,
+            starting_at: None,
+            ending_at: None,
+            amount_filter: None,
+            connector: None,
+            currency: None,
+            status: None,
+            payment_method: None,
+            payment_method_type: None,
+            authentication_type: None,
+            merchant_connector_id: None,
+            profile_id: Some(vec![request.profile_id.clone()]),
+            customer_id: None,
+            starting_after_id: None,
+            ending_before_id: None,
+            limit: Some(limit),
+            order: Default::default(),
+            card_network: None,
+            card_discovery: None,
+            merchant_order_reference_id: None,
+        }));
+
+    let payments = db
+        .get_filtered_payment_intents_attempt(
+            key_manager_state,
+            merchant_id,
+            &fetch_constraints,
+            merchant_context.get_merchant_key_store(),
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payments for routing simulation")?;
+
+    // The addresses of a bounded number of payments are fetched concurrently, instead of one
+    // payment after the other
+    let payment_addresses = futures::stream::iter(&payments)
+        .map(|(payment_intent, payment_attempt)| {
+            get_payment_address_for_simulation(
+                &state,
+                &merchant_context,
+                &business_profile,
+                payment_intent,
+                payment_attempt,
+            )
+        })
+        .buffered(crate::consts::ROUTING_SIMULATION_ADDRESS_LOOKUP_CONCURRENCY)
+        .collect::<Vec<_>>()
+        .await;
+
+    let mut current = HashMap::<String, ConnectorSimulationAccumulator>::new();
+    let mut simulated = HashMap::<String, ConnectorSimulationAccumulator>::new();
+    let mut evaluated_payments = 0u32;
+    let mut skipped_payments = 0u32;
+    let mut rerouted_payments = 0u32;
+
+    for ((payment_intent, payment_attempt), address) in payments.iter().zip(payment_addresses) {
+        let (Some(current_connector), Some(currency)) = (
+            payment_attempt.connector.clone(),
+            payment_attempt.currency.or(payment_intent.currency),
+        ) else {
+            skipped_payments += 1;
+            continue;
+        };
+
+        // Only the additional data of the card is stored on the attempt, which carries the
+        // attributes of the card that are used for routing
+        let card_info = match payment_attempt
+            .payment_method_data
+            .clone()
+            .map(|payment_method_data| {
+                payment_method_data.parse_value::<api_models::payments::AdditionalPaymentData>(
+                    "AdditionalPaymentData",
+                )
+            })
+            .transpose()
+        {
+            Ok(Some(api_models::payments::AdditionalPaymentData::Card(card_info))) => {
+                Some(card_info)
+            }
+            Ok(_) => None,
+            Err(error) => {
+                router_env::logger::debug!(
+                    payment_id = ?payment_attempt.payment_id,
+                    ?error,
+                    "Unable to parse the additional payment data of the payment attempt"
+                );
+                skipped_payments += 1;
+                continue;
+            }
+        };
+
+        let address = match address {
+            Ok(address) => address,
+            Err(error) => {
+                router_env::logger::debug!(
+                    payment_id = ?payment_attempt.payment_id,
+                    ?error,
+                    "Unable to fetch the addresses of the payment"
+                );
+                skipped_payments += 1;
+                continue;
+            }
+        };
+
+        let payments_dsl_input = PaymentsDslInput::new(
+            None,
+            payment_attempt,
+            payment_intent,
+            None,
+            &address,
+            None,
+            currency,
+        );
+
+        let simulated_connector = async {
+            let mut backend_input = payments_routing::make_dsl_input(&payments_dsl_input)?;
+            if let Some(card_info) = &card_info {
+                backend_input
+                    .payment
+                    .card_bin
+                    .clone_from(&card_info.card_isin);
+                backend_input
+                    .payment_method
+                    .card_network
+                    .clone_from(&card_info.card_network);
+            }
+            // Payment time keys are resolved for the time the payment was created at
+            backend_input.temporal_data = Some(
+                payments_routing::get_temporal_data_input(
+                    &state,
+                    merchant_id,
+                    &request.profile_id,
+                    payment_intent.created_at,
+                )
+                .await?,
+            );
+            // Volume splits are seeded with the payment id, so that a payment is always
+            // simulated against the same connector
+            draft_algorithm.evaluate(
+                backend_input,
+                &fee_schedules,
+                Some(payment_attempt.payment_id.get_string_repr()),
+            )
+        }
+        .await
+        .map(|connectors| {
+            connectors
+                .first()
+                .map(|choice| choice.connector.to_string())
+        });
+
+        let simulated_connector = match simulated_connector {
+            Ok(Some(connector)) => connector,
+            Ok(None) | Err(_) => {
+                router_env::logger::debug!(
+                    payment_id = ?payment_attempt.payment_id,
+                    "Unable to evaluate payment against the draft routing algorithm"
+                );
+                skipped_payments += 1;
+                continue;
+            }
+        };
+
+        let amount = payment_attempt.get_total_amount();
+        let outcome = get_attempt_outcome_for_simulation(payment_attempt.status);
+
+        evaluated_payments += 1;
+        if simulated_connector != current_connector {
+            rerouted_payments += 1;
+        }
+
+        current
+            .entry(current_connector)
+            .or_default()
+            .record(currency, amount, outcome);
+        simulated
+            .entry(simulated_connector)
+            .or_default()
+            .record(currency, amount, None);
+    }
+
+    let current_success_rates = current
+        .iter()
+        .filter_map(|(connector, stats)| {
+            stats
+                .success_rate()
+                .map(|success_rate| (connector.clone(), success_rate))
+        })
+        .collect::<HashMap<_, _>>();
+
+    let current_success_rate = get_weighted_success_rate(&current, &current_success_rates);
+    let expected_success_rate = get_weighted_success_rate(&simulated, &current_success_rates);
+
+    let response = routing_types::RoutingSimulationResponse {
+        profile_id: request.profile_id,
+        evaluated_payments,
+        skipped_payments,
+        rerouted_payments,
+        current_distribution: ConnectorSimulationAccumulator::into_stats(
+            current,
+            evaluated_payments,
+            &current_success_rates,
+        ),
+        simulated_distribution: ConnectorSimulationAccumulator::into_stats(
+            simulated,
+            evaluated_payments,
+            &current_success_rates,
+        ),
+        current_success_rate,
+        expected_success_rate,
+    };
+
+    metrics::ROUTING_SIMULATE_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::Json(response))
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub async fn parse_routing_dsl(
+    request: routing_types::RoutingDslParseRequest,
+) -> RouterResponse<routing_types::RoutingDslParseResponse> {
+    metrics::ROUTING_DSL_PARSE.add(1, &[]);
+
+    let program = euclid::frontend::ast::parser::parse_program::<
+        euclid::frontend::ast::ConnectorSelection,
+    >(&request.dsl)
+    .map_err(|message| {
+        error_stack::report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Unable to parse the routing program: {message}"),
+        })
+    })?;
+
+    let program = program.map_output(routing_types::ConnectorSelection::from);
+
+    euclid::dssa::analyzer::analyze(program.clone(), None).map_err(|error| {
+        error_stack::report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("The routing program is invalid: {error}"),
+        })
+    })?;
+
+    metrics::ROUTING_DSL_PARSE_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::RoutingDslParseResponse { program },
+    ))
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub async fn print_routing_dsl(
+    request: routing_types::RoutingDslPrintRequest,
+) -> RouterResponse<routing_types::RoutingDslPrintResponse> {
+    metrics::ROUTING_DSL_PRINT.add(1, &[]);
+
+    let program = request
+        .program
+        .map_output(euclid::frontend::ast::ConnectorSelection::from);
+
+    let dsl = euclid::frontend::ast::printer::print_program(&program).map_err(|message| {
+        error_stack::report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Unable to print the routing program: {message}"),
+        })
+    })?;
+
+    metrics::ROUTING_DSL_PRINT_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::RoutingDslPrintResponse { dsl },
+    ))
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub async fn upsert_routing_temporal_config(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    request: routing_types::RoutingTemporalConfigWrapper,
+) -> RouterResponse<routing_types::RoutingTemporalConfigResponse> {
+    use common_utils::ext_traits::Encode;
+
+    metrics::ROUTING_UPSERT_TEMPORAL_CONFIG.add(1, &[]);
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&request.profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    request.config.validate().map_err(|error| {
+        error_stack::report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: error.to_string(),
+        })
+    })?;
+
+    let key = merchant_context
+        .get_merchant_account()
+        .get_id()
+        .get_routing_temporal_config_key(business_profile.get_id());
+    let config = request
+        .config
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the payment time routing config")?;
+
+    match db.find_config_by_key_from_db(&key).await {
+        Ok(_) => db
+            .update_config_by_key(
+                &key,
+                storage::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+            .map(|_| ()),
+        Err(error) if error.current_context().is_db_not_found() => db
+            .insert_config(storage::ConfigNew { key, config })
+            .await
+            .map(|_| ()),
+        Err(error) => Err(error),
+    }
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to save the payment time routing config")?;
+
+    metrics::ROUTING_UPSERT_TEMPORAL_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::RoutingTemporalConfigResponse {
+            profile_id: request.profile_id,
+            config: request.config,
+        },
+    ))
+}
+
+/// Returns the payment time configuration of the profile, which is the default configuration
+/// resolving the payment time keys in UTC when none is configured
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub async fn retrieve_routing_temporal_config(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    profile_id: common_utils::id_type::ProfileId,
+) -> RouterResponse<routing_types::RoutingTemporalConfigResponse> {
+    use common_utils::ext_traits::StringExt;
+
+    metrics::ROUTING_RETRIEVE_TEMPORAL_CONFIG.add(1, &[]);
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    let key = merchant_context
+        .get_merchant_account()
+        .get_id()
+        .get_routing_temporal_config_key(business_profile.get_id());
+    let config = match db.find_config_by_key_from_db(&key).await {
+        Ok(config) => config
+            .config
+            .parse_struct("RoutingTemporalConfig")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Payment time routing config has invalid structure")?,
+        Err(error) if error.current_context().is_db_not_found() => {
+            routing_types::RoutingTemporalConfig::default()
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the payment time routing config")?,
+    };
+
+    metrics::ROUTING_RETRIEVE_TEMPORAL_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::RoutingTemporalConfigResponse { profile_id, config },
+    ))
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub async fn delete_routing_temporal_config(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    profile_id: common_utils::id_type::ProfileId,
+) -> RouterResponse<()> {
+    metrics::ROUTING_DELETE_TEMPORAL_CONFIG.add(1, &[]);
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    let key = merchant_context
+        .get_merchant_account()
+        .get_id()
+        .get_routing_temporal_config_key(business_profile.get_id());
+    db.delete_config_by_key(&key).await.to_not_found_response(
+        errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Payment time routing config not found for the profile".to_string(),
+        },
+    )?;
+
+    metrics::ROUTING_DELETE_TEMPORAL_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::StatusOk)
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub async fn explain_routing_eligibility(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    request: routing_types::RoutingEligibilityExplanationRequest,
+) -> RouterResponse<routing_types::RoutingEligibilityExplanationResponse> {
+    use std::str::FromStr;
+
+    use api_models::admin::PaymentMethodsEnabled;
+    use euclid::dssa::graph::{AnalysisContext, CgraphExt};
+    use kgraph_utils::transformers::{IntoContext, IntoDirValue};
+    use masking::ExposeInterface;
+
+    metrics::ROUTING_ELIGIBILITY_EXPLANATION.add(1, &[]);
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&request.profile_id),
+        merchant_id,
+    )
+    .await?
+    .get_required_value("Profile")?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    let backend_input = match (request.payment_id, request.context) {
+        (Some(payment_id), _) => {
+            let payment_intent = db
+                .find_payment_intent_by_payment_id_merchant_id(
+                    key_manager_state,
+                    &payment_id,
+                    merchant_id,
+                    merchant_context.get_merchant_key_store(),
+                    storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+            utils::when(
+                payment_intent.profile_id.as_ref() != Some(&request.profile_id),
+                || {
+                    Err(errors::ApiErrorResponse::PreconditionFailed {
+                        message: "The payment does not belong to the given profile".to_string(),
+                    })
+                },
+            )?;
+
+            let payment_attempt = db
+                .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+                    &payment_intent.payment_id,
+                    merchant_id,
+                    payment_intent.active_attempt.get_id().as_str(),
+                    storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+            let currency = payment_attempt
+                .currency
+                .or(payment_intent.currency)
+                .get_required_value("currency")?;
+
+            let address = payment_address::PaymentAddress::default();
+            let payments_dsl_input = PaymentsDslInput::new(
+                None,
+                &payment_attempt,
+                &payment_intent,
+                None,
+                &address,
+                None,
+                currency,
+            );
+            let mut backend_input = payments_routing::make_dsl_input(&payments_dsl_input)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to construct the routing input for the payment")?;
+
+            // The billing address is not part of the payment address used above, the billing
+            // country is filled in from the address stored against the payment
+            if let Some(address_id) = payment_intent.billing_address_id.as_deref() {
+                let billing_address = db
+                    .find_address_by_merchant_id_payment_id_address_id(
+                        key_manager_state,
+                        merchant_id,
+                        &payment_intent.payment_id,
+                        address_id,
+                        merchant_context.get_merchant_key_store(),
+                        storage_scheme,
+                    )
+                    .await
+                    .to_not_found_response(errors::ApiErrorResponse::AddressNotFound)?;
+
+                backend_input.payment.billing_country = billing_address
+                    .address
+                    .country
+                    .map(common_enums::Country::from_alpha2);
+            }
+
+            backend_input
+        }
+        (None, Some(context)) => context,
+        (None, None) => {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Either payment_id or context must be provided".to_string(),
+            }
+            .into())
+        }
+    };
+
+    let context_values = backend_input.into_context().change_context(
+        errors::ApiErrorResponse::InvalidRequestData {
+            message: "Unable to construct the analysis context from the given input".to_string(),
+        },
+    )?;
+
+    let transaction_type = request
+        .transaction_type
+        .unwrap_or(enums::TransactionType::Payment);
+
+    let mca_graph = payments_routing::get_merchant_cgraph(
+        &state,
+        merchant_context.get_merchant_key_store(),
+        &request.profile_id,
+        &transaction_type,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch the knowledge graph of the profile")?;
+
+    let merchant_connector_accounts = db
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            key_manager_state,
+            merchant_id,
+            false,
+            merchant_context.get_merchant_key_store(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?
+        .filter_based_on_profile_and_connector_type(
+            &request.profile_id,
+            common_enums::ConnectorType::PaymentProcessor,
+        );
+
+    let mca_context = AnalysisContext::from_dir_values(context_values.clone());
+    let mut connectors = Vec::new();
+    let mut connector_values = Vec::new();
+
+    for mca in &merchant_connector_accounts {
+        let Ok(connector) = enums::RoutableConnectors::from_str(&mca.connector_name) else {
+            continue;
+        };
+        let connector_value = euclid::frontend::dir::DirValue::Connector(Box::new(
+            euclid::frontend::ast::ConnectorChoice { connector },
+        ));
+
+        let explanation = mca_graph
+            .explain_value_validity(connector_value.clone(), &mca_context, None)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to analyze the knowledge graph of the profile")?;
+
+        connectors.push(routing_types::ConnectorEligibilityExplanation {
+            connector: routing_types::RoutableConnectorChoice {
+                choice_kind: routing_types::RoutableChoiceKind::FullStruct,
+                connector,
+                merchant_connector_id: Some(mca.get_id()),
+            },
+            eligible: explanation.is_none(),
+            explanation: explanation.map(routing_types::ConstraintGraphExplanation::from),
+        });
+        connector_values.push((mca, connector_value));
+    }
+
+    let pm_filter_graph = crate::core::payment_methods::utils::get_or_build_pm_filter_graph(
+        &state,
+        merchant_id,
+        &request.profile_id,
+        &merchant_connector_accounts,
+        &state.conf.pm_filters,
+    )
+    .await?;
+
+    // The payment methods list only considers these keys along with the payment method type
+    // and the connector, see `filter_payment_methods`
+    let pm_filter_context_values = context_values
+        .into_iter()
+        .filter(|value| {
+            matches!(
+                value,
+                euclid::frontend::dir::DirValue::PaymentCurrency(_)
+                    | euclid::frontend::dir::DirValue::BillingCountry(_)
+                    | euclid::frontend::dir::DirValue::PaymentType(_)
+                    | euclid::frontend::dir::DirValue::CaptureMethod(_)
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let mut payment_methods = Vec::new();
+
+    for (mca, connector_value) in connector_values {
+        let domain_ident: &[String] = &[mca.get_id().get_string_repr().to_string()];
+
+        for payment_methods_enabled in
+            mca.payment_methods_enabled
+                .iter()
+                .flatten()
+                .filter_map(|value| {
+                    serde_json::from_value::<PaymentMethodsEnabled>(value.clone().expose())
+                        .map_err(|error| {
+                            router_env::logger::error!(
+                            ?error,
+                            "Failed to deserialize the payment methods enabled for the connector"
+                        )
+                        })
+                        .ok()
+                })
+        {
+            let payment_method = payment_methods_enabled.payment_method;
+
+            for payment_method_type in payment_methods_enabled
+                .payment_method_types
+                .unwrap_or_default()
+                .into_iter()
+                .map(|info| info.payment_method_type)
+            {
+                let Ok(pm_dir_value) = (payment_method_type, payment_method).into_dir_value()
+                else {
+                    continue;
+                };
+
+                let context = AnalysisContext::from_dir_values(
+                    pm_filter_context_values
+                        .iter()
+                        .cloned()
+                        .chain([pm_dir_value.clone(), connector_value.clone()]),
+                );
+
+                let explanation = pm_filter_graph
+                    .explain_key_value_analysis(pm_dir_value, &context, Some(domain_ident))
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to analyze the payment method filters")?;
+
+                payment_methods.push(routing_types::PaymentMethodEligibilityExplanation {
+                    connector: mca.connector_name.clone(),
+                    merchant_connector_id: mca.get_id(),
+                    payment_method,
+                    payment_method_type,
+                    eligible: explanation.is_none(),
+                    explanation: explanation.map(routing_types::ConstraintGraphExplanation::from),
+                });
+            }
+        }
+    }
+
+    metrics::ROUTING_ELIGIBILITY_EXPLANATION_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::RoutingEligibilityExplanationResponse {
+            profile_id: request.profile_id,
+            connectors,
+            payment_methods,
+        },
+    ))
+}
+
+/// Fetches the billing addresses of the payment, which are matched against by the routing rules
+#[cfg(all(feature = "v1", feature = "olap"))]
+async fn get_payment_address_for_simulation(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    business_profile: &domain::Profile,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+) -> CustomResult<payment_address::PaymentAddress, errors::StorageError> {
+    let find_address = |address_id: Option<String>| async move {
+        match address_id {
+            Some(address_id) => state
+                .store
+                .find_address_by_merchant_id_payment_id_address_id(
+                    &state.into(),
+                    &payment_intent.merchant_id,
+                    &payment_intent.payment_id,
+                    &address_id,
+                    merchant_context.get_merchant_key_store(),
+                    merchant_context.get_merchant_account().storage_scheme,
+                )
+                .await
+                .map(|payment_address| Some(payment_address.address)),
+            None => Ok(None),
+        }
+    };
+
+    let billing_address = find_address(payment_intent.billing_address_id.clone()).await?;
+    let payment_method_billing_address =
+        find_address(payment_attempt.payment_method_billing_address_id.clone()).await?;
+
+    Ok(payment_address::PaymentAddress::new(
+        None,
+        billing_address.as_ref().map(From::from),
+        payment_method_billing_address.as_ref().map(From::from),
+        business_profile.use_billing_as_payment_method_billing,
+    ))
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[derive(Default)]
+struct ConnectorSimulationAccumulator {
+    payment_count: u32,
+    volume: HashMap<common_enums::Currency, common_utils::types::MinorUnit>,
+    successful_payments: u32,
+    completed_payments: u32,
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+impl ConnectorSimulationAccumulator {
+    fn record(
+        &mut self,
+        currency: common_enums::Currency,
+        amount: common_utils::types::MinorUnit,
+        outcome: Option<bool>,
+    ) {
+        self.payment_count += 1;
+        let volume = self.volume.entry(currency).or_default();
+        *volume = *volume + amount;
+
+        if let Some(is_success) = outcome {
+            self.completed_payments += 1;
+            if is_success {
+                self.successful_payments += 1;
+            }
+        }
+    }
+
+    fn success_rate(&self) -> Option<f64> {
+        (self.completed_payments > 0).then(|| {
+            f64::from(self.successful_payments) * 100.0 / f64::from(self.completed_payments)
+        })
+    }
+
+    fn into_stats(
+        accumulators: HashMap<String, Self>,
+        evaluated_payments: u32,
+        success_rates: &HashMap<String, f64>,
+    ) -> Vec<routing_types::ConnectorSimulationStats> {
+        let mut stats = accumulators
+            .into_iter()
+            .map(
+                |(connector, accumulator)| routing_types::ConnectorSimulationStats {
+                    success_rate: success_rates.get(&connector).copied(),
+                    payment_share: f64::from(accumulator.payment_count) * 100.0
+                        / f64::from(evaluated_payments.max(1)),
+                    payment_count: accumulator.payment_count,
+                    volume: accumulator.volume,
+                    connector,
+                },
+            )
+            .collect::<Vec<_>>();
+
+        stats.sort_by_key(|stat| std::cmp::Reverse(stat.payment_count));
+        stats
+    }
+}
+
+/// Weighs the success rate of every connector by the number of payments routed to it,
+/// ignoring connectors for which no success rate could be observed.
+#[cfg(all(feature = "v1", feature = "olap"))]
+fn get_weighted_success_rate(
+    accumulators: &HashMap<String, ConnectorSimulationAccumulator>,
+    success_rates: &HashMap<String, f64>,
+) -> Option<f64> {
+    let (weighted_sum, payment_count) = accumulators.iter().fold(
+        (0.0, 0u32),
+        |(weighted_sum, payment_count), (connector, accumulator)| {
+            success_rates
+                .get(connector)
+                .map_or((weighted_sum, payment_count), |success_rate| {
+                    (
+                        weighted_sum + success_rate * f64::from(accumulator.payment_count),
+                        payment_count + accumulator.payment_count,
+                    )
+                })
+        },
+    );
+
+    (payment_count > 0).then(|| weighted_sum / f64::from(payment_count))
+}
+
+/// Returns whether the attempt succeeded, or `None` if it has not reached a final outcome yet.
+#[cfg(all(feature = "v1", feature = "olap"))]
+fn get_attempt_outcome_for_simulation(status: common_enums::AttemptStatus) -> Option<bool> {
+    match status {
+        common_enums::AttemptStatus::Charged
+        | common_enums::AttemptStatus::Authorized
+        | common_enums::AttemptStatus::PartialCharged
+        | common_enums::AttemptStatus::PartialChargedAndChargeable => Some(true),
+        common_enums::AttemptStatus::Failure
+        | common_enums::AttemptStatus::AuthorizationFailed
+        | common_enums::AttemptStatus::AuthenticationFailed
+        | common_enums::AttemptStatus::CaptureFailed
+        | common_enums::AttemptStatus::RouterDeclined => Some(false),
+        common_enums::AttemptStatus::Started
+        | common_enums::AttemptStatus::AuthenticationPending
+        | common_enums::AttemptStatus::AuthenticationSuccessful
+        | common_enums::AttemptStatus::Authorizing
+        | common_enums::AttemptStatus::CodInitiated
+        | common_enums::AttemptStatus::Voided
+        | common_enums::AttemptStatus::VoidInitiated
+        | common_enums::AttemptStatus::CaptureInitiated
+        | common_enums::AttemptStatus::VoidFailed
+        | common_enums::AttemptStatus::AutoRefunded
+        | common_enums::AttemptStatus::Unresolved
+        | common_enums::AttemptStatus::Pending
+        | common_enums::AttemptStatus::IntegrityFailure
+        | common_enums::AttemptStatus::PaymentMethodAwaited
+        | common_enums::AttemptStatus::ConfirmationAwaited
+        | common_enums::AttemptStatus::DeviceDataCollectionPending => None,
+    }
+}
+
+#[cfg(feature = "v2")]
+pub async fn unlink_routing_config_under_profile(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: common_utils::id_type::ProfileId,
+    transaction_type: &enums::TransactionType,
+) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
+    metrics::ROUTING_UNLINK_CONFIG.add(1, &[]);
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")?;
+
+    let routing_algo_id = match transaction_type {
+        enums::TransactionType::Payment => business_profile.routing_algorithm_id.clone(),
+        #[cfg(feature = "payouts")]
+        enums::TransactionType::Payout => business_profile.payout_routing_algorithm_id.clone(),
+        // TODO: Handle ThreeDsAuthentication Transaction Type for Three DS Decision Rule Algorithm configuration
+        enums::TransactionType::ThreeDsAuthentication => todo!(),
+    };
+
+    if let Some(algorithm_id) = routing_algo_id {
+        let record = RoutingAlgorithmUpdate::fetch_routing_algo(
+            merchant_context.get_merchant_account().get_id(),
+            &algorithm_id,
+            db,
+        )
+        .await?;
+        let response = record.0.foreign_into();
+        admin::ProfileWrapper::new(business_profile)
+            .update_profile_and_invalidate_routing_config_for_active_algorithm_id_update(
+                db,
+                key_manager_state,
+                merchant_context.get_merchant_key_store(),
+                algorithm_id,
+                transaction_type,
+            )
+            .await?;
+        metrics::ROUTING_UNLINK_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
+        Ok(service_api::ApplicationResponse::Json(response))
+    } else {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Algorithm is already inactive".to_string(),
+        })?
+    }
+}
+
+#[cfg(feature = "v1")]
+pub async fn unlink_routing_config(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: routing_types::RoutingConfigRequest,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    transaction_type: enums::TransactionType,
+) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
+    metrics::ROUTING_UNLINK_CONFIG.add(1, &[]);
+
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let profile_id = request
+        .profile_id
+        .get_required_value("profile_id")
+        .change_context(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "profile_id",
+        })
+        .attach_printable("Profile_id not provided")?;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?;
+
+    match business_profile {
+        Some(business_profile) => {
+            core_utils::validate_profile_id_from_auth_layer(
+                authentication_profile_id,
+                &business_profile,
+            )?;
+            let routing_algo_ref: routing_types::RoutingAlgorithmRef = match transaction_type {
+                enums::TransactionType::Payment => business_profile.routing_algorithm.clone(),
+                #[cfg(feature = "payouts")]
+                enums::TransactionType::Payout => business_profile.payout_routing_algorithm.clone(),
+                enums::TransactionType::ThreeDsAuthentication => {
+                    business_profile.three_ds_decision_rule_algorithm.clone()
+                }
+            }
+            .map(|val| val.parse_value("RoutingAlgorithmRef"))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("unable to deserialize routing algorithm ref from merchant account")?
+            .unwrap_or_default();
+
+            let timestamp = common_utils::date_time::now_unix_timestamp();
+
+            match routing_algo_ref.algorithm_id {
+                Some(algorithm_id) => {
+                    let routing_algorithm: routing_types::RoutingAlgorithmRef =
+                        routing_types::RoutingAlgorithmRef {
+                            algorithm_id: None,
+                            timestamp,
+                            config_algo_id: routing_algo_ref.config_algo_id.clone(),
+                            surcharge_config_algo_id: routing_algo_ref.surcharge_config_algo_id,
+                            shadow_algorithm_id: routing_algo_ref.shadow_algorithm_id,
+                            previous_algorithm_id: Some(algorithm_id.clone()),
+                        };
+
+                    let record = db
+                        .find_routing_algorithm_by_profile_id_algorithm_id(
+                            &profile_id,
+                            &algorithm_id,
+                        )
+                        .await
+                        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+                    let response = record.foreign_into();
+                    helpers::update_profile_active_algorithm_ref(
+                        db,
+                        key_manager_state,
+                        merchant_context.get_merchant_key_store(),
+                        business_profile,
+                        routing_algorithm,
+                        &transaction_type,
+                    )
+                    .await?;
+
+                    metrics::ROUTING_UNLINK_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
+                    Ok(service_api::ApplicationResponse::Json(response))
+                }
+                None => Err(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "Algorithm is already inactive".to_string(),
+                })?,
+            }
+        }
+        None => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The business_profile is not present".to_string(),
+        }
+        .into()),
+    }
+}
+
+#[cfg(feature = "v1")]
+pub async fn link_shadow_routing_config(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    algorithm_id: common_utils::id_type::RoutingId,
+) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
+    metrics::ROUTING_LINK_SHADOW_CONFIG.add(1, &[]);
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let routing_algorithm = db
+        .find_routing_algorithm_by_algorithm_id_merchant_id(
+            &algorithm_id,
+            merchant_context.get_merchant_account().get_id(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&routing_algorithm.profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: routing_algorithm.profile_id.get_string_repr().to_owned(),
+    })?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    utils::when(
+        !matches!(
+            routing_algorithm.kind,
+            diesel_models::enums::RoutingAlgorithmKind::Single
+                | diesel_models::enums::RoutingAlgorithmKind::Priority
+                | diesel_models::enums::RoutingAlgorithmKind::Advanced
+                | diesel_models::enums::RoutingAlgorithmKind::VolumeSplit
+                | diesel_models::enums::RoutingAlgorithmKind::LeastCost
+        ),
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "{} routing algorithms cannot be used as a shadow algorithm",
+                    routing_algorithm.kind
+                ),
+            })
+        },
+    )?;
+
+    utils::when(
+        routing_algorithm.algorithm_for != enums::TransactionType::Payment,
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Shadow routing is only supported for payments".to_string(),
+            })
+        },
+    )?;
+
+    let mut routing_ref: routing_types::RoutingAlgorithmRef = business_profile
+        .routing_algorithm
+        .clone()
+        .map(|val| val.parse_value("RoutingAlgorithmRef"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to deserialize routing algorithm ref from business profile")?
+        .unwrap_or_default();
+
+    utils::when(
+        routing_ref.algorithm_id.as_ref() == Some(&algorithm_id),
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Algorithm is already active".to_string(),
+            })
+        },
+    )?;
+
+    utils::when(
+        routing_ref.shadow_algorithm_id.as_ref() == Some(&algorithm_id),
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Algorithm is already the shadow algorithm".to_string(),
+            })
+        },
+    )?;
+
+    routing_ref.update_shadow_algorithm_id(Some(algorithm_id));
+    helpers::update_profile_active_algorithm_ref(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        business_profile,
+        routing_ref,
+        &enums::TransactionType::Payment,
+    )
+    .await?;
+
+    metrics::ROUTING_LINK_SHADOW_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::Json(
+        routing_algorithm.foreign_into(),
+    ))
+}
+
+#[cfg(feature = "v1")]
+pub async fn unlink_shadow_routing_config(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: routing_types::RoutingConfigRequest,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
+    metrics::ROUTING_UNLINK_SHADOW_CONFIG.add(1, &[]);
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let profile_id = request
+        .profile_id
+        .get_required_value("profile_id")
+        .change_context(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "profile_id",
+        })
+        .attach_printable("Profile_id not provided")?;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    let mut routing_ref: routing_types::RoutingAlgorithmRef = business_profile
+        .routing_algorithm
+        .clone()
+        .map(|val| val.parse_value("RoutingAlgorithmRef"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to deserialize routing algorithm ref from business profile")?
+        .unwrap_or_default();
+
+    let shadow_algorithm_id = routing_ref.shadow_algorithm_id.clone().ok_or(
+        errors::ApiErrorResponse::PreconditionFailed {
+            message: "No shadow algorithm is configured for the profile".to_string(),
+        },
+    )?;
+
+    let record = db
+        .find_routing_algorithm_by_profile_id_algorithm_id(&profile_id, &shadow_algorithm_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    routing_ref.update_shadow_algorithm_id(None);
+    helpers::update_profile_active_algorithm_ref(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        business_profile,
+        routing_ref,
+        &enums::TransactionType::Payment,
+    )
+    .await?;
+
+    metrics::ROUTING_UNLINK_SHADOW_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::Json(
+        record.foreign_into(),
+    ))
+}
+
+#[cfg(feature = "v1")]
+pub async fn rollback_routing_config(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    request: routing_types::RoutingRollbackRequest,
+    transaction_type: enums::TransactionType,
+) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
+    metrics::ROUTING_ROLLBACK_CONFIG.add(1, &[]);
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&request.profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: request.profile_id.get_string_repr().to_owned(),
+    })?;
+
+    core_utils::validate_profile_id_from_auth_layer(
+        authentication_profile_id.clone(),
+        &business_profile,
+    )?;
+
+    let previous_algorithm_id =
+        helpers::get_profile_routing_algorithm_ref(&business_profile, &transaction_type)?
+            .previous_algorithm_id
+            .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+                message: "No previously active routing algorithm to roll back to".to_string(),
+            })?;
+
+    // Linking records the currently active algorithm as the previous one, so that a rollback
+    // can itself be rolled back
+    let response = link_routing_config(
+        state,
+        merchant_context,
+        authentication_profile_id,
+        previous_algorithm_id,
+        transaction_type,
+    )
+    .await?;
+
+    metrics::ROUTING_ROLLBACK_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(response)
+}
+
+#[cfg(feature = "v1")]
+pub async fn diff_routing_configs(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    algorithm_id: common_utils::id_type::RoutingId,
+    query: routing_types::RoutingAlgorithmDiffQuery,
+) -> RouterResponse<routing_types::RoutingAlgorithmDiffResponse> {
+    metrics::ROUTING_DIFF_CONFIG.add(1, &[]);
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let routing_algorithm = db
+        .find_routing_algorithm_by_algorithm_id_merchant_id(
+            &algorithm_id,
+            merchant_context.get_merchant_account().get_id(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&routing_algorithm.profile_id),
         merchant_context.get_merchant_account().get_id(),
     )
     .await?
-    .get_required_value("Profile")
-    .change_context(errors::ApiErrorResponse::ResourceIdNotFound)?;
+    .get_required_value("Profile")?;
 
     core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
 
-    let response = routing_types::MerchantRoutingAlgorithm::foreign_try_from(routing_algorithm.0)
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("unable to parse routing algorithm")?;
+    let base_algorithm_id = query
+        .base_algorithm_id
+        .or(routing_algorithm.parent_algorithm_id.clone())
+        .ok_or(errors::ApiErrorResponse::InvalidRequestData {
+            message: "base_algorithm_id is required as the routing algorithm has no parent"
+                .to_string(),
+        })?;
 
-    metrics::ROUTING_RETRIEVE_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
-    Ok(service_api::ApplicationResponse::Json(response))
+    let base_algorithm = db
+        .find_routing_algorithm_by_profile_id_algorithm_id(
+            &routing_algorithm.profile_id,
+            &base_algorithm_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    let parse_static_algorithm =
+        |algorithm: &RoutingAlgorithm| -> RouterResult<routing_types::StaticRoutingAlgorithm> {
+            utils::when(
+                algorithm.kind == diesel_models::enums::RoutingAlgorithmKind::Dynamic,
+                || {
+                    Err(errors::ApiErrorResponse::PreconditionFailed {
+                        message: "Dynamic routing algorithms cannot be compared".to_string(),
+                    })
+                },
+            )?;
+
+            algorithm
+                .algorithm_data
+                .clone()
+                .parse_value::<routing_types::StaticRoutingAlgorithm>("StaticRoutingAlgorithm")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Unable to deserialize the routing algorithm")
+        };
+
+    let changes = parse_static_algorithm(&routing_algorithm)?
+        .get_changes_from(&parse_static_algorithm(&base_algorithm)?);
+
+    metrics::ROUTING_DIFF_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::RoutingAlgorithmDiffResponse {
+            algorithm_id,
+            base_algorithm_id,
+            kind: routing_algorithm.kind.foreign_into(),
+            base_kind: base_algorithm.kind.foreign_into(),
+            changes,
+        },
+    ))
 }
 
 #[cfg(feature = "v1")]
-pub async fn retrieve_routing_algorithm_from_algorithm_id(
+pub async fn schedule_routing_config_activation(
     state: SessionState,
     merchant_context: domain::MerchantContext,
     authentication_profile_id: Option<common_utils::id_type::ProfileId>,
     algorithm_id: common_utils::id_type::RoutingId,
-) -> RouterResponse<routing_types::MerchantRoutingAlgorithm> {
-    metrics::ROUTING_RETRIEVE_CONFIG.add(1, &[]);
+    request: routing_types::RoutingActivationScheduleRequest,
+    transaction_type: enums::TransactionType,
+) -> RouterResponse<routing_types::RoutingActivationScheduleResponse> {
+    metrics::ROUTING_SCHEDULE_ACTIVATION.add(1, &[]);
     let db = state.store.as_ref();
     let key_manager_state = &(&state).into();
 
@@ -788,27 +2249,146 @@ pub async fn retrieve_routing_algorithm_from_algorithm_id(
         merchant_context.get_merchant_account().get_id(),
     )
     .await?
-    .get_required_value("Profile")
-    .change_context(errors::ApiErrorResponse::ResourceIdNotFound)?;
+    .get_required_value("Profile")?;
 
     core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
 
-    let response = routing_types::MerchantRoutingAlgorithm::foreign_try_from(routing_algorithm)
+    utils::when(
+        routing_algorithm.kind == diesel_models::enums::RoutingAlgorithmKind::Dynamic,
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Activation of dynamic routing algorithms cannot be scheduled".to_string(),
+            })
+        },
+    )?;
+
+    utils::when(routing_algorithm.algorithm_for != transaction_type, || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "Cannot use {}'s routing algorithm for {} operation",
+                routing_algorithm.algorithm_for, transaction_type
+            ),
+        })
+    })?;
+
+    utils::when(
+        request.activate_at <= common_utils::date_time::now(),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "activate_at must be in the future".to_string(),
+            })
+        },
+    )?;
+
+    let tracking_data = storage::RoutingActivationTrackingData {
+        merchant_id: routing_algorithm.merchant_id.clone(),
+        profile_id: routing_algorithm.profile_id.clone(),
+        algorithm_id: algorithm_id.clone(),
+        transaction_type,
+    };
+
+    let process_tracker_id = format!(
+        "{}{}_{}",
+        get_routing_activation_process_tracker_id_prefix(&routing_algorithm.profile_id),
+        algorithm_id.get_string_repr(),
+        request.activate_at.assume_utc().unix_timestamp()
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id.clone(),
+        ROUTING_ACTIVATION_TASK,
+        storage::ProcessTrackerRunner::RoutingActivationWorkflow,
+        [ROUTING_ACTIVATION_TAG],
+        tracking_data,
+        None,
+        request.activate_at,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct the routing activation process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Activation of the routing algorithm is already scheduled at this time"
+                .to_string(),
+        })
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting the routing activation task for algorithm: {}",
+                algorithm_id.get_string_repr()
+            )
+        })?;
+
+    crate::routes::metrics::TASKS_ADDED_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", "RoutingActivation")),
+    );
+
+    metrics::ROUTING_SCHEDULE_ACTIVATION_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::RoutingActivationScheduleResponse {
+            activation_id: process_tracker_id,
+            algorithm_id,
+            profile_id: routing_algorithm.profile_id,
+            transaction_type,
+            activate_at: request.activate_at,
+        },
+    ))
+}
+
+/// Prefix of the ids of the routing activation tasks of the profile, by which the scheduled
+/// activations of the profile are listed
+#[cfg(feature = "v1")]
+fn get_routing_activation_process_tracker_id_prefix(
+    profile_id: &common_utils::id_type::ProfileId,
+) -> String {
+    format!(
+        "{}_{ROUTING_ACTIVATION_TASK}_{}_",
+        storage::ProcessTrackerRunner::RoutingActivationWorkflow,
+        profile_id.get_string_repr()
+    )
+}
+
+#[cfg(feature = "v1")]
+fn get_routing_activation_tracking_data(
+    process: &storage::ProcessTracker,
+) -> RouterResult<storage::RoutingActivationTrackingData> {
+    process
+        .tracking_data
+        .clone()
+        .parse_value("RoutingActivationTrackingData")
         .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("unable to parse routing algorithm")?;
+        .attach_printable("Failed to parse the tracking data of the routing activation task")
+}
 
-    metrics::ROUTING_RETRIEVE_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
-    Ok(service_api::ApplicationResponse::Json(response))
+#[cfg(feature = "v1")]
+fn get_routing_activation_schedule_response(
+    process: &storage::ProcessTracker,
+    tracking_data: storage::RoutingActivationTrackingData,
+) -> RouterResult<routing_types::RoutingActivationScheduleResponse> {
+    Ok(routing_types::RoutingActivationScheduleResponse {
+        activation_id: process.id.clone(),
+        algorithm_id: tracking_data.algorithm_id,
+        profile_id: tracking_data.profile_id,
+        transaction_type: tracking_data.transaction_type,
+        activate_at: process
+            .schedule_time
+            .get_required_value("schedule_time")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Routing activation task is not scheduled")?,
+    })
 }
 
-#[cfg(feature = "v2")]
-pub async fn unlink_routing_config_under_profile(
+/// Lists the activations of the routing algorithms of the profile which are scheduled and not yet
+/// executed, ordered by the time of the activation
+#[cfg(feature = "v1")]
+pub async fn list_scheduled_routing_activations(
     state: SessionState,
     merchant_context: domain::MerchantContext,
-    profile_id: common_utils::id_type::ProfileId,
-    transaction_type: &enums::TransactionType,
-) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
-    metrics::ROUTING_UNLINK_CONFIG.add(1, &[]);
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    query: routing_types::RoutingActivationScheduleListQuery,
+) -> RouterResponse<Vec<routing_types::RoutingActivationScheduleResponse>> {
+    metrics::ROUTING_LIST_SCHEDULED_ACTIVATIONS.add(1, &[]);
     let db = state.store.as_ref();
     let key_manager_state = &(&state).into();
 
@@ -816,139 +2396,112 @@ pub async fn unlink_routing_config_under_profile(
         db,
         key_manager_state,
         merchant_context.get_merchant_key_store(),
-        Some(&profile_id),
+        Some(&query.profile_id),
         merchant_context.get_merchant_account().get_id(),
     )
     .await?
     .get_required_value("Profile")?;
 
-    let routing_algo_id = match transaction_type {
-        enums::TransactionType::Payment => business_profile.routing_algorithm_id.clone(),
-        #[cfg(feature = "payouts")]
-        enums::TransactionType::Payout => business_profile.payout_routing_algorithm_id.clone(),
-        // TODO: Handle ThreeDsAuthentication Transaction Type for Three DS Decision Rule Algorithm configuration
-        enums::TransactionType::ThreeDsAuthentication => todo!(),
-    };
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
 
-    if let Some(algorithm_id) = routing_algo_id {
-        let record = RoutingAlgorithmUpdate::fetch_routing_algo(
-            merchant_context.get_merchant_account().get_id(),
-            &algorithm_id,
-            db,
+    let scheduled_activations = db
+        .find_processes_by_runner_id_prefix_status(
+            storage::ProcessTrackerRunner::RoutingActivationWorkflow,
+            &get_routing_activation_process_tracker_id_prefix(&query.profile_id),
+            vec![
+                storage_enums::ProcessTrackerStatus::New,
+                storage_enums::ProcessTrackerStatus::Pending,
+            ],
         )
-        .await?;
-        let response = record.0.foreign_into();
-        admin::ProfileWrapper::new(business_profile)
-            .update_profile_and_invalidate_routing_config_for_active_algorithm_id_update(
-                db,
-                key_manager_state,
-                merchant_context.get_merchant_key_store(),
-                algorithm_id,
-                transaction_type,
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the routing activation tasks of the profile")?
+        .iter()
+        .map(|process| {
+            get_routing_activation_schedule_response(
+                process,
+                get_routing_activation_tracking_data(process)?,
             )
-            .await?;
-        metrics::ROUTING_UNLINK_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
-        Ok(service_api::ApplicationResponse::Json(response))
-    } else {
-        Err(errors::ApiErrorResponse::PreconditionFailed {
-            message: "Algorithm is already inactive".to_string(),
-        })?
-    }
+        })
+        .collect::<RouterResult<Vec<_>>>()?
+        .into_iter()
+        .filter(|scheduled_activation| {
+            query.transaction_type.map_or(true, |transaction_type| {
+                scheduled_activation.transaction_type == transaction_type
+            })
+        })
+        .collect();
+
+    metrics::ROUTING_LIST_SCHEDULED_ACTIVATIONS_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::Json(
+        scheduled_activations,
+    ))
 }
 
+/// Cancels a scheduled activation of a routing algorithm which is not yet executed
 #[cfg(feature = "v1")]
-pub async fn unlink_routing_config(
+pub async fn cancel_scheduled_routing_activation(
     state: SessionState,
     merchant_context: domain::MerchantContext,
-    request: routing_types::RoutingConfigRequest,
     authentication_profile_id: Option<common_utils::id_type::ProfileId>,
-    transaction_type: enums::TransactionType,
-) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
-    metrics::ROUTING_UNLINK_CONFIG.add(1, &[]);
-
+    activation_id: String,
+) -> RouterResponse<routing_types::RoutingActivationScheduleResponse> {
+    metrics::ROUTING_CANCEL_SCHEDULED_ACTIVATION.add(1, &[]);
     let db = state.store.as_ref();
     let key_manager_state = &(&state).into();
 
-    let profile_id = request
-        .profile_id
-        .get_required_value("profile_id")
-        .change_context(errors::ApiErrorResponse::MissingRequiredField {
-            field_name: "profile_id",
-        })
-        .attach_printable("Profile_id not provided")?;
+    let runner = storage::ProcessTrackerRunner::RoutingActivationWorkflow.to_string();
+    let process = db
+        .find_process_by_id(&activation_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the routing activation task")?
+        .filter(|process| process.runner.as_deref() == Some(runner.as_str()))
+        .ok_or(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    let tracking_data = get_routing_activation_tracking_data(&process)?;
+    utils::when(
+        &tracking_data.merchant_id != merchant_context.get_merchant_account().get_id(),
+        || Err(errors::ApiErrorResponse::ResourceIdNotFound),
+    )?;
 
     let business_profile = core_utils::validate_and_get_business_profile(
         db,
         key_manager_state,
         merchant_context.get_merchant_key_store(),
-        Some(&profile_id),
+        Some(&tracking_data.profile_id),
         merchant_context.get_merchant_account().get_id(),
     )
-    .await?;
+    .await?
+    .get_required_value("Profile")?;
 
-    match business_profile {
-        Some(business_profile) => {
-            core_utils::validate_profile_id_from_auth_layer(
-                authentication_profile_id,
-                &business_profile,
-            )?;
-            let routing_algo_ref: routing_types::RoutingAlgorithmRef = match transaction_type {
-                enums::TransactionType::Payment => business_profile.routing_algorithm.clone(),
-                #[cfg(feature = "payouts")]
-                enums::TransactionType::Payout => business_profile.payout_routing_algorithm.clone(),
-                enums::TransactionType::ThreeDsAuthentication => {
-                    business_profile.three_ds_decision_rule_algorithm.clone()
-                }
-            }
-            .map(|val| val.parse_value("RoutingAlgorithmRef"))
-            .transpose()
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("unable to deserialize routing algorithm ref from merchant account")?
-            .unwrap_or_default();
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
 
-            let timestamp = common_utils::date_time::now_unix_timestamp();
+    utils::when(
+        !matches!(
+            process.status,
+            storage_enums::ProcessTrackerStatus::New | storage_enums::ProcessTrackerStatus::Pending
+        ),
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The routing activation was already executed or cancelled".to_string(),
+            })
+        },
+    )?;
 
-            match routing_algo_ref.algorithm_id {
-                Some(algorithm_id) => {
-                    let routing_algorithm: routing_types::RoutingAlgorithmRef =
-                        routing_types::RoutingAlgorithmRef {
-                            algorithm_id: None,
-                            timestamp,
-                            config_algo_id: routing_algo_ref.config_algo_id.clone(),
-                            surcharge_config_algo_id: routing_algo_ref.surcharge_config_algo_id,
-                        };
+    let scheduled_activation = get_routing_activation_schedule_response(&process, tracking_data)?;
 
-                    let record = db
-                        .find_routing_algorithm_by_profile_id_algorithm_id(
-                            &profile_id,
-                            &algorithm_id,
-                        )
-                        .await
-                        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
-                    let response = record.foreign_into();
-                    helpers::update_profile_active_algorithm_ref(
-                        db,
-                        key_manager_state,
-                        merchant_context.get_merchant_key_store(),
-                        business_profile,
-                        routing_algorithm,
-                        &transaction_type,
-                    )
-                    .await?;
+    db.as_scheduler()
+        .finish_process_with_business_status(
+            process,
+            diesel_models::process_tracker::business_status::REVOKED,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to cancel the routing activation task")?;
 
-                    metrics::ROUTING_UNLINK_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
-                    Ok(service_api::ApplicationResponse::Json(response))
-                }
-                None => Err(errors::ApiErrorResponse::PreconditionFailed {
-                    message: "Algorithm is already inactive".to_string(),
-                })?,
-            }
-        }
-        None => Err(errors::ApiErrorResponse::InvalidRequestData {
-            message: "The business_profile is not present".to_string(),
-        }
-        .into()),
-    }
+    metrics::ROUTING_CANCEL_SCHEDULED_ACTIVATION_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::Json(scheduled_activation))
 }
 
 #[cfg(feature = "v2")]
@@ -1661,6 +3214,7 @@ pub async fn success_based_routing_update_configs(
         modified_at: timestamp,
         algorithm_for: dynamic_routing_algo_to_update.algorithm_for,
         decision_engine_routing_id: None,
+        parent_algorithm_id: None,
     };
     let record = db
         .insert_routing_algorithm(algo)
@@ -1763,6 +3317,7 @@ pub async fn elimination_routing_update_configs(
         modified_at: timestamp,
         algorithm_for: dynamic_routing_algo_to_update.algorithm_for,
         decision_engine_routing_id: None,
+        parent_algorithm_id: None,
     };
 
     let record = db
@@ -1911,6 +3466,7 @@ pub async fn contract_based_dynamic_routing_setup(
         modified_at: timestamp,
         algorithm_for: common_enums::TransactionType::Payment,
         decision_engine_routing_id: None,
+        parent_algorithm_id: None,
     };
 
     // 1. if dynamic_routing_algo_ref already present, insert contract based algo and disable success based
@@ -2102,6 +3658,7 @@ pub async fn contract_based_routing_update_configs(
         modified_at: timestamp,
         algorithm_for: dynamic_routing_algo_to_update.algorithm_for,
         decision_engine_routing_id: None,
+        parent_algorithm_id: None,
     };
     let record = db
         .insert_routing_algorithm(algo)
@@ -2291,3 +3848,88 @@ impl RoutableConnectors {
         Ok(connector_data)
     }
 }
+
+#[cfg(all(test, feature = "v1", feature = "olap"))]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use common_enums::Currency;
+    use common_utils::types::MinorUnit;
+
+    use super::*;
+
+    #[test]
+    fn test_connector_simulation_accumulator() {
+        let mut accumulator = ConnectorSimulationAccumulator::default();
+        assert_eq!(accumulator.success_rate(), None);
+
+        accumulator.record(Currency::USD, MinorUnit::new(1000), Some(true));
+        accumulator.record(Currency::USD, MinorUnit::new(500), Some(false));
+        accumulator.record(Currency::EUR, MinorUnit::new(200), None);
+        accumulator.record(Currency::USD, MinorUnit::new(300), Some(true));
+
+        assert_eq!(accumulator.payment_count, 4);
+        assert_eq!(accumulator.completed_payments, 3);
+        assert_eq!(accumulator.successful_payments, 2);
+        assert_eq!(
+            accumulator.volume.get(&Currency::USD),
+            Some(&MinorUnit::new(1800))
+        );
+        assert_eq!(
+            accumulator.volume.get(&Currency::EUR),
+            Some(&MinorUnit::new(200))
+        );
+
+        let success_rate = accumulator.success_rate().unwrap();
+        assert!((success_rate - 200.0 / 3.0).abs() < f64::EPSILON);
+
+        let success_rates = HashMap::from([("stripe".to_string(), success_rate)]);
+        let stats = ConnectorSimulationAccumulator::into_stats(
+            HashMap::from([
+                ("stripe".to_string(), accumulator),
+                (
+                    "adyen".to_string(),
+                    ConnectorSimulationAccumulator::default(),
+                ),
+            ]),
+            4,
+            &success_rates,
+        );
+
+        let stripe_stats = stats.first().unwrap();
+        assert_eq!(stripe_stats.connector, "stripe");
+        assert_eq!(stripe_stats.success_rate, Some(success_rate));
+        assert!((stripe_stats.payment_share - 100.0).abs() < f64::EPSILON);
+        assert_eq!(stats.get(1).unwrap().success_rate, None);
+    }
+
+    #[test]
+    fn test_get_weighted_success_rate() {
+        let mut stripe = ConnectorSimulationAccumulator::default();
+        let mut adyen = ConnectorSimulationAccumulator::default();
+        let mut checkout = ConnectorSimulationAccumulator::default();
+        for _ in 0..3 {
+            stripe.record(Currency::USD, MinorUnit::new(100), None);
+        }
+        adyen.record(Currency::USD, MinorUnit::new(100), None);
+        checkout.record(Currency::USD, MinorUnit::new(100), None);
+
+        let accumulators = HashMap::from([
+            ("stripe".to_string(), stripe),
+            ("adyen".to_string(), adyen),
+            ("checkout".to_string(), checkout),
+        ]);
+
+        // Connectors without an observed success rate are not weighed
+        let success_rates =
+            HashMap::from([("stripe".to_string(), 80.0), ("adyen".to_string(), 40.0)]);
+        let weighted_success_rate =
+            get_weighted_success_rate(&accumulators, &success_rates).unwrap();
+        assert!((weighted_success_rate - 70.0).abs() < f64::EPSILON);
+
+        assert_eq!(
+            get_weighted_success_rate(&accumulators, &HashMap::new()),
+            None
+        );
+    }
+}
@@ -11,7 +11,7 @@ use std::sync::Arc;
 #[cfg(feature = "v1")]
 use api_models::open_router;
 use api_models::routing as routing_types;
-#[cfg(all(feature = "dynamic_routing", feature = "v1"))]
+#[cfg(feature = "v1")]
 use common_utils::ext_traits::ValueExt;
 use common_utils::{ext_traits::Encode, id_type, types::keymanager::KeyManagerState};
 use diesel_models::configs;
@@ -227,6 +227,66 @@ pub async fn update_merchant_active_algorithm_ref(
     Ok(())
 }
 
+/// Retrieves the routing algorithm reference of the profile for the given transaction type
+#[cfg(feature = "v1")]
+pub fn get_profile_routing_algorithm_ref(
+    business_profile: &domain::Profile,
+    transaction_type: &storage::enums::TransactionType,
+) -> RouterResult<routing_types::RoutingAlgorithmRef> {
+    match transaction_type {
+        storage::enums::TransactionType::Payment => business_profile.routing_algorithm.clone(),
+        #[cfg(feature = "payouts")]
+        storage::enums::TransactionType::Payout => {
+            business_profile.payout_routing_algorithm.clone()
+        }
+        storage::enums::TransactionType::ThreeDsAuthentication => {
+            business_profile.three_ds_decision_rule_algorithm.clone()
+        }
+    }
+    .map(|val| val.parse_value("RoutingAlgorithmRef"))
+    .transpose()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("unable to deserialize routing algorithm ref from business profile")
+    .map(Option::unwrap_or_default)
+}
+
+/// Statically analyses the algorithm for likely misconfigurations. The analysis is best effort,
+/// failures are logged and only result in no warnings being returned.
+#[cfg(feature = "v1")]
+pub async fn get_routing_algorithm_warnings(
+    state: &SessionState,
+    merchant_key_store: &domain::MerchantKeyStore,
+    profile_id: &id_type::ProfileId,
+    algorithm: &routing_types::StaticRoutingAlgorithm,
+    transaction_type: &storage::enums::TransactionType,
+) -> Option<Vec<routing_types::RoutingAnalysisWarning>> {
+    if !algorithm.should_validate_connectors_in_routing_config() {
+        return None;
+    }
+
+    let mca_graph = crate::core::payments::routing::get_merchant_cgraph(
+        state,
+        merchant_key_store,
+        profile_id,
+        transaction_type,
+    )
+    .await
+    .map_err(|error| logger::error!(?error, "Failed to fetch the knowledge graph of the profile"))
+    .ok();
+
+    let dir_program =
+        euclid::frontend::ast::lowering::lower_program(algorithm.to_connector_selection_program())
+            .map_err(|error| logger::error!(?error, "Failed to lower the routing algorithm"))
+            .ok()?;
+
+    Some(
+        euclid::dssa::analyzer::get_program_warnings(&dir_program, mca_graph.as_deref())
+            .into_iter()
+            .map(routing_types::RoutingAnalysisWarning::from)
+            .collect(),
+    )
+}
+
 #[cfg(feature = "v1")]
 pub async fn update_profile_active_algorithm_ref(
     db: &dyn StorageInterface,
@@ -280,10 +340,22 @@ pub async fn update_profile_active_algorithm_ref(
             .into(),
         );
 
-        cache::redact_from_redis_and_publish(db.get_cache_store().as_ref(), [routing_cache_key])
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed to invalidate routing cache")?;
+        let shadow_routing_cache_key = cache::CacheKind::Routing(
+            format!(
+                "routing_config_shadow_{}_{}",
+                merchant_id.get_string_repr(),
+                profile_id.get_string_repr(),
+            )
+            .into(),
+        );
+
+        cache::redact_from_redis_and_publish(
+            db.get_cache_store().as_ref(),
+            [routing_cache_key, shadow_routing_cache_key],
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to invalidate routing cache")?;
     }
     Ok(())
 }
@@ -422,7 +494,8 @@ impl RoutingAlgorithmHelpers<'_> {
                 self.connector_choice(choice)?;
             }
 
-            routing_types::StaticRoutingAlgorithm::Priority(list) => {
+            routing_types::StaticRoutingAlgorithm::Priority(list)
+            | routing_types::StaticRoutingAlgorithm::LeastCost(list) => {
                 for choice in list {
                     self.connector_choice(choice)?;
                 }
@@ -536,7 +609,8 @@ pub async fn validate_connectors_in_routing_config(
             connector_choice(choice)?;
         }
 
-        routing_types::StaticRoutingAlgorithm::Priority(list) => {
+        routing_types::StaticRoutingAlgorithm::Priority(list)
+        | routing_types::StaticRoutingAlgorithm::LeastCost(list) => {
             for choice in list {
                 connector_choice(choice)?;
             }
@@ -2059,6 +2133,7 @@ pub async fn default_specific_dynamic_routing_setup(
                 modified_at: timestamp,
                 algorithm_for: common_enums::TransactionType::Payment,
                 decision_engine_routing_id: None,
+                parent_algorithm_id: None,
             }
         }
         routing_types::DynamicRoutingType::EliminationRouting => {
@@ -2079,6 +2154,7 @@ pub async fn default_specific_dynamic_routing_setup(
                 modified_at: timestamp,
                 algorithm_for: common_enums::TransactionType::Payment,
                 decision_engine_routing_id: None,
+                parent_algorithm_id: None,
             }
         }
 
@@ -34,6 +34,8 @@ impl ForeignFrom<RoutingProfileMetadata> for RoutingDictionaryRecord {
             modified_at: value.modified_at.assume_utc().unix_timestamp(),
             algorithm_for: Some(value.algorithm_for),
             decision_engine_routing_id: None,
+            parent_algorithm_id: value.parent_algorithm_id,
+            warnings: None,
         }
     }
 }
@@ -51,6 +53,8 @@ impl ForeignFrom<RoutingAlgorithm> for RoutingDictionaryRecord {
             modified_at: value.modified_at.assume_utc().unix_timestamp(),
             algorithm_for: Some(value.algorithm_for),
             decision_engine_routing_id: value.decision_engine_routing_id,
+            parent_algorithm_id: value.parent_algorithm_id,
+            warnings: None,
         }
     }
 }
@@ -92,6 +96,7 @@ impl ForeignFrom<storage_enums::RoutingAlgorithmKind> for RoutingAlgorithmKind {
             storage_enums::RoutingAlgorithmKind::Advanced => Self::Advanced,
             storage_enums::RoutingAlgorithmKind::Dynamic => Self::Dynamic,
             storage_enums::RoutingAlgorithmKind::ThreeDsDecisionRule => Self::ThreeDsDecisionRule,
+            storage_enums::RoutingAlgorithmKind::LeastCost => Self::LeastCost,
         }
     }
 }
@@ -105,6 +110,7 @@ impl ForeignFrom<RoutingAlgorithmKind> for storage_enums::RoutingAlgorithmKind {
             RoutingAlgorithmKind::Advanced => Self::Advanced,
             RoutingAlgorithmKind::Dynamic => Self::Dynamic,
             RoutingAlgorithmKind::ThreeDsDecisionRule => Self::ThreeDsDecisionRule,
+            RoutingAlgorithmKind::LeastCost => Self::LeastCost,
         }
     }
 }
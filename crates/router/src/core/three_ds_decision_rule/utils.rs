@@ -49,6 +49,7 @@ impl ForeignFrom<api_threedsecure::PaymentData> for dsl_inputs::PaymentInput {
             billing_country: None,
             business_label: None,
             setup_future_usage: None,
+            installment_interval: None,
             card_bin: None,
         }
     }
@@ -110,6 +111,7 @@ impl ForeignFrom<api_threedsecure::ThreeDsDecisionRuleExecuteRequest> for dsl_in
             acquirer_data: request.acquirer.map(ForeignFrom::foreign_from),
             customer_device_data: request.customer_device.map(ForeignFrom::foreign_from),
             issuer_data: request.issuer.map(ForeignFrom::foreign_from),
+            temporal_data: None,
         }
     }
 }
@@ -108,6 +108,9 @@ pub async fn get_parent_group_info(
     )
     .unwrap_or_default()
     .into_iter()
+    // Payment method exports are restricted to the predefined admin roles, so they cannot be
+    // added to custom roles
+    .filter(|(parent_group, _)| *parent_group != ParentGroup::PaymentMethodExport)
     .map(|(parent_group, description)| role_api::ParentGroupInfo {
         name: parent_group.clone(),
         description,
@@ -28,7 +28,7 @@ use crate::{
     core::{
         api_locking,
         errors::{self, ConnectorErrorExt, CustomResult, RouterResponse, StorageErrorExt},
-        metrics,
+        ledger, metrics,
         payments::{self, tokenization},
         refunds, relay, utils as core_utils,
         webhooks::utils::construct_webhook_router_data,
@@ -1055,6 +1055,7 @@ async fn refunds_incoming_webhook_flow(
         .await
         .attach_printable_lazy(|| format!("Failed while updating refund: refund_id: {refund_id}"))?
     };
+    ledger::record_refund_entry(&state, &updated_refund).await;
     let event_type: Option<enums::EventType> = updated_refund.refund_status.foreign_into();
 
     // If event is NOT an UnsupportedEvent, trigger Outgoing Webhook
@@ -1662,6 +1663,7 @@ async fn disputes_incoming_webhook_flow(
             connector.id(),
         )
         .await?;
+        ledger::record_dispute_entries(&state, &dispute_object).await;
         let disputes_response = Box::new(dispute_object.clone().foreign_into());
         let event_type: enums::EventType = dispute_object.dispute_status.foreign_into();
 
@@ -1022,6 +1022,11 @@ impl ForeignFrom<&api::OutgoingWebhookContent> for storage::EventMetadata {
                 payment_method_id: mandate_response.payment_method_id.clone(),
                 mandate_id: mandate_response.mandate_id.clone(),
             },
+            webhooks::OutgoingWebhookContent::PaymentMethodDetails(payment_method_response) => {
+                Self::PaymentMethod {
+                    payment_method_id: payment_method_response.payment_method_id.clone(),
+                }
+            }
             #[cfg(feature = "payouts")]
             webhooks::OutgoingWebhookContent::PayoutDetails(payout_response) => Self::Payout {
                 payout_id: payout_response.payout_id.clone(),
@@ -1070,5 +1075,11 @@ fn get_outgoing_webhook_event_content_from_event_metadata(
             mandate_id,
             content: serde_json::Value::Null,
         },
+        diesel_models::EventMetadata::PaymentMethod { payment_method_id } => {
+            OutgoingWebhookEventContent::PaymentMethod {
+                payment_method_id,
+                content: serde_json::Value::Null,
+            }
+        }
     })
 }
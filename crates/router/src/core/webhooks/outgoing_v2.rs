@@ -617,6 +617,11 @@ impl ForeignFrom<&api::OutgoingWebhookContent> for storage::EventMetadata {
                 payment_method_id: mandate_response.payment_method_id.clone(),
                 mandate_id: mandate_response.mandate_id.clone(),
             },
+            webhooks::OutgoingWebhookContent::PaymentMethodDetails(payment_method_response) => {
+                Self::PaymentMethod {
+                    payment_method_id: payment_method_response.id.get_string_repr().to_owned(),
+                }
+            }
             #[cfg(feature = "payouts")]
             webhooks::OutgoingWebhookContent::PayoutDetails(payout_response) => Self::Payout {
                 payout_id: payout_response.payout_id.clone(),
@@ -662,6 +667,12 @@ impl ForeignFrom<storage::EventMetadata> for outgoing_webhook_logs::OutgoingWebh
                 mandate_id,
                 content: serde_json::Value::Null,
             },
+            diesel_models::EventMetadata::PaymentMethod { payment_method_id } => {
+                Self::PaymentMethod {
+                    payment_method_id,
+                    content: serde_json::Value::Null,
+                }
+            }
         }
     }
 }
@@ -20,6 +20,7 @@ pub mod fraud_check;
 pub mod generic_link;
 pub mod gsm;
 pub mod health_check;
+pub mod idempotency_key;
 pub mod kafka_store;
 pub mod locker_mock_up;
 pub mod mandate;
@@ -27,6 +28,7 @@ pub mod merchant_account;
 pub mod merchant_connector_account;
 pub mod merchant_key_store;
 pub mod organization;
+pub mod payment_ledger;
 pub mod payment_link;
 pub mod payment_method_session;
 pub mod refund;
@@ -106,6 +108,7 @@ pub trait StorageInterface:
     + events::EventInterface
     + file::FileMetadataInterface
     + FraudCheckInterface
+    + idempotency_key::IdempotencyKeyInterface
     + locker_mock_up::LockerMockUpInterface
     + mandate::MandateInterface
     + merchant_account::MerchantAccountInterface
@@ -125,6 +128,7 @@ pub trait StorageInterface:
     + CardsInfoInterface<Error = StorageError>
     + merchant_key_store::MerchantKeyStoreInterface
     + MasterKeyInterface
+    + payment_ledger::PaymentLedgerInterface
     + payment_link::PaymentLinkInterface
     + RedisConnInterface
     + RequestIdStore
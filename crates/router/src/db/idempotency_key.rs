@@ -0,0 +1,180 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait IdempotencyKeyInterface {
+    async fn insert_idempotency_key(
+        &self,
+        idempotency_key: storage::IdempotencyKeyNew,
+    ) -> CustomResult<storage::IdempotencyKey, errors::StorageError>;
+
+    async fn find_idempotency_key_by_merchant_id_idempotency_key(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        idempotency_key: &str,
+    ) -> CustomResult<storage::IdempotencyKey, errors::StorageError>;
+
+    async fn delete_idempotency_key_by_merchant_id_idempotency_key(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        idempotency_key: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl IdempotencyKeyInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_idempotency_key(
+        &self,
+        idempotency_key: storage::IdempotencyKeyNew,
+    ) -> CustomResult<storage::IdempotencyKey, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        idempotency_key
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_idempotency_key_by_merchant_id_idempotency_key(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        idempotency_key: &str,
+    ) -> CustomResult<storage::IdempotencyKey, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::IdempotencyKey::find_by_merchant_id_idempotency_key(
+            &conn,
+            merchant_id,
+            idempotency_key,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_idempotency_key_by_merchant_id_idempotency_key(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        idempotency_key: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::IdempotencyKey::delete_by_merchant_id_idempotency_key(
+            &conn,
+            merchant_id,
+            idempotency_key,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl IdempotencyKeyInterface for MockDb {
+    async fn insert_idempotency_key(
+        &self,
+        idempotency_key: storage::IdempotencyKeyNew,
+    ) -> CustomResult<storage::IdempotencyKey, errors::StorageError> {
+        let mut idempotency_keys = self.idempotency_keys.lock().await;
+
+        if idempotency_keys.iter().any(|stored_key| {
+            stored_key.merchant_id == idempotency_key.merchant_id
+                && stored_key.idempotency_key == idempotency_key.idempotency_key
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "idempotency_key",
+                key: Some(idempotency_key.idempotency_key),
+            })?
+        }
+
+        let stored_key = storage::IdempotencyKey {
+            merchant_id: idempotency_key.merchant_id,
+            idempotency_key: idempotency_key.idempotency_key,
+            fingerprint: idempotency_key.fingerprint,
+            response: idempotency_key.response,
+            created_at: idempotency_key.created_at,
+            expires_at: idempotency_key.expires_at,
+        };
+        idempotency_keys.push(stored_key.clone());
+
+        Ok(stored_key)
+    }
+
+    async fn find_idempotency_key_by_merchant_id_idempotency_key(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        idempotency_key: &str,
+    ) -> CustomResult<storage::IdempotencyKey, errors::StorageError> {
+        self.idempotency_keys
+            .lock()
+            .await
+            .iter()
+            .find(|stored_key| {
+                &stored_key.merchant_id == merchant_id
+                    && stored_key.idempotency_key == idempotency_key
+            })
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "No idempotency key available for idempotency_key = {idempotency_key}"
+                ))
+                .into()
+            })
+    }
+
+    async fn delete_idempotency_key_by_merchant_id_idempotency_key(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        idempotency_key: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut idempotency_keys = self.idempotency_keys.lock().await;
+        let keys_count = idempotency_keys.len();
+
+        idempotency_keys.retain(|stored_key| {
+            &stored_key.merchant_id != merchant_id || stored_key.idempotency_key != idempotency_key
+        });
+
+        Ok(idempotency_keys.len() != keys_count)
+    }
+}
+
+#[async_trait::async_trait]
+impl IdempotencyKeyInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_idempotency_key(
+        &self,
+        idempotency_key: storage::IdempotencyKeyNew,
+    ) -> CustomResult<storage::IdempotencyKey, errors::StorageError> {
+        self.diesel_store
+            .insert_idempotency_key(idempotency_key)
+            .await
+    }
+
+    async fn find_idempotency_key_by_merchant_id_idempotency_key(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        idempotency_key: &str,
+    ) -> CustomResult<storage::IdempotencyKey, errors::StorageError> {
+        self.diesel_store
+            .find_idempotency_key_by_merchant_id_idempotency_key(merchant_id, idempotency_key)
+            .await
+    }
+
+    async fn delete_idempotency_key_by_merchant_id_idempotency_key(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        idempotency_key: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_idempotency_key_by_merchant_id_idempotency_key(merchant_id, idempotency_key)
+            .await
+    }
+}
@@ -916,6 +916,31 @@ impl LockerMockUpInterface for KafkaStore {
     ) -> CustomResult<storage::LockerMockUp, errors::StorageError> {
         self.diesel_store.delete_locker_mock_up(card_id).await
     }
+
+    async fn delete_locker_mock_up_by_merchant_id_card_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        card_id: &str,
+    ) -> CustomResult<storage::LockerMockUp, errors::StorageError> {
+        self.diesel_store
+            .delete_locker_mock_up_by_merchant_id_card_id(merchant_id, card_id)
+            .await
+    }
+
+    async fn update_locker_mock_up_by_merchant_id_card_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        card_id: &str,
+        locker_mock_up_update: storage::LockerMockUpUpdate,
+    ) -> CustomResult<storage::LockerMockUp, errors::StorageError> {
+        self.diesel_store
+            .update_locker_mock_up_by_merchant_id_card_id(
+                merchant_id,
+                card_id,
+                locker_mock_up_update,
+            )
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -2207,6 +2232,24 @@ impl PaymentMethodInterface for KafkaStore {
             .await
     }
 
+    #[cfg(feature = "v1")]
+    async fn find_payment_method_list_by_merchant_id_constraints(
+        &self,
+        state: &KeyManagerState,
+        key_store: &domain::MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        constraints: &hyperswitch_domain_models::payment_methods::PaymentMethodListConstraints,
+    ) -> CustomResult<Vec<domain::PaymentMethod>, errors::StorageError> {
+        self.diesel_store
+            .find_payment_method_list_by_merchant_id_constraints(
+                state,
+                key_store,
+                merchant_id,
+                constraints,
+            )
+            .await
+    }
+
     #[cfg(feature = "v1")]
     async fn get_payment_method_count_by_customer_id_merchant_id_status(
         &self,
@@ -2318,6 +2361,66 @@ impl PaymentMethodInterface for KafkaStore {
             .find_payment_method_by_fingerprint_id(state, key_store, fingerprint_id)
             .await
     }
+
+    #[cfg(feature = "v2")]
+    async fn find_payment_method_list_by_merchant_id_fingerprint_id(
+        &self,
+        state: &KeyManagerState,
+        key_store: &domain::MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        fingerprint_id: &str,
+    ) -> CustomResult<Vec<domain::PaymentMethod>, errors::StorageError> {
+        self.diesel_store
+            .find_payment_method_list_by_merchant_id_fingerprint_id(
+                state,
+                key_store,
+                merchant_id,
+                fingerprint_id,
+            )
+            .await
+    }
+
+    #[cfg(feature = "v2")]
+    async fn find_payment_method_list_by_merchant_id_after_id(
+        &self,
+        state: &KeyManagerState,
+        key_store: &domain::MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        after: Option<&id_type::GlobalPaymentMethodId>,
+        limit: i64,
+    ) -> CustomResult<Vec<domain::PaymentMethod>, errors::StorageError> {
+        self.diesel_store
+            .find_payment_method_list_by_merchant_id_after_id(
+                state,
+                key_store,
+                merchant_id,
+                after,
+                limit,
+            )
+            .await
+    }
+
+    #[cfg(feature = "v2")]
+    async fn merge_payment_methods(
+        &self,
+        state: &KeyManagerState,
+        key_store: &domain::MerchantKeyStore,
+        payment_method: domain::PaymentMethod,
+        payment_method_update: storage::PaymentMethodUpdate,
+        duplicates: Vec<domain::PaymentMethod>,
+        mandate_ids: Vec<String>,
+    ) -> CustomResult<domain::PaymentMethod, errors::StorageError> {
+        self.diesel_store
+            .merge_payment_methods(
+                state,
+                key_store,
+                payment_method,
+                payment_method_update,
+                duplicates,
+                mandate_ids,
+            )
+            .await
+    }
 }
 
 #[cfg(not(feature = "payouts"))]
@@ -2609,6 +2712,17 @@ impl ProcessTrackerInterface for KafkaStore {
         self.diesel_store.insert_process(new).await
     }
 
+    async fn find_processes_by_runner_id_prefix_status(
+        &self,
+        runner: storage::ProcessTrackerRunner,
+        id_prefix: &str,
+        statuses: Vec<enums::ProcessTrackerStatus>,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        self.diesel_store
+            .find_processes_by_runner_id_prefix_status(runner, id_prefix, statuses)
+            .await
+    }
+
     async fn reset_process(
         &self,
         this: storage::ProcessTracker,
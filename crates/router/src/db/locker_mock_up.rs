@@ -24,6 +24,19 @@ pub trait LockerMockUpInterface {
         &self,
         card_id: &str,
     ) -> CustomResult<storage::LockerMockUp, errors::StorageError>;
+
+    async fn delete_locker_mock_up_by_merchant_id_card_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        card_id: &str,
+    ) -> CustomResult<storage::LockerMockUp, errors::StorageError>;
+
+    async fn update_locker_mock_up_by_merchant_id_card_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        card_id: &str,
+        locker_mock_up_update: storage::LockerMockUpUpdate,
+    ) -> CustomResult<storage::LockerMockUp, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -60,6 +73,36 @@ impl LockerMockUpInterface for Store {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    #[instrument(skip_all)]
+    async fn delete_locker_mock_up_by_merchant_id_card_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        card_id: &str,
+    ) -> CustomResult<storage::LockerMockUp, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::LockerMockUp::delete_by_merchant_id_card_id(&conn, merchant_id, card_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_locker_mock_up_by_merchant_id_card_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        card_id: &str,
+        locker_mock_up_update: storage::LockerMockUpUpdate,
+    ) -> CustomResult<storage::LockerMockUp, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::LockerMockUp::update_by_merchant_id_card_id(
+            &conn,
+            merchant_id,
+            card_id,
+            locker_mock_up_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
@@ -123,6 +166,50 @@ impl LockerMockUpInterface for MockDb {
 
         Ok(locked_lockers.remove(position))
     }
+
+    async fn delete_locker_mock_up_by_merchant_id_card_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        card_id: &str,
+    ) -> CustomResult<storage::LockerMockUp, errors::StorageError> {
+        let mut locked_lockers = self.lockers.lock().await;
+
+        let position = locked_lockers
+            .iter()
+            .position(|l| &l.merchant_id == merchant_id && l.card_id == card_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No locker entry found for card_id = {card_id}"
+            )))?;
+
+        Ok(locked_lockers.remove(position))
+    }
+
+    async fn update_locker_mock_up_by_merchant_id_card_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        card_id: &str,
+        locker_mock_up_update: storage::LockerMockUpUpdate,
+    ) -> CustomResult<storage::LockerMockUp, errors::StorageError> {
+        let mut locked_lockers = self.lockers.lock().await;
+
+        let locker = locked_lockers
+            .iter_mut()
+            .find(|l| &l.merchant_id == merchant_id && l.card_id == card_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No locker entry found for card_id = {card_id}"
+            )))?;
+
+        locker.card_fingerprint = locker_mock_up_update.card_fingerprint;
+        locker.card_global_fingerprint = locker_mock_up_update.card_global_fingerprint;
+        if let Some(customer_id) = locker_mock_up_update.customer_id {
+            locker.customer_id = Some(customer_id);
+        }
+        if let Some(enc_card_data) = locker_mock_up_update.enc_card_data {
+            locker.enc_card_data = Some(enc_card_data);
+        }
+
+        Ok(locker.clone())
+    }
 }
 
 #[cfg(test)]
@@ -253,5 +340,50 @@ mod tests {
 
             assert!(!exist)
         }
+
+        #[tokio::test]
+        async fn delete_locker_mock_up_by_merchant_id_card_id() {
+            #[allow(clippy::expect_used)]
+            let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+                .await
+                .expect("Failed to create Mock store");
+
+            let merchant_id =
+                id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1")).unwrap();
+            let other_merchant_id =
+                id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_2")).unwrap();
+
+            let created_locker = mockdb
+                .insert_locker_mock_up(create_locker_mock_up_new(LockerMockUpIds {
+                    card_id: "card_1".into(),
+                    external_id: "external_1".into(),
+                    merchant_id: merchant_id.clone(),
+                    customer_id: generate_customer_id_of_default_length(),
+                }))
+                .await
+                .unwrap();
+
+            let other_merchant_deletion = mockdb
+                .delete_locker_mock_up_by_merchant_id_card_id(&other_merchant_id, "card_1")
+                .await;
+
+            assert!(other_merchant_deletion.is_err());
+
+            let deleted_locker = mockdb
+                .delete_locker_mock_up_by_merchant_id_card_id(&merchant_id, "card_1")
+                .await
+                .unwrap();
+
+            assert_eq!(created_locker, deleted_locker);
+
+            let exist = mockdb
+                .lockers
+                .lock()
+                .await
+                .iter()
+                .any(|l| l.card_id == "card_1");
+
+            assert!(!exist)
+        }
     }
 }
@@ -778,28 +778,32 @@ async fn publish_and_redact_merchant_account_cache(
         .as_ref()
         .map(|publishable_key| CacheKind::Accounts(publishable_key.into()));
 
+    // The payment and the payout graphs of the default profile
     #[cfg(feature = "v1")]
-    let cgraph_key = merchant_account.default_profile.as_ref().map(|profile_id| {
-        CacheKind::CGraph(
-            format!(
-                "cgraph_{}_{}",
-                merchant_account.get_id().get_string_repr(),
-                profile_id.get_string_repr(),
+    let cgraph_keys = merchant_account.default_profile.as_ref().map(|profile_id| {
+        ["cgraph", "cgraph_po"].map(|prefix| {
+            CacheKind::CGraph(
+                format!(
+                    "{}_{}_{}",
+                    prefix,
+                    merchant_account.get_id().get_string_repr(),
+                    profile_id.get_string_repr(),
+                )
+                .into(),
             )
-            .into(),
-        )
+        })
     });
 
     // TODO: we will not have default profile in v2
     #[cfg(feature = "v2")]
-    let cgraph_key = None;
+    let cgraph_keys: Option<[CacheKind<'_>; 2]> = None;
 
     let mut cache_keys = vec![CacheKind::Accounts(
         merchant_account.get_id().get_string_repr().into(),
     )];
 
     cache_keys.extend(publishable_key.into_iter());
-    cache_keys.extend(cgraph_key.into_iter());
+    cache_keys.extend(cgraph_keys.into_iter().flatten());
 
     cache::redact_from_redis_and_publish(store.get_cache_store().as_ref(), cache_keys).await?;
     Ok(())
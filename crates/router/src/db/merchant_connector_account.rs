@@ -483,22 +483,49 @@ impl MerchantConnectorAccountInterface for Store {
         key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<domain::MerchantConnectorAccount, errors::StorageError> {
         let conn = connection::pg_accounts_connection_write(self).await?;
-        t.construct_new()
-            .await
-            .change_context(errors::StorageError::EncryptionError)?
-            .insert(&conn)
-            .await
-            .map_err(|error| report!(errors::StorageError::from(error)))
-            .async_and_then(|item| async {
-                item.convert(
-                    state,
-                    key_store.key.get_inner(),
-                    key_store.merchant_id.clone().into(),
-                )
+        let _merchant_id = t.merchant_id.clone();
+        let _profile_id = t.profile_id.clone();
+        let insert_call = || async {
+            t.construct_new()
                 .await
-                .change_context(errors::StorageError::DecryptionError)
-            })
+                .change_context(errors::StorageError::EncryptionError)?
+                .insert(&conn)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+                .async_and_then(|item| async {
+                    item.convert(
+                        state,
+                        key_store.key.get_inner(),
+                        key_store.merchant_id.clone().into(),
+                    )
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)
+                })
+                .await
+        };
+
+        #[cfg(feature = "accounts_cache")]
+        {
+            // The fee schedules of the profile include the new account
+            cache::publish_and_redact(
+                self,
+                cache::CacheKind::Routing(
+                    format!(
+                        "fee_schedules_{}_{}",
+                        _merchant_id.get_string_repr(),
+                        _profile_id.get_string_repr()
+                    )
+                    .into(),
+                ),
+                insert_call,
+            )
             .await
+        }
+
+        #[cfg(not(feature = "accounts_cache"))]
+        {
+            insert_call().await
+        }
     }
 
     async fn list_enabled_connector_accounts_by_profile_id(
@@ -676,6 +703,22 @@ impl MerchantConnectorAccountInterface for Store {
                             )
                             .into(),
                         ),
+                        cache::CacheKind::CGraph(
+                            format!(
+                                "cgraph_po_{}_{}",
+                                _merchant_id.get_string_repr(),
+                                _profile_id.get_string_repr()
+                            )
+                            .into(),
+                        ),
+                        cache::CacheKind::Routing(
+                            format!(
+                                "fee_schedules_{}_{}",
+                                _merchant_id.get_string_repr(),
+                                _profile_id.get_string_repr()
+                            )
+                            .into(),
+                        ),
                     ],
                     || update,
                 ))
@@ -773,6 +816,22 @@ impl MerchantConnectorAccountInterface for Store {
                         )
                         .into(),
                     ),
+                    cache::CacheKind::CGraph(
+                        format!(
+                            "cgraph_po_{}_{}",
+                            _merchant_id.get_string_repr(),
+                            _profile_id.get_string_repr()
+                        )
+                        .into(),
+                    ),
+                    cache::CacheKind::Routing(
+                        format!(
+                            "fee_schedules_{}_{}",
+                            _merchant_id.get_string_repr(),
+                            _profile_id.get_string_repr()
+                        )
+                        .into(),
+                    ),
                     cache::CacheKind::PmFiltersCGraph(
                         format!(
                             "pm_filters_cgraph_{}_{}",
@@ -850,6 +909,22 @@ impl MerchantConnectorAccountInterface for Store {
                         )
                         .into(),
                     ),
+                    cache::CacheKind::CGraph(
+                        format!(
+                            "cgraph_po_{}_{}",
+                            _merchant_id.get_string_repr(),
+                            _profile_id.get_string_repr()
+                        )
+                        .into(),
+                    ),
+                    cache::CacheKind::Routing(
+                        format!(
+                            "fee_schedules_{}_{}",
+                            _merchant_id.get_string_repr(),
+                            _profile_id.get_string_repr()
+                        )
+                        .into(),
+                    ),
                     cache::CacheKind::PmFiltersCGraph(
                         format!(
                             "pm_filters_cgraph_{}_{}",
@@ -926,6 +1001,22 @@ impl MerchantConnectorAccountInterface for Store {
                         )
                         .into(),
                     ),
+                    cache::CacheKind::CGraph(
+                        format!(
+                            "cgraph_po_{}_{}",
+                            mca.merchant_id.get_string_repr(),
+                            _profile_id.get_string_repr()
+                        )
+                        .into(),
+                    ),
+                    cache::CacheKind::Routing(
+                        format!(
+                            "fee_schedules_{}_{}",
+                            mca.merchant_id.get_string_repr(),
+                            _profile_id.get_string_repr()
+                        )
+                        .into(),
+                    ),
                     cache::CacheKind::PmFiltersCGraph(
                         format!(
                             "pm_filters_cgraph_{}_{}",
@@ -991,6 +1082,22 @@ impl MerchantConnectorAccountInterface for Store {
                         )
                         .into(),
                     ),
+                    cache::CacheKind::CGraph(
+                        format!(
+                            "cgraph_po_{}_{}",
+                            mca.merchant_id.get_string_repr(),
+                            _profile_id.get_string_repr()
+                        )
+                        .into(),
+                    ),
+                    cache::CacheKind::Routing(
+                        format!(
+                            "fee_schedules_{}_{}",
+                            mca.merchant_id.get_string_repr(),
+                            _profile_id.get_string_repr()
+                        )
+                        .into(),
+                    ),
                     cache::CacheKind::PmFiltersCGraph(
                         format!(
                             "pm_filters_cgraph_{}_{}",
@@ -1258,6 +1365,7 @@ impl MerchantConnectorAccountInterface for MockDb {
             connector_wallets_details: t.connector_wallets_details.map(Encryption::from),
             additional_merchant_data: t.additional_merchant_data.map(|data| data.into()),
             version: t.version,
+            fee_schedule: t.fee_schedule,
         };
         accounts.push(account.clone());
         account
@@ -1300,6 +1408,7 @@ impl MerchantConnectorAccountInterface for MockDb {
             additional_merchant_data: t.additional_merchant_data.map(|data| data.into()),
             version: t.version,
             feature_metadata: t.feature_metadata.map(From::from),
+            fee_schedule: t.fee_schedule,
         };
         accounts.push(account.clone());
         account
@@ -1679,6 +1788,7 @@ mod merchant_connector_account_cache_tests {
             ),
             additional_merchant_data: None,
             version: common_types::consts::API_VERSION,
+            fee_schedule: None,
         };
 
         db.insert_merchant_connector_account(key_manager_state, mca.clone(), &merchant_key)
@@ -1858,6 +1968,7 @@ mod merchant_connector_account_cache_tests {
             additional_merchant_data: None,
             version: common_types::consts::API_VERSION,
             feature_metadata: None,
+            fee_schedule: None,
         };
 
         db.insert_merchant_connector_account(key_manager_state, mca.clone(), &merchant_key)
@@ -0,0 +1,94 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PaymentLedgerInterface {
+    async fn insert_payment_ledger_entry(
+        &self,
+        payment_ledger_entry: storage::PaymentLedgerEntryNew,
+    ) -> CustomResult<storage::PaymentLedgerEntry, errors::StorageError>;
+
+    async fn find_payment_ledger_entries_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::PaymentLedgerEntry>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PaymentLedgerInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_payment_ledger_entry(
+        &self,
+        payment_ledger_entry: storage::PaymentLedgerEntryNew,
+    ) -> CustomResult<storage::PaymentLedgerEntry, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        payment_ledger_entry
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payment_ledger_entries_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::PaymentLedgerEntry>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentLedgerEntry::find_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentLedgerInterface for MockDb {
+    #[instrument(skip_all)]
+    async fn insert_payment_ledger_entry(
+        &self,
+        _payment_ledger_entry: storage::PaymentLedgerEntryNew,
+    ) -> CustomResult<storage::PaymentLedgerEntry, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payment_ledger_entries_by_merchant_id_payment_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::PaymentLedgerEntry>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentLedgerInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_payment_ledger_entry(
+        &self,
+        payment_ledger_entry: storage::PaymentLedgerEntryNew,
+    ) -> CustomResult<storage::PaymentLedgerEntry, errors::StorageError> {
+        self.diesel_store
+            .insert_payment_ledger_entry(payment_ledger_entry)
+            .await
+    }
+
+    async fn find_payment_ledger_entries_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::PaymentLedgerEntry>, errors::StorageError> {
+        self.diesel_store
+            .find_payment_ledger_entries_by_merchant_id_payment_id(merchant_id, payment_id)
+            .await
+    }
+}
@@ -1,5 +1,5 @@
-use api_models::payments::Amount;
-use common_utils::types::MinorUnit;
+use api_models::{enums::PaymentCancellationReason, payments::Amount};
+use common_utils::{id_type::MerchantId, types::MinorUnit};
 use diesel_models::fraud_check::FraudCheck;
 use events::{Event, EventInfo};
 use serde::Serialize;
@@ -23,6 +23,14 @@ pub enum AuditEventType {
     },
     PaymentCancelled {
         cancellation_reason: Option<String>,
+        cancellation_reason_code: Option<PaymentCancellationReason>,
+    },
+    PaymentCancelledPostCapture {
+        cancellation_reason: Option<String>,
+        cancellation_reason_code: Option<PaymentCancellationReason>,
+    },
+    PaymentAuthorizationReversed {
+        cancellation_reason: Option<String>,
     },
     PaymentCapture {
         capture_amount: Option<MinorUnit>,
@@ -39,6 +47,33 @@ pub enum AuditEventType {
         error_code: Option<String>,
         error_message: Option<String>,
     },
+    PaymentMethodExportRequested {
+        merchant_id: MerchantId,
+        export_id: String,
+    },
+    PaymentMethodExportCompleted {
+        merchant_id: MerchantId,
+        export_id: String,
+        payment_methods_count: usize,
+    },
+    PaymentMethodExportFailed {
+        merchant_id: MerchantId,
+        export_id: String,
+        error_message: String,
+    },
+    PaymentMethodExportDownloaded {
+        merchant_id: MerchantId,
+        export_id: String,
+        part_number: usize,
+    },
+    PaymentMethodExportRecipientKeyRegistered {
+        merchant_id: MerchantId,
+        key_id: String,
+    },
+    PaymentMethodExportRecipientKeyApproved {
+        merchant_id: MerchantId,
+        key_id: String,
+    },
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -77,12 +112,30 @@ impl Event for AuditEvent {
             AuditEventType::RefundSuccess => "refund_success",
             AuditEventType::RefundFail => "refund_fail",
             AuditEventType::PaymentCancelled { .. } => "payment_cancelled",
+            AuditEventType::PaymentCancelledPostCapture { .. } => "payment_cancelled_post_capture",
+            AuditEventType::PaymentAuthorizationReversed { .. } => "payment_authorization_reversed",
             AuditEventType::PaymentUpdate { .. } => "payment_update",
             AuditEventType::PaymentApprove => "payment_approve",
             AuditEventType::PaymentCreate => "payment_create",
             AuditEventType::PaymentStatus => "payment_status",
             AuditEventType::PaymentCompleteAuthorize => "payment_complete_authorize",
             AuditEventType::PaymentReject { .. } => "payment_rejected",
+            AuditEventType::PaymentMethodExportRequested { .. } => {
+                "payment_method_export_requested"
+            }
+            AuditEventType::PaymentMethodExportCompleted { .. } => {
+                "payment_method_export_completed"
+            }
+            AuditEventType::PaymentMethodExportFailed { .. } => "payment_method_export_failed",
+            AuditEventType::PaymentMethodExportDownloaded { .. } => {
+                "payment_method_export_downloaded"
+            }
+            AuditEventType::PaymentMethodExportRecipientKeyRegistered { .. } => {
+                "payment_method_export_recipient_key_registered"
+            }
+            AuditEventType::PaymentMethodExportRecipientKeyApproved { .. } => {
+                "payment_method_export_recipient_key_approved"
+            }
         };
         format!(
             "{event_type}-{}",
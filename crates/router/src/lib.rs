@@ -56,9 +56,11 @@ pub mod headers {
     pub const X_CC_API_KEY: &str = "X-CC-Api-Key";
     pub const API_TOKEN: &str = "Api-Token";
     pub const AUTHORIZATION: &str = "Authorization";
+    pub const CONNECTOR_HTTP_STATUS_CODE: &str = "connector_http_status_code";
     pub const CONTENT_TYPE: &str = "Content-Type";
     pub const DATE: &str = "Date";
     pub const IDEMPOTENCY_KEY: &str = "Idempotency-Key";
+    pub const IDEMPOTENT_REPLAYED: &str = "Idempotent-Replayed";
     pub const NONCE: &str = "nonce";
     pub const TIMESTAMP: &str = "Timestamp";
     pub const TOKEN: &str = "token";
@@ -92,6 +94,7 @@ pub mod headers {
     pub const X_CLIENT_SECRET: &str = "X-Client-Secret";
     pub const X_CUSTOMER_ID: &str = "X-Customer-Id";
     pub const X_CONNECTED_MERCHANT_ID: &str = "x-connected-merchant-id";
+    pub const X_NETWORK_TOKEN_SIGNATURE: &str = "X-Network-Token-Signature";
 }
 
 pub mod pii {
@@ -138,6 +141,13 @@ pub fn mk_app(
 
             server_app = server_app.service(routes::Profile::server(state.clone()));
         }
+        // This is a more specific route as compared to `Webhooks`
+        // so it is registered before `Webhooks`.
+        #[cfg(all(feature = "oltp", feature = "v1"))]
+        {
+            server_app = server_app.service(routes::NetworkTokenWebhooks::server(state.clone()));
+        }
+
         server_app = server_app
             .service(routes::Payments::server(state.clone()))
             .service(routes::Customers::server(state.clone()))
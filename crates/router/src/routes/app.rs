@@ -17,6 +17,7 @@ use external_services::{
     grpc_client::{GrpcClients, GrpcHeaders},
 };
 use hyperswitch_interfaces::{
+    card_account_updater::CardAccountUpdaterInterface,
     crm::CrmInterface,
     encryption_interface::EncryptionManagementInterface,
     secrets_interface::secret_state::{RawSecret, SecuredSecret},
@@ -128,6 +129,7 @@ pub struct SessionState {
     pub theme_storage_client: Arc<dyn FileStorageInterface>,
     pub locale: String,
     pub crm_client: Arc<dyn CrmInterface>,
+    pub card_account_updater_client: Arc<dyn CardAccountUpdaterInterface>,
     pub infra_components: Option<serde_json::Value>,
 }
 impl scheduler::SchedulerSessionState for SessionState {
@@ -241,6 +243,7 @@ pub struct AppState {
     pub grpc_client: Arc<GrpcClients>,
     pub theme_storage_client: Arc<dyn FileStorageInterface>,
     pub crm_client: Arc<dyn CrmInterface>,
+    pub card_account_updater_client: Arc<dyn CardAccountUpdaterInterface>,
     pub infra_components: Option<serde_json::Value>,
 }
 impl scheduler::SchedulerAppState for AppState {
@@ -404,6 +407,11 @@ impl AppState {
             let file_storage_client = conf.file_storage.get_file_storage_client().await;
             let theme_storage_client = conf.theme.storage.get_file_storage_client().await;
             let crm_client = conf.crm.get_crm_client().await;
+            let card_account_updater_client = conf
+                .card_account_updater
+                .provider
+                .get_card_account_updater_client()
+                .await;
 
             let grpc_client = conf.grpc_client.get_grpc_client_interface().await;
             let infra_component_values = Self::process_env_mappings(conf.infra_values.clone());
@@ -427,6 +435,7 @@ impl AppState {
                 grpc_client,
                 theme_storage_client,
                 crm_client,
+                card_account_updater_client,
                 infra_components: infra_component_values,
             }
         })
@@ -522,6 +531,7 @@ impl AppState {
             theme_storage_client: self.theme_storage_client.clone(),
             locale: locale.unwrap_or(common_utils::consts::DEFAULT_LOCALE.to_string()),
             crm_client: self.crm_client.clone(),
+            card_account_updater_client: self.card_account_updater_client.clone(),
             infra_components: self.infra_components.clone(),
         })
     }
@@ -754,6 +764,10 @@ impl Payments {
                     web::resource("/sync")
                         .route(web::post().to(payments::payments_retrieve_with_gateway_creds)),
                 )
+                .service(
+                    web::resource("/installment_plans")
+                        .route(web::post().to(payments::payments_installment_plans)),
+                )
                 .service(
                     web::resource("/{payment_id}")
                         .route(web::get().to(payments::payments_retrieve))
@@ -768,6 +782,14 @@ impl Payments {
                 .service(
                     web::resource("/{payment_id}/cancel").route(web::post().to(payments::payments_cancel)),
                 )
+                .service(
+                    web::resource("/{payment_id}/cancel_post_capture")
+                        .route(web::post().to(payments::payments_cancel_post_capture)),
+                )
+                .service(
+                    web::resource("/{payment_id}/reverse_authorization")
+                        .route(web::post().to(payments::payments_reverse_authorization)),
+                )
                 .service(
                     web::resource("/{payment_id}/capture").route(web::post().to(payments::payments_capture)),
                 )
@@ -820,6 +842,12 @@ impl Payments {
                 .service(
                     web::resource("/{payment_id}/extended_card_info").route(web::get().to(payments::retrieve_extended_card_info)),
                 )
+                .service(
+                    web::resource("/{payment_id}/ledger").route(web::get().to(payments::payments_ledger_retrieve)),
+                )
+                .service(
+                    web::resource("/{payment_id}/currency_conversion_quote").route(web::post().to(payments::payments_currency_conversion_quote)),
+                )
                 .service(
                 web::resource("{payment_id}/calculate_tax")
                     .route(web::post().to(payments::payments_dynamic_tax_calculation)),
@@ -910,6 +938,38 @@ impl Routing {
                     routing::routing_unlink_config(state, req, payload, None)
                 })),
             )
+            .service(
+                web::resource("/simulate").route(web::post().to(routing::routing_simulate_config)),
+            )
+            .service(web::resource("/dsl/parse").route(web::post().to(routing::routing_dsl_parse)))
+            .service(web::resource("/dsl/print").route(web::post().to(routing::routing_dsl_print)))
+            .service(
+                web::resource("/eligibility/explain")
+                    .route(web::post().to(routing::routing_eligibility_explanation)),
+            )
+            .service(
+                web::resource("/shadow/deactivate")
+                    .route(web::post().to(routing::routing_unlink_shadow_config)),
+            )
+            .service(
+                web::resource("/rollback").route(web::post().to(|state, req, payload| {
+                    routing::routing_rollback_config(state, req, payload, None)
+                })),
+            )
+            .service(
+                web::resource("/activations/scheduled")
+                    .route(web::get().to(routing::routing_list_scheduled_activations)),
+            )
+            .service(
+                web::resource("/activations/scheduled/{activation_id}")
+                    .route(web::delete().to(routing::routing_cancel_scheduled_activation)),
+            )
+            .service(
+                web::resource("/temporal_config/profile/{profile_id}")
+                    .route(web::put().to(routing::routing_upsert_temporal_config))
+                    .route(web::get().to(routing::routing_retrieve_temporal_config))
+                    .route(web::delete().to(routing::routing_delete_temporal_config)),
+            )
             .service(
                 web::resource("/decision")
                     .route(web::put().to(routing::upsert_decision_manager_config))
@@ -1061,6 +1121,21 @@ impl Routing {
                         routing::routing_link_config(state, req, path, payload, None)
                     },
                 )),
+            )
+            .service(
+                web::resource("/{algorithm_id}/shadow")
+                    .route(web::post().to(routing::routing_link_shadow_config)),
+            )
+            .service(
+                web::resource("/{algorithm_id}/diff")
+                    .route(web::get().to(routing::routing_diff_config)),
+            )
+            .service(
+                web::resource("/{algorithm_id}/activate/schedule").route(web::post().to(
+                    |state, req, path, payload| {
+                        routing::routing_schedule_activation(state, req, path, payload, None)
+                    },
+                )),
             );
         route
     }
@@ -1289,6 +1364,15 @@ impl PaymentMethods {
                 .service(
                     web::resource("/get-token")
                         .route(web::get().to(payment_methods::get_payment_method_token_data)),
+                )
+                .service(
+                    web::resource("/duplicates")
+                        .route(web::get().to(payment_methods::payment_method_duplicates_list_api)),
+                )
+                .service(
+                    web::resource("/duplicates/merge").route(
+                        web::post().to(payment_methods::payment_method_duplicates_merge_api),
+                    ),
                 ),
         );
 
@@ -1303,12 +1387,42 @@ impl PaymentMethods {
         let mut route = web::scope("/payment_methods").app_data(web::Data::new(state));
         #[cfg(feature = "olap")]
         {
-            route =
-                route.service(web::resource("/filter").route(
+            route = route
+                .service(web::resource("/filter").route(
                     web::get().to(
                         payment_methods::list_countries_currencies_for_connector_payment_method,
                     ),
-                ));
+                ))
+                .service(
+                    web::resource("/export_recipient_keys")
+                        .route(
+                            web::post().to(
+                                payment_methods::payment_method_export_recipient_key_create_api,
+                            ),
+                        )
+                        .route(
+                            web::get()
+                                .to(payment_methods::payment_method_export_recipient_key_list_api),
+                        ),
+                )
+                .service(
+                    web::resource("/export_recipient_keys/{merchant_id}/{key_id}/approve").route(
+                        web::post()
+                            .to(payment_methods::payment_method_export_recipient_key_approve_api),
+                    ),
+                )
+                .service(
+                    web::resource("/export")
+                        .route(web::post().to(payment_methods::payment_method_export_create_api)),
+                )
+                .service(
+                    web::resource("/export/{export_id}")
+                        .route(web::get().to(payment_methods::payment_method_export_retrieve_api)),
+                )
+                .service(
+                    web::resource("/export/{export_id}/parts/{part_number}")
+                        .route(web::get().to(payment_methods::payment_method_export_download_api)),
+                );
         }
         #[cfg(feature = "oltp")]
         {
@@ -1721,6 +1835,20 @@ impl Webhooks {
     }
 }
 
+pub struct NetworkTokenWebhooks;
+
+#[cfg(all(feature = "oltp", feature = "v1"))]
+impl NetworkTokenWebhooks {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/webhooks/network_token")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/{merchant_id}")
+                    .route(web::post().to(receive_network_token_lifecycle_webhook)),
+            )
+    }
+}
+
 pub struct RelayWebhooks;
 
 #[cfg(feature = "oltp")]
@@ -74,6 +74,20 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RoutingUpdateConfig
             | Flow::RoutingUpdateDefaultConfig
             | Flow::RoutingDeleteConfig
+            | Flow::RoutingSimulateConfig
+            | Flow::RoutingDslParse
+            | Flow::RoutingDslPrint
+            | Flow::RoutingRollbackConfig
+            | Flow::RoutingDiffConfig
+            | Flow::RoutingScheduleActivation
+            | Flow::RoutingListScheduledActivations
+            | Flow::RoutingCancelScheduledActivation
+            | Flow::RoutingUpsertTemporalConfig
+            | Flow::RoutingRetrieveTemporalConfig
+            | Flow::RoutingDeleteTemporalConfig
+            | Flow::RoutingEligibilityExplanation
+            | Flow::RoutingLinkShadowConfig
+            | Flow::RoutingUnlinkShadowConfig
             | Flow::DecisionManagerDeleteConfig
             | Flow::DecisionManagerRetrieveConfig
             | Flow::ToggleDynamicRouting
@@ -120,6 +134,14 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentMethodsRetrieve
             | Flow::PaymentMethodsUpdate
             | Flow::PaymentMethodsDelete
+            | Flow::PaymentMethodDuplicatesList
+            | Flow::PaymentMethodDuplicatesMerge
+            | Flow::PaymentMethodExportCreate
+            | Flow::PaymentMethodExportRetrieve
+            | Flow::PaymentMethodExportDownload
+            | Flow::PaymentMethodExportRecipientKeyCreate
+            | Flow::PaymentMethodExportRecipientKeyList
+            | Flow::PaymentMethodExportRecipientKeyApprove
             | Flow::PaymentMethodCollectLink
             | Flow::ValidatePaymentMethod
             | Flow::ListCountriesCurrencies
@@ -131,11 +153,16 @@ impl From<Flow> for ApiIdentifier {
 
             Flow::PaymentsCreate
             | Flow::PaymentsRetrieve
+            | Flow::PaymentsLedgerRetrieve
+            | Flow::PaymentsInstallmentPlans
+            | Flow::PaymentsCurrencyConversionQuote
             | Flow::PaymentsRetrieveForceSync
             | Flow::PaymentsUpdate
             | Flow::PaymentsConfirm
             | Flow::PaymentsCapture
             | Flow::PaymentsCancel
+            | Flow::PaymentsReverseAuthorization
+            | Flow::PaymentsCancelPostCapture
             | Flow::PaymentsApprove
             | Flow::PaymentsReject
             | Flow::PaymentsSessionToken
@@ -186,6 +213,7 @@ impl From<Flow> for ApiIdentifier {
             Flow::FrmFulfillment
             | Flow::IncomingWebhookReceive
             | Flow::IncomingRelayWebhookReceive
+            | Flow::NetworkTokenLifecycleWebhookReceive
             | Flow::WebhookEventInitialDeliveryAttemptList
             | Flow::WebhookEventDeliveryAttemptList
             | Flow::WebhookEventDeliveryRetry
@@ -352,3 +380,46 @@ impl From<Flow> for ApiIdentifier {
         }
     }
 }
+
+/// Trait for flows whose retries can be deduplicated using the `Idempotency-Key` request header
+pub trait IdempotentFlow {
+    fn supports_idempotency(&self) -> bool;
+}
+
+impl IdempotentFlow for Flow {
+    fn supports_idempotency(&self) -> bool {
+        matches!(
+            self,
+            Self::PaymentsCreate
+                | Self::PaymentsUpdate
+                | Self::PaymentsConfirm
+                | Self::PaymentsCompleteAuthorize
+                | Self::PaymentsIncrementalAuthorization
+                | Self::PaymentsCapture
+                | Self::PaymentsCancel
+                | Self::PaymentsCancelPostCapture
+                | Self::PaymentsReverseAuthorization
+                | Self::PaymentsApprove
+                | Self::PaymentsReject
+                | Self::PaymentsManualUpdate
+                | Self::PaymentsUpdateMetadata
+                | Self::RefundsCreate
+                | Self::RefundsUpdate
+                | Self::RefundsManualUpdate
+                | Self::PayoutsCreate
+                | Self::PayoutsUpdate
+                | Self::PayoutsConfirm
+                | Self::PayoutsFulfill
+                | Self::PayoutsCancel
+                | Self::CustomersCreate
+                | Self::CustomersUpdate
+                | Self::CustomersDelete
+                | Self::MandatesRevoke
+                | Self::PaymentMethodsCreate
+                | Self::PaymentMethodSave
+                | Self::PaymentMethodsUpdate
+                | Self::PaymentMethodsDelete
+                | Self::PaymentMethodDuplicatesMerge
+        )
+    }
+}
@@ -10,6 +10,7 @@ pub fn track_response_status_code<Q>(response: &ApplicationResponse<Q>) -> i64 {
         | ApplicationResponse::PaymentLinkForm(_)
         | ApplicationResponse::FileData(_)
         | ApplicationResponse::JsonWithHeaders(_) => 200,
+        ApplicationResponse::Replayed(response) => response.status_code.into(),
         ApplicationResponse::JsonForRedirection(_) => 302,
     }
 }
@@ -13,7 +13,10 @@ use hyperswitch_domain_models::{
 };
 use router_env::{instrument, logger, tracing, Flow};
 
-use super::app::{AppState, SessionState};
+use super::{
+    app::{AppState, SessionState},
+    lock_utils,
+};
 use crate::{
     core::{
         api_locking,
@@ -267,6 +270,77 @@ pub async fn payment_method_delete_api(
     .await
 }
 
+#[cfg(feature = "v2")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodDuplicatesList))]
+pub async fn payment_method_duplicates_list_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::GlobalPaymentMethodId>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodDuplicatesList;
+    let payment_method_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payment_method_id,
+        |state, auth: auth::AuthenticationData, payment_method_id, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            payment_methods_routes::duplicates::list_payment_method_duplicates(
+                state,
+                merchant_context,
+                payment_method_id,
+            )
+        },
+        &auth::V2ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v2")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodDuplicatesMerge))]
+pub async fn payment_method_duplicates_merge_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::GlobalPaymentMethodId>,
+    json_payload: web::Json<payment_methods::PaymentMethodMergeRequest>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodDuplicatesMerge;
+    let payment_method_id = path.into_inner();
+    let payload = json_payload.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            payment_methods_routes::duplicates::merge_payment_method_duplicates(
+                state,
+                merchant_context,
+                payment_method_id.clone(),
+                req,
+            )
+        },
+        &auth::V2ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsMigrate))]
 pub async fn migrate_payment_method_api(
     state: web::Data<AppState>,
@@ -387,6 +461,229 @@ pub async fn migrate_payment_methods(
     .await
 }
 
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodExportCreate))]
+pub async fn payment_method_export_create_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payment_methods::PaymentMethodExportRequest>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodExportCreate;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            payment_methods_routes::export::create_payment_method_export(
+                state,
+                req_state,
+                merchant_context,
+                req,
+            )
+        },
+        // Exports contain the card details of the customers, so they can only be requested by
+        // users with the payment method export permission and not with API keys
+        &auth::JWTAuth {
+            permission: Permission::MerchantPaymentMethodExportWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodExportRetrieve))]
+pub async fn payment_method_export_retrieve_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodExportRetrieve;
+    let export_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        export_id,
+        |state, auth: auth::AuthenticationData, export_id, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            payment_methods_routes::export::retrieve_payment_method_export(
+                state,
+                merchant_context,
+                export_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantPaymentMethodExportRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodExportDownload))]
+pub async fn payment_method_export_download_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, usize)>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodExportDownload;
+    let (export_id, part_number) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        export_id,
+        |state, auth: auth::AuthenticationData, export_id, req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            payment_methods_routes::export::download_payment_method_export(
+                state,
+                req_state,
+                merchant_context,
+                export_id,
+                part_number,
+            )
+        },
+        // The export file contains the card details of the customers, so downloading it requires
+        // the same permission as requesting the export
+        &auth::JWTAuth {
+            permission: Permission::MerchantPaymentMethodExportWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Lock held while the recipient keys of the merchant are updated, as they are stored together and
+/// concurrent updates would overwrite each other
+#[cfg(all(feature = "v1", feature = "olap"))]
+fn get_payment_method_export_recipient_keys_lock_action(flow: Flow) -> api_locking::LockAction {
+    api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: "payment_method_export_recipient_keys".to_string(),
+            api_identifier: lock_utils::ApiIdentifier::from(flow),
+            override_lock_retries: None,
+        },
+    }
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodExportRecipientKeyCreate))]
+pub async fn payment_method_export_recipient_key_create_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payment_methods::PaymentMethodExportRecipientKeyCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodExportRecipientKeyCreate;
+    let lock_action = get_payment_method_export_recipient_keys_lock_action(flow.clone());
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            payment_methods_routes::export::create_payment_method_export_recipient_key(
+                state,
+                req_state,
+                merchant_context,
+                req,
+            )
+        },
+        &auth::JWTAuth {
+            permission: Permission::MerchantPaymentMethodExportWrite,
+        },
+        lock_action,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodExportRecipientKeyList))]
+pub async fn payment_method_export_recipient_key_list_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodExportRecipientKeyList;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            payment_methods_routes::export::list_payment_method_export_recipient_keys(
+                state,
+                merchant_context,
+            )
+        },
+        &auth::JWTAuth {
+            permission: Permission::MerchantPaymentMethodExportRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodExportRecipientKeyApprove))]
+pub async fn payment_method_export_recipient_key_approve_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::MerchantId, String)>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodExportRecipientKeyApprove;
+    let lock_action = get_payment_method_export_recipient_keys_lock_action(flow.clone());
+    let (merchant_id, key_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        key_id,
+        |state, auth: auth::AuthenticationData, key_id, req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            payment_methods_routes::export::approve_payment_method_export_recipient_key(
+                state,
+                req_state,
+                merchant_context,
+                key_id,
+            )
+        },
+        // Recipient keys registered by merchants are approved by the operator of the application
+        &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id),
+        lock_action,
+    ))
+    .await
+}
+
 #[cfg(feature = "v1")]
 #[instrument(skip_all, fields(flow = ?Flow::PaymentMethodSave))]
 pub async fn save_payment_method_api(
@@ -2,6 +2,8 @@ use actix_web::{web, HttpRequest, Responder};
 use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
+#[cfg(feature = "v1")]
+use crate::core::payment_methods::network_token_lifecycle;
 use crate::{
     core::{
         api_locking,
@@ -179,3 +181,36 @@ pub async fn receive_incoming_webhook<W: types::OutgoingWebhookType>(
     ))
     .await
 }
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::NetworkTokenLifecycleWebhookReceive))]
+pub async fn receive_network_token_lifecycle_webhook(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    body: web::Bytes,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> impl Responder {
+    let flow = Flow::NetworkTokenLifecycleWebhookReceive;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth, _, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            network_token_lifecycle::handle_network_token_lifecycle_notification(
+                state,
+                merchant_context,
+                req.headers(),
+                &body,
+            )
+        },
+        &auth::MerchantIdAuth(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
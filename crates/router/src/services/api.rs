@@ -30,7 +30,7 @@ pub use hyperswitch_domain_models::{
     api::{
         ApplicationResponse, GenericExpiredLinkData, GenericLinkFormData, GenericLinkStatusData,
         GenericLinks, PaymentLinkAction, PaymentLinkFormData, PaymentLinkStatusData,
-        RedirectionFormData,
+        RedirectionFormData, StoredResponse,
     },
     payment_method_data::PaymentMethodData,
     router_response_types::RedirectForm,
@@ -61,7 +61,7 @@ use crate::{
     core::{
         api_locking,
         errors::{self, CustomResult},
-        payments,
+        idempotency, payments,
     },
     events::{
         api_logs::{ApiEvent, ApiEventMetric, ApiEventsType},
@@ -70,7 +70,7 @@ use crate::{
     headers, logger,
     routes::{
         app::{AppStateInfo, ReqState, SessionStateInfo},
-        metrics, AppState, SessionState,
+        lock_utils, metrics, AppState, SessionState,
     },
     services::{
         connector_integration_interface::RouterDataConversion,
@@ -546,6 +546,8 @@ pub async fn server_wrap_util<'a, 'b, U, T, Q, F, Fut, E, OErr>(
     func: F,
     api_auth: &dyn AuthenticateAndFetch<U, SessionState>,
     lock_action: api_locking::LockAction,
+    is_idempotency_supported: bool,
+    idempotent_request: &mut Option<idempotency::IdempotentRequest>,
 ) -> CustomResult<ApplicationResponse<Q>, OErr>
 where
     F: Fn(SessionState, U, T, ReqState) -> Fut,
@@ -640,20 +642,62 @@ where
 
     tracing::Span::current().record("merchant_id", merchant_id.get_string_repr().to_owned());
 
-    let output = {
-        lock_action
-            .clone()
-            .perform_locking_action(&session_state, merchant_id.to_owned())
-            .await
-            .switch()?;
-        let res = func(session_state.clone(), auth_out, payload, request_state)
-            .await
-            .switch();
-        lock_action
-            .free_lock_action(&session_state, merchant_id.to_owned())
-            .await
-            .switch()?;
-        res
+    let idempotency_input = if is_idempotency_supported {
+        idempotency::IdempotencyInput::from_request(request, &payload).switch()?
+    } else {
+        None
+    };
+
+    let idempotency_status = match &idempotency_input {
+        Some(input) => input.begin(&session_state, &merchant_id).await.switch()?,
+        None => idempotency::IdempotencyStatus::New,
+    };
+
+    let output = match idempotency_status {
+        idempotency::IdempotencyStatus::Replay(response) => {
+            Ok(ApplicationResponse::Replayed(response))
+        }
+        idempotency::IdempotencyStatus::New => {
+            if let Err(error) = lock_action
+                .clone()
+                .perform_locking_action(&session_state, merchant_id.to_owned())
+                .await
+            {
+                // The request was not processed, so that it can be retried with the same key
+                if let Some(input) = &idempotency_input {
+                    input.release(&session_state, &merchant_id).await;
+                }
+                return Err(error).switch();
+            }
+
+            // The response of the request is stored by the caller once it is rendered
+            *idempotent_request = idempotency_input.map(|input| {
+                idempotency::IdempotentRequest::new(
+                    input,
+                    session_state.clone(),
+                    merchant_id.clone(),
+                )
+            });
+
+            let res = func(session_state.clone(), auth_out, payload, request_state)
+                .await
+                .switch();
+            let free_lock_result = lock_action
+                .free_lock_action(&session_state, merchant_id.to_owned())
+                .await
+                .switch();
+
+            match free_lock_result {
+                // The lock expires on its own, while the response of the processed request has
+                // to be stored for it to be replayed on retries
+                Err(error) if idempotent_request.is_some() => {
+                    logger::error!(?error, "Failed to free the lock of the idempotent request");
+                }
+                free_lock_result => free_lock_result?,
+            }
+
+            res
+        }
     };
     let request_duration = Instant::now()
         .saturating_duration_since(start_instant)
@@ -730,7 +774,7 @@ where
     fields(request_method, request_url_path, status_code)
 )]
 pub async fn server_wrap<'a, T, U, Q, F, Fut, E>(
-    flow: impl router_env::types::FlowMetric,
+    flow: impl router_env::types::FlowMetric + lock_utils::IdempotentFlow,
     state: web::Data<AppState>,
     request: &'a HttpRequest,
     payload: T,
@@ -775,6 +819,7 @@ where
         tag = ?Tag::BeginRequest, payload = ?payload,
     headers = ?incoming_header_to_log);
 
+    let mut idempotent_request = None;
     let server_wrap_util_res = server_wrap_util(
         &flow,
         state.clone(),
@@ -784,6 +829,8 @@ where
         func,
         api_auth,
         lock_action,
+        flow.supports_idempotency(),
+        &mut idempotent_request,
     )
     .await
     .map(|response| {
@@ -804,6 +851,7 @@ where
         },
         Ok(ApplicationResponse::StatusOk) => http_response_ok(),
         Ok(ApplicationResponse::TextPlain(text)) => http_response_plaintext(text),
+        Ok(ApplicationResponse::Replayed(response)) => http_response_replayed(response),
         Ok(ApplicationResponse::FileData((file_data, content_type))) => {
             http_response_file_data(file_data, content_type)
         }
@@ -909,6 +957,11 @@ where
         Err(error) => log_and_return_error_response(error),
     };
 
+    let res = match idempotent_request {
+        Some(idempotent_request) => idempotent_request.complete(res).await,
+        None => res,
+    };
+
     let response_code = res.status().as_u16();
     tracing::Span::current().record("status_code", response_code);
 
@@ -1020,6 +1073,20 @@ pub fn http_response_json_with_headers<T: body::MessageBody + 'static>(
         .body(response)
 }
 
+/// Sends the stored response of an earlier request with the same idempotency key
+pub fn http_response_replayed(response: StoredResponse) -> HttpResponse {
+    let status_code = http::StatusCode::from_u16(response.status_code)
+        .unwrap_or(http::StatusCode::INTERNAL_SERVER_ERROR);
+    let mut response_builder = HttpResponse::build(status_code);
+    for header in response.headers {
+        response_builder.append_header(header);
+    }
+
+    response_builder
+        .insert_header((headers::IDEMPOTENT_REPLAYED, "true"))
+        .body(response.body)
+}
+
 pub fn http_response_plaintext<T: body::MessageBody + 'static>(res: T) -> HttpResponse {
     HttpResponse::Ok().content_type(mime::TEXT_PLAIN).body(res)
 }
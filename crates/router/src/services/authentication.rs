@@ -4156,6 +4156,13 @@ impl ClientSecretFetch for PaymentMethodListRequest {
     }
 }
 
+#[cfg(feature = "v1")]
+impl ClientSecretFetch for payments::CurrencyConversionQuoteRequest {
+    fn get_client_secret(&self) -> Option<&String> {
+        self.client_secret.as_ref()
+    }
+}
+
 impl ClientSecretFetch for payments::PaymentsPostSessionTokensRequest {
     fn get_client_secret(&self) -> Option<&String> {
         Some(self.client_secret.peek())
@@ -49,6 +49,8 @@ fn get_group_description(group: PermissionGroup) -> Option<&'static str> {
         PermissionGroup::ReconOpsView => Some("View and access all reconciliation operations including reports and analytics"),
         PermissionGroup::ReconOpsManage => Some("Manage all reconciliation operations including reports and analytics"),
         PermissionGroup::InternalManage => None, // Internal group, no user-facing description
+        PermissionGroup::PaymentMethodExportView => Some("View exports of the payment methods of the customers"),
+        PermissionGroup::PaymentMethodExportManage => Some("Export the payment methods of the customers, including their card details"),
     }
 }
 
@@ -63,5 +65,6 @@ pub fn get_parent_group_description(group: ParentGroup) -> Option<&'static str>
         ParentGroup::ReconOps => Some("View, manage reconciliation operations like upload and process files, run reconciliation etc"),
         ParentGroup::ReconReports => Some("View, manage reconciliation reports and analytics"),
         ParentGroup::Internal => None, // Internal group, no user-facing description
+        ParentGroup::PaymentMethodExport => Some("Export the payment methods of the customers, including their card details"),
     }
 }
@@ -23,7 +23,8 @@ impl PermissionGroupExt for PermissionGroup {
             | Self::MerchantDetailsView
             | Self::AccountView
             | Self::ReconOpsView
-            | Self::ReconReportsView => PermissionScope::Read,
+            | Self::ReconReportsView
+            | Self::PaymentMethodExportView => PermissionScope::Read,
 
             Self::OperationsManage
             | Self::ConnectorsManage
@@ -34,7 +35,8 @@ impl PermissionGroupExt for PermissionGroup {
             | Self::AccountManage
             | Self::ReconOpsManage
             | Self::ReconReportsManage
-            | Self::InternalManage => PermissionScope::Write,
+            | Self::InternalManage
+            | Self::PaymentMethodExportManage => PermissionScope::Write,
         }
     }
 
@@ -53,6 +55,9 @@ impl PermissionGroupExt for PermissionGroup {
             Self::ReconOpsView | Self::ReconOpsManage => ParentGroup::ReconOps,
             Self::ReconReportsView | Self::ReconReportsManage => ParentGroup::ReconReports,
             Self::InternalManage => ParentGroup::Internal,
+            Self::PaymentMethodExportView | Self::PaymentMethodExportManage => {
+                ParentGroup::PaymentMethodExport
+            }
         }
     }
 
@@ -103,6 +108,12 @@ impl PermissionGroupExt for PermissionGroup {
             Self::AccountManage => vec![Self::AccountView, Self::AccountManage],
 
             Self::InternalManage => vec![Self::InternalManage],
+
+            Self::PaymentMethodExportView => vec![Self::PaymentMethodExportView],
+            Self::PaymentMethodExportManage => vec![
+                Self::PaymentMethodExportView,
+                Self::PaymentMethodExportManage,
+            ],
         }
     }
 }
@@ -127,6 +138,7 @@ impl ParentGroupExt for ParentGroup {
             Self::ReconOps => RECON_OPS.to_vec(),
             Self::ReconReports => RECON_REPORTS.to_vec(),
             Self::Internal => INTERNAL.to_vec(),
+            Self::PaymentMethodExport => PAYMENT_METHOD_EXPORT.to_vec(),
         }
     }
 
@@ -204,6 +216,9 @@ pub static RECON_OPS: [Resource; 8] = [
 
 pub static INTERNAL: [Resource; 1] = [Resource::InternalConnector];
 
+pub static PAYMENT_METHOD_EXPORT: [Resource; 2] =
+    [Resource::PaymentMethodExport, Resource::Account];
+
 pub static RECON_REPORTS: [Resource; 4] = [
     Resource::ReconToken,
     Resource::ReconAndSettlementAnalytics,
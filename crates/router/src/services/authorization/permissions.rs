@@ -102,6 +102,10 @@ generate_permissions! {
         InternalConnector: {
             scopes: [Write],
             entities: [Merchant]
+        },
+        PaymentMethodExport: {
+            scopes: [Read, Write],
+            entities: [Merchant]
         }
     ]
 }
@@ -138,6 +142,7 @@ pub fn get_resource_name(resource: Resource, entity_type: EntityType) -> Option<
         (Resource::Account, EntityType::Organization) => Some("Organization Account"),
         (Resource::Account, EntityType::Tenant) => Some("Tenant Account"),
         (Resource::InternalConnector, _) => None,
+        (Resource::PaymentMethodExport, _) => Some("Payment Method Exports"),
     }
 }
 
@@ -115,6 +115,8 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
                 PermissionGroup::ReconOpsManage,
                 PermissionGroup::ReconReportsView,
                 PermissionGroup::ReconReportsManage,
+                PermissionGroup::PaymentMethodExportView,
+                PermissionGroup::PaymentMethodExportManage,
             ],
             role_id: common_utils::consts::ROLE_ID_TENANT_ADMIN.to_string(),
             role_name: "tenant_admin".to_string(),
@@ -150,6 +152,8 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
                 PermissionGroup::ReconOpsManage,
                 PermissionGroup::ReconReportsView,
                 PermissionGroup::ReconReportsManage,
+                PermissionGroup::PaymentMethodExportView,
+                PermissionGroup::PaymentMethodExportManage,
             ],
             role_id: common_utils::consts::ROLE_ID_ORGANIZATION_ADMIN.to_string(),
             role_name: "organization_admin".to_string(),
@@ -184,6 +188,8 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
                 PermissionGroup::ReconOpsManage,
                 PermissionGroup::ReconReportsView,
                 PermissionGroup::ReconReportsManage,
+                PermissionGroup::PaymentMethodExportView,
+                PermissionGroup::PaymentMethodExportManage,
             ],
             role_id: consts::user_role::ROLE_ID_MERCHANT_ADMIN.to_string(),
             role_name: "merchant_admin".to_string(),
@@ -293,15 +293,17 @@ impl Capturable for PaymentsAuthorizeData {
     where
         F: Clone,
     {
-        match payment_data.get_capture_method().unwrap_or_default()
-        {
-            common_enums::CaptureMethod::Automatic|common_enums::CaptureMethod::SequentialAutomatic  => {
+        match payment_data.get_capture_method().unwrap_or_default() {
+            common_enums::CaptureMethod::Automatic
+            | common_enums::CaptureMethod::SequentialAutomatic => {
                 let intent_status = common_enums::IntentStatus::foreign_from(attempt_status);
                 match intent_status {
                     common_enums::IntentStatus::Succeeded
                     | common_enums::IntentStatus::Failed
-                    | common_enums::IntentStatus::Processing | common_enums::IntentStatus::Conflicted => Some(0),
+                    | common_enums::IntentStatus::Processing
+                    | common_enums::IntentStatus::Conflicted => Some(0),
                     common_enums::IntentStatus::Cancelled
+                    | common_enums::IntentStatus::Expired
                     | common_enums::IntentStatus::PartiallyCaptured
                     | common_enums::IntentStatus::RequiresCustomerAction
                     | common_enums::IntentStatus::RequiresMerchantAction
@@ -310,12 +312,16 @@ impl Capturable for PaymentsAuthorizeData {
                     | common_enums::IntentStatus::RequiresCapture
                     | common_enums::IntentStatus::PartiallyCapturedAndCapturable => None,
                 }
-            },
-            common_enums::CaptureMethod::Manual => Some(payment_data.payment_attempt.get_total_amount().get_amount_as_i64()),
+            }
+            // A payment with scheduled capture is authorized with a manual capture
+            common_enums::CaptureMethod::Manual | common_enums::CaptureMethod::Scheduled => Some(
+                payment_data
+                    .payment_attempt
+                    .get_total_amount()
+                    .get_amount_as_i64(),
+            ),
             // In case of manual multiple, amount capturable must be inferred from all captures.
-            common_enums::CaptureMethod::ManualMultiple |
-            // Scheduled capture is not supported as of now
-            common_enums::CaptureMethod::Scheduled => None,
+            common_enums::CaptureMethod::ManualMultiple => None,
         }
     }
 }
@@ -343,6 +349,7 @@ impl Capturable for PaymentsCaptureData {
             | common_enums::IntentStatus::Conflicted => Some(0),
             common_enums::IntentStatus::Processing
             | common_enums::IntentStatus::Cancelled
+            | common_enums::IntentStatus::Expired
             | common_enums::IntentStatus::Failed
             | common_enums::IntentStatus::RequiresCustomerAction
             | common_enums::IntentStatus::RequiresMerchantAction
@@ -375,17 +382,18 @@ impl Capturable for CompleteAuthorizeData {
     where
         F: Clone,
     {
-        match payment_data
-            .get_capture_method()
-            .unwrap_or_default()
-        {
-            common_enums::CaptureMethod::Automatic | common_enums::CaptureMethod::SequentialAutomatic => {
+        match payment_data.get_capture_method().unwrap_or_default() {
+            common_enums::CaptureMethod::Automatic
+            | common_enums::CaptureMethod::SequentialAutomatic => {
                 let intent_status = common_enums::IntentStatus::foreign_from(attempt_status);
                 match intent_status {
-                    common_enums::IntentStatus::Succeeded|
-                    common_enums::IntentStatus::Failed|
-                    common_enums::IntentStatus::Processing | common_enums::IntentStatus::Conflicted => Some(0),
-                    common_enums::IntentStatus::Cancelled | common_enums::IntentStatus::PartiallyCaptured
+                    common_enums::IntentStatus::Succeeded
+                    | common_enums::IntentStatus::Failed
+                    | common_enums::IntentStatus::Processing
+                    | common_enums::IntentStatus::Conflicted => Some(0),
+                    common_enums::IntentStatus::Cancelled
+                    | common_enums::IntentStatus::PartiallyCaptured
+                    | common_enums::IntentStatus::Expired
                     | common_enums::IntentStatus::RequiresCustomerAction
                     | common_enums::IntentStatus::RequiresMerchantAction
                     | common_enums::IntentStatus::RequiresPaymentMethod
@@ -393,12 +401,16 @@ impl Capturable for CompleteAuthorizeData {
                     | common_enums::IntentStatus::RequiresCapture
                     | common_enums::IntentStatus::PartiallyCapturedAndCapturable => None,
                 }
-            },
-            common_enums::CaptureMethod::Manual => Some(payment_data.payment_attempt.get_total_amount().get_amount_as_i64()),
+            }
+            // A payment with scheduled capture is authorized with a manual capture
+            common_enums::CaptureMethod::Manual | common_enums::CaptureMethod::Scheduled => Some(
+                payment_data
+                    .payment_attempt
+                    .get_total_amount()
+                    .get_amount_as_i64(),
+            ),
             // In case of manual multiple, amount capturable must be inferred from all captures.
-            common_enums::CaptureMethod::ManualMultiple |
-            // Scheduled capture is not supported as of now
-            common_enums::CaptureMethod::Scheduled => None,
+            common_enums::CaptureMethod::ManualMultiple => None,
         }
     }
 }
@@ -430,6 +442,7 @@ impl Capturable for PaymentsCancelData {
         let intent_status = common_enums::IntentStatus::foreign_from(attempt_status);
         match intent_status {
             common_enums::IntentStatus::Cancelled
+            | common_enums::IntentStatus::Expired
             | common_enums::IntentStatus::Processing
             | common_enums::IntentStatus::PartiallyCaptured
             | common_enums::IntentStatus::Conflicted => Some(0),
@@ -1090,6 +1103,8 @@ impl ForeignFrom<&SetupMandateRouterData> for PaymentsAuthorizeData {
             merchant_config_currency: None,
             connector_testing_data: data.request.connector_testing_data.clone(),
             order_id: None,
+            installment_plan: None,
+            currency_conversion: None,
         }
     }
 }
@@ -230,6 +230,10 @@ impl ForeignTryFrom<domain::Profile> for ProfileResponse {
             .into(),
             is_iframe_redirection_enabled: item.is_iframe_redirection_enabled,
             merchant_category_code: item.merchant_category_code,
+            payment_intent_expiry: item.payment_intent_expiry,
+            is_auto_reauthorization_enabled: item.is_auto_reauthorization_enabled,
+            retry_policy: item.retry_policy,
+            dynamic_currency_conversion: item.dynamic_currency_conversion,
         })
     }
 }
@@ -315,6 +319,11 @@ impl ForeignTryFrom<domain::Profile> for ProfileResponse {
                 .external_vault_connector_details
                 .map(ForeignInto::foreign_into),
             merchant_category_code: item.merchant_category_code,
+            payment_intent_expiry: item.payment_intent_expiry,
+            is_auto_reauthorization_enabled: item.is_auto_reauthorization_enabled,
+            retry_policy: item.retry_policy,
+            dynamic_currency_conversion: item.dynamic_currency_conversion,
+            payment_method_duplicate_policy: item.payment_method_duplicate_policy,
         })
     }
 }
@@ -483,5 +492,11 @@ pub async fn create_profile_from_merchant_account(
             .is_pre_network_tokenization_enabled
             .unwrap_or_default(),
         merchant_category_code: request.merchant_category_code,
+        payment_intent_expiry: request.payment_intent_expiry.map(i64::from),
+        is_auto_reauthorization_enabled: request.is_auto_reauthorization_enabled,
+        retry_policy: request
+            .retry_policy
+            .filter(|retry_policy| !retry_policy.is_empty()),
+        dynamic_currency_conversion: request.dynamic_currency_conversion,
     }))
 }
@@ -18,14 +18,26 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "v2")]
 use crate::{
     consts,
+    core::{
+        errors::{self, CustomResult},
+        payment_methods::vault::external_adapter::{self, ExternalVaultAdapter},
+    },
     types::{api, domain, storage},
 };
 
 #[cfg(feature = "v2")]
+#[async_trait::async_trait]
 pub trait VaultingInterface {
     fn get_vaulting_request_url() -> &'static str;
 
     fn get_vaulting_flow_name() -> &'static str;
+
+    /// Serves the request through an external vault adapter instead of the vault service,
+    /// taking and returning the same payloads as the vault service
+    async fn call_external_vault_adapter(
+        adapter: &dyn ExternalVaultAdapter,
+        payload: &[u8],
+    ) -> CustomResult<String, errors::VaultError>;
 }
 
 #[cfg(feature = "v2")]
@@ -75,6 +87,7 @@ pub struct VaultRetrieve;
 pub struct VaultDelete;
 
 #[cfg(feature = "v2")]
+#[async_trait::async_trait]
 impl VaultingInterface for AddVault {
     fn get_vaulting_request_url() -> &'static str {
         consts::ADD_VAULT_REQUEST_URL
@@ -83,9 +96,28 @@ impl VaultingInterface for AddVault {
     fn get_vaulting_flow_name() -> &'static str {
         consts::VAULT_ADD_FLOW_TYPE
     }
+
+    async fn call_external_vault_adapter(
+        adapter: &dyn ExternalVaultAdapter,
+        payload: &[u8],
+    ) -> CustomResult<String, errors::VaultError> {
+        let request: AddVaultRequest<serde_json::Value> =
+            external_adapter::parse_vault_payload(payload)?;
+
+        let response = external_adapter::add_vaulting_data(
+            adapter,
+            &request.entity_id,
+            Some(&request.vault_id),
+            request.data,
+        )
+        .await?;
+
+        external_adapter::encode_vault_response(&response)
+    }
 }
 
 #[cfg(feature = "v2")]
+#[async_trait::async_trait]
 impl VaultingInterface for GetVaultFingerprint {
     fn get_vaulting_request_url() -> &'static str {
         consts::VAULT_FINGERPRINT_REQUEST_URL
@@ -94,9 +126,29 @@ impl VaultingInterface for GetVaultFingerprint {
     fn get_vaulting_flow_name() -> &'static str {
         consts::VAULT_GET_FINGERPRINT_FLOW_TYPE
     }
+
+    async fn call_external_vault_adapter(
+        adapter: &dyn ExternalVaultAdapter,
+        payload: &[u8],
+    ) -> CustomResult<String, errors::VaultError> {
+        let request: VaultFingerprintRequest = external_adapter::parse_vault_payload(payload)?;
+        let data = external_adapter::parse_vault_payload(request.data.as_bytes())?;
+
+        let response = adapter
+            .get_fingerprint(external_adapter::ExternalVaultFingerprintRequest {
+                data: Secret::new(data),
+                fingerprint_key: Secret::new(request.key),
+            })
+            .await?;
+
+        external_adapter::encode_vault_response(&VaultFingerprintResponse {
+            fingerprint_id: response.fingerprint_id,
+        })
+    }
 }
 
 #[cfg(feature = "v2")]
+#[async_trait::async_trait]
 impl VaultingInterface for VaultRetrieve {
     fn get_vaulting_request_url() -> &'static str {
         consts::VAULT_RETRIEVE_REQUEST_URL
@@ -105,9 +157,26 @@ impl VaultingInterface for VaultRetrieve {
     fn get_vaulting_flow_name() -> &'static str {
         consts::VAULT_RETRIEVE_FLOW_TYPE
     }
+
+    async fn call_external_vault_adapter(
+        adapter: &dyn ExternalVaultAdapter,
+        payload: &[u8],
+    ) -> CustomResult<String, errors::VaultError> {
+        let request: VaultRetrieveRequest = external_adapter::parse_vault_payload(payload)?;
+
+        let data = external_adapter::retrieve_vaulting_data(
+            adapter,
+            &request.entity_id,
+            &request.vault_id,
+        )
+        .await?;
+
+        external_adapter::encode_vault_response(&serde_json::json!({ "data": data }))
+    }
 }
 
 #[cfg(feature = "v2")]
+#[async_trait::async_trait]
 impl VaultingInterface for VaultDelete {
     fn get_vaulting_request_url() -> &'static str {
         consts::VAULT_DELETE_REQUEST_URL
@@ -116,6 +185,19 @@ impl VaultingInterface for VaultDelete {
     fn get_vaulting_flow_name() -> &'static str {
         consts::VAULT_DELETE_FLOW_TYPE
     }
+
+    async fn call_external_vault_adapter(
+        adapter: &dyn ExternalVaultAdapter,
+        payload: &[u8],
+    ) -> CustomResult<String, errors::VaultError> {
+        let request: VaultDeleteRequest = external_adapter::parse_vault_payload(payload)?;
+
+        let response =
+            external_adapter::delete_vaulting_data(adapter, &request.entity_id, &request.vault_id)
+                .await?;
+
+        external_adapter::encode_vault_response(&response)
+    }
 }
 
 #[cfg(feature = "v2")]
@@ -287,6 +369,28 @@ pub struct TokenResponse {
     pub nickname: Option<Secret<String>>,
 }
 
+/// Network token and cryptogram fetched from the token service, cached for reuse
+#[cfg(feature = "v1")]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CachedNetworkToken {
+    pub token: CardNumber,
+    pub cryptogram: Secret<String>,
+    pub network: api_enums::CardNetwork,
+    pub token_details: TokenDetails,
+}
+
+#[cfg(feature = "v1")]
+impl From<TokenResponse> for CachedNetworkToken {
+    fn from(token_response: TokenResponse) -> Self {
+        Self {
+            token: token_response.authentication_details.token,
+            cryptogram: token_response.authentication_details.cryptogram,
+            network: token_response.network,
+            token_details: token_response.token_details,
+        }
+    }
+}
+
 #[cfg(feature = "v1")]
 #[derive(Debug, Serialize, Deserialize)]
 pub struct DeleteCardToken {
@@ -343,6 +447,18 @@ pub struct CheckTokenStatus {
 pub enum TokenStatus {
     Active,
     Inactive,
+    Suspended,
+    Deleted,
+}
+
+impl From<TokenStatus> for common_enums::NetworkTokenStatus {
+    fn from(token_status: TokenStatus) -> Self {
+        match token_status {
+            TokenStatus::Active => Self::Active,
+            TokenStatus::Inactive | TokenStatus::Suspended => Self::Suspended,
+            TokenStatus::Deleted => Self::Deleted,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -357,3 +473,29 @@ pub struct CheckTokenStatusResponsePayload {
 pub struct CheckTokenStatusResponse {
     pub payload: CheckTokenStatusResponsePayload,
 }
+
+#[derive(Debug, Deserialize, Clone, Copy, Eq, PartialEq, strum::Display)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "snake_case")]
+pub enum NetworkTokenLifecycleEventType {
+    TokenUpdated,
+    TokenSuspended,
+    TokenResumed,
+    TokenDeleted,
+}
+
+/// Notification sent by the token service when a network token is changed by the card network
+#[cfg(feature = "v1")]
+#[derive(Debug, Deserialize)]
+pub struct NetworkTokenLifecycleNotification {
+    /// Unique identifier of the notification, used to discard replayed notifications
+    pub notification_id: String,
+    /// Time at which the notification was sent, as a unix timestamp in seconds
+    pub timestamp: i64,
+    pub event_type: NetworkTokenLifecycleEventType,
+    pub card_reference: String, //network token requestor ref id
+    pub customer_id: id_type::CustomerId,
+    pub token_expiry_month: Option<Secret<String>>,
+    pub token_expiry_year: Option<Secret<String>>,
+    pub token_last_four: Option<String>,
+}
@@ -21,6 +21,7 @@ pub mod file;
 pub mod fraud_check;
 pub mod generic_link;
 pub mod gsm;
+pub mod idempotency_key;
 #[cfg(feature = "kv_store")]
 pub mod kv;
 pub mod locker_mock_up;
@@ -29,6 +30,8 @@ pub mod merchant_account;
 pub mod merchant_connector_account;
 pub mod merchant_key_store;
 pub mod payment_attempt;
+pub mod payment_intent;
+pub mod payment_ledger;
 pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
@@ -72,8 +75,9 @@ pub use self::{
     blocklist_fingerprint::*, blocklist_lookup::*, business_profile::*, callback_mapper::*,
     capture::*, cards_info::*, configs::*, customers::*, dashboard_metadata::*, dispute::*,
     dynamic_routing_stats::*, ephemeral_key::*, events::*, file::*, fraud_check::*,
-    generic_link::*, gsm::*, locker_mock_up::*, mandate::*, merchant_account::*,
-    merchant_connector_account::*, merchant_key_store::*, payment_link::*, payment_method::*,
-    process_tracker::*, refund::*, reverse_lookup::*, role::*, routing_algorithm::*,
-    unified_translations::*, user::*, user_authentication_method::*, user_role::*,
+    generic_link::*, gsm::*, idempotency_key::*, locker_mock_up::*, mandate::*,
+    merchant_account::*, merchant_connector_account::*, merchant_key_store::*, payment_intent::*,
+    payment_ledger::*, payment_link::*, payment_method::*, process_tracker::*, refund::*,
+    reverse_lookup::*, role::*, routing_algorithm::*, unified_translations::*, user::*,
+    user_authentication_method::*, user_role::*,
 };
@@ -1 +1,8 @@
 pub use diesel_models::capture::*;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ScheduledCaptureTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub attempt_id: String,
+}
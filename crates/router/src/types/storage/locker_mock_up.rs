@@ -1 +1 @@
-pub use diesel_models::locker_mock_up::{LockerMockUp, LockerMockUpNew};
+pub use diesel_models::locker_mock_up::{LockerMockUp, LockerMockUpNew, LockerMockUpUpdate};
@@ -225,6 +225,13 @@ mod tests {
             processor_merchant_id: Default::default(),
             created_by: None,
             setup_future_usage_applied: Default::default(),
+            authorization_reversal_status: Default::default(),
+            amount_reversed: Default::default(),
+            installment_plan: Default::default(),
+            currency_conversion: Default::default(),
+            cancellation_reason_code: Default::default(),
+            authorized_at: Default::default(),
+            captured_at: Default::default(),
         };
 
         let store = state
@@ -315,6 +322,13 @@ mod tests {
             processor_merchant_id: Default::default(),
             created_by: None,
             setup_future_usage_applied: Default::default(),
+            authorization_reversal_status: Default::default(),
+            amount_reversed: Default::default(),
+            installment_plan: Default::default(),
+            currency_conversion: Default::default(),
+            cancellation_reason_code: Default::default(),
+            authorized_at: Default::default(),
+            captured_at: Default::default(),
         };
         let store = state
             .stores
@@ -418,6 +432,13 @@ mod tests {
             processor_merchant_id: Default::default(),
             created_by: None,
             setup_future_usage_applied: Default::default(),
+            authorization_reversal_status: Default::default(),
+            amount_reversed: Default::default(),
+            installment_plan: Default::default(),
+            currency_conversion: Default::default(),
+            cancellation_reason_code: Default::default(),
+            authorized_at: Default::default(),
+            captured_at: Default::default(),
         };
         let store = state
             .stores
@@ -0,0 +1,20 @@
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PaymentIntentExpiryTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub payment_id: common_utils::id_type::PaymentId,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct AuthorizationExpiryTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub payment_id: common_utils::id_type::PaymentId,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PaymentRetryTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub attempt_id: String,
+    pub payment_token: String,
+    pub connectors: Vec<api_models::routing::RoutableConnectorChoice>,
+}
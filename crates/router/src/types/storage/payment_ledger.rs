@@ -0,0 +1,7 @@
+pub use diesel_models::payment_ledger::{PaymentLedgerEntry, PaymentLedgerEntryNew};
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PaymentLedgerTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub payment_id: common_utils::id_type::PaymentId,
+}
@@ -169,3 +169,62 @@ pub struct PaymentMethodStatusTrackingData {
     pub curr_status: enums::PaymentMethodStatus,
     pub merchant_id: common_utils::id_type::MerchantId,
 }
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct CardAccountUpdaterTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct CardExpiryScanTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    /// Time at which the ongoing scan started, the payment methods created after which are
+    /// scanned in the next scan
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub scan_started_at: Option<time::PrimitiveDateTime>,
+    /// Last payment method scanned in the ongoing scan
+    #[serde(default)]
+    pub cursor: Option<hyperswitch_domain_models::payment_methods::PaymentMethodListCursor>,
+}
+
+#[cfg(feature = "v2")]
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct PaymentMethodFingerprintBackfillTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    /// Last payment method whose fingerprint is backfilled, after which the backfill continues
+    pub after: Option<common_utils::id_type::GlobalPaymentMethodId>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct NetworkTokenStatusCheckTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    /// Time at which the ongoing check started, the payment methods created after which are
+    /// checked in the next check
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub check_started_at: Option<time::PrimitiveDateTime>,
+    /// Last payment method checked in the ongoing check
+    #[serde(default)]
+    pub cursor: Option<hyperswitch_domain_models::payment_methods::PaymentMethodListCursor>,
+    /// Whether any network token was checked in the ongoing check
+    #[serde(default)]
+    pub network_tokens_found: bool,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct PaymentMethodExportTrackingData {
+    pub export_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub recipient_key_id: String,
+    pub payment_methods_count: Option<usize>,
+    pub skipped_payment_methods_count: Option<usize>,
+    /// Number of parts of the export which are generated
+    #[serde(default)]
+    pub parts_count: Option<usize>,
+    /// Last payment method in the generated parts, from which the generation of the export
+    /// continues
+    #[serde(default)]
+    pub cursor: Option<hyperswitch_domain_models::payment_methods::PaymentMethodListCursor>,
+}
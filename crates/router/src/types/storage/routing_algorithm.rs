@@ -1,3 +1,11 @@
 pub use diesel_models::routing_algorithm::{
     RoutingAlgorithm, RoutingAlgorithmMetadata, RoutingProfileMetadata,
 };
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RoutingActivationTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    pub transaction_type: common_enums::TransactionType,
+}
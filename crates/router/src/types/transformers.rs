@@ -472,6 +472,7 @@ impl ForeignFrom<api_enums::IntentStatus> for Option<storage_enums::EventType> {
             | api_enums::IntentStatus::RequiresCustomerAction
             | api_enums::IntentStatus::Conflicted => Some(storage_enums::EventType::ActionRequired),
             api_enums::IntentStatus::Cancelled => Some(storage_enums::EventType::PaymentCancelled),
+            api_enums::IntentStatus::Expired => Some(storage_enums::EventType::PaymentExpired),
             api_enums::IntentStatus::PartiallyCaptured
             | api_enums::IntentStatus::PartiallyCapturedAndCapturable => {
                 Some(storage_enums::EventType::PaymentCaptured)
@@ -1212,6 +1213,7 @@ impl ForeignTryFrom<domain::MerchantConnectorAccount>
             applepay_verified_domains: item.applepay_verified_domains,
             pm_auth_config: item.pm_auth_config,
             status: item.status,
+            fee_schedule: item.fee_schedule,
             additional_merchant_data: item
                 .additional_merchant_data
                 .map(|data| {
@@ -1268,6 +1270,7 @@ impl ForeignTryFrom<domain::MerchantConnectorAccount>
             applepay_verified_domains: item.applepay_verified_domains,
             pm_auth_config: item.pm_auth_config,
             status: item.status,
+            fee_schedule: item.fee_schedule,
             additional_merchant_data: item
                 .additional_merchant_data
                 .map(|data| {
@@ -1369,6 +1372,7 @@ impl ForeignTryFrom<domain::MerchantConnectorAccount>
             applepay_verified_domains: item.applepay_verified_domains,
             pm_auth_config: item.pm_auth_config,
             status: item.status,
+            fee_schedule: item.fee_schedule,
             additional_merchant_data: item
                 .additional_merchant_data
                 .map(|data| {
@@ -2152,6 +2156,7 @@ impl ForeignFrom<api_models::admin::WebhookDetails>
             payment_created_enabled: item.payment_created_enabled,
             payment_succeeded_enabled: item.payment_succeeded_enabled,
             payment_failed_enabled: item.payment_failed_enabled,
+            payment_authorization_expiring_enabled: item.payment_authorization_expiring_enabled,
         }
     }
 }
@@ -2168,6 +2173,7 @@ impl ForeignFrom<diesel_models::business_profile::WebhookDetails>
             payment_created_enabled: item.payment_created_enabled,
             payment_succeeded_enabled: item.payment_succeeded_enabled,
             payment_failed_enabled: item.payment_failed_enabled,
+            payment_authorization_expiring_enabled: item.payment_authorization_expiring_enabled,
         }
     }
 }
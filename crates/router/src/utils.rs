@@ -1154,17 +1154,6 @@ where
     D: payments_core::OperationSessionGetters<F>,
 {
     let status = payment_data.get_payment_intent().status;
-    let payment_id = payment_data.get_payment_intent().get_id().to_owned();
-
-    let captures = payment_data
-        .get_multiple_capture_data()
-        .map(|multiple_capture_data| {
-            multiple_capture_data
-                .get_all_captures()
-                .into_iter()
-                .cloned()
-                .collect()
-        });
 
     if matches!(
         status,
@@ -1172,52 +1161,21 @@ where
             | enums::IntentStatus::Failed
             | enums::IntentStatus::PartiallyCaptured
             | enums::IntentStatus::RequiresMerchantAction
+            | enums::IntentStatus::Expired
     ) {
-        let payments_response = crate::core::payments::transformers::payments_to_payments_response(
-            payment_data,
-            captures,
-            customer,
-            services::AuthFlow::Merchant,
-            &state.base_url,
-            &operation,
-            &state.conf.connector_request_reference_id_config,
-            None,
-            None,
-            None,
-        )?;
-
-        let event_type = ForeignFrom::foreign_from(status);
-
-        if let services::ApplicationResponse::JsonWithHeaders((payments_response_json, _)) =
-            payments_response
-        {
-            let cloned_state = state.clone();
-            // This spawns this futures in a background thread, the exception inside this future won't affect
-            // the current thread and the lifecycle of spawn thread is not handled by runtime.
-            // So when server shutdown won't wait for this thread's completion.
-
-            if let Some(event_type) = event_type {
-                tokio::spawn(
-                    async move {
-                        let primary_object_created_at = payments_response_json.created;
-                        Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
-                            cloned_state,
-                            merchant_context.clone(),
-                            business_profile,
-                            event_type,
-                            diesel_models::enums::EventClass::Payments,
-                            payment_id.get_string_repr().to_owned(),
-                            diesel_models::enums::EventObjectType::PaymentDetails,
-                            webhooks::OutgoingWebhookContent::PaymentDetails(Box::new(
-                                payments_response_json,
-                            )),
-                            primary_object_created_at,
-                        ))
-                        .await
-                    }
-                    .in_current_span(),
-                );
-            } else {
+        match ForeignFrom::foreign_from(status) {
+            Some(event_type) => {
+                trigger_payments_webhook_for_event_type(
+                    merchant_context,
+                    business_profile,
+                    payment_data,
+                    customer,
+                    state,
+                    operation,
+                    event_type,
+                )?;
+            }
+            None => {
                 logger::warn!(
                     "Outgoing webhook not sent because of missing event type status mapping"
                 );
@@ -1228,6 +1186,80 @@ where
     Ok(())
 }
 
+/// Sends the outgoing webhook for the payment with the given event type, irrespective of the
+/// status of the payment
+#[cfg(feature = "v1")]
+pub fn trigger_payments_webhook_for_event_type<F, Op, D>(
+    merchant_context: domain::MerchantContext,
+    business_profile: domain::Profile,
+    payment_data: D,
+    customer: Option<domain::Customer>,
+    state: &SessionState,
+    operation: Op,
+    event_type: enums::EventType,
+) -> RouterResult<()>
+where
+    F: Send + Clone + Sync,
+    Op: Debug,
+    D: payments_core::OperationSessionGetters<F>,
+{
+    let payment_id = payment_data.get_payment_intent().get_id().to_owned();
+
+    let captures = payment_data
+        .get_multiple_capture_data()
+        .map(|multiple_capture_data| {
+            multiple_capture_data
+                .get_all_captures()
+                .into_iter()
+                .cloned()
+                .collect()
+        });
+
+    let payments_response = crate::core::payments::transformers::payments_to_payments_response(
+        payment_data,
+        captures,
+        customer,
+        services::AuthFlow::Merchant,
+        &state.base_url,
+        &operation,
+        &state.conf.connector_request_reference_id_config,
+        None,
+        None,
+        None,
+    )?;
+
+    if let services::ApplicationResponse::JsonWithHeaders((payments_response_json, _)) =
+        payments_response
+    {
+        let cloned_state = state.clone();
+        // This spawns this futures in a background thread, the exception inside this future won't affect
+        // the current thread and the lifecycle of spawn thread is not handled by runtime.
+        // So when server shutdown won't wait for this thread's completion.
+        tokio::spawn(
+            async move {
+                let primary_object_created_at = payments_response_json.created;
+                Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+                    cloned_state,
+                    merchant_context.clone(),
+                    business_profile,
+                    event_type,
+                    diesel_models::enums::EventClass::Payments,
+                    payment_id.get_string_repr().to_owned(),
+                    diesel_models::enums::EventObjectType::PaymentDetails,
+                    webhooks::OutgoingWebhookContent::PaymentDetails(Box::new(
+                        payments_response_json,
+                    )),
+                    primary_object_created_at,
+                ))
+                .await
+            }
+            .in_current_span(),
+        );
+    }
+
+    Ok(())
+}
+
 type Handle<T> = tokio::task::JoinHandle<RouterResult<T>>;
 
 pub async fn flatten_join_error<T>(handle: Handle<T>) -> RouterResult<T> {
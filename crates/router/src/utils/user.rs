@@ -501,5 +501,6 @@ pub async fn build_cloned_connector_create_request(
         connector_wallets_details,
         status: Some(source_mca.status),
         additional_merchant_data,
+        fee_schedule: source_mca.fee_schedule.clone(),
     })
 }
@@ -381,6 +381,13 @@ pub async fn generate_sample_data(
             processor_merchant_id: Some(merchant_id.clone()),
             created_by: None,
             setup_future_usage_applied: None,
+            authorization_reversal_status: None,
+            amount_reversed: None,
+            installment_plan: None,
+            currency_conversion: None,
+            cancellation_reason_code: None,
+            authorized_at: None,
+            captured_at: None,
         };
 
         let refund = if refunds_count < number_of_refunds && !is_failed_payment {
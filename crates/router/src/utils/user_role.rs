@@ -31,8 +31,11 @@ pub fn validate_role_groups(groups: &[PermissionGroup]) -> UserResult<()> {
 
     let unique_groups: HashSet<_> = groups.iter().copied().collect();
 
+    // Payment method exports are restricted to the predefined admin roles
     if unique_groups.contains(&PermissionGroup::OrganizationManage)
         || unique_groups.contains(&PermissionGroup::InternalManage)
+        || unique_groups.contains(&PermissionGroup::PaymentMethodExportView)
+        || unique_groups.contains(&PermissionGroup::PaymentMethodExportManage)
     {
         return Err(report!(UserErrors::InvalidRoleOperation))
             .attach_printable("Invalid groups present in the custom role");
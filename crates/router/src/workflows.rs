@@ -2,12 +2,23 @@
 pub mod api_key_expiry;
 #[cfg(feature = "payouts")]
 pub mod attach_payout_account_workflow;
+pub mod authorization_expiry;
+pub mod card_account_updater;
+pub mod network_token_status_check;
 pub mod outgoing_webhook_retry;
+pub mod payment_intent_expiry;
+pub mod payment_ledger;
+pub mod payment_method_export;
+pub mod payment_method_fingerprint_backfill;
 pub mod payment_method_status_update;
+pub mod payment_retry;
 pub mod payment_sync;
+pub mod scheduled_capture;
 
 pub mod refund_router;
 
 pub mod tokenized_data;
 
 pub mod revenue_recovery;
+
+pub mod routing_activation;
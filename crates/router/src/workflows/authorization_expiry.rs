@@ -0,0 +1,471 @@
+use common_utils::ext_traits::{OptionExt, ValueExt};
+use diesel_models::process_tracker::business_status;
+use error_stack::ResultExt;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{
+    core::{
+        errors::{RouterResult, StorageErrorExt},
+        payments::{self as payment_flows, operations},
+    },
+    db::StorageInterface,
+    errors,
+    routes::SessionState,
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums},
+    },
+    utils,
+};
+
+/// Cancellation reason recorded on the payment attempt of a payment which was re-authorized
+const REAUTHORIZATION_CANCELLATION_REASON: &str = "reauthorized";
+
+/// Prefix of the payment id of a re-authorization
+const REAUTHORIZATION_PAYMENT_ID_PREFIX: &str = "reauth";
+
+/// Number of characters of the hash of the re-authorized payment id in the id of its
+/// re-authorization
+const REAUTHORIZATION_PAYMENT_ID_HASH_LENGTH: usize = 32;
+
+pub struct AuthorizationExpiryWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for AuthorizationExpiryWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::AuthorizationExpiryTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("AuthorizationExpiryTrackingData")?;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store.clone(),
+        )));
+
+        let (payment_data, customer) =
+            retrieve_payment(state, &merchant_context, &tracking_data.payment_id).await?;
+
+        // The payment was captured or cancelled before the authorization expired
+        if !matches!(
+            payment_data.payment_intent.status,
+            enums::IntentStatus::RequiresCapture
+                | enums::IntentStatus::PartiallyCapturedAndCapturable
+        ) {
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    business_status::RESOURCE_STATUS_MISMATCH,
+                )
+                .await
+                .map_err(Into::into);
+        }
+
+        let profile_id = payment_data
+            .payment_intent
+            .profile_id
+            .as_ref()
+            .get_required_value("profile_id")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Could not find profile_id in payment intent")?;
+
+        let business_profile = db
+            .find_business_profile_by_profile_id(key_manager_state, &key_store, profile_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+                id: profile_id.get_string_repr().to_owned(),
+            })?;
+
+        let is_reauthorized = if business_profile
+            .is_auto_reauthorization_enabled
+            .unwrap_or(false)
+        {
+            Box::pin(reauthorize_payment(
+                state,
+                &merchant_context,
+                &business_profile,
+                &payment_data,
+            ))
+            .await
+            .map_err(|error| {
+                logger::error!(
+                    %process.id,
+                    payment_id = ?tracking_data.payment_id,
+                    ?error,
+                    "Failed to re-authorize the payment"
+                )
+            })
+            .unwrap_or(false)
+        } else {
+            false
+        };
+
+        // The merchant is notified about the upcoming expiry when the authorization could not be
+        // renewed, so that the payment can be captured before it expires
+        if !is_reauthorized {
+            utils::trigger_payments_webhook_for_event_type(
+                merchant_context,
+                business_profile,
+                payment_data,
+                customer,
+                state,
+                operations::PaymentStatus,
+                enums::EventType::PaymentAuthorizationExpiring,
+            )
+            .map_err(|error| logger::warn!(payments_outgoing_webhook_error=?error))
+            .ok();
+        }
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        Err(sch_errors::ProcessTrackerError::NotImplemented)
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+/// Retrieves the payment without syncing it with the connector
+#[cfg(feature = "v1")]
+async fn retrieve_payment(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_id: &common_utils::id_type::PaymentId,
+) -> RouterResult<(
+    payment_flows::PaymentData<api::PSync>,
+    Option<domain::Customer>,
+)> {
+    let retrieve_request = api::PaymentsRetrieveRequest {
+        resource_id: api::PaymentIdType::PaymentIntentId(payment_id.clone()),
+        merchant_id: Some(merchant_context.get_merchant_account().get_id().clone()),
+        force_sync: false,
+        ..Default::default()
+    };
+
+    // TODO: Add support for ReqState in PT flows
+    let (payment_data, _, customer, _, _) = Box::pin(payment_flows::payments_operation_core::<
+        api::PSync,
+        _,
+        _,
+        _,
+        payment_flows::PaymentData<api::PSync>,
+    >(
+        state,
+        state.get_req_state(),
+        merchant_context,
+        None,
+        operations::PaymentStatus,
+        retrieve_request,
+        payment_flows::CallConnectorAction::Avoid,
+        services::AuthFlow::Merchant,
+        None,
+        hyperswitch_domain_models::payments::HeaderPayload::default(),
+    ))
+    .await?;
+
+    Ok((payment_data, customer))
+}
+
+/// Payment id of the re-authorization of the payment. It is derived from the id of the payment, so
+/// that a retried task finds the re-authorization made by an earlier run of the task instead of
+/// authorizing the payment again.
+fn get_reauthorization_payment_id(
+    payment_id: &common_utils::id_type::PaymentId,
+) -> RouterResult<common_utils::id_type::PaymentId> {
+    let payment_id_hash = blake3::hash(payment_id.get_string_repr().as_bytes())
+        .to_hex()
+        .chars()
+        .take(REAUTHORIZATION_PAYMENT_ID_HASH_LENGTH)
+        .collect::<String>();
+
+    common_utils::id_type::PaymentId::wrap(format!(
+        "{REAUTHORIZATION_PAYMENT_ID_PREFIX}_{payment_id_hash}"
+    ))
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to generate the payment id of the re-authorization")
+}
+
+/// Re-authorizes the capturable amount of the payment as a merchant initiated transaction with the
+/// network transaction id stored on its payment method, and cancels the lapsing authorization once
+/// the new one succeeds. Returns `false` if the payment is not eligible for re-authorization or
+/// the new authorization was not successful.
+#[cfg(feature = "v1")]
+async fn reauthorize_payment(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    business_profile: &domain::Profile,
+    payment_data: &payment_flows::PaymentData<api::PSync>,
+) -> RouterResult<bool> {
+    let payment_intent = &payment_data.payment_intent;
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+    let reauthorization_payment_id = get_reauthorization_payment_id(&payment_intent.payment_id)?;
+
+    let existing_reauthorization = state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            &state.into(),
+            &reauthorization_payment_id,
+            &payment_intent.merchant_id,
+            merchant_context.get_merchant_key_store(),
+            storage_scheme,
+        )
+        .await;
+
+    let reauthorization_status = match existing_reauthorization {
+        // The payment was re-authorized by an earlier run of the task, which failed before the
+        // lapsing authorization was voided
+        Ok(reauthorization_intent) => {
+            logger::info!(
+                reauthorization_payment_id = ?reauthorization_payment_id,
+                "Payment was already re-authorized"
+            );
+            reauthorization_intent.status
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            match create_reauthorization(
+                state,
+                merchant_context,
+                business_profile,
+                payment_data,
+                reauthorization_payment_id.clone(),
+            )
+            .await?
+            {
+                Some(status) => status,
+                None => return Ok(false),
+            }
+        }
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to find the re-authorization of the payment")
+        }
+    };
+
+    if reauthorization_status != enums::IntentStatus::RequiresCapture {
+        logger::info!(
+            reauthorization_payment_id = ?reauthorization_payment_id,
+            status = %reauthorization_status,
+            "Re-authorization of the payment was not successful"
+        );
+        return Ok(false);
+    }
+
+    let cancel_request = api::PaymentsCancelRequest {
+        payment_id: payment_intent.payment_id.clone(),
+        cancellation_reason: Some(REAUTHORIZATION_CANCELLATION_REASON.to_string()),
+        cancellation_reason_code: None,
+        merchant_connector_details: None,
+    };
+
+    // The lapsing authorization is voided, so that the funds are not held twice on the card
+    Box::pin(payment_flows::payments_operation_core::<
+        api::Void,
+        _,
+        _,
+        _,
+        payment_flows::PaymentData<api::Void>,
+    >(
+        state,
+        state.get_req_state(),
+        merchant_context,
+        None,
+        operations::PaymentCancel,
+        cancel_request,
+        payment_flows::CallConnectorAction::Trigger,
+        services::AuthFlow::Merchant,
+        None,
+        hyperswitch_domain_models::payments::HeaderPayload::default(),
+    ))
+    .await
+    .map_err(|error| {
+        logger::error!(
+            ?error,
+            "Failed to void the authorization of the re-authorized payment"
+        )
+    })
+    .ok();
+
+    let (reauthorization_data, reauthorization_customer) =
+        retrieve_payment(state, merchant_context, &reauthorization_payment_id).await?;
+
+    utils::trigger_payments_webhook_for_event_type(
+        merchant_context.clone(),
+        business_profile.clone(),
+        reauthorization_data,
+        reauthorization_customer,
+        state,
+        operations::PaymentStatus,
+        enums::EventType::PaymentReauthorized,
+    )
+    .map_err(|error| logger::warn!(payments_outgoing_webhook_error=?error))
+    .ok();
+
+    Ok(true)
+}
+
+/// Creates the re-authorization of the payment with the given payment id, returning its status, or
+/// `None` if the payment is not eligible for re-authorization
+#[cfg(feature = "v1")]
+async fn create_reauthorization(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    business_profile: &domain::Profile,
+    payment_data: &payment_flows::PaymentData<api::PSync>,
+    reauthorization_payment_id: common_utils::id_type::PaymentId,
+) -> RouterResult<Option<enums::IntentStatus>> {
+    let payment_intent = &payment_data.payment_intent;
+    let payment_attempt = &payment_data.payment_attempt;
+
+    let (Some(payment_method_id), Some(customer_id)) = (
+        payment_attempt.payment_method_id.clone(),
+        payment_intent.customer_id.clone(),
+    ) else {
+        logger::info!(
+            "Payment is not eligible for re-authorization as the payment method was not saved"
+        );
+        return Ok(None);
+    };
+
+    let payment_method = state
+        .store
+        .find_payment_method(
+            &state.into(),
+            merchant_context.get_merchant_key_store(),
+            &payment_method_id,
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    if payment_method.network_transaction_id.is_none() {
+        logger::info!(
+            "Payment is not eligible for re-authorization as the payment method does not have a network transaction id"
+        );
+        return Ok(None);
+    }
+
+    let reauthorization_request = api::PaymentsRequest {
+        amount: Some(payment_attempt.amount_capturable.into()),
+        currency: payment_intent.currency,
+        merchant_id: Some(payment_intent.merchant_id.clone()),
+        capture_method: Some(enums::CaptureMethod::Manual),
+        confirm: Some(true),
+        customer_id: Some(customer_id),
+        off_session: Some(true),
+        description: payment_intent.description.clone(),
+        recurring_details: Some(api_models::mandates::RecurringDetails::PaymentMethodId(
+            payment_method_id,
+        )),
+        metadata: payment_intent.metadata.clone(),
+        merchant_order_reference_id: payment_intent.merchant_order_reference_id.clone(),
+        profile_id: Some(business_profile.get_id().clone()),
+        payment_id: Some(api::PaymentIdType::PaymentIntentId(
+            reauthorization_payment_id,
+        )),
+        ..Default::default()
+    };
+
+    let (reauthorization_data, _, _, _, _) = Box::pin(payment_flows::payments_operation_core::<
+        api::Authorize,
+        _,
+        _,
+        _,
+        payment_flows::PaymentData<api::Authorize>,
+    >(
+        state,
+        state.get_req_state(),
+        merchant_context,
+        Some(business_profile.get_id().clone()),
+        operations::PaymentCreate,
+        reauthorization_request,
+        payment_flows::CallConnectorAction::Trigger,
+        services::AuthFlow::Merchant,
+        None,
+        hyperswitch_domain_models::payments::HeaderPayload::default(),
+    ))
+    .await?;
+
+    Ok(Some(reauthorization_data.payment_intent.status))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_get_reauthorization_payment_id() {
+        let payment_id =
+            common_utils::id_type::PaymentId::wrap("pay_reauth_test".to_string()).unwrap();
+        let reauthorization_payment_id = get_reauthorization_payment_id(&payment_id).unwrap();
+
+        // The id is stable across runs of the task, and differs between payments
+        assert_eq!(
+            reauthorization_payment_id,
+            get_reauthorization_payment_id(&payment_id).unwrap()
+        );
+        assert_ne!(
+            reauthorization_payment_id,
+            get_reauthorization_payment_id(
+                &common_utils::id_type::PaymentId::wrap("pay_reauth_other".to_string()).unwrap()
+            )
+            .unwrap()
+        );
+
+        // Re-authorizations of re-authorizations do not grow the id
+        assert_eq!(
+            get_reauthorization_payment_id(&reauthorization_payment_id)
+                .unwrap()
+                .get_string_repr()
+                .len(),
+            reauthorization_payment_id.get_string_repr().len()
+        );
+    }
+}
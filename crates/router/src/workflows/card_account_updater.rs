@@ -0,0 +1,472 @@
+#[cfg(feature = "v1")]
+use std::collections::HashMap;
+
+#[cfg(feature = "v1")]
+use api_models::payment_methods::Card;
+#[cfg(feature = "v1")]
+use common_utils::{
+    date_time,
+    ext_traits::{Encode, StringExt, ValueExt},
+};
+#[cfg(feature = "v1")]
+use diesel_models::process_tracker::business_status;
+#[cfg(feature = "v1")]
+use hyperswitch_domain_models::payment_methods::{
+    PaymentMethodListConstraints, PaymentMethodListCursor,
+};
+#[cfg(feature = "v1")]
+use hyperswitch_interfaces::card_account_updater::{
+    CardAccountUpdateRequest, CardAccountUpdateResponse,
+};
+#[cfg(feature = "v1")]
+use masking::Secret;
+#[cfg(feature = "v1")]
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+#[cfg(feature = "v1")]
+use crate::{
+    core::{
+        errors::{RouterResult, StorageErrorExt},
+        payment_methods::{
+            card_account_updater::{self, CardAccountUpdaterEnrollment},
+            cards,
+        },
+    },
+    db::StorageInterface,
+    types::{domain, storage::enums},
+};
+use crate::{errors, routes::SessionState, types::storage};
+
+/// Number of times the lookup of a batch of cards is retried when the card account updater fails
+#[cfg(feature = "v1")]
+const MAX_CARD_ACCOUNT_UPDATER_RETRIES: i32 = 3;
+
+pub struct CardAccountUpdaterWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for CardAccountUpdaterWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::CardAccountUpdaterTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("CardAccountUpdaterTrackingData")?;
+        let card_account_updater_conf = &state.conf.card_account_updater;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store,
+        )));
+
+        let redis_conn = db.get_redis_conn()?;
+        let queue_key =
+            card_account_updater::get_card_account_updater_queue_key(&tracking_data.merchant_id);
+
+        // The batch is read without removing it from the queue, and is removed only once it is
+        // processed, so that the cards of a batch which is interrupted are sent again when the
+        // task is retried. Cards are only appended to the queue by enrollments, and only this task
+        // of the merchant removes them, so the batch is still at the head of the queue then.
+        let queued_enrollments = redis_conn
+            .get_list_elements(
+                &queue_key.as_str().into(),
+                0,
+                i64::try_from(card_account_updater_conf.batch_size)
+                    .unwrap_or(i64::MAX)
+                    .saturating_sub(1),
+            )
+            .await?;
+
+        // A card may be queued more than once if it was enrolled again after its earlier
+        // enrollment expired
+        let enrollments = queued_enrollments
+            .iter()
+            .filter_map(|enrollment| {
+                enrollment
+                    .parse_struct::<CardAccountUpdaterEnrollment>("CardAccountUpdaterEnrollment")
+                    .map_err(|error| {
+                        logger::error!(?error, "Failed to parse card account updater enrollment")
+                    })
+                    .ok()
+            })
+            .map(|enrollment| (enrollment.payment_method_id.clone(), enrollment))
+            .collect::<HashMap<_, _>>();
+
+        let mut stored_cards = HashMap::new();
+        for (payment_method_id, enrollment) in enrollments {
+            match get_stored_card(state, &merchant_context, &payment_method_id).await {
+                Ok(Some((payment_method, card))) => {
+                    stored_cards.insert(payment_method_id, (enrollment, payment_method, card));
+                }
+                Ok(None) => {
+                    logger::info!(
+                        %payment_method_id,
+                        "Skipping card account update as the payment method is not an active card"
+                    );
+                }
+                Err(error) => {
+                    logger::error!(
+                        %payment_method_id,
+                        ?error,
+                        "Failed to fetch the stored card for the card account update"
+                    );
+                }
+            }
+        }
+
+        let requests = stored_cards
+            .iter()
+            .map(
+                |(payment_method_id, (_, _, card))| CardAccountUpdateRequest {
+                    reference_id: payment_method_id.clone(),
+                    card_number: Secret::new(card.card_number.get_card_no()),
+                    card_exp_month: card.card_exp_month.clone(),
+                    card_exp_year: card.card_exp_year.clone(),
+                },
+            )
+            .collect::<Vec<_>>();
+
+        if !requests.is_empty() {
+            let updates = match state
+                .card_account_updater_client
+                .get_card_account_updates(requests)
+                .await
+            {
+                Ok(updates) => updates,
+                Err(error) => {
+                    logger::error!(
+                        ?error,
+                        "Failed to get the updates of the stored cards from the card account updater"
+                    );
+
+                    // The cards are left in the queue, so that they are sent to the card account
+                    // updater when the task is retried, or when it is scheduled again by the next
+                    // enrollment if the retries are exhausted
+                    return if process.retry_count < MAX_CARD_ACCOUNT_UPDATER_RETRIES {
+                        let schedule_time = get_next_batch_schedule_time(state);
+                        db.as_scheduler()
+                            .retry_process(process, schedule_time)
+                            .await
+                            .map_err(Into::into)
+                    } else {
+                        db.as_scheduler()
+                            .finish_process_with_business_status(process, business_status::FAILURE)
+                            .await
+                            .map_err(Into::into)
+                    };
+                }
+            };
+
+            for update in updates {
+                let Some((enrollment, payment_method, card)) =
+                    stored_cards.remove(&update.reference_id)
+                else {
+                    logger::warn!(
+                        reference_id = %update.reference_id,
+                        "Received an update from the card account updater for an unknown card"
+                    );
+                    continue;
+                };
+
+                let payment_method_id = update.reference_id.clone();
+                apply_card_account_update(
+                    state,
+                    &merchant_context,
+                    enrollment,
+                    payment_method,
+                    card,
+                    update,
+                )
+                .await
+                .map_err(|error| {
+                    logger::error!(
+                        %payment_method_id,
+                        ?error,
+                        "Failed to apply the update of the stored card"
+                    )
+                })
+                .ok();
+            }
+        }
+
+        redis_conn
+            .lpop_list_elements(&queue_key.as_str().into(), Some(queued_enrollments.len()))
+            .await?;
+
+        // The remaining cards of the queue are sent in the next batch
+        if redis_conn
+            .get_list_length(&queue_key.as_str().into())
+            .await?
+            > 0
+        {
+            let schedule_time = get_next_batch_schedule_time(state);
+            db.as_scheduler()
+                .reset_process(process, schedule_time)
+                .await?;
+        } else {
+            db.as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        Err(sch_errors::ProcessTrackerError::NotImplemented)
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+pub struct CardExpiryScanWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for CardExpiryScanWorkflow {
+    /// Scans a page of the stored cards of the merchant, enrolling the expiring cards for an
+    /// update. The task is scheduled right away for the next page, and after the scan interval
+    /// for the next scan once all the stored cards are scanned.
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::CardExpiryScanTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("CardExpiryScanTrackingData")?;
+        let card_account_updater_conf = &state.conf.card_account_updater;
+
+        // The task is added again when a card is saved after the card account updater is enabled
+        if !card_account_updater_conf.provider.is_enabled() {
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await
+                .map_err(Into::into);
+        }
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let scan_started_at = tracking_data.scan_started_at.unwrap_or_else(date_time::now);
+        let constraints = PaymentMethodListConstraints {
+            status: enums::PaymentMethodStatus::Active,
+            created_before: scan_started_at,
+            after: tracking_data.cursor.clone(),
+            limit: i64::try_from(card_account_updater_conf.batch_size).unwrap_or(i64::MAX),
+        };
+        let payment_methods = db
+            .find_payment_method_list_by_merchant_id_constraints(
+                key_manager_state,
+                &key_store,
+                &tracking_data.merchant_id,
+                &constraints,
+            )
+            .await?;
+
+        card_account_updater::enroll_expiring_cards_for_card_account_update(
+            state,
+            &payment_methods,
+            merchant_account.default_profile.as_ref(),
+        )
+        .await;
+
+        let (updated_tracking_data, schedule_time) = match payment_methods.last() {
+            Some(last_payment_method)
+                if payment_methods.len() == card_account_updater_conf.batch_size =>
+            {
+                (
+                    storage::CardExpiryScanTrackingData {
+                        scan_started_at: Some(scan_started_at),
+                        cursor: Some(PaymentMethodListCursor::from(last_payment_method)),
+                        ..tracking_data
+                    },
+                    date_time::now(),
+                )
+            }
+            _ => (
+                storage::CardExpiryScanTrackingData {
+                    scan_started_at: None,
+                    cursor: None,
+                    ..tracking_data
+                },
+                scan_started_at.saturating_add(time::Duration::seconds(
+                    card_account_updater_conf.expiry_scan_interval_in_seconds,
+                )),
+            ),
+        };
+
+        db.as_scheduler()
+            .update_process(
+                process,
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: Some(0),
+                    schedule_time: Some(schedule_time),
+                    tracking_data: Some(updated_tracking_data.encode_to_value()?),
+                    business_status: Some(String::from(business_status::PENDING)),
+                    status: Some(enums::ProcessTrackerStatus::New),
+                    updated_at: Some(date_time::now()),
+                },
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        Err(sch_errors::ProcessTrackerError::NotImplemented)
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+#[cfg(feature = "v1")]
+fn get_next_batch_schedule_time(state: &SessionState) -> time::PrimitiveDateTime {
+    date_time::now().saturating_add(time::Duration::seconds(
+        state.conf.card_account_updater.batch_interval_in_seconds,
+    ))
+}
+
+/// Fetches the card stored in the locker for the payment method, if the payment method is an
+/// active card
+#[cfg(feature = "v1")]
+async fn get_stored_card(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_method_id: &str,
+) -> RouterResult<Option<(domain::PaymentMethod, Card)>> {
+    let payment_method = state
+        .store
+        .find_payment_method(
+            &state.into(),
+            merchant_context.get_merchant_key_store(),
+            payment_method_id,
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    if payment_method.get_payment_method_type() != Some(enums::PaymentMethod::Card)
+        || payment_method.status != enums::PaymentMethodStatus::Active
+    {
+        return Ok(None);
+    }
+
+    let card = cards::get_card_from_locker(
+        state,
+        &payment_method.customer_id,
+        &payment_method.merchant_id,
+        payment_method
+            .locker_id
+            .as_ref()
+            .unwrap_or(&payment_method.payment_method_id),
+    )
+    .await?;
+
+    Ok(Some((payment_method, card)))
+}
+
+/// Applies the update of the stored card, notifying the profile for which the card was enrolled
+#[cfg(feature = "v1")]
+async fn apply_card_account_update(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    enrollment: CardAccountUpdaterEnrollment,
+    payment_method: domain::PaymentMethod,
+    card: Card,
+    update: CardAccountUpdateResponse,
+) -> RouterResult<()> {
+    let business_profile = match enrollment.profile_id {
+        Some(profile_id) => Some(
+            state
+                .store
+                .find_business_profile_by_profile_id(
+                    &state.into(),
+                    merchant_context.get_merchant_key_store(),
+                    &profile_id,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+                    id: profile_id.get_string_repr().to_owned(),
+                })?,
+        ),
+        None => None,
+    };
+
+    card_account_updater::apply_card_account_update(
+        state,
+        merchant_context,
+        business_profile.as_ref(),
+        payment_method,
+        card,
+        update,
+    )
+    .await
+}
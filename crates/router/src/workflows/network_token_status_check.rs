@@ -0,0 +1,304 @@
+#[cfg(feature = "v1")]
+use common_utils::{
+    date_time,
+    ext_traits::{Encode, ValueExt},
+};
+#[cfg(feature = "v1")]
+use diesel_models::process_tracker::business_status;
+#[cfg(feature = "v1")]
+use hyperswitch_domain_models::payment_methods::{
+    PaymentMethodListConstraints, PaymentMethodListCursor,
+};
+#[cfg(feature = "v1")]
+use masking::{PeekInterface, Secret};
+#[cfg(feature = "v1")]
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+#[cfg(feature = "v1")]
+use crate::{
+    configs::settings,
+    core::{
+        errors::RouterResult,
+        payment_methods::{
+            network_token_lifecycle::{self, NetworkTokenDetailsUpdate},
+            network_tokenization,
+        },
+    },
+    db::StorageInterface,
+    types::{domain, storage::enums},
+};
+use crate::{errors, routes::SessionState, types::storage};
+
+pub struct NetworkTokenStatusCheckWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for NetworkTokenStatusCheckWorkflow {
+    /// Checks the network tokens of a page of the payment methods of the merchant with the token
+    /// service. The task is scheduled right away for the next page, and after the status check
+    /// interval for the next check once all the payment methods are checked.
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::NetworkTokenStatusCheckTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("NetworkTokenStatusCheckTrackingData")?;
+        let network_token_lifecycle_conf = &state.conf.network_token_lifecycle;
+
+        let Some(network_tokenization_service) = state.conf.network_tokenization_service.as_ref()
+        else {
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await
+                .map_err(Into::into);
+        };
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let check_started_at = tracking_data
+            .check_started_at
+            .unwrap_or_else(date_time::now);
+        let constraints = PaymentMethodListConstraints {
+            status: enums::PaymentMethodStatus::Active,
+            created_before: check_started_at,
+            after: tracking_data.cursor.clone(),
+            limit: i64::try_from(network_token_lifecycle_conf.status_check_batch_size)
+                .unwrap_or(i64::MAX),
+        };
+        let payment_methods = db
+            .find_payment_method_list_by_merchant_id_constraints(
+                key_manager_state,
+                &key_store,
+                &tracking_data.merchant_id,
+                &constraints,
+            )
+            .await?;
+        let next_cursor = payment_methods
+            .last()
+            .filter(|_| {
+                payment_methods.len() == network_token_lifecycle_conf.status_check_batch_size
+            })
+            .map(PaymentMethodListCursor::from);
+
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store,
+        )));
+
+        let mut network_tokens_found = tracking_data.network_tokens_found;
+        for payment_method in payment_methods
+            .into_iter()
+            .filter(network_token_lifecycle::is_network_token_status_check_applicable)
+        {
+            network_tokens_found = true;
+            check_network_token_status(
+                state,
+                &merchant_context,
+                payment_method,
+                network_tokenization_service.get_inner(),
+            )
+            .await;
+        }
+
+        let (updated_tracking_data, schedule_time) = match next_cursor {
+            Some(cursor) => (
+                storage::NetworkTokenStatusCheckTrackingData {
+                    check_started_at: Some(check_started_at),
+                    cursor: Some(cursor),
+                    network_tokens_found,
+                    ..tracking_data
+                },
+                date_time::now(),
+            ),
+            // The task is added again when a network token is stored for the merchant
+            None if !network_tokens_found => {
+                return db
+                    .as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await
+                    .map_err(Into::into);
+            }
+            None => (
+                storage::NetworkTokenStatusCheckTrackingData {
+                    check_started_at: None,
+                    cursor: None,
+                    network_tokens_found: false,
+                    ..tracking_data
+                },
+                check_started_at.saturating_add(time::Duration::hours(
+                    network_token_lifecycle_conf.status_check_interval_in_hours,
+                )),
+            ),
+        };
+
+        db.as_scheduler()
+            .update_process(
+                process,
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: Some(0),
+                    schedule_time: Some(schedule_time),
+                    tracking_data: Some(updated_tracking_data.encode_to_value()?),
+                    business_status: Some(String::from(business_status::PENDING)),
+                    status: Some(enums::ProcessTrackerStatus::New),
+                    updated_at: Some(date_time::now()),
+                },
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        Err(sch_errors::ProcessTrackerError::NotImplemented)
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+/// Checks the status of the network token of the payment method with the token service, and
+/// propagates the changes to the payment method. Failures are logged, and the token is checked
+/// again in the next check.
+#[cfg(feature = "v1")]
+async fn check_network_token_status(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_method: domain::PaymentMethod,
+    network_tokenization_service: &settings::NetworkTokenizationService,
+) {
+    let Some(network_token_requestor_reference_id) =
+        payment_method.network_token_requestor_reference_id.clone()
+    else {
+        return;
+    };
+    let payment_method_id = payment_method.payment_method_id.clone();
+
+    let token_status = match network_tokenization::get_token_status_from_tokenization_service(
+        state,
+        &payment_method.customer_id,
+        network_token_requestor_reference_id,
+        network_tokenization_service,
+    )
+    .await
+    {
+        Ok(token_status) => token_status,
+        Err(error) => {
+            logger::error!(
+                %payment_method_id,
+                ?error,
+                "Failed to check the network token status with the token service"
+            );
+            return;
+        }
+    };
+
+    update_network_token_status(
+        state,
+        merchant_context,
+        payment_method,
+        enums::NetworkTokenStatus::from(token_status.token_status),
+        token_status.token_expiry_month,
+        token_status.token_expiry_year,
+    )
+    .await
+    .map_err(|error| {
+        logger::error!(
+            %payment_method_id,
+            ?error,
+            "Failed to update the network token status of the payment method"
+        )
+    })
+    .ok();
+}
+
+/// Propagates the status and the expiry of the network token received from the token service to
+/// the payment method, if either of them changed
+#[cfg(feature = "v1")]
+async fn update_network_token_status(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_method: domain::PaymentMethod,
+    network_token_status: enums::NetworkTokenStatus,
+    token_expiry_month: Secret<String>,
+    token_expiry_year: Secret<String>,
+) -> RouterResult<()> {
+    let network_token_details_update = (network_token_status == enums::NetworkTokenStatus::Active)
+        .then(|| {
+            network_token_lifecycle::get_network_token_details_from_payment_method(&payment_method)
+        })
+        .flatten()
+        .filter(|token_details| {
+            token_details.expiry_month.as_ref().map(PeekInterface::peek)
+                != Some(token_expiry_month.peek())
+                || token_details.expiry_year.as_ref().map(PeekInterface::peek)
+                    != Some(token_expiry_year.peek())
+        })
+        .map(|_| NetworkTokenDetailsUpdate {
+            token_expiry_month: Some(token_expiry_month),
+            token_expiry_year: Some(token_expiry_year),
+            token_last_four: None,
+        });
+
+    // Tokens stored before their status was tracked are considered to be active
+    let current_network_token_status = payment_method
+        .network_token_status
+        .unwrap_or(enums::NetworkTokenStatus::Active);
+
+    if current_network_token_status == network_token_status
+        && network_token_details_update.is_none()
+    {
+        return Ok(());
+    }
+
+    logger::info!(
+        payment_method_id = %payment_method.payment_method_id,
+        %network_token_status,
+        "Updating the network token of the payment method"
+    );
+
+    network_token_lifecycle::update_network_token_details(
+        state,
+        merchant_context,
+        payment_method,
+        network_token_status,
+        network_token_details_update,
+    )
+    .await
+    .map(|_| ())
+}
@@ -358,8 +358,10 @@ async fn get_outgoing_webhook_content_and_event_type(
     key_store: domain::MerchantKeyStore,
     tracking_data: &OutgoingWebhookTrackingData,
 ) -> Result<(OutgoingWebhookContent, Option<EventType>), errors::ProcessTrackerError> {
+    use ::payment_methods::controller::PaymentMethodsController;
     use api_models::{
         mandates::MandateId,
+        payment_methods::PaymentMethodId,
         payments::{PaymentIdType, PaymentsResponse, PaymentsRetrieveRequest},
         refunds::{RefundResponse, RefundsRetrieveRequest},
     };
@@ -368,6 +370,7 @@ async fn get_outgoing_webhook_content_and_event_type(
         core::{
             disputes::retrieve_dispute,
             mandate::get_mandate,
+            payment_methods::cards::PmCards,
             payments::{payments_core, CallConnectorAction, PaymentStatus},
             refunds::refund_retrieve_core_with_refund_id,
         },
@@ -432,7 +435,8 @@ async fn get_outgoing_webhook_content_and_event_type(
                 | ApplicationResponse::Form(_)
                 | ApplicationResponse::GenericLinkForm(_)
                 | ApplicationResponse::PaymentLinkForm(_)
-                | ApplicationResponse::FileData(_) => {
+                | ApplicationResponse::FileData(_)
+                | ApplicationResponse::Replayed(_) => {
                     Err(errors::ProcessTrackerError::ResourceFetchingFailed {
                         resource_name: tracking_data.primary_object_id.clone(),
                     })
@@ -488,7 +492,8 @@ async fn get_outgoing_webhook_content_and_event_type(
                     | ApplicationResponse::Form(_)
                     | ApplicationResponse::GenericLinkForm(_)
                     | ApplicationResponse::PaymentLinkForm(_)
-                    | ApplicationResponse::FileData(_) => {
+                    | ApplicationResponse::FileData(_)
+                    | ApplicationResponse::Replayed(_) => {
                         Err(errors::ProcessTrackerError::ResourceFetchingFailed {
                             resource_name: tracking_data.primary_object_id.clone(),
                         })
@@ -520,7 +525,8 @@ async fn get_outgoing_webhook_content_and_event_type(
                     | ApplicationResponse::Form(_)
                     | ApplicationResponse::GenericLinkForm(_)
                     | ApplicationResponse::PaymentLinkForm(_)
-                    | ApplicationResponse::FileData(_) => {
+                    | ApplicationResponse::FileData(_)
+                    | ApplicationResponse::Replayed(_) => {
                         Err(errors::ProcessTrackerError::ResourceFetchingFailed {
                             resource_name: tracking_data.primary_object_id.clone(),
                         })
@@ -535,6 +541,42 @@ async fn get_outgoing_webhook_content_and_event_type(
                 event_type,
             ))
         }
+
+        diesel_models::enums::EventClass::PaymentMethods => {
+            let payment_method_id = tracking_data.primary_object_id.clone();
+            let request = PaymentMethodId { payment_method_id };
+
+            let cards = PmCards {
+                state: &state,
+                merchant_context: &merchant_context,
+            };
+            let payment_method_response = match cards.retrieve_payment_method(request).await? {
+                ApplicationResponse::Json(payment_method_response)
+                | ApplicationResponse::JsonWithHeaders((payment_method_response, _)) => {
+                    Ok(payment_method_response)
+                }
+                ApplicationResponse::StatusOk
+                | ApplicationResponse::TextPlain(_)
+                | ApplicationResponse::JsonForRedirection(_)
+                | ApplicationResponse::Form(_)
+                | ApplicationResponse::GenericLinkForm(_)
+                | ApplicationResponse::PaymentLinkForm(_)
+                | ApplicationResponse::FileData(_)
+                | ApplicationResponse::Replayed(_) => {
+                    Err(errors::ProcessTrackerError::ResourceFetchingFailed {
+                        resource_name: tracking_data.primary_object_id.clone(),
+                    })
+                }
+            }
+            .map(Box::new)?;
+
+            // Payment method webhooks are only sent for updates of the payment method, which are
+            // not reflected in its status
+            Ok((
+                OutgoingWebhookContent::PaymentMethodDetails(payment_method_response),
+                Some(EventType::PaymentMethodUpdated),
+            ))
+        }
         #[cfg(feature = "payouts")]
         diesel_models::enums::EventClass::Payouts => {
             let payout_id = tracking_data.primary_object_id.clone();
@@ -0,0 +1,246 @@
+use common_utils::ext_traits::{OptionExt, ValueExt};
+use diesel_models::process_tracker::business_status;
+use error_stack::ResultExt;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{
+    core::{
+        errors::StorageErrorExt,
+        payments::{self as payment_flows, operations},
+    },
+    db::StorageInterface,
+    errors,
+    routes::SessionState,
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums},
+    },
+    utils,
+};
+
+/// Cancellation reason recorded on the payment attempt of an expired payment
+const PAYMENT_INTENT_EXPIRY_CANCELLATION_REASON: &str = "payment_intent_expired";
+
+pub struct PaymentIntentExpiryWorkflow;
+
+/// Whether a payment in the status expires once its expiry is reached. Authorized payments are
+/// left to expire with their authorization, so that the funds held for them are not released.
+fn is_payment_intent_expirable(status: enums::IntentStatus) -> bool {
+    matches!(
+        status,
+        enums::IntentStatus::RequiresPaymentMethod
+            | enums::IntentStatus::RequiresConfirmation
+            | enums::IntentStatus::RequiresCustomerAction
+    )
+}
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PaymentIntentExpiryWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::PaymentIntentExpiryTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentIntentExpiryTrackingData")?;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                key_manager_state,
+                &tracking_data.payment_id,
+                &tracking_data.merchant_id,
+                &key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        // The payment was authorized, completed, failed or cancelled before it expired
+        if !is_payment_intent_expirable(payment_intent.status) {
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    business_status::RESOURCE_STATUS_MISMATCH,
+                )
+                .await
+                .map_err(Into::into);
+        }
+
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account.clone(),
+            key_store.clone(),
+        )));
+
+        // The payment is cancelled through the cancel flow, which also cancels the payment with
+        // the connector if it is awaiting a customer action there
+        let cancel_request = api::PaymentsCancelRequest {
+            payment_id: tracking_data.payment_id.clone(),
+            cancellation_reason: Some(PAYMENT_INTENT_EXPIRY_CANCELLATION_REASON.to_string()),
+            cancellation_reason_code: Some(enums::PaymentCancellationReason::Abandoned),
+            merchant_connector_details: None,
+        };
+
+        // TODO: Add support for ReqState in PT flows
+        let (mut payment_data, _, customer, _, _) =
+            Box::pin(payment_flows::payments_operation_core::<
+                api::Void,
+                _,
+                _,
+                _,
+                payment_flows::PaymentData<api::Void>,
+            >(
+                state,
+                state.get_req_state(),
+                &merchant_context,
+                None,
+                operations::PaymentCancel,
+                cancel_request,
+                payment_flows::CallConnectorAction::Trigger,
+                services::AuthFlow::Merchant,
+                None,
+                hyperswitch_domain_models::payments::HeaderPayload::default(),
+            ))
+            .await?;
+
+        if payment_data.payment_intent.status != enums::IntentStatus::Cancelled {
+            logger::error!(
+                %process.id,
+                payment_id = ?tracking_data.payment_id,
+                status = %payment_data.payment_intent.status,
+                "Failed to void the authorization of the expired payment"
+            );
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::FAILURE)
+                .await
+                .map_err(Into::into);
+        }
+
+        let payment_intent_update = storage::PaymentIntentUpdate::PGStatusUpdate {
+            status: enums::IntentStatus::Expired,
+            updated_by: merchant_account.storage_scheme.to_string(),
+            incremental_authorization_allowed: Some(false),
+        };
+
+        payment_data.payment_intent = db
+            .update_payment_intent(
+                key_manager_state,
+                payment_data.payment_intent,
+                payment_intent_update,
+                &key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        let profile_id = payment_data
+            .payment_intent
+            .profile_id
+            .as_ref()
+            .get_required_value("profile_id")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Could not find profile_id in payment intent")?;
+
+        let business_profile = db
+            .find_business_profile_by_profile_id(key_manager_state, &key_store, profile_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+                id: profile_id.get_string_repr().to_owned(),
+            })?;
+
+        // Trigger the outgoing webhook to notify the merchant about the expired payment
+        Box::pin(utils::trigger_payments_webhook(
+            merchant_context,
+            business_profile,
+            payment_data,
+            customer,
+            state,
+            operations::PaymentCancel,
+        ))
+        .await
+        .map_err(|error| logger::warn!(payments_outgoing_webhook_error=?error))
+        .ok();
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        Err(sch_errors::ProcessTrackerError::NotImplemented)
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_payment_intent_expirable() {
+        assert!(is_payment_intent_expirable(
+            enums::IntentStatus::RequiresPaymentMethod
+        ));
+        assert!(is_payment_intent_expirable(
+            enums::IntentStatus::RequiresConfirmation
+        ));
+        assert!(is_payment_intent_expirable(
+            enums::IntentStatus::RequiresCustomerAction
+        ));
+
+        for status in [
+            enums::IntentStatus::RequiresCapture,
+            enums::IntentStatus::PartiallyCapturedAndCapturable,
+            enums::IntentStatus::Processing,
+            enums::IntentStatus::Succeeded,
+            enums::IntentStatus::PartiallyCaptured,
+            enums::IntentStatus::Failed,
+            enums::IntentStatus::Cancelled,
+            enums::IntentStatus::Expired,
+        ] {
+            assert!(!is_payment_intent_expirable(status), "{status}");
+        }
+    }
+}
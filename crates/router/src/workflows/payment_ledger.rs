@@ -0,0 +1,108 @@
+#[cfg(feature = "v1")]
+use common_utils::{date_time, ext_traits::ValueExt};
+#[cfg(feature = "v1")]
+use diesel_models::process_tracker::business_status;
+#[cfg(feature = "v1")]
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+#[cfg(feature = "v1")]
+use crate::{core::ledger, db::StorageInterface};
+use crate::{errors, routes::SessionState, types::storage};
+
+/// Number of times the ledger task records the missing entries of a payment before giving up
+#[cfg(feature = "v1")]
+const MAX_PAYMENT_LEDGER_RETRIES: i32 = 5;
+
+pub struct PaymentLedgerWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PaymentLedgerWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::PaymentLedgerTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentLedgerTrackingData")?;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        if let Err(error) = ledger::record_missing_entries(
+            state,
+            &merchant_account,
+            &key_store,
+            &tracking_data.payment_id,
+        )
+        .await
+        {
+            logger::error!(
+                payment_id = ?tracking_data.payment_id,
+                ?error,
+                "Failed to record the missing ledger entries of the payment"
+            );
+
+            return if process.retry_count < MAX_PAYMENT_LEDGER_RETRIES {
+                let schedule_time = date_time::now().saturating_add(time::Duration::seconds(
+                    ledger::PAYMENT_LEDGER_RETRY_INTERVAL_IN_SECONDS
+                        * i64::from(process.retry_count + 1),
+                ));
+                db.as_scheduler()
+                    .retry_process(process, schedule_time)
+                    .await
+                    .map_err(Into::into)
+            } else {
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::FAILURE)
+                    .await
+                    .map_err(Into::into)
+            };
+        }
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        Err(sch_errors::ProcessTrackerError::NotImplemented)
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,165 @@
+#[cfg(feature = "v1")]
+use common_utils::{
+    date_time,
+    ext_traits::{Encode, ValueExt},
+};
+#[cfg(feature = "v1")]
+use diesel_models::process_tracker::business_status;
+#[cfg(feature = "v1")]
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+#[cfg(feature = "v1")]
+use crate::{
+    core::payment_methods::export,
+    db::StorageInterface,
+    events::audit_events::{AuditEvent, AuditEventType},
+    types::{domain, storage::enums},
+};
+use crate::{errors, routes::SessionState, types::storage};
+
+/// Number of times the generation of an export is retried before the export is marked as failed
+#[cfg(feature = "v1")]
+const MAX_PAYMENT_METHOD_EXPORT_RETRIES: i32 = 3;
+
+/// Time after which the generation of an export is retried
+#[cfg(feature = "v1")]
+const PAYMENT_METHOD_EXPORT_RETRY_INTERVAL_IN_SECONDS: i64 = 5 * 60;
+
+pub struct PaymentMethodExportWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PaymentMethodExportWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::PaymentMethodExportTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentMethodExportTrackingData")?;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store,
+        )));
+
+        // The parts generated before a failure are kept, and the retried export continues after
+        // the last of them
+        let updated_tracking_data = match export::generate_payment_method_export(
+            state,
+            &merchant_context,
+            &process,
+            tracking_data.clone(),
+        )
+        .await
+        {
+            Ok(updated_tracking_data) => updated_tracking_data,
+            Err(error) => {
+                logger::error!(
+                    export_id = %tracking_data.export_id,
+                    ?error,
+                    "Failed to generate the payment method export"
+                );
+
+                return if process.retry_count < MAX_PAYMENT_METHOD_EXPORT_RETRIES {
+                    let schedule_time = date_time::now().saturating_add(time::Duration::seconds(
+                        PAYMENT_METHOD_EXPORT_RETRY_INTERVAL_IN_SECONDS,
+                    ));
+                    db.as_scheduler()
+                        .retry_process(process, schedule_time)
+                        .await
+                        .map_err(Into::into)
+                } else {
+                    db.as_scheduler()
+                        .finish_process_with_business_status(process, business_status::FAILURE)
+                        .await?;
+
+                    state
+                        .get_req_state()
+                        .event_context
+                        .event(AuditEvent::new(AuditEventType::PaymentMethodExportFailed {
+                            merchant_id: tracking_data.merchant_id,
+                            export_id: tracking_data.export_id,
+                            error_message: error.current_context().to_string(),
+                        }))
+                        .emit();
+
+                    Ok(())
+                };
+            }
+        };
+
+        db.as_scheduler()
+            .update_process(
+                process,
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: None,
+                    schedule_time: None,
+                    tracking_data: Some(updated_tracking_data.encode_to_value()?),
+                    business_status: Some(String::from(business_status::COMPLETED_BY_PT)),
+                    status: Some(enums::ProcessTrackerStatus::Finish),
+                    updated_at: Some(date_time::now()),
+                },
+            )
+            .await?;
+
+        state
+            .get_req_state()
+            .event_context
+            .event(AuditEvent::new(
+                AuditEventType::PaymentMethodExportCompleted {
+                    merchant_id: tracking_data.merchant_id,
+                    export_id: tracking_data.export_id,
+                    payment_methods_count: updated_tracking_data
+                        .payment_methods_count
+                        .unwrap_or_default(),
+                },
+            ))
+            .emit();
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        Err(sch_errors::ProcessTrackerError::NotImplemented)
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,154 @@
+#[cfg(feature = "v2")]
+use common_utils::{
+    date_time,
+    ext_traits::{Encode, ValueExt},
+};
+#[cfg(feature = "v2")]
+use diesel_models::process_tracker::business_status;
+#[cfg(feature = "v2")]
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+#[cfg(feature = "v2")]
+use crate::{
+    core::payment_methods::duplicates,
+    db::StorageInterface,
+    types::{domain, storage::enums},
+};
+use crate::{errors, routes::SessionState, types::storage};
+
+/// Number of times a page of the backfill is retried before the backfill is marked as failed
+#[cfg(feature = "v2")]
+const MAX_FINGERPRINT_BACKFILL_RETRIES: i32 = 3;
+
+/// Time after which a failed page of the backfill is retried
+#[cfg(feature = "v2")]
+const FINGERPRINT_BACKFILL_RETRY_INTERVAL_IN_SECONDS: i64 = 5 * 60;
+
+pub struct PaymentMethodFingerprintBackfillWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PaymentMethodFingerprintBackfillWorkflow {
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::PaymentMethodFingerprintBackfillTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentMethodFingerprintBackfillTrackingData")?;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store,
+        )));
+
+        let after = match duplicates::backfill_fingerprint_ids(
+            state,
+            &merchant_context,
+            tracking_data.after.as_ref(),
+        )
+        .await
+        {
+            Ok(after) => after,
+            Err(error) => {
+                logger::error!(
+                    merchant_id = ?tracking_data.merchant_id,
+                    ?error,
+                    "Failed to backfill the fingerprints of the payment methods"
+                );
+
+                return if process.retry_count < MAX_FINGERPRINT_BACKFILL_RETRIES {
+                    let schedule_time = date_time::now().saturating_add(time::Duration::seconds(
+                        FINGERPRINT_BACKFILL_RETRY_INTERVAL_IN_SECONDS,
+                    ));
+                    db.as_scheduler()
+                        .retry_process(process, schedule_time)
+                        .await
+                        .map_err(Into::into)
+                } else {
+                    db.as_scheduler()
+                        .finish_process_with_business_status(process, business_status::FAILURE)
+                        .await
+                        .map_err(Into::into)
+                };
+            }
+        };
+
+        // The next page is backfilled in the next run, so that a merchant with many payment
+        // methods does not hold the consumer
+        let process_tracker_update = match after {
+            Some(after) => storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: Some(0),
+                schedule_time: Some(date_time::now()),
+                tracking_data: Some(
+                    storage::PaymentMethodFingerprintBackfillTrackingData {
+                        merchant_id: tracking_data.merchant_id,
+                        after: Some(after),
+                    }
+                    .encode_to_value()?,
+                ),
+                business_status: Some(String::from(business_status::PENDING)),
+                status: Some(enums::ProcessTrackerStatus::Pending),
+                updated_at: Some(date_time::now()),
+            },
+            None => storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: None,
+                schedule_time: None,
+                tracking_data: None,
+                business_status: Some(String::from(business_status::COMPLETED_BY_PT)),
+                status: Some(enums::ProcessTrackerStatus::Finish),
+                updated_at: Some(date_time::now()),
+            },
+        };
+
+        db.as_scheduler()
+            .update_process(process, process_tracker_update)
+            .await?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        Err(sch_errors::ProcessTrackerError::NotImplemented)
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,177 @@
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::{Encode, ValueExt};
+#[cfg(feature = "v1")]
+use diesel_models::process_tracker::business_status;
+#[cfg(feature = "v1")]
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+#[cfg(feature = "v1")]
+use crate::{
+    core::{
+        errors::StorageErrorExt,
+        payments::{self as payment_flows, operations},
+    },
+    db::StorageInterface,
+    services,
+    types::{api, domain, storage::enums},
+};
+use crate::{errors, routes::SessionState, types::storage};
+
+pub struct PaymentRetryWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PaymentRetryWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::PaymentRetryTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentRetryTrackingData")?;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                key_manager_state,
+                &tracking_data.payment_id,
+                &tracking_data.merchant_id,
+                &key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        // The payment was retried by the merchant, or succeeded through a webhook of the
+        // connector, before the retry was due
+        if payment_intent.status != enums::IntentStatus::Failed
+            || payment_intent.active_attempt.get_id() != tracking_data.attempt_id
+        {
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    business_status::RESOURCE_STATUS_MISMATCH,
+                )
+                .await
+                .map_err(Into::into);
+        }
+
+        let payment_attempt = db
+            .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+                &tracking_data.payment_id,
+                &tracking_data.merchant_id,
+                &tracking_data.attempt_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store,
+        )));
+
+        // The payment is retried with a new attempt, with the connectors chosen when the
+        // payment failed
+        let routing =
+            api_models::routing::StraightThroughAlgorithm::Priority(tracking_data.connectors)
+                .encode_to_value()?;
+
+        let retry_request = api::PaymentsRequest {
+            payment_id: Some(api::PaymentIdType::PaymentIntentId(
+                tracking_data.payment_id.clone(),
+            )),
+            merchant_id: Some(tracking_data.merchant_id.clone()),
+            confirm: Some(true),
+            retry_action: Some(api_models::enums::RetryAction::ManualRetry),
+            payment_token: Some(tracking_data.payment_token),
+            payment_method: payment_attempt.payment_method,
+            payment_method_type: payment_attempt.payment_method_type,
+            routing: Some(routing),
+            ..Default::default()
+        };
+
+        // TODO: Add support for ReqState in PT flows
+        let retry_result = Box::pin(payment_flows::payments_operation_core::<
+            api::Authorize,
+            _,
+            _,
+            _,
+            payment_flows::PaymentData<api::Authorize>,
+        >(
+            state,
+            state.get_req_state(),
+            &merchant_context,
+            None,
+            operations::PaymentConfirm,
+            retry_request,
+            payment_flows::CallConnectorAction::Trigger,
+            services::AuthFlow::Merchant,
+            None,
+            hyperswitch_domain_models::payments::HeaderPayload::default(),
+        ))
+        .await;
+
+        // The retry is not repeated on errors, as the payment may have been sent to the connector
+        let retry_business_status = match retry_result {
+            Ok(_) => business_status::COMPLETED_BY_PT,
+            Err(error) => {
+                logger::error!(
+                    %process.id,
+                    payment_id = ?tracking_data.payment_id,
+                    ?error,
+                    "Failed to retry the payment after the retry delay"
+                );
+                business_status::FAILURE
+            }
+        };
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, retry_business_status)
+            .await?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        Err(sch_errors::ProcessTrackerError::NotImplemented)
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,96 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use scheduler::consumer::{self, workflows::ProcessTrackerWorkflow};
+
+use crate::{
+    errors,
+    logger::error,
+    routes::SessionState,
+    types::{
+        domain,
+        storage::{self, RoutingActivationTrackingData},
+    },
+};
+
+pub struct RoutingActivationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for RoutingActivationWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: RoutingActivationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("RoutingActivationTrackingData")?;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store,
+        )));
+
+        let activation_result = crate::core::routing::link_routing_config(
+            state.clone(),
+            merchant_context,
+            Some(tracking_data.profile_id),
+            tracking_data.algorithm_id,
+            tracking_data.transaction_type,
+        )
+        .await;
+
+        // A failed activation is not retried, as a retry would activate the algorithm later than
+        // the time it was scheduled for
+        let status = match activation_result {
+            Ok(_) => business_status::COMPLETED_BY_PT,
+            Err(err) => {
+                error!(?err, %process.id, "Failed to activate the scheduled routing algorithm");
+                business_status::FAILURE
+            }
+        };
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, status)
+            .await?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        Err(errors::ProcessTrackerError::NotImplemented)
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,246 @@
+use common_utils::ext_traits::{OptionExt, ValueExt};
+use diesel_models::process_tracker::business_status;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{
+    core::{
+        errors::StorageErrorExt,
+        payments::{self as payment_flows, operations},
+    },
+    db::StorageInterface,
+    errors,
+    routes::SessionState,
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums},
+    },
+    workflows::payment_sync,
+};
+
+pub struct ScheduledCaptureWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for ScheduledCaptureWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::ScheduledCaptureTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("ScheduledCaptureTrackingData")?;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                key_manager_state,
+                &tracking_data.payment_id,
+                &tracking_data.merchant_id,
+                &key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        let payment_attempt = db
+            .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+                &tracking_data.payment_id,
+                &tracking_data.merchant_id,
+                &tracking_data.attempt_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        // The payment was captured, failed or cancelled before the scheduled time, or was
+        // confirmed again with another attempt
+        if payment_attempt.capture_on.is_none()
+            || payment_intent.active_attempt.get_id() != tracking_data.attempt_id
+            || !matches!(
+                payment_intent.status,
+                enums::IntentStatus::RequiresCapture
+                    | enums::IntentStatus::Processing
+                    | enums::IntentStatus::RequiresCustomerAction
+            )
+        {
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    business_status::RESOURCE_STATUS_MISMATCH,
+                )
+                .await
+                .map_err(Into::into);
+        }
+
+        let connector = payment_attempt
+            .connector
+            .clone()
+            .get_required_value("connector")?;
+
+        // The authorization of the payment is not complete yet, the capture is retried later
+        if payment_intent.status != enums::IntentStatus::RequiresCapture {
+            payment_sync::retry_sync_task(db, connector, tracking_data.merchant_id, process)
+                .await?;
+            return Ok(());
+        }
+
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store,
+        )));
+
+        let capture_request = api::PaymentsCaptureRequest {
+            payment_id: tracking_data.payment_id.clone(),
+            merchant_id: Some(tracking_data.merchant_id.clone()),
+            ..Default::default()
+        };
+
+        // TODO: Add support for ReqState in PT flows
+        let capture_result = Box::pin(payment_flows::payments_operation_core::<
+            api::Capture,
+            _,
+            _,
+            _,
+            payment_flows::PaymentData<api::Capture>,
+        >(
+            state,
+            state.get_req_state(),
+            &merchant_context,
+            None,
+            operations::PaymentCapture,
+            capture_request,
+            payment_flows::CallConnectorAction::Trigger,
+            services::AuthFlow::Merchant,
+            None,
+            hyperswitch_domain_models::payments::HeaderPayload::default(),
+        ))
+        .await;
+
+        let capture_business_status = match &capture_result {
+            // The capture was not applied, as when the connector rate limits the request
+            Ok((payment_data, ..))
+                if payment_data.payment_intent.status == enums::IntentStatus::RequiresCapture =>
+            {
+                None
+            }
+            Ok(_) => Some(business_status::COMPLETED_BY_PT),
+            Err(error) => {
+                logger::error!(
+                    %process.id,
+                    payment_id = ?tracking_data.payment_id,
+                    ?error,
+                    "Failed to capture the payment at the scheduled time"
+                );
+                (!is_capture_error_transient(error.current_context()))
+                    .then_some(business_status::FAILURE)
+            }
+        };
+
+        match capture_business_status {
+            Some(capture_business_status) => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, capture_business_status)
+                    .await?;
+            }
+            None => {
+                payment_sync::retry_sync_task(db, connector, tracking_data.merchant_id, process)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        Err(sch_errors::ProcessTrackerError::NotImplemented)
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+/// Whether the capture failed with an error which may not occur when the capture is retried, as
+/// opposed to the capture being rejected for the payment
+#[cfg(feature = "v1")]
+fn is_capture_error_transient(error: &errors::ApiErrorResponse) -> bool {
+    match error {
+        errors::ApiErrorResponse::ExternalConnectorError { status_code, .. } => {
+            *status_code == 429 || (500..=599).contains(status_code)
+        }
+        errors::ApiErrorResponse::InternalServerError | errors::ApiErrorResponse::ResourceBusy => {
+            true
+        }
+        _ => false,
+    }
+}
+
+#[cfg(feature = "v1")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_capture_error_transient() {
+        let connector_error = |status_code| errors::ApiErrorResponse::ExternalConnectorError {
+            code: "E0001".to_string(),
+            message: "Capture failed".to_string(),
+            connector: "stripe".to_string(),
+            status_code,
+            reason: None,
+        };
+
+        assert!(is_capture_error_transient(&connector_error(503)));
+        assert!(is_capture_error_transient(&connector_error(429)));
+        assert!(!is_capture_error_transient(&connector_error(400)));
+        assert!(is_capture_error_transient(
+            &errors::ApiErrorResponse::InternalServerError
+        ));
+        assert!(!is_capture_error_transient(
+            &errors::ApiErrorResponse::PaymentCaptureFailed { data: None }
+        ));
+        assert!(!is_capture_error_transient(
+            &errors::ApiErrorResponse::PaymentUnexpectedState {
+                current_flow: "captured".to_string(),
+                field_name: "capture_method".to_string(),
+                current_value: "automatic".to_string(),
+                states: "manual, manual_multiple, scheduled".to_string(),
+            }
+        ));
+    }
+}
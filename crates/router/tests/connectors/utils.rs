@@ -999,6 +999,8 @@ impl Default for PaymentAuthorizeType {
             merchant_config_currency: None,
             connector_testing_data: None,
             order_id: None,
+            installment_plan: None,
+            currency_conversion: None,
         };
         Self(data)
     }
@@ -465,6 +465,12 @@ async fn payments_create_core() {
         issuer_error_message: None,
         is_iframe_redirection_enabled: None,
         whole_connector_response: None,
+        authorization_reversal_status: None,
+        amount_reversed: None,
+        installment_plan: None,
+        currency_conversion: None,
+        cancellation_reason_code: None,
+        authorized_at: None,
     };
     let expected_response =
         services::ApplicationResponse::JsonWithHeaders((expected_response, vec![]));
@@ -743,6 +749,12 @@ async fn payments_create_core_adyen_no_redirect() {
             issuer_error_message: None,
             is_iframe_redirection_enabled: None,
             whole_connector_response: None,
+            authorization_reversal_status: None,
+            amount_reversed: None,
+            installment_plan: None,
+            currency_conversion: None,
+            cancellation_reason_code: None,
+            authorized_at: None,
         },
         vec![],
     ));
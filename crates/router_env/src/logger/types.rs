@@ -138,12 +138,34 @@ pub enum Flow {
     PaymentMethodsUpdate,
     /// Payment methods delete flow.
     PaymentMethodsDelete,
+    /// Payment method duplicates list flow.
+    PaymentMethodDuplicatesList,
+    /// Payment method duplicates merge flow.
+    PaymentMethodDuplicatesMerge,
+    /// Payment method export create flow.
+    PaymentMethodExportCreate,
+    /// Payment method export retrieve flow.
+    PaymentMethodExportRetrieve,
+    /// Payment method export file download flow.
+    PaymentMethodExportDownload,
+    /// Payment method export recipient key create flow.
+    PaymentMethodExportRecipientKeyCreate,
+    /// Payment method export recipient keys list flow.
+    PaymentMethodExportRecipientKeyList,
+    /// Payment method export recipient key approve flow.
+    PaymentMethodExportRecipientKeyApprove,
     /// Default Payment method flow.
     DefaultPaymentMethodsSet,
     /// Payments create flow.
     PaymentsCreate,
     /// Payments Retrieve flow.
     PaymentsRetrieve,
+    /// Payments ledger retrieve flow.
+    PaymentsLedgerRetrieve,
+    /// Payments installment plans list flow.
+    PaymentsInstallmentPlans,
+    /// Payments currency conversion quote flow.
+    PaymentsCurrencyConversionQuote,
     /// Payments Retrieve force sync flow.
     PaymentsRetrieveForceSync,
     /// Payments Retrieve using merchant reference id
@@ -156,6 +178,10 @@ pub enum Flow {
     PaymentsCapture,
     /// Payments cancel flow.
     PaymentsCancel,
+    /// Payments authorization reversal flow, releasing the uncaptured amount of a partially captured payment.
+    PaymentsReverseAuthorization,
+    /// Payments cancel flow for captured payments, voiding the capture or refunding it.
+    PaymentsCancelPostCapture,
     /// Payments approve flow.
     PaymentsApprove,
     /// Payments reject flow.
@@ -255,6 +281,34 @@ pub enum Flow {
     RoutingUpdateDefaultConfig,
     /// Routing delete config
     RoutingDeleteConfig,
+    /// Routing simulate config
+    RoutingSimulateConfig,
+    /// Routing DSL parse
+    RoutingDslParse,
+    /// Routing DSL print
+    RoutingDslPrint,
+    /// Routing link shadow config
+    RoutingLinkShadowConfig,
+    /// Routing unlink shadow config
+    RoutingUnlinkShadowConfig,
+    /// Routing rollback config
+    RoutingRollbackConfig,
+    /// Routing diff config
+    RoutingDiffConfig,
+    /// Routing schedule activation
+    RoutingScheduleActivation,
+    /// Routing list scheduled activations
+    RoutingListScheduledActivations,
+    /// Routing cancel scheduled activation
+    RoutingCancelScheduledActivation,
+    /// Routing upsert payment time config
+    RoutingUpsertTemporalConfig,
+    /// Routing retrieve payment time config
+    RoutingRetrieveTemporalConfig,
+    /// Routing delete payment time config
+    RoutingDeleteTemporalConfig,
+    /// Routing eligibility explanation
+    RoutingEligibilityExplanation,
     /// Toggle dynamic routing
     ToggleDynamicRouting,
     /// Update dynamic routing config
@@ -558,6 +612,8 @@ pub enum Flow {
     TokenizeCardBatch,
     /// Incoming Relay Webhook Receive
     IncomingRelayWebhookReceive,
+    /// Incoming network token lifecycle webhook receive
+    NetworkTokenLifecycleWebhookReceive,
     /// Generate Hypersense Token
     HypersenseTokenRequest,
     /// Verify Hypersense Token
@@ -62,6 +62,13 @@ pub trait ProcessTrackerInterface: Send + Sync + 'static {
         status: storage_enums::ProcessTrackerStatus,
         limit: Option<i64>,
     ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
+
+    async fn find_processes_by_runner_id_prefix_status(
+        &self,
+        runner: storage_enums::ProcessTrackerRunner,
+        id_prefix: &str,
+        statuses: Vec<storage_enums::ProcessTrackerStatus>,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -107,6 +114,23 @@ impl ProcessTrackerInterface for Store {
         .map_err(|error| report!(errors::StorageError::from(error)))
     }
 
+    async fn find_processes_by_runner_id_prefix_status(
+        &self,
+        runner: storage_enums::ProcessTrackerRunner,
+        id_prefix: &str,
+        statuses: Vec<storage_enums::ProcessTrackerStatus>,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ProcessTracker::find_processes_by_runner_id_prefix_status(
+            &conn,
+            &runner.to_string(),
+            id_prefix,
+            statuses,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
     async fn insert_process(
         &self,
         new: storage::ProcessTrackerNew,
@@ -230,6 +254,31 @@ impl ProcessTrackerInterface for MockDb {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn find_processes_by_runner_id_prefix_status(
+        &self,
+        runner: storage_enums::ProcessTrackerRunner,
+        id_prefix: &str,
+        statuses: Vec<storage_enums::ProcessTrackerStatus>,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let runner = runner.to_string();
+        let mut processes: Vec<_> = self
+            .processes
+            .lock()
+            .await
+            .iter()
+            .filter(|process| {
+                process.runner.as_deref() == Some(runner.as_str())
+                    && process.id.starts_with(id_prefix)
+                    && statuses.contains(&process.status)
+            })
+            .cloned()
+            .collect();
+        processes.sort_by_key(|process| process.schedule_time);
+
+        Ok(processes)
+    }
+
     async fn insert_process(
         &self,
         new: storage::ProcessTrackerNew,
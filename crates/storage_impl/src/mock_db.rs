@@ -40,6 +40,7 @@ pub struct MockDb {
     pub ephemeral_keys: Arc<Mutex<Vec<store::EphemeralKey>>>,
     pub cards_info: Arc<Mutex<Vec<store::CardInfo>>>,
     pub events: Arc<Mutex<Vec<store::Event>>>,
+    pub idempotency_keys: Arc<Mutex<Vec<store::IdempotencyKey>>>,
     pub disputes: Arc<Mutex<Vec<store::Dispute>>>,
     pub lockers: Arc<Mutex<Vec<store::LockerMockUp>>>,
     pub mandates: Arc<Mutex<Vec<store::Mandate>>>,
@@ -89,6 +90,7 @@ impl MockDb {
             ephemeral_keys: Default::default(),
             cards_info: Default::default(),
             events: Default::default(),
+            idempotency_keys: Default::default(),
             disputes: Default::default(),
             lockers: Default::default(),
             mandates: Default::default(),
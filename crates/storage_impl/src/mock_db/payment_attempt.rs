@@ -235,6 +235,13 @@ impl PaymentAttemptInterface for MockDb {
             processor_merchant_id: payment_attempt.processor_merchant_id,
             created_by: payment_attempt.created_by,
             setup_future_usage_applied: payment_attempt.setup_future_usage_applied,
+            authorization_reversal_status: payment_attempt.authorization_reversal_status,
+            amount_reversed: payment_attempt.amount_reversed,
+            installment_plan: payment_attempt.installment_plan,
+            currency_conversion: payment_attempt.currency_conversion,
+            cancellation_reason_code: payment_attempt.cancellation_reason_code,
+            authorized_at: payment_attempt.authorized_at,
+            captured_at: payment_attempt.captured_at,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
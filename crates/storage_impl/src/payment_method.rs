@@ -4,13 +4,19 @@ use crate::redis::kv_store::KvStorePartition;
 
 impl KvStorePartition for PaymentMethod {}
 
+#[cfg(feature = "v2")]
+use async_bb8_diesel::AsyncConnection;
 use common_enums::enums::MerchantStorageScheme;
 use common_utils::{errors::CustomResult, id_type, types::keymanager::KeyManagerState};
+#[cfg(feature = "v2")]
+use diesel_models::mandate::{Mandate, MandateUpdate};
 use diesel_models::{
     kv,
     payment_method::{PaymentMethodUpdate, PaymentMethodUpdateInternal},
 };
 use error_stack::ResultExt;
+#[cfg(feature = "v1")]
+use hyperswitch_domain_models::payment_methods::PaymentMethodListConstraints;
 use hyperswitch_domain_models::{
     behaviour::{Conversion, ReverseConversion},
     merchant_key_store::MerchantKeyStore,
@@ -96,6 +102,26 @@ impl<T: DatabaseStore> PaymentMethodInterface for KVRouterStore<T> {
         .await
     }
 
+    // not supported in kv
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_payment_method_list_by_merchant_id_constraints(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        constraints: &PaymentMethodListConstraints,
+    ) -> CustomResult<Vec<DomainPaymentMethod>, errors::StorageError> {
+        self.router_store
+            .find_payment_method_list_by_merchant_id_constraints(
+                state,
+                key_store,
+                merchant_id,
+                constraints,
+            )
+            .await
+    }
+
     // not supported in kv
     #[cfg(feature = "v1")]
     #[instrument(skip_all)]
@@ -394,6 +420,68 @@ impl<T: DatabaseStore> PaymentMethodInterface for KVRouterStore<T> {
             .find_payment_method_by_fingerprint_id(state, key_store, fingerprint_id)
             .await
     }
+
+    #[cfg(feature = "v2")]
+    async fn find_payment_method_list_by_merchant_id_fingerprint_id(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        fingerprint_id: &str,
+    ) -> CustomResult<Vec<DomainPaymentMethod>, errors::StorageError> {
+        self.router_store
+            .find_payment_method_list_by_merchant_id_fingerprint_id(
+                state,
+                key_store,
+                merchant_id,
+                fingerprint_id,
+            )
+            .await
+    }
+
+    #[cfg(feature = "v2")]
+    #[instrument(skip_all)]
+    async fn find_payment_method_list_by_merchant_id_after_id(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        after: Option<&id_type::GlobalPaymentMethodId>,
+        limit: i64,
+    ) -> CustomResult<Vec<DomainPaymentMethod>, errors::StorageError> {
+        self.router_store
+            .find_payment_method_list_by_merchant_id_after_id(
+                state,
+                key_store,
+                merchant_id,
+                after,
+                limit,
+            )
+            .await
+    }
+
+    #[cfg(feature = "v2")]
+    #[instrument(skip_all)]
+    async fn merge_payment_methods(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        payment_method: DomainPaymentMethod,
+        payment_method_update: PaymentMethodUpdate,
+        duplicates: Vec<DomainPaymentMethod>,
+        mandate_ids: Vec<String>,
+    ) -> CustomResult<DomainPaymentMethod, errors::StorageError> {
+        self.router_store
+            .merge_payment_methods(
+                state,
+                key_store,
+                payment_method,
+                payment_method_update,
+                duplicates,
+                mandate_ids,
+            )
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -452,6 +540,34 @@ impl<T: DatabaseStore> PaymentMethodInterface for RouterStore<T> {
         .await
     }
 
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_payment_method_list_by_merchant_id_constraints(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        constraints: &PaymentMethodListConstraints,
+    ) -> CustomResult<Vec<DomainPaymentMethod>, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        self.find_resources(
+            state,
+            key_store,
+            PaymentMethod::find_by_merchant_id_status_after(
+                &conn,
+                merchant_id,
+                constraints.status,
+                constraints.created_before,
+                constraints
+                    .after
+                    .as_ref()
+                    .map(|cursor| (cursor.created_at, cursor.payment_method_id.as_str())),
+                constraints.limit,
+            ),
+        )
+        .await
+    }
+
     #[cfg(feature = "v1")]
     #[instrument(skip_all)]
     async fn get_payment_method_count_by_customer_id_merchant_id_status(
@@ -702,6 +818,116 @@ impl<T: DatabaseStore> PaymentMethodInterface for RouterStore<T> {
         )
         .await
     }
+
+    #[cfg(feature = "v2")]
+    async fn find_payment_method_list_by_merchant_id_fingerprint_id(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        fingerprint_id: &str,
+    ) -> CustomResult<Vec<DomainPaymentMethod>, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        self.find_resources(
+            state,
+            key_store,
+            PaymentMethod::find_by_merchant_id_fingerprint_id(&conn, merchant_id, fingerprint_id),
+        )
+        .await
+    }
+
+    #[cfg(feature = "v2")]
+    #[instrument(skip_all)]
+    async fn find_payment_method_list_by_merchant_id_after_id(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        after: Option<&id_type::GlobalPaymentMethodId>,
+        limit: i64,
+    ) -> CustomResult<Vec<DomainPaymentMethod>, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        self.find_resources(
+            state,
+            key_store,
+            PaymentMethod::find_by_merchant_id_after_id(&conn, merchant_id, after, limit),
+        )
+        .await
+    }
+
+    #[cfg(feature = "v2")]
+    #[instrument(skip_all)]
+    async fn merge_payment_methods(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        payment_method: DomainPaymentMethod,
+        payment_method_update: PaymentMethodUpdate,
+        duplicates: Vec<DomainPaymentMethod>,
+        mandate_ids: Vec<String>,
+    ) -> CustomResult<DomainPaymentMethod, errors::StorageError> {
+        let payment_method = Conversion::convert(payment_method)
+            .await
+            .change_context(errors::StorageError::EncryptionError)?;
+        let mut duplicate_payment_methods = Vec::with_capacity(duplicates.len());
+        for duplicate in duplicates {
+            duplicate_payment_methods.push(
+                Conversion::convert(duplicate)
+                    .await
+                    .change_context(errors::StorageError::EncryptionError)?,
+            );
+        }
+
+        let conn = pg_connection_write(self).await?;
+        let merged_payment_method = conn
+            .transaction_async(|conn| async move {
+                let merchant_id = payment_method.merchant_id.clone();
+                let payment_method_id = payment_method.get_id().get_string_repr().to_owned();
+
+                for mandate_id in mandate_ids {
+                    Mandate::update_by_merchant_id_mandate_id(
+                        &conn,
+                        &merchant_id,
+                        &mandate_id,
+                        MandateUpdate::PaymentMethodIdUpdate {
+                            payment_method_id: payment_method_id.clone(),
+                        }
+                        .into(),
+                    )
+                    .await?;
+                }
+
+                for duplicate in duplicate_payment_methods {
+                    duplicate
+                        .update_with_id(
+                            &conn,
+                            PaymentMethodUpdate::StatusUpdate {
+                                status: Some(common_enums::PaymentMethodStatus::Inactive),
+                            }
+                            .into(),
+                        )
+                        .await?;
+                }
+
+                Ok::<_, errors::StorageError>(
+                    payment_method
+                        .update_with_id(&conn, payment_method_update.into())
+                        .await?,
+                )
+            })
+            .await
+            .map_err(|error| error_stack::report!(error))
+            .attach_printable("Failed to merge the duplicate payment methods")?;
+
+        merged_payment_method
+            .convert(
+                state,
+                key_store.key.get_inner(),
+                key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
 }
 
 #[async_trait::async_trait]
@@ -764,6 +990,52 @@ impl PaymentMethodInterface for MockDb {
         .await
     }
 
+    #[cfg(feature = "v1")]
+    async fn find_payment_method_list_by_merchant_id_constraints(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        constraints: &PaymentMethodListConstraints,
+    ) -> CustomResult<Vec<DomainPaymentMethod>, errors::StorageError> {
+        let limit = usize::try_from(constraints.limit).unwrap_or(usize::MAX);
+
+        let payment_methods = self.payment_methods.lock().await;
+        let mut page = payment_methods
+            .iter()
+            .filter(|pm| {
+                pm.merchant_id == *merchant_id
+                    && constraints.is_listed(pm.status, pm.created_at, &pm.payment_method_id)
+            })
+            .map(|pm| (pm.created_at, pm.payment_method_id.clone()))
+            .collect::<Vec<_>>();
+        page.sort();
+        page.truncate(limit);
+
+        // An empty page is not an error, unlike a missing payment method
+        if page.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.get_resources(
+            state,
+            key_store,
+            payment_methods,
+            |pm| {
+                page.binary_search(&(pm.created_at, pm.payment_method_id.clone()))
+                    .is_ok()
+            },
+            "cannot find payment method".to_string(),
+        )
+        .await
+        .map(|mut payment_methods: Vec<DomainPaymentMethod>| {
+            payment_methods.sort_by(|a, b| {
+                (a.created_at, &a.payment_method_id).cmp(&(b.created_at, &b.payment_method_id))
+            });
+            payment_methods
+        })
+    }
+
     #[cfg(feature = "v1")]
     async fn get_payment_method_count_by_customer_id_merchant_id_status(
         &self,
@@ -991,4 +1263,120 @@ impl PaymentMethodInterface for MockDb {
         )
         .await
     }
+
+    #[cfg(feature = "v2")]
+    async fn find_payment_method_list_by_merchant_id_fingerprint_id(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        fingerprint_id: &str,
+    ) -> CustomResult<Vec<DomainPaymentMethod>, errors::StorageError> {
+        let payment_methods = self.payment_methods.lock().await;
+        self.get_resources(
+            state,
+            key_store,
+            payment_methods,
+            |pm| {
+                pm.merchant_id == *merchant_id
+                    && pm.locker_fingerprint_id.as_deref() == Some(fingerprint_id)
+            },
+            "cannot find payment method".to_string(),
+        )
+        .await
+    }
+
+    #[cfg(feature = "v2")]
+    async fn find_payment_method_list_by_merchant_id_after_id(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        after: Option<&id_type::GlobalPaymentMethodId>,
+        limit: i64,
+    ) -> CustomResult<Vec<DomainPaymentMethod>, errors::StorageError> {
+        let limit = usize::try_from(limit).unwrap_or(usize::MAX);
+
+        let payment_methods = self.payment_methods.lock().await;
+        let mut page = payment_methods
+            .iter()
+            .filter(|pm| {
+                pm.merchant_id == *merchant_id
+                    && after.map_or(true, |after| {
+                        pm.get_id().get_string_repr() > after.get_string_repr()
+                    })
+            })
+            .map(|pm| pm.get_id().get_string_repr().to_owned())
+            .collect::<Vec<_>>();
+        page.sort();
+        page.truncate(limit);
+
+        // An empty page is not an error, unlike a missing payment method
+        if page.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.get_resources(
+            state,
+            key_store,
+            payment_methods,
+            |pm| {
+                page.binary_search(&pm.get_id().get_string_repr().to_owned())
+                    .is_ok()
+            },
+            "cannot find payment method".to_string(),
+        )
+        .await
+        .map(|mut payment_methods: Vec<DomainPaymentMethod>| {
+            payment_methods.sort_by(|a, b| {
+                a.get_id()
+                    .get_string_repr()
+                    .cmp(b.get_id().get_string_repr())
+            });
+            payment_methods
+        })
+    }
+
+    #[cfg(feature = "v2")]
+    async fn merge_payment_methods(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        payment_method: DomainPaymentMethod,
+        payment_method_update: PaymentMethodUpdate,
+        duplicates: Vec<DomainPaymentMethod>,
+        mandate_ids: Vec<String>,
+    ) -> CustomResult<DomainPaymentMethod, errors::StorageError> {
+        let payment_method_id = payment_method.get_id().get_string_repr().to_owned();
+        let mut mandates = self.mandates.lock().await;
+        for mandate in mandates.iter_mut().filter(|mandate| {
+            mandate.merchant_id == payment_method.merchant_id
+                && mandate_ids.contains(&mandate.mandate_id)
+        }) {
+            mandate.payment_method_id = payment_method_id.clone();
+        }
+        drop(mandates);
+
+        for duplicate in duplicates {
+            self.update_payment_method(
+                state,
+                key_store,
+                duplicate,
+                PaymentMethodUpdate::StatusUpdate {
+                    status: Some(common_enums::PaymentMethodStatus::Inactive),
+                },
+                MerchantStorageScheme::PostgresOnly,
+            )
+            .await?;
+        }
+
+        self.update_payment_method(
+            state,
+            key_store,
+            payment_method,
+            payment_method_update,
+            MerchantStorageScheme::PostgresOnly,
+        )
+        .await
+    }
 }
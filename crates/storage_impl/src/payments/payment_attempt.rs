@@ -680,6 +680,13 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                     processor_merchant_id: payment_attempt.processor_merchant_id.clone(),
                     created_by: payment_attempt.created_by.clone(),
                     setup_future_usage_applied: payment_attempt.setup_future_usage_applied,
+                    authorization_reversal_status: payment_attempt.authorization_reversal_status,
+                    amount_reversed: payment_attempt.amount_reversed,
+                    installment_plan: payment_attempt.installment_plan,
+                    currency_conversion: payment_attempt.currency_conversion.clone(),
+                    cancellation_reason_code: payment_attempt.cancellation_reason_code,
+                    authorized_at: payment_attempt.authorized_at,
+                    captured_at: payment_attempt.captured_at,
                 };
 
                 let field = format!("pa_{}", created_attempt.attempt_id);
@@ -1704,6 +1711,13 @@ impl DataModelExt for PaymentAttempt {
             issuer_error_code: self.issuer_error_code,
             issuer_error_message: self.issuer_error_message,
             setup_future_usage_applied: self.setup_future_usage_applied,
+            authorization_reversal_status: self.authorization_reversal_status,
+            amount_reversed: self.amount_reversed,
+            installment_plan: self.installment_plan,
+            currency_conversion: self.currency_conversion,
+            cancellation_reason_code: self.cancellation_reason_code,
+            authorized_at: self.authorized_at,
+            captured_at: self.captured_at,
             // Below fields are deprecated. Please add any new fields above this line.
             connector_transaction_data: None,
             processor_merchant_id: Some(self.processor_merchant_id),
@@ -1798,6 +1812,13 @@ impl DataModelExt for PaymentAttempt {
                 .created_by
                 .and_then(|created_by| created_by.parse::<CreatedBy>().ok()),
             setup_future_usage_applied: storage_model.setup_future_usage_applied,
+            authorization_reversal_status: storage_model.authorization_reversal_status,
+            amount_reversed: storage_model.amount_reversed,
+            installment_plan: storage_model.installment_plan,
+            currency_conversion: storage_model.currency_conversion,
+            cancellation_reason_code: storage_model.cancellation_reason_code,
+            authorized_at: storage_model.authorized_at,
+            captured_at: storage_model.captured_at,
         }
     }
 }
@@ -1887,6 +1908,13 @@ impl DataModelExt for PaymentAttemptNew {
             processor_merchant_id: Some(self.processor_merchant_id),
             created_by: self.created_by.map(|created_by| created_by.to_string()),
             setup_future_usage_applied: self.setup_future_usage_applied,
+            authorization_reversal_status: self.authorization_reversal_status,
+            amount_reversed: self.amount_reversed,
+            installment_plan: self.installment_plan,
+            currency_conversion: self.currency_conversion,
+            cancellation_reason_code: self.cancellation_reason_code,
+            authorized_at: self.authorized_at,
+            captured_at: self.captured_at,
         }
     }
 
@@ -1969,6 +1997,13 @@ impl DataModelExt for PaymentAttemptNew {
                 .created_by
                 .and_then(|created_by| created_by.parse::<CreatedBy>().ok()),
             setup_future_usage_applied: storage_model.setup_future_usage_applied,
+            authorization_reversal_status: storage_model.authorization_reversal_status,
+            amount_reversed: storage_model.amount_reversed,
+            installment_plan: storage_model.installment_plan,
+            currency_conversion: storage_model.currency_conversion,
+            cancellation_reason_code: storage_model.cancellation_reason_code,
+            authorized_at: storage_model.authorized_at,
+            captured_at: storage_model.captured_at,
         }
     }
 }
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE routing_algorithm
+DROP COLUMN IF EXISTS parent_algorithm_id;
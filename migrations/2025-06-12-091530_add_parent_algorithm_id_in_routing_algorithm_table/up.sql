@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE routing_algorithm
+ADD COLUMN IF NOT EXISTS parent_algorithm_id VARCHAR(64);
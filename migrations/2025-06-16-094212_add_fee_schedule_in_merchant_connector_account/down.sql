@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE merchant_connector_account
+DROP COLUMN IF EXISTS fee_schedule;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TYPE "IntentStatus" ADD VALUE IF NOT EXISTS 'expired';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_expired';
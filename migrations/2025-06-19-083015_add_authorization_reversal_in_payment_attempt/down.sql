@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_attempt
+DROP COLUMN IF EXISTS authorization_reversal_status,
+DROP COLUMN IF EXISTS amount_reversed;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt
+ADD COLUMN IF NOT EXISTS authorization_reversal_status VARCHAR(32) DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS amount_reversed BIGINT DEFAULT NULL;
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE business_profile
+ADD COLUMN IF NOT EXISTS is_auto_reauthorization_enabled BOOLEAN DEFAULT NULL;
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payment_ledger_entry_reference_index;
+
+DROP TABLE IF EXISTS payment_ledger_entry;
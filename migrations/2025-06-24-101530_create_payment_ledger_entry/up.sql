@@ -0,0 +1,22 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS payment_ledger_entry (
+    id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(64) NOT NULL,
+    attempt_id VARCHAR(64) NOT NULL,
+    reference_id VARCHAR(64) NOT NULL,
+    entry_type VARCHAR(32) NOT NULL,
+    debit_account VARCHAR(32) NOT NULL,
+    credit_account VARCHAR(32) NOT NULL,
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+-- An amount movement is recorded only once, even when the flow recording it is processed again
+CREATE UNIQUE INDEX IF NOT EXISTS payment_ledger_entry_reference_index ON payment_ledger_entry (
+    merchant_id,
+    payment_id,
+    reference_id,
+    entry_type
+);
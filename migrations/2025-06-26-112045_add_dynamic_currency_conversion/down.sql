@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE business_profile DROP COLUMN IF EXISTS dynamic_currency_conversion;
+
+ALTER TABLE payment_attempt DROP COLUMN IF EXISTS currency_conversion;
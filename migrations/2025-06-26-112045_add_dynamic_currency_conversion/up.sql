@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE business_profile ADD COLUMN IF NOT EXISTS dynamic_currency_conversion JSONB DEFAULT NULL;
+
+ALTER TABLE payment_attempt ADD COLUMN IF NOT EXISTS currency_conversion JSONB DEFAULT NULL;
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'payment_methods';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_method_updated';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'payment_method_details';
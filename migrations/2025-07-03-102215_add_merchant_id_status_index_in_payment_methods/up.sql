@@ -0,0 +1,2 @@
+-- Your SQL goes here
+CREATE INDEX IF NOT EXISTS payment_methods_merchant_id_status_created_at_index ON payment_methods (merchant_id, status, created_at, payment_method_id);
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payment_methods_merchant_id_locker_fingerprint_id_index;
+
+ALTER TABLE business_profile
+DROP COLUMN IF EXISTS payment_method_duplicate_policy;
@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE business_profile
+ADD COLUMN IF NOT EXISTS payment_method_duplicate_policy VARCHAR(32);
+
+CREATE INDEX IF NOT EXISTS payment_methods_merchant_id_locker_fingerprint_id_index ON payment_methods (merchant_id, locker_fingerprint_id);
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DELETE FROM process_tracker
+WHERE runner = 'PAYMENT_METHOD_FINGERPRINT_BACKFILL_WORKFLOW';
@@ -0,0 +1,40 @@
+-- Your SQL goes here
+-- Payment methods were fingerprinted with a key scoped to the customer, schedule the backfill of
+-- their fingerprints with the key scoped to the merchant for every merchant with fingerprinted
+-- payment methods
+INSERT INTO process_tracker (
+        id,
+        name,
+        tag,
+        runner,
+        retry_count,
+        schedule_time,
+        rule,
+        tracking_data,
+        business_status,
+        status,
+        event,
+        created_at,
+        updated_at,
+        version
+    )
+SELECT 'PAYMENT_METHOD_FINGERPRINT_BACKFILL_WORKFLOW_BACKFILL_FINGERPRINTS_' || merchant_id || '_' || merchant_id,
+    'BACKFILL_FINGERPRINTS',
+    ARRAY ['PAYMENT_METHOD'],
+    'PAYMENT_METHOD_FINGERPRINT_BACKFILL_WORKFLOW',
+    0,
+    now(),
+    '',
+    json_build_object('merchant_id', merchant_id, 'after', NULL),
+    'Pending',
+    'new',
+    ARRAY []::TEXT [],
+    now(),
+    now(),
+    'v2'
+FROM (
+        SELECT DISTINCT merchant_id
+        FROM payment_methods
+        WHERE locker_fingerprint_id IS NOT NULL
+    ) AS merchants
+ON CONFLICT (id) DO NOTHING;